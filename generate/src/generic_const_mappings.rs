@@ -9,7 +9,10 @@ pub fn emit_impls() -> String {
 //!   - It requires the `const-generics` crate feature to be enabled.
 //!
 //! The main type to use here is [`U`], although [`Const`] and [`ToUInt`] may be needed
-//! in a generic context.
+//! in a generic context. [`ToConst`] maps the other way, from a [`UInt`] back to a `const`.
+//!
+//! For signed `const N: isize` parameters, [`I`], [`ConstI`], and [`ToInteger`] do the same,
+//! mapping to [`PInt`], [`NInt`], and [`Z0`].
 
 use crate::*;
 
@@ -58,6 +61,116 @@ pub trait ToUInt {
     /// The [`UN`][`crate::U42`] type corresponding to `Self = Const<N>`.
     type Output;
 }
+
+/// The reverse of [`U`]: the mapping from a [`UInt`] to the [`Const`] of its value, which is
+/// implemented for the same numbers as [`ToUInt`].
+///
+///   - It requires the `const-generics` crate feature to be enabled.
+///
+/// The value can't be used as an array length directly, as in `[T; N::USIZE]`, but a
+/// `const N: usize` parameter bound by `ToConst<Output = Const<N>>` is inferred from the type:
+///
+/// ```rust
+/// use typenum::*;
+///
+/// fn zeros<L, const N: usize>(_: L) -> [u8; N]
+/// where
+///     L: ToConst<Output = Const<N>>,
+/// {
+///     [0; N]
+/// }
+///
+/// assert_eq!(zeros(U4::new()), [0; 4]);
+///
+/// trait Storage {
+///     type Array;
+/// }
+///
+/// impl<L, const N: usize> Storage for L
+/// where
+///     L: ToConst<Output = Const<N>>,
+/// {
+///     type Array = [u32; N];
+/// }
+///
+/// let array: <U3 as Storage>::Array = [1, 2, 3];
+/// assert_eq!(array.len(), U3::USIZE);
+/// ```
+pub trait ToConst {
+    /// The `Const<N>` type corresponding to `Self = UN`.
+    type Output;
+}
+
+/// The mapping from a generic `const: isize` to a signed integer: [`I<N>`] is expected to work
+/// like [`PN`] for positive `N`, like [`NN`] for negative `N`, and like [`Z0`] for zero.
+///
+///   - It requires the `const-generics` crate feature to be enabled.
+///
+/// It is defined for the same magnitudes as [`U`].
+///
+/// [`I<N>`]: `I`
+/// [`PN`]: `P42`
+/// [`NN`]: `N42`
+///
+/// # Example
+///
+/// ```rust
+/// use typenum::*;
+///
+/// assert_type_eq!(I<42>, P42);
+/// assert_type_eq!(I<-42>, N42);
+/// assert_type_eq!(I<0>, Z0);
+/// ```
+///
+/// As with [`U`], it can be used in a generic `const N: isize` context when guarded by a
+/// `where` clause:
+///
+/// ```rust
+/// use typenum::*;
+///
+/// struct Exponent<const N: isize>;
+///
+/// trait ToTypenum { type Output; }
+///
+/// impl<const N: isize> ToTypenum for Exponent<N>
+/// where
+///     ConstI<N>: ToInteger,
+/// {
+///     type Output = I<N>;
+/// }
+///
+/// assert_type_eq!(<Exponent<-3> as ToTypenum>::Output, N3);
+/// ```
+pub type I<const N: isize> = <ConstI<N> as ToInteger>::Output;
+
+/// Used to allow the usage of [`I`] in a generic context.
+pub struct ConstI<const N: isize>;
+
+/// Used to allow the usage of [`I`] in a generic context.
+pub trait ToInteger {
+    /// The [`PN`][`crate::P42`], [`NN`][`crate::N42`], or [`Z0`] type corresponding to
+    /// `Self = ConstI<N>`.
+    type Output;
+}
+
+/// The value of `N`, the same as that of [`U<N>`](U).
+///
+/// ```rust
+/// use typenum::*;
+///
+/// assert_eq!(<Const<42> as ToInt<i32>>::INT, 42);
+/// ```
+impl<T, const N: usize> ToInt<T> for Const<N>
+where
+    Const<N>: ToUInt,
+    U<N>: ToInt<T>,
+{
+    #[inline]
+    fn to_int() -> T {
+        <U<N> as ToInt<T>>::to_int()
+    }
+    const INT: T = <U<N> as ToInt<T>>::INT;
+}
 ");
 
     for uint in uints() {
@@ -67,6 +180,11 @@ pub trait ToUInt {
     impl ToUInt for Const<{uint}> {{
         type Output = U{uint};
     }}
+
+    {cfg}
+    impl ToConst for U{uint} {{
+        type Output = Const<{uint}>;
+    }}
 \
             ",
             uint = uint,
@@ -74,6 +192,32 @@ pub trait ToUInt {
         ));
     }
 
+    result.push_str(
+        "
+    impl ToInteger for ConstI<0> {
+        type Output = Z0;
+    }
+",
+    );
+    for uint in uints().filter(|&uint| uint != 0 && uint <= i64::MAX as u64) {
+        result.push_str(&format!(
+            "
+    {cfg}
+    impl ToInteger for ConstI<{uint}> {{
+        type Output = P{uint};
+    }}
+
+    {cfg}
+    impl ToInteger for ConstI<-{uint}> {{
+        type Output = N{uint};
+    }}
+\
+            ",
+            uint = uint,
+            cfg = feature_gate_to_pointer_width_signed(uint),
+        ));
+    }
+
     result
 }
 
@@ -86,3 +230,13 @@ const fn feature_gate_to_pointer_width(uint: u64) -> &'static str {
         ""
     }
 }
+
+const fn feature_gate_to_pointer_width_signed(magnitude: u64) -> &'static str {
+    if magnitude > i32::MAX as u64 {
+        r#"#[cfg(target_pointer_width = "64")]"#
+    } else if magnitude > i16::MAX as u64 {
+        r#"#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]"#
+    } else {
+        ""
+    }
+}
@@ -1,6 +1,7 @@
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum OpType {
     Operator,
+    Prefix,
     Function,
 }
 
@@ -10,225 +11,987 @@ struct Op {
     token: &'static str,
     operator: &'static str,
     example: (&'static str, &'static str),
+    /// Items the example needs, written before it.
+    setup: &'static str,
     precedence: u8,
     n_args: u8,
+    /// Whether the two arguments are passed to the alias in the opposite order, for functions
+    /// written with `Self` last whose aliases take it first.
+    swap_args: bool,
     op_type: OpType,
 }
 
+impl Op {
+    /// The identifier that stands for the operator on the stack of `__op_internal__`. This is the
+    /// operator's alias, except for `&&` and `||`, which expand to the aliases of `&` and `|` but
+    /// need keys of their own for their lower precedence.
+    fn key(&self) -> &'static str {
+        match self.token {
+            "&&" => "LogicalAnd",
+            "||" => "LogicalOr",
+            _ => self.operator,
+        }
+    }
+}
+
 pub fn gen_op_macro() -> String {
     // Operator precedence is taken from
     // https://doc.rust-lang.org/reference.html#operator-precedence
     //
-    // We choose 16 as the highest precedence (functions are set to 255 but it doesn't matter
-    // for them).  We also only use operators that are left associative so we don't have to worry
-    // about that.
+    // We choose 16 as the highest precedence for binary operators and 17 for prefix operators
+    // (functions are set to 255 but it doesn't matter for them).  We also only use binary operators
+    // that are left associative so we don't have to worry about that.
     let ops = &[
+        Op {
+            token: "!",
+            operator: "Complement",
+            example: ("!True", "False"),
+            setup: "",
+            precedence: 17,
+            n_args: 1,
+            swap_args: false,
+            op_type: Prefix,
+        },
+        Op {
+            token: "-",
+            operator: "Negate",
+            example: ("-P3", "N3"),
+            setup: "",
+            precedence: 17,
+            n_args: 1,
+            swap_args: false,
+            op_type: Prefix,
+        },
         Op {
             token: "*",
             operator: "Prod",
             example: ("P2 * P3", "P6"),
+            setup: "",
             precedence: 16,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "/",
             operator: "Quot",
             example: ("P6 / P2", "P3"),
+            setup: "",
             precedence: 16,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "%",
             operator: "Mod",
             example: ("P5 % P3", "P2"),
+            setup: "",
             precedence: 16,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "+",
             operator: "Sum",
             example: ("P2 + P3", "P5"),
+            setup: "",
             precedence: 15,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "-",
             operator: "Diff",
             example: ("P2 - P3", "N1"),
+            setup: "",
             precedence: 15,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "<<",
             operator: "Shleft",
             example: ("U1 << U5", "U32"),
+            setup: "",
             precedence: 14,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: ">>",
             operator: "Shright",
             example: ("U32 >> U5", "U1"),
+            setup: "",
             precedence: 14,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "&",
             operator: "And",
             example: ("U5 & U3", "U1"),
+            setup: "",
             precedence: 13,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "^",
             operator: "Xor",
             example: ("U5 ^ U3", "U6"),
+            setup: "",
             precedence: 12,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "|",
             operator: "Or",
             example: ("U5 | U3", "U7"),
+            setup: "",
             precedence: 11,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "==",
             operator: "Eq",
             example: ("P5 == P3 + P2", "True"),
+            setup: "",
             precedence: 10,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "!=",
             operator: "NotEq",
             example: ("P5 != P3 + P2", "False"),
+            setup: "",
             precedence: 10,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "<=",
             operator: "LeEq",
             example: ("P6 <= P3 + P2", "False"),
+            setup: "",
             precedence: 10,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: ">=",
             operator: "GrEq",
             example: ("P6 >= P3 + P2", "True"),
+            setup: "",
             precedence: 10,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "<",
             operator: "Le",
             example: ("P4 < P3 + P2", "True"),
+            setup: "",
             precedence: 10,
             n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: ">",
             operator: "Gr",
             example: ("P5 < P3 + P2", "False"),
+            setup: "",
             precedence: 10,
             n_args: 2,
+            swap_args: false,
+            op_type: Operator,
+        },
+        Op {
+            token: "&&",
+            operator: "And",
+            example: ("P1 < P2 && P3 < P2", "False"),
+            setup: "",
+            precedence: 9,
+            n_args: 2,
+            swap_args: false,
+            op_type: Operator,
+        },
+        Op {
+            token: "||",
+            operator: "Or",
+            example: ("P1 < P2 || P3 < P2", "True"),
+            setup: "",
+            precedence: 8,
+            n_args: 2,
+            swap_args: false,
             op_type: Operator,
         },
         Op {
             token: "cmp",
             operator: "Compare",
             example: ("cmp(P2, P3)", "Less"),
+            setup: "",
             precedence: !0,
             n_args: 2,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "sqr",
             operator: "Square",
             example: ("sqr(P2)", "P4"),
+            setup: "",
             precedence: !0,
             n_args: 1,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "sqrt",
             operator: "Sqrt",
             example: ("sqrt(U9)", "U3"),
+            setup: "",
             precedence: !0,
             n_args: 1,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "abs",
             operator: "AbsVal",
             example: ("abs(N2)", "P2"),
+            setup: "",
             precedence: !0,
             n_args: 1,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "cube",
             operator: "Cube",
             example: ("cube(P2)", "P8"),
+            setup: "",
             precedence: !0,
             n_args: 1,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "pow",
             operator: "Exp",
             example: ("pow(P2, P3)", "P8"),
+            setup: "",
             precedence: !0,
             n_args: 2,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "min",
             operator: "Minimum",
             example: ("min(P2, P3)", "P2"),
+            setup: "",
             precedence: !0,
             n_args: 2,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "max",
             operator: "Maximum",
             example: ("max(P2, P3)", "P3"),
+            setup: "",
             precedence: !0,
             n_args: 2,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "log2",
             operator: "Log2",
             example: ("log2(U9)", "U3"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "log",
+            operator: "Log",
+            example: ("log(U10, U1000)", "U3"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: true,
+            op_type: Function,
+        },
+        Op {
+            token: "log10",
+            operator: "Log10",
+            example: ("log10(U999)", "U2"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "ceil_log2",
+            operator: "CeilLog2",
+            example: ("ceil_log2(U5)", "U3"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "root",
+            operator: "Root",
+            example: ("root(U3, U64)", "U4"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: true,
+            op_type: Function,
+        },
+        Op {
+            token: "cbrt",
+            operator: "Cbrt",
+            example: ("cbrt(U26)", "U2"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "is_power_of_two",
+            operator: "IsPow2",
+            example: ("is_power_of_two(U64)", "B1"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "next_power_of_two",
+            operator: "NextPow2",
+            example: ("next_power_of_two(U1000)", "U1024"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "prev_power_of_two",
+            operator: "PrevPow2",
+            example: ("prev_power_of_two(U1000)", "U512"),
+            setup: "",
             precedence: !0,
             n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "count_ones",
+            operator: "Ones",
+            example: ("count_ones(U255)", "U8"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "count_zeros",
+            operator: "Zeros",
+            example: ("count_zeros(U1, U8)", "U7"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "trailing_zeros",
+            operator: "TrailZeros",
+            example: ("trailing_zeros(U24)", "U3"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "leading_zeros",
+            operator: "LeadZeros",
+            example: ("leading_zeros(U1, U32)", "U31"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "reverse_bits",
+            operator: "BitsReversed",
+            example: ("reverse_bits(U1, U8)", "U128"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
             op_type: Function,
         },
         Op {
             token: "gcd",
             operator: "Gcf",
             example: ("gcd(U9, U21)", "U3"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "add_mod",
+            operator: "SumMod",
+            example: ("add_mod(U5, U4, U7)", "U2"),
+            setup: "",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "mul_mod",
+            operator: "ProdMod",
+            example: ("mul_mod(U5, U4, U7)", "U6"),
+            setup: "",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "pow_mod",
+            operator: "ExpMod",
+            example: ("pow_mod(U2, U10, U1000)", "U24"),
+            setup: "",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "mod_inverse",
+            operator: "InvMod",
+            example: ("mod_inverse(U3, U7)", "U5"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "is_prime",
+            operator: "Primality",
+            example: ("is_prime(U7)", "B1"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "smallest_factor",
+            operator: "LeastFactor",
+            example: ("smallest_factor(U91)", "U7"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "factorize",
+            operator: "Factors",
+            example: ("factorize(U12)", "tarr![U2, U2, U3]"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "lcm",
+            operator: "Scm",
+            example: ("lcm(U4, U6)", "U12"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "is_coprime",
+            operator: "Coprimality",
+            example: ("is_coprime(U35, U64)", "B1"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "div_floor",
+            operator: "QuotFloor",
+            example: ("div_floor(N7, P2)", "N4"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "div_ceil",
+            operator: "QuotCeil",
+            example: ("div_ceil(U40, U16)", "U3"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "div_euclid",
+            operator: "QuotEuclid",
+            example: ("div_euclid(N7, P2)", "N4"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "rem_euclid",
+            operator: "ModEuclid",
+            example: ("rem_euclid(N7, P2)", "P1"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "if",
+            operator: "If",
+            example: ("if(P2 < P3, U1, U2)", "U1"),
+            setup: "",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "add1",
+            operator: "Add1",
+            example: ("add1(U4)", "U5"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "sub1",
+            operator: "Sub1",
+            example: ("sub1(U4)", "U3"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "double",
+            operator: "Double",
+            example: ("double(U4)", "U8"),
+            setup: "",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "partial_div",
+            operator: "PartialQuot",
+            example: ("partial_div(U6, U2)", "U3"),
+            setup: "",
             precedence: !0,
             n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "get_bit",
+            operator: "GetBitOut",
+            example: ("get_bit(U5, U2)", "True"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "set_bit",
+            operator: "SetBitOut",
+            example: ("set_bit(U5, U1, True)", "U7"),
+            setup: "",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "checked_add",
+            operator: "CheckedSum",
+            example: ("checked_add(U200, U55, U8)", "U255"),
+            setup: "",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "wrapping_add",
+            operator: "WrappingSum",
+            example: ("wrapping_add(U200, U57, U8)", "U1"),
+            setup: "",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "saturating_sub",
+            operator: "SaturatingDiff",
+            example: ("saturating_sub(U3, U5)", "U0"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "implies",
+            operator: "Imply",
+            example: ("implies(True, False)", "False"),
+            setup: "",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "len",
+            operator: "Length",
+            example: ("len(A)", "U3"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "fold_add",
+            operator: "FoldSum",
+            example: ("fold_add(A)", "P2"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "fold_mul",
+            operator: "FoldProd",
+            example: ("fold_mul(A)", "N6"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "fold_max",
+            operator: "FoldMaximum",
+            example: ("fold_max(A)", "P3"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "fold_min",
+            operator: "FoldMinimum",
+            example: ("fold_min(A)", "N2"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "all",
+            operator: "FoldAll",
+            example: ("all(A)", "False"),
+            setup: "type A = tarr![True, False, True];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "any",
+            operator: "FoldAny",
+            example: ("any(A)", "True"),
+            setup: "type A = tarr![True, False, True];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "at",
+            operator: "At",
+            example: ("at(A, U1)", "N2"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "push",
+            operator: "Pushed",
+            example: ("push(A, P4)", "tarr![P1, N2, P3, P4]"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "pop",
+            operator: "Popped",
+            example: ("pop(A)", "tarr![P1, N2]"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "last",
+            operator: "Last",
+            example: ("last(A)", "P3"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "concat",
+            operator: "Concatenated",
+            example: ("concat(A, B)", "tarr![P1, N2, P3, P4]"),
+            setup: "type A = tarr![P1, N2];
+type B = tarr![P3, P4];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "reverse",
+            operator: "Reversed",
+            example: ("reverse(A)", "tarr![P3, N2, P1]"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "take",
+            operator: "Taken",
+            example: ("take(A, U2)", "tarr![P1, N2]"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "skip",
+            operator: "Skipped",
+            example: ("skip(A, U2)", "tarr![P3]"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "contains",
+            operator: "Contained",
+            example: ("contains(A, N2)", "True"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "dot",
+            operator: "Dot",
+            example: ("dot(A, B)", "N5"),
+            setup: "type A = tarr![P1, N2];
+type B = tarr![P3, P4];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "sort",
+            operator: "Sorted",
+            example: ("sort(A)", "tarr![N2, P1, P3]"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "sort_desc",
+            operator: "SortedDesc",
+            example: ("sort_desc(A)", "tarr![P3, P1, N2]"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "dedup",
+            operator: "Deduped",
+            example: ("dedup(A)", "tarr![P1, P3]"),
+            setup: "type A = tarr![P1, P1, P3, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "is_sorted",
+            operator: "InOrder",
+            example: ("is_sorted(A)", "False"),
+            setup: "type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 1,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "apply",
+            operator: "Apply",
+            example: ("apply(Twice, P3)", "P6"),
+            setup: "struct Twice;
+impl<X: core::ops::Mul<P2>> TypeFn<X> for Twice {
+    type Output = Prod<X, P2>;
+}",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "map",
+            operator: "Mapped",
+            example: ("map(A, Twice)", "tarr![P2, N4, P6]"),
+            setup: "struct Twice;
+impl<X: core::ops::Mul<P2>> TypeFn<X> for Twice {
+    type Output = Prod<X, P2>;
+}
+type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "zip_with",
+            operator: "ZippedWith",
+            example: ("zip_with(A, Minus, B)", "tarr![P3, N1]"),
+            setup: "struct Minus;
+impl<L: core::ops::Sub<R>, R> TypeFn<(L, R)> for Minus {
+    type Output = Diff<L, R>;
+}
+type A = tarr![P5, P2];
+type B = tarr![P2, P3];",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "filter",
+            operator: "Filtered",
+            example: ("filter(A, Positive)", "tarr![P1, P3]"),
+            setup: "struct Positive;
+impl<X: IsGreater<Z0>> TypeFn<X> for Positive {
+    type Output = Gr<X, Z0>;
+}
+type A = tarr![P1, N2, Z0, P3];",
+            precedence: !0,
+            n_args: 2,
+            swap_args: false,
+            op_type: Function,
+        },
+        Op {
+            token: "fold",
+            operator: "Folded",
+            example: ("fold(A, Minus, Z0)", "N2"),
+            setup: "struct Minus;
+impl<L: core::ops::Sub<R>, R> TypeFn<(L, R)> for Minus {
+    type Output = Diff<L, R>;
+}
+type A = tarr![P1, N2, P3];",
+            precedence: !0,
+            n_args: 3,
+            swap_args: false,
             op_type: Function,
         },
     ];
@@ -239,10 +1002,16 @@ pub fn gen_op_macro() -> String {
 /**
 Convenient type operations.
 
-Any types representing values must be able to be expressed as `ident`s. That means they need to be
-in scope.
+Operands may be any type path, such as `P5`, `typenum::P5`, `T::Output`, or
+`<A as Trait>::Output`, or a macro invocation such as `tarr![P1, P2]`. As in Rust expressions,
+generic arguments need a turbofish, so write `Sum::<A, B>` rather than `Sum<A, B>`, where the `<`
+would be a comparison. Any other type can be written in braces, such as `{{ Sum<A, B> }}`.
 
-For example, `P5` is okay, but `typenum::P5` is not.
+Integer literals are operands as well, and expand to the constant with that value. A literal
+without a sign is unsigned, and one with a sign is signed, so `3` is `U3`, `+3` is `P3`, and `-3` is
+`N3`. With the `macros` feature, literals are read by `tn!`, so they may be of any size and in any
+base, such as `0x10` or `1_000`. Without it, they must be written in decimal, without a suffix,
+and have a constant in `consts`.
 
 You may combine operators arbitrarily, although doing so excessively may require raising the
 recursion limit.
@@ -257,10 +1026,45 @@ fn main() {{
     assert_type!(
         op!(min((P1 - P2) * (N3 + N7), P5 * (P3 + P4)) == P10)
     );
+    assert_type!(
+        op!(-P2 * P3 == N6 && !(P2 > P3) || get_bit(U4, U0))
+    );
 }}
 ```
+
+Operands can be paths, literals, and types in braces:
+```rust
+use core::ops::Add;
+use typenum::{{assert_type_eq, op, tarr, Sum, Unsigned, P1, P2, U1, U3, U8}};
+
+trait Shape {{
+    type Sides: Unsigned;
+}}
+
+struct Square;
+
+impl Shape for Square {{
+    type Sides = typenum::U4;
+}}
+
+assert_type_eq!(op!(<Square as Shape>::Sides * 2), U8);
+assert_type_eq!(op!(typenum::consts::U2 + len(tarr![P1, P2])), typenum::U4);
+assert_type_eq!(op!(-3 + +4), P1);
+assert_type_eq!(op!(Sum::<U1, U1> == {{ Sum<U1, U1> }}), typenum::True);
+
+fn incremented<T: Add<U1>>() -> usize
+where
+    op!(T + 1): Unsigned,
+{{
+    <op!(T::Output)>::USIZE
+}}
+
+assert_eq!(incremented::<U3>(), 4);
+```
 Operators are evaluated based on the operator precedence outlined
-[here](https://doc.rust-lang.org/reference.html#operator-precedence).
+[here](https://doc.rust-lang.org/reference.html#operator-precedence). As in Rust, `-` and `!`
+before an operand are the prefix operators `Neg` and `Not`, and `&&` and `||` bind less tightly
+than comparisons.
 
 The full list of supported operators and functions is as follows:
 
@@ -271,7 +1075,10 @@ including examples:
 
 ",
         ops.iter()
-            .map(|op| format!("`{}`", op.token))
+            .map(|op| match op.op_type {
+                Prefix => format!("prefix `{}`", op.token),
+                _ => format!("`{}`", op.token),
+            })
             .collect::<Vec<_>>()
             .join(", ")
     ));
@@ -279,17 +1086,28 @@ including examples:
     //write!(f, "Token | Alias | Example\n ===|===|===\n")?;
 
     for op in ops.iter() {
+        let setup = if op.setup.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", op.setup)
+        };
+        let kind = match op.op_type {
+            Prefix => "Prefix operator",
+            _ => "Operator",
+        };
         result.push_str(&format!(
-            "---\nOperator `{token}`. Expands to `{operator}`.
+            "---\n{kind} `{token}`. Expands to `{operator}`.
 
 ```rust
 # #[macro_use] extern crate typenum;
 # use typenum::*;
 # fn main() {{
-assert_type_eq!(op!({ex0}), {ex1});
+{setup}assert_type_eq!(op!({ex0}), {ex1});
 # }}
 ```\n
 ",
+            kind = kind,
+            setup = setup,
             token = op.token,
             operator = op.operator,
             ex0 = op.example.0,
@@ -297,18 +1115,18 @@ assert_type_eq!(op!({ex0}), {ex1});
         ));
     }
 
-    result.push_str(&format!(
+    result.push_str(
         "*/
 #[macro_export(local_inner_macros)]
-macro_rules! op {{
+macro_rules! op {
     ($($tail:tt)*) => ( __op_internal__!($($tail)*) );
-}}
+}
 
     #[doc(hidden)]
     #[macro_export(local_inner_macros)]
-    macro_rules! __op_internal__ {{
-"
-    ));
+    macro_rules! __op_internal__ {
+",
+    );
 
     // We first us the shunting-yard algorithm to produce our tokens in Polish notation.
     // See: https://en.wikipedia.org/wiki/Shunting-yard_algorithm
@@ -319,12 +1137,120 @@ macro_rules! op {{
     // -----------------------------------------------------------------------------------------
     // Stage 1: There are tokens to be read:
 
+    // -------
+    // Case 0: An operand is expected, i.e. we are at the start of the input or just after an
+    // operator, "(", or ",". Operands are pushed onto the queue wrapped in braces, so that they
+    // are single token trees and can't be mistaken for operators.
+
+    // A "+" followed by a literal makes it signed:
+    result.push_str(
+        "
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $literal:tt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*]
+                     @queue[{ $crate::__op_operand__!(+ $literal) }, $($queue,)*]
+                     @tail: $($tail)*)
+);",
+    );
+    // "-" and "!" are prefix operators, which bind tighter than every binary operator, so they
+    // don't pop anything:
+    for prefix in ops.iter().filter(|op| op.op_type == Prefix) {
+        result.push_str(&format!(
+            "
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: {p_token} $($tail:tt)*) => (
+    __op_internal__!(@prefix[{p_op} {p_token}] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);",
+            p_token = prefix.token,
+            p_op = prefix.operator
+        ));
+    }
+    // Functions and parentheses are read as usual:
+    for fun in ops.iter().filter(|f| f.op_type == Function) {
+        result.push_str(&format!(
+            "
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: {f_token} $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: {f_token} $($tail)*)
+);",
+            f_token = fun.token
+        ));
+    }
+    result.push_str(
+        "
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ( $($stuff:tt)* ) $($tail:tt)*)
+ => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: ( $($stuff)* ) $($tail)*)
+);",
+    );
+    // A type in braces is taken as it is:
+    result.push_str(
+        "
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: { $($operand:tt)* } $($tail:tt)*)
+ => (
+    __op_internal__!(@stack[$($stack,)*] @queue[{ $($operand)* }, $($queue,)*] @tail: $($tail)*)
+);",
+    );
+    // The start of a path, see Case 6:
+    result.push_str(
+        "
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: :: $($tail:tt)*) => (
+    __op_internal__!(@segment[::] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
+    __op_internal__!(@angle[<] @path[<] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $segment:ident $($tail:tt)*) => (
+    __op_internal__!(@path[$segment] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);",
+    );
+    // Otherwise, it must be a literal, or a type fragment passed on by another macro:
+    result.push_str(
+        "
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $literal:tt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[{ $crate::__op_operand__!($literal) }, $($queue,)*]
+                     @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: )
+);",
+    );
+
+    // After a prefix operator, push it onto the stack and expect another operand, unless it is
+    // followed by a literal, which it is the sign of:
+    for (pattern, tokens) in &[
+        ("$x:ident", "$x"),
+        ("::", "::"),
+        ("<", "<"),
+        ("-", "-"),
+        ("!", "!"),
+        ("( $($x:tt)* )", "( $($x)* )"),
+        ("{ $($x:tt)* }", "{ $($x)* }"),
+    ] {
+        result.push_str(&format!(
+            "
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: {pattern} $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[$op, $($stack,)*] @queue[$($queue,)*] @tail: {tokens} $($tail)*)
+);",
+            pattern = pattern,
+            tokens = tokens
+        ));
+    }
+    result.push_str(
+        "
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $literal:tt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*]
+                     @queue[{ $crate::__op_operand__!($sign $literal) }, $($queue,)*]
+                     @tail: $($tail)*)
+);",
+    );
+
     // -------
     // Case 1: Token is a function => Push it onto the stack:
     for fun in ops.iter().filter(|f| f.op_type == Function) {
         result.push_str(&format!(
             "
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: {f_token} $($tail:tt)*) => (
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: {f_token} $($tail:tt)*) => (
     __op_internal__!(@stack[{f_op}, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );",
             f_token = fun.token,
@@ -339,14 +1265,14 @@ macro_rules! op {{
     // Base case: Top of stack is LParen, ditch comma and continue
     result.push_str(
         "
-(@stack[LParen, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: , $($tail:tt)*) => (
-    __op_internal__!(@stack[LParen, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[LParen, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: , $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[LParen, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );",
     );
     // Recursive case: Not LParen, pop from stack to queue
     result.push_str(
         "
-(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: , $($tail:tt)*) => (
+(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: , $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[$stack_top, $($queue,)*] @tail: , $($tail)*)
 );",
     );
@@ -358,25 +1284,25 @@ macro_rules! op {{
         // Then pop o2 off stack onto queue:
         for o2 in ops
             .iter()
-            .filter(|op| op.op_type == Operator)
+            .filter(|op| op.op_type != Function)
             .filter(|o2| o1.precedence <= o2.precedence)
         {
             result.push_str(&format!(
                 "
-(@stack[{o2_op}, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: {o1_token} $($tail:tt)*) => (
+(@stack[{o2_op}, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: {o1_token} $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[{o2_op}, $($queue,)*] @tail: {o1_token} $($tail)*)
 );",
-                o2_op = o2.operator,
+                o2_op = o2.key(),
                 o1_token = o1.token
             ));
         }
         // Base case: push o1 onto stack
         result.push_str(&format!(
             "
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: {o1_token} $($tail:tt)*) => (
-    __op_internal__!(@stack[{o1_op}, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: {o1_token} $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[{o1_op}, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );",
-            o1_op = o1.operator,
+            o1_op = o1.key(),
             o1_token = o1.token
         ));
     }
@@ -386,9 +1312,9 @@ macro_rules! op {{
     // appease the macro gods:
     result.push_str(
         "
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ( $($stuff:tt)* ) $($tail:tt)* )
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ( $($stuff:tt)* ) $($tail:tt)* )
  => (
-    __op_internal__!(@stack[LParen, $($stack,)*] @queue[$($queue,)*]
+    __op_internal__!(@operand @stack[LParen, $($stack,)*] @queue[$($queue,)*]
                      @tail: $($stuff)* RParen $($tail)*)
 );",
     );
@@ -401,14 +1327,14 @@ macro_rules! op {{
     // 2. Base case:
     result.push_str(
         "
-(@stack[LParen, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: RParen $($tail:tt)*) => (
+(@stack[LParen, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: RParen $($tail:tt)*) => (
     __op_internal__!(@rp3 @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );",
     );
     // 1. Recursive case:
     result.push_str(
         "
-(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: RParen $($tail:tt)*)
+(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: RParen $($tail:tt)*)
  => (
     __op_internal__!(@stack[$($stack,)*] @queue[$stack_top, $($queue,)*] @tail: RParen $($tail)*)
 );",
@@ -417,7 +1343,7 @@ macro_rules! op {{
     for fun in ops.iter().filter(|f| f.op_type == Function) {
         result.push_str(&format!(
             "
-(@rp3 @stack[{fun_op}, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[{fun_op}, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[{fun_op}, $($queue,)*] @tail: $($tail)*)
 );",
             fun_op = fun.operator
@@ -426,17 +1352,70 @@ macro_rules! op {{
     // 3. If no function found:
     result.push_str(
         "
-(@rp3 @stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );",
     );
 
     // -------
-    // Case 6: Token is a number: Push it onto the queue
+    // Case 6: Token is part of a path: Collect it, then push the path onto the queue.
+    // "@segment" expects an identifier or a generic argument list, "@angle" collects everything up
+    // to the matching ">", and "@path" is after a complete segment, where "::" continues the path
+    // and "!" makes it a macro call.
     result.push_str(
         "
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $num:ident $($tail:tt)*) => (
-    __op_internal__!(@stack[$($stack,)*] @queue[$num, $($queue,)*] @tail: $($tail)*)
+(@segment[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: < $($tail:tt)*) => (
+    __op_internal__!(@angle[<] @path[$($path)* <] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);
+(@segment[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $segment:ident $($tail:tt)*) => (
+    __op_internal__!(@path[$($path)* $segment] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);
+(@angle[] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $($tail:tt)*) => (
+    __op_internal__!(@path[$($path)*] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@angle[< $($depth:tt)*] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: > $($tail:tt)*) => (
+    __op_internal__!(@angle[$($depth)*] @path[$($path)* >] @stack[$($stack,)*]
+                     @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@angle[< < $($depth:tt)*] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: >> $($tail:tt)*) => (
+    __op_internal__!(@angle[$($depth)*] @path[$($path)* >>] @stack[$($stack,)*]
+                     @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@angle[<] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: >> $($tail:tt)*) => (
+    __op_internal__!(@angle[] @path[$($path)* >] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: > $($tail)*)
+);
+(@angle[$($depth:tt)*] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: < $($tail:tt)*) => (
+    __op_internal__!(@angle[< $($depth)*] @path[$($path)* <] @stack[$($stack,)*]
+                     @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@angle[$($depth:tt)*] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $token:tt $($tail:tt)*) => (
+    __op_internal__!(@angle[$($depth)*] @path[$($path)* $token] @stack[$($stack,)*]
+                     @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: :: $($tail:tt)*) => (
+    __op_internal__!(@segment[$($path)* ::] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);
+(@path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: ! $args:tt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[{ $($path)* ! $args }, $($queue,)*]
+                     @tail: $($tail)*)
+);
+(@path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[{ $($path)* }, $($queue,)*] @tail: $($tail)*)
 );",
     );
 
@@ -445,14 +1424,14 @@ macro_rules! op {{
     // Base case: Stack empty: Start evaluating
     result.push_str(
         "
-(@stack[] @queue[$($queue:ident,)*] @tail: ) => (
+(@stack[] @queue[$($queue:tt,)*] @tail: ) => (
     __op_internal__!(@reverse[] @input: $($queue,)*)
 );",
     );
     // Recursive case: Pop stack to queue
     result.push_str(
         "
-(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail:) => (
+(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail:) => (
     __op_internal__!(@stack[$($stack,)*] @queue[$stack_top, $($queue,)*] @tail: )
 );",
     );
@@ -461,13 +1440,13 @@ macro_rules! op {{
     // Stage 2: Reverse so we have RPN
     result.push_str(
         "
-(@reverse[$($revved:ident,)*] @input: $head:ident, $($tail:ident,)* ) => (
+(@reverse[$($revved:tt,)*] @input: $head:tt, $($tail:tt,)* ) => (
     __op_internal__!(@reverse[$head, $($revved,)*] @input: $($tail,)*)
 );",
     );
     result.push_str(
         "
-(@reverse[$($revved:ident,)*] @input: ) => (
+(@reverse[$($revved:tt,)*] @input: ) => (
     __op_internal__!(@eval @stack[] @input[$($revved,)*])
 );",
     );
@@ -477,10 +1456,23 @@ macro_rules! op {{
     // Operators / Operators with 2 args:
     for op in ops.iter().filter(|op| op.n_args == 2) {
         // Note: We have to switch $a and $b here, otherwise non-commutative functions are backwards
+        let args = if op.swap_args { "$a, $b" } else { "$b, $a" };
+        result.push_str(&format!(
+            "
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[{key}, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::{op}<{args}>, $($stack,)*] @input[$($tail,)*])
+);",
+            key = op.key(),
+            op = op.operator,
+            args = args
+        ));
+    }
+    // Functions with 3 args:
+    for op in ops.iter().filter(|op| op.n_args == 3) {
         result.push_str(&format!(
             "
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[{op}, $($tail:ident,)*]) => (
-    __op_internal__!(@eval @stack[$crate::{op}<$b, $a>, $($stack,)*] @input[$($tail,)*])
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[{op}, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::{op}<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
 );",
             op = op.operator
         ));
@@ -489,7 +1481,7 @@ macro_rules! op {{
     for op in ops.iter().filter(|op| op.n_args == 1) {
         result.push_str(&format!(
             "
-(@eval @stack[$a:ty, $($stack:ty,)*] @input[{op}, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[{op}, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::{op}<$a>, $($stack,)*] @input[$($tail,)*])
 );",
             op = op.operator
@@ -499,8 +1491,8 @@ macro_rules! op {{
     // Wasn't a function or operator, so must be a value => push onto stack
     result.push_str(
         "
-(@eval @stack[$($stack:ty,)*] @input[$head:ident, $($tail:ident,)*]) => (
-    __op_internal__!(@eval @stack[$head, $($stack,)*] @input[$($tail,)*])
+(@eval @stack[$($stack:ty,)*] @input[{ $($head:tt)* }, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$($head)*, $($stack,)*] @input[$($tail,)*])
 );",
     );
 
@@ -517,7 +1509,7 @@ macro_rules! op {{
     result.push_str(
         "
 ($($tail:tt)* ) => (
-    __op_internal__!(@stack[] @queue[] @tail: $($tail)*)
+    __op_internal__!(@operand @stack[] @queue[] @tail: $($tail)*)
 );",
     );
 
@@ -526,5 +1518,54 @@ macro_rules! op {{
 }",
     );
 
+    // The constant for an integer literal, which may have a sign, or a type fragment as it is.
+    // With the `macros` feature, `tn!` reads the literal; otherwise, it must have a constant.
+    result.push_str(
+        "
+
+#[cfg(feature = \"macros\")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __op_operand__ {
+(+ $literal:tt) => ($crate::__tn!($crate; + $literal));
+(- $literal:tt) => ($crate::__tn!($crate; - $literal));
+($literal:literal) => ($crate::__tn!($crate; $literal));
+($operand:ty) => ($operand);
+($($literal:tt)*) => (
+    compile_error!(concat!(\"`op!` has no constant for `\", stringify!($($literal)*), \"`\"))
+);
+}
+
+#[cfg(not(feature = \"macros\"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __op_operand__ {
+(+ 0) => ($crate::consts::Z0);
+(- 0) => ($crate::consts::Z0);",
+    );
+    for u in super::uints() {
+        result.push_str(&format!(
+            "
+({u}) => ($crate::consts::U{u});",
+            u = u
+        ));
+        if u <= i64::MAX as u64 && u != 0 {
+            result.push_str(&format!(
+                "
+(+ {u}) => ($crate::consts::P{u});
+(- {u}) => ($crate::consts::N{u});",
+                u = u
+            ));
+        }
+    }
+    result.push_str(
+        "
+($operand:ty) => ($operand);
+($($literal:tt)*) => (
+    compile_error!(concat!(\"`op!` has no constant for `\", stringify!($($literal)*), \"`\"))
+);
+}",
+    );
+
     result
 }
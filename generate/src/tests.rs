@@ -23,7 +23,7 @@ fn gen_int(i: i64) -> IntCode {
 
     match i.cmp(&0) {
         Greater => IntCode::Pos(Box::new(gen_uint(i as u64))),
-        Less => IntCode::Neg(Box::new(gen_uint(i.abs() as u64))),
+        Less => IntCode::Neg(Box::new(gen_uint(i.unsigned_abs()))),
         Equal => IntCode::Zero,
     }
 }
@@ -52,6 +52,30 @@ fn gcdu(mut a: u64, mut b: u64) -> u64 {
     b
 }
 
+/// Divides two integers, rounding towards negative infinity.
+fn div_floor(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+/// Computes the integer square root of a non-negative integer.
+fn isqrt(n: i64) -> i64 {
+    let mut r = 0;
+    while (r + 1) * (r + 1) <= n {
+        r += 1;
+    }
+    r
+}
+
+/// Computes the integer binary logarithm of a positive integer.
+fn ilog2(n: i64) -> i64 {
+    63 - i64::from(n.leading_zeros())
+}
+
 fn sign(i: i64) -> char {
     use std::cmp::Ordering::*;
     match i.cmp(&0) {
@@ -213,6 +237,33 @@ fn int_unary_test(operator: &'static str, num: i64, result: i64) -> IntUnaryTest
     }
 }
 
+fn int_shift_test(a: i64, op: &'static str, b: u64, r: i64) -> String {
+    format!(
+        "
+#[test]
+#[allow(non_snake_case)]
+fn test_{sa}{a}_{op}_{b}() {{
+    type A = {gen_a};
+    type B = {gen_b};
+    type {sr}{r} = {result};
+
+    #[allow(non_camel_case_types)]
+    type {sa}{a}{op}U{b} = <<A as {op}<B>>::Output as Same<{sr}{r}>>::Output;
+
+    assert_eq!(<{sa}{a}{op}U{b} as Integer>::to_i64(), <{sr}{r} as Integer>::to_i64());
+}}",
+        a = a.abs(),
+        b = b,
+        sa = sign(a),
+        sr = sign(r),
+        r = r.abs(),
+        op = op,
+        gen_a = gen_int(a),
+        gen_b = gen_uint(b),
+        result = gen_int(r)
+    )
+}
+
 fn uint_cmp_test(a: u64, b: u64) -> String {
     format!(
         "
@@ -286,6 +337,7 @@ use core::cmp::Ordering;
             uint_binary_test(a, "Min", b, cmp::min(a, b)),
             uint_binary_test(a, "Max", b, cmp::max(a, b)),
             uint_binary_test(a, "Gcd", b, gcdu(a, b)),
+            uint_binary_test(a, "SaturatingSub", b, a.saturating_sub(b)),
         ];
         if a >= b {
             tests.push(uint_binary_test(a, "Sub", b, a - b));
@@ -293,6 +345,10 @@ use core::cmp::Ordering;
         if b != 0 {
             tests.push(uint_binary_test(a, "Div", b, a / b));
             tests.push(uint_binary_test(a, "Rem", b, a % b));
+            tests.push(uint_binary_test(a, "DivFloor", b, a / b));
+            tests.push(uint_binary_test(a, "DivCeil", b, a.div_ceil(b)));
+            tests.push(uint_binary_test(a, "DivEuclid", b, a / b));
+            tests.push(uint_binary_test(a, "RemEuclid", b, a % b));
             if a % b == 0 {
                 tests.push(uint_binary_test(a, "PartialDiv", b, a / b));
             }
@@ -307,6 +363,9 @@ use core::cmp::Ordering;
     // int operators:
     for (a, b) in ints {
         let mut tests = vec![
+            int_binary_test(a, "BitAnd", b, a & b),
+            int_binary_test(a, "BitOr", b, a | b),
+            int_binary_test(a, "BitXor", b, a ^ b),
             int_binary_test(a, "Add", b, a + b),
             int_binary_test(a, "Sub", b, a - b),
             int_binary_test(a, "Mul", b, a * b),
@@ -317,6 +376,15 @@ use core::cmp::Ordering;
         if b != 0 {
             tests.push(int_binary_test(a, "Div", b, a / b));
             tests.push(int_binary_test(a, "Rem", b, a % b));
+            tests.push(int_binary_test(a, "DivFloor", b, div_floor(a, b)));
+            tests.push(int_binary_test(
+                a,
+                "DivCeil",
+                b,
+                div_floor(a, b) + (a % b != 0) as i64,
+            ));
+            tests.push(int_binary_test(a, "DivEuclid", b, a.div_euclid(b)));
+            tests.push(int_binary_test(a, "RemEuclid", b, a.rem_euclid(b)));
             if a % b == 0 {
                 tests.push(int_binary_test(a, "PartialDiv", b, a / b));
             }
@@ -339,14 +407,26 @@ use core::cmp::Ordering;
             result.push_str(&test.to_string());
         }
         result.push_str(&int_cmp_test(a, b));
+        if b >= 0 {
+            let b = b as u64;
+            result.push_str(&int_shift_test(a, "Shl", b, a << b));
+            result.push_str(&int_shift_test(a, "Shr", b, a >> b));
+        }
     }
 
     // int unary operators:
     for n in -high..high + 1 {
-        let tests = vec![
+        let mut tests = vec![
             int_unary_test("Neg", n, -n),
             int_unary_test("Abs", n, n.abs()),
+            int_unary_test("Not", n, !n),
         ];
+        if n >= 0 {
+            tests.push(int_unary_test("SquareRoot", n, isqrt(n)));
+        }
+        if n > 0 {
+            tests.push(int_unary_test("Logarithm2", n, ilog2(n)));
+        }
         for test in tests {
             result.push_str(&test.to_string());
         }
@@ -0,0 +1,311 @@
+//! Procedural macros for [typenum](https://docs.rs/typenum).
+//!
+//! The [`tn!`](macro@tn) macro writes the type of any integer literal, so numbers without a
+//! constant in `typenum::consts` need not be spelled out as `UInt<UInt<...>, ...>` by hand.
+//!
+//! The expansion refers to the types through `::typenum`, so the crate using the macro must
+//! depend on `typenum` as well. With the `macros` feature, `typenum` provides its own `tn!`,
+//! which refers to them through `$crate` and so works however `typenum` is named.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use std::iter::Peekable;
+
+type Tokens = Peekable<proc_macro::token_stream::IntoIter>;
+
+/// Expands to the typenum type of an integer literal, or of an array of them.
+///
+/// - A literal without a sign is an unsigned integer, so `tn!(42)` is `U42`.
+/// - A literal with a sign is a signed integer, so `tn!(+42)` is `P42`, `tn!(-42)` is `N42`, and
+///   `tn!(-0)` is `Z0`.
+/// - An array of literals is a `TArr`. If any element has a sign, every element is a signed
+///   integer, so `tn!([1, -2, 3])` is `tarr![P1, N2, P3]`; otherwise they are all unsigned.
+///
+/// Literals may be written in any base Rust supports and contain underscores, but must not
+/// have a suffix. There is no upper limit on their size, though the compiler's `recursion_limit`
+/// may need to be raised to do much with very large numbers.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Unsigned, N42, P1, P3, N2, P42, U1, U11, U2, U42, Z0};
+/// use typenum_macros::tn;
+///
+/// assert_type_eq!(tn!(42), U42);
+/// assert_type_eq!(tn!(+42), P42);
+/// assert_type_eq!(tn!(-42), N42);
+/// assert_type_eq!(tn!(-0), Z0);
+/// assert_type_eq!(tn!(0b1011), U11);
+/// assert_type_eq!(tn!([1, -2, 3]), tarr![P1, N2, P3]);
+/// assert_type_eq!(tn!([1, 2]), tarr![U1, U2]);
+///
+/// type Big = tn!(123_456_789_012_345_678_901_234_567_890);
+/// assert_eq!(Big::new().to_string(), "123456789012345678901234567890");
+/// ```
+///
+/// Anything other than an integer literal fails to compile:
+///
+/// ```rust,compile_fail
+/// use typenum_macros::tn;
+///
+/// type Byte = tn!(42u8);
+/// ```
+#[proc_macro]
+pub fn tn(input: TokenStream) -> TokenStream {
+    let krate = "::typenum"
+        .parse()
+        .expect("crate path should be valid tokens");
+    expand(krate, input)
+}
+
+/// Like [`tn!`](macro@tn), but takes the path to `typenum` first, followed by `;`.
+///
+/// This is what `typenum::tn!` expands to, passing `$crate` so that the generated types resolve
+/// even when `typenum` is renamed or only reachable through a re-export.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __tn(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let krate = tokens
+        .by_ref()
+        .take_while(|token| match *token {
+            TokenTree::Punct(ref punct) => punct.as_char() != ';',
+            _ => true,
+        })
+        .collect();
+    expand(krate, tokens.collect())
+}
+
+/// The placeholder for the path to `typenum` in the output of [`Value::to_type`].
+const KRATE: &str = "__typenum_crate";
+
+fn expand(krate: TokenStream, input: TokenStream) -> TokenStream {
+    match parse_all(input) {
+        Ok(value) => {
+            let output = value
+                .to_type(false)
+                .parse()
+                .expect("generated type should be valid tokens");
+            replace_krate(output, &krate)
+        }
+        Err(error) => error.to_compile_error(),
+    }
+}
+
+/// Replaces every [`KRATE`] in `tokens` by `krate`. The path is spliced in as tokens rather than
+/// text, as `$crate` cannot be written out and parsed back.
+fn replace_krate(tokens: TokenStream, krate: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ref ident) if ident.to_string() == KRATE => krate.clone(),
+            TokenTree::Group(ref group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_krate(group.stream(), krate));
+                replaced.set_span(group.span());
+                TokenStream::from(TokenTree::Group(replaced))
+            }
+            token => TokenStream::from(token),
+        })
+        .collect()
+}
+
+/// An error to report with `compile_error!`, at the given span.
+struct Error {
+    span: Span,
+    message: &'static str,
+}
+
+impl Error {
+    fn new(span: Span, message: &'static str) -> Self {
+        Error { span, message }
+    }
+
+    fn to_compile_error(&self) -> TokenStream {
+        let mut bang = Punct::new('!', Spacing::Alone);
+        bang.set_span(self.span);
+        let mut message = Literal::string(self.message);
+        message.set_span(self.span);
+        let mut group = Group::new(
+            Delimiter::Parenthesis,
+            TokenStream::from(TokenTree::Literal(message)),
+        );
+        group.set_span(self.span);
+
+        vec![
+            TokenTree::Ident(Ident::new("compile_error", self.span)),
+            TokenTree::Punct(bang),
+            TokenTree::Group(group),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Sign {
+    Plus,
+    Minus,
+}
+
+enum Value {
+    Integer {
+        sign: Option<Sign>,
+        /// The bits of the magnitude, most significant first, without leading zeros.
+        bits: Vec<bool>,
+    },
+    Array(Vec<Value>),
+}
+
+impl Value {
+    fn has_sign(&self) -> bool {
+        match *self {
+            Value::Integer { sign, .. } => sign.is_some(),
+            Value::Array(_) => false,
+        }
+    }
+
+    /// Writes the type of the value, with [`KRATE`] standing for the path to `typenum`. Integers
+    /// are signed if they have a sign or `signed` is set.
+    fn to_type(&self, signed: bool) -> String {
+        match *self {
+            Value::Integer { sign, ref bits } => {
+                let mut unsigned = format!("{}::UTerm", KRATE);
+                for &bit in bits {
+                    let bit = if bit { "B1" } else { "B0" };
+                    unsigned = format!("{0}::UInt<{1}, {0}::{2}>", KRATE, unsigned, bit);
+                }
+
+                if !signed && sign.is_none() {
+                    unsigned
+                } else if bits.is_empty() {
+                    format!("{}::Z0", KRATE)
+                } else if sign == Some(Sign::Minus) {
+                    format!("{}::NInt<{}>", KRATE, unsigned)
+                } else {
+                    format!("{}::PInt<{}>", KRATE, unsigned)
+                }
+            }
+            Value::Array(ref elements) => {
+                let signed = elements.iter().any(Value::has_sign);
+                elements
+                    .iter()
+                    .rev()
+                    .fold(format!("{}::ATerm", KRATE), |rest, element| {
+                        format!("{}::TArr<{}, {}>", KRATE, element.to_type(signed), rest)
+                    })
+            }
+        }
+    }
+}
+
+/// Parses a single value that makes up all of `input`.
+fn parse_all(input: TokenStream) -> Result<Value, Error> {
+    let mut tokens = input.into_iter().peekable();
+    let value = parse_value(&mut tokens)?;
+    match tokens.next() {
+        None => Ok(value),
+        Some(token) => Err(Error::new(token.span(), "unexpected token after the value")),
+    }
+}
+
+fn parse_value(tokens: &mut Tokens) -> Result<Value, Error> {
+    match tokens.next() {
+        None => Err(Error::new(
+            Span::call_site(),
+            "expected an integer literal or an array",
+        )),
+        Some(TokenTree::Punct(ref punct)) if punct.as_char() == '+' || punct.as_char() == '-' => {
+            let sign = if punct.as_char() == '+' {
+                Sign::Plus
+            } else {
+                Sign::Minus
+            };
+            match tokens.next() {
+                Some(TokenTree::Literal(ref literal)) => parse_literal(literal, Some(sign)),
+                Some(token) => Err(Error::new(token.span(), "expected an integer literal")),
+                None => Err(Error::new(punct.span(), "expected an integer literal")),
+            }
+        }
+        Some(TokenTree::Literal(ref literal)) => parse_literal(literal, None),
+        Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::Bracket => {
+            let mut elements = Vec::new();
+            let mut inner = group.stream().into_iter().peekable();
+            while inner.peek().is_some() {
+                elements.push(parse_value(&mut inner)?);
+                match inner.next() {
+                    None => break,
+                    Some(TokenTree::Punct(ref punct)) if punct.as_char() == ',' => {}
+                    Some(token) => return Err(Error::new(token.span(), "expected `,`")),
+                }
+            }
+            Ok(Value::Array(elements))
+        }
+        // Fragments passed through `macro_rules!` arrive in invisible groups.
+        Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::None => {
+            parse_all(group.stream())
+        }
+        Some(token) => Err(Error::new(
+            token.span(),
+            "expected an integer literal or an array",
+        )),
+    }
+}
+
+fn parse_literal(literal: &Literal, sign: Option<Sign>) -> Result<Value, Error> {
+    let invalid = || {
+        Error::new(
+            literal.span(),
+            "expected an integer literal without a suffix",
+        )
+    };
+
+    let text = literal.to_string();
+    // A negative literal can be a single token when it comes from another macro.
+    let (sign, text) = match (sign, text.starts_with('-')) {
+        (None, true) => (Some(Sign::Minus), &text[1..]),
+        (Some(_), true) => return Err(invalid()),
+        (sign, false) => (sign, &text[..]),
+    };
+    let (radix, digits) = if text.starts_with("0x") {
+        (16, &text[2..])
+    } else if text.starts_with("0o") {
+        (8, &text[2..])
+    } else if text.starts_with("0b") {
+        (2, &text[2..])
+    } else {
+        (10, text)
+    };
+
+    // The magnitude, in little-endian limbs.
+    let mut limbs: Vec<u32> = Vec::new();
+    let mut any_digits = false;
+    for c in digits.chars().filter(|&c| c != '_') {
+        let digit = c.to_digit(radix).ok_or_else(invalid)?;
+        any_digits = true;
+
+        let mut carry = u64::from(digit);
+        for limb in &mut limbs {
+            let value = u64::from(*limb) * u64::from(radix) + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+    }
+    if !any_digits {
+        return Err(invalid());
+    }
+
+    let bits = limbs
+        .iter()
+        .rev()
+        .flat_map(|&limb| (0..32).rev().map(move |i| limb >> i & 1 == 1))
+        .skip_while(|&bit| !bit)
+        .collect();
+    Ok(Value::Integer { sign, bits })
+}
@@ -0,0 +1,73 @@
+// Numbers of more than 128 bits need a higher limit.
+#![recursion_limit = "256"]
+
+use typenum::{
+    assert_type_eq, tarr, Integer, Prod, Sub1, N1, N128, P1, P2, P255, U0, U1, U1024, U2, U255, U3,
+    U4294967296, Z0,
+};
+use typenum_macros::tn;
+
+/// `2^64`
+type U18446744073709551616 = Prod<U4294967296, U4294967296>;
+
+#[test]
+fn unsigned() {
+    assert_type_eq!(tn!(0), U0);
+    assert_type_eq!(tn!(1), U1);
+    assert_type_eq!(tn!(1024), U1024);
+    assert_type_eq!(tn!(1_0_2_4), U1024);
+    assert_type_eq!(tn!(18446744073709551615), Sub1<U18446744073709551616>);
+}
+
+#[test]
+fn bases() {
+    assert_type_eq!(tn!(0xff), U255);
+    assert_type_eq!(tn!(0xFF), U255);
+    assert_type_eq!(tn!(0o377), U255);
+    assert_type_eq!(tn!(0b1111_1111), U255);
+    assert_type_eq!(tn!(0b0), U0);
+    assert_type_eq!(tn!(0x0000_0001), U1);
+}
+
+#[test]
+fn signed() {
+    assert_type_eq!(tn!(+0), Z0);
+    assert_type_eq!(tn!(-0), Z0);
+    assert_type_eq!(tn!(+1), P1);
+    assert_type_eq!(tn!(-1), N1);
+    assert_type_eq!(tn!(+0xff), P255);
+    assert_type_eq!(tn!(-128), N128);
+    assert_eq!(<tn!(-9223372036854775808)>::I64, i64::min_value());
+}
+
+#[test]
+fn wider_than_u64() {
+    assert_type_eq!(tn!(18446744073709551616), U18446744073709551616);
+    assert_type_eq!(tn!(0x1_0000_0000_0000_0000), U18446744073709551616);
+    assert_eq!(
+        <tn!(340282366920938463463374607431768211457)>::new().to_string(),
+        "340282366920938463463374607431768211457"
+    );
+}
+
+#[test]
+fn arrays() {
+    assert_type_eq!(tn!([]), tarr![]);
+    assert_type_eq!(tn!([1, 2, 3]), tarr![U1, U2, U3]);
+    assert_type_eq!(tn!([1, 2, 3,]), tarr![U1, U2, U3]);
+    assert_type_eq!(tn!([1, -1, 0]), tarr![P1, N1, Z0]);
+    assert_type_eq!(tn!([+2, 0]), tarr![P2, Z0]);
+    assert_type_eq!(tn!([[1], [-1]]), tarr![tarr![U1], tarr![N1]]);
+}
+
+macro_rules! through_macro_rules {
+    ($value:expr) => {
+        tn!($value)
+    };
+}
+
+#[test]
+fn fragments() {
+    assert_type_eq!(through_macro_rules!(255), U255);
+    assert_type_eq!(through_macro_rules!(-128), N128);
+}
@@ -2,12 +2,16 @@
 //!
 //! It is not very featureful right now, and should be considered a work in progress.
 
-use core::ops::{Add, Div, Mul, Sub};
+use core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{Add, BitAnd, BitOr, Div, Mul, Sub},
+};
 
 use super::*;
 
 /// The terminating type for type arrays.
-#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash)]
 #[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
 pub struct ATerm;
 
@@ -19,7 +23,7 @@ impl TypeArray for ATerm {}
 ///
 /// This array is only really designed to contain `Integer` types. If you use it with others, you
 /// may find it lacking functionality.
-#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash)]
 #[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
 pub struct TArr<V, A> {
     first: V,
@@ -131,6 +135,38 @@ where
     type Output = Prod<FoldProd<A>, V>;
 }
 
+// ---------------------------------------------------------------------------------------
+// FoldAnd
+
+impl FoldAnd for ATerm {
+    type Output = True;
+}
+
+impl<V, A> FoldAnd for TArr<V, A>
+where
+    A: FoldAnd,
+    V: BitAnd<FoldAll<A>>,
+    And<V, FoldAll<A>>: Bit,
+{
+    type Output = And<V, FoldAll<A>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// FoldOr
+
+impl FoldOr for ATerm {
+    type Output = False;
+}
+
+impl<V, A> FoldOr for TArr<V, A>
+where
+    A: FoldOr,
+    V: BitOr<FoldAny<A>>,
+    Or<V, FoldAny<A>>: Bit,
+{
+    type Output = Or<V, FoldAny<A>>;
+}
+
 // ---------------------------------------------------------------------------------------
 // Add arrays
 // Note that two arrays are only addable if they are the same length.
@@ -394,3 +430,808 @@ where
         }
     }
 }
+
+// ---------------------------------------------------------------------------------------
+// Get
+
+impl<V, A> Get<U0> for TArr<V, A> {
+    type Output = V;
+    #[inline]
+    fn get(self, _: U0) -> Self::Output {
+        self.first
+    }
+}
+
+impl<V, A, U, B> Get<UInt<U, B>> for TArr<V, A>
+where
+    UInt<U, B>: Sub<B1>,
+    A: Get<Sub1<UInt<U, B>>>,
+{
+    type Output = At<A, Sub1<UInt<U, B>>>;
+    #[inline]
+    fn get(self, index: UInt<U, B>) -> Self::Output {
+        self.rest.get(index - B1)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Push
+
+impl<V> Push<V> for ATerm {
+    type Output = TArr<V, ATerm>;
+    #[inline]
+    fn push(self, value: V) -> Self::Output {
+        TArr {
+            first: value,
+            rest: ATerm,
+        }
+    }
+}
+
+impl<F, A, V> Push<V> for TArr<F, A>
+where
+    A: Push<V>,
+{
+    type Output = TArr<F, Pushed<A, V>>;
+    #[inline]
+    fn push(self, value: V) -> Self::Output {
+        TArr {
+            first: self.first,
+            rest: self.rest.push(value),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Pop
+
+impl<V> Pop for TArr<V, ATerm> {
+    type Output = ATerm;
+    type Last = V;
+    #[inline]
+    fn pop(self) -> (Self::Output, Self::Last) {
+        (ATerm, self.first)
+    }
+}
+
+impl<V, V2, A> Pop for TArr<V, TArr<V2, A>>
+where
+    TArr<V2, A>: Pop,
+{
+    type Output = TArr<V, Popped<TArr<V2, A>>>;
+    type Last = Last<TArr<V2, A>>;
+    #[inline]
+    fn pop(self) -> (Self::Output, Self::Last) {
+        let (rest, last) = self.rest.pop();
+        (
+            TArr {
+                first: self.first,
+                rest,
+            },
+            last,
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Concat
+
+impl<Rhs: TypeArray> Concat<Rhs> for ATerm {
+    type Output = Rhs;
+    #[inline]
+    fn concat(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<V, A, Rhs> Concat<Rhs> for TArr<V, A>
+where
+    A: Concat<Rhs>,
+    Rhs: TypeArray,
+{
+    type Output = TArr<V, Concatenated<A, Rhs>>;
+    #[inline]
+    fn concat(self, rhs: Rhs) -> Self::Output {
+        TArr {
+            first: self.first,
+            rest: self.rest.concat(rhs),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Reverse
+
+impl Reverse for ATerm {
+    type Output = ATerm;
+    #[inline]
+    fn reverse(self) -> Self::Output {
+        ATerm
+    }
+}
+
+impl<V, A> Reverse for TArr<V, A>
+where
+    A: Reverse,
+    Reversed<A>: Push<V>,
+{
+    type Output = Pushed<Reversed<A>, V>;
+    #[inline]
+    fn reverse(self) -> Self::Output {
+        self.rest.reverse().push(self.first)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Take
+
+impl Take<U0> for ATerm {
+    type Output = ATerm;
+    #[inline]
+    fn take(self, _: U0) -> Self::Output {
+        ATerm
+    }
+}
+
+impl<V, A> Take<U0> for TArr<V, A> {
+    type Output = ATerm;
+    #[inline]
+    fn take(self, _: U0) -> Self::Output {
+        ATerm
+    }
+}
+
+impl<V, A, U, B> Take<UInt<U, B>> for TArr<V, A>
+where
+    UInt<U, B>: Sub<B1>,
+    A: Take<Sub1<UInt<U, B>>>,
+{
+    type Output = TArr<V, Taken<A, Sub1<UInt<U, B>>>>;
+    #[inline]
+    fn take(self, n: UInt<U, B>) -> Self::Output {
+        TArr {
+            first: self.first,
+            rest: self.rest.take(n - B1),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Skip
+
+impl Skip<U0> for ATerm {
+    type Output = ATerm;
+    #[inline]
+    fn skip(self, _: U0) -> Self::Output {
+        ATerm
+    }
+}
+
+impl<V, A> Skip<U0> for TArr<V, A> {
+    type Output = TArr<V, A>;
+    #[inline]
+    fn skip(self, _: U0) -> Self::Output {
+        self
+    }
+}
+
+impl<V, A, U, B> Skip<UInt<U, B>> for TArr<V, A>
+where
+    UInt<U, B>: Sub<B1>,
+    A: Skip<Sub1<UInt<U, B>>>,
+{
+    type Output = Skipped<A, Sub1<UInt<U, B>>>;
+    #[inline]
+    fn skip(self, n: UInt<U, B>) -> Self::Output {
+        self.rest.skip(n - B1)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Contains
+
+impl<V> Contains<V> for ATerm {
+    type Output = False;
+}
+
+impl<F, A, V> Contains<V> for TArr<F, A>
+where
+    F: IsEqual<V>,
+    A: Contains<V>,
+    Eq<F, V>: BitOr<Contained<A, V>>,
+    Or<Eq<F, V>, Contained<A, V>>: Bit,
+{
+    type Output = Or<Eq<F, V>, Contained<A, V>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// FoldMax
+// These dispatch on the tail of the array, as impls for both `TArr<V, ATerm>` and
+// `TArr<V, TArr<V2, A>>` overflow the trait solver on older compilers.
+use crate::private::{
+    PrivateDotProduct, PrivateDotProductOut, PrivateFoldMax, PrivateFoldMaxOut, PrivateFoldMin,
+    PrivateFoldMinOut,
+};
+
+impl<V, A> FoldMax for TArr<V, A>
+where
+    A: PrivateFoldMax<V>,
+{
+    type Output = PrivateFoldMaxOut<A, V>;
+}
+
+impl<V> PrivateFoldMax<V> for ATerm {
+    type Output = V;
+}
+
+impl<V, V2, A> PrivateFoldMax<V> for TArr<V2, A>
+where
+    A: PrivateFoldMax<V2>,
+    V: Max<PrivateFoldMaxOut<A, V2>>,
+{
+    type Output = Maximum<V, PrivateFoldMaxOut<A, V2>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// FoldMin
+
+impl<V, A> FoldMin for TArr<V, A>
+where
+    A: PrivateFoldMin<V>,
+{
+    type Output = PrivateFoldMinOut<A, V>;
+}
+
+impl<V> PrivateFoldMin<V> for ATerm {
+    type Output = V;
+}
+
+impl<V, V2, A> PrivateFoldMin<V> for TArr<V2, A>
+where
+    A: PrivateFoldMin<V2>,
+    V: Min<PrivateFoldMinOut<A, V2>>,
+{
+    type Output = Minimum<V, PrivateFoldMinOut<A, V2>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Dot product
+// Note that the dot product is only defined for non-empty arrays of the same length.
+
+impl<Vl, Al, Vr, Ar> DotProduct<TArr<Vr, Ar>> for TArr<Vl, Al>
+where
+    Al: PrivateDotProduct<Vl, Vr, Ar>,
+{
+    type Output = PrivateDotProductOut<Al, Vl, Vr, Ar>;
+}
+
+impl<Vl, Vr> PrivateDotProduct<Vl, Vr, ATerm> for ATerm
+where
+    Vl: Mul<Vr>,
+{
+    type Output = Prod<Vl, Vr>;
+}
+
+impl<Vl, Vl2, Al, Vr, Vr2, Ar> PrivateDotProduct<Vl, Vr, TArr<Vr2, Ar>> for TArr<Vl2, Al>
+where
+    Vl: Mul<Vr>,
+    Al: PrivateDotProduct<Vl2, Vr2, Ar>,
+    Prod<Vl, Vr>: Add<PrivateDotProductOut<Al, Vl2, Vr2, Ar>>,
+{
+    type Output = Sum<Prod<Vl, Vr>, PrivateDotProductOut<Al, Vl2, Vr2, Ar>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Sort
+// This is an insertion sort, as it is easy to express with types.
+use crate::private::{
+    PrivateDedup, PrivateDedupOut, PrivateInsertSorted, PrivateInsertSortedIf,
+    PrivateInsertSortedIfOut, PrivateInsertSortedOut, PrivateIsSorted, PrivateIsSortedOut,
+};
+
+impl<V> PrivateInsertSorted<V> for ATerm {
+    type Output = TArr<V, ATerm>;
+}
+
+impl<F, A, V> PrivateInsertSorted<V> for TArr<F, A>
+where
+    V: Cmp<F>,
+    TArr<F, A>: PrivateInsertSortedIf<V, Compare<V, F>>,
+{
+    type Output = PrivateInsertSortedIfOut<TArr<F, A>, V, Compare<V, F>>;
+}
+
+impl<F, A, V> PrivateInsertSortedIf<V, Less> for TArr<F, A> {
+    type Output = TArr<V, TArr<F, A>>;
+}
+
+impl<F, A, V> PrivateInsertSortedIf<V, Equal> for TArr<F, A> {
+    type Output = TArr<V, TArr<F, A>>;
+}
+
+impl<F, A, V> PrivateInsertSortedIf<V, Greater> for TArr<F, A>
+where
+    A: PrivateInsertSorted<V>,
+{
+    type Output = TArr<F, PrivateInsertSortedOut<A, V>>;
+}
+
+impl Sort for ATerm {
+    type Output = ATerm;
+}
+
+impl<V, A> Sort for TArr<V, A>
+where
+    A: Sort,
+    Sorted<A>: PrivateInsertSorted<V>,
+{
+    type Output = PrivateInsertSortedOut<Sorted<A>, V>;
+}
+
+impl<A> SortDesc for A
+where
+    A: Sort,
+    Sorted<A>: Reverse,
+{
+    type Output = Reversed<Sorted<A>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Dedup
+
+impl Dedup for ATerm {
+    type Output = ATerm;
+}
+
+impl<V> Dedup for TArr<V, ATerm> {
+    type Output = TArr<V, ATerm>;
+}
+
+impl<V, V2, A> Dedup for TArr<V, TArr<V2, A>>
+where
+    V: IsEqual<V2>,
+    TArr<V, TArr<V2, A>>: PrivateDedup<Eq<V, V2>>,
+{
+    type Output = PrivateDedupOut<TArr<V, TArr<V2, A>>, Eq<V, V2>>;
+}
+
+impl<V, A> PrivateDedup<True> for TArr<V, A>
+where
+    A: Dedup,
+{
+    type Output = Deduped<A>;
+}
+
+impl<V, A> PrivateDedup<False> for TArr<V, A>
+where
+    A: Dedup,
+{
+    type Output = TArr<V, Deduped<A>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// IsSorted
+
+impl IsSorted for ATerm {
+    type Output = True;
+}
+
+impl<V, A> IsSorted for TArr<V, A>
+where
+    A: PrivateIsSorted<V>,
+    PrivateIsSortedOut<A, V>: Bit,
+{
+    type Output = PrivateIsSortedOut<A, V>;
+}
+
+impl<V> PrivateIsSorted<V> for ATerm {
+    type Output = True;
+}
+
+impl<V, V2, A> PrivateIsSorted<V> for TArr<V2, A>
+where
+    V: IsLessOrEqual<V2>,
+    A: PrivateIsSorted<V2>,
+    LeEq<V, V2>: BitAnd<PrivateIsSortedOut<A, V2>>,
+    And<LeEq<V, V2>, PrivateIsSortedOut<A, V2>>: Bit,
+{
+    type Output = And<LeEq<V, V2>, PrivateIsSortedOut<A, V2>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Map
+
+impl<F> Map<F> for ATerm {
+    type Output = ATerm;
+}
+
+impl<V, A, F> Map<F> for TArr<V, A>
+where
+    F: TypeFn<V>,
+    A: Map<F>,
+{
+    type Output = TArr<Apply<F, V>, Mapped<A, F>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// ZipWith
+// Note that two arrays can only be zipped if they are the same length.
+
+impl<F> ZipWith<F, ATerm> for ATerm {
+    type Output = ATerm;
+}
+
+impl<Vl, Al, Vr, Ar, F> ZipWith<F, TArr<Vr, Ar>> for TArr<Vl, Al>
+where
+    F: TypeFn<(Vl, Vr)>,
+    Al: ZipWith<F, Ar>,
+{
+    type Output = TArr<Apply<F, (Vl, Vr)>, ZippedWith<Al, F, Ar>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Filter
+use crate::private::{PrivateFilter, PrivateFilterOut};
+
+impl<P> Filter<P> for ATerm {
+    type Output = ATerm;
+}
+
+impl<V, A, P> Filter<P> for TArr<V, A>
+where
+    P: TypeFn<V>,
+    TArr<V, A>: PrivateFilter<P, Apply<P, V>>,
+{
+    type Output = PrivateFilterOut<TArr<V, A>, P, Apply<P, V>>;
+}
+
+impl<V, A, P> PrivateFilter<P, True> for TArr<V, A>
+where
+    A: Filter<P>,
+{
+    type Output = TArr<V, Filtered<A, P>>;
+}
+
+impl<V, A, P> PrivateFilter<P, False> for TArr<V, A>
+where
+    A: Filter<P>,
+{
+    type Output = Filtered<A, P>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Fold
+
+impl<F, Init> Fold<F, Init> for ATerm {
+    type Output = Init;
+}
+
+impl<V, A, F, Init> Fold<F, Init> for TArr<V, A>
+where
+    F: TypeFn<(Init, V)>,
+    A: Fold<F, Apply<F, (Init, V)>>,
+{
+    type Output = Folded<A, F, Apply<F, (Init, V)>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Runtime values
+use crate::private::{PrivateElement, PrivateToArray};
+
+impl<T> PrivateToArray<T> for ATerm {
+    #[inline]
+    fn private_first() -> Option<PrivateElement<T>> {
+        None
+    }
+}
+
+impl<V, A, T> PrivateToArray<T> for TArr<V, A>
+where
+    V: ToInt<T>,
+    A: PrivateToArray<T>,
+{
+    #[inline]
+    fn private_first() -> Option<PrivateElement<T>> {
+        Some(PrivateElement {
+            value: V::to_int,
+            rest: A::private_first,
+        })
+    }
+}
+
+impl<T> ToArray<T> for ATerm {
+    const LEN: usize = 0;
+}
+
+impl<V, A, T> ToArray<T> for TArr<V, A>
+where
+    V: ToInt<T>,
+    A: ToArray<T>,
+{
+    const LEN: usize = A::LEN + 1;
+}
+
+#[cfg(feature = "const-generics")]
+use crate::{private::PrivateArraySuffix, Const, Length, ToConstArray, ToUInt, U};
+
+#[cfg(feature = "const-generics")]
+impl<T> ToConstArray<T, 0> for ATerm {
+    const ARRAY: [T; 0] = [];
+}
+
+#[cfg(feature = "const-generics")]
+impl<V, A, T, const N: usize> ToConstArray<T, N> for TArr<V, A>
+where
+    TArr<V, A>: ToArray<T> + Len + PrivateArraySuffix<T, N>,
+    Const<N>: ToUInt,
+    U<N>: Same<Length<TArr<V, A>>>,
+{
+    const ARRAY: [T; N] = Self::PRIVATE_SUFFIX;
+}
+
+#[cfg(feature = "const-generics")]
+impl<V, T, const N: usize> PrivateArraySuffix<T, N> for TArr<V, ATerm>
+where
+    V: ToInt<T>,
+    T: Copy,
+{
+    const PRIVATE_SUFFIX: [T; N] = [V::INT; N];
+}
+
+#[cfg(feature = "const-generics")]
+impl<V, Vr, Ar, T, const N: usize> PrivateArraySuffix<T, N> for TArr<V, TArr<Vr, Ar>>
+where
+    V: ToInt<T>,
+    T: Copy,
+    TArr<Vr, Ar>: ToArray<T> + PrivateArraySuffix<T, N>,
+{
+    const PRIVATE_SUFFIX: [T; N] = {
+        let mut array = <TArr<Vr, Ar>>::PRIVATE_SUFFIX;
+        array[N - 1 - <TArr<Vr, Ar>>::LEN] = V::INT;
+        array
+    };
+}
+
+/// An iterator over the values of the elements of a type-level array, created by
+/// [`ToArray::iter`].
+pub struct Iter<T> {
+    next: Option<PrivateElement<T>>,
+    len: usize,
+}
+
+impl<T> Iter<T> {
+    pub(crate) fn new(first: Option<PrivateElement<T>>, len: usize) -> Self {
+        Iter { next: first, len }
+    }
+}
+
+impl<T> Clone for Iter<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter {
+            next: self.next.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Iter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Iter").field("len", &self.len).finish()
+    }
+}
+
+impl<T> Iterator for Iter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let element = self.next.take()?;
+        self.next = (element.rest)();
+        self.len -= 1;
+        Some((element.value)())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<T> {}
+
+impl<T> FusedIterator for Iter<T> {}
+
+// ---------------------------------------------------------------------------------------
+// Formatting
+
+use crate::private::{PrivateDebugEntries, PrivateDisplayEntries};
+
+impl PrivateDebugEntries for ATerm {
+    #[inline]
+    fn private_debug_entries(&self, _: &mut fmt::DebugList<'_, '_>) {}
+}
+
+impl<V: fmt::Debug, A: PrivateDebugEntries> PrivateDebugEntries for TArr<V, A> {
+    #[inline]
+    fn private_debug_entries(&self, list: &mut fmt::DebugList<'_, '_>) {
+        list.entry(&self.first);
+        self.rest.private_debug_entries(list);
+    }
+}
+
+impl PrivateDisplayEntries for ATerm {
+    #[inline]
+    fn private_display_entries(&self, _: &mut fmt::Formatter<'_>, _: bool) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl<V: fmt::Display, A: PrivateDisplayEntries> PrivateDisplayEntries for TArr<V, A> {
+    #[inline]
+    fn private_display_entries(&self, f: &mut fmt::Formatter<'_>, first: bool) -> fmt::Result {
+        if !first {
+            f.write_str(", ")?;
+        }
+        fmt::Display::fmt(&self.first, f)?;
+        self.rest.private_display_entries(f, false)
+    }
+}
+
+/// Formats as a list of the `Debug` representations of the elements, such as `[P1, N2]`.
+impl fmt::Debug for ATerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().finish()
+    }
+}
+
+/// Formats as a list of the `Debug` representations of the elements, such as `[P1, N2]`.
+impl<V: fmt::Debug, A: PrivateDebugEntries> fmt::Debug for TArr<V, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        self.private_debug_entries(&mut list);
+        list.finish()
+    }
+}
+
+/// Formats as a list of the values of the elements, such as `[1, -2]`.
+impl fmt::Display for ATerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[]")
+    }
+}
+
+/// Formats as a list of the values of the elements, such as `[1, -2]`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ATerm, Push, N2, P1, U3};
+///
+/// let a = ATerm.push(P1::new()).push(N2::new());
+/// assert_eq!(format!("{} {:?}", a, a), "[1, -2] [P1, N2]");
+/// assert_eq!(format!("{:?} {:?}", ATerm, ATerm.push(U3::new())), "[] [U3]");
+/// ```
+impl<V: fmt::Display, A: PrivateDisplayEntries> fmt::Display for TArr<V, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        self.private_display_entries(f, true)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Mul;
+
+    use crate::{
+        assert_type_eq, At, Concatenated, Contained, Dot, False, FoldMaximum, FoldMinimum, IsLess,
+        Last, Popped, Pushed, Reversed, Skipped, Taken, True, TypeFn, N1, N4, N8, P1, P2, P3, P7,
+        P8, U0, U1, U2, U3, Z0,
+    };
+
+    type A = tarr![P3, N4, P8];
+
+    #[test]
+    fn structure() {
+        assert_type_eq!(At<A, U0>, P3);
+        assert_type_eq!(At<A, U2>, P8);
+        assert_type_eq!(Pushed<A, Z0>, tarr![P3, N4, P8, Z0]);
+        assert_type_eq!(Pushed<tarr![], Z0>, tarr![Z0]);
+        assert_type_eq!(Popped<tarr![Z0]>, tarr![]);
+        assert_type_eq!(Last<A>, P8);
+        assert_type_eq!(Concatenated<A, tarr![N1]>, tarr![P3, N4, P8, N1]);
+        assert_type_eq!(Concatenated<tarr![], A>, A);
+        assert_type_eq!(Reversed<A>, tarr![P8, N4, P3]);
+        assert_type_eq!(Reversed<tarr![]>, tarr![]);
+        assert_type_eq!(Taken<A, U0>, tarr![]);
+        assert_type_eq!(Taken<A, U3>, A);
+        assert_type_eq!(Skipped<A, U0>, A);
+        assert_type_eq!(Skipped<A, U3>, tarr![]);
+        assert_type_eq!(Concatenated<Taken<A, U1>, Skipped<A, U1>>, A);
+    }
+
+    #[test]
+    fn folds() {
+        assert_type_eq!(Contained<A, N4>, True);
+        assert_type_eq!(Contained<A, P1>, False);
+        assert_type_eq!(Contained<tarr![], P1>, False);
+        assert_type_eq!(FoldMaximum<A>, P8);
+        assert_type_eq!(FoldMinimum<A>, N4);
+        assert_type_eq!(FoldMinimum<tarr![P2]>, P2);
+        assert_type_eq!(Dot<A, tarr![P1, P1, P1]>, P7);
+        assert_type_eq!(Dot<tarr![P2], tarr![N4]>, N8);
+    }
+
+    #[test]
+    fn sorting() {
+        use crate::{Deduped, InOrder, Sorted, SortedDesc};
+
+        assert_type_eq!(Sorted<tarr![]>, tarr![]);
+        assert_type_eq!(Sorted<A>, tarr![N4, P3, P8]);
+        assert_type_eq!(Sorted<tarr![P3, N4, P3, N1]>, tarr![N4, N1, P3, P3]);
+        assert_type_eq!(SortedDesc<A>, tarr![P8, P3, N4]);
+        assert_type_eq!(Sorted<tarr![U3, U0, U2]>, tarr![U0, U2, U3]);
+        assert_type_eq!(Deduped<tarr![]>, tarr![]);
+        assert_type_eq!(Deduped<tarr![P1, P1, P1]>, tarr![P1]);
+        assert_type_eq!(Deduped<tarr![P1, N1, N1, P1]>, tarr![P1, N1, P1]);
+        assert_type_eq!(InOrder<tarr![]>, True);
+        assert_type_eq!(InOrder<Sorted<A>>, True);
+        assert_type_eq!(InOrder<A>, False);
+    }
+
+    #[allow(dead_code)]
+    struct Square;
+
+    impl<X: Mul> TypeFn<X> for Square {
+        type Output = crate::Square<X>;
+    }
+
+    #[allow(dead_code)]
+    struct IsNegative;
+
+    impl<X: IsLess<Z0>> TypeFn<X> for IsNegative {
+        type Output = crate::Le<X, Z0>;
+    }
+
+    #[allow(dead_code)]
+    struct Max;
+
+    impl<L: crate::Max<R>, R> TypeFn<(L, R)> for Max {
+        type Output = crate::Maximum<L, R>;
+    }
+
+    #[test]
+    fn combinators() {
+        use crate::{Filtered, Folded, Mapped, ZippedWith, P16, P64, P9};
+
+        assert_type_eq!(Mapped<tarr![], Square>, tarr![]);
+        assert_type_eq!(Mapped<A, Square>, tarr![P9, P16, P64]);
+        assert_type_eq!(ZippedWith<A, Max, tarr![P1, P1, P9]>, tarr![P3, P1, P9]);
+        assert_type_eq!(Filtered<A, IsNegative>, tarr![N4]);
+        assert_type_eq!(Filtered<tarr![P1, P2], IsNegative>, tarr![]);
+        assert_type_eq!(Folded<A, Max, N8>, P8);
+        assert_type_eq!(Folded<tarr![], Max, N8>, N8);
+    }
+
+    #[test]
+    fn values() {
+        use crate::{ToArray, U5};
+
+        assert_eq!(<tarr![] as ToArray<i8>>::iter().next(), None);
+        assert!(<A as ToArray<i64>>::iter().eq([3, -4, 8].iter().cloned()));
+        assert_eq!(<A as ToArray<i64>>::value_at(2), Some(8));
+        assert_eq!(<A as ToArray<i64>>::iter().len(), 3);
+        assert!(<tarr![U5, U0, U2] as ToArray<u8>>::iter().eq([5, 0, 2].iter().cloned()));
+    }
+
+    #[cfg(feature = "const-generics")]
+    #[test]
+    fn to_array() {
+        use crate::{ToArray, ToConstArray, U5};
+
+        assert_eq!(<tarr![] as ToArray<i32>>::to_array(), []);
+        assert_eq!(<A as ToArray<i32>>::to_array(), [3, -4, 8]);
+        assert_eq!(<tarr![U5] as ToConstArray<u8, 1>>::ARRAY, [5]);
+        assert_eq!(<A as ToConstArray<i64, 3>>::ARRAY, [3, -4, 8]);
+    }
+}
@@ -7,9 +7,12 @@
 //! **Type operators** implemented:
 //!
 //! - From `core::ops`: `BitAnd`, `BitOr`, `BitXor`, and `Not`.
-//! - From `typenum`: `Same` and `Cmp`.
+//! - From `typenum`: `Same`, `Cmp`, `Implies`, and `IfThenElse`.
 
-use crate::{private::InternalMarker, Cmp, Equal, Greater, Less, NonZero, PowerOfTwo, Zero};
+use crate::{
+    private::InternalMarker, Cmp, Equal, Greater, IfThenElse, Implies, Less, NonZero, PowerOfTwo,
+    Zero,
+};
 use core::ops::{BitAnd, BitOr, BitXor, Not};
 
 pub use crate::marker_traits::Bit;
@@ -184,11 +187,47 @@ impl BitXor<B1> for B1 {
     }
 }
 
+/// Implication from 0 ( 0 -> B = 1)
+impl<Rhs: Bit> Implies<Rhs> for B0 {
+    type Output = B1;
+    #[inline]
+    fn implies(self, _: Rhs) -> Self::Output {
+        B1
+    }
+}
+
+/// Implication from 1 ( 1 -> B = B)
+impl<Rhs: Bit> Implies<Rhs> for B1 {
+    type Output = Rhs;
+    #[inline]
+    fn implies(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+/// Selection on 0 (if 0 { T } else { E } = E)
+impl<Then, Else> IfThenElse<Then, Else> for B0 {
+    type Output = Else;
+    #[inline]
+    fn if_then_else(self, _: Then, else_: Else) -> Self::Output {
+        else_
+    }
+}
+
+/// Selection on 1 (if 1 { T } else { E } = T)
+impl<Then, Else> IfThenElse<Then, Else> for B1 {
+    type Output = Then;
+    #[inline]
+    fn if_then_else(self, then: Then, _: Else) -> Self::Output {
+        then
+    }
+}
+
 #[cfg(test)]
 mod bit_op_tests {
     use core::ops::{BitAnd, BitOr, BitXor, Not};
 
-    use crate::{B0, B1};
+    use crate::{Implies, B0, B1};
 
     // macro for testing operation results. Uses `Same` to ensure the types are equal and
     // not just the values they evaluate to.
@@ -228,6 +267,20 @@ mod bit_op_tests {
         test_bit_op!(B0 BitXor B1 = B1);
         test_bit_op!(B1 BitXor B0 = B1);
         test_bit_op!(B1 BitXor B1 = B0);
+
+        test_bit_op!(B0 Implies B0 = B1);
+        test_bit_op!(B0 Implies B1 = B1);
+        test_bit_op!(B1 Implies B0 = B0);
+        test_bit_op!(B1 Implies B1 = B1);
+    }
+
+    #[test]
+    fn selection() {
+        use crate::{assert_type_eq, If, P1, P2};
+
+        assert_type_eq!(If<B0, P1, P2>, P2);
+        assert_type_eq!(If<B1, P1, P2>, P1);
+        assert_type_eq!(If<crate::Le<P1, P2>, P1, P2>, P1);
     }
 }
 
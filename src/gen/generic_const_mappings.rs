@@ -5,7 +5,10 @@
 //!   - It requires the `const-generics` crate feature to be enabled.
 //!
 //! The main type to use here is [`U`], although [`Const`] and [`ToUInt`] may be needed
-//! in a generic context.
+//! in a generic context. [`ToConst`] maps the other way, from a [`UInt`] back to a `const`.
+//!
+//! For signed `const N: isize` parameters, [`I`], [`ConstI`], and [`ToInteger`] do the same,
+//! mapping to [`PInt`], [`NInt`], and [`Z0`].
 
 use crate::*;
 
@@ -55,4704 +58,18900 @@ pub trait ToUInt {
     type Output;
 }
 
+/// The reverse of [`U`]: the mapping from a [`UInt`] to the [`Const`] of its value, which is
+/// implemented for the same numbers as [`ToUInt`].
+///
+///   - It requires the `const-generics` crate feature to be enabled.
+///
+/// The value can't be used as an array length directly, as in `[T; N::USIZE]`, but a
+/// `const N: usize` parameter bound by `ToConst<Output = Const<N>>` is inferred from the type:
+///
+/// ```rust
+/// use typenum::*;
+///
+/// fn zeros<L, const N: usize>(_: L) -> [u8; N]
+/// where
+///     L: ToConst<Output = Const<N>>,
+/// {
+///     [0; N]
+/// }
+///
+/// assert_eq!(zeros(U4::new()), [0; 4]);
+///
+/// trait Storage {
+///     type Array;
+/// }
+///
+/// impl<L, const N: usize> Storage for L
+/// where
+///     L: ToConst<Output = Const<N>>,
+/// {
+///     type Array = [u32; N];
+/// }
+///
+/// let array: <U3 as Storage>::Array = [1, 2, 3];
+/// assert_eq!(array.len(), U3::USIZE);
+/// ```
+pub trait ToConst {
+    /// The `Const<N>` type corresponding to `Self = UN`.
+    type Output;
+}
+
+/// The mapping from a generic `const: isize` to a signed integer: [`I<N>`] is expected to work
+/// like [`PN`] for positive `N`, like [`NN`] for negative `N`, and like [`Z0`] for zero.
+///
+///   - It requires the `const-generics` crate feature to be enabled.
+///
+/// It is defined for the same magnitudes as [`U`].
+///
+/// [`I<N>`]: `I`
+/// [`PN`]: `P42`
+/// [`NN`]: `N42`
+///
+/// # Example
+///
+/// ```rust
+/// use typenum::*;
+///
+/// assert_type_eq!(I<42>, P42);
+/// assert_type_eq!(I<-42>, N42);
+/// assert_type_eq!(I<0>, Z0);
+/// ```
+///
+/// As with [`U`], it can be used in a generic `const N: isize` context when guarded by a
+/// `where` clause:
+///
+/// ```rust
+/// use typenum::*;
+///
+/// struct Exponent<const N: isize>;
+///
+/// trait ToTypenum { type Output; }
+///
+/// impl<const N: isize> ToTypenum for Exponent<N>
+/// where
+///     ConstI<N>: ToInteger,
+/// {
+///     type Output = I<N>;
+/// }
+///
+/// assert_type_eq!(<Exponent<-3> as ToTypenum>::Output, N3);
+/// ```
+pub type I<const N: isize> = <ConstI<N> as ToInteger>::Output;
+
+/// Used to allow the usage of [`I`] in a generic context.
+pub struct ConstI<const N: isize>;
+
+/// Used to allow the usage of [`I`] in a generic context.
+pub trait ToInteger {
+    /// The [`PN`][`crate::P42`], [`NN`][`crate::N42`], or [`Z0`] type corresponding to
+    /// `Self = ConstI<N>`.
+    type Output;
+}
+
+/// The value of `N`, the same as that of [`U<N>`](U).
+///
+/// ```rust
+/// use typenum::*;
+///
+/// assert_eq!(<Const<42> as ToInt<i32>>::INT, 42);
+/// ```
+impl<T, const N: usize> ToInt<T> for Const<N>
+where
+    Const<N>: ToUInt,
+    U<N>: ToInt<T>,
+{
+    #[inline]
+    fn to_int() -> T {
+        <U<N> as ToInt<T>>::to_int()
+    }
+    const INT: T = <U<N> as ToInt<T>>::INT;
+}
+
 impl ToUInt for Const<0> {
     type Output = U0;
 }
 
+impl ToConst for U0 {
+    type Output = Const<0>;
+}
+
 impl ToUInt for Const<1> {
     type Output = U1;
 }
 
+impl ToConst for U1 {
+    type Output = Const<1>;
+}
+
 impl ToUInt for Const<2> {
     type Output = U2;
 }
 
+impl ToConst for U2 {
+    type Output = Const<2>;
+}
+
 impl ToUInt for Const<3> {
     type Output = U3;
 }
 
+impl ToConst for U3 {
+    type Output = Const<3>;
+}
+
 impl ToUInt for Const<4> {
     type Output = U4;
 }
 
+impl ToConst for U4 {
+    type Output = Const<4>;
+}
+
 impl ToUInt for Const<5> {
     type Output = U5;
 }
 
+impl ToConst for U5 {
+    type Output = Const<5>;
+}
+
 impl ToUInt for Const<6> {
     type Output = U6;
 }
 
+impl ToConst for U6 {
+    type Output = Const<6>;
+}
+
 impl ToUInt for Const<7> {
     type Output = U7;
 }
 
+impl ToConst for U7 {
+    type Output = Const<7>;
+}
+
 impl ToUInt for Const<8> {
     type Output = U8;
 }
 
+impl ToConst for U8 {
+    type Output = Const<8>;
+}
+
 impl ToUInt for Const<9> {
     type Output = U9;
 }
 
+impl ToConst for U9 {
+    type Output = Const<9>;
+}
+
 impl ToUInt for Const<10> {
     type Output = U10;
 }
 
+impl ToConst for U10 {
+    type Output = Const<10>;
+}
+
 impl ToUInt for Const<11> {
     type Output = U11;
 }
 
+impl ToConst for U11 {
+    type Output = Const<11>;
+}
+
 impl ToUInt for Const<12> {
     type Output = U12;
 }
 
+impl ToConst for U12 {
+    type Output = Const<12>;
+}
+
 impl ToUInt for Const<13> {
     type Output = U13;
 }
 
+impl ToConst for U13 {
+    type Output = Const<13>;
+}
+
 impl ToUInt for Const<14> {
     type Output = U14;
 }
 
+impl ToConst for U14 {
+    type Output = Const<14>;
+}
+
 impl ToUInt for Const<15> {
     type Output = U15;
 }
 
+impl ToConst for U15 {
+    type Output = Const<15>;
+}
+
 impl ToUInt for Const<16> {
     type Output = U16;
 }
 
+impl ToConst for U16 {
+    type Output = Const<16>;
+}
+
 impl ToUInt for Const<17> {
     type Output = U17;
 }
 
+impl ToConst for U17 {
+    type Output = Const<17>;
+}
+
 impl ToUInt for Const<18> {
     type Output = U18;
 }
 
+impl ToConst for U18 {
+    type Output = Const<18>;
+}
+
 impl ToUInt for Const<19> {
     type Output = U19;
 }
 
+impl ToConst for U19 {
+    type Output = Const<19>;
+}
+
 impl ToUInt for Const<20> {
     type Output = U20;
 }
 
+impl ToConst for U20 {
+    type Output = Const<20>;
+}
+
 impl ToUInt for Const<21> {
     type Output = U21;
 }
 
+impl ToConst for U21 {
+    type Output = Const<21>;
+}
+
 impl ToUInt for Const<22> {
     type Output = U22;
 }
 
+impl ToConst for U22 {
+    type Output = Const<22>;
+}
+
 impl ToUInt for Const<23> {
     type Output = U23;
 }
 
+impl ToConst for U23 {
+    type Output = Const<23>;
+}
+
 impl ToUInt for Const<24> {
     type Output = U24;
 }
 
+impl ToConst for U24 {
+    type Output = Const<24>;
+}
+
 impl ToUInt for Const<25> {
     type Output = U25;
 }
 
+impl ToConst for U25 {
+    type Output = Const<25>;
+}
+
 impl ToUInt for Const<26> {
     type Output = U26;
 }
 
+impl ToConst for U26 {
+    type Output = Const<26>;
+}
+
 impl ToUInt for Const<27> {
     type Output = U27;
 }
 
+impl ToConst for U27 {
+    type Output = Const<27>;
+}
+
 impl ToUInt for Const<28> {
     type Output = U28;
 }
 
+impl ToConst for U28 {
+    type Output = Const<28>;
+}
+
 impl ToUInt for Const<29> {
     type Output = U29;
 }
 
+impl ToConst for U29 {
+    type Output = Const<29>;
+}
+
 impl ToUInt for Const<30> {
     type Output = U30;
 }
 
+impl ToConst for U30 {
+    type Output = Const<30>;
+}
+
 impl ToUInt for Const<31> {
     type Output = U31;
 }
 
+impl ToConst for U31 {
+    type Output = Const<31>;
+}
+
 impl ToUInt for Const<32> {
     type Output = U32;
 }
 
+impl ToConst for U32 {
+    type Output = Const<32>;
+}
+
 impl ToUInt for Const<33> {
     type Output = U33;
 }
 
+impl ToConst for U33 {
+    type Output = Const<33>;
+}
+
 impl ToUInt for Const<34> {
     type Output = U34;
 }
 
+impl ToConst for U34 {
+    type Output = Const<34>;
+}
+
 impl ToUInt for Const<35> {
     type Output = U35;
 }
 
+impl ToConst for U35 {
+    type Output = Const<35>;
+}
+
 impl ToUInt for Const<36> {
     type Output = U36;
 }
 
+impl ToConst for U36 {
+    type Output = Const<36>;
+}
+
 impl ToUInt for Const<37> {
     type Output = U37;
 }
 
+impl ToConst for U37 {
+    type Output = Const<37>;
+}
+
 impl ToUInt for Const<38> {
     type Output = U38;
 }
 
+impl ToConst for U38 {
+    type Output = Const<38>;
+}
+
 impl ToUInt for Const<39> {
     type Output = U39;
 }
 
+impl ToConst for U39 {
+    type Output = Const<39>;
+}
+
 impl ToUInt for Const<40> {
     type Output = U40;
 }
 
+impl ToConst for U40 {
+    type Output = Const<40>;
+}
+
 impl ToUInt for Const<41> {
     type Output = U41;
 }
 
+impl ToConst for U41 {
+    type Output = Const<41>;
+}
+
 impl ToUInt for Const<42> {
     type Output = U42;
 }
 
+impl ToConst for U42 {
+    type Output = Const<42>;
+}
+
 impl ToUInt for Const<43> {
     type Output = U43;
 }
 
+impl ToConst for U43 {
+    type Output = Const<43>;
+}
+
 impl ToUInt for Const<44> {
     type Output = U44;
 }
 
+impl ToConst for U44 {
+    type Output = Const<44>;
+}
+
 impl ToUInt for Const<45> {
     type Output = U45;
 }
 
+impl ToConst for U45 {
+    type Output = Const<45>;
+}
+
 impl ToUInt for Const<46> {
     type Output = U46;
 }
 
+impl ToConst for U46 {
+    type Output = Const<46>;
+}
+
 impl ToUInt for Const<47> {
     type Output = U47;
 }
 
+impl ToConst for U47 {
+    type Output = Const<47>;
+}
+
 impl ToUInt for Const<48> {
     type Output = U48;
 }
 
+impl ToConst for U48 {
+    type Output = Const<48>;
+}
+
 impl ToUInt for Const<49> {
     type Output = U49;
 }
 
+impl ToConst for U49 {
+    type Output = Const<49>;
+}
+
 impl ToUInt for Const<50> {
     type Output = U50;
 }
 
+impl ToConst for U50 {
+    type Output = Const<50>;
+}
+
 impl ToUInt for Const<51> {
     type Output = U51;
 }
 
+impl ToConst for U51 {
+    type Output = Const<51>;
+}
+
 impl ToUInt for Const<52> {
     type Output = U52;
 }
 
+impl ToConst for U52 {
+    type Output = Const<52>;
+}
+
 impl ToUInt for Const<53> {
     type Output = U53;
 }
 
+impl ToConst for U53 {
+    type Output = Const<53>;
+}
+
 impl ToUInt for Const<54> {
     type Output = U54;
 }
 
+impl ToConst for U54 {
+    type Output = Const<54>;
+}
+
 impl ToUInt for Const<55> {
     type Output = U55;
 }
 
+impl ToConst for U55 {
+    type Output = Const<55>;
+}
+
 impl ToUInt for Const<56> {
     type Output = U56;
 }
 
+impl ToConst for U56 {
+    type Output = Const<56>;
+}
+
 impl ToUInt for Const<57> {
     type Output = U57;
 }
 
+impl ToConst for U57 {
+    type Output = Const<57>;
+}
+
 impl ToUInt for Const<58> {
     type Output = U58;
 }
 
+impl ToConst for U58 {
+    type Output = Const<58>;
+}
+
 impl ToUInt for Const<59> {
     type Output = U59;
 }
 
+impl ToConst for U59 {
+    type Output = Const<59>;
+}
+
 impl ToUInt for Const<60> {
     type Output = U60;
 }
 
+impl ToConst for U60 {
+    type Output = Const<60>;
+}
+
 impl ToUInt for Const<61> {
     type Output = U61;
 }
 
+impl ToConst for U61 {
+    type Output = Const<61>;
+}
+
 impl ToUInt for Const<62> {
     type Output = U62;
 }
 
+impl ToConst for U62 {
+    type Output = Const<62>;
+}
+
 impl ToUInt for Const<63> {
     type Output = U63;
 }
 
+impl ToConst for U63 {
+    type Output = Const<63>;
+}
+
 impl ToUInt for Const<64> {
     type Output = U64;
 }
 
+impl ToConst for U64 {
+    type Output = Const<64>;
+}
+
 impl ToUInt for Const<65> {
     type Output = U65;
 }
 
+impl ToConst for U65 {
+    type Output = Const<65>;
+}
+
 impl ToUInt for Const<66> {
     type Output = U66;
 }
 
+impl ToConst for U66 {
+    type Output = Const<66>;
+}
+
 impl ToUInt for Const<67> {
     type Output = U67;
 }
 
+impl ToConst for U67 {
+    type Output = Const<67>;
+}
+
 impl ToUInt for Const<68> {
     type Output = U68;
 }
 
+impl ToConst for U68 {
+    type Output = Const<68>;
+}
+
 impl ToUInt for Const<69> {
     type Output = U69;
 }
 
+impl ToConst for U69 {
+    type Output = Const<69>;
+}
+
 impl ToUInt for Const<70> {
     type Output = U70;
 }
 
+impl ToConst for U70 {
+    type Output = Const<70>;
+}
+
 impl ToUInt for Const<71> {
     type Output = U71;
 }
 
+impl ToConst for U71 {
+    type Output = Const<71>;
+}
+
 impl ToUInt for Const<72> {
     type Output = U72;
 }
 
+impl ToConst for U72 {
+    type Output = Const<72>;
+}
+
 impl ToUInt for Const<73> {
     type Output = U73;
 }
 
+impl ToConst for U73 {
+    type Output = Const<73>;
+}
+
 impl ToUInt for Const<74> {
     type Output = U74;
 }
 
+impl ToConst for U74 {
+    type Output = Const<74>;
+}
+
 impl ToUInt for Const<75> {
     type Output = U75;
 }
 
+impl ToConst for U75 {
+    type Output = Const<75>;
+}
+
 impl ToUInt for Const<76> {
     type Output = U76;
 }
 
+impl ToConst for U76 {
+    type Output = Const<76>;
+}
+
 impl ToUInt for Const<77> {
     type Output = U77;
 }
 
+impl ToConst for U77 {
+    type Output = Const<77>;
+}
+
 impl ToUInt for Const<78> {
     type Output = U78;
 }
 
+impl ToConst for U78 {
+    type Output = Const<78>;
+}
+
 impl ToUInt for Const<79> {
     type Output = U79;
 }
 
+impl ToConst for U79 {
+    type Output = Const<79>;
+}
+
 impl ToUInt for Const<80> {
     type Output = U80;
 }
 
+impl ToConst for U80 {
+    type Output = Const<80>;
+}
+
 impl ToUInt for Const<81> {
     type Output = U81;
 }
 
+impl ToConst for U81 {
+    type Output = Const<81>;
+}
+
 impl ToUInt for Const<82> {
     type Output = U82;
 }
 
+impl ToConst for U82 {
+    type Output = Const<82>;
+}
+
 impl ToUInt for Const<83> {
     type Output = U83;
 }
 
+impl ToConst for U83 {
+    type Output = Const<83>;
+}
+
 impl ToUInt for Const<84> {
     type Output = U84;
 }
 
+impl ToConst for U84 {
+    type Output = Const<84>;
+}
+
 impl ToUInt for Const<85> {
     type Output = U85;
 }
 
+impl ToConst for U85 {
+    type Output = Const<85>;
+}
+
 impl ToUInt for Const<86> {
     type Output = U86;
 }
 
+impl ToConst for U86 {
+    type Output = Const<86>;
+}
+
 impl ToUInt for Const<87> {
     type Output = U87;
 }
 
+impl ToConst for U87 {
+    type Output = Const<87>;
+}
+
 impl ToUInt for Const<88> {
     type Output = U88;
 }
 
+impl ToConst for U88 {
+    type Output = Const<88>;
+}
+
 impl ToUInt for Const<89> {
     type Output = U89;
 }
 
+impl ToConst for U89 {
+    type Output = Const<89>;
+}
+
 impl ToUInt for Const<90> {
     type Output = U90;
 }
 
+impl ToConst for U90 {
+    type Output = Const<90>;
+}
+
 impl ToUInt for Const<91> {
     type Output = U91;
 }
 
+impl ToConst for U91 {
+    type Output = Const<91>;
+}
+
 impl ToUInt for Const<92> {
     type Output = U92;
 }
 
+impl ToConst for U92 {
+    type Output = Const<92>;
+}
+
 impl ToUInt for Const<93> {
     type Output = U93;
 }
 
+impl ToConst for U93 {
+    type Output = Const<93>;
+}
+
 impl ToUInt for Const<94> {
     type Output = U94;
 }
 
+impl ToConst for U94 {
+    type Output = Const<94>;
+}
+
 impl ToUInt for Const<95> {
     type Output = U95;
 }
 
+impl ToConst for U95 {
+    type Output = Const<95>;
+}
+
 impl ToUInt for Const<96> {
     type Output = U96;
 }
 
+impl ToConst for U96 {
+    type Output = Const<96>;
+}
+
 impl ToUInt for Const<97> {
     type Output = U97;
 }
 
+impl ToConst for U97 {
+    type Output = Const<97>;
+}
+
 impl ToUInt for Const<98> {
     type Output = U98;
 }
 
+impl ToConst for U98 {
+    type Output = Const<98>;
+}
+
 impl ToUInt for Const<99> {
     type Output = U99;
 }
 
+impl ToConst for U99 {
+    type Output = Const<99>;
+}
+
 impl ToUInt for Const<100> {
     type Output = U100;
 }
 
+impl ToConst for U100 {
+    type Output = Const<100>;
+}
+
 impl ToUInt for Const<101> {
     type Output = U101;
 }
 
+impl ToConst for U101 {
+    type Output = Const<101>;
+}
+
 impl ToUInt for Const<102> {
     type Output = U102;
 }
 
+impl ToConst for U102 {
+    type Output = Const<102>;
+}
+
 impl ToUInt for Const<103> {
     type Output = U103;
 }
 
+impl ToConst for U103 {
+    type Output = Const<103>;
+}
+
 impl ToUInt for Const<104> {
     type Output = U104;
 }
 
+impl ToConst for U104 {
+    type Output = Const<104>;
+}
+
 impl ToUInt for Const<105> {
     type Output = U105;
 }
 
+impl ToConst for U105 {
+    type Output = Const<105>;
+}
+
 impl ToUInt for Const<106> {
     type Output = U106;
 }
 
+impl ToConst for U106 {
+    type Output = Const<106>;
+}
+
 impl ToUInt for Const<107> {
     type Output = U107;
 }
 
+impl ToConst for U107 {
+    type Output = Const<107>;
+}
+
 impl ToUInt for Const<108> {
     type Output = U108;
 }
 
+impl ToConst for U108 {
+    type Output = Const<108>;
+}
+
 impl ToUInt for Const<109> {
     type Output = U109;
 }
 
+impl ToConst for U109 {
+    type Output = Const<109>;
+}
+
 impl ToUInt for Const<110> {
     type Output = U110;
 }
 
+impl ToConst for U110 {
+    type Output = Const<110>;
+}
+
 impl ToUInt for Const<111> {
     type Output = U111;
 }
 
+impl ToConst for U111 {
+    type Output = Const<111>;
+}
+
 impl ToUInt for Const<112> {
     type Output = U112;
 }
 
+impl ToConst for U112 {
+    type Output = Const<112>;
+}
+
 impl ToUInt for Const<113> {
     type Output = U113;
 }
 
+impl ToConst for U113 {
+    type Output = Const<113>;
+}
+
 impl ToUInt for Const<114> {
     type Output = U114;
 }
 
+impl ToConst for U114 {
+    type Output = Const<114>;
+}
+
 impl ToUInt for Const<115> {
     type Output = U115;
 }
 
+impl ToConst for U115 {
+    type Output = Const<115>;
+}
+
 impl ToUInt for Const<116> {
     type Output = U116;
 }
 
+impl ToConst for U116 {
+    type Output = Const<116>;
+}
+
 impl ToUInt for Const<117> {
     type Output = U117;
 }
 
+impl ToConst for U117 {
+    type Output = Const<117>;
+}
+
 impl ToUInt for Const<118> {
     type Output = U118;
 }
 
+impl ToConst for U118 {
+    type Output = Const<118>;
+}
+
 impl ToUInt for Const<119> {
     type Output = U119;
 }
 
+impl ToConst for U119 {
+    type Output = Const<119>;
+}
+
 impl ToUInt for Const<120> {
     type Output = U120;
 }
 
+impl ToConst for U120 {
+    type Output = Const<120>;
+}
+
 impl ToUInt for Const<121> {
     type Output = U121;
 }
 
+impl ToConst for U121 {
+    type Output = Const<121>;
+}
+
 impl ToUInt for Const<122> {
     type Output = U122;
 }
 
+impl ToConst for U122 {
+    type Output = Const<122>;
+}
+
 impl ToUInt for Const<123> {
     type Output = U123;
 }
 
+impl ToConst for U123 {
+    type Output = Const<123>;
+}
+
 impl ToUInt for Const<124> {
     type Output = U124;
 }
 
+impl ToConst for U124 {
+    type Output = Const<124>;
+}
+
 impl ToUInt for Const<125> {
     type Output = U125;
 }
 
+impl ToConst for U125 {
+    type Output = Const<125>;
+}
+
 impl ToUInt for Const<126> {
     type Output = U126;
 }
 
+impl ToConst for U126 {
+    type Output = Const<126>;
+}
+
 impl ToUInt for Const<127> {
     type Output = U127;
 }
 
+impl ToConst for U127 {
+    type Output = Const<127>;
+}
+
 impl ToUInt for Const<128> {
     type Output = U128;
 }
 
+impl ToConst for U128 {
+    type Output = Const<128>;
+}
+
 impl ToUInt for Const<129> {
     type Output = U129;
 }
 
+impl ToConst for U129 {
+    type Output = Const<129>;
+}
+
 impl ToUInt for Const<130> {
     type Output = U130;
 }
 
+impl ToConst for U130 {
+    type Output = Const<130>;
+}
+
 impl ToUInt for Const<131> {
     type Output = U131;
 }
 
+impl ToConst for U131 {
+    type Output = Const<131>;
+}
+
 impl ToUInt for Const<132> {
     type Output = U132;
 }
 
+impl ToConst for U132 {
+    type Output = Const<132>;
+}
+
 impl ToUInt for Const<133> {
     type Output = U133;
 }
 
+impl ToConst for U133 {
+    type Output = Const<133>;
+}
+
 impl ToUInt for Const<134> {
     type Output = U134;
 }
 
+impl ToConst for U134 {
+    type Output = Const<134>;
+}
+
 impl ToUInt for Const<135> {
     type Output = U135;
 }
 
+impl ToConst for U135 {
+    type Output = Const<135>;
+}
+
 impl ToUInt for Const<136> {
     type Output = U136;
 }
 
+impl ToConst for U136 {
+    type Output = Const<136>;
+}
+
 impl ToUInt for Const<137> {
     type Output = U137;
 }
 
+impl ToConst for U137 {
+    type Output = Const<137>;
+}
+
 impl ToUInt for Const<138> {
     type Output = U138;
 }
 
+impl ToConst for U138 {
+    type Output = Const<138>;
+}
+
 impl ToUInt for Const<139> {
     type Output = U139;
 }
 
+impl ToConst for U139 {
+    type Output = Const<139>;
+}
+
 impl ToUInt for Const<140> {
     type Output = U140;
 }
 
+impl ToConst for U140 {
+    type Output = Const<140>;
+}
+
 impl ToUInt for Const<141> {
     type Output = U141;
 }
 
+impl ToConst for U141 {
+    type Output = Const<141>;
+}
+
 impl ToUInt for Const<142> {
     type Output = U142;
 }
 
+impl ToConst for U142 {
+    type Output = Const<142>;
+}
+
 impl ToUInt for Const<143> {
     type Output = U143;
 }
 
+impl ToConst for U143 {
+    type Output = Const<143>;
+}
+
 impl ToUInt for Const<144> {
     type Output = U144;
 }
 
+impl ToConst for U144 {
+    type Output = Const<144>;
+}
+
 impl ToUInt for Const<145> {
     type Output = U145;
 }
 
+impl ToConst for U145 {
+    type Output = Const<145>;
+}
+
 impl ToUInt for Const<146> {
     type Output = U146;
 }
 
+impl ToConst for U146 {
+    type Output = Const<146>;
+}
+
 impl ToUInt for Const<147> {
     type Output = U147;
 }
 
+impl ToConst for U147 {
+    type Output = Const<147>;
+}
+
 impl ToUInt for Const<148> {
     type Output = U148;
 }
 
+impl ToConst for U148 {
+    type Output = Const<148>;
+}
+
 impl ToUInt for Const<149> {
     type Output = U149;
 }
 
+impl ToConst for U149 {
+    type Output = Const<149>;
+}
+
 impl ToUInt for Const<150> {
     type Output = U150;
 }
 
+impl ToConst for U150 {
+    type Output = Const<150>;
+}
+
 impl ToUInt for Const<151> {
     type Output = U151;
 }
 
+impl ToConst for U151 {
+    type Output = Const<151>;
+}
+
 impl ToUInt for Const<152> {
     type Output = U152;
 }
 
+impl ToConst for U152 {
+    type Output = Const<152>;
+}
+
 impl ToUInt for Const<153> {
     type Output = U153;
 }
 
+impl ToConst for U153 {
+    type Output = Const<153>;
+}
+
 impl ToUInt for Const<154> {
     type Output = U154;
 }
 
+impl ToConst for U154 {
+    type Output = Const<154>;
+}
+
 impl ToUInt for Const<155> {
     type Output = U155;
 }
 
+impl ToConst for U155 {
+    type Output = Const<155>;
+}
+
 impl ToUInt for Const<156> {
     type Output = U156;
 }
 
+impl ToConst for U156 {
+    type Output = Const<156>;
+}
+
 impl ToUInt for Const<157> {
     type Output = U157;
 }
 
+impl ToConst for U157 {
+    type Output = Const<157>;
+}
+
 impl ToUInt for Const<158> {
     type Output = U158;
 }
 
+impl ToConst for U158 {
+    type Output = Const<158>;
+}
+
 impl ToUInt for Const<159> {
     type Output = U159;
 }
 
+impl ToConst for U159 {
+    type Output = Const<159>;
+}
+
 impl ToUInt for Const<160> {
     type Output = U160;
 }
 
+impl ToConst for U160 {
+    type Output = Const<160>;
+}
+
 impl ToUInt for Const<161> {
     type Output = U161;
 }
 
+impl ToConst for U161 {
+    type Output = Const<161>;
+}
+
 impl ToUInt for Const<162> {
     type Output = U162;
 }
 
+impl ToConst for U162 {
+    type Output = Const<162>;
+}
+
 impl ToUInt for Const<163> {
     type Output = U163;
 }
 
+impl ToConst for U163 {
+    type Output = Const<163>;
+}
+
 impl ToUInt for Const<164> {
     type Output = U164;
 }
 
+impl ToConst for U164 {
+    type Output = Const<164>;
+}
+
 impl ToUInt for Const<165> {
     type Output = U165;
 }
 
+impl ToConst for U165 {
+    type Output = Const<165>;
+}
+
 impl ToUInt for Const<166> {
     type Output = U166;
 }
 
+impl ToConst for U166 {
+    type Output = Const<166>;
+}
+
 impl ToUInt for Const<167> {
     type Output = U167;
 }
 
+impl ToConst for U167 {
+    type Output = Const<167>;
+}
+
 impl ToUInt for Const<168> {
     type Output = U168;
 }
 
+impl ToConst for U168 {
+    type Output = Const<168>;
+}
+
 impl ToUInt for Const<169> {
     type Output = U169;
 }
 
+impl ToConst for U169 {
+    type Output = Const<169>;
+}
+
 impl ToUInt for Const<170> {
     type Output = U170;
 }
 
+impl ToConst for U170 {
+    type Output = Const<170>;
+}
+
 impl ToUInt for Const<171> {
     type Output = U171;
 }
 
+impl ToConst for U171 {
+    type Output = Const<171>;
+}
+
 impl ToUInt for Const<172> {
     type Output = U172;
 }
 
+impl ToConst for U172 {
+    type Output = Const<172>;
+}
+
 impl ToUInt for Const<173> {
     type Output = U173;
 }
 
+impl ToConst for U173 {
+    type Output = Const<173>;
+}
+
 impl ToUInt for Const<174> {
     type Output = U174;
 }
 
+impl ToConst for U174 {
+    type Output = Const<174>;
+}
+
 impl ToUInt for Const<175> {
     type Output = U175;
 }
 
+impl ToConst for U175 {
+    type Output = Const<175>;
+}
+
 impl ToUInt for Const<176> {
     type Output = U176;
 }
 
+impl ToConst for U176 {
+    type Output = Const<176>;
+}
+
 impl ToUInt for Const<177> {
     type Output = U177;
 }
 
+impl ToConst for U177 {
+    type Output = Const<177>;
+}
+
 impl ToUInt for Const<178> {
     type Output = U178;
 }
 
+impl ToConst for U178 {
+    type Output = Const<178>;
+}
+
 impl ToUInt for Const<179> {
     type Output = U179;
 }
 
+impl ToConst for U179 {
+    type Output = Const<179>;
+}
+
 impl ToUInt for Const<180> {
     type Output = U180;
 }
 
+impl ToConst for U180 {
+    type Output = Const<180>;
+}
+
 impl ToUInt for Const<181> {
     type Output = U181;
 }
 
+impl ToConst for U181 {
+    type Output = Const<181>;
+}
+
 impl ToUInt for Const<182> {
     type Output = U182;
 }
 
+impl ToConst for U182 {
+    type Output = Const<182>;
+}
+
 impl ToUInt for Const<183> {
     type Output = U183;
 }
 
+impl ToConst for U183 {
+    type Output = Const<183>;
+}
+
 impl ToUInt for Const<184> {
     type Output = U184;
 }
 
+impl ToConst for U184 {
+    type Output = Const<184>;
+}
+
 impl ToUInt for Const<185> {
     type Output = U185;
 }
 
+impl ToConst for U185 {
+    type Output = Const<185>;
+}
+
 impl ToUInt for Const<186> {
     type Output = U186;
 }
 
+impl ToConst for U186 {
+    type Output = Const<186>;
+}
+
 impl ToUInt for Const<187> {
     type Output = U187;
 }
 
+impl ToConst for U187 {
+    type Output = Const<187>;
+}
+
 impl ToUInt for Const<188> {
     type Output = U188;
 }
 
+impl ToConst for U188 {
+    type Output = Const<188>;
+}
+
 impl ToUInt for Const<189> {
     type Output = U189;
 }
 
+impl ToConst for U189 {
+    type Output = Const<189>;
+}
+
 impl ToUInt for Const<190> {
     type Output = U190;
 }
 
+impl ToConst for U190 {
+    type Output = Const<190>;
+}
+
 impl ToUInt for Const<191> {
     type Output = U191;
 }
 
+impl ToConst for U191 {
+    type Output = Const<191>;
+}
+
 impl ToUInt for Const<192> {
     type Output = U192;
 }
 
+impl ToConst for U192 {
+    type Output = Const<192>;
+}
+
 impl ToUInt for Const<193> {
     type Output = U193;
 }
 
+impl ToConst for U193 {
+    type Output = Const<193>;
+}
+
 impl ToUInt for Const<194> {
     type Output = U194;
 }
 
+impl ToConst for U194 {
+    type Output = Const<194>;
+}
+
 impl ToUInt for Const<195> {
     type Output = U195;
 }
 
+impl ToConst for U195 {
+    type Output = Const<195>;
+}
+
 impl ToUInt for Const<196> {
     type Output = U196;
 }
 
+impl ToConst for U196 {
+    type Output = Const<196>;
+}
+
 impl ToUInt for Const<197> {
     type Output = U197;
 }
 
+impl ToConst for U197 {
+    type Output = Const<197>;
+}
+
 impl ToUInt for Const<198> {
     type Output = U198;
 }
 
+impl ToConst for U198 {
+    type Output = Const<198>;
+}
+
 impl ToUInt for Const<199> {
     type Output = U199;
 }
 
+impl ToConst for U199 {
+    type Output = Const<199>;
+}
+
 impl ToUInt for Const<200> {
     type Output = U200;
 }
 
+impl ToConst for U200 {
+    type Output = Const<200>;
+}
+
 impl ToUInt for Const<201> {
     type Output = U201;
 }
 
+impl ToConst for U201 {
+    type Output = Const<201>;
+}
+
 impl ToUInt for Const<202> {
     type Output = U202;
 }
 
+impl ToConst for U202 {
+    type Output = Const<202>;
+}
+
 impl ToUInt for Const<203> {
     type Output = U203;
 }
 
+impl ToConst for U203 {
+    type Output = Const<203>;
+}
+
 impl ToUInt for Const<204> {
     type Output = U204;
 }
 
+impl ToConst for U204 {
+    type Output = Const<204>;
+}
+
 impl ToUInt for Const<205> {
     type Output = U205;
 }
 
+impl ToConst for U205 {
+    type Output = Const<205>;
+}
+
 impl ToUInt for Const<206> {
     type Output = U206;
 }
 
+impl ToConst for U206 {
+    type Output = Const<206>;
+}
+
 impl ToUInt for Const<207> {
     type Output = U207;
 }
 
+impl ToConst for U207 {
+    type Output = Const<207>;
+}
+
 impl ToUInt for Const<208> {
     type Output = U208;
 }
 
+impl ToConst for U208 {
+    type Output = Const<208>;
+}
+
 impl ToUInt for Const<209> {
     type Output = U209;
 }
 
+impl ToConst for U209 {
+    type Output = Const<209>;
+}
+
 impl ToUInt for Const<210> {
     type Output = U210;
 }
 
+impl ToConst for U210 {
+    type Output = Const<210>;
+}
+
 impl ToUInt for Const<211> {
     type Output = U211;
 }
 
+impl ToConst for U211 {
+    type Output = Const<211>;
+}
+
 impl ToUInt for Const<212> {
     type Output = U212;
 }
 
+impl ToConst for U212 {
+    type Output = Const<212>;
+}
+
 impl ToUInt for Const<213> {
     type Output = U213;
 }
 
+impl ToConst for U213 {
+    type Output = Const<213>;
+}
+
 impl ToUInt for Const<214> {
     type Output = U214;
 }
 
+impl ToConst for U214 {
+    type Output = Const<214>;
+}
+
 impl ToUInt for Const<215> {
     type Output = U215;
 }
 
+impl ToConst for U215 {
+    type Output = Const<215>;
+}
+
 impl ToUInt for Const<216> {
     type Output = U216;
 }
 
+impl ToConst for U216 {
+    type Output = Const<216>;
+}
+
 impl ToUInt for Const<217> {
     type Output = U217;
 }
 
+impl ToConst for U217 {
+    type Output = Const<217>;
+}
+
 impl ToUInt for Const<218> {
     type Output = U218;
 }
 
+impl ToConst for U218 {
+    type Output = Const<218>;
+}
+
 impl ToUInt for Const<219> {
     type Output = U219;
 }
 
+impl ToConst for U219 {
+    type Output = Const<219>;
+}
+
 impl ToUInt for Const<220> {
     type Output = U220;
 }
 
+impl ToConst for U220 {
+    type Output = Const<220>;
+}
+
 impl ToUInt for Const<221> {
     type Output = U221;
 }
 
+impl ToConst for U221 {
+    type Output = Const<221>;
+}
+
 impl ToUInt for Const<222> {
     type Output = U222;
 }
 
+impl ToConst for U222 {
+    type Output = Const<222>;
+}
+
 impl ToUInt for Const<223> {
     type Output = U223;
 }
 
+impl ToConst for U223 {
+    type Output = Const<223>;
+}
+
 impl ToUInt for Const<224> {
     type Output = U224;
 }
 
+impl ToConst for U224 {
+    type Output = Const<224>;
+}
+
 impl ToUInt for Const<225> {
     type Output = U225;
 }
 
+impl ToConst for U225 {
+    type Output = Const<225>;
+}
+
 impl ToUInt for Const<226> {
     type Output = U226;
 }
 
+impl ToConst for U226 {
+    type Output = Const<226>;
+}
+
 impl ToUInt for Const<227> {
     type Output = U227;
 }
 
+impl ToConst for U227 {
+    type Output = Const<227>;
+}
+
 impl ToUInt for Const<228> {
     type Output = U228;
 }
 
+impl ToConst for U228 {
+    type Output = Const<228>;
+}
+
 impl ToUInt for Const<229> {
     type Output = U229;
 }
 
+impl ToConst for U229 {
+    type Output = Const<229>;
+}
+
 impl ToUInt for Const<230> {
     type Output = U230;
 }
 
+impl ToConst for U230 {
+    type Output = Const<230>;
+}
+
 impl ToUInt for Const<231> {
     type Output = U231;
 }
 
+impl ToConst for U231 {
+    type Output = Const<231>;
+}
+
 impl ToUInt for Const<232> {
     type Output = U232;
 }
 
+impl ToConst for U232 {
+    type Output = Const<232>;
+}
+
 impl ToUInt for Const<233> {
     type Output = U233;
 }
 
+impl ToConst for U233 {
+    type Output = Const<233>;
+}
+
 impl ToUInt for Const<234> {
     type Output = U234;
 }
 
+impl ToConst for U234 {
+    type Output = Const<234>;
+}
+
 impl ToUInt for Const<235> {
     type Output = U235;
 }
 
+impl ToConst for U235 {
+    type Output = Const<235>;
+}
+
 impl ToUInt for Const<236> {
     type Output = U236;
 }
 
+impl ToConst for U236 {
+    type Output = Const<236>;
+}
+
 impl ToUInt for Const<237> {
     type Output = U237;
 }
 
+impl ToConst for U237 {
+    type Output = Const<237>;
+}
+
 impl ToUInt for Const<238> {
     type Output = U238;
 }
 
+impl ToConst for U238 {
+    type Output = Const<238>;
+}
+
 impl ToUInt for Const<239> {
     type Output = U239;
 }
 
+impl ToConst for U239 {
+    type Output = Const<239>;
+}
+
 impl ToUInt for Const<240> {
     type Output = U240;
 }
 
+impl ToConst for U240 {
+    type Output = Const<240>;
+}
+
 impl ToUInt for Const<241> {
     type Output = U241;
 }
 
+impl ToConst for U241 {
+    type Output = Const<241>;
+}
+
 impl ToUInt for Const<242> {
     type Output = U242;
 }
 
+impl ToConst for U242 {
+    type Output = Const<242>;
+}
+
 impl ToUInt for Const<243> {
     type Output = U243;
 }
 
+impl ToConst for U243 {
+    type Output = Const<243>;
+}
+
 impl ToUInt for Const<244> {
     type Output = U244;
 }
 
+impl ToConst for U244 {
+    type Output = Const<244>;
+}
+
 impl ToUInt for Const<245> {
     type Output = U245;
 }
 
+impl ToConst for U245 {
+    type Output = Const<245>;
+}
+
 impl ToUInt for Const<246> {
     type Output = U246;
 }
 
+impl ToConst for U246 {
+    type Output = Const<246>;
+}
+
 impl ToUInt for Const<247> {
     type Output = U247;
 }
 
+impl ToConst for U247 {
+    type Output = Const<247>;
+}
+
 impl ToUInt for Const<248> {
     type Output = U248;
 }
 
+impl ToConst for U248 {
+    type Output = Const<248>;
+}
+
 impl ToUInt for Const<249> {
     type Output = U249;
 }
 
+impl ToConst for U249 {
+    type Output = Const<249>;
+}
+
 impl ToUInt for Const<250> {
     type Output = U250;
 }
 
+impl ToConst for U250 {
+    type Output = Const<250>;
+}
+
 impl ToUInt for Const<251> {
     type Output = U251;
 }
 
+impl ToConst for U251 {
+    type Output = Const<251>;
+}
+
 impl ToUInt for Const<252> {
     type Output = U252;
 }
 
+impl ToConst for U252 {
+    type Output = Const<252>;
+}
+
 impl ToUInt for Const<253> {
     type Output = U253;
 }
 
+impl ToConst for U253 {
+    type Output = Const<253>;
+}
+
 impl ToUInt for Const<254> {
     type Output = U254;
 }
 
+impl ToConst for U254 {
+    type Output = Const<254>;
+}
+
 impl ToUInt for Const<255> {
     type Output = U255;
 }
 
+impl ToConst for U255 {
+    type Output = Const<255>;
+}
+
 impl ToUInt for Const<256> {
     type Output = U256;
 }
 
+impl ToConst for U256 {
+    type Output = Const<256>;
+}
+
 impl ToUInt for Const<257> {
     type Output = U257;
 }
 
+impl ToConst for U257 {
+    type Output = Const<257>;
+}
+
 impl ToUInt for Const<258> {
     type Output = U258;
 }
 
+impl ToConst for U258 {
+    type Output = Const<258>;
+}
+
 impl ToUInt for Const<259> {
     type Output = U259;
 }
 
+impl ToConst for U259 {
+    type Output = Const<259>;
+}
+
 impl ToUInt for Const<260> {
     type Output = U260;
 }
 
+impl ToConst for U260 {
+    type Output = Const<260>;
+}
+
 impl ToUInt for Const<261> {
     type Output = U261;
 }
 
+impl ToConst for U261 {
+    type Output = Const<261>;
+}
+
 impl ToUInt for Const<262> {
     type Output = U262;
 }
 
+impl ToConst for U262 {
+    type Output = Const<262>;
+}
+
 impl ToUInt for Const<263> {
     type Output = U263;
 }
 
+impl ToConst for U263 {
+    type Output = Const<263>;
+}
+
 impl ToUInt for Const<264> {
     type Output = U264;
 }
 
+impl ToConst for U264 {
+    type Output = Const<264>;
+}
+
 impl ToUInt for Const<265> {
     type Output = U265;
 }
 
+impl ToConst for U265 {
+    type Output = Const<265>;
+}
+
 impl ToUInt for Const<266> {
     type Output = U266;
 }
 
+impl ToConst for U266 {
+    type Output = Const<266>;
+}
+
 impl ToUInt for Const<267> {
     type Output = U267;
 }
 
+impl ToConst for U267 {
+    type Output = Const<267>;
+}
+
 impl ToUInt for Const<268> {
     type Output = U268;
 }
 
+impl ToConst for U268 {
+    type Output = Const<268>;
+}
+
 impl ToUInt for Const<269> {
     type Output = U269;
 }
 
+impl ToConst for U269 {
+    type Output = Const<269>;
+}
+
 impl ToUInt for Const<270> {
     type Output = U270;
 }
 
+impl ToConst for U270 {
+    type Output = Const<270>;
+}
+
 impl ToUInt for Const<271> {
     type Output = U271;
 }
 
+impl ToConst for U271 {
+    type Output = Const<271>;
+}
+
 impl ToUInt for Const<272> {
     type Output = U272;
 }
 
+impl ToConst for U272 {
+    type Output = Const<272>;
+}
+
 impl ToUInt for Const<273> {
     type Output = U273;
 }
 
+impl ToConst for U273 {
+    type Output = Const<273>;
+}
+
 impl ToUInt for Const<274> {
     type Output = U274;
 }
 
+impl ToConst for U274 {
+    type Output = Const<274>;
+}
+
 impl ToUInt for Const<275> {
     type Output = U275;
 }
 
+impl ToConst for U275 {
+    type Output = Const<275>;
+}
+
 impl ToUInt for Const<276> {
     type Output = U276;
 }
 
+impl ToConst for U276 {
+    type Output = Const<276>;
+}
+
 impl ToUInt for Const<277> {
     type Output = U277;
 }
 
+impl ToConst for U277 {
+    type Output = Const<277>;
+}
+
 impl ToUInt for Const<278> {
     type Output = U278;
 }
 
+impl ToConst for U278 {
+    type Output = Const<278>;
+}
+
 impl ToUInt for Const<279> {
     type Output = U279;
 }
 
+impl ToConst for U279 {
+    type Output = Const<279>;
+}
+
 impl ToUInt for Const<280> {
     type Output = U280;
 }
 
+impl ToConst for U280 {
+    type Output = Const<280>;
+}
+
 impl ToUInt for Const<281> {
     type Output = U281;
 }
 
+impl ToConst for U281 {
+    type Output = Const<281>;
+}
+
 impl ToUInt for Const<282> {
     type Output = U282;
 }
 
+impl ToConst for U282 {
+    type Output = Const<282>;
+}
+
 impl ToUInt for Const<283> {
     type Output = U283;
 }
 
+impl ToConst for U283 {
+    type Output = Const<283>;
+}
+
 impl ToUInt for Const<284> {
     type Output = U284;
 }
 
+impl ToConst for U284 {
+    type Output = Const<284>;
+}
+
 impl ToUInt for Const<285> {
     type Output = U285;
 }
 
+impl ToConst for U285 {
+    type Output = Const<285>;
+}
+
 impl ToUInt for Const<286> {
     type Output = U286;
 }
 
+impl ToConst for U286 {
+    type Output = Const<286>;
+}
+
 impl ToUInt for Const<287> {
     type Output = U287;
 }
 
+impl ToConst for U287 {
+    type Output = Const<287>;
+}
+
 impl ToUInt for Const<288> {
     type Output = U288;
 }
 
+impl ToConst for U288 {
+    type Output = Const<288>;
+}
+
 impl ToUInt for Const<289> {
     type Output = U289;
 }
 
+impl ToConst for U289 {
+    type Output = Const<289>;
+}
+
 impl ToUInt for Const<290> {
     type Output = U290;
 }
 
+impl ToConst for U290 {
+    type Output = Const<290>;
+}
+
 impl ToUInt for Const<291> {
     type Output = U291;
 }
 
+impl ToConst for U291 {
+    type Output = Const<291>;
+}
+
 impl ToUInt for Const<292> {
     type Output = U292;
 }
 
+impl ToConst for U292 {
+    type Output = Const<292>;
+}
+
 impl ToUInt for Const<293> {
     type Output = U293;
 }
 
+impl ToConst for U293 {
+    type Output = Const<293>;
+}
+
 impl ToUInt for Const<294> {
     type Output = U294;
 }
 
+impl ToConst for U294 {
+    type Output = Const<294>;
+}
+
 impl ToUInt for Const<295> {
     type Output = U295;
 }
 
+impl ToConst for U295 {
+    type Output = Const<295>;
+}
+
 impl ToUInt for Const<296> {
     type Output = U296;
 }
 
+impl ToConst for U296 {
+    type Output = Const<296>;
+}
+
 impl ToUInt for Const<297> {
     type Output = U297;
 }
 
+impl ToConst for U297 {
+    type Output = Const<297>;
+}
+
 impl ToUInt for Const<298> {
     type Output = U298;
 }
 
+impl ToConst for U298 {
+    type Output = Const<298>;
+}
+
 impl ToUInt for Const<299> {
     type Output = U299;
 }
 
+impl ToConst for U299 {
+    type Output = Const<299>;
+}
+
 impl ToUInt for Const<300> {
     type Output = U300;
 }
 
+impl ToConst for U300 {
+    type Output = Const<300>;
+}
+
 impl ToUInt for Const<301> {
     type Output = U301;
 }
 
+impl ToConst for U301 {
+    type Output = Const<301>;
+}
+
 impl ToUInt for Const<302> {
     type Output = U302;
 }
 
+impl ToConst for U302 {
+    type Output = Const<302>;
+}
+
 impl ToUInt for Const<303> {
     type Output = U303;
 }
 
+impl ToConst for U303 {
+    type Output = Const<303>;
+}
+
 impl ToUInt for Const<304> {
     type Output = U304;
 }
 
+impl ToConst for U304 {
+    type Output = Const<304>;
+}
+
 impl ToUInt for Const<305> {
     type Output = U305;
 }
 
+impl ToConst for U305 {
+    type Output = Const<305>;
+}
+
 impl ToUInt for Const<306> {
     type Output = U306;
 }
 
+impl ToConst for U306 {
+    type Output = Const<306>;
+}
+
 impl ToUInt for Const<307> {
     type Output = U307;
 }
 
+impl ToConst for U307 {
+    type Output = Const<307>;
+}
+
 impl ToUInt for Const<308> {
     type Output = U308;
 }
 
+impl ToConst for U308 {
+    type Output = Const<308>;
+}
+
 impl ToUInt for Const<309> {
     type Output = U309;
 }
 
+impl ToConst for U309 {
+    type Output = Const<309>;
+}
+
 impl ToUInt for Const<310> {
     type Output = U310;
 }
 
+impl ToConst for U310 {
+    type Output = Const<310>;
+}
+
 impl ToUInt for Const<311> {
     type Output = U311;
 }
 
+impl ToConst for U311 {
+    type Output = Const<311>;
+}
+
 impl ToUInt for Const<312> {
     type Output = U312;
 }
 
+impl ToConst for U312 {
+    type Output = Const<312>;
+}
+
 impl ToUInt for Const<313> {
     type Output = U313;
 }
 
+impl ToConst for U313 {
+    type Output = Const<313>;
+}
+
 impl ToUInt for Const<314> {
     type Output = U314;
 }
 
+impl ToConst for U314 {
+    type Output = Const<314>;
+}
+
 impl ToUInt for Const<315> {
     type Output = U315;
 }
 
+impl ToConst for U315 {
+    type Output = Const<315>;
+}
+
 impl ToUInt for Const<316> {
     type Output = U316;
 }
 
+impl ToConst for U316 {
+    type Output = Const<316>;
+}
+
 impl ToUInt for Const<317> {
     type Output = U317;
 }
 
+impl ToConst for U317 {
+    type Output = Const<317>;
+}
+
 impl ToUInt for Const<318> {
     type Output = U318;
 }
 
+impl ToConst for U318 {
+    type Output = Const<318>;
+}
+
 impl ToUInt for Const<319> {
     type Output = U319;
 }
 
+impl ToConst for U319 {
+    type Output = Const<319>;
+}
+
 impl ToUInt for Const<320> {
     type Output = U320;
 }
 
+impl ToConst for U320 {
+    type Output = Const<320>;
+}
+
 impl ToUInt for Const<321> {
     type Output = U321;
 }
 
+impl ToConst for U321 {
+    type Output = Const<321>;
+}
+
 impl ToUInt for Const<322> {
     type Output = U322;
 }
 
+impl ToConst for U322 {
+    type Output = Const<322>;
+}
+
 impl ToUInt for Const<323> {
     type Output = U323;
 }
 
+impl ToConst for U323 {
+    type Output = Const<323>;
+}
+
 impl ToUInt for Const<324> {
     type Output = U324;
 }
 
+impl ToConst for U324 {
+    type Output = Const<324>;
+}
+
 impl ToUInt for Const<325> {
     type Output = U325;
 }
 
+impl ToConst for U325 {
+    type Output = Const<325>;
+}
+
 impl ToUInt for Const<326> {
     type Output = U326;
 }
 
+impl ToConst for U326 {
+    type Output = Const<326>;
+}
+
 impl ToUInt for Const<327> {
     type Output = U327;
 }
 
+impl ToConst for U327 {
+    type Output = Const<327>;
+}
+
 impl ToUInt for Const<328> {
     type Output = U328;
 }
 
+impl ToConst for U328 {
+    type Output = Const<328>;
+}
+
 impl ToUInt for Const<329> {
     type Output = U329;
 }
 
+impl ToConst for U329 {
+    type Output = Const<329>;
+}
+
 impl ToUInt for Const<330> {
     type Output = U330;
 }
 
+impl ToConst for U330 {
+    type Output = Const<330>;
+}
+
 impl ToUInt for Const<331> {
     type Output = U331;
 }
 
+impl ToConst for U331 {
+    type Output = Const<331>;
+}
+
 impl ToUInt for Const<332> {
     type Output = U332;
 }
 
+impl ToConst for U332 {
+    type Output = Const<332>;
+}
+
 impl ToUInt for Const<333> {
     type Output = U333;
 }
 
+impl ToConst for U333 {
+    type Output = Const<333>;
+}
+
 impl ToUInt for Const<334> {
     type Output = U334;
 }
 
+impl ToConst for U334 {
+    type Output = Const<334>;
+}
+
 impl ToUInt for Const<335> {
     type Output = U335;
 }
 
+impl ToConst for U335 {
+    type Output = Const<335>;
+}
+
 impl ToUInt for Const<336> {
     type Output = U336;
 }
 
+impl ToConst for U336 {
+    type Output = Const<336>;
+}
+
 impl ToUInt for Const<337> {
     type Output = U337;
 }
 
+impl ToConst for U337 {
+    type Output = Const<337>;
+}
+
 impl ToUInt for Const<338> {
     type Output = U338;
 }
 
+impl ToConst for U338 {
+    type Output = Const<338>;
+}
+
 impl ToUInt for Const<339> {
     type Output = U339;
 }
 
+impl ToConst for U339 {
+    type Output = Const<339>;
+}
+
 impl ToUInt for Const<340> {
     type Output = U340;
 }
 
+impl ToConst for U340 {
+    type Output = Const<340>;
+}
+
 impl ToUInt for Const<341> {
     type Output = U341;
 }
 
+impl ToConst for U341 {
+    type Output = Const<341>;
+}
+
 impl ToUInt for Const<342> {
     type Output = U342;
 }
 
+impl ToConst for U342 {
+    type Output = Const<342>;
+}
+
 impl ToUInt for Const<343> {
     type Output = U343;
 }
 
+impl ToConst for U343 {
+    type Output = Const<343>;
+}
+
 impl ToUInt for Const<344> {
     type Output = U344;
 }
 
+impl ToConst for U344 {
+    type Output = Const<344>;
+}
+
 impl ToUInt for Const<345> {
     type Output = U345;
 }
 
+impl ToConst for U345 {
+    type Output = Const<345>;
+}
+
 impl ToUInt for Const<346> {
     type Output = U346;
 }
 
+impl ToConst for U346 {
+    type Output = Const<346>;
+}
+
 impl ToUInt for Const<347> {
     type Output = U347;
 }
 
+impl ToConst for U347 {
+    type Output = Const<347>;
+}
+
 impl ToUInt for Const<348> {
     type Output = U348;
 }
 
+impl ToConst for U348 {
+    type Output = Const<348>;
+}
+
 impl ToUInt for Const<349> {
     type Output = U349;
 }
 
+impl ToConst for U349 {
+    type Output = Const<349>;
+}
+
 impl ToUInt for Const<350> {
     type Output = U350;
 }
 
+impl ToConst for U350 {
+    type Output = Const<350>;
+}
+
 impl ToUInt for Const<351> {
     type Output = U351;
 }
 
+impl ToConst for U351 {
+    type Output = Const<351>;
+}
+
 impl ToUInt for Const<352> {
     type Output = U352;
 }
 
+impl ToConst for U352 {
+    type Output = Const<352>;
+}
+
 impl ToUInt for Const<353> {
     type Output = U353;
 }
 
+impl ToConst for U353 {
+    type Output = Const<353>;
+}
+
 impl ToUInt for Const<354> {
     type Output = U354;
 }
 
+impl ToConst for U354 {
+    type Output = Const<354>;
+}
+
 impl ToUInt for Const<355> {
     type Output = U355;
 }
 
+impl ToConst for U355 {
+    type Output = Const<355>;
+}
+
 impl ToUInt for Const<356> {
     type Output = U356;
 }
 
+impl ToConst for U356 {
+    type Output = Const<356>;
+}
+
 impl ToUInt for Const<357> {
     type Output = U357;
 }
 
+impl ToConst for U357 {
+    type Output = Const<357>;
+}
+
 impl ToUInt for Const<358> {
     type Output = U358;
 }
 
+impl ToConst for U358 {
+    type Output = Const<358>;
+}
+
 impl ToUInt for Const<359> {
     type Output = U359;
 }
 
+impl ToConst for U359 {
+    type Output = Const<359>;
+}
+
 impl ToUInt for Const<360> {
     type Output = U360;
 }
 
+impl ToConst for U360 {
+    type Output = Const<360>;
+}
+
 impl ToUInt for Const<361> {
     type Output = U361;
 }
 
+impl ToConst for U361 {
+    type Output = Const<361>;
+}
+
 impl ToUInt for Const<362> {
     type Output = U362;
 }
 
+impl ToConst for U362 {
+    type Output = Const<362>;
+}
+
 impl ToUInt for Const<363> {
     type Output = U363;
 }
 
+impl ToConst for U363 {
+    type Output = Const<363>;
+}
+
 impl ToUInt for Const<364> {
     type Output = U364;
 }
 
+impl ToConst for U364 {
+    type Output = Const<364>;
+}
+
 impl ToUInt for Const<365> {
     type Output = U365;
 }
 
+impl ToConst for U365 {
+    type Output = Const<365>;
+}
+
 impl ToUInt for Const<366> {
     type Output = U366;
 }
 
+impl ToConst for U366 {
+    type Output = Const<366>;
+}
+
 impl ToUInt for Const<367> {
     type Output = U367;
 }
 
+impl ToConst for U367 {
+    type Output = Const<367>;
+}
+
 impl ToUInt for Const<368> {
     type Output = U368;
 }
 
+impl ToConst for U368 {
+    type Output = Const<368>;
+}
+
 impl ToUInt for Const<369> {
     type Output = U369;
 }
 
+impl ToConst for U369 {
+    type Output = Const<369>;
+}
+
 impl ToUInt for Const<370> {
     type Output = U370;
 }
 
+impl ToConst for U370 {
+    type Output = Const<370>;
+}
+
 impl ToUInt for Const<371> {
     type Output = U371;
 }
 
+impl ToConst for U371 {
+    type Output = Const<371>;
+}
+
 impl ToUInt for Const<372> {
     type Output = U372;
 }
 
+impl ToConst for U372 {
+    type Output = Const<372>;
+}
+
 impl ToUInt for Const<373> {
     type Output = U373;
 }
 
+impl ToConst for U373 {
+    type Output = Const<373>;
+}
+
 impl ToUInt for Const<374> {
     type Output = U374;
 }
 
+impl ToConst for U374 {
+    type Output = Const<374>;
+}
+
 impl ToUInt for Const<375> {
     type Output = U375;
 }
 
+impl ToConst for U375 {
+    type Output = Const<375>;
+}
+
 impl ToUInt for Const<376> {
     type Output = U376;
 }
 
+impl ToConst for U376 {
+    type Output = Const<376>;
+}
+
 impl ToUInt for Const<377> {
     type Output = U377;
 }
 
+impl ToConst for U377 {
+    type Output = Const<377>;
+}
+
 impl ToUInt for Const<378> {
     type Output = U378;
 }
 
+impl ToConst for U378 {
+    type Output = Const<378>;
+}
+
 impl ToUInt for Const<379> {
     type Output = U379;
 }
 
+impl ToConst for U379 {
+    type Output = Const<379>;
+}
+
 impl ToUInt for Const<380> {
     type Output = U380;
 }
 
+impl ToConst for U380 {
+    type Output = Const<380>;
+}
+
 impl ToUInt for Const<381> {
     type Output = U381;
 }
 
+impl ToConst for U381 {
+    type Output = Const<381>;
+}
+
 impl ToUInt for Const<382> {
     type Output = U382;
 }
 
+impl ToConst for U382 {
+    type Output = Const<382>;
+}
+
 impl ToUInt for Const<383> {
     type Output = U383;
 }
 
+impl ToConst for U383 {
+    type Output = Const<383>;
+}
+
 impl ToUInt for Const<384> {
     type Output = U384;
 }
 
+impl ToConst for U384 {
+    type Output = Const<384>;
+}
+
 impl ToUInt for Const<385> {
     type Output = U385;
 }
 
+impl ToConst for U385 {
+    type Output = Const<385>;
+}
+
 impl ToUInt for Const<386> {
     type Output = U386;
 }
 
+impl ToConst for U386 {
+    type Output = Const<386>;
+}
+
 impl ToUInt for Const<387> {
     type Output = U387;
 }
 
+impl ToConst for U387 {
+    type Output = Const<387>;
+}
+
 impl ToUInt for Const<388> {
     type Output = U388;
 }
 
+impl ToConst for U388 {
+    type Output = Const<388>;
+}
+
 impl ToUInt for Const<389> {
     type Output = U389;
 }
 
+impl ToConst for U389 {
+    type Output = Const<389>;
+}
+
 impl ToUInt for Const<390> {
     type Output = U390;
 }
 
+impl ToConst for U390 {
+    type Output = Const<390>;
+}
+
 impl ToUInt for Const<391> {
     type Output = U391;
 }
 
+impl ToConst for U391 {
+    type Output = Const<391>;
+}
+
 impl ToUInt for Const<392> {
     type Output = U392;
 }
 
+impl ToConst for U392 {
+    type Output = Const<392>;
+}
+
 impl ToUInt for Const<393> {
     type Output = U393;
 }
 
+impl ToConst for U393 {
+    type Output = Const<393>;
+}
+
 impl ToUInt for Const<394> {
     type Output = U394;
 }
 
+impl ToConst for U394 {
+    type Output = Const<394>;
+}
+
 impl ToUInt for Const<395> {
     type Output = U395;
 }
 
+impl ToConst for U395 {
+    type Output = Const<395>;
+}
+
 impl ToUInt for Const<396> {
     type Output = U396;
 }
 
+impl ToConst for U396 {
+    type Output = Const<396>;
+}
+
 impl ToUInt for Const<397> {
     type Output = U397;
 }
 
+impl ToConst for U397 {
+    type Output = Const<397>;
+}
+
 impl ToUInt for Const<398> {
     type Output = U398;
 }
 
+impl ToConst for U398 {
+    type Output = Const<398>;
+}
+
 impl ToUInt for Const<399> {
     type Output = U399;
 }
 
+impl ToConst for U399 {
+    type Output = Const<399>;
+}
+
 impl ToUInt for Const<400> {
     type Output = U400;
 }
 
+impl ToConst for U400 {
+    type Output = Const<400>;
+}
+
 impl ToUInt for Const<401> {
     type Output = U401;
 }
 
+impl ToConst for U401 {
+    type Output = Const<401>;
+}
+
 impl ToUInt for Const<402> {
     type Output = U402;
 }
 
+impl ToConst for U402 {
+    type Output = Const<402>;
+}
+
 impl ToUInt for Const<403> {
     type Output = U403;
 }
 
+impl ToConst for U403 {
+    type Output = Const<403>;
+}
+
 impl ToUInt for Const<404> {
     type Output = U404;
 }
 
+impl ToConst for U404 {
+    type Output = Const<404>;
+}
+
 impl ToUInt for Const<405> {
     type Output = U405;
 }
 
+impl ToConst for U405 {
+    type Output = Const<405>;
+}
+
 impl ToUInt for Const<406> {
     type Output = U406;
 }
 
+impl ToConst for U406 {
+    type Output = Const<406>;
+}
+
 impl ToUInt for Const<407> {
     type Output = U407;
 }
 
+impl ToConst for U407 {
+    type Output = Const<407>;
+}
+
 impl ToUInt for Const<408> {
     type Output = U408;
 }
 
+impl ToConst for U408 {
+    type Output = Const<408>;
+}
+
 impl ToUInt for Const<409> {
     type Output = U409;
 }
 
+impl ToConst for U409 {
+    type Output = Const<409>;
+}
+
 impl ToUInt for Const<410> {
     type Output = U410;
 }
 
+impl ToConst for U410 {
+    type Output = Const<410>;
+}
+
 impl ToUInt for Const<411> {
     type Output = U411;
 }
 
+impl ToConst for U411 {
+    type Output = Const<411>;
+}
+
 impl ToUInt for Const<412> {
     type Output = U412;
 }
 
+impl ToConst for U412 {
+    type Output = Const<412>;
+}
+
 impl ToUInt for Const<413> {
     type Output = U413;
 }
 
+impl ToConst for U413 {
+    type Output = Const<413>;
+}
+
 impl ToUInt for Const<414> {
     type Output = U414;
 }
 
+impl ToConst for U414 {
+    type Output = Const<414>;
+}
+
 impl ToUInt for Const<415> {
     type Output = U415;
 }
 
+impl ToConst for U415 {
+    type Output = Const<415>;
+}
+
 impl ToUInt for Const<416> {
     type Output = U416;
 }
 
+impl ToConst for U416 {
+    type Output = Const<416>;
+}
+
 impl ToUInt for Const<417> {
     type Output = U417;
 }
 
+impl ToConst for U417 {
+    type Output = Const<417>;
+}
+
 impl ToUInt for Const<418> {
     type Output = U418;
 }
 
+impl ToConst for U418 {
+    type Output = Const<418>;
+}
+
 impl ToUInt for Const<419> {
     type Output = U419;
 }
 
+impl ToConst for U419 {
+    type Output = Const<419>;
+}
+
 impl ToUInt for Const<420> {
     type Output = U420;
 }
 
+impl ToConst for U420 {
+    type Output = Const<420>;
+}
+
 impl ToUInt for Const<421> {
     type Output = U421;
 }
 
+impl ToConst for U421 {
+    type Output = Const<421>;
+}
+
 impl ToUInt for Const<422> {
     type Output = U422;
 }
 
+impl ToConst for U422 {
+    type Output = Const<422>;
+}
+
 impl ToUInt for Const<423> {
     type Output = U423;
 }
 
+impl ToConst for U423 {
+    type Output = Const<423>;
+}
+
 impl ToUInt for Const<424> {
     type Output = U424;
 }
 
+impl ToConst for U424 {
+    type Output = Const<424>;
+}
+
 impl ToUInt for Const<425> {
     type Output = U425;
 }
 
+impl ToConst for U425 {
+    type Output = Const<425>;
+}
+
 impl ToUInt for Const<426> {
     type Output = U426;
 }
 
+impl ToConst for U426 {
+    type Output = Const<426>;
+}
+
 impl ToUInt for Const<427> {
     type Output = U427;
 }
 
+impl ToConst for U427 {
+    type Output = Const<427>;
+}
+
 impl ToUInt for Const<428> {
     type Output = U428;
 }
 
+impl ToConst for U428 {
+    type Output = Const<428>;
+}
+
 impl ToUInt for Const<429> {
     type Output = U429;
 }
 
+impl ToConst for U429 {
+    type Output = Const<429>;
+}
+
 impl ToUInt for Const<430> {
     type Output = U430;
 }
 
+impl ToConst for U430 {
+    type Output = Const<430>;
+}
+
 impl ToUInt for Const<431> {
     type Output = U431;
 }
 
+impl ToConst for U431 {
+    type Output = Const<431>;
+}
+
 impl ToUInt for Const<432> {
     type Output = U432;
 }
 
+impl ToConst for U432 {
+    type Output = Const<432>;
+}
+
 impl ToUInt for Const<433> {
     type Output = U433;
 }
 
+impl ToConst for U433 {
+    type Output = Const<433>;
+}
+
 impl ToUInt for Const<434> {
     type Output = U434;
 }
 
+impl ToConst for U434 {
+    type Output = Const<434>;
+}
+
 impl ToUInt for Const<435> {
     type Output = U435;
 }
 
+impl ToConst for U435 {
+    type Output = Const<435>;
+}
+
 impl ToUInt for Const<436> {
     type Output = U436;
 }
 
+impl ToConst for U436 {
+    type Output = Const<436>;
+}
+
 impl ToUInt for Const<437> {
     type Output = U437;
 }
 
+impl ToConst for U437 {
+    type Output = Const<437>;
+}
+
 impl ToUInt for Const<438> {
     type Output = U438;
 }
 
+impl ToConst for U438 {
+    type Output = Const<438>;
+}
+
 impl ToUInt for Const<439> {
     type Output = U439;
 }
 
+impl ToConst for U439 {
+    type Output = Const<439>;
+}
+
 impl ToUInt for Const<440> {
     type Output = U440;
 }
 
+impl ToConst for U440 {
+    type Output = Const<440>;
+}
+
 impl ToUInt for Const<441> {
     type Output = U441;
 }
 
+impl ToConst for U441 {
+    type Output = Const<441>;
+}
+
 impl ToUInt for Const<442> {
     type Output = U442;
 }
 
+impl ToConst for U442 {
+    type Output = Const<442>;
+}
+
 impl ToUInt for Const<443> {
     type Output = U443;
 }
 
+impl ToConst for U443 {
+    type Output = Const<443>;
+}
+
 impl ToUInt for Const<444> {
     type Output = U444;
 }
 
+impl ToConst for U444 {
+    type Output = Const<444>;
+}
+
 impl ToUInt for Const<445> {
     type Output = U445;
 }
 
+impl ToConst for U445 {
+    type Output = Const<445>;
+}
+
 impl ToUInt for Const<446> {
     type Output = U446;
 }
 
+impl ToConst for U446 {
+    type Output = Const<446>;
+}
+
 impl ToUInt for Const<447> {
     type Output = U447;
 }
 
+impl ToConst for U447 {
+    type Output = Const<447>;
+}
+
 impl ToUInt for Const<448> {
     type Output = U448;
 }
 
+impl ToConst for U448 {
+    type Output = Const<448>;
+}
+
 impl ToUInt for Const<449> {
     type Output = U449;
 }
 
+impl ToConst for U449 {
+    type Output = Const<449>;
+}
+
 impl ToUInt for Const<450> {
     type Output = U450;
 }
 
+impl ToConst for U450 {
+    type Output = Const<450>;
+}
+
 impl ToUInt for Const<451> {
     type Output = U451;
 }
 
+impl ToConst for U451 {
+    type Output = Const<451>;
+}
+
 impl ToUInt for Const<452> {
     type Output = U452;
 }
 
+impl ToConst for U452 {
+    type Output = Const<452>;
+}
+
 impl ToUInt for Const<453> {
     type Output = U453;
 }
 
+impl ToConst for U453 {
+    type Output = Const<453>;
+}
+
 impl ToUInt for Const<454> {
     type Output = U454;
 }
 
+impl ToConst for U454 {
+    type Output = Const<454>;
+}
+
 impl ToUInt for Const<455> {
     type Output = U455;
 }
 
+impl ToConst for U455 {
+    type Output = Const<455>;
+}
+
 impl ToUInt for Const<456> {
     type Output = U456;
 }
 
+impl ToConst for U456 {
+    type Output = Const<456>;
+}
+
 impl ToUInt for Const<457> {
     type Output = U457;
 }
 
+impl ToConst for U457 {
+    type Output = Const<457>;
+}
+
 impl ToUInt for Const<458> {
     type Output = U458;
 }
 
+impl ToConst for U458 {
+    type Output = Const<458>;
+}
+
 impl ToUInt for Const<459> {
     type Output = U459;
 }
 
+impl ToConst for U459 {
+    type Output = Const<459>;
+}
+
 impl ToUInt for Const<460> {
     type Output = U460;
 }
 
+impl ToConst for U460 {
+    type Output = Const<460>;
+}
+
 impl ToUInt for Const<461> {
     type Output = U461;
 }
 
+impl ToConst for U461 {
+    type Output = Const<461>;
+}
+
 impl ToUInt for Const<462> {
     type Output = U462;
 }
 
+impl ToConst for U462 {
+    type Output = Const<462>;
+}
+
 impl ToUInt for Const<463> {
     type Output = U463;
 }
 
+impl ToConst for U463 {
+    type Output = Const<463>;
+}
+
 impl ToUInt for Const<464> {
     type Output = U464;
 }
 
+impl ToConst for U464 {
+    type Output = Const<464>;
+}
+
 impl ToUInt for Const<465> {
     type Output = U465;
 }
 
+impl ToConst for U465 {
+    type Output = Const<465>;
+}
+
 impl ToUInt for Const<466> {
     type Output = U466;
 }
 
+impl ToConst for U466 {
+    type Output = Const<466>;
+}
+
 impl ToUInt for Const<467> {
     type Output = U467;
 }
 
+impl ToConst for U467 {
+    type Output = Const<467>;
+}
+
 impl ToUInt for Const<468> {
     type Output = U468;
 }
 
+impl ToConst for U468 {
+    type Output = Const<468>;
+}
+
 impl ToUInt for Const<469> {
     type Output = U469;
 }
 
+impl ToConst for U469 {
+    type Output = Const<469>;
+}
+
 impl ToUInt for Const<470> {
     type Output = U470;
 }
 
+impl ToConst for U470 {
+    type Output = Const<470>;
+}
+
 impl ToUInt for Const<471> {
     type Output = U471;
 }
 
+impl ToConst for U471 {
+    type Output = Const<471>;
+}
+
 impl ToUInt for Const<472> {
     type Output = U472;
 }
 
+impl ToConst for U472 {
+    type Output = Const<472>;
+}
+
 impl ToUInt for Const<473> {
     type Output = U473;
 }
 
+impl ToConst for U473 {
+    type Output = Const<473>;
+}
+
 impl ToUInt for Const<474> {
     type Output = U474;
 }
 
+impl ToConst for U474 {
+    type Output = Const<474>;
+}
+
 impl ToUInt for Const<475> {
     type Output = U475;
 }
 
+impl ToConst for U475 {
+    type Output = Const<475>;
+}
+
 impl ToUInt for Const<476> {
     type Output = U476;
 }
 
+impl ToConst for U476 {
+    type Output = Const<476>;
+}
+
 impl ToUInt for Const<477> {
     type Output = U477;
 }
 
+impl ToConst for U477 {
+    type Output = Const<477>;
+}
+
 impl ToUInt for Const<478> {
     type Output = U478;
 }
 
+impl ToConst for U478 {
+    type Output = Const<478>;
+}
+
 impl ToUInt for Const<479> {
     type Output = U479;
 }
 
+impl ToConst for U479 {
+    type Output = Const<479>;
+}
+
 impl ToUInt for Const<480> {
     type Output = U480;
 }
 
+impl ToConst for U480 {
+    type Output = Const<480>;
+}
+
 impl ToUInt for Const<481> {
     type Output = U481;
 }
 
+impl ToConst for U481 {
+    type Output = Const<481>;
+}
+
 impl ToUInt for Const<482> {
     type Output = U482;
 }
 
+impl ToConst for U482 {
+    type Output = Const<482>;
+}
+
 impl ToUInt for Const<483> {
     type Output = U483;
 }
 
+impl ToConst for U483 {
+    type Output = Const<483>;
+}
+
 impl ToUInt for Const<484> {
     type Output = U484;
 }
 
+impl ToConst for U484 {
+    type Output = Const<484>;
+}
+
 impl ToUInt for Const<485> {
     type Output = U485;
 }
 
+impl ToConst for U485 {
+    type Output = Const<485>;
+}
+
 impl ToUInt for Const<486> {
     type Output = U486;
 }
 
+impl ToConst for U486 {
+    type Output = Const<486>;
+}
+
 impl ToUInt for Const<487> {
     type Output = U487;
 }
 
+impl ToConst for U487 {
+    type Output = Const<487>;
+}
+
 impl ToUInt for Const<488> {
     type Output = U488;
 }
 
+impl ToConst for U488 {
+    type Output = Const<488>;
+}
+
 impl ToUInt for Const<489> {
     type Output = U489;
 }
 
+impl ToConst for U489 {
+    type Output = Const<489>;
+}
+
 impl ToUInt for Const<490> {
     type Output = U490;
 }
 
+impl ToConst for U490 {
+    type Output = Const<490>;
+}
+
 impl ToUInt for Const<491> {
     type Output = U491;
 }
 
+impl ToConst for U491 {
+    type Output = Const<491>;
+}
+
 impl ToUInt for Const<492> {
     type Output = U492;
 }
 
+impl ToConst for U492 {
+    type Output = Const<492>;
+}
+
 impl ToUInt for Const<493> {
     type Output = U493;
 }
 
+impl ToConst for U493 {
+    type Output = Const<493>;
+}
+
 impl ToUInt for Const<494> {
     type Output = U494;
 }
 
+impl ToConst for U494 {
+    type Output = Const<494>;
+}
+
 impl ToUInt for Const<495> {
     type Output = U495;
 }
 
+impl ToConst for U495 {
+    type Output = Const<495>;
+}
+
 impl ToUInt for Const<496> {
     type Output = U496;
 }
 
+impl ToConst for U496 {
+    type Output = Const<496>;
+}
+
 impl ToUInt for Const<497> {
     type Output = U497;
 }
 
+impl ToConst for U497 {
+    type Output = Const<497>;
+}
+
 impl ToUInt for Const<498> {
     type Output = U498;
 }
 
+impl ToConst for U498 {
+    type Output = Const<498>;
+}
+
 impl ToUInt for Const<499> {
     type Output = U499;
 }
 
+impl ToConst for U499 {
+    type Output = Const<499>;
+}
+
 impl ToUInt for Const<500> {
     type Output = U500;
 }
 
+impl ToConst for U500 {
+    type Output = Const<500>;
+}
+
 impl ToUInt for Const<501> {
     type Output = U501;
 }
 
+impl ToConst for U501 {
+    type Output = Const<501>;
+}
+
 impl ToUInt for Const<502> {
     type Output = U502;
 }
 
+impl ToConst for U502 {
+    type Output = Const<502>;
+}
+
 impl ToUInt for Const<503> {
     type Output = U503;
 }
 
+impl ToConst for U503 {
+    type Output = Const<503>;
+}
+
 impl ToUInt for Const<504> {
     type Output = U504;
 }
 
+impl ToConst for U504 {
+    type Output = Const<504>;
+}
+
 impl ToUInt for Const<505> {
     type Output = U505;
 }
 
+impl ToConst for U505 {
+    type Output = Const<505>;
+}
+
 impl ToUInt for Const<506> {
     type Output = U506;
 }
 
+impl ToConst for U506 {
+    type Output = Const<506>;
+}
+
 impl ToUInt for Const<507> {
     type Output = U507;
 }
 
+impl ToConst for U507 {
+    type Output = Const<507>;
+}
+
 impl ToUInt for Const<508> {
     type Output = U508;
 }
 
+impl ToConst for U508 {
+    type Output = Const<508>;
+}
+
 impl ToUInt for Const<509> {
     type Output = U509;
 }
 
+impl ToConst for U509 {
+    type Output = Const<509>;
+}
+
 impl ToUInt for Const<510> {
     type Output = U510;
 }
 
+impl ToConst for U510 {
+    type Output = Const<510>;
+}
+
 impl ToUInt for Const<511> {
     type Output = U511;
 }
 
+impl ToConst for U511 {
+    type Output = Const<511>;
+}
+
 impl ToUInt for Const<512> {
     type Output = U512;
 }
 
+impl ToConst for U512 {
+    type Output = Const<512>;
+}
+
 impl ToUInt for Const<513> {
     type Output = U513;
 }
 
+impl ToConst for U513 {
+    type Output = Const<513>;
+}
+
 impl ToUInt for Const<514> {
     type Output = U514;
 }
 
+impl ToConst for U514 {
+    type Output = Const<514>;
+}
+
 impl ToUInt for Const<515> {
     type Output = U515;
 }
 
+impl ToConst for U515 {
+    type Output = Const<515>;
+}
+
 impl ToUInt for Const<516> {
     type Output = U516;
 }
 
+impl ToConst for U516 {
+    type Output = Const<516>;
+}
+
 impl ToUInt for Const<517> {
     type Output = U517;
 }
 
+impl ToConst for U517 {
+    type Output = Const<517>;
+}
+
 impl ToUInt for Const<518> {
     type Output = U518;
 }
 
+impl ToConst for U518 {
+    type Output = Const<518>;
+}
+
 impl ToUInt for Const<519> {
     type Output = U519;
 }
 
+impl ToConst for U519 {
+    type Output = Const<519>;
+}
+
 impl ToUInt for Const<520> {
     type Output = U520;
 }
 
+impl ToConst for U520 {
+    type Output = Const<520>;
+}
+
 impl ToUInt for Const<521> {
     type Output = U521;
 }
 
+impl ToConst for U521 {
+    type Output = Const<521>;
+}
+
 impl ToUInt for Const<522> {
     type Output = U522;
 }
 
+impl ToConst for U522 {
+    type Output = Const<522>;
+}
+
 impl ToUInt for Const<523> {
     type Output = U523;
 }
 
+impl ToConst for U523 {
+    type Output = Const<523>;
+}
+
 impl ToUInt for Const<524> {
     type Output = U524;
 }
 
+impl ToConst for U524 {
+    type Output = Const<524>;
+}
+
 impl ToUInt for Const<525> {
     type Output = U525;
 }
 
+impl ToConst for U525 {
+    type Output = Const<525>;
+}
+
 impl ToUInt for Const<526> {
     type Output = U526;
 }
 
+impl ToConst for U526 {
+    type Output = Const<526>;
+}
+
 impl ToUInt for Const<527> {
     type Output = U527;
 }
 
+impl ToConst for U527 {
+    type Output = Const<527>;
+}
+
 impl ToUInt for Const<528> {
     type Output = U528;
 }
 
+impl ToConst for U528 {
+    type Output = Const<528>;
+}
+
 impl ToUInt for Const<529> {
     type Output = U529;
 }
 
+impl ToConst for U529 {
+    type Output = Const<529>;
+}
+
 impl ToUInt for Const<530> {
     type Output = U530;
 }
 
+impl ToConst for U530 {
+    type Output = Const<530>;
+}
+
 impl ToUInt for Const<531> {
     type Output = U531;
 }
 
+impl ToConst for U531 {
+    type Output = Const<531>;
+}
+
 impl ToUInt for Const<532> {
     type Output = U532;
 }
 
+impl ToConst for U532 {
+    type Output = Const<532>;
+}
+
 impl ToUInt for Const<533> {
     type Output = U533;
 }
 
+impl ToConst for U533 {
+    type Output = Const<533>;
+}
+
 impl ToUInt for Const<534> {
     type Output = U534;
 }
 
+impl ToConst for U534 {
+    type Output = Const<534>;
+}
+
 impl ToUInt for Const<535> {
     type Output = U535;
 }
 
+impl ToConst for U535 {
+    type Output = Const<535>;
+}
+
 impl ToUInt for Const<536> {
     type Output = U536;
 }
 
+impl ToConst for U536 {
+    type Output = Const<536>;
+}
+
 impl ToUInt for Const<537> {
     type Output = U537;
 }
 
+impl ToConst for U537 {
+    type Output = Const<537>;
+}
+
 impl ToUInt for Const<538> {
     type Output = U538;
 }
 
+impl ToConst for U538 {
+    type Output = Const<538>;
+}
+
 impl ToUInt for Const<539> {
     type Output = U539;
 }
 
+impl ToConst for U539 {
+    type Output = Const<539>;
+}
+
 impl ToUInt for Const<540> {
     type Output = U540;
 }
 
+impl ToConst for U540 {
+    type Output = Const<540>;
+}
+
 impl ToUInt for Const<541> {
     type Output = U541;
 }
 
+impl ToConst for U541 {
+    type Output = Const<541>;
+}
+
 impl ToUInt for Const<542> {
     type Output = U542;
 }
 
+impl ToConst for U542 {
+    type Output = Const<542>;
+}
+
 impl ToUInt for Const<543> {
     type Output = U543;
 }
 
+impl ToConst for U543 {
+    type Output = Const<543>;
+}
+
 impl ToUInt for Const<544> {
     type Output = U544;
 }
 
+impl ToConst for U544 {
+    type Output = Const<544>;
+}
+
 impl ToUInt for Const<545> {
     type Output = U545;
 }
 
+impl ToConst for U545 {
+    type Output = Const<545>;
+}
+
 impl ToUInt for Const<546> {
     type Output = U546;
 }
 
+impl ToConst for U546 {
+    type Output = Const<546>;
+}
+
 impl ToUInt for Const<547> {
     type Output = U547;
 }
 
+impl ToConst for U547 {
+    type Output = Const<547>;
+}
+
 impl ToUInt for Const<548> {
     type Output = U548;
 }
 
+impl ToConst for U548 {
+    type Output = Const<548>;
+}
+
 impl ToUInt for Const<549> {
     type Output = U549;
 }
 
+impl ToConst for U549 {
+    type Output = Const<549>;
+}
+
 impl ToUInt for Const<550> {
     type Output = U550;
 }
 
+impl ToConst for U550 {
+    type Output = Const<550>;
+}
+
 impl ToUInt for Const<551> {
     type Output = U551;
 }
 
+impl ToConst for U551 {
+    type Output = Const<551>;
+}
+
 impl ToUInt for Const<552> {
     type Output = U552;
 }
 
+impl ToConst for U552 {
+    type Output = Const<552>;
+}
+
 impl ToUInt for Const<553> {
     type Output = U553;
 }
 
+impl ToConst for U553 {
+    type Output = Const<553>;
+}
+
 impl ToUInt for Const<554> {
     type Output = U554;
 }
 
+impl ToConst for U554 {
+    type Output = Const<554>;
+}
+
 impl ToUInt for Const<555> {
     type Output = U555;
 }
 
+impl ToConst for U555 {
+    type Output = Const<555>;
+}
+
 impl ToUInt for Const<556> {
     type Output = U556;
 }
 
+impl ToConst for U556 {
+    type Output = Const<556>;
+}
+
 impl ToUInt for Const<557> {
     type Output = U557;
 }
 
+impl ToConst for U557 {
+    type Output = Const<557>;
+}
+
 impl ToUInt for Const<558> {
     type Output = U558;
 }
 
+impl ToConst for U558 {
+    type Output = Const<558>;
+}
+
 impl ToUInt for Const<559> {
     type Output = U559;
 }
 
+impl ToConst for U559 {
+    type Output = Const<559>;
+}
+
 impl ToUInt for Const<560> {
     type Output = U560;
 }
 
+impl ToConst for U560 {
+    type Output = Const<560>;
+}
+
 impl ToUInt for Const<561> {
     type Output = U561;
 }
 
+impl ToConst for U561 {
+    type Output = Const<561>;
+}
+
 impl ToUInt for Const<562> {
     type Output = U562;
 }
 
+impl ToConst for U562 {
+    type Output = Const<562>;
+}
+
 impl ToUInt for Const<563> {
     type Output = U563;
 }
 
+impl ToConst for U563 {
+    type Output = Const<563>;
+}
+
 impl ToUInt for Const<564> {
     type Output = U564;
 }
 
+impl ToConst for U564 {
+    type Output = Const<564>;
+}
+
 impl ToUInt for Const<565> {
     type Output = U565;
 }
 
+impl ToConst for U565 {
+    type Output = Const<565>;
+}
+
 impl ToUInt for Const<566> {
     type Output = U566;
 }
 
+impl ToConst for U566 {
+    type Output = Const<566>;
+}
+
 impl ToUInt for Const<567> {
     type Output = U567;
 }
 
+impl ToConst for U567 {
+    type Output = Const<567>;
+}
+
 impl ToUInt for Const<568> {
     type Output = U568;
 }
 
+impl ToConst for U568 {
+    type Output = Const<568>;
+}
+
 impl ToUInt for Const<569> {
     type Output = U569;
 }
 
+impl ToConst for U569 {
+    type Output = Const<569>;
+}
+
 impl ToUInt for Const<570> {
     type Output = U570;
 }
 
+impl ToConst for U570 {
+    type Output = Const<570>;
+}
+
 impl ToUInt for Const<571> {
     type Output = U571;
 }
 
+impl ToConst for U571 {
+    type Output = Const<571>;
+}
+
 impl ToUInt for Const<572> {
     type Output = U572;
 }
 
+impl ToConst for U572 {
+    type Output = Const<572>;
+}
+
 impl ToUInt for Const<573> {
     type Output = U573;
 }
 
+impl ToConst for U573 {
+    type Output = Const<573>;
+}
+
 impl ToUInt for Const<574> {
     type Output = U574;
 }
 
+impl ToConst for U574 {
+    type Output = Const<574>;
+}
+
 impl ToUInt for Const<575> {
     type Output = U575;
 }
 
+impl ToConst for U575 {
+    type Output = Const<575>;
+}
+
 impl ToUInt for Const<576> {
     type Output = U576;
 }
 
+impl ToConst for U576 {
+    type Output = Const<576>;
+}
+
 impl ToUInt for Const<577> {
     type Output = U577;
 }
 
+impl ToConst for U577 {
+    type Output = Const<577>;
+}
+
 impl ToUInt for Const<578> {
     type Output = U578;
 }
 
+impl ToConst for U578 {
+    type Output = Const<578>;
+}
+
 impl ToUInt for Const<579> {
     type Output = U579;
 }
 
+impl ToConst for U579 {
+    type Output = Const<579>;
+}
+
 impl ToUInt for Const<580> {
     type Output = U580;
 }
 
+impl ToConst for U580 {
+    type Output = Const<580>;
+}
+
 impl ToUInt for Const<581> {
     type Output = U581;
 }
 
+impl ToConst for U581 {
+    type Output = Const<581>;
+}
+
 impl ToUInt for Const<582> {
     type Output = U582;
 }
 
+impl ToConst for U582 {
+    type Output = Const<582>;
+}
+
 impl ToUInt for Const<583> {
     type Output = U583;
 }
 
+impl ToConst for U583 {
+    type Output = Const<583>;
+}
+
 impl ToUInt for Const<584> {
     type Output = U584;
 }
 
+impl ToConst for U584 {
+    type Output = Const<584>;
+}
+
 impl ToUInt for Const<585> {
     type Output = U585;
 }
 
+impl ToConst for U585 {
+    type Output = Const<585>;
+}
+
 impl ToUInt for Const<586> {
     type Output = U586;
 }
 
+impl ToConst for U586 {
+    type Output = Const<586>;
+}
+
 impl ToUInt for Const<587> {
     type Output = U587;
 }
 
+impl ToConst for U587 {
+    type Output = Const<587>;
+}
+
 impl ToUInt for Const<588> {
     type Output = U588;
 }
 
+impl ToConst for U588 {
+    type Output = Const<588>;
+}
+
 impl ToUInt for Const<589> {
     type Output = U589;
 }
 
+impl ToConst for U589 {
+    type Output = Const<589>;
+}
+
 impl ToUInt for Const<590> {
     type Output = U590;
 }
 
+impl ToConst for U590 {
+    type Output = Const<590>;
+}
+
 impl ToUInt for Const<591> {
     type Output = U591;
 }
 
+impl ToConst for U591 {
+    type Output = Const<591>;
+}
+
 impl ToUInt for Const<592> {
     type Output = U592;
 }
 
+impl ToConst for U592 {
+    type Output = Const<592>;
+}
+
 impl ToUInt for Const<593> {
     type Output = U593;
 }
 
+impl ToConst for U593 {
+    type Output = Const<593>;
+}
+
 impl ToUInt for Const<594> {
     type Output = U594;
 }
 
+impl ToConst for U594 {
+    type Output = Const<594>;
+}
+
 impl ToUInt for Const<595> {
     type Output = U595;
 }
 
+impl ToConst for U595 {
+    type Output = Const<595>;
+}
+
 impl ToUInt for Const<596> {
     type Output = U596;
 }
 
+impl ToConst for U596 {
+    type Output = Const<596>;
+}
+
 impl ToUInt for Const<597> {
     type Output = U597;
 }
 
+impl ToConst for U597 {
+    type Output = Const<597>;
+}
+
 impl ToUInt for Const<598> {
     type Output = U598;
 }
 
+impl ToConst for U598 {
+    type Output = Const<598>;
+}
+
 impl ToUInt for Const<599> {
     type Output = U599;
 }
 
+impl ToConst for U599 {
+    type Output = Const<599>;
+}
+
 impl ToUInt for Const<600> {
     type Output = U600;
 }
 
+impl ToConst for U600 {
+    type Output = Const<600>;
+}
+
 impl ToUInt for Const<601> {
     type Output = U601;
 }
 
+impl ToConst for U601 {
+    type Output = Const<601>;
+}
+
 impl ToUInt for Const<602> {
     type Output = U602;
 }
 
+impl ToConst for U602 {
+    type Output = Const<602>;
+}
+
 impl ToUInt for Const<603> {
     type Output = U603;
 }
 
+impl ToConst for U603 {
+    type Output = Const<603>;
+}
+
 impl ToUInt for Const<604> {
     type Output = U604;
 }
 
+impl ToConst for U604 {
+    type Output = Const<604>;
+}
+
 impl ToUInt for Const<605> {
     type Output = U605;
 }
 
+impl ToConst for U605 {
+    type Output = Const<605>;
+}
+
 impl ToUInt for Const<606> {
     type Output = U606;
 }
 
+impl ToConst for U606 {
+    type Output = Const<606>;
+}
+
 impl ToUInt for Const<607> {
     type Output = U607;
 }
 
+impl ToConst for U607 {
+    type Output = Const<607>;
+}
+
 impl ToUInt for Const<608> {
     type Output = U608;
 }
 
+impl ToConst for U608 {
+    type Output = Const<608>;
+}
+
 impl ToUInt for Const<609> {
     type Output = U609;
 }
 
+impl ToConst for U609 {
+    type Output = Const<609>;
+}
+
 impl ToUInt for Const<610> {
     type Output = U610;
 }
 
+impl ToConst for U610 {
+    type Output = Const<610>;
+}
+
 impl ToUInt for Const<611> {
     type Output = U611;
 }
 
+impl ToConst for U611 {
+    type Output = Const<611>;
+}
+
 impl ToUInt for Const<612> {
     type Output = U612;
 }
 
+impl ToConst for U612 {
+    type Output = Const<612>;
+}
+
 impl ToUInt for Const<613> {
     type Output = U613;
 }
 
+impl ToConst for U613 {
+    type Output = Const<613>;
+}
+
 impl ToUInt for Const<614> {
     type Output = U614;
 }
 
+impl ToConst for U614 {
+    type Output = Const<614>;
+}
+
 impl ToUInt for Const<615> {
     type Output = U615;
 }
 
+impl ToConst for U615 {
+    type Output = Const<615>;
+}
+
 impl ToUInt for Const<616> {
     type Output = U616;
 }
 
+impl ToConst for U616 {
+    type Output = Const<616>;
+}
+
 impl ToUInt for Const<617> {
     type Output = U617;
 }
 
+impl ToConst for U617 {
+    type Output = Const<617>;
+}
+
 impl ToUInt for Const<618> {
     type Output = U618;
 }
 
+impl ToConst for U618 {
+    type Output = Const<618>;
+}
+
 impl ToUInt for Const<619> {
     type Output = U619;
 }
 
+impl ToConst for U619 {
+    type Output = Const<619>;
+}
+
 impl ToUInt for Const<620> {
     type Output = U620;
 }
 
+impl ToConst for U620 {
+    type Output = Const<620>;
+}
+
 impl ToUInt for Const<621> {
     type Output = U621;
 }
 
+impl ToConst for U621 {
+    type Output = Const<621>;
+}
+
 impl ToUInt for Const<622> {
     type Output = U622;
 }
 
+impl ToConst for U622 {
+    type Output = Const<622>;
+}
+
 impl ToUInt for Const<623> {
     type Output = U623;
 }
 
+impl ToConst for U623 {
+    type Output = Const<623>;
+}
+
 impl ToUInt for Const<624> {
     type Output = U624;
 }
 
+impl ToConst for U624 {
+    type Output = Const<624>;
+}
+
 impl ToUInt for Const<625> {
     type Output = U625;
 }
 
+impl ToConst for U625 {
+    type Output = Const<625>;
+}
+
 impl ToUInt for Const<626> {
     type Output = U626;
 }
 
+impl ToConst for U626 {
+    type Output = Const<626>;
+}
+
 impl ToUInt for Const<627> {
     type Output = U627;
 }
 
+impl ToConst for U627 {
+    type Output = Const<627>;
+}
+
 impl ToUInt for Const<628> {
     type Output = U628;
 }
 
+impl ToConst for U628 {
+    type Output = Const<628>;
+}
+
 impl ToUInt for Const<629> {
     type Output = U629;
 }
 
+impl ToConst for U629 {
+    type Output = Const<629>;
+}
+
 impl ToUInt for Const<630> {
     type Output = U630;
 }
 
+impl ToConst for U630 {
+    type Output = Const<630>;
+}
+
 impl ToUInt for Const<631> {
     type Output = U631;
 }
 
+impl ToConst for U631 {
+    type Output = Const<631>;
+}
+
 impl ToUInt for Const<632> {
     type Output = U632;
 }
 
+impl ToConst for U632 {
+    type Output = Const<632>;
+}
+
 impl ToUInt for Const<633> {
     type Output = U633;
 }
 
+impl ToConst for U633 {
+    type Output = Const<633>;
+}
+
 impl ToUInt for Const<634> {
     type Output = U634;
 }
 
+impl ToConst for U634 {
+    type Output = Const<634>;
+}
+
 impl ToUInt for Const<635> {
     type Output = U635;
 }
 
+impl ToConst for U635 {
+    type Output = Const<635>;
+}
+
 impl ToUInt for Const<636> {
     type Output = U636;
 }
 
+impl ToConst for U636 {
+    type Output = Const<636>;
+}
+
 impl ToUInt for Const<637> {
     type Output = U637;
 }
 
+impl ToConst for U637 {
+    type Output = Const<637>;
+}
+
 impl ToUInt for Const<638> {
     type Output = U638;
 }
 
+impl ToConst for U638 {
+    type Output = Const<638>;
+}
+
 impl ToUInt for Const<639> {
     type Output = U639;
 }
 
+impl ToConst for U639 {
+    type Output = Const<639>;
+}
+
 impl ToUInt for Const<640> {
     type Output = U640;
 }
 
+impl ToConst for U640 {
+    type Output = Const<640>;
+}
+
 impl ToUInt for Const<641> {
     type Output = U641;
 }
 
+impl ToConst for U641 {
+    type Output = Const<641>;
+}
+
 impl ToUInt for Const<642> {
     type Output = U642;
 }
 
+impl ToConst for U642 {
+    type Output = Const<642>;
+}
+
 impl ToUInt for Const<643> {
     type Output = U643;
 }
 
+impl ToConst for U643 {
+    type Output = Const<643>;
+}
+
 impl ToUInt for Const<644> {
     type Output = U644;
 }
 
+impl ToConst for U644 {
+    type Output = Const<644>;
+}
+
 impl ToUInt for Const<645> {
     type Output = U645;
 }
 
+impl ToConst for U645 {
+    type Output = Const<645>;
+}
+
 impl ToUInt for Const<646> {
     type Output = U646;
 }
 
+impl ToConst for U646 {
+    type Output = Const<646>;
+}
+
 impl ToUInt for Const<647> {
     type Output = U647;
 }
 
+impl ToConst for U647 {
+    type Output = Const<647>;
+}
+
 impl ToUInt for Const<648> {
     type Output = U648;
 }
 
+impl ToConst for U648 {
+    type Output = Const<648>;
+}
+
 impl ToUInt for Const<649> {
     type Output = U649;
 }
 
+impl ToConst for U649 {
+    type Output = Const<649>;
+}
+
 impl ToUInt for Const<650> {
     type Output = U650;
 }
 
+impl ToConst for U650 {
+    type Output = Const<650>;
+}
+
 impl ToUInt for Const<651> {
     type Output = U651;
 }
 
+impl ToConst for U651 {
+    type Output = Const<651>;
+}
+
 impl ToUInt for Const<652> {
     type Output = U652;
 }
 
+impl ToConst for U652 {
+    type Output = Const<652>;
+}
+
 impl ToUInt for Const<653> {
     type Output = U653;
 }
 
+impl ToConst for U653 {
+    type Output = Const<653>;
+}
+
 impl ToUInt for Const<654> {
     type Output = U654;
 }
 
+impl ToConst for U654 {
+    type Output = Const<654>;
+}
+
 impl ToUInt for Const<655> {
     type Output = U655;
 }
 
+impl ToConst for U655 {
+    type Output = Const<655>;
+}
+
 impl ToUInt for Const<656> {
     type Output = U656;
 }
 
+impl ToConst for U656 {
+    type Output = Const<656>;
+}
+
 impl ToUInt for Const<657> {
     type Output = U657;
 }
 
+impl ToConst for U657 {
+    type Output = Const<657>;
+}
+
 impl ToUInt for Const<658> {
     type Output = U658;
 }
 
+impl ToConst for U658 {
+    type Output = Const<658>;
+}
+
 impl ToUInt for Const<659> {
     type Output = U659;
 }
 
+impl ToConst for U659 {
+    type Output = Const<659>;
+}
+
 impl ToUInt for Const<660> {
     type Output = U660;
 }
 
+impl ToConst for U660 {
+    type Output = Const<660>;
+}
+
 impl ToUInt for Const<661> {
     type Output = U661;
 }
 
+impl ToConst for U661 {
+    type Output = Const<661>;
+}
+
 impl ToUInt for Const<662> {
     type Output = U662;
 }
 
+impl ToConst for U662 {
+    type Output = Const<662>;
+}
+
 impl ToUInt for Const<663> {
     type Output = U663;
 }
 
+impl ToConst for U663 {
+    type Output = Const<663>;
+}
+
 impl ToUInt for Const<664> {
     type Output = U664;
 }
 
+impl ToConst for U664 {
+    type Output = Const<664>;
+}
+
 impl ToUInt for Const<665> {
     type Output = U665;
 }
 
+impl ToConst for U665 {
+    type Output = Const<665>;
+}
+
 impl ToUInt for Const<666> {
     type Output = U666;
 }
 
+impl ToConst for U666 {
+    type Output = Const<666>;
+}
+
 impl ToUInt for Const<667> {
     type Output = U667;
 }
 
+impl ToConst for U667 {
+    type Output = Const<667>;
+}
+
 impl ToUInt for Const<668> {
     type Output = U668;
 }
 
+impl ToConst for U668 {
+    type Output = Const<668>;
+}
+
 impl ToUInt for Const<669> {
     type Output = U669;
 }
 
+impl ToConst for U669 {
+    type Output = Const<669>;
+}
+
 impl ToUInt for Const<670> {
     type Output = U670;
 }
 
+impl ToConst for U670 {
+    type Output = Const<670>;
+}
+
 impl ToUInt for Const<671> {
     type Output = U671;
 }
 
+impl ToConst for U671 {
+    type Output = Const<671>;
+}
+
 impl ToUInt for Const<672> {
     type Output = U672;
 }
 
+impl ToConst for U672 {
+    type Output = Const<672>;
+}
+
 impl ToUInt for Const<673> {
     type Output = U673;
 }
 
+impl ToConst for U673 {
+    type Output = Const<673>;
+}
+
 impl ToUInt for Const<674> {
     type Output = U674;
 }
 
+impl ToConst for U674 {
+    type Output = Const<674>;
+}
+
 impl ToUInt for Const<675> {
     type Output = U675;
 }
 
+impl ToConst for U675 {
+    type Output = Const<675>;
+}
+
 impl ToUInt for Const<676> {
     type Output = U676;
 }
 
+impl ToConst for U676 {
+    type Output = Const<676>;
+}
+
 impl ToUInt for Const<677> {
     type Output = U677;
 }
 
+impl ToConst for U677 {
+    type Output = Const<677>;
+}
+
 impl ToUInt for Const<678> {
     type Output = U678;
 }
 
+impl ToConst for U678 {
+    type Output = Const<678>;
+}
+
 impl ToUInt for Const<679> {
     type Output = U679;
 }
 
+impl ToConst for U679 {
+    type Output = Const<679>;
+}
+
 impl ToUInt for Const<680> {
     type Output = U680;
 }
 
+impl ToConst for U680 {
+    type Output = Const<680>;
+}
+
 impl ToUInt for Const<681> {
     type Output = U681;
 }
 
+impl ToConst for U681 {
+    type Output = Const<681>;
+}
+
 impl ToUInt for Const<682> {
     type Output = U682;
 }
 
+impl ToConst for U682 {
+    type Output = Const<682>;
+}
+
 impl ToUInt for Const<683> {
     type Output = U683;
 }
 
+impl ToConst for U683 {
+    type Output = Const<683>;
+}
+
 impl ToUInt for Const<684> {
     type Output = U684;
 }
 
+impl ToConst for U684 {
+    type Output = Const<684>;
+}
+
 impl ToUInt for Const<685> {
     type Output = U685;
 }
 
+impl ToConst for U685 {
+    type Output = Const<685>;
+}
+
 impl ToUInt for Const<686> {
     type Output = U686;
 }
 
+impl ToConst for U686 {
+    type Output = Const<686>;
+}
+
 impl ToUInt for Const<687> {
     type Output = U687;
 }
 
+impl ToConst for U687 {
+    type Output = Const<687>;
+}
+
 impl ToUInt for Const<688> {
     type Output = U688;
 }
 
+impl ToConst for U688 {
+    type Output = Const<688>;
+}
+
 impl ToUInt for Const<689> {
     type Output = U689;
 }
 
+impl ToConst for U689 {
+    type Output = Const<689>;
+}
+
 impl ToUInt for Const<690> {
     type Output = U690;
 }
 
+impl ToConst for U690 {
+    type Output = Const<690>;
+}
+
 impl ToUInt for Const<691> {
     type Output = U691;
 }
 
+impl ToConst for U691 {
+    type Output = Const<691>;
+}
+
 impl ToUInt for Const<692> {
     type Output = U692;
 }
 
+impl ToConst for U692 {
+    type Output = Const<692>;
+}
+
 impl ToUInt for Const<693> {
     type Output = U693;
 }
 
+impl ToConst for U693 {
+    type Output = Const<693>;
+}
+
 impl ToUInt for Const<694> {
     type Output = U694;
 }
 
+impl ToConst for U694 {
+    type Output = Const<694>;
+}
+
 impl ToUInt for Const<695> {
     type Output = U695;
 }
 
+impl ToConst for U695 {
+    type Output = Const<695>;
+}
+
 impl ToUInt for Const<696> {
     type Output = U696;
 }
 
+impl ToConst for U696 {
+    type Output = Const<696>;
+}
+
 impl ToUInt for Const<697> {
     type Output = U697;
 }
 
+impl ToConst for U697 {
+    type Output = Const<697>;
+}
+
 impl ToUInt for Const<698> {
     type Output = U698;
 }
 
+impl ToConst for U698 {
+    type Output = Const<698>;
+}
+
 impl ToUInt for Const<699> {
     type Output = U699;
 }
 
+impl ToConst for U699 {
+    type Output = Const<699>;
+}
+
 impl ToUInt for Const<700> {
     type Output = U700;
 }
 
+impl ToConst for U700 {
+    type Output = Const<700>;
+}
+
 impl ToUInt for Const<701> {
     type Output = U701;
 }
 
+impl ToConst for U701 {
+    type Output = Const<701>;
+}
+
 impl ToUInt for Const<702> {
     type Output = U702;
 }
 
+impl ToConst for U702 {
+    type Output = Const<702>;
+}
+
 impl ToUInt for Const<703> {
     type Output = U703;
 }
 
+impl ToConst for U703 {
+    type Output = Const<703>;
+}
+
 impl ToUInt for Const<704> {
     type Output = U704;
 }
 
+impl ToConst for U704 {
+    type Output = Const<704>;
+}
+
 impl ToUInt for Const<705> {
     type Output = U705;
 }
 
+impl ToConst for U705 {
+    type Output = Const<705>;
+}
+
 impl ToUInt for Const<706> {
     type Output = U706;
 }
 
+impl ToConst for U706 {
+    type Output = Const<706>;
+}
+
 impl ToUInt for Const<707> {
     type Output = U707;
 }
 
+impl ToConst for U707 {
+    type Output = Const<707>;
+}
+
 impl ToUInt for Const<708> {
     type Output = U708;
 }
 
+impl ToConst for U708 {
+    type Output = Const<708>;
+}
+
 impl ToUInt for Const<709> {
     type Output = U709;
 }
 
+impl ToConst for U709 {
+    type Output = Const<709>;
+}
+
 impl ToUInt for Const<710> {
     type Output = U710;
 }
 
+impl ToConst for U710 {
+    type Output = Const<710>;
+}
+
 impl ToUInt for Const<711> {
     type Output = U711;
 }
 
+impl ToConst for U711 {
+    type Output = Const<711>;
+}
+
 impl ToUInt for Const<712> {
     type Output = U712;
 }
 
+impl ToConst for U712 {
+    type Output = Const<712>;
+}
+
 impl ToUInt for Const<713> {
     type Output = U713;
 }
 
+impl ToConst for U713 {
+    type Output = Const<713>;
+}
+
 impl ToUInt for Const<714> {
     type Output = U714;
 }
 
+impl ToConst for U714 {
+    type Output = Const<714>;
+}
+
 impl ToUInt for Const<715> {
     type Output = U715;
 }
 
+impl ToConst for U715 {
+    type Output = Const<715>;
+}
+
 impl ToUInt for Const<716> {
     type Output = U716;
 }
 
+impl ToConst for U716 {
+    type Output = Const<716>;
+}
+
 impl ToUInt for Const<717> {
     type Output = U717;
 }
 
+impl ToConst for U717 {
+    type Output = Const<717>;
+}
+
 impl ToUInt for Const<718> {
     type Output = U718;
 }
 
+impl ToConst for U718 {
+    type Output = Const<718>;
+}
+
 impl ToUInt for Const<719> {
     type Output = U719;
 }
 
+impl ToConst for U719 {
+    type Output = Const<719>;
+}
+
 impl ToUInt for Const<720> {
     type Output = U720;
 }
 
+impl ToConst for U720 {
+    type Output = Const<720>;
+}
+
 impl ToUInt for Const<721> {
     type Output = U721;
 }
 
+impl ToConst for U721 {
+    type Output = Const<721>;
+}
+
 impl ToUInt for Const<722> {
     type Output = U722;
 }
 
+impl ToConst for U722 {
+    type Output = Const<722>;
+}
+
 impl ToUInt for Const<723> {
     type Output = U723;
 }
 
+impl ToConst for U723 {
+    type Output = Const<723>;
+}
+
 impl ToUInt for Const<724> {
     type Output = U724;
 }
 
+impl ToConst for U724 {
+    type Output = Const<724>;
+}
+
 impl ToUInt for Const<725> {
     type Output = U725;
 }
 
+impl ToConst for U725 {
+    type Output = Const<725>;
+}
+
 impl ToUInt for Const<726> {
     type Output = U726;
 }
 
+impl ToConst for U726 {
+    type Output = Const<726>;
+}
+
 impl ToUInt for Const<727> {
     type Output = U727;
 }
 
+impl ToConst for U727 {
+    type Output = Const<727>;
+}
+
 impl ToUInt for Const<728> {
     type Output = U728;
 }
 
+impl ToConst for U728 {
+    type Output = Const<728>;
+}
+
 impl ToUInt for Const<729> {
     type Output = U729;
 }
 
+impl ToConst for U729 {
+    type Output = Const<729>;
+}
+
 impl ToUInt for Const<730> {
     type Output = U730;
 }
 
+impl ToConst for U730 {
+    type Output = Const<730>;
+}
+
 impl ToUInt for Const<731> {
     type Output = U731;
 }
 
+impl ToConst for U731 {
+    type Output = Const<731>;
+}
+
 impl ToUInt for Const<732> {
     type Output = U732;
 }
 
+impl ToConst for U732 {
+    type Output = Const<732>;
+}
+
 impl ToUInt for Const<733> {
     type Output = U733;
 }
 
+impl ToConst for U733 {
+    type Output = Const<733>;
+}
+
 impl ToUInt for Const<734> {
     type Output = U734;
 }
 
+impl ToConst for U734 {
+    type Output = Const<734>;
+}
+
 impl ToUInt for Const<735> {
     type Output = U735;
 }
 
+impl ToConst for U735 {
+    type Output = Const<735>;
+}
+
 impl ToUInt for Const<736> {
     type Output = U736;
 }
 
+impl ToConst for U736 {
+    type Output = Const<736>;
+}
+
 impl ToUInt for Const<737> {
     type Output = U737;
 }
 
+impl ToConst for U737 {
+    type Output = Const<737>;
+}
+
 impl ToUInt for Const<738> {
     type Output = U738;
 }
 
+impl ToConst for U738 {
+    type Output = Const<738>;
+}
+
 impl ToUInt for Const<739> {
     type Output = U739;
 }
 
+impl ToConst for U739 {
+    type Output = Const<739>;
+}
+
 impl ToUInt for Const<740> {
     type Output = U740;
 }
 
+impl ToConst for U740 {
+    type Output = Const<740>;
+}
+
 impl ToUInt for Const<741> {
     type Output = U741;
 }
 
+impl ToConst for U741 {
+    type Output = Const<741>;
+}
+
 impl ToUInt for Const<742> {
     type Output = U742;
 }
 
+impl ToConst for U742 {
+    type Output = Const<742>;
+}
+
 impl ToUInt for Const<743> {
     type Output = U743;
 }
 
+impl ToConst for U743 {
+    type Output = Const<743>;
+}
+
 impl ToUInt for Const<744> {
     type Output = U744;
 }
 
+impl ToConst for U744 {
+    type Output = Const<744>;
+}
+
 impl ToUInt for Const<745> {
     type Output = U745;
 }
 
+impl ToConst for U745 {
+    type Output = Const<745>;
+}
+
 impl ToUInt for Const<746> {
     type Output = U746;
 }
 
+impl ToConst for U746 {
+    type Output = Const<746>;
+}
+
 impl ToUInt for Const<747> {
     type Output = U747;
 }
 
+impl ToConst for U747 {
+    type Output = Const<747>;
+}
+
 impl ToUInt for Const<748> {
     type Output = U748;
 }
 
+impl ToConst for U748 {
+    type Output = Const<748>;
+}
+
 impl ToUInt for Const<749> {
     type Output = U749;
 }
 
+impl ToConst for U749 {
+    type Output = Const<749>;
+}
+
 impl ToUInt for Const<750> {
     type Output = U750;
 }
 
+impl ToConst for U750 {
+    type Output = Const<750>;
+}
+
 impl ToUInt for Const<751> {
     type Output = U751;
 }
 
+impl ToConst for U751 {
+    type Output = Const<751>;
+}
+
 impl ToUInt for Const<752> {
     type Output = U752;
 }
 
+impl ToConst for U752 {
+    type Output = Const<752>;
+}
+
 impl ToUInt for Const<753> {
     type Output = U753;
 }
 
+impl ToConst for U753 {
+    type Output = Const<753>;
+}
+
 impl ToUInt for Const<754> {
     type Output = U754;
 }
 
+impl ToConst for U754 {
+    type Output = Const<754>;
+}
+
 impl ToUInt for Const<755> {
     type Output = U755;
 }
 
+impl ToConst for U755 {
+    type Output = Const<755>;
+}
+
 impl ToUInt for Const<756> {
     type Output = U756;
 }
 
+impl ToConst for U756 {
+    type Output = Const<756>;
+}
+
 impl ToUInt for Const<757> {
     type Output = U757;
 }
 
+impl ToConst for U757 {
+    type Output = Const<757>;
+}
+
 impl ToUInt for Const<758> {
     type Output = U758;
 }
 
+impl ToConst for U758 {
+    type Output = Const<758>;
+}
+
 impl ToUInt for Const<759> {
     type Output = U759;
 }
 
+impl ToConst for U759 {
+    type Output = Const<759>;
+}
+
 impl ToUInt for Const<760> {
     type Output = U760;
 }
 
+impl ToConst for U760 {
+    type Output = Const<760>;
+}
+
 impl ToUInt for Const<761> {
     type Output = U761;
 }
 
+impl ToConst for U761 {
+    type Output = Const<761>;
+}
+
 impl ToUInt for Const<762> {
     type Output = U762;
 }
 
+impl ToConst for U762 {
+    type Output = Const<762>;
+}
+
 impl ToUInt for Const<763> {
     type Output = U763;
 }
 
+impl ToConst for U763 {
+    type Output = Const<763>;
+}
+
 impl ToUInt for Const<764> {
     type Output = U764;
 }
 
+impl ToConst for U764 {
+    type Output = Const<764>;
+}
+
 impl ToUInt for Const<765> {
     type Output = U765;
 }
 
+impl ToConst for U765 {
+    type Output = Const<765>;
+}
+
 impl ToUInt for Const<766> {
     type Output = U766;
 }
 
+impl ToConst for U766 {
+    type Output = Const<766>;
+}
+
 impl ToUInt for Const<767> {
     type Output = U767;
 }
 
+impl ToConst for U767 {
+    type Output = Const<767>;
+}
+
 impl ToUInt for Const<768> {
     type Output = U768;
 }
 
+impl ToConst for U768 {
+    type Output = Const<768>;
+}
+
 impl ToUInt for Const<769> {
     type Output = U769;
 }
 
+impl ToConst for U769 {
+    type Output = Const<769>;
+}
+
 impl ToUInt for Const<770> {
     type Output = U770;
 }
 
+impl ToConst for U770 {
+    type Output = Const<770>;
+}
+
 impl ToUInt for Const<771> {
     type Output = U771;
 }
 
+impl ToConst for U771 {
+    type Output = Const<771>;
+}
+
 impl ToUInt for Const<772> {
     type Output = U772;
 }
 
+impl ToConst for U772 {
+    type Output = Const<772>;
+}
+
 impl ToUInt for Const<773> {
     type Output = U773;
 }
 
+impl ToConst for U773 {
+    type Output = Const<773>;
+}
+
 impl ToUInt for Const<774> {
     type Output = U774;
 }
 
+impl ToConst for U774 {
+    type Output = Const<774>;
+}
+
 impl ToUInt for Const<775> {
     type Output = U775;
 }
 
+impl ToConst for U775 {
+    type Output = Const<775>;
+}
+
 impl ToUInt for Const<776> {
     type Output = U776;
 }
 
+impl ToConst for U776 {
+    type Output = Const<776>;
+}
+
 impl ToUInt for Const<777> {
     type Output = U777;
 }
 
+impl ToConst for U777 {
+    type Output = Const<777>;
+}
+
 impl ToUInt for Const<778> {
     type Output = U778;
 }
 
+impl ToConst for U778 {
+    type Output = Const<778>;
+}
+
 impl ToUInt for Const<779> {
     type Output = U779;
 }
 
+impl ToConst for U779 {
+    type Output = Const<779>;
+}
+
 impl ToUInt for Const<780> {
     type Output = U780;
 }
 
+impl ToConst for U780 {
+    type Output = Const<780>;
+}
+
 impl ToUInt for Const<781> {
     type Output = U781;
 }
 
+impl ToConst for U781 {
+    type Output = Const<781>;
+}
+
 impl ToUInt for Const<782> {
     type Output = U782;
 }
 
+impl ToConst for U782 {
+    type Output = Const<782>;
+}
+
 impl ToUInt for Const<783> {
     type Output = U783;
 }
 
+impl ToConst for U783 {
+    type Output = Const<783>;
+}
+
 impl ToUInt for Const<784> {
     type Output = U784;
 }
 
+impl ToConst for U784 {
+    type Output = Const<784>;
+}
+
 impl ToUInt for Const<785> {
     type Output = U785;
 }
 
+impl ToConst for U785 {
+    type Output = Const<785>;
+}
+
 impl ToUInt for Const<786> {
     type Output = U786;
 }
 
+impl ToConst for U786 {
+    type Output = Const<786>;
+}
+
 impl ToUInt for Const<787> {
     type Output = U787;
 }
 
+impl ToConst for U787 {
+    type Output = Const<787>;
+}
+
 impl ToUInt for Const<788> {
     type Output = U788;
 }
 
+impl ToConst for U788 {
+    type Output = Const<788>;
+}
+
 impl ToUInt for Const<789> {
     type Output = U789;
 }
 
+impl ToConst for U789 {
+    type Output = Const<789>;
+}
+
 impl ToUInt for Const<790> {
     type Output = U790;
 }
 
+impl ToConst for U790 {
+    type Output = Const<790>;
+}
+
 impl ToUInt for Const<791> {
     type Output = U791;
 }
 
+impl ToConst for U791 {
+    type Output = Const<791>;
+}
+
 impl ToUInt for Const<792> {
     type Output = U792;
 }
 
+impl ToConst for U792 {
+    type Output = Const<792>;
+}
+
 impl ToUInt for Const<793> {
     type Output = U793;
 }
 
+impl ToConst for U793 {
+    type Output = Const<793>;
+}
+
 impl ToUInt for Const<794> {
     type Output = U794;
 }
 
+impl ToConst for U794 {
+    type Output = Const<794>;
+}
+
 impl ToUInt for Const<795> {
     type Output = U795;
 }
 
+impl ToConst for U795 {
+    type Output = Const<795>;
+}
+
 impl ToUInt for Const<796> {
     type Output = U796;
 }
 
+impl ToConst for U796 {
+    type Output = Const<796>;
+}
+
 impl ToUInt for Const<797> {
     type Output = U797;
 }
 
+impl ToConst for U797 {
+    type Output = Const<797>;
+}
+
 impl ToUInt for Const<798> {
     type Output = U798;
 }
 
+impl ToConst for U798 {
+    type Output = Const<798>;
+}
+
 impl ToUInt for Const<799> {
     type Output = U799;
 }
 
+impl ToConst for U799 {
+    type Output = Const<799>;
+}
+
 impl ToUInt for Const<800> {
     type Output = U800;
 }
 
+impl ToConst for U800 {
+    type Output = Const<800>;
+}
+
 impl ToUInt for Const<801> {
     type Output = U801;
 }
 
+impl ToConst for U801 {
+    type Output = Const<801>;
+}
+
 impl ToUInt for Const<802> {
     type Output = U802;
 }
 
+impl ToConst for U802 {
+    type Output = Const<802>;
+}
+
 impl ToUInt for Const<803> {
     type Output = U803;
 }
 
+impl ToConst for U803 {
+    type Output = Const<803>;
+}
+
 impl ToUInt for Const<804> {
     type Output = U804;
 }
 
+impl ToConst for U804 {
+    type Output = Const<804>;
+}
+
 impl ToUInt for Const<805> {
     type Output = U805;
 }
 
+impl ToConst for U805 {
+    type Output = Const<805>;
+}
+
 impl ToUInt for Const<806> {
     type Output = U806;
 }
 
+impl ToConst for U806 {
+    type Output = Const<806>;
+}
+
 impl ToUInt for Const<807> {
     type Output = U807;
 }
 
+impl ToConst for U807 {
+    type Output = Const<807>;
+}
+
 impl ToUInt for Const<808> {
     type Output = U808;
 }
 
+impl ToConst for U808 {
+    type Output = Const<808>;
+}
+
 impl ToUInt for Const<809> {
     type Output = U809;
 }
 
+impl ToConst for U809 {
+    type Output = Const<809>;
+}
+
 impl ToUInt for Const<810> {
     type Output = U810;
 }
 
+impl ToConst for U810 {
+    type Output = Const<810>;
+}
+
 impl ToUInt for Const<811> {
     type Output = U811;
 }
 
+impl ToConst for U811 {
+    type Output = Const<811>;
+}
+
 impl ToUInt for Const<812> {
     type Output = U812;
 }
 
+impl ToConst for U812 {
+    type Output = Const<812>;
+}
+
 impl ToUInt for Const<813> {
     type Output = U813;
 }
 
+impl ToConst for U813 {
+    type Output = Const<813>;
+}
+
 impl ToUInt for Const<814> {
     type Output = U814;
 }
 
+impl ToConst for U814 {
+    type Output = Const<814>;
+}
+
 impl ToUInt for Const<815> {
     type Output = U815;
 }
 
+impl ToConst for U815 {
+    type Output = Const<815>;
+}
+
 impl ToUInt for Const<816> {
     type Output = U816;
 }
 
+impl ToConst for U816 {
+    type Output = Const<816>;
+}
+
 impl ToUInt for Const<817> {
     type Output = U817;
 }
 
+impl ToConst for U817 {
+    type Output = Const<817>;
+}
+
 impl ToUInt for Const<818> {
     type Output = U818;
 }
 
+impl ToConst for U818 {
+    type Output = Const<818>;
+}
+
 impl ToUInt for Const<819> {
     type Output = U819;
 }
 
+impl ToConst for U819 {
+    type Output = Const<819>;
+}
+
 impl ToUInt for Const<820> {
     type Output = U820;
 }
 
+impl ToConst for U820 {
+    type Output = Const<820>;
+}
+
 impl ToUInt for Const<821> {
     type Output = U821;
 }
 
+impl ToConst for U821 {
+    type Output = Const<821>;
+}
+
 impl ToUInt for Const<822> {
     type Output = U822;
 }
 
+impl ToConst for U822 {
+    type Output = Const<822>;
+}
+
 impl ToUInt for Const<823> {
     type Output = U823;
 }
 
+impl ToConst for U823 {
+    type Output = Const<823>;
+}
+
 impl ToUInt for Const<824> {
     type Output = U824;
 }
 
+impl ToConst for U824 {
+    type Output = Const<824>;
+}
+
 impl ToUInt for Const<825> {
     type Output = U825;
 }
 
+impl ToConst for U825 {
+    type Output = Const<825>;
+}
+
 impl ToUInt for Const<826> {
     type Output = U826;
 }
 
+impl ToConst for U826 {
+    type Output = Const<826>;
+}
+
 impl ToUInt for Const<827> {
     type Output = U827;
 }
 
+impl ToConst for U827 {
+    type Output = Const<827>;
+}
+
 impl ToUInt for Const<828> {
     type Output = U828;
 }
 
+impl ToConst for U828 {
+    type Output = Const<828>;
+}
+
 impl ToUInt for Const<829> {
     type Output = U829;
 }
 
+impl ToConst for U829 {
+    type Output = Const<829>;
+}
+
 impl ToUInt for Const<830> {
     type Output = U830;
 }
 
+impl ToConst for U830 {
+    type Output = Const<830>;
+}
+
 impl ToUInt for Const<831> {
     type Output = U831;
 }
 
+impl ToConst for U831 {
+    type Output = Const<831>;
+}
+
 impl ToUInt for Const<832> {
     type Output = U832;
 }
 
+impl ToConst for U832 {
+    type Output = Const<832>;
+}
+
 impl ToUInt for Const<833> {
     type Output = U833;
 }
 
+impl ToConst for U833 {
+    type Output = Const<833>;
+}
+
 impl ToUInt for Const<834> {
     type Output = U834;
 }
 
+impl ToConst for U834 {
+    type Output = Const<834>;
+}
+
 impl ToUInt for Const<835> {
     type Output = U835;
 }
 
+impl ToConst for U835 {
+    type Output = Const<835>;
+}
+
 impl ToUInt for Const<836> {
     type Output = U836;
 }
 
+impl ToConst for U836 {
+    type Output = Const<836>;
+}
+
 impl ToUInt for Const<837> {
     type Output = U837;
 }
 
+impl ToConst for U837 {
+    type Output = Const<837>;
+}
+
 impl ToUInt for Const<838> {
     type Output = U838;
 }
 
+impl ToConst for U838 {
+    type Output = Const<838>;
+}
+
 impl ToUInt for Const<839> {
     type Output = U839;
 }
 
+impl ToConst for U839 {
+    type Output = Const<839>;
+}
+
 impl ToUInt for Const<840> {
     type Output = U840;
 }
 
+impl ToConst for U840 {
+    type Output = Const<840>;
+}
+
 impl ToUInt for Const<841> {
     type Output = U841;
 }
 
+impl ToConst for U841 {
+    type Output = Const<841>;
+}
+
 impl ToUInt for Const<842> {
     type Output = U842;
 }
 
+impl ToConst for U842 {
+    type Output = Const<842>;
+}
+
 impl ToUInt for Const<843> {
     type Output = U843;
 }
 
+impl ToConst for U843 {
+    type Output = Const<843>;
+}
+
 impl ToUInt for Const<844> {
     type Output = U844;
 }
 
+impl ToConst for U844 {
+    type Output = Const<844>;
+}
+
 impl ToUInt for Const<845> {
     type Output = U845;
 }
 
+impl ToConst for U845 {
+    type Output = Const<845>;
+}
+
 impl ToUInt for Const<846> {
     type Output = U846;
 }
 
+impl ToConst for U846 {
+    type Output = Const<846>;
+}
+
 impl ToUInt for Const<847> {
     type Output = U847;
 }
 
+impl ToConst for U847 {
+    type Output = Const<847>;
+}
+
 impl ToUInt for Const<848> {
     type Output = U848;
 }
 
+impl ToConst for U848 {
+    type Output = Const<848>;
+}
+
 impl ToUInt for Const<849> {
     type Output = U849;
 }
 
+impl ToConst for U849 {
+    type Output = Const<849>;
+}
+
 impl ToUInt for Const<850> {
     type Output = U850;
 }
 
+impl ToConst for U850 {
+    type Output = Const<850>;
+}
+
 impl ToUInt for Const<851> {
     type Output = U851;
 }
 
+impl ToConst for U851 {
+    type Output = Const<851>;
+}
+
 impl ToUInt for Const<852> {
     type Output = U852;
 }
 
+impl ToConst for U852 {
+    type Output = Const<852>;
+}
+
 impl ToUInt for Const<853> {
     type Output = U853;
 }
 
+impl ToConst for U853 {
+    type Output = Const<853>;
+}
+
 impl ToUInt for Const<854> {
     type Output = U854;
 }
 
+impl ToConst for U854 {
+    type Output = Const<854>;
+}
+
 impl ToUInt for Const<855> {
     type Output = U855;
 }
 
+impl ToConst for U855 {
+    type Output = Const<855>;
+}
+
 impl ToUInt for Const<856> {
     type Output = U856;
 }
 
+impl ToConst for U856 {
+    type Output = Const<856>;
+}
+
 impl ToUInt for Const<857> {
     type Output = U857;
 }
 
+impl ToConst for U857 {
+    type Output = Const<857>;
+}
+
 impl ToUInt for Const<858> {
     type Output = U858;
 }
 
+impl ToConst for U858 {
+    type Output = Const<858>;
+}
+
 impl ToUInt for Const<859> {
     type Output = U859;
 }
 
+impl ToConst for U859 {
+    type Output = Const<859>;
+}
+
 impl ToUInt for Const<860> {
     type Output = U860;
 }
 
+impl ToConst for U860 {
+    type Output = Const<860>;
+}
+
 impl ToUInt for Const<861> {
     type Output = U861;
 }
 
+impl ToConst for U861 {
+    type Output = Const<861>;
+}
+
 impl ToUInt for Const<862> {
     type Output = U862;
 }
 
+impl ToConst for U862 {
+    type Output = Const<862>;
+}
+
 impl ToUInt for Const<863> {
     type Output = U863;
 }
 
+impl ToConst for U863 {
+    type Output = Const<863>;
+}
+
 impl ToUInt for Const<864> {
     type Output = U864;
 }
 
+impl ToConst for U864 {
+    type Output = Const<864>;
+}
+
 impl ToUInt for Const<865> {
     type Output = U865;
 }
 
+impl ToConst for U865 {
+    type Output = Const<865>;
+}
+
 impl ToUInt for Const<866> {
     type Output = U866;
 }
 
+impl ToConst for U866 {
+    type Output = Const<866>;
+}
+
 impl ToUInt for Const<867> {
     type Output = U867;
 }
 
+impl ToConst for U867 {
+    type Output = Const<867>;
+}
+
 impl ToUInt for Const<868> {
     type Output = U868;
 }
 
+impl ToConst for U868 {
+    type Output = Const<868>;
+}
+
 impl ToUInt for Const<869> {
     type Output = U869;
 }
 
+impl ToConst for U869 {
+    type Output = Const<869>;
+}
+
 impl ToUInt for Const<870> {
     type Output = U870;
 }
 
+impl ToConst for U870 {
+    type Output = Const<870>;
+}
+
 impl ToUInt for Const<871> {
     type Output = U871;
 }
 
+impl ToConst for U871 {
+    type Output = Const<871>;
+}
+
 impl ToUInt for Const<872> {
     type Output = U872;
 }
 
+impl ToConst for U872 {
+    type Output = Const<872>;
+}
+
 impl ToUInt for Const<873> {
     type Output = U873;
 }
 
+impl ToConst for U873 {
+    type Output = Const<873>;
+}
+
 impl ToUInt for Const<874> {
     type Output = U874;
 }
 
+impl ToConst for U874 {
+    type Output = Const<874>;
+}
+
 impl ToUInt for Const<875> {
     type Output = U875;
 }
 
+impl ToConst for U875 {
+    type Output = Const<875>;
+}
+
 impl ToUInt for Const<876> {
     type Output = U876;
 }
 
+impl ToConst for U876 {
+    type Output = Const<876>;
+}
+
 impl ToUInt for Const<877> {
     type Output = U877;
 }
 
+impl ToConst for U877 {
+    type Output = Const<877>;
+}
+
 impl ToUInt for Const<878> {
     type Output = U878;
 }
 
+impl ToConst for U878 {
+    type Output = Const<878>;
+}
+
 impl ToUInt for Const<879> {
     type Output = U879;
 }
 
+impl ToConst for U879 {
+    type Output = Const<879>;
+}
+
 impl ToUInt for Const<880> {
     type Output = U880;
 }
 
+impl ToConst for U880 {
+    type Output = Const<880>;
+}
+
 impl ToUInt for Const<881> {
     type Output = U881;
 }
 
+impl ToConst for U881 {
+    type Output = Const<881>;
+}
+
 impl ToUInt for Const<882> {
     type Output = U882;
 }
 
+impl ToConst for U882 {
+    type Output = Const<882>;
+}
+
 impl ToUInt for Const<883> {
     type Output = U883;
 }
 
+impl ToConst for U883 {
+    type Output = Const<883>;
+}
+
 impl ToUInt for Const<884> {
     type Output = U884;
 }
 
+impl ToConst for U884 {
+    type Output = Const<884>;
+}
+
 impl ToUInt for Const<885> {
     type Output = U885;
 }
 
+impl ToConst for U885 {
+    type Output = Const<885>;
+}
+
 impl ToUInt for Const<886> {
     type Output = U886;
 }
 
+impl ToConst for U886 {
+    type Output = Const<886>;
+}
+
 impl ToUInt for Const<887> {
     type Output = U887;
 }
 
+impl ToConst for U887 {
+    type Output = Const<887>;
+}
+
 impl ToUInt for Const<888> {
     type Output = U888;
 }
 
+impl ToConst for U888 {
+    type Output = Const<888>;
+}
+
 impl ToUInt for Const<889> {
     type Output = U889;
 }
 
+impl ToConst for U889 {
+    type Output = Const<889>;
+}
+
 impl ToUInt for Const<890> {
     type Output = U890;
 }
 
+impl ToConst for U890 {
+    type Output = Const<890>;
+}
+
 impl ToUInt for Const<891> {
     type Output = U891;
 }
 
+impl ToConst for U891 {
+    type Output = Const<891>;
+}
+
 impl ToUInt for Const<892> {
     type Output = U892;
 }
 
+impl ToConst for U892 {
+    type Output = Const<892>;
+}
+
 impl ToUInt for Const<893> {
     type Output = U893;
 }
 
+impl ToConst for U893 {
+    type Output = Const<893>;
+}
+
 impl ToUInt for Const<894> {
     type Output = U894;
 }
 
+impl ToConst for U894 {
+    type Output = Const<894>;
+}
+
 impl ToUInt for Const<895> {
     type Output = U895;
 }
 
+impl ToConst for U895 {
+    type Output = Const<895>;
+}
+
 impl ToUInt for Const<896> {
     type Output = U896;
 }
 
+impl ToConst for U896 {
+    type Output = Const<896>;
+}
+
 impl ToUInt for Const<897> {
     type Output = U897;
 }
 
+impl ToConst for U897 {
+    type Output = Const<897>;
+}
+
 impl ToUInt for Const<898> {
     type Output = U898;
 }
 
+impl ToConst for U898 {
+    type Output = Const<898>;
+}
+
 impl ToUInt for Const<899> {
     type Output = U899;
 }
 
+impl ToConst for U899 {
+    type Output = Const<899>;
+}
+
 impl ToUInt for Const<900> {
     type Output = U900;
 }
 
+impl ToConst for U900 {
+    type Output = Const<900>;
+}
+
 impl ToUInt for Const<901> {
     type Output = U901;
 }
 
+impl ToConst for U901 {
+    type Output = Const<901>;
+}
+
 impl ToUInt for Const<902> {
     type Output = U902;
 }
 
+impl ToConst for U902 {
+    type Output = Const<902>;
+}
+
 impl ToUInt for Const<903> {
     type Output = U903;
 }
 
+impl ToConst for U903 {
+    type Output = Const<903>;
+}
+
 impl ToUInt for Const<904> {
     type Output = U904;
 }
 
+impl ToConst for U904 {
+    type Output = Const<904>;
+}
+
 impl ToUInt for Const<905> {
     type Output = U905;
 }
 
+impl ToConst for U905 {
+    type Output = Const<905>;
+}
+
 impl ToUInt for Const<906> {
     type Output = U906;
 }
 
+impl ToConst for U906 {
+    type Output = Const<906>;
+}
+
 impl ToUInt for Const<907> {
     type Output = U907;
 }
 
+impl ToConst for U907 {
+    type Output = Const<907>;
+}
+
 impl ToUInt for Const<908> {
     type Output = U908;
 }
 
+impl ToConst for U908 {
+    type Output = Const<908>;
+}
+
 impl ToUInt for Const<909> {
     type Output = U909;
 }
 
+impl ToConst for U909 {
+    type Output = Const<909>;
+}
+
 impl ToUInt for Const<910> {
     type Output = U910;
 }
 
+impl ToConst for U910 {
+    type Output = Const<910>;
+}
+
 impl ToUInt for Const<911> {
     type Output = U911;
 }
 
+impl ToConst for U911 {
+    type Output = Const<911>;
+}
+
 impl ToUInt for Const<912> {
     type Output = U912;
 }
 
+impl ToConst for U912 {
+    type Output = Const<912>;
+}
+
 impl ToUInt for Const<913> {
     type Output = U913;
 }
 
+impl ToConst for U913 {
+    type Output = Const<913>;
+}
+
 impl ToUInt for Const<914> {
     type Output = U914;
 }
 
+impl ToConst for U914 {
+    type Output = Const<914>;
+}
+
 impl ToUInt for Const<915> {
     type Output = U915;
 }
 
+impl ToConst for U915 {
+    type Output = Const<915>;
+}
+
 impl ToUInt for Const<916> {
     type Output = U916;
 }
 
+impl ToConst for U916 {
+    type Output = Const<916>;
+}
+
 impl ToUInt for Const<917> {
     type Output = U917;
 }
 
+impl ToConst for U917 {
+    type Output = Const<917>;
+}
+
 impl ToUInt for Const<918> {
     type Output = U918;
 }
 
+impl ToConst for U918 {
+    type Output = Const<918>;
+}
+
 impl ToUInt for Const<919> {
     type Output = U919;
 }
 
+impl ToConst for U919 {
+    type Output = Const<919>;
+}
+
 impl ToUInt for Const<920> {
     type Output = U920;
 }
 
+impl ToConst for U920 {
+    type Output = Const<920>;
+}
+
 impl ToUInt for Const<921> {
     type Output = U921;
 }
 
+impl ToConst for U921 {
+    type Output = Const<921>;
+}
+
 impl ToUInt for Const<922> {
     type Output = U922;
 }
 
+impl ToConst for U922 {
+    type Output = Const<922>;
+}
+
 impl ToUInt for Const<923> {
     type Output = U923;
 }
 
+impl ToConst for U923 {
+    type Output = Const<923>;
+}
+
 impl ToUInt for Const<924> {
     type Output = U924;
 }
 
+impl ToConst for U924 {
+    type Output = Const<924>;
+}
+
 impl ToUInt for Const<925> {
     type Output = U925;
 }
 
+impl ToConst for U925 {
+    type Output = Const<925>;
+}
+
 impl ToUInt for Const<926> {
     type Output = U926;
 }
 
+impl ToConst for U926 {
+    type Output = Const<926>;
+}
+
 impl ToUInt for Const<927> {
     type Output = U927;
 }
 
+impl ToConst for U927 {
+    type Output = Const<927>;
+}
+
 impl ToUInt for Const<928> {
     type Output = U928;
 }
 
+impl ToConst for U928 {
+    type Output = Const<928>;
+}
+
 impl ToUInt for Const<929> {
     type Output = U929;
 }
 
+impl ToConst for U929 {
+    type Output = Const<929>;
+}
+
 impl ToUInt for Const<930> {
     type Output = U930;
 }
 
+impl ToConst for U930 {
+    type Output = Const<930>;
+}
+
 impl ToUInt for Const<931> {
     type Output = U931;
 }
 
+impl ToConst for U931 {
+    type Output = Const<931>;
+}
+
 impl ToUInt for Const<932> {
     type Output = U932;
 }
 
+impl ToConst for U932 {
+    type Output = Const<932>;
+}
+
 impl ToUInt for Const<933> {
     type Output = U933;
 }
 
+impl ToConst for U933 {
+    type Output = Const<933>;
+}
+
 impl ToUInt for Const<934> {
     type Output = U934;
 }
 
+impl ToConst for U934 {
+    type Output = Const<934>;
+}
+
 impl ToUInt for Const<935> {
     type Output = U935;
 }
 
+impl ToConst for U935 {
+    type Output = Const<935>;
+}
+
 impl ToUInt for Const<936> {
     type Output = U936;
 }
 
+impl ToConst for U936 {
+    type Output = Const<936>;
+}
+
 impl ToUInt for Const<937> {
     type Output = U937;
 }
 
+impl ToConst for U937 {
+    type Output = Const<937>;
+}
+
 impl ToUInt for Const<938> {
     type Output = U938;
 }
 
+impl ToConst for U938 {
+    type Output = Const<938>;
+}
+
 impl ToUInt for Const<939> {
     type Output = U939;
 }
 
+impl ToConst for U939 {
+    type Output = Const<939>;
+}
+
 impl ToUInt for Const<940> {
     type Output = U940;
 }
 
+impl ToConst for U940 {
+    type Output = Const<940>;
+}
+
 impl ToUInt for Const<941> {
     type Output = U941;
 }
 
+impl ToConst for U941 {
+    type Output = Const<941>;
+}
+
 impl ToUInt for Const<942> {
     type Output = U942;
 }
 
+impl ToConst for U942 {
+    type Output = Const<942>;
+}
+
 impl ToUInt for Const<943> {
     type Output = U943;
 }
 
+impl ToConst for U943 {
+    type Output = Const<943>;
+}
+
 impl ToUInt for Const<944> {
     type Output = U944;
 }
 
+impl ToConst for U944 {
+    type Output = Const<944>;
+}
+
 impl ToUInt for Const<945> {
     type Output = U945;
 }
 
+impl ToConst for U945 {
+    type Output = Const<945>;
+}
+
 impl ToUInt for Const<946> {
     type Output = U946;
 }
 
+impl ToConst for U946 {
+    type Output = Const<946>;
+}
+
 impl ToUInt for Const<947> {
     type Output = U947;
 }
 
+impl ToConst for U947 {
+    type Output = Const<947>;
+}
+
 impl ToUInt for Const<948> {
     type Output = U948;
 }
 
+impl ToConst for U948 {
+    type Output = Const<948>;
+}
+
 impl ToUInt for Const<949> {
     type Output = U949;
 }
 
+impl ToConst for U949 {
+    type Output = Const<949>;
+}
+
 impl ToUInt for Const<950> {
     type Output = U950;
 }
 
+impl ToConst for U950 {
+    type Output = Const<950>;
+}
+
 impl ToUInt for Const<951> {
     type Output = U951;
 }
 
+impl ToConst for U951 {
+    type Output = Const<951>;
+}
+
 impl ToUInt for Const<952> {
     type Output = U952;
 }
 
+impl ToConst for U952 {
+    type Output = Const<952>;
+}
+
 impl ToUInt for Const<953> {
     type Output = U953;
 }
 
+impl ToConst for U953 {
+    type Output = Const<953>;
+}
+
 impl ToUInt for Const<954> {
     type Output = U954;
 }
 
+impl ToConst for U954 {
+    type Output = Const<954>;
+}
+
 impl ToUInt for Const<955> {
     type Output = U955;
 }
 
+impl ToConst for U955 {
+    type Output = Const<955>;
+}
+
 impl ToUInt for Const<956> {
     type Output = U956;
 }
 
+impl ToConst for U956 {
+    type Output = Const<956>;
+}
+
 impl ToUInt for Const<957> {
     type Output = U957;
 }
 
+impl ToConst for U957 {
+    type Output = Const<957>;
+}
+
 impl ToUInt for Const<958> {
     type Output = U958;
 }
 
+impl ToConst for U958 {
+    type Output = Const<958>;
+}
+
 impl ToUInt for Const<959> {
     type Output = U959;
 }
 
+impl ToConst for U959 {
+    type Output = Const<959>;
+}
+
 impl ToUInt for Const<960> {
     type Output = U960;
 }
 
+impl ToConst for U960 {
+    type Output = Const<960>;
+}
+
 impl ToUInt for Const<961> {
     type Output = U961;
 }
 
+impl ToConst for U961 {
+    type Output = Const<961>;
+}
+
 impl ToUInt for Const<962> {
     type Output = U962;
 }
 
+impl ToConst for U962 {
+    type Output = Const<962>;
+}
+
 impl ToUInt for Const<963> {
     type Output = U963;
 }
 
+impl ToConst for U963 {
+    type Output = Const<963>;
+}
+
 impl ToUInt for Const<964> {
     type Output = U964;
 }
 
+impl ToConst for U964 {
+    type Output = Const<964>;
+}
+
 impl ToUInt for Const<965> {
     type Output = U965;
 }
 
+impl ToConst for U965 {
+    type Output = Const<965>;
+}
+
 impl ToUInt for Const<966> {
     type Output = U966;
 }
 
+impl ToConst for U966 {
+    type Output = Const<966>;
+}
+
 impl ToUInt for Const<967> {
     type Output = U967;
 }
 
+impl ToConst for U967 {
+    type Output = Const<967>;
+}
+
 impl ToUInt for Const<968> {
     type Output = U968;
 }
 
+impl ToConst for U968 {
+    type Output = Const<968>;
+}
+
 impl ToUInt for Const<969> {
     type Output = U969;
 }
 
+impl ToConst for U969 {
+    type Output = Const<969>;
+}
+
 impl ToUInt for Const<970> {
     type Output = U970;
 }
 
+impl ToConst for U970 {
+    type Output = Const<970>;
+}
+
 impl ToUInt for Const<971> {
     type Output = U971;
 }
 
+impl ToConst for U971 {
+    type Output = Const<971>;
+}
+
 impl ToUInt for Const<972> {
     type Output = U972;
 }
 
+impl ToConst for U972 {
+    type Output = Const<972>;
+}
+
 impl ToUInt for Const<973> {
     type Output = U973;
 }
 
+impl ToConst for U973 {
+    type Output = Const<973>;
+}
+
 impl ToUInt for Const<974> {
     type Output = U974;
 }
 
+impl ToConst for U974 {
+    type Output = Const<974>;
+}
+
 impl ToUInt for Const<975> {
     type Output = U975;
 }
 
+impl ToConst for U975 {
+    type Output = Const<975>;
+}
+
 impl ToUInt for Const<976> {
     type Output = U976;
 }
 
+impl ToConst for U976 {
+    type Output = Const<976>;
+}
+
 impl ToUInt for Const<977> {
     type Output = U977;
 }
 
+impl ToConst for U977 {
+    type Output = Const<977>;
+}
+
 impl ToUInt for Const<978> {
     type Output = U978;
 }
 
+impl ToConst for U978 {
+    type Output = Const<978>;
+}
+
 impl ToUInt for Const<979> {
     type Output = U979;
 }
 
+impl ToConst for U979 {
+    type Output = Const<979>;
+}
+
 impl ToUInt for Const<980> {
     type Output = U980;
 }
 
+impl ToConst for U980 {
+    type Output = Const<980>;
+}
+
 impl ToUInt for Const<981> {
     type Output = U981;
 }
 
+impl ToConst for U981 {
+    type Output = Const<981>;
+}
+
 impl ToUInt for Const<982> {
     type Output = U982;
 }
 
+impl ToConst for U982 {
+    type Output = Const<982>;
+}
+
 impl ToUInt for Const<983> {
     type Output = U983;
 }
 
+impl ToConst for U983 {
+    type Output = Const<983>;
+}
+
 impl ToUInt for Const<984> {
     type Output = U984;
 }
 
+impl ToConst for U984 {
+    type Output = Const<984>;
+}
+
 impl ToUInt for Const<985> {
     type Output = U985;
 }
 
+impl ToConst for U985 {
+    type Output = Const<985>;
+}
+
 impl ToUInt for Const<986> {
     type Output = U986;
 }
 
+impl ToConst for U986 {
+    type Output = Const<986>;
+}
+
 impl ToUInt for Const<987> {
     type Output = U987;
 }
 
+impl ToConst for U987 {
+    type Output = Const<987>;
+}
+
 impl ToUInt for Const<988> {
     type Output = U988;
 }
 
+impl ToConst for U988 {
+    type Output = Const<988>;
+}
+
 impl ToUInt for Const<989> {
     type Output = U989;
 }
 
+impl ToConst for U989 {
+    type Output = Const<989>;
+}
+
 impl ToUInt for Const<990> {
     type Output = U990;
 }
 
+impl ToConst for U990 {
+    type Output = Const<990>;
+}
+
 impl ToUInt for Const<991> {
     type Output = U991;
 }
 
+impl ToConst for U991 {
+    type Output = Const<991>;
+}
+
 impl ToUInt for Const<992> {
     type Output = U992;
 }
 
+impl ToConst for U992 {
+    type Output = Const<992>;
+}
+
 impl ToUInt for Const<993> {
     type Output = U993;
 }
 
+impl ToConst for U993 {
+    type Output = Const<993>;
+}
+
 impl ToUInt for Const<994> {
     type Output = U994;
 }
 
+impl ToConst for U994 {
+    type Output = Const<994>;
+}
+
 impl ToUInt for Const<995> {
     type Output = U995;
 }
 
+impl ToConst for U995 {
+    type Output = Const<995>;
+}
+
 impl ToUInt for Const<996> {
     type Output = U996;
 }
 
+impl ToConst for U996 {
+    type Output = Const<996>;
+}
+
 impl ToUInt for Const<997> {
     type Output = U997;
 }
 
+impl ToConst for U997 {
+    type Output = Const<997>;
+}
+
 impl ToUInt for Const<998> {
     type Output = U998;
 }
 
+impl ToConst for U998 {
+    type Output = Const<998>;
+}
+
 impl ToUInt for Const<999> {
     type Output = U999;
 }
 
+impl ToConst for U999 {
+    type Output = Const<999>;
+}
+
 impl ToUInt for Const<1000> {
     type Output = U1000;
 }
 
+impl ToConst for U1000 {
+    type Output = Const<1000>;
+}
+
 impl ToUInt for Const<1001> {
     type Output = U1001;
 }
 
+impl ToConst for U1001 {
+    type Output = Const<1001>;
+}
+
 impl ToUInt for Const<1002> {
     type Output = U1002;
 }
 
+impl ToConst for U1002 {
+    type Output = Const<1002>;
+}
+
 impl ToUInt for Const<1003> {
     type Output = U1003;
 }
 
+impl ToConst for U1003 {
+    type Output = Const<1003>;
+}
+
 impl ToUInt for Const<1004> {
     type Output = U1004;
 }
 
+impl ToConst for U1004 {
+    type Output = Const<1004>;
+}
+
 impl ToUInt for Const<1005> {
     type Output = U1005;
 }
 
+impl ToConst for U1005 {
+    type Output = Const<1005>;
+}
+
 impl ToUInt for Const<1006> {
     type Output = U1006;
 }
 
+impl ToConst for U1006 {
+    type Output = Const<1006>;
+}
+
 impl ToUInt for Const<1007> {
     type Output = U1007;
 }
 
+impl ToConst for U1007 {
+    type Output = Const<1007>;
+}
+
 impl ToUInt for Const<1008> {
     type Output = U1008;
 }
 
+impl ToConst for U1008 {
+    type Output = Const<1008>;
+}
+
 impl ToUInt for Const<1009> {
     type Output = U1009;
 }
 
+impl ToConst for U1009 {
+    type Output = Const<1009>;
+}
+
 impl ToUInt for Const<1010> {
     type Output = U1010;
 }
 
+impl ToConst for U1010 {
+    type Output = Const<1010>;
+}
+
 impl ToUInt for Const<1011> {
     type Output = U1011;
 }
 
+impl ToConst for U1011 {
+    type Output = Const<1011>;
+}
+
 impl ToUInt for Const<1012> {
     type Output = U1012;
 }
 
+impl ToConst for U1012 {
+    type Output = Const<1012>;
+}
+
 impl ToUInt for Const<1013> {
     type Output = U1013;
 }
 
+impl ToConst for U1013 {
+    type Output = Const<1013>;
+}
+
 impl ToUInt for Const<1014> {
     type Output = U1014;
 }
 
+impl ToConst for U1014 {
+    type Output = Const<1014>;
+}
+
 impl ToUInt for Const<1015> {
     type Output = U1015;
 }
 
+impl ToConst for U1015 {
+    type Output = Const<1015>;
+}
+
 impl ToUInt for Const<1016> {
     type Output = U1016;
 }
 
+impl ToConst for U1016 {
+    type Output = Const<1016>;
+}
+
 impl ToUInt for Const<1017> {
     type Output = U1017;
 }
 
+impl ToConst for U1017 {
+    type Output = Const<1017>;
+}
+
 impl ToUInt for Const<1018> {
     type Output = U1018;
 }
 
+impl ToConst for U1018 {
+    type Output = Const<1018>;
+}
+
 impl ToUInt for Const<1019> {
     type Output = U1019;
 }
 
+impl ToConst for U1019 {
+    type Output = Const<1019>;
+}
+
 impl ToUInt for Const<1020> {
     type Output = U1020;
 }
 
+impl ToConst for U1020 {
+    type Output = Const<1020>;
+}
+
 impl ToUInt for Const<1021> {
     type Output = U1021;
 }
 
+impl ToConst for U1021 {
+    type Output = Const<1021>;
+}
+
 impl ToUInt for Const<1022> {
     type Output = U1022;
 }
 
+impl ToConst for U1022 {
+    type Output = Const<1022>;
+}
+
 impl ToUInt for Const<1023> {
     type Output = U1023;
 }
 
+impl ToConst for U1023 {
+    type Output = Const<1023>;
+}
+
 impl ToUInt for Const<1024> {
     type Output = U1024;
 }
 
+impl ToConst for U1024 {
+    type Output = Const<1024>;
+}
+
 impl ToUInt for Const<3600> {
     type Output = U3600;
 }
 
+impl ToConst for U3600 {
+    type Output = Const<3600>;
+}
+
 impl ToUInt for Const<2047> {
     type Output = U2047;
 }
 
+impl ToConst for U2047 {
+    type Output = Const<2047>;
+}
+
 impl ToUInt for Const<2048> {
     type Output = U2048;
 }
 
+impl ToConst for U2048 {
+    type Output = Const<2048>;
+}
+
 impl ToUInt for Const<4095> {
     type Output = U4095;
 }
 
+impl ToConst for U4095 {
+    type Output = Const<4095>;
+}
+
 impl ToUInt for Const<4096> {
     type Output = U4096;
 }
 
+impl ToConst for U4096 {
+    type Output = Const<4096>;
+}
+
 impl ToUInt for Const<8191> {
     type Output = U8191;
 }
 
+impl ToConst for U8191 {
+    type Output = Const<8191>;
+}
+
 impl ToUInt for Const<8192> {
     type Output = U8192;
 }
 
+impl ToConst for U8192 {
+    type Output = Const<8192>;
+}
+
 impl ToUInt for Const<16383> {
     type Output = U16383;
 }
 
+impl ToConst for U16383 {
+    type Output = Const<16383>;
+}
+
 impl ToUInt for Const<16384> {
     type Output = U16384;
 }
 
+impl ToConst for U16384 {
+    type Output = Const<16384>;
+}
+
 impl ToUInt for Const<32767> {
     type Output = U32767;
 }
 
+impl ToConst for U32767 {
+    type Output = Const<32767>;
+}
+
 impl ToUInt for Const<32768> {
     type Output = U32768;
 }
 
+impl ToConst for U32768 {
+    type Output = Const<32768>;
+}
+
 impl ToUInt for Const<65535> {
     type Output = U65535;
 }
 
+impl ToConst for U65535 {
+    type Output = Const<65535>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<65536> {
     type Output = U65536;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U65536 {
+    type Output = Const<65536>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<131071> {
     type Output = U131071;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U131071 {
+    type Output = Const<131071>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<131072> {
     type Output = U131072;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U131072 {
+    type Output = Const<131072>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<262143> {
     type Output = U262143;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U262143 {
+    type Output = Const<262143>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<262144> {
     type Output = U262144;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U262144 {
+    type Output = Const<262144>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<524287> {
     type Output = U524287;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U524287 {
+    type Output = Const<524287>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<524288> {
     type Output = U524288;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U524288 {
+    type Output = Const<524288>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<1048575> {
     type Output = U1048575;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U1048575 {
+    type Output = Const<1048575>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<1048576> {
     type Output = U1048576;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U1048576 {
+    type Output = Const<1048576>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<2097151> {
     type Output = U2097151;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U2097151 {
+    type Output = Const<2097151>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<2097152> {
     type Output = U2097152;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U2097152 {
+    type Output = Const<2097152>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<4194303> {
     type Output = U4194303;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U4194303 {
+    type Output = Const<4194303>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<4194304> {
     type Output = U4194304;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U4194304 {
+    type Output = Const<4194304>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<8388607> {
     type Output = U8388607;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U8388607 {
+    type Output = Const<8388607>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<8388608> {
     type Output = U8388608;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U8388608 {
+    type Output = Const<8388608>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<16777215> {
     type Output = U16777215;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U16777215 {
+    type Output = Const<16777215>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<16777216> {
     type Output = U16777216;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U16777216 {
+    type Output = Const<16777216>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<33554431> {
     type Output = U33554431;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U33554431 {
+    type Output = Const<33554431>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<33554432> {
     type Output = U33554432;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U33554432 {
+    type Output = Const<33554432>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<67108863> {
     type Output = U67108863;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U67108863 {
+    type Output = Const<67108863>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<67108864> {
     type Output = U67108864;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U67108864 {
+    type Output = Const<67108864>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<134217727> {
     type Output = U134217727;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U134217727 {
+    type Output = Const<134217727>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<134217728> {
     type Output = U134217728;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U134217728 {
+    type Output = Const<134217728>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<268435455> {
     type Output = U268435455;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U268435455 {
+    type Output = Const<268435455>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<268435456> {
     type Output = U268435456;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U268435456 {
+    type Output = Const<268435456>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<536870911> {
     type Output = U536870911;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U536870911 {
+    type Output = Const<536870911>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<536870912> {
     type Output = U536870912;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U536870912 {
+    type Output = Const<536870912>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<1073741823> {
     type Output = U1073741823;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U1073741823 {
+    type Output = Const<1073741823>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<1073741824> {
     type Output = U1073741824;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U1073741824 {
+    type Output = Const<1073741824>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<2147483647> {
     type Output = U2147483647;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U2147483647 {
+    type Output = Const<2147483647>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<2147483648> {
     type Output = U2147483648;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U2147483648 {
+    type Output = Const<2147483648>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<4294967295> {
     type Output = U4294967295;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U4294967295 {
+    type Output = Const<4294967295>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<4294967296> {
     type Output = U4294967296;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U4294967296 {
+    type Output = Const<4294967296>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<8589934591> {
     type Output = U8589934591;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U8589934591 {
+    type Output = Const<8589934591>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<8589934592> {
     type Output = U8589934592;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U8589934592 {
+    type Output = Const<8589934592>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<17179869183> {
     type Output = U17179869183;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U17179869183 {
+    type Output = Const<17179869183>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<17179869184> {
     type Output = U17179869184;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U17179869184 {
+    type Output = Const<17179869184>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<34359738367> {
     type Output = U34359738367;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U34359738367 {
+    type Output = Const<34359738367>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<34359738368> {
     type Output = U34359738368;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U34359738368 {
+    type Output = Const<34359738368>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<68719476735> {
     type Output = U68719476735;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U68719476735 {
+    type Output = Const<68719476735>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<68719476736> {
     type Output = U68719476736;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U68719476736 {
+    type Output = Const<68719476736>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<137438953471> {
     type Output = U137438953471;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U137438953471 {
+    type Output = Const<137438953471>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<137438953472> {
     type Output = U137438953472;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U137438953472 {
+    type Output = Const<137438953472>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<274877906943> {
     type Output = U274877906943;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U274877906943 {
+    type Output = Const<274877906943>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<274877906944> {
     type Output = U274877906944;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U274877906944 {
+    type Output = Const<274877906944>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<549755813887> {
     type Output = U549755813887;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U549755813887 {
+    type Output = Const<549755813887>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<549755813888> {
     type Output = U549755813888;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U549755813888 {
+    type Output = Const<549755813888>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1099511627775> {
     type Output = U1099511627775;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1099511627775 {
+    type Output = Const<1099511627775>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1099511627776> {
     type Output = U1099511627776;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1099511627776 {
+    type Output = Const<1099511627776>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<2199023255551> {
     type Output = U2199023255551;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U2199023255551 {
+    type Output = Const<2199023255551>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<2199023255552> {
     type Output = U2199023255552;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U2199023255552 {
+    type Output = Const<2199023255552>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<4398046511103> {
     type Output = U4398046511103;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U4398046511103 {
+    type Output = Const<4398046511103>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<4398046511104> {
     type Output = U4398046511104;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U4398046511104 {
+    type Output = Const<4398046511104>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<8796093022207> {
     type Output = U8796093022207;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U8796093022207 {
+    type Output = Const<8796093022207>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<8796093022208> {
     type Output = U8796093022208;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U8796093022208 {
+    type Output = Const<8796093022208>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<17592186044415> {
     type Output = U17592186044415;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U17592186044415 {
+    type Output = Const<17592186044415>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<17592186044416> {
     type Output = U17592186044416;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U17592186044416 {
+    type Output = Const<17592186044416>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<35184372088831> {
     type Output = U35184372088831;
 }
 
 #[cfg(target_pointer_width = "64")]
-impl ToUInt for Const<35184372088832> {
+impl ToConst for U35184372088831 {
+    type Output = Const<35184372088831>;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToUInt for Const<35184372088832> {
     type Output = U35184372088832;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U35184372088832 {
+    type Output = Const<35184372088832>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<70368744177663> {
     type Output = U70368744177663;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U70368744177663 {
+    type Output = Const<70368744177663>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<70368744177664> {
     type Output = U70368744177664;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U70368744177664 {
+    type Output = Const<70368744177664>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<140737488355327> {
     type Output = U140737488355327;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U140737488355327 {
+    type Output = Const<140737488355327>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<140737488355328> {
     type Output = U140737488355328;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U140737488355328 {
+    type Output = Const<140737488355328>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<281474976710655> {
     type Output = U281474976710655;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U281474976710655 {
+    type Output = Const<281474976710655>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<281474976710656> {
     type Output = U281474976710656;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U281474976710656 {
+    type Output = Const<281474976710656>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<562949953421311> {
     type Output = U562949953421311;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U562949953421311 {
+    type Output = Const<562949953421311>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<562949953421312> {
     type Output = U562949953421312;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U562949953421312 {
+    type Output = Const<562949953421312>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1125899906842623> {
     type Output = U1125899906842623;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1125899906842623 {
+    type Output = Const<1125899906842623>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1125899906842624> {
     type Output = U1125899906842624;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1125899906842624 {
+    type Output = Const<1125899906842624>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<2251799813685247> {
     type Output = U2251799813685247;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U2251799813685247 {
+    type Output = Const<2251799813685247>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<2251799813685248> {
     type Output = U2251799813685248;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U2251799813685248 {
+    type Output = Const<2251799813685248>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<4503599627370495> {
     type Output = U4503599627370495;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U4503599627370495 {
+    type Output = Const<4503599627370495>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<4503599627370496> {
     type Output = U4503599627370496;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U4503599627370496 {
+    type Output = Const<4503599627370496>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<9007199254740991> {
     type Output = U9007199254740991;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U9007199254740991 {
+    type Output = Const<9007199254740991>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<9007199254740992> {
     type Output = U9007199254740992;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U9007199254740992 {
+    type Output = Const<9007199254740992>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<18014398509481983> {
     type Output = U18014398509481983;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U18014398509481983 {
+    type Output = Const<18014398509481983>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<18014398509481984> {
     type Output = U18014398509481984;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U18014398509481984 {
+    type Output = Const<18014398509481984>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<36028797018963967> {
     type Output = U36028797018963967;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U36028797018963967 {
+    type Output = Const<36028797018963967>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<36028797018963968> {
     type Output = U36028797018963968;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U36028797018963968 {
+    type Output = Const<36028797018963968>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<72057594037927935> {
     type Output = U72057594037927935;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U72057594037927935 {
+    type Output = Const<72057594037927935>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<72057594037927936> {
     type Output = U72057594037927936;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U72057594037927936 {
+    type Output = Const<72057594037927936>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<144115188075855871> {
     type Output = U144115188075855871;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U144115188075855871 {
+    type Output = Const<144115188075855871>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<144115188075855872> {
     type Output = U144115188075855872;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U144115188075855872 {
+    type Output = Const<144115188075855872>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<288230376151711743> {
     type Output = U288230376151711743;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U288230376151711743 {
+    type Output = Const<288230376151711743>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<288230376151711744> {
     type Output = U288230376151711744;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U288230376151711744 {
+    type Output = Const<288230376151711744>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<576460752303423487> {
     type Output = U576460752303423487;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U576460752303423487 {
+    type Output = Const<576460752303423487>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<576460752303423488> {
     type Output = U576460752303423488;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U576460752303423488 {
+    type Output = Const<576460752303423488>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1152921504606846975> {
     type Output = U1152921504606846975;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1152921504606846975 {
+    type Output = Const<1152921504606846975>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1152921504606846976> {
     type Output = U1152921504606846976;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1152921504606846976 {
+    type Output = Const<1152921504606846976>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<2305843009213693951> {
     type Output = U2305843009213693951;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U2305843009213693951 {
+    type Output = Const<2305843009213693951>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<2305843009213693952> {
     type Output = U2305843009213693952;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U2305843009213693952 {
+    type Output = Const<2305843009213693952>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<4611686018427387903> {
     type Output = U4611686018427387903;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U4611686018427387903 {
+    type Output = Const<4611686018427387903>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<4611686018427387904> {
     type Output = U4611686018427387904;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U4611686018427387904 {
+    type Output = Const<4611686018427387904>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<9223372036854775807> {
     type Output = U9223372036854775807;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U9223372036854775807 {
+    type Output = Const<9223372036854775807>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<9223372036854775808> {
     type Output = U9223372036854775808;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U9223372036854775808 {
+    type Output = Const<9223372036854775808>;
+}
+
 impl ToUInt for Const<10000> {
     type Output = U10000;
 }
 
+impl ToConst for U10000 {
+    type Output = Const<10000>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<100000> {
     type Output = U100000;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U100000 {
+    type Output = Const<100000>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<1000000> {
     type Output = U1000000;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U1000000 {
+    type Output = Const<1000000>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<10000000> {
     type Output = U10000000;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U10000000 {
+    type Output = Const<10000000>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<100000000> {
     type Output = U100000000;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U100000000 {
+    type Output = Const<100000000>;
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl ToUInt for Const<1000000000> {
     type Output = U1000000000;
 }
 
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToConst for U1000000000 {
+    type Output = Const<1000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<10000000000> {
     type Output = U10000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U10000000000 {
+    type Output = Const<10000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<100000000000> {
     type Output = U100000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U100000000000 {
+    type Output = Const<100000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1000000000000> {
     type Output = U1000000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1000000000000 {
+    type Output = Const<1000000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<10000000000000> {
     type Output = U10000000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U10000000000000 {
+    type Output = Const<10000000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<100000000000000> {
     type Output = U100000000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U100000000000000 {
+    type Output = Const<100000000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1000000000000000> {
     type Output = U1000000000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1000000000000000 {
+    type Output = Const<1000000000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<10000000000000000> {
     type Output = U10000000000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U10000000000000000 {
+    type Output = Const<10000000000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<100000000000000000> {
     type Output = U100000000000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U100000000000000000 {
+    type Output = Const<100000000000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<1000000000000000000> {
     type Output = U1000000000000000000;
 }
 
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U1000000000000000000 {
+    type Output = Const<1000000000000000000>;
+}
+
 #[cfg(target_pointer_width = "64")]
 impl ToUInt for Const<10000000000000000000> {
     type Output = U10000000000000000000;
 }
+
+#[cfg(target_pointer_width = "64")]
+impl ToConst for U10000000000000000000 {
+    type Output = Const<10000000000000000000>;
+}
+
+impl ToInteger for ConstI<0> {
+    type Output = Z0;
+}
+
+impl ToInteger for ConstI<1> {
+    type Output = P1;
+}
+
+impl ToInteger for ConstI<-1> {
+    type Output = N1;
+}
+
+impl ToInteger for ConstI<2> {
+    type Output = P2;
+}
+
+impl ToInteger for ConstI<-2> {
+    type Output = N2;
+}
+
+impl ToInteger for ConstI<3> {
+    type Output = P3;
+}
+
+impl ToInteger for ConstI<-3> {
+    type Output = N3;
+}
+
+impl ToInteger for ConstI<4> {
+    type Output = P4;
+}
+
+impl ToInteger for ConstI<-4> {
+    type Output = N4;
+}
+
+impl ToInteger for ConstI<5> {
+    type Output = P5;
+}
+
+impl ToInteger for ConstI<-5> {
+    type Output = N5;
+}
+
+impl ToInteger for ConstI<6> {
+    type Output = P6;
+}
+
+impl ToInteger for ConstI<-6> {
+    type Output = N6;
+}
+
+impl ToInteger for ConstI<7> {
+    type Output = P7;
+}
+
+impl ToInteger for ConstI<-7> {
+    type Output = N7;
+}
+
+impl ToInteger for ConstI<8> {
+    type Output = P8;
+}
+
+impl ToInteger for ConstI<-8> {
+    type Output = N8;
+}
+
+impl ToInteger for ConstI<9> {
+    type Output = P9;
+}
+
+impl ToInteger for ConstI<-9> {
+    type Output = N9;
+}
+
+impl ToInteger for ConstI<10> {
+    type Output = P10;
+}
+
+impl ToInteger for ConstI<-10> {
+    type Output = N10;
+}
+
+impl ToInteger for ConstI<11> {
+    type Output = P11;
+}
+
+impl ToInteger for ConstI<-11> {
+    type Output = N11;
+}
+
+impl ToInteger for ConstI<12> {
+    type Output = P12;
+}
+
+impl ToInteger for ConstI<-12> {
+    type Output = N12;
+}
+
+impl ToInteger for ConstI<13> {
+    type Output = P13;
+}
+
+impl ToInteger for ConstI<-13> {
+    type Output = N13;
+}
+
+impl ToInteger for ConstI<14> {
+    type Output = P14;
+}
+
+impl ToInteger for ConstI<-14> {
+    type Output = N14;
+}
+
+impl ToInteger for ConstI<15> {
+    type Output = P15;
+}
+
+impl ToInteger for ConstI<-15> {
+    type Output = N15;
+}
+
+impl ToInteger for ConstI<16> {
+    type Output = P16;
+}
+
+impl ToInteger for ConstI<-16> {
+    type Output = N16;
+}
+
+impl ToInteger for ConstI<17> {
+    type Output = P17;
+}
+
+impl ToInteger for ConstI<-17> {
+    type Output = N17;
+}
+
+impl ToInteger for ConstI<18> {
+    type Output = P18;
+}
+
+impl ToInteger for ConstI<-18> {
+    type Output = N18;
+}
+
+impl ToInteger for ConstI<19> {
+    type Output = P19;
+}
+
+impl ToInteger for ConstI<-19> {
+    type Output = N19;
+}
+
+impl ToInteger for ConstI<20> {
+    type Output = P20;
+}
+
+impl ToInteger for ConstI<-20> {
+    type Output = N20;
+}
+
+impl ToInteger for ConstI<21> {
+    type Output = P21;
+}
+
+impl ToInteger for ConstI<-21> {
+    type Output = N21;
+}
+
+impl ToInteger for ConstI<22> {
+    type Output = P22;
+}
+
+impl ToInteger for ConstI<-22> {
+    type Output = N22;
+}
+
+impl ToInteger for ConstI<23> {
+    type Output = P23;
+}
+
+impl ToInteger for ConstI<-23> {
+    type Output = N23;
+}
+
+impl ToInteger for ConstI<24> {
+    type Output = P24;
+}
+
+impl ToInteger for ConstI<-24> {
+    type Output = N24;
+}
+
+impl ToInteger for ConstI<25> {
+    type Output = P25;
+}
+
+impl ToInteger for ConstI<-25> {
+    type Output = N25;
+}
+
+impl ToInteger for ConstI<26> {
+    type Output = P26;
+}
+
+impl ToInteger for ConstI<-26> {
+    type Output = N26;
+}
+
+impl ToInteger for ConstI<27> {
+    type Output = P27;
+}
+
+impl ToInteger for ConstI<-27> {
+    type Output = N27;
+}
+
+impl ToInteger for ConstI<28> {
+    type Output = P28;
+}
+
+impl ToInteger for ConstI<-28> {
+    type Output = N28;
+}
+
+impl ToInteger for ConstI<29> {
+    type Output = P29;
+}
+
+impl ToInteger for ConstI<-29> {
+    type Output = N29;
+}
+
+impl ToInteger for ConstI<30> {
+    type Output = P30;
+}
+
+impl ToInteger for ConstI<-30> {
+    type Output = N30;
+}
+
+impl ToInteger for ConstI<31> {
+    type Output = P31;
+}
+
+impl ToInteger for ConstI<-31> {
+    type Output = N31;
+}
+
+impl ToInteger for ConstI<32> {
+    type Output = P32;
+}
+
+impl ToInteger for ConstI<-32> {
+    type Output = N32;
+}
+
+impl ToInteger for ConstI<33> {
+    type Output = P33;
+}
+
+impl ToInteger for ConstI<-33> {
+    type Output = N33;
+}
+
+impl ToInteger for ConstI<34> {
+    type Output = P34;
+}
+
+impl ToInteger for ConstI<-34> {
+    type Output = N34;
+}
+
+impl ToInteger for ConstI<35> {
+    type Output = P35;
+}
+
+impl ToInteger for ConstI<-35> {
+    type Output = N35;
+}
+
+impl ToInteger for ConstI<36> {
+    type Output = P36;
+}
+
+impl ToInteger for ConstI<-36> {
+    type Output = N36;
+}
+
+impl ToInteger for ConstI<37> {
+    type Output = P37;
+}
+
+impl ToInteger for ConstI<-37> {
+    type Output = N37;
+}
+
+impl ToInteger for ConstI<38> {
+    type Output = P38;
+}
+
+impl ToInteger for ConstI<-38> {
+    type Output = N38;
+}
+
+impl ToInteger for ConstI<39> {
+    type Output = P39;
+}
+
+impl ToInteger for ConstI<-39> {
+    type Output = N39;
+}
+
+impl ToInteger for ConstI<40> {
+    type Output = P40;
+}
+
+impl ToInteger for ConstI<-40> {
+    type Output = N40;
+}
+
+impl ToInteger for ConstI<41> {
+    type Output = P41;
+}
+
+impl ToInteger for ConstI<-41> {
+    type Output = N41;
+}
+
+impl ToInteger for ConstI<42> {
+    type Output = P42;
+}
+
+impl ToInteger for ConstI<-42> {
+    type Output = N42;
+}
+
+impl ToInteger for ConstI<43> {
+    type Output = P43;
+}
+
+impl ToInteger for ConstI<-43> {
+    type Output = N43;
+}
+
+impl ToInteger for ConstI<44> {
+    type Output = P44;
+}
+
+impl ToInteger for ConstI<-44> {
+    type Output = N44;
+}
+
+impl ToInteger for ConstI<45> {
+    type Output = P45;
+}
+
+impl ToInteger for ConstI<-45> {
+    type Output = N45;
+}
+
+impl ToInteger for ConstI<46> {
+    type Output = P46;
+}
+
+impl ToInteger for ConstI<-46> {
+    type Output = N46;
+}
+
+impl ToInteger for ConstI<47> {
+    type Output = P47;
+}
+
+impl ToInteger for ConstI<-47> {
+    type Output = N47;
+}
+
+impl ToInteger for ConstI<48> {
+    type Output = P48;
+}
+
+impl ToInteger for ConstI<-48> {
+    type Output = N48;
+}
+
+impl ToInteger for ConstI<49> {
+    type Output = P49;
+}
+
+impl ToInteger for ConstI<-49> {
+    type Output = N49;
+}
+
+impl ToInteger for ConstI<50> {
+    type Output = P50;
+}
+
+impl ToInteger for ConstI<-50> {
+    type Output = N50;
+}
+
+impl ToInteger for ConstI<51> {
+    type Output = P51;
+}
+
+impl ToInteger for ConstI<-51> {
+    type Output = N51;
+}
+
+impl ToInteger for ConstI<52> {
+    type Output = P52;
+}
+
+impl ToInteger for ConstI<-52> {
+    type Output = N52;
+}
+
+impl ToInteger for ConstI<53> {
+    type Output = P53;
+}
+
+impl ToInteger for ConstI<-53> {
+    type Output = N53;
+}
+
+impl ToInteger for ConstI<54> {
+    type Output = P54;
+}
+
+impl ToInteger for ConstI<-54> {
+    type Output = N54;
+}
+
+impl ToInteger for ConstI<55> {
+    type Output = P55;
+}
+
+impl ToInteger for ConstI<-55> {
+    type Output = N55;
+}
+
+impl ToInteger for ConstI<56> {
+    type Output = P56;
+}
+
+impl ToInteger for ConstI<-56> {
+    type Output = N56;
+}
+
+impl ToInteger for ConstI<57> {
+    type Output = P57;
+}
+
+impl ToInteger for ConstI<-57> {
+    type Output = N57;
+}
+
+impl ToInteger for ConstI<58> {
+    type Output = P58;
+}
+
+impl ToInteger for ConstI<-58> {
+    type Output = N58;
+}
+
+impl ToInteger for ConstI<59> {
+    type Output = P59;
+}
+
+impl ToInteger for ConstI<-59> {
+    type Output = N59;
+}
+
+impl ToInteger for ConstI<60> {
+    type Output = P60;
+}
+
+impl ToInteger for ConstI<-60> {
+    type Output = N60;
+}
+
+impl ToInteger for ConstI<61> {
+    type Output = P61;
+}
+
+impl ToInteger for ConstI<-61> {
+    type Output = N61;
+}
+
+impl ToInteger for ConstI<62> {
+    type Output = P62;
+}
+
+impl ToInteger for ConstI<-62> {
+    type Output = N62;
+}
+
+impl ToInteger for ConstI<63> {
+    type Output = P63;
+}
+
+impl ToInteger for ConstI<-63> {
+    type Output = N63;
+}
+
+impl ToInteger for ConstI<64> {
+    type Output = P64;
+}
+
+impl ToInteger for ConstI<-64> {
+    type Output = N64;
+}
+
+impl ToInteger for ConstI<65> {
+    type Output = P65;
+}
+
+impl ToInteger for ConstI<-65> {
+    type Output = N65;
+}
+
+impl ToInteger for ConstI<66> {
+    type Output = P66;
+}
+
+impl ToInteger for ConstI<-66> {
+    type Output = N66;
+}
+
+impl ToInteger for ConstI<67> {
+    type Output = P67;
+}
+
+impl ToInteger for ConstI<-67> {
+    type Output = N67;
+}
+
+impl ToInteger for ConstI<68> {
+    type Output = P68;
+}
+
+impl ToInteger for ConstI<-68> {
+    type Output = N68;
+}
+
+impl ToInteger for ConstI<69> {
+    type Output = P69;
+}
+
+impl ToInteger for ConstI<-69> {
+    type Output = N69;
+}
+
+impl ToInteger for ConstI<70> {
+    type Output = P70;
+}
+
+impl ToInteger for ConstI<-70> {
+    type Output = N70;
+}
+
+impl ToInteger for ConstI<71> {
+    type Output = P71;
+}
+
+impl ToInteger for ConstI<-71> {
+    type Output = N71;
+}
+
+impl ToInteger for ConstI<72> {
+    type Output = P72;
+}
+
+impl ToInteger for ConstI<-72> {
+    type Output = N72;
+}
+
+impl ToInteger for ConstI<73> {
+    type Output = P73;
+}
+
+impl ToInteger for ConstI<-73> {
+    type Output = N73;
+}
+
+impl ToInteger for ConstI<74> {
+    type Output = P74;
+}
+
+impl ToInteger for ConstI<-74> {
+    type Output = N74;
+}
+
+impl ToInteger for ConstI<75> {
+    type Output = P75;
+}
+
+impl ToInteger for ConstI<-75> {
+    type Output = N75;
+}
+
+impl ToInteger for ConstI<76> {
+    type Output = P76;
+}
+
+impl ToInteger for ConstI<-76> {
+    type Output = N76;
+}
+
+impl ToInteger for ConstI<77> {
+    type Output = P77;
+}
+
+impl ToInteger for ConstI<-77> {
+    type Output = N77;
+}
+
+impl ToInteger for ConstI<78> {
+    type Output = P78;
+}
+
+impl ToInteger for ConstI<-78> {
+    type Output = N78;
+}
+
+impl ToInteger for ConstI<79> {
+    type Output = P79;
+}
+
+impl ToInteger for ConstI<-79> {
+    type Output = N79;
+}
+
+impl ToInteger for ConstI<80> {
+    type Output = P80;
+}
+
+impl ToInteger for ConstI<-80> {
+    type Output = N80;
+}
+
+impl ToInteger for ConstI<81> {
+    type Output = P81;
+}
+
+impl ToInteger for ConstI<-81> {
+    type Output = N81;
+}
+
+impl ToInteger for ConstI<82> {
+    type Output = P82;
+}
+
+impl ToInteger for ConstI<-82> {
+    type Output = N82;
+}
+
+impl ToInteger for ConstI<83> {
+    type Output = P83;
+}
+
+impl ToInteger for ConstI<-83> {
+    type Output = N83;
+}
+
+impl ToInteger for ConstI<84> {
+    type Output = P84;
+}
+
+impl ToInteger for ConstI<-84> {
+    type Output = N84;
+}
+
+impl ToInteger for ConstI<85> {
+    type Output = P85;
+}
+
+impl ToInteger for ConstI<-85> {
+    type Output = N85;
+}
+
+impl ToInteger for ConstI<86> {
+    type Output = P86;
+}
+
+impl ToInteger for ConstI<-86> {
+    type Output = N86;
+}
+
+impl ToInteger for ConstI<87> {
+    type Output = P87;
+}
+
+impl ToInteger for ConstI<-87> {
+    type Output = N87;
+}
+
+impl ToInteger for ConstI<88> {
+    type Output = P88;
+}
+
+impl ToInteger for ConstI<-88> {
+    type Output = N88;
+}
+
+impl ToInteger for ConstI<89> {
+    type Output = P89;
+}
+
+impl ToInteger for ConstI<-89> {
+    type Output = N89;
+}
+
+impl ToInteger for ConstI<90> {
+    type Output = P90;
+}
+
+impl ToInteger for ConstI<-90> {
+    type Output = N90;
+}
+
+impl ToInteger for ConstI<91> {
+    type Output = P91;
+}
+
+impl ToInteger for ConstI<-91> {
+    type Output = N91;
+}
+
+impl ToInteger for ConstI<92> {
+    type Output = P92;
+}
+
+impl ToInteger for ConstI<-92> {
+    type Output = N92;
+}
+
+impl ToInteger for ConstI<93> {
+    type Output = P93;
+}
+
+impl ToInteger for ConstI<-93> {
+    type Output = N93;
+}
+
+impl ToInteger for ConstI<94> {
+    type Output = P94;
+}
+
+impl ToInteger for ConstI<-94> {
+    type Output = N94;
+}
+
+impl ToInteger for ConstI<95> {
+    type Output = P95;
+}
+
+impl ToInteger for ConstI<-95> {
+    type Output = N95;
+}
+
+impl ToInteger for ConstI<96> {
+    type Output = P96;
+}
+
+impl ToInteger for ConstI<-96> {
+    type Output = N96;
+}
+
+impl ToInteger for ConstI<97> {
+    type Output = P97;
+}
+
+impl ToInteger for ConstI<-97> {
+    type Output = N97;
+}
+
+impl ToInteger for ConstI<98> {
+    type Output = P98;
+}
+
+impl ToInteger for ConstI<-98> {
+    type Output = N98;
+}
+
+impl ToInteger for ConstI<99> {
+    type Output = P99;
+}
+
+impl ToInteger for ConstI<-99> {
+    type Output = N99;
+}
+
+impl ToInteger for ConstI<100> {
+    type Output = P100;
+}
+
+impl ToInteger for ConstI<-100> {
+    type Output = N100;
+}
+
+impl ToInteger for ConstI<101> {
+    type Output = P101;
+}
+
+impl ToInteger for ConstI<-101> {
+    type Output = N101;
+}
+
+impl ToInteger for ConstI<102> {
+    type Output = P102;
+}
+
+impl ToInteger for ConstI<-102> {
+    type Output = N102;
+}
+
+impl ToInteger for ConstI<103> {
+    type Output = P103;
+}
+
+impl ToInteger for ConstI<-103> {
+    type Output = N103;
+}
+
+impl ToInteger for ConstI<104> {
+    type Output = P104;
+}
+
+impl ToInteger for ConstI<-104> {
+    type Output = N104;
+}
+
+impl ToInteger for ConstI<105> {
+    type Output = P105;
+}
+
+impl ToInteger for ConstI<-105> {
+    type Output = N105;
+}
+
+impl ToInteger for ConstI<106> {
+    type Output = P106;
+}
+
+impl ToInteger for ConstI<-106> {
+    type Output = N106;
+}
+
+impl ToInteger for ConstI<107> {
+    type Output = P107;
+}
+
+impl ToInteger for ConstI<-107> {
+    type Output = N107;
+}
+
+impl ToInteger for ConstI<108> {
+    type Output = P108;
+}
+
+impl ToInteger for ConstI<-108> {
+    type Output = N108;
+}
+
+impl ToInteger for ConstI<109> {
+    type Output = P109;
+}
+
+impl ToInteger for ConstI<-109> {
+    type Output = N109;
+}
+
+impl ToInteger for ConstI<110> {
+    type Output = P110;
+}
+
+impl ToInteger for ConstI<-110> {
+    type Output = N110;
+}
+
+impl ToInteger for ConstI<111> {
+    type Output = P111;
+}
+
+impl ToInteger for ConstI<-111> {
+    type Output = N111;
+}
+
+impl ToInteger for ConstI<112> {
+    type Output = P112;
+}
+
+impl ToInteger for ConstI<-112> {
+    type Output = N112;
+}
+
+impl ToInteger for ConstI<113> {
+    type Output = P113;
+}
+
+impl ToInteger for ConstI<-113> {
+    type Output = N113;
+}
+
+impl ToInteger for ConstI<114> {
+    type Output = P114;
+}
+
+impl ToInteger for ConstI<-114> {
+    type Output = N114;
+}
+
+impl ToInteger for ConstI<115> {
+    type Output = P115;
+}
+
+impl ToInteger for ConstI<-115> {
+    type Output = N115;
+}
+
+impl ToInteger for ConstI<116> {
+    type Output = P116;
+}
+
+impl ToInteger for ConstI<-116> {
+    type Output = N116;
+}
+
+impl ToInteger for ConstI<117> {
+    type Output = P117;
+}
+
+impl ToInteger for ConstI<-117> {
+    type Output = N117;
+}
+
+impl ToInteger for ConstI<118> {
+    type Output = P118;
+}
+
+impl ToInteger for ConstI<-118> {
+    type Output = N118;
+}
+
+impl ToInteger for ConstI<119> {
+    type Output = P119;
+}
+
+impl ToInteger for ConstI<-119> {
+    type Output = N119;
+}
+
+impl ToInteger for ConstI<120> {
+    type Output = P120;
+}
+
+impl ToInteger for ConstI<-120> {
+    type Output = N120;
+}
+
+impl ToInteger for ConstI<121> {
+    type Output = P121;
+}
+
+impl ToInteger for ConstI<-121> {
+    type Output = N121;
+}
+
+impl ToInteger for ConstI<122> {
+    type Output = P122;
+}
+
+impl ToInteger for ConstI<-122> {
+    type Output = N122;
+}
+
+impl ToInteger for ConstI<123> {
+    type Output = P123;
+}
+
+impl ToInteger for ConstI<-123> {
+    type Output = N123;
+}
+
+impl ToInteger for ConstI<124> {
+    type Output = P124;
+}
+
+impl ToInteger for ConstI<-124> {
+    type Output = N124;
+}
+
+impl ToInteger for ConstI<125> {
+    type Output = P125;
+}
+
+impl ToInteger for ConstI<-125> {
+    type Output = N125;
+}
+
+impl ToInteger for ConstI<126> {
+    type Output = P126;
+}
+
+impl ToInteger for ConstI<-126> {
+    type Output = N126;
+}
+
+impl ToInteger for ConstI<127> {
+    type Output = P127;
+}
+
+impl ToInteger for ConstI<-127> {
+    type Output = N127;
+}
+
+impl ToInteger for ConstI<128> {
+    type Output = P128;
+}
+
+impl ToInteger for ConstI<-128> {
+    type Output = N128;
+}
+
+impl ToInteger for ConstI<129> {
+    type Output = P129;
+}
+
+impl ToInteger for ConstI<-129> {
+    type Output = N129;
+}
+
+impl ToInteger for ConstI<130> {
+    type Output = P130;
+}
+
+impl ToInteger for ConstI<-130> {
+    type Output = N130;
+}
+
+impl ToInteger for ConstI<131> {
+    type Output = P131;
+}
+
+impl ToInteger for ConstI<-131> {
+    type Output = N131;
+}
+
+impl ToInteger for ConstI<132> {
+    type Output = P132;
+}
+
+impl ToInteger for ConstI<-132> {
+    type Output = N132;
+}
+
+impl ToInteger for ConstI<133> {
+    type Output = P133;
+}
+
+impl ToInteger for ConstI<-133> {
+    type Output = N133;
+}
+
+impl ToInteger for ConstI<134> {
+    type Output = P134;
+}
+
+impl ToInteger for ConstI<-134> {
+    type Output = N134;
+}
+
+impl ToInteger for ConstI<135> {
+    type Output = P135;
+}
+
+impl ToInteger for ConstI<-135> {
+    type Output = N135;
+}
+
+impl ToInteger for ConstI<136> {
+    type Output = P136;
+}
+
+impl ToInteger for ConstI<-136> {
+    type Output = N136;
+}
+
+impl ToInteger for ConstI<137> {
+    type Output = P137;
+}
+
+impl ToInteger for ConstI<-137> {
+    type Output = N137;
+}
+
+impl ToInteger for ConstI<138> {
+    type Output = P138;
+}
+
+impl ToInteger for ConstI<-138> {
+    type Output = N138;
+}
+
+impl ToInteger for ConstI<139> {
+    type Output = P139;
+}
+
+impl ToInteger for ConstI<-139> {
+    type Output = N139;
+}
+
+impl ToInteger for ConstI<140> {
+    type Output = P140;
+}
+
+impl ToInteger for ConstI<-140> {
+    type Output = N140;
+}
+
+impl ToInteger for ConstI<141> {
+    type Output = P141;
+}
+
+impl ToInteger for ConstI<-141> {
+    type Output = N141;
+}
+
+impl ToInteger for ConstI<142> {
+    type Output = P142;
+}
+
+impl ToInteger for ConstI<-142> {
+    type Output = N142;
+}
+
+impl ToInteger for ConstI<143> {
+    type Output = P143;
+}
+
+impl ToInteger for ConstI<-143> {
+    type Output = N143;
+}
+
+impl ToInteger for ConstI<144> {
+    type Output = P144;
+}
+
+impl ToInteger for ConstI<-144> {
+    type Output = N144;
+}
+
+impl ToInteger for ConstI<145> {
+    type Output = P145;
+}
+
+impl ToInteger for ConstI<-145> {
+    type Output = N145;
+}
+
+impl ToInteger for ConstI<146> {
+    type Output = P146;
+}
+
+impl ToInteger for ConstI<-146> {
+    type Output = N146;
+}
+
+impl ToInteger for ConstI<147> {
+    type Output = P147;
+}
+
+impl ToInteger for ConstI<-147> {
+    type Output = N147;
+}
+
+impl ToInteger for ConstI<148> {
+    type Output = P148;
+}
+
+impl ToInteger for ConstI<-148> {
+    type Output = N148;
+}
+
+impl ToInteger for ConstI<149> {
+    type Output = P149;
+}
+
+impl ToInteger for ConstI<-149> {
+    type Output = N149;
+}
+
+impl ToInteger for ConstI<150> {
+    type Output = P150;
+}
+
+impl ToInteger for ConstI<-150> {
+    type Output = N150;
+}
+
+impl ToInteger for ConstI<151> {
+    type Output = P151;
+}
+
+impl ToInteger for ConstI<-151> {
+    type Output = N151;
+}
+
+impl ToInteger for ConstI<152> {
+    type Output = P152;
+}
+
+impl ToInteger for ConstI<-152> {
+    type Output = N152;
+}
+
+impl ToInteger for ConstI<153> {
+    type Output = P153;
+}
+
+impl ToInteger for ConstI<-153> {
+    type Output = N153;
+}
+
+impl ToInteger for ConstI<154> {
+    type Output = P154;
+}
+
+impl ToInteger for ConstI<-154> {
+    type Output = N154;
+}
+
+impl ToInteger for ConstI<155> {
+    type Output = P155;
+}
+
+impl ToInteger for ConstI<-155> {
+    type Output = N155;
+}
+
+impl ToInteger for ConstI<156> {
+    type Output = P156;
+}
+
+impl ToInteger for ConstI<-156> {
+    type Output = N156;
+}
+
+impl ToInteger for ConstI<157> {
+    type Output = P157;
+}
+
+impl ToInteger for ConstI<-157> {
+    type Output = N157;
+}
+
+impl ToInteger for ConstI<158> {
+    type Output = P158;
+}
+
+impl ToInteger for ConstI<-158> {
+    type Output = N158;
+}
+
+impl ToInteger for ConstI<159> {
+    type Output = P159;
+}
+
+impl ToInteger for ConstI<-159> {
+    type Output = N159;
+}
+
+impl ToInteger for ConstI<160> {
+    type Output = P160;
+}
+
+impl ToInteger for ConstI<-160> {
+    type Output = N160;
+}
+
+impl ToInteger for ConstI<161> {
+    type Output = P161;
+}
+
+impl ToInteger for ConstI<-161> {
+    type Output = N161;
+}
+
+impl ToInteger for ConstI<162> {
+    type Output = P162;
+}
+
+impl ToInteger for ConstI<-162> {
+    type Output = N162;
+}
+
+impl ToInteger for ConstI<163> {
+    type Output = P163;
+}
+
+impl ToInteger for ConstI<-163> {
+    type Output = N163;
+}
+
+impl ToInteger for ConstI<164> {
+    type Output = P164;
+}
+
+impl ToInteger for ConstI<-164> {
+    type Output = N164;
+}
+
+impl ToInteger for ConstI<165> {
+    type Output = P165;
+}
+
+impl ToInteger for ConstI<-165> {
+    type Output = N165;
+}
+
+impl ToInteger for ConstI<166> {
+    type Output = P166;
+}
+
+impl ToInteger for ConstI<-166> {
+    type Output = N166;
+}
+
+impl ToInteger for ConstI<167> {
+    type Output = P167;
+}
+
+impl ToInteger for ConstI<-167> {
+    type Output = N167;
+}
+
+impl ToInteger for ConstI<168> {
+    type Output = P168;
+}
+
+impl ToInteger for ConstI<-168> {
+    type Output = N168;
+}
+
+impl ToInteger for ConstI<169> {
+    type Output = P169;
+}
+
+impl ToInteger for ConstI<-169> {
+    type Output = N169;
+}
+
+impl ToInteger for ConstI<170> {
+    type Output = P170;
+}
+
+impl ToInteger for ConstI<-170> {
+    type Output = N170;
+}
+
+impl ToInteger for ConstI<171> {
+    type Output = P171;
+}
+
+impl ToInteger for ConstI<-171> {
+    type Output = N171;
+}
+
+impl ToInteger for ConstI<172> {
+    type Output = P172;
+}
+
+impl ToInteger for ConstI<-172> {
+    type Output = N172;
+}
+
+impl ToInteger for ConstI<173> {
+    type Output = P173;
+}
+
+impl ToInteger for ConstI<-173> {
+    type Output = N173;
+}
+
+impl ToInteger for ConstI<174> {
+    type Output = P174;
+}
+
+impl ToInteger for ConstI<-174> {
+    type Output = N174;
+}
+
+impl ToInteger for ConstI<175> {
+    type Output = P175;
+}
+
+impl ToInteger for ConstI<-175> {
+    type Output = N175;
+}
+
+impl ToInteger for ConstI<176> {
+    type Output = P176;
+}
+
+impl ToInteger for ConstI<-176> {
+    type Output = N176;
+}
+
+impl ToInteger for ConstI<177> {
+    type Output = P177;
+}
+
+impl ToInteger for ConstI<-177> {
+    type Output = N177;
+}
+
+impl ToInteger for ConstI<178> {
+    type Output = P178;
+}
+
+impl ToInteger for ConstI<-178> {
+    type Output = N178;
+}
+
+impl ToInteger for ConstI<179> {
+    type Output = P179;
+}
+
+impl ToInteger for ConstI<-179> {
+    type Output = N179;
+}
+
+impl ToInteger for ConstI<180> {
+    type Output = P180;
+}
+
+impl ToInteger for ConstI<-180> {
+    type Output = N180;
+}
+
+impl ToInteger for ConstI<181> {
+    type Output = P181;
+}
+
+impl ToInteger for ConstI<-181> {
+    type Output = N181;
+}
+
+impl ToInteger for ConstI<182> {
+    type Output = P182;
+}
+
+impl ToInteger for ConstI<-182> {
+    type Output = N182;
+}
+
+impl ToInteger for ConstI<183> {
+    type Output = P183;
+}
+
+impl ToInteger for ConstI<-183> {
+    type Output = N183;
+}
+
+impl ToInteger for ConstI<184> {
+    type Output = P184;
+}
+
+impl ToInteger for ConstI<-184> {
+    type Output = N184;
+}
+
+impl ToInteger for ConstI<185> {
+    type Output = P185;
+}
+
+impl ToInteger for ConstI<-185> {
+    type Output = N185;
+}
+
+impl ToInteger for ConstI<186> {
+    type Output = P186;
+}
+
+impl ToInteger for ConstI<-186> {
+    type Output = N186;
+}
+
+impl ToInteger for ConstI<187> {
+    type Output = P187;
+}
+
+impl ToInteger for ConstI<-187> {
+    type Output = N187;
+}
+
+impl ToInteger for ConstI<188> {
+    type Output = P188;
+}
+
+impl ToInteger for ConstI<-188> {
+    type Output = N188;
+}
+
+impl ToInteger for ConstI<189> {
+    type Output = P189;
+}
+
+impl ToInteger for ConstI<-189> {
+    type Output = N189;
+}
+
+impl ToInteger for ConstI<190> {
+    type Output = P190;
+}
+
+impl ToInteger for ConstI<-190> {
+    type Output = N190;
+}
+
+impl ToInteger for ConstI<191> {
+    type Output = P191;
+}
+
+impl ToInteger for ConstI<-191> {
+    type Output = N191;
+}
+
+impl ToInteger for ConstI<192> {
+    type Output = P192;
+}
+
+impl ToInteger for ConstI<-192> {
+    type Output = N192;
+}
+
+impl ToInteger for ConstI<193> {
+    type Output = P193;
+}
+
+impl ToInteger for ConstI<-193> {
+    type Output = N193;
+}
+
+impl ToInteger for ConstI<194> {
+    type Output = P194;
+}
+
+impl ToInteger for ConstI<-194> {
+    type Output = N194;
+}
+
+impl ToInteger for ConstI<195> {
+    type Output = P195;
+}
+
+impl ToInteger for ConstI<-195> {
+    type Output = N195;
+}
+
+impl ToInteger for ConstI<196> {
+    type Output = P196;
+}
+
+impl ToInteger for ConstI<-196> {
+    type Output = N196;
+}
+
+impl ToInteger for ConstI<197> {
+    type Output = P197;
+}
+
+impl ToInteger for ConstI<-197> {
+    type Output = N197;
+}
+
+impl ToInteger for ConstI<198> {
+    type Output = P198;
+}
+
+impl ToInteger for ConstI<-198> {
+    type Output = N198;
+}
+
+impl ToInteger for ConstI<199> {
+    type Output = P199;
+}
+
+impl ToInteger for ConstI<-199> {
+    type Output = N199;
+}
+
+impl ToInteger for ConstI<200> {
+    type Output = P200;
+}
+
+impl ToInteger for ConstI<-200> {
+    type Output = N200;
+}
+
+impl ToInteger for ConstI<201> {
+    type Output = P201;
+}
+
+impl ToInteger for ConstI<-201> {
+    type Output = N201;
+}
+
+impl ToInteger for ConstI<202> {
+    type Output = P202;
+}
+
+impl ToInteger for ConstI<-202> {
+    type Output = N202;
+}
+
+impl ToInteger for ConstI<203> {
+    type Output = P203;
+}
+
+impl ToInteger for ConstI<-203> {
+    type Output = N203;
+}
+
+impl ToInteger for ConstI<204> {
+    type Output = P204;
+}
+
+impl ToInteger for ConstI<-204> {
+    type Output = N204;
+}
+
+impl ToInteger for ConstI<205> {
+    type Output = P205;
+}
+
+impl ToInteger for ConstI<-205> {
+    type Output = N205;
+}
+
+impl ToInteger for ConstI<206> {
+    type Output = P206;
+}
+
+impl ToInteger for ConstI<-206> {
+    type Output = N206;
+}
+
+impl ToInteger for ConstI<207> {
+    type Output = P207;
+}
+
+impl ToInteger for ConstI<-207> {
+    type Output = N207;
+}
+
+impl ToInteger for ConstI<208> {
+    type Output = P208;
+}
+
+impl ToInteger for ConstI<-208> {
+    type Output = N208;
+}
+
+impl ToInteger for ConstI<209> {
+    type Output = P209;
+}
+
+impl ToInteger for ConstI<-209> {
+    type Output = N209;
+}
+
+impl ToInteger for ConstI<210> {
+    type Output = P210;
+}
+
+impl ToInteger for ConstI<-210> {
+    type Output = N210;
+}
+
+impl ToInteger for ConstI<211> {
+    type Output = P211;
+}
+
+impl ToInteger for ConstI<-211> {
+    type Output = N211;
+}
+
+impl ToInteger for ConstI<212> {
+    type Output = P212;
+}
+
+impl ToInteger for ConstI<-212> {
+    type Output = N212;
+}
+
+impl ToInteger for ConstI<213> {
+    type Output = P213;
+}
+
+impl ToInteger for ConstI<-213> {
+    type Output = N213;
+}
+
+impl ToInteger for ConstI<214> {
+    type Output = P214;
+}
+
+impl ToInteger for ConstI<-214> {
+    type Output = N214;
+}
+
+impl ToInteger for ConstI<215> {
+    type Output = P215;
+}
+
+impl ToInteger for ConstI<-215> {
+    type Output = N215;
+}
+
+impl ToInteger for ConstI<216> {
+    type Output = P216;
+}
+
+impl ToInteger for ConstI<-216> {
+    type Output = N216;
+}
+
+impl ToInteger for ConstI<217> {
+    type Output = P217;
+}
+
+impl ToInteger for ConstI<-217> {
+    type Output = N217;
+}
+
+impl ToInteger for ConstI<218> {
+    type Output = P218;
+}
+
+impl ToInteger for ConstI<-218> {
+    type Output = N218;
+}
+
+impl ToInteger for ConstI<219> {
+    type Output = P219;
+}
+
+impl ToInteger for ConstI<-219> {
+    type Output = N219;
+}
+
+impl ToInteger for ConstI<220> {
+    type Output = P220;
+}
+
+impl ToInteger for ConstI<-220> {
+    type Output = N220;
+}
+
+impl ToInteger for ConstI<221> {
+    type Output = P221;
+}
+
+impl ToInteger for ConstI<-221> {
+    type Output = N221;
+}
+
+impl ToInteger for ConstI<222> {
+    type Output = P222;
+}
+
+impl ToInteger for ConstI<-222> {
+    type Output = N222;
+}
+
+impl ToInteger for ConstI<223> {
+    type Output = P223;
+}
+
+impl ToInteger for ConstI<-223> {
+    type Output = N223;
+}
+
+impl ToInteger for ConstI<224> {
+    type Output = P224;
+}
+
+impl ToInteger for ConstI<-224> {
+    type Output = N224;
+}
+
+impl ToInteger for ConstI<225> {
+    type Output = P225;
+}
+
+impl ToInteger for ConstI<-225> {
+    type Output = N225;
+}
+
+impl ToInteger for ConstI<226> {
+    type Output = P226;
+}
+
+impl ToInteger for ConstI<-226> {
+    type Output = N226;
+}
+
+impl ToInteger for ConstI<227> {
+    type Output = P227;
+}
+
+impl ToInteger for ConstI<-227> {
+    type Output = N227;
+}
+
+impl ToInteger for ConstI<228> {
+    type Output = P228;
+}
+
+impl ToInteger for ConstI<-228> {
+    type Output = N228;
+}
+
+impl ToInteger for ConstI<229> {
+    type Output = P229;
+}
+
+impl ToInteger for ConstI<-229> {
+    type Output = N229;
+}
+
+impl ToInteger for ConstI<230> {
+    type Output = P230;
+}
+
+impl ToInteger for ConstI<-230> {
+    type Output = N230;
+}
+
+impl ToInteger for ConstI<231> {
+    type Output = P231;
+}
+
+impl ToInteger for ConstI<-231> {
+    type Output = N231;
+}
+
+impl ToInteger for ConstI<232> {
+    type Output = P232;
+}
+
+impl ToInteger for ConstI<-232> {
+    type Output = N232;
+}
+
+impl ToInteger for ConstI<233> {
+    type Output = P233;
+}
+
+impl ToInteger for ConstI<-233> {
+    type Output = N233;
+}
+
+impl ToInteger for ConstI<234> {
+    type Output = P234;
+}
+
+impl ToInteger for ConstI<-234> {
+    type Output = N234;
+}
+
+impl ToInteger for ConstI<235> {
+    type Output = P235;
+}
+
+impl ToInteger for ConstI<-235> {
+    type Output = N235;
+}
+
+impl ToInteger for ConstI<236> {
+    type Output = P236;
+}
+
+impl ToInteger for ConstI<-236> {
+    type Output = N236;
+}
+
+impl ToInteger for ConstI<237> {
+    type Output = P237;
+}
+
+impl ToInteger for ConstI<-237> {
+    type Output = N237;
+}
+
+impl ToInteger for ConstI<238> {
+    type Output = P238;
+}
+
+impl ToInteger for ConstI<-238> {
+    type Output = N238;
+}
+
+impl ToInteger for ConstI<239> {
+    type Output = P239;
+}
+
+impl ToInteger for ConstI<-239> {
+    type Output = N239;
+}
+
+impl ToInteger for ConstI<240> {
+    type Output = P240;
+}
+
+impl ToInteger for ConstI<-240> {
+    type Output = N240;
+}
+
+impl ToInteger for ConstI<241> {
+    type Output = P241;
+}
+
+impl ToInteger for ConstI<-241> {
+    type Output = N241;
+}
+
+impl ToInteger for ConstI<242> {
+    type Output = P242;
+}
+
+impl ToInteger for ConstI<-242> {
+    type Output = N242;
+}
+
+impl ToInteger for ConstI<243> {
+    type Output = P243;
+}
+
+impl ToInteger for ConstI<-243> {
+    type Output = N243;
+}
+
+impl ToInteger for ConstI<244> {
+    type Output = P244;
+}
+
+impl ToInteger for ConstI<-244> {
+    type Output = N244;
+}
+
+impl ToInteger for ConstI<245> {
+    type Output = P245;
+}
+
+impl ToInteger for ConstI<-245> {
+    type Output = N245;
+}
+
+impl ToInteger for ConstI<246> {
+    type Output = P246;
+}
+
+impl ToInteger for ConstI<-246> {
+    type Output = N246;
+}
+
+impl ToInteger for ConstI<247> {
+    type Output = P247;
+}
+
+impl ToInteger for ConstI<-247> {
+    type Output = N247;
+}
+
+impl ToInteger for ConstI<248> {
+    type Output = P248;
+}
+
+impl ToInteger for ConstI<-248> {
+    type Output = N248;
+}
+
+impl ToInteger for ConstI<249> {
+    type Output = P249;
+}
+
+impl ToInteger for ConstI<-249> {
+    type Output = N249;
+}
+
+impl ToInteger for ConstI<250> {
+    type Output = P250;
+}
+
+impl ToInteger for ConstI<-250> {
+    type Output = N250;
+}
+
+impl ToInteger for ConstI<251> {
+    type Output = P251;
+}
+
+impl ToInteger for ConstI<-251> {
+    type Output = N251;
+}
+
+impl ToInteger for ConstI<252> {
+    type Output = P252;
+}
+
+impl ToInteger for ConstI<-252> {
+    type Output = N252;
+}
+
+impl ToInteger for ConstI<253> {
+    type Output = P253;
+}
+
+impl ToInteger for ConstI<-253> {
+    type Output = N253;
+}
+
+impl ToInteger for ConstI<254> {
+    type Output = P254;
+}
+
+impl ToInteger for ConstI<-254> {
+    type Output = N254;
+}
+
+impl ToInteger for ConstI<255> {
+    type Output = P255;
+}
+
+impl ToInteger for ConstI<-255> {
+    type Output = N255;
+}
+
+impl ToInteger for ConstI<256> {
+    type Output = P256;
+}
+
+impl ToInteger for ConstI<-256> {
+    type Output = N256;
+}
+
+impl ToInteger for ConstI<257> {
+    type Output = P257;
+}
+
+impl ToInteger for ConstI<-257> {
+    type Output = N257;
+}
+
+impl ToInteger for ConstI<258> {
+    type Output = P258;
+}
+
+impl ToInteger for ConstI<-258> {
+    type Output = N258;
+}
+
+impl ToInteger for ConstI<259> {
+    type Output = P259;
+}
+
+impl ToInteger for ConstI<-259> {
+    type Output = N259;
+}
+
+impl ToInteger for ConstI<260> {
+    type Output = P260;
+}
+
+impl ToInteger for ConstI<-260> {
+    type Output = N260;
+}
+
+impl ToInteger for ConstI<261> {
+    type Output = P261;
+}
+
+impl ToInteger for ConstI<-261> {
+    type Output = N261;
+}
+
+impl ToInteger for ConstI<262> {
+    type Output = P262;
+}
+
+impl ToInteger for ConstI<-262> {
+    type Output = N262;
+}
+
+impl ToInteger for ConstI<263> {
+    type Output = P263;
+}
+
+impl ToInteger for ConstI<-263> {
+    type Output = N263;
+}
+
+impl ToInteger for ConstI<264> {
+    type Output = P264;
+}
+
+impl ToInteger for ConstI<-264> {
+    type Output = N264;
+}
+
+impl ToInteger for ConstI<265> {
+    type Output = P265;
+}
+
+impl ToInteger for ConstI<-265> {
+    type Output = N265;
+}
+
+impl ToInteger for ConstI<266> {
+    type Output = P266;
+}
+
+impl ToInteger for ConstI<-266> {
+    type Output = N266;
+}
+
+impl ToInteger for ConstI<267> {
+    type Output = P267;
+}
+
+impl ToInteger for ConstI<-267> {
+    type Output = N267;
+}
+
+impl ToInteger for ConstI<268> {
+    type Output = P268;
+}
+
+impl ToInteger for ConstI<-268> {
+    type Output = N268;
+}
+
+impl ToInteger for ConstI<269> {
+    type Output = P269;
+}
+
+impl ToInteger for ConstI<-269> {
+    type Output = N269;
+}
+
+impl ToInteger for ConstI<270> {
+    type Output = P270;
+}
+
+impl ToInteger for ConstI<-270> {
+    type Output = N270;
+}
+
+impl ToInteger for ConstI<271> {
+    type Output = P271;
+}
+
+impl ToInteger for ConstI<-271> {
+    type Output = N271;
+}
+
+impl ToInteger for ConstI<272> {
+    type Output = P272;
+}
+
+impl ToInteger for ConstI<-272> {
+    type Output = N272;
+}
+
+impl ToInteger for ConstI<273> {
+    type Output = P273;
+}
+
+impl ToInteger for ConstI<-273> {
+    type Output = N273;
+}
+
+impl ToInteger for ConstI<274> {
+    type Output = P274;
+}
+
+impl ToInteger for ConstI<-274> {
+    type Output = N274;
+}
+
+impl ToInteger for ConstI<275> {
+    type Output = P275;
+}
+
+impl ToInteger for ConstI<-275> {
+    type Output = N275;
+}
+
+impl ToInteger for ConstI<276> {
+    type Output = P276;
+}
+
+impl ToInteger for ConstI<-276> {
+    type Output = N276;
+}
+
+impl ToInteger for ConstI<277> {
+    type Output = P277;
+}
+
+impl ToInteger for ConstI<-277> {
+    type Output = N277;
+}
+
+impl ToInteger for ConstI<278> {
+    type Output = P278;
+}
+
+impl ToInteger for ConstI<-278> {
+    type Output = N278;
+}
+
+impl ToInteger for ConstI<279> {
+    type Output = P279;
+}
+
+impl ToInteger for ConstI<-279> {
+    type Output = N279;
+}
+
+impl ToInteger for ConstI<280> {
+    type Output = P280;
+}
+
+impl ToInteger for ConstI<-280> {
+    type Output = N280;
+}
+
+impl ToInteger for ConstI<281> {
+    type Output = P281;
+}
+
+impl ToInteger for ConstI<-281> {
+    type Output = N281;
+}
+
+impl ToInteger for ConstI<282> {
+    type Output = P282;
+}
+
+impl ToInteger for ConstI<-282> {
+    type Output = N282;
+}
+
+impl ToInteger for ConstI<283> {
+    type Output = P283;
+}
+
+impl ToInteger for ConstI<-283> {
+    type Output = N283;
+}
+
+impl ToInteger for ConstI<284> {
+    type Output = P284;
+}
+
+impl ToInteger for ConstI<-284> {
+    type Output = N284;
+}
+
+impl ToInteger for ConstI<285> {
+    type Output = P285;
+}
+
+impl ToInteger for ConstI<-285> {
+    type Output = N285;
+}
+
+impl ToInteger for ConstI<286> {
+    type Output = P286;
+}
+
+impl ToInteger for ConstI<-286> {
+    type Output = N286;
+}
+
+impl ToInteger for ConstI<287> {
+    type Output = P287;
+}
+
+impl ToInteger for ConstI<-287> {
+    type Output = N287;
+}
+
+impl ToInteger for ConstI<288> {
+    type Output = P288;
+}
+
+impl ToInteger for ConstI<-288> {
+    type Output = N288;
+}
+
+impl ToInteger for ConstI<289> {
+    type Output = P289;
+}
+
+impl ToInteger for ConstI<-289> {
+    type Output = N289;
+}
+
+impl ToInteger for ConstI<290> {
+    type Output = P290;
+}
+
+impl ToInteger for ConstI<-290> {
+    type Output = N290;
+}
+
+impl ToInteger for ConstI<291> {
+    type Output = P291;
+}
+
+impl ToInteger for ConstI<-291> {
+    type Output = N291;
+}
+
+impl ToInteger for ConstI<292> {
+    type Output = P292;
+}
+
+impl ToInteger for ConstI<-292> {
+    type Output = N292;
+}
+
+impl ToInteger for ConstI<293> {
+    type Output = P293;
+}
+
+impl ToInteger for ConstI<-293> {
+    type Output = N293;
+}
+
+impl ToInteger for ConstI<294> {
+    type Output = P294;
+}
+
+impl ToInteger for ConstI<-294> {
+    type Output = N294;
+}
+
+impl ToInteger for ConstI<295> {
+    type Output = P295;
+}
+
+impl ToInteger for ConstI<-295> {
+    type Output = N295;
+}
+
+impl ToInteger for ConstI<296> {
+    type Output = P296;
+}
+
+impl ToInteger for ConstI<-296> {
+    type Output = N296;
+}
+
+impl ToInteger for ConstI<297> {
+    type Output = P297;
+}
+
+impl ToInteger for ConstI<-297> {
+    type Output = N297;
+}
+
+impl ToInteger for ConstI<298> {
+    type Output = P298;
+}
+
+impl ToInteger for ConstI<-298> {
+    type Output = N298;
+}
+
+impl ToInteger for ConstI<299> {
+    type Output = P299;
+}
+
+impl ToInteger for ConstI<-299> {
+    type Output = N299;
+}
+
+impl ToInteger for ConstI<300> {
+    type Output = P300;
+}
+
+impl ToInteger for ConstI<-300> {
+    type Output = N300;
+}
+
+impl ToInteger for ConstI<301> {
+    type Output = P301;
+}
+
+impl ToInteger for ConstI<-301> {
+    type Output = N301;
+}
+
+impl ToInteger for ConstI<302> {
+    type Output = P302;
+}
+
+impl ToInteger for ConstI<-302> {
+    type Output = N302;
+}
+
+impl ToInteger for ConstI<303> {
+    type Output = P303;
+}
+
+impl ToInteger for ConstI<-303> {
+    type Output = N303;
+}
+
+impl ToInteger for ConstI<304> {
+    type Output = P304;
+}
+
+impl ToInteger for ConstI<-304> {
+    type Output = N304;
+}
+
+impl ToInteger for ConstI<305> {
+    type Output = P305;
+}
+
+impl ToInteger for ConstI<-305> {
+    type Output = N305;
+}
+
+impl ToInteger for ConstI<306> {
+    type Output = P306;
+}
+
+impl ToInteger for ConstI<-306> {
+    type Output = N306;
+}
+
+impl ToInteger for ConstI<307> {
+    type Output = P307;
+}
+
+impl ToInteger for ConstI<-307> {
+    type Output = N307;
+}
+
+impl ToInteger for ConstI<308> {
+    type Output = P308;
+}
+
+impl ToInteger for ConstI<-308> {
+    type Output = N308;
+}
+
+impl ToInteger for ConstI<309> {
+    type Output = P309;
+}
+
+impl ToInteger for ConstI<-309> {
+    type Output = N309;
+}
+
+impl ToInteger for ConstI<310> {
+    type Output = P310;
+}
+
+impl ToInteger for ConstI<-310> {
+    type Output = N310;
+}
+
+impl ToInteger for ConstI<311> {
+    type Output = P311;
+}
+
+impl ToInteger for ConstI<-311> {
+    type Output = N311;
+}
+
+impl ToInteger for ConstI<312> {
+    type Output = P312;
+}
+
+impl ToInteger for ConstI<-312> {
+    type Output = N312;
+}
+
+impl ToInteger for ConstI<313> {
+    type Output = P313;
+}
+
+impl ToInteger for ConstI<-313> {
+    type Output = N313;
+}
+
+impl ToInteger for ConstI<314> {
+    type Output = P314;
+}
+
+impl ToInteger for ConstI<-314> {
+    type Output = N314;
+}
+
+impl ToInteger for ConstI<315> {
+    type Output = P315;
+}
+
+impl ToInteger for ConstI<-315> {
+    type Output = N315;
+}
+
+impl ToInteger for ConstI<316> {
+    type Output = P316;
+}
+
+impl ToInteger for ConstI<-316> {
+    type Output = N316;
+}
+
+impl ToInteger for ConstI<317> {
+    type Output = P317;
+}
+
+impl ToInteger for ConstI<-317> {
+    type Output = N317;
+}
+
+impl ToInteger for ConstI<318> {
+    type Output = P318;
+}
+
+impl ToInteger for ConstI<-318> {
+    type Output = N318;
+}
+
+impl ToInteger for ConstI<319> {
+    type Output = P319;
+}
+
+impl ToInteger for ConstI<-319> {
+    type Output = N319;
+}
+
+impl ToInteger for ConstI<320> {
+    type Output = P320;
+}
+
+impl ToInteger for ConstI<-320> {
+    type Output = N320;
+}
+
+impl ToInteger for ConstI<321> {
+    type Output = P321;
+}
+
+impl ToInteger for ConstI<-321> {
+    type Output = N321;
+}
+
+impl ToInteger for ConstI<322> {
+    type Output = P322;
+}
+
+impl ToInteger for ConstI<-322> {
+    type Output = N322;
+}
+
+impl ToInteger for ConstI<323> {
+    type Output = P323;
+}
+
+impl ToInteger for ConstI<-323> {
+    type Output = N323;
+}
+
+impl ToInteger for ConstI<324> {
+    type Output = P324;
+}
+
+impl ToInteger for ConstI<-324> {
+    type Output = N324;
+}
+
+impl ToInteger for ConstI<325> {
+    type Output = P325;
+}
+
+impl ToInteger for ConstI<-325> {
+    type Output = N325;
+}
+
+impl ToInteger for ConstI<326> {
+    type Output = P326;
+}
+
+impl ToInteger for ConstI<-326> {
+    type Output = N326;
+}
+
+impl ToInteger for ConstI<327> {
+    type Output = P327;
+}
+
+impl ToInteger for ConstI<-327> {
+    type Output = N327;
+}
+
+impl ToInteger for ConstI<328> {
+    type Output = P328;
+}
+
+impl ToInteger for ConstI<-328> {
+    type Output = N328;
+}
+
+impl ToInteger for ConstI<329> {
+    type Output = P329;
+}
+
+impl ToInteger for ConstI<-329> {
+    type Output = N329;
+}
+
+impl ToInteger for ConstI<330> {
+    type Output = P330;
+}
+
+impl ToInteger for ConstI<-330> {
+    type Output = N330;
+}
+
+impl ToInteger for ConstI<331> {
+    type Output = P331;
+}
+
+impl ToInteger for ConstI<-331> {
+    type Output = N331;
+}
+
+impl ToInteger for ConstI<332> {
+    type Output = P332;
+}
+
+impl ToInteger for ConstI<-332> {
+    type Output = N332;
+}
+
+impl ToInteger for ConstI<333> {
+    type Output = P333;
+}
+
+impl ToInteger for ConstI<-333> {
+    type Output = N333;
+}
+
+impl ToInteger for ConstI<334> {
+    type Output = P334;
+}
+
+impl ToInteger for ConstI<-334> {
+    type Output = N334;
+}
+
+impl ToInteger for ConstI<335> {
+    type Output = P335;
+}
+
+impl ToInteger for ConstI<-335> {
+    type Output = N335;
+}
+
+impl ToInteger for ConstI<336> {
+    type Output = P336;
+}
+
+impl ToInteger for ConstI<-336> {
+    type Output = N336;
+}
+
+impl ToInteger for ConstI<337> {
+    type Output = P337;
+}
+
+impl ToInteger for ConstI<-337> {
+    type Output = N337;
+}
+
+impl ToInteger for ConstI<338> {
+    type Output = P338;
+}
+
+impl ToInteger for ConstI<-338> {
+    type Output = N338;
+}
+
+impl ToInteger for ConstI<339> {
+    type Output = P339;
+}
+
+impl ToInteger for ConstI<-339> {
+    type Output = N339;
+}
+
+impl ToInteger for ConstI<340> {
+    type Output = P340;
+}
+
+impl ToInteger for ConstI<-340> {
+    type Output = N340;
+}
+
+impl ToInteger for ConstI<341> {
+    type Output = P341;
+}
+
+impl ToInteger for ConstI<-341> {
+    type Output = N341;
+}
+
+impl ToInteger for ConstI<342> {
+    type Output = P342;
+}
+
+impl ToInteger for ConstI<-342> {
+    type Output = N342;
+}
+
+impl ToInteger for ConstI<343> {
+    type Output = P343;
+}
+
+impl ToInteger for ConstI<-343> {
+    type Output = N343;
+}
+
+impl ToInteger for ConstI<344> {
+    type Output = P344;
+}
+
+impl ToInteger for ConstI<-344> {
+    type Output = N344;
+}
+
+impl ToInteger for ConstI<345> {
+    type Output = P345;
+}
+
+impl ToInteger for ConstI<-345> {
+    type Output = N345;
+}
+
+impl ToInteger for ConstI<346> {
+    type Output = P346;
+}
+
+impl ToInteger for ConstI<-346> {
+    type Output = N346;
+}
+
+impl ToInteger for ConstI<347> {
+    type Output = P347;
+}
+
+impl ToInteger for ConstI<-347> {
+    type Output = N347;
+}
+
+impl ToInteger for ConstI<348> {
+    type Output = P348;
+}
+
+impl ToInteger for ConstI<-348> {
+    type Output = N348;
+}
+
+impl ToInteger for ConstI<349> {
+    type Output = P349;
+}
+
+impl ToInteger for ConstI<-349> {
+    type Output = N349;
+}
+
+impl ToInteger for ConstI<350> {
+    type Output = P350;
+}
+
+impl ToInteger for ConstI<-350> {
+    type Output = N350;
+}
+
+impl ToInteger for ConstI<351> {
+    type Output = P351;
+}
+
+impl ToInteger for ConstI<-351> {
+    type Output = N351;
+}
+
+impl ToInteger for ConstI<352> {
+    type Output = P352;
+}
+
+impl ToInteger for ConstI<-352> {
+    type Output = N352;
+}
+
+impl ToInteger for ConstI<353> {
+    type Output = P353;
+}
+
+impl ToInteger for ConstI<-353> {
+    type Output = N353;
+}
+
+impl ToInteger for ConstI<354> {
+    type Output = P354;
+}
+
+impl ToInteger for ConstI<-354> {
+    type Output = N354;
+}
+
+impl ToInteger for ConstI<355> {
+    type Output = P355;
+}
+
+impl ToInteger for ConstI<-355> {
+    type Output = N355;
+}
+
+impl ToInteger for ConstI<356> {
+    type Output = P356;
+}
+
+impl ToInteger for ConstI<-356> {
+    type Output = N356;
+}
+
+impl ToInteger for ConstI<357> {
+    type Output = P357;
+}
+
+impl ToInteger for ConstI<-357> {
+    type Output = N357;
+}
+
+impl ToInteger for ConstI<358> {
+    type Output = P358;
+}
+
+impl ToInteger for ConstI<-358> {
+    type Output = N358;
+}
+
+impl ToInteger for ConstI<359> {
+    type Output = P359;
+}
+
+impl ToInteger for ConstI<-359> {
+    type Output = N359;
+}
+
+impl ToInteger for ConstI<360> {
+    type Output = P360;
+}
+
+impl ToInteger for ConstI<-360> {
+    type Output = N360;
+}
+
+impl ToInteger for ConstI<361> {
+    type Output = P361;
+}
+
+impl ToInteger for ConstI<-361> {
+    type Output = N361;
+}
+
+impl ToInteger for ConstI<362> {
+    type Output = P362;
+}
+
+impl ToInteger for ConstI<-362> {
+    type Output = N362;
+}
+
+impl ToInteger for ConstI<363> {
+    type Output = P363;
+}
+
+impl ToInteger for ConstI<-363> {
+    type Output = N363;
+}
+
+impl ToInteger for ConstI<364> {
+    type Output = P364;
+}
+
+impl ToInteger for ConstI<-364> {
+    type Output = N364;
+}
+
+impl ToInteger for ConstI<365> {
+    type Output = P365;
+}
+
+impl ToInteger for ConstI<-365> {
+    type Output = N365;
+}
+
+impl ToInteger for ConstI<366> {
+    type Output = P366;
+}
+
+impl ToInteger for ConstI<-366> {
+    type Output = N366;
+}
+
+impl ToInteger for ConstI<367> {
+    type Output = P367;
+}
+
+impl ToInteger for ConstI<-367> {
+    type Output = N367;
+}
+
+impl ToInteger for ConstI<368> {
+    type Output = P368;
+}
+
+impl ToInteger for ConstI<-368> {
+    type Output = N368;
+}
+
+impl ToInteger for ConstI<369> {
+    type Output = P369;
+}
+
+impl ToInteger for ConstI<-369> {
+    type Output = N369;
+}
+
+impl ToInteger for ConstI<370> {
+    type Output = P370;
+}
+
+impl ToInteger for ConstI<-370> {
+    type Output = N370;
+}
+
+impl ToInteger for ConstI<371> {
+    type Output = P371;
+}
+
+impl ToInteger for ConstI<-371> {
+    type Output = N371;
+}
+
+impl ToInteger for ConstI<372> {
+    type Output = P372;
+}
+
+impl ToInteger for ConstI<-372> {
+    type Output = N372;
+}
+
+impl ToInteger for ConstI<373> {
+    type Output = P373;
+}
+
+impl ToInteger for ConstI<-373> {
+    type Output = N373;
+}
+
+impl ToInteger for ConstI<374> {
+    type Output = P374;
+}
+
+impl ToInteger for ConstI<-374> {
+    type Output = N374;
+}
+
+impl ToInteger for ConstI<375> {
+    type Output = P375;
+}
+
+impl ToInteger for ConstI<-375> {
+    type Output = N375;
+}
+
+impl ToInteger for ConstI<376> {
+    type Output = P376;
+}
+
+impl ToInteger for ConstI<-376> {
+    type Output = N376;
+}
+
+impl ToInteger for ConstI<377> {
+    type Output = P377;
+}
+
+impl ToInteger for ConstI<-377> {
+    type Output = N377;
+}
+
+impl ToInteger for ConstI<378> {
+    type Output = P378;
+}
+
+impl ToInteger for ConstI<-378> {
+    type Output = N378;
+}
+
+impl ToInteger for ConstI<379> {
+    type Output = P379;
+}
+
+impl ToInteger for ConstI<-379> {
+    type Output = N379;
+}
+
+impl ToInteger for ConstI<380> {
+    type Output = P380;
+}
+
+impl ToInteger for ConstI<-380> {
+    type Output = N380;
+}
+
+impl ToInteger for ConstI<381> {
+    type Output = P381;
+}
+
+impl ToInteger for ConstI<-381> {
+    type Output = N381;
+}
+
+impl ToInteger for ConstI<382> {
+    type Output = P382;
+}
+
+impl ToInteger for ConstI<-382> {
+    type Output = N382;
+}
+
+impl ToInteger for ConstI<383> {
+    type Output = P383;
+}
+
+impl ToInteger for ConstI<-383> {
+    type Output = N383;
+}
+
+impl ToInteger for ConstI<384> {
+    type Output = P384;
+}
+
+impl ToInteger for ConstI<-384> {
+    type Output = N384;
+}
+
+impl ToInteger for ConstI<385> {
+    type Output = P385;
+}
+
+impl ToInteger for ConstI<-385> {
+    type Output = N385;
+}
+
+impl ToInteger for ConstI<386> {
+    type Output = P386;
+}
+
+impl ToInteger for ConstI<-386> {
+    type Output = N386;
+}
+
+impl ToInteger for ConstI<387> {
+    type Output = P387;
+}
+
+impl ToInteger for ConstI<-387> {
+    type Output = N387;
+}
+
+impl ToInteger for ConstI<388> {
+    type Output = P388;
+}
+
+impl ToInteger for ConstI<-388> {
+    type Output = N388;
+}
+
+impl ToInteger for ConstI<389> {
+    type Output = P389;
+}
+
+impl ToInteger for ConstI<-389> {
+    type Output = N389;
+}
+
+impl ToInteger for ConstI<390> {
+    type Output = P390;
+}
+
+impl ToInteger for ConstI<-390> {
+    type Output = N390;
+}
+
+impl ToInteger for ConstI<391> {
+    type Output = P391;
+}
+
+impl ToInteger for ConstI<-391> {
+    type Output = N391;
+}
+
+impl ToInteger for ConstI<392> {
+    type Output = P392;
+}
+
+impl ToInteger for ConstI<-392> {
+    type Output = N392;
+}
+
+impl ToInteger for ConstI<393> {
+    type Output = P393;
+}
+
+impl ToInteger for ConstI<-393> {
+    type Output = N393;
+}
+
+impl ToInteger for ConstI<394> {
+    type Output = P394;
+}
+
+impl ToInteger for ConstI<-394> {
+    type Output = N394;
+}
+
+impl ToInteger for ConstI<395> {
+    type Output = P395;
+}
+
+impl ToInteger for ConstI<-395> {
+    type Output = N395;
+}
+
+impl ToInteger for ConstI<396> {
+    type Output = P396;
+}
+
+impl ToInteger for ConstI<-396> {
+    type Output = N396;
+}
+
+impl ToInteger for ConstI<397> {
+    type Output = P397;
+}
+
+impl ToInteger for ConstI<-397> {
+    type Output = N397;
+}
+
+impl ToInteger for ConstI<398> {
+    type Output = P398;
+}
+
+impl ToInteger for ConstI<-398> {
+    type Output = N398;
+}
+
+impl ToInteger for ConstI<399> {
+    type Output = P399;
+}
+
+impl ToInteger for ConstI<-399> {
+    type Output = N399;
+}
+
+impl ToInteger for ConstI<400> {
+    type Output = P400;
+}
+
+impl ToInteger for ConstI<-400> {
+    type Output = N400;
+}
+
+impl ToInteger for ConstI<401> {
+    type Output = P401;
+}
+
+impl ToInteger for ConstI<-401> {
+    type Output = N401;
+}
+
+impl ToInteger for ConstI<402> {
+    type Output = P402;
+}
+
+impl ToInteger for ConstI<-402> {
+    type Output = N402;
+}
+
+impl ToInteger for ConstI<403> {
+    type Output = P403;
+}
+
+impl ToInteger for ConstI<-403> {
+    type Output = N403;
+}
+
+impl ToInteger for ConstI<404> {
+    type Output = P404;
+}
+
+impl ToInteger for ConstI<-404> {
+    type Output = N404;
+}
+
+impl ToInteger for ConstI<405> {
+    type Output = P405;
+}
+
+impl ToInteger for ConstI<-405> {
+    type Output = N405;
+}
+
+impl ToInteger for ConstI<406> {
+    type Output = P406;
+}
+
+impl ToInteger for ConstI<-406> {
+    type Output = N406;
+}
+
+impl ToInteger for ConstI<407> {
+    type Output = P407;
+}
+
+impl ToInteger for ConstI<-407> {
+    type Output = N407;
+}
+
+impl ToInteger for ConstI<408> {
+    type Output = P408;
+}
+
+impl ToInteger for ConstI<-408> {
+    type Output = N408;
+}
+
+impl ToInteger for ConstI<409> {
+    type Output = P409;
+}
+
+impl ToInteger for ConstI<-409> {
+    type Output = N409;
+}
+
+impl ToInteger for ConstI<410> {
+    type Output = P410;
+}
+
+impl ToInteger for ConstI<-410> {
+    type Output = N410;
+}
+
+impl ToInteger for ConstI<411> {
+    type Output = P411;
+}
+
+impl ToInteger for ConstI<-411> {
+    type Output = N411;
+}
+
+impl ToInteger for ConstI<412> {
+    type Output = P412;
+}
+
+impl ToInteger for ConstI<-412> {
+    type Output = N412;
+}
+
+impl ToInteger for ConstI<413> {
+    type Output = P413;
+}
+
+impl ToInteger for ConstI<-413> {
+    type Output = N413;
+}
+
+impl ToInteger for ConstI<414> {
+    type Output = P414;
+}
+
+impl ToInteger for ConstI<-414> {
+    type Output = N414;
+}
+
+impl ToInteger for ConstI<415> {
+    type Output = P415;
+}
+
+impl ToInteger for ConstI<-415> {
+    type Output = N415;
+}
+
+impl ToInteger for ConstI<416> {
+    type Output = P416;
+}
+
+impl ToInteger for ConstI<-416> {
+    type Output = N416;
+}
+
+impl ToInteger for ConstI<417> {
+    type Output = P417;
+}
+
+impl ToInteger for ConstI<-417> {
+    type Output = N417;
+}
+
+impl ToInteger for ConstI<418> {
+    type Output = P418;
+}
+
+impl ToInteger for ConstI<-418> {
+    type Output = N418;
+}
+
+impl ToInteger for ConstI<419> {
+    type Output = P419;
+}
+
+impl ToInteger for ConstI<-419> {
+    type Output = N419;
+}
+
+impl ToInteger for ConstI<420> {
+    type Output = P420;
+}
+
+impl ToInteger for ConstI<-420> {
+    type Output = N420;
+}
+
+impl ToInteger for ConstI<421> {
+    type Output = P421;
+}
+
+impl ToInteger for ConstI<-421> {
+    type Output = N421;
+}
+
+impl ToInteger for ConstI<422> {
+    type Output = P422;
+}
+
+impl ToInteger for ConstI<-422> {
+    type Output = N422;
+}
+
+impl ToInteger for ConstI<423> {
+    type Output = P423;
+}
+
+impl ToInteger for ConstI<-423> {
+    type Output = N423;
+}
+
+impl ToInteger for ConstI<424> {
+    type Output = P424;
+}
+
+impl ToInteger for ConstI<-424> {
+    type Output = N424;
+}
+
+impl ToInteger for ConstI<425> {
+    type Output = P425;
+}
+
+impl ToInteger for ConstI<-425> {
+    type Output = N425;
+}
+
+impl ToInteger for ConstI<426> {
+    type Output = P426;
+}
+
+impl ToInteger for ConstI<-426> {
+    type Output = N426;
+}
+
+impl ToInteger for ConstI<427> {
+    type Output = P427;
+}
+
+impl ToInteger for ConstI<-427> {
+    type Output = N427;
+}
+
+impl ToInteger for ConstI<428> {
+    type Output = P428;
+}
+
+impl ToInteger for ConstI<-428> {
+    type Output = N428;
+}
+
+impl ToInteger for ConstI<429> {
+    type Output = P429;
+}
+
+impl ToInteger for ConstI<-429> {
+    type Output = N429;
+}
+
+impl ToInteger for ConstI<430> {
+    type Output = P430;
+}
+
+impl ToInteger for ConstI<-430> {
+    type Output = N430;
+}
+
+impl ToInteger for ConstI<431> {
+    type Output = P431;
+}
+
+impl ToInteger for ConstI<-431> {
+    type Output = N431;
+}
+
+impl ToInteger for ConstI<432> {
+    type Output = P432;
+}
+
+impl ToInteger for ConstI<-432> {
+    type Output = N432;
+}
+
+impl ToInteger for ConstI<433> {
+    type Output = P433;
+}
+
+impl ToInteger for ConstI<-433> {
+    type Output = N433;
+}
+
+impl ToInteger for ConstI<434> {
+    type Output = P434;
+}
+
+impl ToInteger for ConstI<-434> {
+    type Output = N434;
+}
+
+impl ToInteger for ConstI<435> {
+    type Output = P435;
+}
+
+impl ToInteger for ConstI<-435> {
+    type Output = N435;
+}
+
+impl ToInteger for ConstI<436> {
+    type Output = P436;
+}
+
+impl ToInteger for ConstI<-436> {
+    type Output = N436;
+}
+
+impl ToInteger for ConstI<437> {
+    type Output = P437;
+}
+
+impl ToInteger for ConstI<-437> {
+    type Output = N437;
+}
+
+impl ToInteger for ConstI<438> {
+    type Output = P438;
+}
+
+impl ToInteger for ConstI<-438> {
+    type Output = N438;
+}
+
+impl ToInteger for ConstI<439> {
+    type Output = P439;
+}
+
+impl ToInteger for ConstI<-439> {
+    type Output = N439;
+}
+
+impl ToInteger for ConstI<440> {
+    type Output = P440;
+}
+
+impl ToInteger for ConstI<-440> {
+    type Output = N440;
+}
+
+impl ToInteger for ConstI<441> {
+    type Output = P441;
+}
+
+impl ToInteger for ConstI<-441> {
+    type Output = N441;
+}
+
+impl ToInteger for ConstI<442> {
+    type Output = P442;
+}
+
+impl ToInteger for ConstI<-442> {
+    type Output = N442;
+}
+
+impl ToInteger for ConstI<443> {
+    type Output = P443;
+}
+
+impl ToInteger for ConstI<-443> {
+    type Output = N443;
+}
+
+impl ToInteger for ConstI<444> {
+    type Output = P444;
+}
+
+impl ToInteger for ConstI<-444> {
+    type Output = N444;
+}
+
+impl ToInteger for ConstI<445> {
+    type Output = P445;
+}
+
+impl ToInteger for ConstI<-445> {
+    type Output = N445;
+}
+
+impl ToInteger for ConstI<446> {
+    type Output = P446;
+}
+
+impl ToInteger for ConstI<-446> {
+    type Output = N446;
+}
+
+impl ToInteger for ConstI<447> {
+    type Output = P447;
+}
+
+impl ToInteger for ConstI<-447> {
+    type Output = N447;
+}
+
+impl ToInteger for ConstI<448> {
+    type Output = P448;
+}
+
+impl ToInteger for ConstI<-448> {
+    type Output = N448;
+}
+
+impl ToInteger for ConstI<449> {
+    type Output = P449;
+}
+
+impl ToInteger for ConstI<-449> {
+    type Output = N449;
+}
+
+impl ToInteger for ConstI<450> {
+    type Output = P450;
+}
+
+impl ToInteger for ConstI<-450> {
+    type Output = N450;
+}
+
+impl ToInteger for ConstI<451> {
+    type Output = P451;
+}
+
+impl ToInteger for ConstI<-451> {
+    type Output = N451;
+}
+
+impl ToInteger for ConstI<452> {
+    type Output = P452;
+}
+
+impl ToInteger for ConstI<-452> {
+    type Output = N452;
+}
+
+impl ToInteger for ConstI<453> {
+    type Output = P453;
+}
+
+impl ToInteger for ConstI<-453> {
+    type Output = N453;
+}
+
+impl ToInteger for ConstI<454> {
+    type Output = P454;
+}
+
+impl ToInteger for ConstI<-454> {
+    type Output = N454;
+}
+
+impl ToInteger for ConstI<455> {
+    type Output = P455;
+}
+
+impl ToInteger for ConstI<-455> {
+    type Output = N455;
+}
+
+impl ToInteger for ConstI<456> {
+    type Output = P456;
+}
+
+impl ToInteger for ConstI<-456> {
+    type Output = N456;
+}
+
+impl ToInteger for ConstI<457> {
+    type Output = P457;
+}
+
+impl ToInteger for ConstI<-457> {
+    type Output = N457;
+}
+
+impl ToInteger for ConstI<458> {
+    type Output = P458;
+}
+
+impl ToInteger for ConstI<-458> {
+    type Output = N458;
+}
+
+impl ToInteger for ConstI<459> {
+    type Output = P459;
+}
+
+impl ToInteger for ConstI<-459> {
+    type Output = N459;
+}
+
+impl ToInteger for ConstI<460> {
+    type Output = P460;
+}
+
+impl ToInteger for ConstI<-460> {
+    type Output = N460;
+}
+
+impl ToInteger for ConstI<461> {
+    type Output = P461;
+}
+
+impl ToInteger for ConstI<-461> {
+    type Output = N461;
+}
+
+impl ToInteger for ConstI<462> {
+    type Output = P462;
+}
+
+impl ToInteger for ConstI<-462> {
+    type Output = N462;
+}
+
+impl ToInteger for ConstI<463> {
+    type Output = P463;
+}
+
+impl ToInteger for ConstI<-463> {
+    type Output = N463;
+}
+
+impl ToInteger for ConstI<464> {
+    type Output = P464;
+}
+
+impl ToInteger for ConstI<-464> {
+    type Output = N464;
+}
+
+impl ToInteger for ConstI<465> {
+    type Output = P465;
+}
+
+impl ToInteger for ConstI<-465> {
+    type Output = N465;
+}
+
+impl ToInteger for ConstI<466> {
+    type Output = P466;
+}
+
+impl ToInteger for ConstI<-466> {
+    type Output = N466;
+}
+
+impl ToInteger for ConstI<467> {
+    type Output = P467;
+}
+
+impl ToInteger for ConstI<-467> {
+    type Output = N467;
+}
+
+impl ToInteger for ConstI<468> {
+    type Output = P468;
+}
+
+impl ToInteger for ConstI<-468> {
+    type Output = N468;
+}
+
+impl ToInteger for ConstI<469> {
+    type Output = P469;
+}
+
+impl ToInteger for ConstI<-469> {
+    type Output = N469;
+}
+
+impl ToInteger for ConstI<470> {
+    type Output = P470;
+}
+
+impl ToInteger for ConstI<-470> {
+    type Output = N470;
+}
+
+impl ToInteger for ConstI<471> {
+    type Output = P471;
+}
+
+impl ToInteger for ConstI<-471> {
+    type Output = N471;
+}
+
+impl ToInteger for ConstI<472> {
+    type Output = P472;
+}
+
+impl ToInteger for ConstI<-472> {
+    type Output = N472;
+}
+
+impl ToInteger for ConstI<473> {
+    type Output = P473;
+}
+
+impl ToInteger for ConstI<-473> {
+    type Output = N473;
+}
+
+impl ToInteger for ConstI<474> {
+    type Output = P474;
+}
+
+impl ToInteger for ConstI<-474> {
+    type Output = N474;
+}
+
+impl ToInteger for ConstI<475> {
+    type Output = P475;
+}
+
+impl ToInteger for ConstI<-475> {
+    type Output = N475;
+}
+
+impl ToInteger for ConstI<476> {
+    type Output = P476;
+}
+
+impl ToInteger for ConstI<-476> {
+    type Output = N476;
+}
+
+impl ToInteger for ConstI<477> {
+    type Output = P477;
+}
+
+impl ToInteger for ConstI<-477> {
+    type Output = N477;
+}
+
+impl ToInteger for ConstI<478> {
+    type Output = P478;
+}
+
+impl ToInteger for ConstI<-478> {
+    type Output = N478;
+}
+
+impl ToInteger for ConstI<479> {
+    type Output = P479;
+}
+
+impl ToInteger for ConstI<-479> {
+    type Output = N479;
+}
+
+impl ToInteger for ConstI<480> {
+    type Output = P480;
+}
+
+impl ToInteger for ConstI<-480> {
+    type Output = N480;
+}
+
+impl ToInteger for ConstI<481> {
+    type Output = P481;
+}
+
+impl ToInteger for ConstI<-481> {
+    type Output = N481;
+}
+
+impl ToInteger for ConstI<482> {
+    type Output = P482;
+}
+
+impl ToInteger for ConstI<-482> {
+    type Output = N482;
+}
+
+impl ToInteger for ConstI<483> {
+    type Output = P483;
+}
+
+impl ToInteger for ConstI<-483> {
+    type Output = N483;
+}
+
+impl ToInteger for ConstI<484> {
+    type Output = P484;
+}
+
+impl ToInteger for ConstI<-484> {
+    type Output = N484;
+}
+
+impl ToInteger for ConstI<485> {
+    type Output = P485;
+}
+
+impl ToInteger for ConstI<-485> {
+    type Output = N485;
+}
+
+impl ToInteger for ConstI<486> {
+    type Output = P486;
+}
+
+impl ToInteger for ConstI<-486> {
+    type Output = N486;
+}
+
+impl ToInteger for ConstI<487> {
+    type Output = P487;
+}
+
+impl ToInteger for ConstI<-487> {
+    type Output = N487;
+}
+
+impl ToInteger for ConstI<488> {
+    type Output = P488;
+}
+
+impl ToInteger for ConstI<-488> {
+    type Output = N488;
+}
+
+impl ToInteger for ConstI<489> {
+    type Output = P489;
+}
+
+impl ToInteger for ConstI<-489> {
+    type Output = N489;
+}
+
+impl ToInteger for ConstI<490> {
+    type Output = P490;
+}
+
+impl ToInteger for ConstI<-490> {
+    type Output = N490;
+}
+
+impl ToInteger for ConstI<491> {
+    type Output = P491;
+}
+
+impl ToInteger for ConstI<-491> {
+    type Output = N491;
+}
+
+impl ToInteger for ConstI<492> {
+    type Output = P492;
+}
+
+impl ToInteger for ConstI<-492> {
+    type Output = N492;
+}
+
+impl ToInteger for ConstI<493> {
+    type Output = P493;
+}
+
+impl ToInteger for ConstI<-493> {
+    type Output = N493;
+}
+
+impl ToInteger for ConstI<494> {
+    type Output = P494;
+}
+
+impl ToInteger for ConstI<-494> {
+    type Output = N494;
+}
+
+impl ToInteger for ConstI<495> {
+    type Output = P495;
+}
+
+impl ToInteger for ConstI<-495> {
+    type Output = N495;
+}
+
+impl ToInteger for ConstI<496> {
+    type Output = P496;
+}
+
+impl ToInteger for ConstI<-496> {
+    type Output = N496;
+}
+
+impl ToInteger for ConstI<497> {
+    type Output = P497;
+}
+
+impl ToInteger for ConstI<-497> {
+    type Output = N497;
+}
+
+impl ToInteger for ConstI<498> {
+    type Output = P498;
+}
+
+impl ToInteger for ConstI<-498> {
+    type Output = N498;
+}
+
+impl ToInteger for ConstI<499> {
+    type Output = P499;
+}
+
+impl ToInteger for ConstI<-499> {
+    type Output = N499;
+}
+
+impl ToInteger for ConstI<500> {
+    type Output = P500;
+}
+
+impl ToInteger for ConstI<-500> {
+    type Output = N500;
+}
+
+impl ToInteger for ConstI<501> {
+    type Output = P501;
+}
+
+impl ToInteger for ConstI<-501> {
+    type Output = N501;
+}
+
+impl ToInteger for ConstI<502> {
+    type Output = P502;
+}
+
+impl ToInteger for ConstI<-502> {
+    type Output = N502;
+}
+
+impl ToInteger for ConstI<503> {
+    type Output = P503;
+}
+
+impl ToInteger for ConstI<-503> {
+    type Output = N503;
+}
+
+impl ToInteger for ConstI<504> {
+    type Output = P504;
+}
+
+impl ToInteger for ConstI<-504> {
+    type Output = N504;
+}
+
+impl ToInteger for ConstI<505> {
+    type Output = P505;
+}
+
+impl ToInteger for ConstI<-505> {
+    type Output = N505;
+}
+
+impl ToInteger for ConstI<506> {
+    type Output = P506;
+}
+
+impl ToInteger for ConstI<-506> {
+    type Output = N506;
+}
+
+impl ToInteger for ConstI<507> {
+    type Output = P507;
+}
+
+impl ToInteger for ConstI<-507> {
+    type Output = N507;
+}
+
+impl ToInteger for ConstI<508> {
+    type Output = P508;
+}
+
+impl ToInteger for ConstI<-508> {
+    type Output = N508;
+}
+
+impl ToInteger for ConstI<509> {
+    type Output = P509;
+}
+
+impl ToInteger for ConstI<-509> {
+    type Output = N509;
+}
+
+impl ToInteger for ConstI<510> {
+    type Output = P510;
+}
+
+impl ToInteger for ConstI<-510> {
+    type Output = N510;
+}
+
+impl ToInteger for ConstI<511> {
+    type Output = P511;
+}
+
+impl ToInteger for ConstI<-511> {
+    type Output = N511;
+}
+
+impl ToInteger for ConstI<512> {
+    type Output = P512;
+}
+
+impl ToInteger for ConstI<-512> {
+    type Output = N512;
+}
+
+impl ToInteger for ConstI<513> {
+    type Output = P513;
+}
+
+impl ToInteger for ConstI<-513> {
+    type Output = N513;
+}
+
+impl ToInteger for ConstI<514> {
+    type Output = P514;
+}
+
+impl ToInteger for ConstI<-514> {
+    type Output = N514;
+}
+
+impl ToInteger for ConstI<515> {
+    type Output = P515;
+}
+
+impl ToInteger for ConstI<-515> {
+    type Output = N515;
+}
+
+impl ToInteger for ConstI<516> {
+    type Output = P516;
+}
+
+impl ToInteger for ConstI<-516> {
+    type Output = N516;
+}
+
+impl ToInteger for ConstI<517> {
+    type Output = P517;
+}
+
+impl ToInteger for ConstI<-517> {
+    type Output = N517;
+}
+
+impl ToInteger for ConstI<518> {
+    type Output = P518;
+}
+
+impl ToInteger for ConstI<-518> {
+    type Output = N518;
+}
+
+impl ToInteger for ConstI<519> {
+    type Output = P519;
+}
+
+impl ToInteger for ConstI<-519> {
+    type Output = N519;
+}
+
+impl ToInteger for ConstI<520> {
+    type Output = P520;
+}
+
+impl ToInteger for ConstI<-520> {
+    type Output = N520;
+}
+
+impl ToInteger for ConstI<521> {
+    type Output = P521;
+}
+
+impl ToInteger for ConstI<-521> {
+    type Output = N521;
+}
+
+impl ToInteger for ConstI<522> {
+    type Output = P522;
+}
+
+impl ToInteger for ConstI<-522> {
+    type Output = N522;
+}
+
+impl ToInteger for ConstI<523> {
+    type Output = P523;
+}
+
+impl ToInteger for ConstI<-523> {
+    type Output = N523;
+}
+
+impl ToInteger for ConstI<524> {
+    type Output = P524;
+}
+
+impl ToInteger for ConstI<-524> {
+    type Output = N524;
+}
+
+impl ToInteger for ConstI<525> {
+    type Output = P525;
+}
+
+impl ToInteger for ConstI<-525> {
+    type Output = N525;
+}
+
+impl ToInteger for ConstI<526> {
+    type Output = P526;
+}
+
+impl ToInteger for ConstI<-526> {
+    type Output = N526;
+}
+
+impl ToInteger for ConstI<527> {
+    type Output = P527;
+}
+
+impl ToInteger for ConstI<-527> {
+    type Output = N527;
+}
+
+impl ToInteger for ConstI<528> {
+    type Output = P528;
+}
+
+impl ToInteger for ConstI<-528> {
+    type Output = N528;
+}
+
+impl ToInteger for ConstI<529> {
+    type Output = P529;
+}
+
+impl ToInteger for ConstI<-529> {
+    type Output = N529;
+}
+
+impl ToInteger for ConstI<530> {
+    type Output = P530;
+}
+
+impl ToInteger for ConstI<-530> {
+    type Output = N530;
+}
+
+impl ToInteger for ConstI<531> {
+    type Output = P531;
+}
+
+impl ToInteger for ConstI<-531> {
+    type Output = N531;
+}
+
+impl ToInteger for ConstI<532> {
+    type Output = P532;
+}
+
+impl ToInteger for ConstI<-532> {
+    type Output = N532;
+}
+
+impl ToInteger for ConstI<533> {
+    type Output = P533;
+}
+
+impl ToInteger for ConstI<-533> {
+    type Output = N533;
+}
+
+impl ToInteger for ConstI<534> {
+    type Output = P534;
+}
+
+impl ToInteger for ConstI<-534> {
+    type Output = N534;
+}
+
+impl ToInteger for ConstI<535> {
+    type Output = P535;
+}
+
+impl ToInteger for ConstI<-535> {
+    type Output = N535;
+}
+
+impl ToInteger for ConstI<536> {
+    type Output = P536;
+}
+
+impl ToInteger for ConstI<-536> {
+    type Output = N536;
+}
+
+impl ToInteger for ConstI<537> {
+    type Output = P537;
+}
+
+impl ToInteger for ConstI<-537> {
+    type Output = N537;
+}
+
+impl ToInteger for ConstI<538> {
+    type Output = P538;
+}
+
+impl ToInteger for ConstI<-538> {
+    type Output = N538;
+}
+
+impl ToInteger for ConstI<539> {
+    type Output = P539;
+}
+
+impl ToInteger for ConstI<-539> {
+    type Output = N539;
+}
+
+impl ToInteger for ConstI<540> {
+    type Output = P540;
+}
+
+impl ToInteger for ConstI<-540> {
+    type Output = N540;
+}
+
+impl ToInteger for ConstI<541> {
+    type Output = P541;
+}
+
+impl ToInteger for ConstI<-541> {
+    type Output = N541;
+}
+
+impl ToInteger for ConstI<542> {
+    type Output = P542;
+}
+
+impl ToInteger for ConstI<-542> {
+    type Output = N542;
+}
+
+impl ToInteger for ConstI<543> {
+    type Output = P543;
+}
+
+impl ToInteger for ConstI<-543> {
+    type Output = N543;
+}
+
+impl ToInteger for ConstI<544> {
+    type Output = P544;
+}
+
+impl ToInteger for ConstI<-544> {
+    type Output = N544;
+}
+
+impl ToInteger for ConstI<545> {
+    type Output = P545;
+}
+
+impl ToInteger for ConstI<-545> {
+    type Output = N545;
+}
+
+impl ToInteger for ConstI<546> {
+    type Output = P546;
+}
+
+impl ToInteger for ConstI<-546> {
+    type Output = N546;
+}
+
+impl ToInteger for ConstI<547> {
+    type Output = P547;
+}
+
+impl ToInteger for ConstI<-547> {
+    type Output = N547;
+}
+
+impl ToInteger for ConstI<548> {
+    type Output = P548;
+}
+
+impl ToInteger for ConstI<-548> {
+    type Output = N548;
+}
+
+impl ToInteger for ConstI<549> {
+    type Output = P549;
+}
+
+impl ToInteger for ConstI<-549> {
+    type Output = N549;
+}
+
+impl ToInteger for ConstI<550> {
+    type Output = P550;
+}
+
+impl ToInteger for ConstI<-550> {
+    type Output = N550;
+}
+
+impl ToInteger for ConstI<551> {
+    type Output = P551;
+}
+
+impl ToInteger for ConstI<-551> {
+    type Output = N551;
+}
+
+impl ToInteger for ConstI<552> {
+    type Output = P552;
+}
+
+impl ToInteger for ConstI<-552> {
+    type Output = N552;
+}
+
+impl ToInteger for ConstI<553> {
+    type Output = P553;
+}
+
+impl ToInteger for ConstI<-553> {
+    type Output = N553;
+}
+
+impl ToInteger for ConstI<554> {
+    type Output = P554;
+}
+
+impl ToInteger for ConstI<-554> {
+    type Output = N554;
+}
+
+impl ToInteger for ConstI<555> {
+    type Output = P555;
+}
+
+impl ToInteger for ConstI<-555> {
+    type Output = N555;
+}
+
+impl ToInteger for ConstI<556> {
+    type Output = P556;
+}
+
+impl ToInteger for ConstI<-556> {
+    type Output = N556;
+}
+
+impl ToInteger for ConstI<557> {
+    type Output = P557;
+}
+
+impl ToInteger for ConstI<-557> {
+    type Output = N557;
+}
+
+impl ToInteger for ConstI<558> {
+    type Output = P558;
+}
+
+impl ToInteger for ConstI<-558> {
+    type Output = N558;
+}
+
+impl ToInteger for ConstI<559> {
+    type Output = P559;
+}
+
+impl ToInteger for ConstI<-559> {
+    type Output = N559;
+}
+
+impl ToInteger for ConstI<560> {
+    type Output = P560;
+}
+
+impl ToInteger for ConstI<-560> {
+    type Output = N560;
+}
+
+impl ToInteger for ConstI<561> {
+    type Output = P561;
+}
+
+impl ToInteger for ConstI<-561> {
+    type Output = N561;
+}
+
+impl ToInteger for ConstI<562> {
+    type Output = P562;
+}
+
+impl ToInteger for ConstI<-562> {
+    type Output = N562;
+}
+
+impl ToInteger for ConstI<563> {
+    type Output = P563;
+}
+
+impl ToInteger for ConstI<-563> {
+    type Output = N563;
+}
+
+impl ToInteger for ConstI<564> {
+    type Output = P564;
+}
+
+impl ToInteger for ConstI<-564> {
+    type Output = N564;
+}
+
+impl ToInteger for ConstI<565> {
+    type Output = P565;
+}
+
+impl ToInteger for ConstI<-565> {
+    type Output = N565;
+}
+
+impl ToInteger for ConstI<566> {
+    type Output = P566;
+}
+
+impl ToInteger for ConstI<-566> {
+    type Output = N566;
+}
+
+impl ToInteger for ConstI<567> {
+    type Output = P567;
+}
+
+impl ToInteger for ConstI<-567> {
+    type Output = N567;
+}
+
+impl ToInteger for ConstI<568> {
+    type Output = P568;
+}
+
+impl ToInteger for ConstI<-568> {
+    type Output = N568;
+}
+
+impl ToInteger for ConstI<569> {
+    type Output = P569;
+}
+
+impl ToInteger for ConstI<-569> {
+    type Output = N569;
+}
+
+impl ToInteger for ConstI<570> {
+    type Output = P570;
+}
+
+impl ToInteger for ConstI<-570> {
+    type Output = N570;
+}
+
+impl ToInteger for ConstI<571> {
+    type Output = P571;
+}
+
+impl ToInteger for ConstI<-571> {
+    type Output = N571;
+}
+
+impl ToInteger for ConstI<572> {
+    type Output = P572;
+}
+
+impl ToInteger for ConstI<-572> {
+    type Output = N572;
+}
+
+impl ToInteger for ConstI<573> {
+    type Output = P573;
+}
+
+impl ToInteger for ConstI<-573> {
+    type Output = N573;
+}
+
+impl ToInteger for ConstI<574> {
+    type Output = P574;
+}
+
+impl ToInteger for ConstI<-574> {
+    type Output = N574;
+}
+
+impl ToInteger for ConstI<575> {
+    type Output = P575;
+}
+
+impl ToInteger for ConstI<-575> {
+    type Output = N575;
+}
+
+impl ToInteger for ConstI<576> {
+    type Output = P576;
+}
+
+impl ToInteger for ConstI<-576> {
+    type Output = N576;
+}
+
+impl ToInteger for ConstI<577> {
+    type Output = P577;
+}
+
+impl ToInteger for ConstI<-577> {
+    type Output = N577;
+}
+
+impl ToInteger for ConstI<578> {
+    type Output = P578;
+}
+
+impl ToInteger for ConstI<-578> {
+    type Output = N578;
+}
+
+impl ToInteger for ConstI<579> {
+    type Output = P579;
+}
+
+impl ToInteger for ConstI<-579> {
+    type Output = N579;
+}
+
+impl ToInteger for ConstI<580> {
+    type Output = P580;
+}
+
+impl ToInteger for ConstI<-580> {
+    type Output = N580;
+}
+
+impl ToInteger for ConstI<581> {
+    type Output = P581;
+}
+
+impl ToInteger for ConstI<-581> {
+    type Output = N581;
+}
+
+impl ToInteger for ConstI<582> {
+    type Output = P582;
+}
+
+impl ToInteger for ConstI<-582> {
+    type Output = N582;
+}
+
+impl ToInteger for ConstI<583> {
+    type Output = P583;
+}
+
+impl ToInteger for ConstI<-583> {
+    type Output = N583;
+}
+
+impl ToInteger for ConstI<584> {
+    type Output = P584;
+}
+
+impl ToInteger for ConstI<-584> {
+    type Output = N584;
+}
+
+impl ToInteger for ConstI<585> {
+    type Output = P585;
+}
+
+impl ToInteger for ConstI<-585> {
+    type Output = N585;
+}
+
+impl ToInteger for ConstI<586> {
+    type Output = P586;
+}
+
+impl ToInteger for ConstI<-586> {
+    type Output = N586;
+}
+
+impl ToInteger for ConstI<587> {
+    type Output = P587;
+}
+
+impl ToInteger for ConstI<-587> {
+    type Output = N587;
+}
+
+impl ToInteger for ConstI<588> {
+    type Output = P588;
+}
+
+impl ToInteger for ConstI<-588> {
+    type Output = N588;
+}
+
+impl ToInteger for ConstI<589> {
+    type Output = P589;
+}
+
+impl ToInteger for ConstI<-589> {
+    type Output = N589;
+}
+
+impl ToInteger for ConstI<590> {
+    type Output = P590;
+}
+
+impl ToInteger for ConstI<-590> {
+    type Output = N590;
+}
+
+impl ToInteger for ConstI<591> {
+    type Output = P591;
+}
+
+impl ToInteger for ConstI<-591> {
+    type Output = N591;
+}
+
+impl ToInteger for ConstI<592> {
+    type Output = P592;
+}
+
+impl ToInteger for ConstI<-592> {
+    type Output = N592;
+}
+
+impl ToInteger for ConstI<593> {
+    type Output = P593;
+}
+
+impl ToInteger for ConstI<-593> {
+    type Output = N593;
+}
+
+impl ToInteger for ConstI<594> {
+    type Output = P594;
+}
+
+impl ToInteger for ConstI<-594> {
+    type Output = N594;
+}
+
+impl ToInteger for ConstI<595> {
+    type Output = P595;
+}
+
+impl ToInteger for ConstI<-595> {
+    type Output = N595;
+}
+
+impl ToInteger for ConstI<596> {
+    type Output = P596;
+}
+
+impl ToInteger for ConstI<-596> {
+    type Output = N596;
+}
+
+impl ToInteger for ConstI<597> {
+    type Output = P597;
+}
+
+impl ToInteger for ConstI<-597> {
+    type Output = N597;
+}
+
+impl ToInteger for ConstI<598> {
+    type Output = P598;
+}
+
+impl ToInteger for ConstI<-598> {
+    type Output = N598;
+}
+
+impl ToInteger for ConstI<599> {
+    type Output = P599;
+}
+
+impl ToInteger for ConstI<-599> {
+    type Output = N599;
+}
+
+impl ToInteger for ConstI<600> {
+    type Output = P600;
+}
+
+impl ToInteger for ConstI<-600> {
+    type Output = N600;
+}
+
+impl ToInteger for ConstI<601> {
+    type Output = P601;
+}
+
+impl ToInteger for ConstI<-601> {
+    type Output = N601;
+}
+
+impl ToInteger for ConstI<602> {
+    type Output = P602;
+}
+
+impl ToInteger for ConstI<-602> {
+    type Output = N602;
+}
+
+impl ToInteger for ConstI<603> {
+    type Output = P603;
+}
+
+impl ToInteger for ConstI<-603> {
+    type Output = N603;
+}
+
+impl ToInteger for ConstI<604> {
+    type Output = P604;
+}
+
+impl ToInteger for ConstI<-604> {
+    type Output = N604;
+}
+
+impl ToInteger for ConstI<605> {
+    type Output = P605;
+}
+
+impl ToInteger for ConstI<-605> {
+    type Output = N605;
+}
+
+impl ToInteger for ConstI<606> {
+    type Output = P606;
+}
+
+impl ToInteger for ConstI<-606> {
+    type Output = N606;
+}
+
+impl ToInteger for ConstI<607> {
+    type Output = P607;
+}
+
+impl ToInteger for ConstI<-607> {
+    type Output = N607;
+}
+
+impl ToInteger for ConstI<608> {
+    type Output = P608;
+}
+
+impl ToInteger for ConstI<-608> {
+    type Output = N608;
+}
+
+impl ToInteger for ConstI<609> {
+    type Output = P609;
+}
+
+impl ToInteger for ConstI<-609> {
+    type Output = N609;
+}
+
+impl ToInteger for ConstI<610> {
+    type Output = P610;
+}
+
+impl ToInteger for ConstI<-610> {
+    type Output = N610;
+}
+
+impl ToInteger for ConstI<611> {
+    type Output = P611;
+}
+
+impl ToInteger for ConstI<-611> {
+    type Output = N611;
+}
+
+impl ToInteger for ConstI<612> {
+    type Output = P612;
+}
+
+impl ToInteger for ConstI<-612> {
+    type Output = N612;
+}
+
+impl ToInteger for ConstI<613> {
+    type Output = P613;
+}
+
+impl ToInteger for ConstI<-613> {
+    type Output = N613;
+}
+
+impl ToInteger for ConstI<614> {
+    type Output = P614;
+}
+
+impl ToInteger for ConstI<-614> {
+    type Output = N614;
+}
+
+impl ToInteger for ConstI<615> {
+    type Output = P615;
+}
+
+impl ToInteger for ConstI<-615> {
+    type Output = N615;
+}
+
+impl ToInteger for ConstI<616> {
+    type Output = P616;
+}
+
+impl ToInteger for ConstI<-616> {
+    type Output = N616;
+}
+
+impl ToInteger for ConstI<617> {
+    type Output = P617;
+}
+
+impl ToInteger for ConstI<-617> {
+    type Output = N617;
+}
+
+impl ToInteger for ConstI<618> {
+    type Output = P618;
+}
+
+impl ToInteger for ConstI<-618> {
+    type Output = N618;
+}
+
+impl ToInteger for ConstI<619> {
+    type Output = P619;
+}
+
+impl ToInteger for ConstI<-619> {
+    type Output = N619;
+}
+
+impl ToInteger for ConstI<620> {
+    type Output = P620;
+}
+
+impl ToInteger for ConstI<-620> {
+    type Output = N620;
+}
+
+impl ToInteger for ConstI<621> {
+    type Output = P621;
+}
+
+impl ToInteger for ConstI<-621> {
+    type Output = N621;
+}
+
+impl ToInteger for ConstI<622> {
+    type Output = P622;
+}
+
+impl ToInteger for ConstI<-622> {
+    type Output = N622;
+}
+
+impl ToInteger for ConstI<623> {
+    type Output = P623;
+}
+
+impl ToInteger for ConstI<-623> {
+    type Output = N623;
+}
+
+impl ToInteger for ConstI<624> {
+    type Output = P624;
+}
+
+impl ToInteger for ConstI<-624> {
+    type Output = N624;
+}
+
+impl ToInteger for ConstI<625> {
+    type Output = P625;
+}
+
+impl ToInteger for ConstI<-625> {
+    type Output = N625;
+}
+
+impl ToInteger for ConstI<626> {
+    type Output = P626;
+}
+
+impl ToInteger for ConstI<-626> {
+    type Output = N626;
+}
+
+impl ToInteger for ConstI<627> {
+    type Output = P627;
+}
+
+impl ToInteger for ConstI<-627> {
+    type Output = N627;
+}
+
+impl ToInteger for ConstI<628> {
+    type Output = P628;
+}
+
+impl ToInteger for ConstI<-628> {
+    type Output = N628;
+}
+
+impl ToInteger for ConstI<629> {
+    type Output = P629;
+}
+
+impl ToInteger for ConstI<-629> {
+    type Output = N629;
+}
+
+impl ToInteger for ConstI<630> {
+    type Output = P630;
+}
+
+impl ToInteger for ConstI<-630> {
+    type Output = N630;
+}
+
+impl ToInteger for ConstI<631> {
+    type Output = P631;
+}
+
+impl ToInteger for ConstI<-631> {
+    type Output = N631;
+}
+
+impl ToInteger for ConstI<632> {
+    type Output = P632;
+}
+
+impl ToInteger for ConstI<-632> {
+    type Output = N632;
+}
+
+impl ToInteger for ConstI<633> {
+    type Output = P633;
+}
+
+impl ToInteger for ConstI<-633> {
+    type Output = N633;
+}
+
+impl ToInteger for ConstI<634> {
+    type Output = P634;
+}
+
+impl ToInteger for ConstI<-634> {
+    type Output = N634;
+}
+
+impl ToInteger for ConstI<635> {
+    type Output = P635;
+}
+
+impl ToInteger for ConstI<-635> {
+    type Output = N635;
+}
+
+impl ToInteger for ConstI<636> {
+    type Output = P636;
+}
+
+impl ToInteger for ConstI<-636> {
+    type Output = N636;
+}
+
+impl ToInteger for ConstI<637> {
+    type Output = P637;
+}
+
+impl ToInteger for ConstI<-637> {
+    type Output = N637;
+}
+
+impl ToInteger for ConstI<638> {
+    type Output = P638;
+}
+
+impl ToInteger for ConstI<-638> {
+    type Output = N638;
+}
+
+impl ToInteger for ConstI<639> {
+    type Output = P639;
+}
+
+impl ToInteger for ConstI<-639> {
+    type Output = N639;
+}
+
+impl ToInteger for ConstI<640> {
+    type Output = P640;
+}
+
+impl ToInteger for ConstI<-640> {
+    type Output = N640;
+}
+
+impl ToInteger for ConstI<641> {
+    type Output = P641;
+}
+
+impl ToInteger for ConstI<-641> {
+    type Output = N641;
+}
+
+impl ToInteger for ConstI<642> {
+    type Output = P642;
+}
+
+impl ToInteger for ConstI<-642> {
+    type Output = N642;
+}
+
+impl ToInteger for ConstI<643> {
+    type Output = P643;
+}
+
+impl ToInteger for ConstI<-643> {
+    type Output = N643;
+}
+
+impl ToInteger for ConstI<644> {
+    type Output = P644;
+}
+
+impl ToInteger for ConstI<-644> {
+    type Output = N644;
+}
+
+impl ToInteger for ConstI<645> {
+    type Output = P645;
+}
+
+impl ToInteger for ConstI<-645> {
+    type Output = N645;
+}
+
+impl ToInteger for ConstI<646> {
+    type Output = P646;
+}
+
+impl ToInteger for ConstI<-646> {
+    type Output = N646;
+}
+
+impl ToInteger for ConstI<647> {
+    type Output = P647;
+}
+
+impl ToInteger for ConstI<-647> {
+    type Output = N647;
+}
+
+impl ToInteger for ConstI<648> {
+    type Output = P648;
+}
+
+impl ToInteger for ConstI<-648> {
+    type Output = N648;
+}
+
+impl ToInteger for ConstI<649> {
+    type Output = P649;
+}
+
+impl ToInteger for ConstI<-649> {
+    type Output = N649;
+}
+
+impl ToInteger for ConstI<650> {
+    type Output = P650;
+}
+
+impl ToInteger for ConstI<-650> {
+    type Output = N650;
+}
+
+impl ToInteger for ConstI<651> {
+    type Output = P651;
+}
+
+impl ToInteger for ConstI<-651> {
+    type Output = N651;
+}
+
+impl ToInteger for ConstI<652> {
+    type Output = P652;
+}
+
+impl ToInteger for ConstI<-652> {
+    type Output = N652;
+}
+
+impl ToInteger for ConstI<653> {
+    type Output = P653;
+}
+
+impl ToInteger for ConstI<-653> {
+    type Output = N653;
+}
+
+impl ToInteger for ConstI<654> {
+    type Output = P654;
+}
+
+impl ToInteger for ConstI<-654> {
+    type Output = N654;
+}
+
+impl ToInteger for ConstI<655> {
+    type Output = P655;
+}
+
+impl ToInteger for ConstI<-655> {
+    type Output = N655;
+}
+
+impl ToInteger for ConstI<656> {
+    type Output = P656;
+}
+
+impl ToInteger for ConstI<-656> {
+    type Output = N656;
+}
+
+impl ToInteger for ConstI<657> {
+    type Output = P657;
+}
+
+impl ToInteger for ConstI<-657> {
+    type Output = N657;
+}
+
+impl ToInteger for ConstI<658> {
+    type Output = P658;
+}
+
+impl ToInteger for ConstI<-658> {
+    type Output = N658;
+}
+
+impl ToInteger for ConstI<659> {
+    type Output = P659;
+}
+
+impl ToInteger for ConstI<-659> {
+    type Output = N659;
+}
+
+impl ToInteger for ConstI<660> {
+    type Output = P660;
+}
+
+impl ToInteger for ConstI<-660> {
+    type Output = N660;
+}
+
+impl ToInteger for ConstI<661> {
+    type Output = P661;
+}
+
+impl ToInteger for ConstI<-661> {
+    type Output = N661;
+}
+
+impl ToInteger for ConstI<662> {
+    type Output = P662;
+}
+
+impl ToInteger for ConstI<-662> {
+    type Output = N662;
+}
+
+impl ToInteger for ConstI<663> {
+    type Output = P663;
+}
+
+impl ToInteger for ConstI<-663> {
+    type Output = N663;
+}
+
+impl ToInteger for ConstI<664> {
+    type Output = P664;
+}
+
+impl ToInteger for ConstI<-664> {
+    type Output = N664;
+}
+
+impl ToInteger for ConstI<665> {
+    type Output = P665;
+}
+
+impl ToInteger for ConstI<-665> {
+    type Output = N665;
+}
+
+impl ToInteger for ConstI<666> {
+    type Output = P666;
+}
+
+impl ToInteger for ConstI<-666> {
+    type Output = N666;
+}
+
+impl ToInteger for ConstI<667> {
+    type Output = P667;
+}
+
+impl ToInteger for ConstI<-667> {
+    type Output = N667;
+}
+
+impl ToInteger for ConstI<668> {
+    type Output = P668;
+}
+
+impl ToInteger for ConstI<-668> {
+    type Output = N668;
+}
+
+impl ToInteger for ConstI<669> {
+    type Output = P669;
+}
+
+impl ToInteger for ConstI<-669> {
+    type Output = N669;
+}
+
+impl ToInteger for ConstI<670> {
+    type Output = P670;
+}
+
+impl ToInteger for ConstI<-670> {
+    type Output = N670;
+}
+
+impl ToInteger for ConstI<671> {
+    type Output = P671;
+}
+
+impl ToInteger for ConstI<-671> {
+    type Output = N671;
+}
+
+impl ToInteger for ConstI<672> {
+    type Output = P672;
+}
+
+impl ToInteger for ConstI<-672> {
+    type Output = N672;
+}
+
+impl ToInteger for ConstI<673> {
+    type Output = P673;
+}
+
+impl ToInteger for ConstI<-673> {
+    type Output = N673;
+}
+
+impl ToInteger for ConstI<674> {
+    type Output = P674;
+}
+
+impl ToInteger for ConstI<-674> {
+    type Output = N674;
+}
+
+impl ToInteger for ConstI<675> {
+    type Output = P675;
+}
+
+impl ToInteger for ConstI<-675> {
+    type Output = N675;
+}
+
+impl ToInteger for ConstI<676> {
+    type Output = P676;
+}
+
+impl ToInteger for ConstI<-676> {
+    type Output = N676;
+}
+
+impl ToInteger for ConstI<677> {
+    type Output = P677;
+}
+
+impl ToInteger for ConstI<-677> {
+    type Output = N677;
+}
+
+impl ToInteger for ConstI<678> {
+    type Output = P678;
+}
+
+impl ToInteger for ConstI<-678> {
+    type Output = N678;
+}
+
+impl ToInteger for ConstI<679> {
+    type Output = P679;
+}
+
+impl ToInteger for ConstI<-679> {
+    type Output = N679;
+}
+
+impl ToInteger for ConstI<680> {
+    type Output = P680;
+}
+
+impl ToInteger for ConstI<-680> {
+    type Output = N680;
+}
+
+impl ToInteger for ConstI<681> {
+    type Output = P681;
+}
+
+impl ToInteger for ConstI<-681> {
+    type Output = N681;
+}
+
+impl ToInteger for ConstI<682> {
+    type Output = P682;
+}
+
+impl ToInteger for ConstI<-682> {
+    type Output = N682;
+}
+
+impl ToInteger for ConstI<683> {
+    type Output = P683;
+}
+
+impl ToInteger for ConstI<-683> {
+    type Output = N683;
+}
+
+impl ToInteger for ConstI<684> {
+    type Output = P684;
+}
+
+impl ToInteger for ConstI<-684> {
+    type Output = N684;
+}
+
+impl ToInteger for ConstI<685> {
+    type Output = P685;
+}
+
+impl ToInteger for ConstI<-685> {
+    type Output = N685;
+}
+
+impl ToInteger for ConstI<686> {
+    type Output = P686;
+}
+
+impl ToInteger for ConstI<-686> {
+    type Output = N686;
+}
+
+impl ToInteger for ConstI<687> {
+    type Output = P687;
+}
+
+impl ToInteger for ConstI<-687> {
+    type Output = N687;
+}
+
+impl ToInteger for ConstI<688> {
+    type Output = P688;
+}
+
+impl ToInteger for ConstI<-688> {
+    type Output = N688;
+}
+
+impl ToInteger for ConstI<689> {
+    type Output = P689;
+}
+
+impl ToInteger for ConstI<-689> {
+    type Output = N689;
+}
+
+impl ToInteger for ConstI<690> {
+    type Output = P690;
+}
+
+impl ToInteger for ConstI<-690> {
+    type Output = N690;
+}
+
+impl ToInteger for ConstI<691> {
+    type Output = P691;
+}
+
+impl ToInteger for ConstI<-691> {
+    type Output = N691;
+}
+
+impl ToInteger for ConstI<692> {
+    type Output = P692;
+}
+
+impl ToInteger for ConstI<-692> {
+    type Output = N692;
+}
+
+impl ToInteger for ConstI<693> {
+    type Output = P693;
+}
+
+impl ToInteger for ConstI<-693> {
+    type Output = N693;
+}
+
+impl ToInteger for ConstI<694> {
+    type Output = P694;
+}
+
+impl ToInteger for ConstI<-694> {
+    type Output = N694;
+}
+
+impl ToInteger for ConstI<695> {
+    type Output = P695;
+}
+
+impl ToInteger for ConstI<-695> {
+    type Output = N695;
+}
+
+impl ToInteger for ConstI<696> {
+    type Output = P696;
+}
+
+impl ToInteger for ConstI<-696> {
+    type Output = N696;
+}
+
+impl ToInteger for ConstI<697> {
+    type Output = P697;
+}
+
+impl ToInteger for ConstI<-697> {
+    type Output = N697;
+}
+
+impl ToInteger for ConstI<698> {
+    type Output = P698;
+}
+
+impl ToInteger for ConstI<-698> {
+    type Output = N698;
+}
+
+impl ToInteger for ConstI<699> {
+    type Output = P699;
+}
+
+impl ToInteger for ConstI<-699> {
+    type Output = N699;
+}
+
+impl ToInteger for ConstI<700> {
+    type Output = P700;
+}
+
+impl ToInteger for ConstI<-700> {
+    type Output = N700;
+}
+
+impl ToInteger for ConstI<701> {
+    type Output = P701;
+}
+
+impl ToInteger for ConstI<-701> {
+    type Output = N701;
+}
+
+impl ToInteger for ConstI<702> {
+    type Output = P702;
+}
+
+impl ToInteger for ConstI<-702> {
+    type Output = N702;
+}
+
+impl ToInteger for ConstI<703> {
+    type Output = P703;
+}
+
+impl ToInteger for ConstI<-703> {
+    type Output = N703;
+}
+
+impl ToInteger for ConstI<704> {
+    type Output = P704;
+}
+
+impl ToInteger for ConstI<-704> {
+    type Output = N704;
+}
+
+impl ToInteger for ConstI<705> {
+    type Output = P705;
+}
+
+impl ToInteger for ConstI<-705> {
+    type Output = N705;
+}
+
+impl ToInteger for ConstI<706> {
+    type Output = P706;
+}
+
+impl ToInteger for ConstI<-706> {
+    type Output = N706;
+}
+
+impl ToInteger for ConstI<707> {
+    type Output = P707;
+}
+
+impl ToInteger for ConstI<-707> {
+    type Output = N707;
+}
+
+impl ToInteger for ConstI<708> {
+    type Output = P708;
+}
+
+impl ToInteger for ConstI<-708> {
+    type Output = N708;
+}
+
+impl ToInteger for ConstI<709> {
+    type Output = P709;
+}
+
+impl ToInteger for ConstI<-709> {
+    type Output = N709;
+}
+
+impl ToInteger for ConstI<710> {
+    type Output = P710;
+}
+
+impl ToInteger for ConstI<-710> {
+    type Output = N710;
+}
+
+impl ToInteger for ConstI<711> {
+    type Output = P711;
+}
+
+impl ToInteger for ConstI<-711> {
+    type Output = N711;
+}
+
+impl ToInteger for ConstI<712> {
+    type Output = P712;
+}
+
+impl ToInteger for ConstI<-712> {
+    type Output = N712;
+}
+
+impl ToInteger for ConstI<713> {
+    type Output = P713;
+}
+
+impl ToInteger for ConstI<-713> {
+    type Output = N713;
+}
+
+impl ToInteger for ConstI<714> {
+    type Output = P714;
+}
+
+impl ToInteger for ConstI<-714> {
+    type Output = N714;
+}
+
+impl ToInteger for ConstI<715> {
+    type Output = P715;
+}
+
+impl ToInteger for ConstI<-715> {
+    type Output = N715;
+}
+
+impl ToInteger for ConstI<716> {
+    type Output = P716;
+}
+
+impl ToInteger for ConstI<-716> {
+    type Output = N716;
+}
+
+impl ToInteger for ConstI<717> {
+    type Output = P717;
+}
+
+impl ToInteger for ConstI<-717> {
+    type Output = N717;
+}
+
+impl ToInteger for ConstI<718> {
+    type Output = P718;
+}
+
+impl ToInteger for ConstI<-718> {
+    type Output = N718;
+}
+
+impl ToInteger for ConstI<719> {
+    type Output = P719;
+}
+
+impl ToInteger for ConstI<-719> {
+    type Output = N719;
+}
+
+impl ToInteger for ConstI<720> {
+    type Output = P720;
+}
+
+impl ToInteger for ConstI<-720> {
+    type Output = N720;
+}
+
+impl ToInteger for ConstI<721> {
+    type Output = P721;
+}
+
+impl ToInteger for ConstI<-721> {
+    type Output = N721;
+}
+
+impl ToInteger for ConstI<722> {
+    type Output = P722;
+}
+
+impl ToInteger for ConstI<-722> {
+    type Output = N722;
+}
+
+impl ToInteger for ConstI<723> {
+    type Output = P723;
+}
+
+impl ToInteger for ConstI<-723> {
+    type Output = N723;
+}
+
+impl ToInteger for ConstI<724> {
+    type Output = P724;
+}
+
+impl ToInteger for ConstI<-724> {
+    type Output = N724;
+}
+
+impl ToInteger for ConstI<725> {
+    type Output = P725;
+}
+
+impl ToInteger for ConstI<-725> {
+    type Output = N725;
+}
+
+impl ToInteger for ConstI<726> {
+    type Output = P726;
+}
+
+impl ToInteger for ConstI<-726> {
+    type Output = N726;
+}
+
+impl ToInteger for ConstI<727> {
+    type Output = P727;
+}
+
+impl ToInteger for ConstI<-727> {
+    type Output = N727;
+}
+
+impl ToInteger for ConstI<728> {
+    type Output = P728;
+}
+
+impl ToInteger for ConstI<-728> {
+    type Output = N728;
+}
+
+impl ToInteger for ConstI<729> {
+    type Output = P729;
+}
+
+impl ToInteger for ConstI<-729> {
+    type Output = N729;
+}
+
+impl ToInteger for ConstI<730> {
+    type Output = P730;
+}
+
+impl ToInteger for ConstI<-730> {
+    type Output = N730;
+}
+
+impl ToInteger for ConstI<731> {
+    type Output = P731;
+}
+
+impl ToInteger for ConstI<-731> {
+    type Output = N731;
+}
+
+impl ToInteger for ConstI<732> {
+    type Output = P732;
+}
+
+impl ToInteger for ConstI<-732> {
+    type Output = N732;
+}
+
+impl ToInteger for ConstI<733> {
+    type Output = P733;
+}
+
+impl ToInteger for ConstI<-733> {
+    type Output = N733;
+}
+
+impl ToInteger for ConstI<734> {
+    type Output = P734;
+}
+
+impl ToInteger for ConstI<-734> {
+    type Output = N734;
+}
+
+impl ToInteger for ConstI<735> {
+    type Output = P735;
+}
+
+impl ToInteger for ConstI<-735> {
+    type Output = N735;
+}
+
+impl ToInteger for ConstI<736> {
+    type Output = P736;
+}
+
+impl ToInteger for ConstI<-736> {
+    type Output = N736;
+}
+
+impl ToInteger for ConstI<737> {
+    type Output = P737;
+}
+
+impl ToInteger for ConstI<-737> {
+    type Output = N737;
+}
+
+impl ToInteger for ConstI<738> {
+    type Output = P738;
+}
+
+impl ToInteger for ConstI<-738> {
+    type Output = N738;
+}
+
+impl ToInteger for ConstI<739> {
+    type Output = P739;
+}
+
+impl ToInteger for ConstI<-739> {
+    type Output = N739;
+}
+
+impl ToInteger for ConstI<740> {
+    type Output = P740;
+}
+
+impl ToInteger for ConstI<-740> {
+    type Output = N740;
+}
+
+impl ToInteger for ConstI<741> {
+    type Output = P741;
+}
+
+impl ToInteger for ConstI<-741> {
+    type Output = N741;
+}
+
+impl ToInteger for ConstI<742> {
+    type Output = P742;
+}
+
+impl ToInteger for ConstI<-742> {
+    type Output = N742;
+}
+
+impl ToInteger for ConstI<743> {
+    type Output = P743;
+}
+
+impl ToInteger for ConstI<-743> {
+    type Output = N743;
+}
+
+impl ToInteger for ConstI<744> {
+    type Output = P744;
+}
+
+impl ToInteger for ConstI<-744> {
+    type Output = N744;
+}
+
+impl ToInteger for ConstI<745> {
+    type Output = P745;
+}
+
+impl ToInteger for ConstI<-745> {
+    type Output = N745;
+}
+
+impl ToInteger for ConstI<746> {
+    type Output = P746;
+}
+
+impl ToInteger for ConstI<-746> {
+    type Output = N746;
+}
+
+impl ToInteger for ConstI<747> {
+    type Output = P747;
+}
+
+impl ToInteger for ConstI<-747> {
+    type Output = N747;
+}
+
+impl ToInteger for ConstI<748> {
+    type Output = P748;
+}
+
+impl ToInteger for ConstI<-748> {
+    type Output = N748;
+}
+
+impl ToInteger for ConstI<749> {
+    type Output = P749;
+}
+
+impl ToInteger for ConstI<-749> {
+    type Output = N749;
+}
+
+impl ToInteger for ConstI<750> {
+    type Output = P750;
+}
+
+impl ToInteger for ConstI<-750> {
+    type Output = N750;
+}
+
+impl ToInteger for ConstI<751> {
+    type Output = P751;
+}
+
+impl ToInteger for ConstI<-751> {
+    type Output = N751;
+}
+
+impl ToInteger for ConstI<752> {
+    type Output = P752;
+}
+
+impl ToInteger for ConstI<-752> {
+    type Output = N752;
+}
+
+impl ToInteger for ConstI<753> {
+    type Output = P753;
+}
+
+impl ToInteger for ConstI<-753> {
+    type Output = N753;
+}
+
+impl ToInteger for ConstI<754> {
+    type Output = P754;
+}
+
+impl ToInteger for ConstI<-754> {
+    type Output = N754;
+}
+
+impl ToInteger for ConstI<755> {
+    type Output = P755;
+}
+
+impl ToInteger for ConstI<-755> {
+    type Output = N755;
+}
+
+impl ToInteger for ConstI<756> {
+    type Output = P756;
+}
+
+impl ToInteger for ConstI<-756> {
+    type Output = N756;
+}
+
+impl ToInteger for ConstI<757> {
+    type Output = P757;
+}
+
+impl ToInteger for ConstI<-757> {
+    type Output = N757;
+}
+
+impl ToInteger for ConstI<758> {
+    type Output = P758;
+}
+
+impl ToInteger for ConstI<-758> {
+    type Output = N758;
+}
+
+impl ToInteger for ConstI<759> {
+    type Output = P759;
+}
+
+impl ToInteger for ConstI<-759> {
+    type Output = N759;
+}
+
+impl ToInteger for ConstI<760> {
+    type Output = P760;
+}
+
+impl ToInteger for ConstI<-760> {
+    type Output = N760;
+}
+
+impl ToInteger for ConstI<761> {
+    type Output = P761;
+}
+
+impl ToInteger for ConstI<-761> {
+    type Output = N761;
+}
+
+impl ToInteger for ConstI<762> {
+    type Output = P762;
+}
+
+impl ToInteger for ConstI<-762> {
+    type Output = N762;
+}
+
+impl ToInteger for ConstI<763> {
+    type Output = P763;
+}
+
+impl ToInteger for ConstI<-763> {
+    type Output = N763;
+}
+
+impl ToInteger for ConstI<764> {
+    type Output = P764;
+}
+
+impl ToInteger for ConstI<-764> {
+    type Output = N764;
+}
+
+impl ToInteger for ConstI<765> {
+    type Output = P765;
+}
+
+impl ToInteger for ConstI<-765> {
+    type Output = N765;
+}
+
+impl ToInteger for ConstI<766> {
+    type Output = P766;
+}
+
+impl ToInteger for ConstI<-766> {
+    type Output = N766;
+}
+
+impl ToInteger for ConstI<767> {
+    type Output = P767;
+}
+
+impl ToInteger for ConstI<-767> {
+    type Output = N767;
+}
+
+impl ToInteger for ConstI<768> {
+    type Output = P768;
+}
+
+impl ToInteger for ConstI<-768> {
+    type Output = N768;
+}
+
+impl ToInteger for ConstI<769> {
+    type Output = P769;
+}
+
+impl ToInteger for ConstI<-769> {
+    type Output = N769;
+}
+
+impl ToInteger for ConstI<770> {
+    type Output = P770;
+}
+
+impl ToInteger for ConstI<-770> {
+    type Output = N770;
+}
+
+impl ToInteger for ConstI<771> {
+    type Output = P771;
+}
+
+impl ToInteger for ConstI<-771> {
+    type Output = N771;
+}
+
+impl ToInteger for ConstI<772> {
+    type Output = P772;
+}
+
+impl ToInteger for ConstI<-772> {
+    type Output = N772;
+}
+
+impl ToInteger for ConstI<773> {
+    type Output = P773;
+}
+
+impl ToInteger for ConstI<-773> {
+    type Output = N773;
+}
+
+impl ToInteger for ConstI<774> {
+    type Output = P774;
+}
+
+impl ToInteger for ConstI<-774> {
+    type Output = N774;
+}
+
+impl ToInteger for ConstI<775> {
+    type Output = P775;
+}
+
+impl ToInteger for ConstI<-775> {
+    type Output = N775;
+}
+
+impl ToInteger for ConstI<776> {
+    type Output = P776;
+}
+
+impl ToInteger for ConstI<-776> {
+    type Output = N776;
+}
+
+impl ToInteger for ConstI<777> {
+    type Output = P777;
+}
+
+impl ToInteger for ConstI<-777> {
+    type Output = N777;
+}
+
+impl ToInteger for ConstI<778> {
+    type Output = P778;
+}
+
+impl ToInteger for ConstI<-778> {
+    type Output = N778;
+}
+
+impl ToInteger for ConstI<779> {
+    type Output = P779;
+}
+
+impl ToInteger for ConstI<-779> {
+    type Output = N779;
+}
+
+impl ToInteger for ConstI<780> {
+    type Output = P780;
+}
+
+impl ToInteger for ConstI<-780> {
+    type Output = N780;
+}
+
+impl ToInteger for ConstI<781> {
+    type Output = P781;
+}
+
+impl ToInteger for ConstI<-781> {
+    type Output = N781;
+}
+
+impl ToInteger for ConstI<782> {
+    type Output = P782;
+}
+
+impl ToInteger for ConstI<-782> {
+    type Output = N782;
+}
+
+impl ToInteger for ConstI<783> {
+    type Output = P783;
+}
+
+impl ToInteger for ConstI<-783> {
+    type Output = N783;
+}
+
+impl ToInteger for ConstI<784> {
+    type Output = P784;
+}
+
+impl ToInteger for ConstI<-784> {
+    type Output = N784;
+}
+
+impl ToInteger for ConstI<785> {
+    type Output = P785;
+}
+
+impl ToInteger for ConstI<-785> {
+    type Output = N785;
+}
+
+impl ToInteger for ConstI<786> {
+    type Output = P786;
+}
+
+impl ToInteger for ConstI<-786> {
+    type Output = N786;
+}
+
+impl ToInteger for ConstI<787> {
+    type Output = P787;
+}
+
+impl ToInteger for ConstI<-787> {
+    type Output = N787;
+}
+
+impl ToInteger for ConstI<788> {
+    type Output = P788;
+}
+
+impl ToInteger for ConstI<-788> {
+    type Output = N788;
+}
+
+impl ToInteger for ConstI<789> {
+    type Output = P789;
+}
+
+impl ToInteger for ConstI<-789> {
+    type Output = N789;
+}
+
+impl ToInteger for ConstI<790> {
+    type Output = P790;
+}
+
+impl ToInteger for ConstI<-790> {
+    type Output = N790;
+}
+
+impl ToInteger for ConstI<791> {
+    type Output = P791;
+}
+
+impl ToInteger for ConstI<-791> {
+    type Output = N791;
+}
+
+impl ToInteger for ConstI<792> {
+    type Output = P792;
+}
+
+impl ToInteger for ConstI<-792> {
+    type Output = N792;
+}
+
+impl ToInteger for ConstI<793> {
+    type Output = P793;
+}
+
+impl ToInteger for ConstI<-793> {
+    type Output = N793;
+}
+
+impl ToInteger for ConstI<794> {
+    type Output = P794;
+}
+
+impl ToInteger for ConstI<-794> {
+    type Output = N794;
+}
+
+impl ToInteger for ConstI<795> {
+    type Output = P795;
+}
+
+impl ToInteger for ConstI<-795> {
+    type Output = N795;
+}
+
+impl ToInteger for ConstI<796> {
+    type Output = P796;
+}
+
+impl ToInteger for ConstI<-796> {
+    type Output = N796;
+}
+
+impl ToInteger for ConstI<797> {
+    type Output = P797;
+}
+
+impl ToInteger for ConstI<-797> {
+    type Output = N797;
+}
+
+impl ToInteger for ConstI<798> {
+    type Output = P798;
+}
+
+impl ToInteger for ConstI<-798> {
+    type Output = N798;
+}
+
+impl ToInteger for ConstI<799> {
+    type Output = P799;
+}
+
+impl ToInteger for ConstI<-799> {
+    type Output = N799;
+}
+
+impl ToInteger for ConstI<800> {
+    type Output = P800;
+}
+
+impl ToInteger for ConstI<-800> {
+    type Output = N800;
+}
+
+impl ToInteger for ConstI<801> {
+    type Output = P801;
+}
+
+impl ToInteger for ConstI<-801> {
+    type Output = N801;
+}
+
+impl ToInteger for ConstI<802> {
+    type Output = P802;
+}
+
+impl ToInteger for ConstI<-802> {
+    type Output = N802;
+}
+
+impl ToInteger for ConstI<803> {
+    type Output = P803;
+}
+
+impl ToInteger for ConstI<-803> {
+    type Output = N803;
+}
+
+impl ToInteger for ConstI<804> {
+    type Output = P804;
+}
+
+impl ToInteger for ConstI<-804> {
+    type Output = N804;
+}
+
+impl ToInteger for ConstI<805> {
+    type Output = P805;
+}
+
+impl ToInteger for ConstI<-805> {
+    type Output = N805;
+}
+
+impl ToInteger for ConstI<806> {
+    type Output = P806;
+}
+
+impl ToInteger for ConstI<-806> {
+    type Output = N806;
+}
+
+impl ToInteger for ConstI<807> {
+    type Output = P807;
+}
+
+impl ToInteger for ConstI<-807> {
+    type Output = N807;
+}
+
+impl ToInteger for ConstI<808> {
+    type Output = P808;
+}
+
+impl ToInteger for ConstI<-808> {
+    type Output = N808;
+}
+
+impl ToInteger for ConstI<809> {
+    type Output = P809;
+}
+
+impl ToInteger for ConstI<-809> {
+    type Output = N809;
+}
+
+impl ToInteger for ConstI<810> {
+    type Output = P810;
+}
+
+impl ToInteger for ConstI<-810> {
+    type Output = N810;
+}
+
+impl ToInteger for ConstI<811> {
+    type Output = P811;
+}
+
+impl ToInteger for ConstI<-811> {
+    type Output = N811;
+}
+
+impl ToInteger for ConstI<812> {
+    type Output = P812;
+}
+
+impl ToInteger for ConstI<-812> {
+    type Output = N812;
+}
+
+impl ToInteger for ConstI<813> {
+    type Output = P813;
+}
+
+impl ToInteger for ConstI<-813> {
+    type Output = N813;
+}
+
+impl ToInteger for ConstI<814> {
+    type Output = P814;
+}
+
+impl ToInteger for ConstI<-814> {
+    type Output = N814;
+}
+
+impl ToInteger for ConstI<815> {
+    type Output = P815;
+}
+
+impl ToInteger for ConstI<-815> {
+    type Output = N815;
+}
+
+impl ToInteger for ConstI<816> {
+    type Output = P816;
+}
+
+impl ToInteger for ConstI<-816> {
+    type Output = N816;
+}
+
+impl ToInteger for ConstI<817> {
+    type Output = P817;
+}
+
+impl ToInteger for ConstI<-817> {
+    type Output = N817;
+}
+
+impl ToInteger for ConstI<818> {
+    type Output = P818;
+}
+
+impl ToInteger for ConstI<-818> {
+    type Output = N818;
+}
+
+impl ToInteger for ConstI<819> {
+    type Output = P819;
+}
+
+impl ToInteger for ConstI<-819> {
+    type Output = N819;
+}
+
+impl ToInteger for ConstI<820> {
+    type Output = P820;
+}
+
+impl ToInteger for ConstI<-820> {
+    type Output = N820;
+}
+
+impl ToInteger for ConstI<821> {
+    type Output = P821;
+}
+
+impl ToInteger for ConstI<-821> {
+    type Output = N821;
+}
+
+impl ToInteger for ConstI<822> {
+    type Output = P822;
+}
+
+impl ToInteger for ConstI<-822> {
+    type Output = N822;
+}
+
+impl ToInteger for ConstI<823> {
+    type Output = P823;
+}
+
+impl ToInteger for ConstI<-823> {
+    type Output = N823;
+}
+
+impl ToInteger for ConstI<824> {
+    type Output = P824;
+}
+
+impl ToInteger for ConstI<-824> {
+    type Output = N824;
+}
+
+impl ToInteger for ConstI<825> {
+    type Output = P825;
+}
+
+impl ToInteger for ConstI<-825> {
+    type Output = N825;
+}
+
+impl ToInteger for ConstI<826> {
+    type Output = P826;
+}
+
+impl ToInteger for ConstI<-826> {
+    type Output = N826;
+}
+
+impl ToInteger for ConstI<827> {
+    type Output = P827;
+}
+
+impl ToInteger for ConstI<-827> {
+    type Output = N827;
+}
+
+impl ToInteger for ConstI<828> {
+    type Output = P828;
+}
+
+impl ToInteger for ConstI<-828> {
+    type Output = N828;
+}
+
+impl ToInteger for ConstI<829> {
+    type Output = P829;
+}
+
+impl ToInteger for ConstI<-829> {
+    type Output = N829;
+}
+
+impl ToInteger for ConstI<830> {
+    type Output = P830;
+}
+
+impl ToInteger for ConstI<-830> {
+    type Output = N830;
+}
+
+impl ToInteger for ConstI<831> {
+    type Output = P831;
+}
+
+impl ToInteger for ConstI<-831> {
+    type Output = N831;
+}
+
+impl ToInteger for ConstI<832> {
+    type Output = P832;
+}
+
+impl ToInteger for ConstI<-832> {
+    type Output = N832;
+}
+
+impl ToInteger for ConstI<833> {
+    type Output = P833;
+}
+
+impl ToInteger for ConstI<-833> {
+    type Output = N833;
+}
+
+impl ToInteger for ConstI<834> {
+    type Output = P834;
+}
+
+impl ToInteger for ConstI<-834> {
+    type Output = N834;
+}
+
+impl ToInteger for ConstI<835> {
+    type Output = P835;
+}
+
+impl ToInteger for ConstI<-835> {
+    type Output = N835;
+}
+
+impl ToInteger for ConstI<836> {
+    type Output = P836;
+}
+
+impl ToInteger for ConstI<-836> {
+    type Output = N836;
+}
+
+impl ToInteger for ConstI<837> {
+    type Output = P837;
+}
+
+impl ToInteger for ConstI<-837> {
+    type Output = N837;
+}
+
+impl ToInteger for ConstI<838> {
+    type Output = P838;
+}
+
+impl ToInteger for ConstI<-838> {
+    type Output = N838;
+}
+
+impl ToInteger for ConstI<839> {
+    type Output = P839;
+}
+
+impl ToInteger for ConstI<-839> {
+    type Output = N839;
+}
+
+impl ToInteger for ConstI<840> {
+    type Output = P840;
+}
+
+impl ToInteger for ConstI<-840> {
+    type Output = N840;
+}
+
+impl ToInteger for ConstI<841> {
+    type Output = P841;
+}
+
+impl ToInteger for ConstI<-841> {
+    type Output = N841;
+}
+
+impl ToInteger for ConstI<842> {
+    type Output = P842;
+}
+
+impl ToInteger for ConstI<-842> {
+    type Output = N842;
+}
+
+impl ToInteger for ConstI<843> {
+    type Output = P843;
+}
+
+impl ToInteger for ConstI<-843> {
+    type Output = N843;
+}
+
+impl ToInteger for ConstI<844> {
+    type Output = P844;
+}
+
+impl ToInteger for ConstI<-844> {
+    type Output = N844;
+}
+
+impl ToInteger for ConstI<845> {
+    type Output = P845;
+}
+
+impl ToInteger for ConstI<-845> {
+    type Output = N845;
+}
+
+impl ToInteger for ConstI<846> {
+    type Output = P846;
+}
+
+impl ToInteger for ConstI<-846> {
+    type Output = N846;
+}
+
+impl ToInteger for ConstI<847> {
+    type Output = P847;
+}
+
+impl ToInteger for ConstI<-847> {
+    type Output = N847;
+}
+
+impl ToInteger for ConstI<848> {
+    type Output = P848;
+}
+
+impl ToInteger for ConstI<-848> {
+    type Output = N848;
+}
+
+impl ToInteger for ConstI<849> {
+    type Output = P849;
+}
+
+impl ToInteger for ConstI<-849> {
+    type Output = N849;
+}
+
+impl ToInteger for ConstI<850> {
+    type Output = P850;
+}
+
+impl ToInteger for ConstI<-850> {
+    type Output = N850;
+}
+
+impl ToInteger for ConstI<851> {
+    type Output = P851;
+}
+
+impl ToInteger for ConstI<-851> {
+    type Output = N851;
+}
+
+impl ToInteger for ConstI<852> {
+    type Output = P852;
+}
+
+impl ToInteger for ConstI<-852> {
+    type Output = N852;
+}
+
+impl ToInteger for ConstI<853> {
+    type Output = P853;
+}
+
+impl ToInteger for ConstI<-853> {
+    type Output = N853;
+}
+
+impl ToInteger for ConstI<854> {
+    type Output = P854;
+}
+
+impl ToInteger for ConstI<-854> {
+    type Output = N854;
+}
+
+impl ToInteger for ConstI<855> {
+    type Output = P855;
+}
+
+impl ToInteger for ConstI<-855> {
+    type Output = N855;
+}
+
+impl ToInteger for ConstI<856> {
+    type Output = P856;
+}
+
+impl ToInteger for ConstI<-856> {
+    type Output = N856;
+}
+
+impl ToInteger for ConstI<857> {
+    type Output = P857;
+}
+
+impl ToInteger for ConstI<-857> {
+    type Output = N857;
+}
+
+impl ToInteger for ConstI<858> {
+    type Output = P858;
+}
+
+impl ToInteger for ConstI<-858> {
+    type Output = N858;
+}
+
+impl ToInteger for ConstI<859> {
+    type Output = P859;
+}
+
+impl ToInteger for ConstI<-859> {
+    type Output = N859;
+}
+
+impl ToInteger for ConstI<860> {
+    type Output = P860;
+}
+
+impl ToInteger for ConstI<-860> {
+    type Output = N860;
+}
+
+impl ToInteger for ConstI<861> {
+    type Output = P861;
+}
+
+impl ToInteger for ConstI<-861> {
+    type Output = N861;
+}
+
+impl ToInteger for ConstI<862> {
+    type Output = P862;
+}
+
+impl ToInteger for ConstI<-862> {
+    type Output = N862;
+}
+
+impl ToInteger for ConstI<863> {
+    type Output = P863;
+}
+
+impl ToInteger for ConstI<-863> {
+    type Output = N863;
+}
+
+impl ToInteger for ConstI<864> {
+    type Output = P864;
+}
+
+impl ToInteger for ConstI<-864> {
+    type Output = N864;
+}
+
+impl ToInteger for ConstI<865> {
+    type Output = P865;
+}
+
+impl ToInteger for ConstI<-865> {
+    type Output = N865;
+}
+
+impl ToInteger for ConstI<866> {
+    type Output = P866;
+}
+
+impl ToInteger for ConstI<-866> {
+    type Output = N866;
+}
+
+impl ToInteger for ConstI<867> {
+    type Output = P867;
+}
+
+impl ToInteger for ConstI<-867> {
+    type Output = N867;
+}
+
+impl ToInteger for ConstI<868> {
+    type Output = P868;
+}
+
+impl ToInteger for ConstI<-868> {
+    type Output = N868;
+}
+
+impl ToInteger for ConstI<869> {
+    type Output = P869;
+}
+
+impl ToInteger for ConstI<-869> {
+    type Output = N869;
+}
+
+impl ToInteger for ConstI<870> {
+    type Output = P870;
+}
+
+impl ToInteger for ConstI<-870> {
+    type Output = N870;
+}
+
+impl ToInteger for ConstI<871> {
+    type Output = P871;
+}
+
+impl ToInteger for ConstI<-871> {
+    type Output = N871;
+}
+
+impl ToInteger for ConstI<872> {
+    type Output = P872;
+}
+
+impl ToInteger for ConstI<-872> {
+    type Output = N872;
+}
+
+impl ToInteger for ConstI<873> {
+    type Output = P873;
+}
+
+impl ToInteger for ConstI<-873> {
+    type Output = N873;
+}
+
+impl ToInteger for ConstI<874> {
+    type Output = P874;
+}
+
+impl ToInteger for ConstI<-874> {
+    type Output = N874;
+}
+
+impl ToInteger for ConstI<875> {
+    type Output = P875;
+}
+
+impl ToInteger for ConstI<-875> {
+    type Output = N875;
+}
+
+impl ToInteger for ConstI<876> {
+    type Output = P876;
+}
+
+impl ToInteger for ConstI<-876> {
+    type Output = N876;
+}
+
+impl ToInteger for ConstI<877> {
+    type Output = P877;
+}
+
+impl ToInteger for ConstI<-877> {
+    type Output = N877;
+}
+
+impl ToInteger for ConstI<878> {
+    type Output = P878;
+}
+
+impl ToInteger for ConstI<-878> {
+    type Output = N878;
+}
+
+impl ToInteger for ConstI<879> {
+    type Output = P879;
+}
+
+impl ToInteger for ConstI<-879> {
+    type Output = N879;
+}
+
+impl ToInteger for ConstI<880> {
+    type Output = P880;
+}
+
+impl ToInteger for ConstI<-880> {
+    type Output = N880;
+}
+
+impl ToInteger for ConstI<881> {
+    type Output = P881;
+}
+
+impl ToInteger for ConstI<-881> {
+    type Output = N881;
+}
+
+impl ToInteger for ConstI<882> {
+    type Output = P882;
+}
+
+impl ToInteger for ConstI<-882> {
+    type Output = N882;
+}
+
+impl ToInteger for ConstI<883> {
+    type Output = P883;
+}
+
+impl ToInteger for ConstI<-883> {
+    type Output = N883;
+}
+
+impl ToInteger for ConstI<884> {
+    type Output = P884;
+}
+
+impl ToInteger for ConstI<-884> {
+    type Output = N884;
+}
+
+impl ToInteger for ConstI<885> {
+    type Output = P885;
+}
+
+impl ToInteger for ConstI<-885> {
+    type Output = N885;
+}
+
+impl ToInteger for ConstI<886> {
+    type Output = P886;
+}
+
+impl ToInteger for ConstI<-886> {
+    type Output = N886;
+}
+
+impl ToInteger for ConstI<887> {
+    type Output = P887;
+}
+
+impl ToInteger for ConstI<-887> {
+    type Output = N887;
+}
+
+impl ToInteger for ConstI<888> {
+    type Output = P888;
+}
+
+impl ToInteger for ConstI<-888> {
+    type Output = N888;
+}
+
+impl ToInteger for ConstI<889> {
+    type Output = P889;
+}
+
+impl ToInteger for ConstI<-889> {
+    type Output = N889;
+}
+
+impl ToInteger for ConstI<890> {
+    type Output = P890;
+}
+
+impl ToInteger for ConstI<-890> {
+    type Output = N890;
+}
+
+impl ToInteger for ConstI<891> {
+    type Output = P891;
+}
+
+impl ToInteger for ConstI<-891> {
+    type Output = N891;
+}
+
+impl ToInteger for ConstI<892> {
+    type Output = P892;
+}
+
+impl ToInteger for ConstI<-892> {
+    type Output = N892;
+}
+
+impl ToInteger for ConstI<893> {
+    type Output = P893;
+}
+
+impl ToInteger for ConstI<-893> {
+    type Output = N893;
+}
+
+impl ToInteger for ConstI<894> {
+    type Output = P894;
+}
+
+impl ToInteger for ConstI<-894> {
+    type Output = N894;
+}
+
+impl ToInteger for ConstI<895> {
+    type Output = P895;
+}
+
+impl ToInteger for ConstI<-895> {
+    type Output = N895;
+}
+
+impl ToInteger for ConstI<896> {
+    type Output = P896;
+}
+
+impl ToInteger for ConstI<-896> {
+    type Output = N896;
+}
+
+impl ToInteger for ConstI<897> {
+    type Output = P897;
+}
+
+impl ToInteger for ConstI<-897> {
+    type Output = N897;
+}
+
+impl ToInteger for ConstI<898> {
+    type Output = P898;
+}
+
+impl ToInteger for ConstI<-898> {
+    type Output = N898;
+}
+
+impl ToInteger for ConstI<899> {
+    type Output = P899;
+}
+
+impl ToInteger for ConstI<-899> {
+    type Output = N899;
+}
+
+impl ToInteger for ConstI<900> {
+    type Output = P900;
+}
+
+impl ToInteger for ConstI<-900> {
+    type Output = N900;
+}
+
+impl ToInteger for ConstI<901> {
+    type Output = P901;
+}
+
+impl ToInteger for ConstI<-901> {
+    type Output = N901;
+}
+
+impl ToInteger for ConstI<902> {
+    type Output = P902;
+}
+
+impl ToInteger for ConstI<-902> {
+    type Output = N902;
+}
+
+impl ToInteger for ConstI<903> {
+    type Output = P903;
+}
+
+impl ToInteger for ConstI<-903> {
+    type Output = N903;
+}
+
+impl ToInteger for ConstI<904> {
+    type Output = P904;
+}
+
+impl ToInteger for ConstI<-904> {
+    type Output = N904;
+}
+
+impl ToInteger for ConstI<905> {
+    type Output = P905;
+}
+
+impl ToInteger for ConstI<-905> {
+    type Output = N905;
+}
+
+impl ToInteger for ConstI<906> {
+    type Output = P906;
+}
+
+impl ToInteger for ConstI<-906> {
+    type Output = N906;
+}
+
+impl ToInteger for ConstI<907> {
+    type Output = P907;
+}
+
+impl ToInteger for ConstI<-907> {
+    type Output = N907;
+}
+
+impl ToInteger for ConstI<908> {
+    type Output = P908;
+}
+
+impl ToInteger for ConstI<-908> {
+    type Output = N908;
+}
+
+impl ToInteger for ConstI<909> {
+    type Output = P909;
+}
+
+impl ToInteger for ConstI<-909> {
+    type Output = N909;
+}
+
+impl ToInteger for ConstI<910> {
+    type Output = P910;
+}
+
+impl ToInteger for ConstI<-910> {
+    type Output = N910;
+}
+
+impl ToInteger for ConstI<911> {
+    type Output = P911;
+}
+
+impl ToInteger for ConstI<-911> {
+    type Output = N911;
+}
+
+impl ToInteger for ConstI<912> {
+    type Output = P912;
+}
+
+impl ToInteger for ConstI<-912> {
+    type Output = N912;
+}
+
+impl ToInteger for ConstI<913> {
+    type Output = P913;
+}
+
+impl ToInteger for ConstI<-913> {
+    type Output = N913;
+}
+
+impl ToInteger for ConstI<914> {
+    type Output = P914;
+}
+
+impl ToInteger for ConstI<-914> {
+    type Output = N914;
+}
+
+impl ToInteger for ConstI<915> {
+    type Output = P915;
+}
+
+impl ToInteger for ConstI<-915> {
+    type Output = N915;
+}
+
+impl ToInteger for ConstI<916> {
+    type Output = P916;
+}
+
+impl ToInteger for ConstI<-916> {
+    type Output = N916;
+}
+
+impl ToInteger for ConstI<917> {
+    type Output = P917;
+}
+
+impl ToInteger for ConstI<-917> {
+    type Output = N917;
+}
+
+impl ToInteger for ConstI<918> {
+    type Output = P918;
+}
+
+impl ToInteger for ConstI<-918> {
+    type Output = N918;
+}
+
+impl ToInteger for ConstI<919> {
+    type Output = P919;
+}
+
+impl ToInteger for ConstI<-919> {
+    type Output = N919;
+}
+
+impl ToInteger for ConstI<920> {
+    type Output = P920;
+}
+
+impl ToInteger for ConstI<-920> {
+    type Output = N920;
+}
+
+impl ToInteger for ConstI<921> {
+    type Output = P921;
+}
+
+impl ToInteger for ConstI<-921> {
+    type Output = N921;
+}
+
+impl ToInteger for ConstI<922> {
+    type Output = P922;
+}
+
+impl ToInteger for ConstI<-922> {
+    type Output = N922;
+}
+
+impl ToInteger for ConstI<923> {
+    type Output = P923;
+}
+
+impl ToInteger for ConstI<-923> {
+    type Output = N923;
+}
+
+impl ToInteger for ConstI<924> {
+    type Output = P924;
+}
+
+impl ToInteger for ConstI<-924> {
+    type Output = N924;
+}
+
+impl ToInteger for ConstI<925> {
+    type Output = P925;
+}
+
+impl ToInteger for ConstI<-925> {
+    type Output = N925;
+}
+
+impl ToInteger for ConstI<926> {
+    type Output = P926;
+}
+
+impl ToInteger for ConstI<-926> {
+    type Output = N926;
+}
+
+impl ToInteger for ConstI<927> {
+    type Output = P927;
+}
+
+impl ToInteger for ConstI<-927> {
+    type Output = N927;
+}
+
+impl ToInteger for ConstI<928> {
+    type Output = P928;
+}
+
+impl ToInteger for ConstI<-928> {
+    type Output = N928;
+}
+
+impl ToInteger for ConstI<929> {
+    type Output = P929;
+}
+
+impl ToInteger for ConstI<-929> {
+    type Output = N929;
+}
+
+impl ToInteger for ConstI<930> {
+    type Output = P930;
+}
+
+impl ToInteger for ConstI<-930> {
+    type Output = N930;
+}
+
+impl ToInteger for ConstI<931> {
+    type Output = P931;
+}
+
+impl ToInteger for ConstI<-931> {
+    type Output = N931;
+}
+
+impl ToInteger for ConstI<932> {
+    type Output = P932;
+}
+
+impl ToInteger for ConstI<-932> {
+    type Output = N932;
+}
+
+impl ToInteger for ConstI<933> {
+    type Output = P933;
+}
+
+impl ToInteger for ConstI<-933> {
+    type Output = N933;
+}
+
+impl ToInteger for ConstI<934> {
+    type Output = P934;
+}
+
+impl ToInteger for ConstI<-934> {
+    type Output = N934;
+}
+
+impl ToInteger for ConstI<935> {
+    type Output = P935;
+}
+
+impl ToInteger for ConstI<-935> {
+    type Output = N935;
+}
+
+impl ToInteger for ConstI<936> {
+    type Output = P936;
+}
+
+impl ToInteger for ConstI<-936> {
+    type Output = N936;
+}
+
+impl ToInteger for ConstI<937> {
+    type Output = P937;
+}
+
+impl ToInteger for ConstI<-937> {
+    type Output = N937;
+}
+
+impl ToInteger for ConstI<938> {
+    type Output = P938;
+}
+
+impl ToInteger for ConstI<-938> {
+    type Output = N938;
+}
+
+impl ToInteger for ConstI<939> {
+    type Output = P939;
+}
+
+impl ToInteger for ConstI<-939> {
+    type Output = N939;
+}
+
+impl ToInteger for ConstI<940> {
+    type Output = P940;
+}
+
+impl ToInteger for ConstI<-940> {
+    type Output = N940;
+}
+
+impl ToInteger for ConstI<941> {
+    type Output = P941;
+}
+
+impl ToInteger for ConstI<-941> {
+    type Output = N941;
+}
+
+impl ToInteger for ConstI<942> {
+    type Output = P942;
+}
+
+impl ToInteger for ConstI<-942> {
+    type Output = N942;
+}
+
+impl ToInteger for ConstI<943> {
+    type Output = P943;
+}
+
+impl ToInteger for ConstI<-943> {
+    type Output = N943;
+}
+
+impl ToInteger for ConstI<944> {
+    type Output = P944;
+}
+
+impl ToInteger for ConstI<-944> {
+    type Output = N944;
+}
+
+impl ToInteger for ConstI<945> {
+    type Output = P945;
+}
+
+impl ToInteger for ConstI<-945> {
+    type Output = N945;
+}
+
+impl ToInteger for ConstI<946> {
+    type Output = P946;
+}
+
+impl ToInteger for ConstI<-946> {
+    type Output = N946;
+}
+
+impl ToInteger for ConstI<947> {
+    type Output = P947;
+}
+
+impl ToInteger for ConstI<-947> {
+    type Output = N947;
+}
+
+impl ToInteger for ConstI<948> {
+    type Output = P948;
+}
+
+impl ToInteger for ConstI<-948> {
+    type Output = N948;
+}
+
+impl ToInteger for ConstI<949> {
+    type Output = P949;
+}
+
+impl ToInteger for ConstI<-949> {
+    type Output = N949;
+}
+
+impl ToInteger for ConstI<950> {
+    type Output = P950;
+}
+
+impl ToInteger for ConstI<-950> {
+    type Output = N950;
+}
+
+impl ToInteger for ConstI<951> {
+    type Output = P951;
+}
+
+impl ToInteger for ConstI<-951> {
+    type Output = N951;
+}
+
+impl ToInteger for ConstI<952> {
+    type Output = P952;
+}
+
+impl ToInteger for ConstI<-952> {
+    type Output = N952;
+}
+
+impl ToInteger for ConstI<953> {
+    type Output = P953;
+}
+
+impl ToInteger for ConstI<-953> {
+    type Output = N953;
+}
+
+impl ToInteger for ConstI<954> {
+    type Output = P954;
+}
+
+impl ToInteger for ConstI<-954> {
+    type Output = N954;
+}
+
+impl ToInteger for ConstI<955> {
+    type Output = P955;
+}
+
+impl ToInteger for ConstI<-955> {
+    type Output = N955;
+}
+
+impl ToInteger for ConstI<956> {
+    type Output = P956;
+}
+
+impl ToInteger for ConstI<-956> {
+    type Output = N956;
+}
+
+impl ToInteger for ConstI<957> {
+    type Output = P957;
+}
+
+impl ToInteger for ConstI<-957> {
+    type Output = N957;
+}
+
+impl ToInteger for ConstI<958> {
+    type Output = P958;
+}
+
+impl ToInteger for ConstI<-958> {
+    type Output = N958;
+}
+
+impl ToInteger for ConstI<959> {
+    type Output = P959;
+}
+
+impl ToInteger for ConstI<-959> {
+    type Output = N959;
+}
+
+impl ToInteger for ConstI<960> {
+    type Output = P960;
+}
+
+impl ToInteger for ConstI<-960> {
+    type Output = N960;
+}
+
+impl ToInteger for ConstI<961> {
+    type Output = P961;
+}
+
+impl ToInteger for ConstI<-961> {
+    type Output = N961;
+}
+
+impl ToInteger for ConstI<962> {
+    type Output = P962;
+}
+
+impl ToInteger for ConstI<-962> {
+    type Output = N962;
+}
+
+impl ToInteger for ConstI<963> {
+    type Output = P963;
+}
+
+impl ToInteger for ConstI<-963> {
+    type Output = N963;
+}
+
+impl ToInteger for ConstI<964> {
+    type Output = P964;
+}
+
+impl ToInteger for ConstI<-964> {
+    type Output = N964;
+}
+
+impl ToInteger for ConstI<965> {
+    type Output = P965;
+}
+
+impl ToInteger for ConstI<-965> {
+    type Output = N965;
+}
+
+impl ToInteger for ConstI<966> {
+    type Output = P966;
+}
+
+impl ToInteger for ConstI<-966> {
+    type Output = N966;
+}
+
+impl ToInteger for ConstI<967> {
+    type Output = P967;
+}
+
+impl ToInteger for ConstI<-967> {
+    type Output = N967;
+}
+
+impl ToInteger for ConstI<968> {
+    type Output = P968;
+}
+
+impl ToInteger for ConstI<-968> {
+    type Output = N968;
+}
+
+impl ToInteger for ConstI<969> {
+    type Output = P969;
+}
+
+impl ToInteger for ConstI<-969> {
+    type Output = N969;
+}
+
+impl ToInteger for ConstI<970> {
+    type Output = P970;
+}
+
+impl ToInteger for ConstI<-970> {
+    type Output = N970;
+}
+
+impl ToInteger for ConstI<971> {
+    type Output = P971;
+}
+
+impl ToInteger for ConstI<-971> {
+    type Output = N971;
+}
+
+impl ToInteger for ConstI<972> {
+    type Output = P972;
+}
+
+impl ToInteger for ConstI<-972> {
+    type Output = N972;
+}
+
+impl ToInteger for ConstI<973> {
+    type Output = P973;
+}
+
+impl ToInteger for ConstI<-973> {
+    type Output = N973;
+}
+
+impl ToInteger for ConstI<974> {
+    type Output = P974;
+}
+
+impl ToInteger for ConstI<-974> {
+    type Output = N974;
+}
+
+impl ToInteger for ConstI<975> {
+    type Output = P975;
+}
+
+impl ToInteger for ConstI<-975> {
+    type Output = N975;
+}
+
+impl ToInteger for ConstI<976> {
+    type Output = P976;
+}
+
+impl ToInteger for ConstI<-976> {
+    type Output = N976;
+}
+
+impl ToInteger for ConstI<977> {
+    type Output = P977;
+}
+
+impl ToInteger for ConstI<-977> {
+    type Output = N977;
+}
+
+impl ToInteger for ConstI<978> {
+    type Output = P978;
+}
+
+impl ToInteger for ConstI<-978> {
+    type Output = N978;
+}
+
+impl ToInteger for ConstI<979> {
+    type Output = P979;
+}
+
+impl ToInteger for ConstI<-979> {
+    type Output = N979;
+}
+
+impl ToInteger for ConstI<980> {
+    type Output = P980;
+}
+
+impl ToInteger for ConstI<-980> {
+    type Output = N980;
+}
+
+impl ToInteger for ConstI<981> {
+    type Output = P981;
+}
+
+impl ToInteger for ConstI<-981> {
+    type Output = N981;
+}
+
+impl ToInteger for ConstI<982> {
+    type Output = P982;
+}
+
+impl ToInteger for ConstI<-982> {
+    type Output = N982;
+}
+
+impl ToInteger for ConstI<983> {
+    type Output = P983;
+}
+
+impl ToInteger for ConstI<-983> {
+    type Output = N983;
+}
+
+impl ToInteger for ConstI<984> {
+    type Output = P984;
+}
+
+impl ToInteger for ConstI<-984> {
+    type Output = N984;
+}
+
+impl ToInteger for ConstI<985> {
+    type Output = P985;
+}
+
+impl ToInteger for ConstI<-985> {
+    type Output = N985;
+}
+
+impl ToInteger for ConstI<986> {
+    type Output = P986;
+}
+
+impl ToInteger for ConstI<-986> {
+    type Output = N986;
+}
+
+impl ToInteger for ConstI<987> {
+    type Output = P987;
+}
+
+impl ToInteger for ConstI<-987> {
+    type Output = N987;
+}
+
+impl ToInteger for ConstI<988> {
+    type Output = P988;
+}
+
+impl ToInteger for ConstI<-988> {
+    type Output = N988;
+}
+
+impl ToInteger for ConstI<989> {
+    type Output = P989;
+}
+
+impl ToInteger for ConstI<-989> {
+    type Output = N989;
+}
+
+impl ToInteger for ConstI<990> {
+    type Output = P990;
+}
+
+impl ToInteger for ConstI<-990> {
+    type Output = N990;
+}
+
+impl ToInteger for ConstI<991> {
+    type Output = P991;
+}
+
+impl ToInteger for ConstI<-991> {
+    type Output = N991;
+}
+
+impl ToInteger for ConstI<992> {
+    type Output = P992;
+}
+
+impl ToInteger for ConstI<-992> {
+    type Output = N992;
+}
+
+impl ToInteger for ConstI<993> {
+    type Output = P993;
+}
+
+impl ToInteger for ConstI<-993> {
+    type Output = N993;
+}
+
+impl ToInteger for ConstI<994> {
+    type Output = P994;
+}
+
+impl ToInteger for ConstI<-994> {
+    type Output = N994;
+}
+
+impl ToInteger for ConstI<995> {
+    type Output = P995;
+}
+
+impl ToInteger for ConstI<-995> {
+    type Output = N995;
+}
+
+impl ToInteger for ConstI<996> {
+    type Output = P996;
+}
+
+impl ToInteger for ConstI<-996> {
+    type Output = N996;
+}
+
+impl ToInteger for ConstI<997> {
+    type Output = P997;
+}
+
+impl ToInteger for ConstI<-997> {
+    type Output = N997;
+}
+
+impl ToInteger for ConstI<998> {
+    type Output = P998;
+}
+
+impl ToInteger for ConstI<-998> {
+    type Output = N998;
+}
+
+impl ToInteger for ConstI<999> {
+    type Output = P999;
+}
+
+impl ToInteger for ConstI<-999> {
+    type Output = N999;
+}
+
+impl ToInteger for ConstI<1000> {
+    type Output = P1000;
+}
+
+impl ToInteger for ConstI<-1000> {
+    type Output = N1000;
+}
+
+impl ToInteger for ConstI<1001> {
+    type Output = P1001;
+}
+
+impl ToInteger for ConstI<-1001> {
+    type Output = N1001;
+}
+
+impl ToInteger for ConstI<1002> {
+    type Output = P1002;
+}
+
+impl ToInteger for ConstI<-1002> {
+    type Output = N1002;
+}
+
+impl ToInteger for ConstI<1003> {
+    type Output = P1003;
+}
+
+impl ToInteger for ConstI<-1003> {
+    type Output = N1003;
+}
+
+impl ToInteger for ConstI<1004> {
+    type Output = P1004;
+}
+
+impl ToInteger for ConstI<-1004> {
+    type Output = N1004;
+}
+
+impl ToInteger for ConstI<1005> {
+    type Output = P1005;
+}
+
+impl ToInteger for ConstI<-1005> {
+    type Output = N1005;
+}
+
+impl ToInteger for ConstI<1006> {
+    type Output = P1006;
+}
+
+impl ToInteger for ConstI<-1006> {
+    type Output = N1006;
+}
+
+impl ToInteger for ConstI<1007> {
+    type Output = P1007;
+}
+
+impl ToInteger for ConstI<-1007> {
+    type Output = N1007;
+}
+
+impl ToInteger for ConstI<1008> {
+    type Output = P1008;
+}
+
+impl ToInteger for ConstI<-1008> {
+    type Output = N1008;
+}
+
+impl ToInteger for ConstI<1009> {
+    type Output = P1009;
+}
+
+impl ToInteger for ConstI<-1009> {
+    type Output = N1009;
+}
+
+impl ToInteger for ConstI<1010> {
+    type Output = P1010;
+}
+
+impl ToInteger for ConstI<-1010> {
+    type Output = N1010;
+}
+
+impl ToInteger for ConstI<1011> {
+    type Output = P1011;
+}
+
+impl ToInteger for ConstI<-1011> {
+    type Output = N1011;
+}
+
+impl ToInteger for ConstI<1012> {
+    type Output = P1012;
+}
+
+impl ToInteger for ConstI<-1012> {
+    type Output = N1012;
+}
+
+impl ToInteger for ConstI<1013> {
+    type Output = P1013;
+}
+
+impl ToInteger for ConstI<-1013> {
+    type Output = N1013;
+}
+
+impl ToInteger for ConstI<1014> {
+    type Output = P1014;
+}
+
+impl ToInteger for ConstI<-1014> {
+    type Output = N1014;
+}
+
+impl ToInteger for ConstI<1015> {
+    type Output = P1015;
+}
+
+impl ToInteger for ConstI<-1015> {
+    type Output = N1015;
+}
+
+impl ToInteger for ConstI<1016> {
+    type Output = P1016;
+}
+
+impl ToInteger for ConstI<-1016> {
+    type Output = N1016;
+}
+
+impl ToInteger for ConstI<1017> {
+    type Output = P1017;
+}
+
+impl ToInteger for ConstI<-1017> {
+    type Output = N1017;
+}
+
+impl ToInteger for ConstI<1018> {
+    type Output = P1018;
+}
+
+impl ToInteger for ConstI<-1018> {
+    type Output = N1018;
+}
+
+impl ToInteger for ConstI<1019> {
+    type Output = P1019;
+}
+
+impl ToInteger for ConstI<-1019> {
+    type Output = N1019;
+}
+
+impl ToInteger for ConstI<1020> {
+    type Output = P1020;
+}
+
+impl ToInteger for ConstI<-1020> {
+    type Output = N1020;
+}
+
+impl ToInteger for ConstI<1021> {
+    type Output = P1021;
+}
+
+impl ToInteger for ConstI<-1021> {
+    type Output = N1021;
+}
+
+impl ToInteger for ConstI<1022> {
+    type Output = P1022;
+}
+
+impl ToInteger for ConstI<-1022> {
+    type Output = N1022;
+}
+
+impl ToInteger for ConstI<1023> {
+    type Output = P1023;
+}
+
+impl ToInteger for ConstI<-1023> {
+    type Output = N1023;
+}
+
+impl ToInteger for ConstI<1024> {
+    type Output = P1024;
+}
+
+impl ToInteger for ConstI<-1024> {
+    type Output = N1024;
+}
+
+impl ToInteger for ConstI<3600> {
+    type Output = P3600;
+}
+
+impl ToInteger for ConstI<-3600> {
+    type Output = N3600;
+}
+
+impl ToInteger for ConstI<2047> {
+    type Output = P2047;
+}
+
+impl ToInteger for ConstI<-2047> {
+    type Output = N2047;
+}
+
+impl ToInteger for ConstI<2048> {
+    type Output = P2048;
+}
+
+impl ToInteger for ConstI<-2048> {
+    type Output = N2048;
+}
+
+impl ToInteger for ConstI<4095> {
+    type Output = P4095;
+}
+
+impl ToInteger for ConstI<-4095> {
+    type Output = N4095;
+}
+
+impl ToInteger for ConstI<4096> {
+    type Output = P4096;
+}
+
+impl ToInteger for ConstI<-4096> {
+    type Output = N4096;
+}
+
+impl ToInteger for ConstI<8191> {
+    type Output = P8191;
+}
+
+impl ToInteger for ConstI<-8191> {
+    type Output = N8191;
+}
+
+impl ToInteger for ConstI<8192> {
+    type Output = P8192;
+}
+
+impl ToInteger for ConstI<-8192> {
+    type Output = N8192;
+}
+
+impl ToInteger for ConstI<16383> {
+    type Output = P16383;
+}
+
+impl ToInteger for ConstI<-16383> {
+    type Output = N16383;
+}
+
+impl ToInteger for ConstI<16384> {
+    type Output = P16384;
+}
+
+impl ToInteger for ConstI<-16384> {
+    type Output = N16384;
+}
+
+impl ToInteger for ConstI<32767> {
+    type Output = P32767;
+}
+
+impl ToInteger for ConstI<-32767> {
+    type Output = N32767;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<32768> {
+    type Output = P32768;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-32768> {
+    type Output = N32768;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<65535> {
+    type Output = P65535;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-65535> {
+    type Output = N65535;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<65536> {
+    type Output = P65536;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-65536> {
+    type Output = N65536;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<131071> {
+    type Output = P131071;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-131071> {
+    type Output = N131071;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<131072> {
+    type Output = P131072;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-131072> {
+    type Output = N131072;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<262143> {
+    type Output = P262143;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-262143> {
+    type Output = N262143;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<262144> {
+    type Output = P262144;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-262144> {
+    type Output = N262144;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<524287> {
+    type Output = P524287;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-524287> {
+    type Output = N524287;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<524288> {
+    type Output = P524288;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-524288> {
+    type Output = N524288;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<1048575> {
+    type Output = P1048575;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-1048575> {
+    type Output = N1048575;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<1048576> {
+    type Output = P1048576;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-1048576> {
+    type Output = N1048576;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<2097151> {
+    type Output = P2097151;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-2097151> {
+    type Output = N2097151;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<2097152> {
+    type Output = P2097152;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-2097152> {
+    type Output = N2097152;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<4194303> {
+    type Output = P4194303;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-4194303> {
+    type Output = N4194303;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<4194304> {
+    type Output = P4194304;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-4194304> {
+    type Output = N4194304;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<8388607> {
+    type Output = P8388607;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-8388607> {
+    type Output = N8388607;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<8388608> {
+    type Output = P8388608;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-8388608> {
+    type Output = N8388608;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<16777215> {
+    type Output = P16777215;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-16777215> {
+    type Output = N16777215;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<16777216> {
+    type Output = P16777216;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-16777216> {
+    type Output = N16777216;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<33554431> {
+    type Output = P33554431;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-33554431> {
+    type Output = N33554431;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<33554432> {
+    type Output = P33554432;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-33554432> {
+    type Output = N33554432;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<67108863> {
+    type Output = P67108863;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-67108863> {
+    type Output = N67108863;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<67108864> {
+    type Output = P67108864;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-67108864> {
+    type Output = N67108864;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<134217727> {
+    type Output = P134217727;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-134217727> {
+    type Output = N134217727;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<134217728> {
+    type Output = P134217728;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-134217728> {
+    type Output = N134217728;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<268435455> {
+    type Output = P268435455;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-268435455> {
+    type Output = N268435455;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<268435456> {
+    type Output = P268435456;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-268435456> {
+    type Output = N268435456;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<536870911> {
+    type Output = P536870911;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-536870911> {
+    type Output = N536870911;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<536870912> {
+    type Output = P536870912;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-536870912> {
+    type Output = N536870912;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<1073741823> {
+    type Output = P1073741823;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-1073741823> {
+    type Output = N1073741823;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<1073741824> {
+    type Output = P1073741824;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-1073741824> {
+    type Output = N1073741824;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<2147483647> {
+    type Output = P2147483647;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-2147483647> {
+    type Output = N2147483647;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<2147483648> {
+    type Output = P2147483648;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-2147483648> {
+    type Output = N2147483648;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<4294967295> {
+    type Output = P4294967295;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-4294967295> {
+    type Output = N4294967295;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<4294967296> {
+    type Output = P4294967296;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-4294967296> {
+    type Output = N4294967296;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<8589934591> {
+    type Output = P8589934591;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-8589934591> {
+    type Output = N8589934591;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<8589934592> {
+    type Output = P8589934592;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-8589934592> {
+    type Output = N8589934592;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<17179869183> {
+    type Output = P17179869183;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-17179869183> {
+    type Output = N17179869183;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<17179869184> {
+    type Output = P17179869184;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-17179869184> {
+    type Output = N17179869184;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<34359738367> {
+    type Output = P34359738367;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-34359738367> {
+    type Output = N34359738367;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<34359738368> {
+    type Output = P34359738368;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-34359738368> {
+    type Output = N34359738368;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<68719476735> {
+    type Output = P68719476735;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-68719476735> {
+    type Output = N68719476735;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<68719476736> {
+    type Output = P68719476736;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-68719476736> {
+    type Output = N68719476736;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<137438953471> {
+    type Output = P137438953471;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-137438953471> {
+    type Output = N137438953471;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<137438953472> {
+    type Output = P137438953472;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-137438953472> {
+    type Output = N137438953472;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<274877906943> {
+    type Output = P274877906943;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-274877906943> {
+    type Output = N274877906943;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<274877906944> {
+    type Output = P274877906944;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-274877906944> {
+    type Output = N274877906944;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<549755813887> {
+    type Output = P549755813887;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-549755813887> {
+    type Output = N549755813887;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<549755813888> {
+    type Output = P549755813888;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-549755813888> {
+    type Output = N549755813888;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1099511627775> {
+    type Output = P1099511627775;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1099511627775> {
+    type Output = N1099511627775;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1099511627776> {
+    type Output = P1099511627776;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1099511627776> {
+    type Output = N1099511627776;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<2199023255551> {
+    type Output = P2199023255551;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-2199023255551> {
+    type Output = N2199023255551;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<2199023255552> {
+    type Output = P2199023255552;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-2199023255552> {
+    type Output = N2199023255552;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<4398046511103> {
+    type Output = P4398046511103;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-4398046511103> {
+    type Output = N4398046511103;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<4398046511104> {
+    type Output = P4398046511104;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-4398046511104> {
+    type Output = N4398046511104;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<8796093022207> {
+    type Output = P8796093022207;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-8796093022207> {
+    type Output = N8796093022207;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<8796093022208> {
+    type Output = P8796093022208;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-8796093022208> {
+    type Output = N8796093022208;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<17592186044415> {
+    type Output = P17592186044415;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-17592186044415> {
+    type Output = N17592186044415;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<17592186044416> {
+    type Output = P17592186044416;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-17592186044416> {
+    type Output = N17592186044416;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<35184372088831> {
+    type Output = P35184372088831;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-35184372088831> {
+    type Output = N35184372088831;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<35184372088832> {
+    type Output = P35184372088832;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-35184372088832> {
+    type Output = N35184372088832;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<70368744177663> {
+    type Output = P70368744177663;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-70368744177663> {
+    type Output = N70368744177663;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<70368744177664> {
+    type Output = P70368744177664;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-70368744177664> {
+    type Output = N70368744177664;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<140737488355327> {
+    type Output = P140737488355327;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-140737488355327> {
+    type Output = N140737488355327;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<140737488355328> {
+    type Output = P140737488355328;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-140737488355328> {
+    type Output = N140737488355328;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<281474976710655> {
+    type Output = P281474976710655;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-281474976710655> {
+    type Output = N281474976710655;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<281474976710656> {
+    type Output = P281474976710656;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-281474976710656> {
+    type Output = N281474976710656;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<562949953421311> {
+    type Output = P562949953421311;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-562949953421311> {
+    type Output = N562949953421311;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<562949953421312> {
+    type Output = P562949953421312;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-562949953421312> {
+    type Output = N562949953421312;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1125899906842623> {
+    type Output = P1125899906842623;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1125899906842623> {
+    type Output = N1125899906842623;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1125899906842624> {
+    type Output = P1125899906842624;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1125899906842624> {
+    type Output = N1125899906842624;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<2251799813685247> {
+    type Output = P2251799813685247;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-2251799813685247> {
+    type Output = N2251799813685247;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<2251799813685248> {
+    type Output = P2251799813685248;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-2251799813685248> {
+    type Output = N2251799813685248;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<4503599627370495> {
+    type Output = P4503599627370495;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-4503599627370495> {
+    type Output = N4503599627370495;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<4503599627370496> {
+    type Output = P4503599627370496;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-4503599627370496> {
+    type Output = N4503599627370496;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<9007199254740991> {
+    type Output = P9007199254740991;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-9007199254740991> {
+    type Output = N9007199254740991;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<9007199254740992> {
+    type Output = P9007199254740992;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-9007199254740992> {
+    type Output = N9007199254740992;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<18014398509481983> {
+    type Output = P18014398509481983;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-18014398509481983> {
+    type Output = N18014398509481983;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<18014398509481984> {
+    type Output = P18014398509481984;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-18014398509481984> {
+    type Output = N18014398509481984;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<36028797018963967> {
+    type Output = P36028797018963967;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-36028797018963967> {
+    type Output = N36028797018963967;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<36028797018963968> {
+    type Output = P36028797018963968;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-36028797018963968> {
+    type Output = N36028797018963968;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<72057594037927935> {
+    type Output = P72057594037927935;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-72057594037927935> {
+    type Output = N72057594037927935;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<72057594037927936> {
+    type Output = P72057594037927936;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-72057594037927936> {
+    type Output = N72057594037927936;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<144115188075855871> {
+    type Output = P144115188075855871;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-144115188075855871> {
+    type Output = N144115188075855871;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<144115188075855872> {
+    type Output = P144115188075855872;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-144115188075855872> {
+    type Output = N144115188075855872;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<288230376151711743> {
+    type Output = P288230376151711743;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-288230376151711743> {
+    type Output = N288230376151711743;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<288230376151711744> {
+    type Output = P288230376151711744;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-288230376151711744> {
+    type Output = N288230376151711744;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<576460752303423487> {
+    type Output = P576460752303423487;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-576460752303423487> {
+    type Output = N576460752303423487;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<576460752303423488> {
+    type Output = P576460752303423488;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-576460752303423488> {
+    type Output = N576460752303423488;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1152921504606846975> {
+    type Output = P1152921504606846975;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1152921504606846975> {
+    type Output = N1152921504606846975;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1152921504606846976> {
+    type Output = P1152921504606846976;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1152921504606846976> {
+    type Output = N1152921504606846976;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<2305843009213693951> {
+    type Output = P2305843009213693951;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-2305843009213693951> {
+    type Output = N2305843009213693951;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<2305843009213693952> {
+    type Output = P2305843009213693952;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-2305843009213693952> {
+    type Output = N2305843009213693952;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<4611686018427387903> {
+    type Output = P4611686018427387903;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-4611686018427387903> {
+    type Output = N4611686018427387903;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<4611686018427387904> {
+    type Output = P4611686018427387904;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-4611686018427387904> {
+    type Output = N4611686018427387904;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<9223372036854775807> {
+    type Output = P9223372036854775807;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-9223372036854775807> {
+    type Output = N9223372036854775807;
+}
+
+impl ToInteger for ConstI<10000> {
+    type Output = P10000;
+}
+
+impl ToInteger for ConstI<-10000> {
+    type Output = N10000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<100000> {
+    type Output = P100000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-100000> {
+    type Output = N100000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<1000000> {
+    type Output = P1000000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-1000000> {
+    type Output = N1000000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<10000000> {
+    type Output = P10000000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-10000000> {
+    type Output = N10000000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<100000000> {
+    type Output = P100000000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-100000000> {
+    type Output = N100000000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<1000000000> {
+    type Output = P1000000000;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ToInteger for ConstI<-1000000000> {
+    type Output = N1000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<10000000000> {
+    type Output = P10000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-10000000000> {
+    type Output = N10000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<100000000000> {
+    type Output = P100000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-100000000000> {
+    type Output = N100000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1000000000000> {
+    type Output = P1000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1000000000000> {
+    type Output = N1000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<10000000000000> {
+    type Output = P10000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-10000000000000> {
+    type Output = N10000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<100000000000000> {
+    type Output = P100000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-100000000000000> {
+    type Output = N100000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1000000000000000> {
+    type Output = P1000000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1000000000000000> {
+    type Output = N1000000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<10000000000000000> {
+    type Output = P10000000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-10000000000000000> {
+    type Output = N10000000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<100000000000000000> {
+    type Output = P100000000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-100000000000000000> {
+    type Output = N100000000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<1000000000000000000> {
+    type Output = P1000000000000000000;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ToInteger for ConstI<-1000000000000000000> {
+    type Output = N1000000000000000000;
+}
@@ -2,10 +2,16 @@
 /**
 Convenient type operations.
 
-Any types representing values must be able to be expressed as `ident`s. That means they need to be
-in scope.
+Operands may be any type path, such as `P5`, `typenum::P5`, `T::Output`, or
+`<A as Trait>::Output`, or a macro invocation such as `tarr![P1, P2]`. As in Rust expressions,
+generic arguments need a turbofish, so write `Sum::<A, B>` rather than `Sum<A, B>`, where the `<`
+would be a comparison. Any other type can be written in braces, such as `{ Sum<A, B> }`.
 
-For example, `P5` is okay, but `typenum::P5` is not.
+Integer literals are operands as well, and expand to the constant with that value. A literal
+without a sign is unsigned, and one with a sign is signed, so `3` is `U3`, `+3` is `P3`, and `-3` is
+`N3`. With the `macros` feature, literals are read by `tn!`, so they may be of any size and in any
+base, such as `0x10` or `1_000`. Without it, they must be written in decimal, without a suffix,
+and have a constant in `consts`.
 
 You may combine operators arbitrarily, although doing so excessively may require raising the
 recursion limit.
@@ -20,18 +26,75 @@ fn main() {
     assert_type!(
         op!(min((P1 - P2) * (N3 + N7), P5 * (P3 + P4)) == P10)
     );
+    assert_type!(
+        op!(-P2 * P3 == N6 && !(P2 > P3) || get_bit(U4, U0))
+    );
+}
+```
+
+Operands can be paths, literals, and types in braces:
+```rust
+use core::ops::Add;
+use typenum::{assert_type_eq, op, tarr, Sum, Unsigned, P1, P2, U1, U3, U8};
+
+trait Shape {
+    type Sides: Unsigned;
+}
+
+struct Square;
+
+impl Shape for Square {
+    type Sides = typenum::U4;
+}
+
+assert_type_eq!(op!(<Square as Shape>::Sides * 2), U8);
+assert_type_eq!(op!(typenum::consts::U2 + len(tarr![P1, P2])), typenum::U4);
+assert_type_eq!(op!(-3 + +4), P1);
+assert_type_eq!(op!(Sum::<U1, U1> == { Sum<U1, U1> }), typenum::True);
+
+fn incremented<T: Add<U1>>() -> usize
+where
+    op!(T + 1): Unsigned,
+{
+    <op!(T::Output)>::USIZE
 }
+
+assert_eq!(incremented::<U3>(), 4);
 ```
 Operators are evaluated based on the operator precedence outlined
-[here](https://doc.rust-lang.org/reference.html#operator-precedence).
+[here](https://doc.rust-lang.org/reference.html#operator-precedence). As in Rust, `-` and `!`
+before an operand are the prefix operators `Neg` and `Not`, and `&&` and `||` bind less tightly
+than comparisons.
 
 The full list of supported operators and functions is as follows:
 
-`*`, `/`, `%`, `+`, `-`, `<<`, `>>`, `&`, `^`, `|`, `==`, `!=`, `<=`, `>=`, `<`, `>`, `cmp`, `sqr`, `sqrt`, `abs`, `cube`, `pow`, `min`, `max`, `log2`, `gcd`
+prefix `!`, prefix `-`, `*`, `/`, `%`, `+`, `-`, `<<`, `>>`, `&`, `^`, `|`, `==`, `!=`, `<=`, `>=`, `<`, `>`, `&&`, `||`, `cmp`, `sqr`, `sqrt`, `abs`, `cube`, `pow`, `min`, `max`, `log2`, `log`, `log10`, `ceil_log2`, `root`, `cbrt`, `is_power_of_two`, `next_power_of_two`, `prev_power_of_two`, `count_ones`, `count_zeros`, `trailing_zeros`, `leading_zeros`, `reverse_bits`, `gcd`, `add_mod`, `mul_mod`, `pow_mod`, `mod_inverse`, `is_prime`, `smallest_factor`, `factorize`, `lcm`, `is_coprime`, `div_floor`, `div_ceil`, `div_euclid`, `rem_euclid`, `if`, `add1`, `sub1`, `double`, `partial_div`, `get_bit`, `set_bit`, `checked_add`, `wrapping_add`, `saturating_sub`, `implies`, `len`, `fold_add`, `fold_mul`, `fold_max`, `fold_min`, `all`, `any`, `at`, `push`, `pop`, `last`, `concat`, `reverse`, `take`, `skip`, `contains`, `dot`, `sort`, `sort_desc`, `dedup`, `is_sorted`, `apply`, `map`, `zip_with`, `filter`, `fold`
 
 They all expand to type aliases defined in the `operator_aliases` module. Here is an expanded list,
 including examples:
 
+---
+Prefix operator `!`. Expands to `Complement`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(!True), False);
+# }
+```
+
+---
+Prefix operator `-`. Expands to `Negate`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(-P3), N3);
+# }
+```
+
 ---
 Operator `*`. Expands to `Prod`.
 
@@ -208,6 +271,28 @@ assert_type_eq!(op!(P5 < P3 + P2), False);
 # }
 ```
 
+---
+Operator `&&`. Expands to `And`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(P1 < P2 && P3 < P2), False);
+# }
+```
+
+---
+Operator `||`. Expands to `Or`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(P1 < P2 || P3 < P2), True);
+# }
+```
+
 ---
 Operator `cmp`. Expands to `Compare`.
 
@@ -307,6 +392,149 @@ assert_type_eq!(op!(log2(U9)), U3);
 # }
 ```
 
+---
+Operator `log`. Expands to `Log`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(log(U10, U1000)), U3);
+# }
+```
+
+---
+Operator `log10`. Expands to `Log10`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(log10(U999)), U2);
+# }
+```
+
+---
+Operator `ceil_log2`. Expands to `CeilLog2`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(ceil_log2(U5)), U3);
+# }
+```
+
+---
+Operator `root`. Expands to `Root`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(root(U3, U64)), U4);
+# }
+```
+
+---
+Operator `cbrt`. Expands to `Cbrt`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(cbrt(U26)), U2);
+# }
+```
+
+---
+Operator `is_power_of_two`. Expands to `IsPow2`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(is_power_of_two(U64)), B1);
+# }
+```
+
+---
+Operator `next_power_of_two`. Expands to `NextPow2`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(next_power_of_two(U1000)), U1024);
+# }
+```
+
+---
+Operator `prev_power_of_two`. Expands to `PrevPow2`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(prev_power_of_two(U1000)), U512);
+# }
+```
+
+---
+Operator `count_ones`. Expands to `Ones`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(count_ones(U255)), U8);
+# }
+```
+
+---
+Operator `count_zeros`. Expands to `Zeros`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(count_zeros(U1, U8)), U7);
+# }
+```
+
+---
+Operator `trailing_zeros`. Expands to `TrailZeros`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(trailing_zeros(U24)), U3);
+# }
+```
+
+---
+Operator `leading_zeros`. Expands to `LeadZeros`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(leading_zeros(U1, U32)), U31);
+# }
+```
+
+---
+Operator `reverse_bits`. Expands to `BitsReversed`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(reverse_bits(U1, U8)), U128);
+# }
+```
+
 ---
 Operator `gcd`. Expands to `Gcf`.
 
@@ -318,713 +546,5912 @@ assert_type_eq!(op!(gcd(U9, U21)), U3);
 # }
 ```
 
-*/
-#[macro_export(local_inner_macros)]
-macro_rules! op {
-    ($($tail:tt)*) => ( __op_internal__!($($tail)*) );
-}
+---
+Operator `add_mod`. Expands to `SumMod`.
 
-#[doc(hidden)]
-#[macro_export(local_inner_macros)]
-macro_rules! __op_internal__ {
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(add_mod(U5, U4, U7)), U2);
+# }
+```
 
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: cmp $($tail:tt)*) => (
-    __op_internal__!(@stack[Compare, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+---
+Operator `mul_mod`. Expands to `ProdMod`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(mul_mod(U5, U4, U7)), U6);
+# }
+```
+
+---
+Operator `pow_mod`. Expands to `ExpMod`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(pow_mod(U2, U10, U1000)), U24);
+# }
+```
+
+---
+Operator `mod_inverse`. Expands to `InvMod`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(mod_inverse(U3, U7)), U5);
+# }
+```
+
+---
+Operator `is_prime`. Expands to `Primality`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(is_prime(U7)), B1);
+# }
+```
+
+---
+Operator `smallest_factor`. Expands to `LeastFactor`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(smallest_factor(U91)), U7);
+# }
+```
+
+---
+Operator `factorize`. Expands to `Factors`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(factorize(U12)), tarr![U2, U2, U3]);
+# }
+```
+
+---
+Operator `lcm`. Expands to `Scm`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(lcm(U4, U6)), U12);
+# }
+```
+
+---
+Operator `is_coprime`. Expands to `Coprimality`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(is_coprime(U35, U64)), B1);
+# }
+```
+
+---
+Operator `div_floor`. Expands to `QuotFloor`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(div_floor(N7, P2)), N4);
+# }
+```
+
+---
+Operator `div_ceil`. Expands to `QuotCeil`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(div_ceil(U40, U16)), U3);
+# }
+```
+
+---
+Operator `div_euclid`. Expands to `QuotEuclid`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(div_euclid(N7, P2)), N4);
+# }
+```
+
+---
+Operator `rem_euclid`. Expands to `ModEuclid`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(rem_euclid(N7, P2)), P1);
+# }
+```
+
+---
+Operator `if`. Expands to `If`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(if(P2 < P3, U1, U2)), U1);
+# }
+```
+
+---
+Operator `add1`. Expands to `Add1`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(add1(U4)), U5);
+# }
+```
+
+---
+Operator `sub1`. Expands to `Sub1`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(sub1(U4)), U3);
+# }
+```
+
+---
+Operator `double`. Expands to `Double`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(double(U4)), U8);
+# }
+```
+
+---
+Operator `partial_div`. Expands to `PartialQuot`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(partial_div(U6, U2)), U3);
+# }
+```
+
+---
+Operator `get_bit`. Expands to `GetBitOut`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(get_bit(U5, U2)), True);
+# }
+```
+
+---
+Operator `set_bit`. Expands to `SetBitOut`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(set_bit(U5, U1, True)), U7);
+# }
+```
+
+---
+Operator `checked_add`. Expands to `CheckedSum`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(checked_add(U200, U55, U8)), U255);
+# }
+```
+
+---
+Operator `wrapping_add`. Expands to `WrappingSum`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(wrapping_add(U200, U57, U8)), U1);
+# }
+```
+
+---
+Operator `saturating_sub`. Expands to `SaturatingDiff`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(saturating_sub(U3, U5)), U0);
+# }
+```
+
+---
+Operator `implies`. Expands to `Imply`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+assert_type_eq!(op!(implies(True, False)), False);
+# }
+```
+
+---
+Operator `len`. Expands to `Length`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(len(A)), U3);
+# }
+```
+
+---
+Operator `fold_add`. Expands to `FoldSum`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(fold_add(A)), P2);
+# }
+```
+
+---
+Operator `fold_mul`. Expands to `FoldProd`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(fold_mul(A)), N6);
+# }
+```
+
+---
+Operator `fold_max`. Expands to `FoldMaximum`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(fold_max(A)), P3);
+# }
+```
+
+---
+Operator `fold_min`. Expands to `FoldMinimum`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(fold_min(A)), N2);
+# }
+```
+
+---
+Operator `all`. Expands to `FoldAll`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![True, False, True];
+assert_type_eq!(op!(all(A)), False);
+# }
+```
+
+---
+Operator `any`. Expands to `FoldAny`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![True, False, True];
+assert_type_eq!(op!(any(A)), True);
+# }
+```
+
+---
+Operator `at`. Expands to `At`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(at(A, U1)), N2);
+# }
+```
+
+---
+Operator `push`. Expands to `Pushed`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(push(A, P4)), tarr![P1, N2, P3, P4]);
+# }
+```
+
+---
+Operator `pop`. Expands to `Popped`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(pop(A)), tarr![P1, N2]);
+# }
+```
+
+---
+Operator `last`. Expands to `Last`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(last(A)), P3);
+# }
+```
+
+---
+Operator `concat`. Expands to `Concatenated`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2];
+type B = tarr![P3, P4];
+assert_type_eq!(op!(concat(A, B)), tarr![P1, N2, P3, P4]);
+# }
+```
+
+---
+Operator `reverse`. Expands to `Reversed`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(reverse(A)), tarr![P3, N2, P1]);
+# }
+```
+
+---
+Operator `take`. Expands to `Taken`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(take(A, U2)), tarr![P1, N2]);
+# }
+```
+
+---
+Operator `skip`. Expands to `Skipped`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(skip(A, U2)), tarr![P3]);
+# }
+```
+
+---
+Operator `contains`. Expands to `Contained`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(contains(A, N2)), True);
+# }
+```
+
+---
+Operator `dot`. Expands to `Dot`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2];
+type B = tarr![P3, P4];
+assert_type_eq!(op!(dot(A, B)), N5);
+# }
+```
+
+---
+Operator `sort`. Expands to `Sorted`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(sort(A)), tarr![N2, P1, P3]);
+# }
+```
+
+---
+Operator `sort_desc`. Expands to `SortedDesc`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(sort_desc(A)), tarr![P3, P1, N2]);
+# }
+```
+
+---
+Operator `dedup`. Expands to `Deduped`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, P1, P3, P3];
+assert_type_eq!(op!(dedup(A)), tarr![P1, P3]);
+# }
+```
+
+---
+Operator `is_sorted`. Expands to `InOrder`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(is_sorted(A)), False);
+# }
+```
+
+---
+Operator `apply`. Expands to `Apply`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+struct Twice;
+impl<X: core::ops::Mul<P2>> TypeFn<X> for Twice {
+    type Output = Prod<X, P2>;
+}
+assert_type_eq!(op!(apply(Twice, P3)), P6);
+# }
+```
+
+---
+Operator `map`. Expands to `Mapped`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+struct Twice;
+impl<X: core::ops::Mul<P2>> TypeFn<X> for Twice {
+    type Output = Prod<X, P2>;
+}
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(map(A, Twice)), tarr![P2, N4, P6]);
+# }
+```
+
+---
+Operator `zip_with`. Expands to `ZippedWith`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+struct Minus;
+impl<L: core::ops::Sub<R>, R> TypeFn<(L, R)> for Minus {
+    type Output = Diff<L, R>;
+}
+type A = tarr![P5, P2];
+type B = tarr![P2, P3];
+assert_type_eq!(op!(zip_with(A, Minus, B)), tarr![P3, N1]);
+# }
+```
+
+---
+Operator `filter`. Expands to `Filtered`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+struct Positive;
+impl<X: IsGreater<Z0>> TypeFn<X> for Positive {
+    type Output = Gr<X, Z0>;
+}
+type A = tarr![P1, N2, Z0, P3];
+assert_type_eq!(op!(filter(A, Positive)), tarr![P1, P3]);
+# }
+```
+
+---
+Operator `fold`. Expands to `Folded`.
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {
+struct Minus;
+impl<L: core::ops::Sub<R>, R> TypeFn<(L, R)> for Minus {
+    type Output = Diff<L, R>;
+}
+type A = tarr![P1, N2, P3];
+assert_type_eq!(op!(fold(A, Minus, Z0)), N2);
+# }
+```
+
+*/
+#[macro_export(local_inner_macros)]
+macro_rules! op {
+    ($($tail:tt)*) => ( __op_internal__!($($tail)*) );
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! __op_internal__ {
+
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $literal:tt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*]
+                     @queue[{ $crate::__op_operand__!(+ $literal) }, $($queue,)*]
+                     @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ! $($tail:tt)*) => (
+    __op_internal__!(@prefix[Complement !] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
+    __op_internal__!(@prefix[Negate -] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: cmp $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: cmp $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sqr $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: sqr $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sqrt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: sqrt $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: abs $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: abs $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: cube $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: cube $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: pow $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: pow $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: min $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: min $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: max $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: max $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: log2 $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: log2 $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: log $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: log $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: log10 $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: log10 $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ceil_log2 $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: ceil_log2 $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: root $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: root $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: cbrt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: cbrt $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: is_power_of_two $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: is_power_of_two $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: next_power_of_two $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: next_power_of_two $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: prev_power_of_two $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: prev_power_of_two $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: count_ones $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: count_ones $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: count_zeros $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: count_zeros $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: trailing_zeros $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: trailing_zeros $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: leading_zeros $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: leading_zeros $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: reverse_bits $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: reverse_bits $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: gcd $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: gcd $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: add_mod $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: add_mod $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: mul_mod $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: mul_mod $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: pow_mod $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: pow_mod $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: mod_inverse $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: mod_inverse $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: is_prime $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: is_prime $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: smallest_factor $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: smallest_factor $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: factorize $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: factorize $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: lcm $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: lcm $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: is_coprime $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: is_coprime $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: div_floor $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: div_floor $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: div_ceil $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: div_ceil $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: div_euclid $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: div_euclid $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: rem_euclid $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: rem_euclid $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: if $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: if $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: add1 $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: add1 $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sub1 $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: sub1 $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: double $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: double $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: partial_div $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: partial_div $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: get_bit $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: get_bit $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: set_bit $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: set_bit $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: checked_add $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: checked_add $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: wrapping_add $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: wrapping_add $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: saturating_sub $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: saturating_sub $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: implies $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: implies $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: len $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: len $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold_add $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: fold_add $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold_mul $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: fold_mul $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold_max $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: fold_max $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold_min $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: fold_min $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: all $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: all $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: any $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: any $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: at $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: at $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: push $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: push $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: pop $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: pop $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: last $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: last $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: concat $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: concat $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: reverse $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: reverse $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: take $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: take $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: skip $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: skip $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: contains $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: contains $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: dot $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: dot $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sort $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: sort $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sort_desc $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: sort_desc $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: dedup $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: dedup $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: is_sorted $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: is_sorted $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: apply $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: apply $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: map $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: map $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: zip_with $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: zip_with $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: filter $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: filter $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: fold $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ( $($stuff:tt)* ) $($tail:tt)*)
+ => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: ( $($stuff)* ) $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: { $($operand:tt)* } $($tail:tt)*)
+ => (
+    __op_internal__!(@stack[$($stack,)*] @queue[{ $($operand)* }, $($queue,)*] @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: :: $($tail:tt)*) => (
+    __op_internal__!(@segment[::] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
+    __op_internal__!(@angle[<] @path[<] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $segment:ident $($tail:tt)*) => (
+    __op_internal__!(@path[$segment] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $literal:tt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[{ $crate::__op_operand__!($literal) }, $($queue,)*]
+                     @tail: $($tail)*)
+);
+(@operand @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: )
+);
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $x:ident $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[$op, $($stack,)*] @queue[$($queue,)*] @tail: $x $($tail)*)
+);
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: :: $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[$op, $($stack,)*] @queue[$($queue,)*] @tail: :: $($tail)*)
+);
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: < $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[$op, $($stack,)*] @queue[$($queue,)*] @tail: < $($tail)*)
+);
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: - $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[$op, $($stack,)*] @queue[$($queue,)*] @tail: - $($tail)*)
+);
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: ! $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[$op, $($stack,)*] @queue[$($queue,)*] @tail: ! $($tail)*)
+);
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: ( $($x:tt)* ) $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[$op, $($stack,)*] @queue[$($queue,)*] @tail: ( $($x)* ) $($tail)*)
+);
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: { $($x:tt)* } $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[$op, $($stack,)*] @queue[$($queue,)*] @tail: { $($x)* } $($tail)*)
+);
+(@prefix[$op:ident $sign:tt] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $literal:tt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*]
+                     @queue[{ $crate::__op_operand__!($sign $literal) }, $($queue,)*]
+                     @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: cmp $($tail:tt)*) => (
+    __op_internal__!(@stack[Compare, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sqr $($tail:tt)*) => (
+    __op_internal__!(@stack[Square, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sqrt $($tail:tt)*) => (
+    __op_internal__!(@stack[Sqrt, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: abs $($tail:tt)*) => (
+    __op_internal__!(@stack[AbsVal, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: cube $($tail:tt)*) => (
+    __op_internal__!(@stack[Cube, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: pow $($tail:tt)*) => (
+    __op_internal__!(@stack[Exp, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: min $($tail:tt)*) => (
+    __op_internal__!(@stack[Minimum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: max $($tail:tt)*) => (
+    __op_internal__!(@stack[Maximum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: log2 $($tail:tt)*) => (
+    __op_internal__!(@stack[Log2, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: log $($tail:tt)*) => (
+    __op_internal__!(@stack[Log, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: log10 $($tail:tt)*) => (
+    __op_internal__!(@stack[Log10, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ceil_log2 $($tail:tt)*) => (
+    __op_internal__!(@stack[CeilLog2, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: root $($tail:tt)*) => (
+    __op_internal__!(@stack[Root, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: cbrt $($tail:tt)*) => (
+    __op_internal__!(@stack[Cbrt, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: is_power_of_two $($tail:tt)*) => (
+    __op_internal__!(@stack[IsPow2, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: next_power_of_two $($tail:tt)*) => (
+    __op_internal__!(@stack[NextPow2, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: prev_power_of_two $($tail:tt)*) => (
+    __op_internal__!(@stack[PrevPow2, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: count_ones $($tail:tt)*) => (
+    __op_internal__!(@stack[Ones, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: count_zeros $($tail:tt)*) => (
+    __op_internal__!(@stack[Zeros, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: trailing_zeros $($tail:tt)*) => (
+    __op_internal__!(@stack[TrailZeros, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: leading_zeros $($tail:tt)*) => (
+    __op_internal__!(@stack[LeadZeros, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: reverse_bits $($tail:tt)*) => (
+    __op_internal__!(@stack[BitsReversed, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: gcd $($tail:tt)*) => (
+    __op_internal__!(@stack[Gcf, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: add_mod $($tail:tt)*) => (
+    __op_internal__!(@stack[SumMod, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: mul_mod $($tail:tt)*) => (
+    __op_internal__!(@stack[ProdMod, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: pow_mod $($tail:tt)*) => (
+    __op_internal__!(@stack[ExpMod, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: mod_inverse $($tail:tt)*) => (
+    __op_internal__!(@stack[InvMod, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: is_prime $($tail:tt)*) => (
+    __op_internal__!(@stack[Primality, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: smallest_factor $($tail:tt)*) => (
+    __op_internal__!(@stack[LeastFactor, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: factorize $($tail:tt)*) => (
+    __op_internal__!(@stack[Factors, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: lcm $($tail:tt)*) => (
+    __op_internal__!(@stack[Scm, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: sqr $($tail:tt)*) => (
-    __op_internal__!(@stack[Square, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: is_coprime $($tail:tt)*) => (
+    __op_internal__!(@stack[Coprimality, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: sqrt $($tail:tt)*) => (
-    __op_internal__!(@stack[Sqrt, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: div_floor $($tail:tt)*) => (
+    __op_internal__!(@stack[QuotFloor, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: abs $($tail:tt)*) => (
-    __op_internal__!(@stack[AbsVal, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: div_ceil $($tail:tt)*) => (
+    __op_internal__!(@stack[QuotCeil, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: cube $($tail:tt)*) => (
-    __op_internal__!(@stack[Cube, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: div_euclid $($tail:tt)*) => (
+    __op_internal__!(@stack[QuotEuclid, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: pow $($tail:tt)*) => (
-    __op_internal__!(@stack[Exp, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: rem_euclid $($tail:tt)*) => (
+    __op_internal__!(@stack[ModEuclid, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: min $($tail:tt)*) => (
-    __op_internal__!(@stack[Minimum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: if $($tail:tt)*) => (
+    __op_internal__!(@stack[If, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: max $($tail:tt)*) => (
-    __op_internal__!(@stack[Maximum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: add1 $($tail:tt)*) => (
+    __op_internal__!(@stack[Add1, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: log2 $($tail:tt)*) => (
-    __op_internal__!(@stack[Log2, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sub1 $($tail:tt)*) => (
+    __op_internal__!(@stack[Sub1, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: gcd $($tail:tt)*) => (
-    __op_internal__!(@stack[Gcf, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: double $($tail:tt)*) => (
+    __op_internal__!(@stack[Double, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: partial_div $($tail:tt)*) => (
+    __op_internal__!(@stack[PartialQuot, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: get_bit $($tail:tt)*) => (
+    __op_internal__!(@stack[GetBitOut, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: set_bit $($tail:tt)*) => (
+    __op_internal__!(@stack[SetBitOut, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: checked_add $($tail:tt)*) => (
+    __op_internal__!(@stack[CheckedSum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: wrapping_add $($tail:tt)*) => (
+    __op_internal__!(@stack[WrappingSum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: saturating_sub $($tail:tt)*) => (
+    __op_internal__!(@stack[SaturatingDiff, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: implies $($tail:tt)*) => (
+    __op_internal__!(@stack[Imply, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: len $($tail:tt)*) => (
+    __op_internal__!(@stack[Length, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold_add $($tail:tt)*) => (
+    __op_internal__!(@stack[FoldSum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold_mul $($tail:tt)*) => (
+    __op_internal__!(@stack[FoldProd, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold_max $($tail:tt)*) => (
+    __op_internal__!(@stack[FoldMaximum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold_min $($tail:tt)*) => (
+    __op_internal__!(@stack[FoldMinimum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: all $($tail:tt)*) => (
+    __op_internal__!(@stack[FoldAll, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: any $($tail:tt)*) => (
+    __op_internal__!(@stack[FoldAny, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: at $($tail:tt)*) => (
+    __op_internal__!(@stack[At, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: push $($tail:tt)*) => (
+    __op_internal__!(@stack[Pushed, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: pop $($tail:tt)*) => (
+    __op_internal__!(@stack[Popped, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: last $($tail:tt)*) => (
+    __op_internal__!(@stack[Last, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: concat $($tail:tt)*) => (
+    __op_internal__!(@stack[Concatenated, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: reverse $($tail:tt)*) => (
+    __op_internal__!(@stack[Reversed, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: take $($tail:tt)*) => (
+    __op_internal__!(@stack[Taken, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: skip $($tail:tt)*) => (
+    __op_internal__!(@stack[Skipped, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: contains $($tail:tt)*) => (
+    __op_internal__!(@stack[Contained, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[LParen, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: , $($tail:tt)*) => (
-    __op_internal__!(@stack[LParen, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: dot $($tail:tt)*) => (
+    __op_internal__!(@stack[Dot, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: , $($tail:tt)*) => (
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sort $($tail:tt)*) => (
+    __op_internal__!(@stack[Sorted, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: sort_desc $($tail:tt)*) => (
+    __op_internal__!(@stack[SortedDesc, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: dedup $($tail:tt)*) => (
+    __op_internal__!(@stack[Deduped, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: is_sorted $($tail:tt)*) => (
+    __op_internal__!(@stack[InOrder, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: apply $($tail:tt)*) => (
+    __op_internal__!(@stack[Apply, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: map $($tail:tt)*) => (
+    __op_internal__!(@stack[Mapped, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: zip_with $($tail:tt)*) => (
+    __op_internal__!(@stack[ZippedWith, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: filter $($tail:tt)*) => (
+    __op_internal__!(@stack[Filtered, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: fold $($tail:tt)*) => (
+    __op_internal__!(@stack[Folded, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[LParen, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: , $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[LParen, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: , $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[$stack_top, $($queue,)*] @tail: , $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: * $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: * $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: * $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: * $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: * $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: * $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: * $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: * $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: * $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: * $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: * $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: * $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: * $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: * $($tail:tt)*) => (
-    __op_internal__!(@stack[Prod, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: * $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Prod, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: / $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: / $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: / $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: / $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: / $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: / $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: / $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: / $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: / $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: / $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: / $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: / $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: / $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: / $($tail:tt)*) => (
-    __op_internal__!(@stack[Quot, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: / $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Quot, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: % $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: % $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: % $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: % $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: % $($tail:tt)*) => (
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: % $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: % $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: % $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: % $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: % $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: % $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: % $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: % $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: % $($tail:tt)*) => (
-    __op_internal__!(@stack[Mod, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: % $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Mod, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: + $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: + $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: + $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: + $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: + $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: + $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: + $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: + $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: + $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: + $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: + $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: + $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: + $($tail:tt)*) => (
-    __op_internal__!(@stack[Sum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: + $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Sum, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: - $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: - $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: - $($tail:tt)*) => (
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: - $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: - $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: - $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: - $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: - $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: - $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: - $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: - $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: - $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: - $($tail:tt)*) => (
-    __op_internal__!(@stack[Diff, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: - $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Diff, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: << $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: << $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: << $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: << $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: << $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: << $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: << $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: << $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: << $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: << $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: << $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: << $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: << $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: << $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: << $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: << $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: << $($tail:tt)*) => (
-    __op_internal__!(@stack[Shleft, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: << $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Shleft, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: >> $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: >> $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >> $($tail:tt)*) => (
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: >> $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >> $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: >> $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >> $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: >> $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >> $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: >> $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >> $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: >> $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >> $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: >> $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >> $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: >> $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >> $($tail:tt)*) => (
-    __op_internal__!(@stack[Shright, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >> $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Shright, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: & $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: & $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: & $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: & $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: & $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: & $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: & $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: & $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: & $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: & $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: & $($tail:tt)*) => (
-    __op_internal__!(@stack[And, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: & $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[And, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: ^ $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[Xor, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: ^ $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ^ $($tail:tt)*) => (
-    __op_internal__!(@stack[Xor, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ^ $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Xor, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: | $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: | $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[Xor, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[Or, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: | $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: | $($tail:tt)*) => (
-    __op_internal__!(@stack[Or, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: | $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Or, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: == $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Xor, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Or, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Eq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Eq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Eq, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[NotEq, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[LeEq, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[GrEq, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Le, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Le, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Le, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[Gr, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
+(@stack[Gr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Gr, $($queue,)*] @tail: == $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: == $($tail:tt)*) => (
-    __op_internal__!(@stack[Eq, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: == $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Eq, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: != $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: != $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Xor, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Or, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Eq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Eq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Eq, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[NotEq, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[LeEq, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[GrEq, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Le, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Le, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Le, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[Gr, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
+(@stack[Gr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Gr, $($queue,)*] @tail: != $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: != $($tail:tt)*) => (
-    __op_internal__!(@stack[NotEq, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: != $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[NotEq, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: <= $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Xor, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Or, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Eq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Eq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Eq, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[NotEq, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[LeEq, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[GrEq, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Le, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Le, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Le, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[Gr, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
+(@stack[Gr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Gr, $($queue,)*] @tail: <= $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: <= $($tail:tt)*) => (
-    __op_internal__!(@stack[LeEq, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: <= $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[LeEq, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: >= $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: >= $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Xor, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Or, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Eq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Eq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Eq, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[NotEq, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[LeEq, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[GrEq, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Le, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Le, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Le, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[Gr, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
+(@stack[Gr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Gr, $($queue,)*] @tail: >= $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: >= $($tail:tt)*) => (
-    __op_internal__!(@stack[GrEq, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: >= $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[GrEq, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: < $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Xor, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Or, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Eq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Eq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Eq, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[NotEq, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[LeEq, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[GrEq, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Le, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Le, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Le, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[Gr, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
+(@stack[Gr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Gr, $($queue,)*] @tail: < $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: < $($tail:tt)*) => (
-    __op_internal__!(@stack[Le, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: < $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Le, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[Prod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: > $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: > $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Quot, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Mod, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Sum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Diff, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Shright, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[And, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Xor, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Or, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Eq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Eq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Eq, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[NotEq, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[LeEq, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[GrEq, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Le, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Le, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Le, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[Gr, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
+(@stack[Gr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Gr, $($queue,)*] @tail: > $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: > $($tail:tt)*) => (
-    __op_internal__!(@stack[Gr, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: > $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[Gr, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Eq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Eq, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[NotEq, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[LeEq, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[GrEq, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Le, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Le, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[Gr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Gr, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[LogicalAnd, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[LogicalAnd, $($queue,)*] @tail: && $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: && $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[LogicalAnd, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[Complement, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Complement, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Negate, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Negate, $($queue,)*] @tail: || $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: ( $($stuff:tt)* ) $($tail:tt)* )
+(@stack[Prod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Prod, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Quot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Quot, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Mod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Mod, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Sum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Sum, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Diff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Diff, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Shleft, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Shleft, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Shright, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Shright, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[And, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[And, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Xor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Xor, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Or, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Or, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Eq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Eq, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[NotEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[NotEq, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[LeEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[LeEq, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[GrEq, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[GrEq, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Le, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Le, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[Gr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Gr, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[LogicalAnd, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[LogicalAnd, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[LogicalOr, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[LogicalOr, $($queue,)*] @tail: || $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: || $($tail:tt)*) => (
+    __op_internal__!(@operand @stack[LogicalOr, $($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: ( $($stuff:tt)* ) $($tail:tt)* )
  => (
-    __op_internal__!(@stack[LParen, $($stack,)*] @queue[$($queue,)*]
+    __op_internal__!(@operand @stack[LParen, $($stack,)*] @queue[$($queue,)*]
                      @tail: $($stuff)* RParen $($tail)*)
 );
-(@stack[LParen, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: RParen $($tail:tt)*) => (
+(@stack[LParen, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: RParen $($tail:tt)*) => (
     __op_internal__!(@rp3 @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
 );
-(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: RParen $($tail:tt)*)
+(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: RParen $($tail:tt)*)
  => (
     __op_internal__!(@stack[$($stack,)*] @queue[$stack_top, $($queue,)*] @tail: RParen $($tail)*)
 );
-(@rp3 @stack[Compare, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Compare, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Compare, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[Square, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Square, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Square, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[Sqrt, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Sqrt, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Sqrt, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[AbsVal, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[AbsVal, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[AbsVal, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[Cube, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Cube, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Cube, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[Exp, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Exp, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Exp, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[Minimum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Minimum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Minimum, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[Maximum, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Maximum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Maximum, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[Log2, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Log2, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Log2, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[Gcf, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
+(@rp3 @stack[Log, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Log, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Log10, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Log10, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[CeilLog2, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[CeilLog2, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Root, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Root, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Cbrt, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Cbrt, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[IsPow2, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[IsPow2, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[NextPow2, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[NextPow2, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[PrevPow2, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[PrevPow2, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Ones, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Ones, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Zeros, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Zeros, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[TrailZeros, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[TrailZeros, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[LeadZeros, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[LeadZeros, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[BitsReversed, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[BitsReversed, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Gcf, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
     __op_internal__!(@stack[$($stack,)*] @queue[Gcf, $($queue,)*] @tail: $($tail)*)
 );
-(@rp3 @stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $($tail:tt)*) => (
-    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+(@rp3 @stack[SumMod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[SumMod, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[ProdMod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[ProdMod, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[ExpMod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[ExpMod, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[InvMod, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[InvMod, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Primality, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Primality, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[LeastFactor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[LeastFactor, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Factors, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Factors, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Scm, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Scm, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Coprimality, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Coprimality, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[QuotFloor, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[QuotFloor, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[QuotCeil, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[QuotCeil, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[QuotEuclid, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[QuotEuclid, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[ModEuclid, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[ModEuclid, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[If, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[If, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Add1, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Add1, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Sub1, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Sub1, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Double, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Double, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[PartialQuot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[PartialQuot, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[GetBitOut, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[GetBitOut, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[SetBitOut, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[SetBitOut, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[CheckedSum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[CheckedSum, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[WrappingSum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[WrappingSum, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[SaturatingDiff, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[SaturatingDiff, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Imply, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Imply, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Length, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Length, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[FoldSum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[FoldSum, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[FoldProd, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[FoldProd, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[FoldMaximum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[FoldMaximum, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[FoldMinimum, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[FoldMinimum, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[FoldAll, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[FoldAll, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[FoldAny, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[FoldAny, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[At, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[At, $($queue,)*] @tail: $($tail)*)
 );
-(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: $num:ident $($tail:tt)*) => (
-    __op_internal__!(@stack[$($stack,)*] @queue[$num, $($queue,)*] @tail: $($tail)*)
+(@rp3 @stack[Pushed, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Pushed, $($queue,)*] @tail: $($tail)*)
 );
-(@stack[] @queue[$($queue:ident,)*] @tail: ) => (
+(@rp3 @stack[Popped, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Popped, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Last, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Last, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Concatenated, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Concatenated, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Reversed, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Reversed, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Taken, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Taken, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Skipped, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Skipped, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Contained, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Contained, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Dot, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Dot, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Sorted, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Sorted, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[SortedDesc, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[SortedDesc, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Deduped, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Deduped, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[InOrder, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[InOrder, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Apply, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Apply, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Mapped, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Mapped, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[ZippedWith, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[ZippedWith, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Filtered, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Filtered, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[Folded, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[Folded, $($queue,)*] @tail: $($tail)*)
+);
+(@rp3 @stack[$($stack:ident,)*] @queue[$($queue:tt,)*] @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@segment[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: < $($tail:tt)*) => (
+    __op_internal__!(@angle[<] @path[$($path)* <] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);
+(@segment[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $segment:ident $($tail:tt)*) => (
+    __op_internal__!(@path[$($path)* $segment] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);
+(@angle[] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $($tail:tt)*) => (
+    __op_internal__!(@path[$($path)*] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@angle[< $($depth:tt)*] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: > $($tail:tt)*) => (
+    __op_internal__!(@angle[$($depth)*] @path[$($path)* >] @stack[$($stack,)*]
+                     @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@angle[< < $($depth:tt)*] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: >> $($tail:tt)*) => (
+    __op_internal__!(@angle[$($depth)*] @path[$($path)* >>] @stack[$($stack,)*]
+                     @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@angle[<] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: >> $($tail:tt)*) => (
+    __op_internal__!(@angle[] @path[$($path)* >] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: > $($tail)*)
+);
+(@angle[$($depth:tt)*] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: < $($tail:tt)*) => (
+    __op_internal__!(@angle[< $($depth)*] @path[$($path)* <] @stack[$($stack,)*]
+                     @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@angle[$($depth:tt)*] @path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $token:tt $($tail:tt)*) => (
+    __op_internal__!(@angle[$($depth)*] @path[$($path)* $token] @stack[$($stack,)*]
+                     @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: :: $($tail:tt)*) => (
+    __op_internal__!(@segment[$($path)* ::] @stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: $($tail)*)
+);
+(@path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: ! $args:tt $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[{ $($path)* ! $args }, $($queue,)*]
+                     @tail: $($tail)*)
+);
+(@path[$($path:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:tt,)*]
+ @tail: $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[{ $($path)* }, $($queue,)*] @tail: $($tail)*)
+);
+(@stack[] @queue[$($queue:tt,)*] @tail: ) => (
     __op_internal__!(@reverse[] @input: $($queue,)*)
 );
-(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:ident,)*] @tail:) => (
+(@stack[$stack_top:ident, $($stack:ident,)*] @queue[$($queue:tt,)*] @tail:) => (
     __op_internal__!(@stack[$($stack,)*] @queue[$stack_top, $($queue,)*] @tail: )
 );
-(@reverse[$($revved:ident,)*] @input: $head:ident, $($tail:ident,)* ) => (
+(@reverse[$($revved:tt,)*] @input: $head:tt, $($tail:tt,)* ) => (
     __op_internal__!(@reverse[$head, $($revved,)*] @input: $($tail,)*)
 );
-(@reverse[$($revved:ident,)*] @input: ) => (
+(@reverse[$($revved:tt,)*] @input: ) => (
     __op_internal__!(@eval @stack[] @input[$($revved,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Prod, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Prod, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Prod<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Quot, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Quot, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Quot<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Mod, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Mod, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Mod<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Sum, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Sum, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Sum<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Diff, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Diff, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Diff<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Shleft, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Shleft, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Shleft<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Shright, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Shright, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Shright<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[And, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[And, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::And<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Xor, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Xor, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Xor<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Or, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Or, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Or<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Eq, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Eq, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Eq<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[NotEq, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[NotEq, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::NotEq<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[LeEq, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[LeEq, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::LeEq<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[GrEq, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[GrEq, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::GrEq<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Le, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Le, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Le<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Gr, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Gr, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Gr<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Compare, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[LogicalAnd, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::And<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[LogicalOr, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Or<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Compare, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Compare<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Exp, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Exp, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Exp<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Minimum, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Minimum, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Minimum<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Maximum, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Maximum, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Maximum<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Gcf, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Log, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Log<$a, $b>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Root, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Root<$a, $b>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Zeros, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Zeros<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[LeadZeros, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::LeadZeros<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[BitsReversed, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::BitsReversed<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Gcf, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Gcf<$b, $a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $($stack:ty,)*] @input[Square, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[InvMod, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::InvMod<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Scm, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Scm<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Coprimality, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Coprimality<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[QuotFloor, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::QuotFloor<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[QuotCeil, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::QuotCeil<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[QuotEuclid, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::QuotEuclid<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[ModEuclid, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::ModEuclid<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[PartialQuot, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::PartialQuot<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[GetBitOut, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::GetBitOut<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[SaturatingDiff, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::SaturatingDiff<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Imply, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Imply<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[At, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::At<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Pushed, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Pushed<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Concatenated, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Concatenated<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Taken, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Taken<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Skipped, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Skipped<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Contained, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Contained<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Dot, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Dot<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Apply, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Apply<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Mapped, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Mapped<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $($stack:ty,)*] @input[Filtered, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Filtered<$b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[SumMod, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::SumMod<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[ProdMod, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::ProdMod<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[ExpMod, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::ExpMod<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[If, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::If<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[SetBitOut, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::SetBitOut<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[CheckedSum, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::CheckedSum<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[WrappingSum, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::WrappingSum<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[ZippedWith, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::ZippedWith<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[Folded, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Folded<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Complement, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Complement<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Negate, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Negate<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Square, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Square<$a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $($stack:ty,)*] @input[Sqrt, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Sqrt, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Sqrt<$a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $($stack:ty,)*] @input[AbsVal, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[AbsVal, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::AbsVal<$a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $($stack:ty,)*] @input[Cube, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Cube, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Cube<$a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$a:ty, $($stack:ty,)*] @input[Log2, $($tail:ident,)*]) => (
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Log2, $($tail:tt,)*]) => (
     __op_internal__!(@eval @stack[$crate::Log2<$a>, $($stack,)*] @input[$($tail,)*])
 );
-(@eval @stack[$($stack:ty,)*] @input[$head:ident, $($tail:ident,)*]) => (
-    __op_internal__!(@eval @stack[$head, $($stack,)*] @input[$($tail,)*])
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Log10, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Log10<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[CeilLog2, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::CeilLog2<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Cbrt, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Cbrt<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[IsPow2, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::IsPow2<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[NextPow2, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::NextPow2<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[PrevPow2, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::PrevPow2<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Ones, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Ones<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[TrailZeros, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::TrailZeros<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Primality, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Primality<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[LeastFactor, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::LeastFactor<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Factors, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Factors<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Add1, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Add1<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Sub1, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Sub1<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Double, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Double<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Length, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Length<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[FoldSum, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::FoldSum<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[FoldProd, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::FoldProd<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[FoldMaximum, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::FoldMaximum<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[FoldMinimum, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::FoldMinimum<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[FoldAll, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::FoldAll<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[FoldAny, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::FoldAny<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Popped, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Popped<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Last, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Last<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Reversed, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Reversed<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Sorted, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Sorted<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[SortedDesc, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::SortedDesc<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[Deduped, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::Deduped<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$a:ty, $($stack:ty,)*] @input[InOrder, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$crate::InOrder<$a>, $($stack,)*] @input[$($tail,)*])
+);
+(@eval @stack[$($stack:ty,)*] @input[{ $($head:tt)* }, $($tail:tt,)*]) => (
+    __op_internal__!(@eval @stack[$($head)*, $($stack,)*] @input[$($tail,)*])
 );
 (@eval @stack[$stack:ty,] @input[]) => (
     $stack
 );
 ($($tail:tt)* ) => (
-    __op_internal__!(@stack[] @queue[] @tail: $($tail)*)
+    __op_internal__!(@operand @stack[] @queue[] @tail: $($tail)*)
+);
+}
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __op_operand__ {
+(+ $literal:tt) => ($crate::__tn!($crate; + $literal));
+(- $literal:tt) => ($crate::__tn!($crate; - $literal));
+($literal:literal) => ($crate::__tn!($crate; $literal));
+($operand:ty) => ($operand);
+($($literal:tt)*) => (
+    compile_error!(concat!("`op!` has no constant for `", stringify!($($literal)*), "`"))
+);
+}
+
+#[cfg(not(feature = "macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __op_operand__ {
+(+ 0) => ($crate::consts::Z0);
+(- 0) => ($crate::consts::Z0);
+(0) => ($crate::consts::U0);
+(1) => ($crate::consts::U1);
+(+ 1) => ($crate::consts::P1);
+(- 1) => ($crate::consts::N1);
+(2) => ($crate::consts::U2);
+(+ 2) => ($crate::consts::P2);
+(- 2) => ($crate::consts::N2);
+(3) => ($crate::consts::U3);
+(+ 3) => ($crate::consts::P3);
+(- 3) => ($crate::consts::N3);
+(4) => ($crate::consts::U4);
+(+ 4) => ($crate::consts::P4);
+(- 4) => ($crate::consts::N4);
+(5) => ($crate::consts::U5);
+(+ 5) => ($crate::consts::P5);
+(- 5) => ($crate::consts::N5);
+(6) => ($crate::consts::U6);
+(+ 6) => ($crate::consts::P6);
+(- 6) => ($crate::consts::N6);
+(7) => ($crate::consts::U7);
+(+ 7) => ($crate::consts::P7);
+(- 7) => ($crate::consts::N7);
+(8) => ($crate::consts::U8);
+(+ 8) => ($crate::consts::P8);
+(- 8) => ($crate::consts::N8);
+(9) => ($crate::consts::U9);
+(+ 9) => ($crate::consts::P9);
+(- 9) => ($crate::consts::N9);
+(10) => ($crate::consts::U10);
+(+ 10) => ($crate::consts::P10);
+(- 10) => ($crate::consts::N10);
+(11) => ($crate::consts::U11);
+(+ 11) => ($crate::consts::P11);
+(- 11) => ($crate::consts::N11);
+(12) => ($crate::consts::U12);
+(+ 12) => ($crate::consts::P12);
+(- 12) => ($crate::consts::N12);
+(13) => ($crate::consts::U13);
+(+ 13) => ($crate::consts::P13);
+(- 13) => ($crate::consts::N13);
+(14) => ($crate::consts::U14);
+(+ 14) => ($crate::consts::P14);
+(- 14) => ($crate::consts::N14);
+(15) => ($crate::consts::U15);
+(+ 15) => ($crate::consts::P15);
+(- 15) => ($crate::consts::N15);
+(16) => ($crate::consts::U16);
+(+ 16) => ($crate::consts::P16);
+(- 16) => ($crate::consts::N16);
+(17) => ($crate::consts::U17);
+(+ 17) => ($crate::consts::P17);
+(- 17) => ($crate::consts::N17);
+(18) => ($crate::consts::U18);
+(+ 18) => ($crate::consts::P18);
+(- 18) => ($crate::consts::N18);
+(19) => ($crate::consts::U19);
+(+ 19) => ($crate::consts::P19);
+(- 19) => ($crate::consts::N19);
+(20) => ($crate::consts::U20);
+(+ 20) => ($crate::consts::P20);
+(- 20) => ($crate::consts::N20);
+(21) => ($crate::consts::U21);
+(+ 21) => ($crate::consts::P21);
+(- 21) => ($crate::consts::N21);
+(22) => ($crate::consts::U22);
+(+ 22) => ($crate::consts::P22);
+(- 22) => ($crate::consts::N22);
+(23) => ($crate::consts::U23);
+(+ 23) => ($crate::consts::P23);
+(- 23) => ($crate::consts::N23);
+(24) => ($crate::consts::U24);
+(+ 24) => ($crate::consts::P24);
+(- 24) => ($crate::consts::N24);
+(25) => ($crate::consts::U25);
+(+ 25) => ($crate::consts::P25);
+(- 25) => ($crate::consts::N25);
+(26) => ($crate::consts::U26);
+(+ 26) => ($crate::consts::P26);
+(- 26) => ($crate::consts::N26);
+(27) => ($crate::consts::U27);
+(+ 27) => ($crate::consts::P27);
+(- 27) => ($crate::consts::N27);
+(28) => ($crate::consts::U28);
+(+ 28) => ($crate::consts::P28);
+(- 28) => ($crate::consts::N28);
+(29) => ($crate::consts::U29);
+(+ 29) => ($crate::consts::P29);
+(- 29) => ($crate::consts::N29);
+(30) => ($crate::consts::U30);
+(+ 30) => ($crate::consts::P30);
+(- 30) => ($crate::consts::N30);
+(31) => ($crate::consts::U31);
+(+ 31) => ($crate::consts::P31);
+(- 31) => ($crate::consts::N31);
+(32) => ($crate::consts::U32);
+(+ 32) => ($crate::consts::P32);
+(- 32) => ($crate::consts::N32);
+(33) => ($crate::consts::U33);
+(+ 33) => ($crate::consts::P33);
+(- 33) => ($crate::consts::N33);
+(34) => ($crate::consts::U34);
+(+ 34) => ($crate::consts::P34);
+(- 34) => ($crate::consts::N34);
+(35) => ($crate::consts::U35);
+(+ 35) => ($crate::consts::P35);
+(- 35) => ($crate::consts::N35);
+(36) => ($crate::consts::U36);
+(+ 36) => ($crate::consts::P36);
+(- 36) => ($crate::consts::N36);
+(37) => ($crate::consts::U37);
+(+ 37) => ($crate::consts::P37);
+(- 37) => ($crate::consts::N37);
+(38) => ($crate::consts::U38);
+(+ 38) => ($crate::consts::P38);
+(- 38) => ($crate::consts::N38);
+(39) => ($crate::consts::U39);
+(+ 39) => ($crate::consts::P39);
+(- 39) => ($crate::consts::N39);
+(40) => ($crate::consts::U40);
+(+ 40) => ($crate::consts::P40);
+(- 40) => ($crate::consts::N40);
+(41) => ($crate::consts::U41);
+(+ 41) => ($crate::consts::P41);
+(- 41) => ($crate::consts::N41);
+(42) => ($crate::consts::U42);
+(+ 42) => ($crate::consts::P42);
+(- 42) => ($crate::consts::N42);
+(43) => ($crate::consts::U43);
+(+ 43) => ($crate::consts::P43);
+(- 43) => ($crate::consts::N43);
+(44) => ($crate::consts::U44);
+(+ 44) => ($crate::consts::P44);
+(- 44) => ($crate::consts::N44);
+(45) => ($crate::consts::U45);
+(+ 45) => ($crate::consts::P45);
+(- 45) => ($crate::consts::N45);
+(46) => ($crate::consts::U46);
+(+ 46) => ($crate::consts::P46);
+(- 46) => ($crate::consts::N46);
+(47) => ($crate::consts::U47);
+(+ 47) => ($crate::consts::P47);
+(- 47) => ($crate::consts::N47);
+(48) => ($crate::consts::U48);
+(+ 48) => ($crate::consts::P48);
+(- 48) => ($crate::consts::N48);
+(49) => ($crate::consts::U49);
+(+ 49) => ($crate::consts::P49);
+(- 49) => ($crate::consts::N49);
+(50) => ($crate::consts::U50);
+(+ 50) => ($crate::consts::P50);
+(- 50) => ($crate::consts::N50);
+(51) => ($crate::consts::U51);
+(+ 51) => ($crate::consts::P51);
+(- 51) => ($crate::consts::N51);
+(52) => ($crate::consts::U52);
+(+ 52) => ($crate::consts::P52);
+(- 52) => ($crate::consts::N52);
+(53) => ($crate::consts::U53);
+(+ 53) => ($crate::consts::P53);
+(- 53) => ($crate::consts::N53);
+(54) => ($crate::consts::U54);
+(+ 54) => ($crate::consts::P54);
+(- 54) => ($crate::consts::N54);
+(55) => ($crate::consts::U55);
+(+ 55) => ($crate::consts::P55);
+(- 55) => ($crate::consts::N55);
+(56) => ($crate::consts::U56);
+(+ 56) => ($crate::consts::P56);
+(- 56) => ($crate::consts::N56);
+(57) => ($crate::consts::U57);
+(+ 57) => ($crate::consts::P57);
+(- 57) => ($crate::consts::N57);
+(58) => ($crate::consts::U58);
+(+ 58) => ($crate::consts::P58);
+(- 58) => ($crate::consts::N58);
+(59) => ($crate::consts::U59);
+(+ 59) => ($crate::consts::P59);
+(- 59) => ($crate::consts::N59);
+(60) => ($crate::consts::U60);
+(+ 60) => ($crate::consts::P60);
+(- 60) => ($crate::consts::N60);
+(61) => ($crate::consts::U61);
+(+ 61) => ($crate::consts::P61);
+(- 61) => ($crate::consts::N61);
+(62) => ($crate::consts::U62);
+(+ 62) => ($crate::consts::P62);
+(- 62) => ($crate::consts::N62);
+(63) => ($crate::consts::U63);
+(+ 63) => ($crate::consts::P63);
+(- 63) => ($crate::consts::N63);
+(64) => ($crate::consts::U64);
+(+ 64) => ($crate::consts::P64);
+(- 64) => ($crate::consts::N64);
+(65) => ($crate::consts::U65);
+(+ 65) => ($crate::consts::P65);
+(- 65) => ($crate::consts::N65);
+(66) => ($crate::consts::U66);
+(+ 66) => ($crate::consts::P66);
+(- 66) => ($crate::consts::N66);
+(67) => ($crate::consts::U67);
+(+ 67) => ($crate::consts::P67);
+(- 67) => ($crate::consts::N67);
+(68) => ($crate::consts::U68);
+(+ 68) => ($crate::consts::P68);
+(- 68) => ($crate::consts::N68);
+(69) => ($crate::consts::U69);
+(+ 69) => ($crate::consts::P69);
+(- 69) => ($crate::consts::N69);
+(70) => ($crate::consts::U70);
+(+ 70) => ($crate::consts::P70);
+(- 70) => ($crate::consts::N70);
+(71) => ($crate::consts::U71);
+(+ 71) => ($crate::consts::P71);
+(- 71) => ($crate::consts::N71);
+(72) => ($crate::consts::U72);
+(+ 72) => ($crate::consts::P72);
+(- 72) => ($crate::consts::N72);
+(73) => ($crate::consts::U73);
+(+ 73) => ($crate::consts::P73);
+(- 73) => ($crate::consts::N73);
+(74) => ($crate::consts::U74);
+(+ 74) => ($crate::consts::P74);
+(- 74) => ($crate::consts::N74);
+(75) => ($crate::consts::U75);
+(+ 75) => ($crate::consts::P75);
+(- 75) => ($crate::consts::N75);
+(76) => ($crate::consts::U76);
+(+ 76) => ($crate::consts::P76);
+(- 76) => ($crate::consts::N76);
+(77) => ($crate::consts::U77);
+(+ 77) => ($crate::consts::P77);
+(- 77) => ($crate::consts::N77);
+(78) => ($crate::consts::U78);
+(+ 78) => ($crate::consts::P78);
+(- 78) => ($crate::consts::N78);
+(79) => ($crate::consts::U79);
+(+ 79) => ($crate::consts::P79);
+(- 79) => ($crate::consts::N79);
+(80) => ($crate::consts::U80);
+(+ 80) => ($crate::consts::P80);
+(- 80) => ($crate::consts::N80);
+(81) => ($crate::consts::U81);
+(+ 81) => ($crate::consts::P81);
+(- 81) => ($crate::consts::N81);
+(82) => ($crate::consts::U82);
+(+ 82) => ($crate::consts::P82);
+(- 82) => ($crate::consts::N82);
+(83) => ($crate::consts::U83);
+(+ 83) => ($crate::consts::P83);
+(- 83) => ($crate::consts::N83);
+(84) => ($crate::consts::U84);
+(+ 84) => ($crate::consts::P84);
+(- 84) => ($crate::consts::N84);
+(85) => ($crate::consts::U85);
+(+ 85) => ($crate::consts::P85);
+(- 85) => ($crate::consts::N85);
+(86) => ($crate::consts::U86);
+(+ 86) => ($crate::consts::P86);
+(- 86) => ($crate::consts::N86);
+(87) => ($crate::consts::U87);
+(+ 87) => ($crate::consts::P87);
+(- 87) => ($crate::consts::N87);
+(88) => ($crate::consts::U88);
+(+ 88) => ($crate::consts::P88);
+(- 88) => ($crate::consts::N88);
+(89) => ($crate::consts::U89);
+(+ 89) => ($crate::consts::P89);
+(- 89) => ($crate::consts::N89);
+(90) => ($crate::consts::U90);
+(+ 90) => ($crate::consts::P90);
+(- 90) => ($crate::consts::N90);
+(91) => ($crate::consts::U91);
+(+ 91) => ($crate::consts::P91);
+(- 91) => ($crate::consts::N91);
+(92) => ($crate::consts::U92);
+(+ 92) => ($crate::consts::P92);
+(- 92) => ($crate::consts::N92);
+(93) => ($crate::consts::U93);
+(+ 93) => ($crate::consts::P93);
+(- 93) => ($crate::consts::N93);
+(94) => ($crate::consts::U94);
+(+ 94) => ($crate::consts::P94);
+(- 94) => ($crate::consts::N94);
+(95) => ($crate::consts::U95);
+(+ 95) => ($crate::consts::P95);
+(- 95) => ($crate::consts::N95);
+(96) => ($crate::consts::U96);
+(+ 96) => ($crate::consts::P96);
+(- 96) => ($crate::consts::N96);
+(97) => ($crate::consts::U97);
+(+ 97) => ($crate::consts::P97);
+(- 97) => ($crate::consts::N97);
+(98) => ($crate::consts::U98);
+(+ 98) => ($crate::consts::P98);
+(- 98) => ($crate::consts::N98);
+(99) => ($crate::consts::U99);
+(+ 99) => ($crate::consts::P99);
+(- 99) => ($crate::consts::N99);
+(100) => ($crate::consts::U100);
+(+ 100) => ($crate::consts::P100);
+(- 100) => ($crate::consts::N100);
+(101) => ($crate::consts::U101);
+(+ 101) => ($crate::consts::P101);
+(- 101) => ($crate::consts::N101);
+(102) => ($crate::consts::U102);
+(+ 102) => ($crate::consts::P102);
+(- 102) => ($crate::consts::N102);
+(103) => ($crate::consts::U103);
+(+ 103) => ($crate::consts::P103);
+(- 103) => ($crate::consts::N103);
+(104) => ($crate::consts::U104);
+(+ 104) => ($crate::consts::P104);
+(- 104) => ($crate::consts::N104);
+(105) => ($crate::consts::U105);
+(+ 105) => ($crate::consts::P105);
+(- 105) => ($crate::consts::N105);
+(106) => ($crate::consts::U106);
+(+ 106) => ($crate::consts::P106);
+(- 106) => ($crate::consts::N106);
+(107) => ($crate::consts::U107);
+(+ 107) => ($crate::consts::P107);
+(- 107) => ($crate::consts::N107);
+(108) => ($crate::consts::U108);
+(+ 108) => ($crate::consts::P108);
+(- 108) => ($crate::consts::N108);
+(109) => ($crate::consts::U109);
+(+ 109) => ($crate::consts::P109);
+(- 109) => ($crate::consts::N109);
+(110) => ($crate::consts::U110);
+(+ 110) => ($crate::consts::P110);
+(- 110) => ($crate::consts::N110);
+(111) => ($crate::consts::U111);
+(+ 111) => ($crate::consts::P111);
+(- 111) => ($crate::consts::N111);
+(112) => ($crate::consts::U112);
+(+ 112) => ($crate::consts::P112);
+(- 112) => ($crate::consts::N112);
+(113) => ($crate::consts::U113);
+(+ 113) => ($crate::consts::P113);
+(- 113) => ($crate::consts::N113);
+(114) => ($crate::consts::U114);
+(+ 114) => ($crate::consts::P114);
+(- 114) => ($crate::consts::N114);
+(115) => ($crate::consts::U115);
+(+ 115) => ($crate::consts::P115);
+(- 115) => ($crate::consts::N115);
+(116) => ($crate::consts::U116);
+(+ 116) => ($crate::consts::P116);
+(- 116) => ($crate::consts::N116);
+(117) => ($crate::consts::U117);
+(+ 117) => ($crate::consts::P117);
+(- 117) => ($crate::consts::N117);
+(118) => ($crate::consts::U118);
+(+ 118) => ($crate::consts::P118);
+(- 118) => ($crate::consts::N118);
+(119) => ($crate::consts::U119);
+(+ 119) => ($crate::consts::P119);
+(- 119) => ($crate::consts::N119);
+(120) => ($crate::consts::U120);
+(+ 120) => ($crate::consts::P120);
+(- 120) => ($crate::consts::N120);
+(121) => ($crate::consts::U121);
+(+ 121) => ($crate::consts::P121);
+(- 121) => ($crate::consts::N121);
+(122) => ($crate::consts::U122);
+(+ 122) => ($crate::consts::P122);
+(- 122) => ($crate::consts::N122);
+(123) => ($crate::consts::U123);
+(+ 123) => ($crate::consts::P123);
+(- 123) => ($crate::consts::N123);
+(124) => ($crate::consts::U124);
+(+ 124) => ($crate::consts::P124);
+(- 124) => ($crate::consts::N124);
+(125) => ($crate::consts::U125);
+(+ 125) => ($crate::consts::P125);
+(- 125) => ($crate::consts::N125);
+(126) => ($crate::consts::U126);
+(+ 126) => ($crate::consts::P126);
+(- 126) => ($crate::consts::N126);
+(127) => ($crate::consts::U127);
+(+ 127) => ($crate::consts::P127);
+(- 127) => ($crate::consts::N127);
+(128) => ($crate::consts::U128);
+(+ 128) => ($crate::consts::P128);
+(- 128) => ($crate::consts::N128);
+(129) => ($crate::consts::U129);
+(+ 129) => ($crate::consts::P129);
+(- 129) => ($crate::consts::N129);
+(130) => ($crate::consts::U130);
+(+ 130) => ($crate::consts::P130);
+(- 130) => ($crate::consts::N130);
+(131) => ($crate::consts::U131);
+(+ 131) => ($crate::consts::P131);
+(- 131) => ($crate::consts::N131);
+(132) => ($crate::consts::U132);
+(+ 132) => ($crate::consts::P132);
+(- 132) => ($crate::consts::N132);
+(133) => ($crate::consts::U133);
+(+ 133) => ($crate::consts::P133);
+(- 133) => ($crate::consts::N133);
+(134) => ($crate::consts::U134);
+(+ 134) => ($crate::consts::P134);
+(- 134) => ($crate::consts::N134);
+(135) => ($crate::consts::U135);
+(+ 135) => ($crate::consts::P135);
+(- 135) => ($crate::consts::N135);
+(136) => ($crate::consts::U136);
+(+ 136) => ($crate::consts::P136);
+(- 136) => ($crate::consts::N136);
+(137) => ($crate::consts::U137);
+(+ 137) => ($crate::consts::P137);
+(- 137) => ($crate::consts::N137);
+(138) => ($crate::consts::U138);
+(+ 138) => ($crate::consts::P138);
+(- 138) => ($crate::consts::N138);
+(139) => ($crate::consts::U139);
+(+ 139) => ($crate::consts::P139);
+(- 139) => ($crate::consts::N139);
+(140) => ($crate::consts::U140);
+(+ 140) => ($crate::consts::P140);
+(- 140) => ($crate::consts::N140);
+(141) => ($crate::consts::U141);
+(+ 141) => ($crate::consts::P141);
+(- 141) => ($crate::consts::N141);
+(142) => ($crate::consts::U142);
+(+ 142) => ($crate::consts::P142);
+(- 142) => ($crate::consts::N142);
+(143) => ($crate::consts::U143);
+(+ 143) => ($crate::consts::P143);
+(- 143) => ($crate::consts::N143);
+(144) => ($crate::consts::U144);
+(+ 144) => ($crate::consts::P144);
+(- 144) => ($crate::consts::N144);
+(145) => ($crate::consts::U145);
+(+ 145) => ($crate::consts::P145);
+(- 145) => ($crate::consts::N145);
+(146) => ($crate::consts::U146);
+(+ 146) => ($crate::consts::P146);
+(- 146) => ($crate::consts::N146);
+(147) => ($crate::consts::U147);
+(+ 147) => ($crate::consts::P147);
+(- 147) => ($crate::consts::N147);
+(148) => ($crate::consts::U148);
+(+ 148) => ($crate::consts::P148);
+(- 148) => ($crate::consts::N148);
+(149) => ($crate::consts::U149);
+(+ 149) => ($crate::consts::P149);
+(- 149) => ($crate::consts::N149);
+(150) => ($crate::consts::U150);
+(+ 150) => ($crate::consts::P150);
+(- 150) => ($crate::consts::N150);
+(151) => ($crate::consts::U151);
+(+ 151) => ($crate::consts::P151);
+(- 151) => ($crate::consts::N151);
+(152) => ($crate::consts::U152);
+(+ 152) => ($crate::consts::P152);
+(- 152) => ($crate::consts::N152);
+(153) => ($crate::consts::U153);
+(+ 153) => ($crate::consts::P153);
+(- 153) => ($crate::consts::N153);
+(154) => ($crate::consts::U154);
+(+ 154) => ($crate::consts::P154);
+(- 154) => ($crate::consts::N154);
+(155) => ($crate::consts::U155);
+(+ 155) => ($crate::consts::P155);
+(- 155) => ($crate::consts::N155);
+(156) => ($crate::consts::U156);
+(+ 156) => ($crate::consts::P156);
+(- 156) => ($crate::consts::N156);
+(157) => ($crate::consts::U157);
+(+ 157) => ($crate::consts::P157);
+(- 157) => ($crate::consts::N157);
+(158) => ($crate::consts::U158);
+(+ 158) => ($crate::consts::P158);
+(- 158) => ($crate::consts::N158);
+(159) => ($crate::consts::U159);
+(+ 159) => ($crate::consts::P159);
+(- 159) => ($crate::consts::N159);
+(160) => ($crate::consts::U160);
+(+ 160) => ($crate::consts::P160);
+(- 160) => ($crate::consts::N160);
+(161) => ($crate::consts::U161);
+(+ 161) => ($crate::consts::P161);
+(- 161) => ($crate::consts::N161);
+(162) => ($crate::consts::U162);
+(+ 162) => ($crate::consts::P162);
+(- 162) => ($crate::consts::N162);
+(163) => ($crate::consts::U163);
+(+ 163) => ($crate::consts::P163);
+(- 163) => ($crate::consts::N163);
+(164) => ($crate::consts::U164);
+(+ 164) => ($crate::consts::P164);
+(- 164) => ($crate::consts::N164);
+(165) => ($crate::consts::U165);
+(+ 165) => ($crate::consts::P165);
+(- 165) => ($crate::consts::N165);
+(166) => ($crate::consts::U166);
+(+ 166) => ($crate::consts::P166);
+(- 166) => ($crate::consts::N166);
+(167) => ($crate::consts::U167);
+(+ 167) => ($crate::consts::P167);
+(- 167) => ($crate::consts::N167);
+(168) => ($crate::consts::U168);
+(+ 168) => ($crate::consts::P168);
+(- 168) => ($crate::consts::N168);
+(169) => ($crate::consts::U169);
+(+ 169) => ($crate::consts::P169);
+(- 169) => ($crate::consts::N169);
+(170) => ($crate::consts::U170);
+(+ 170) => ($crate::consts::P170);
+(- 170) => ($crate::consts::N170);
+(171) => ($crate::consts::U171);
+(+ 171) => ($crate::consts::P171);
+(- 171) => ($crate::consts::N171);
+(172) => ($crate::consts::U172);
+(+ 172) => ($crate::consts::P172);
+(- 172) => ($crate::consts::N172);
+(173) => ($crate::consts::U173);
+(+ 173) => ($crate::consts::P173);
+(- 173) => ($crate::consts::N173);
+(174) => ($crate::consts::U174);
+(+ 174) => ($crate::consts::P174);
+(- 174) => ($crate::consts::N174);
+(175) => ($crate::consts::U175);
+(+ 175) => ($crate::consts::P175);
+(- 175) => ($crate::consts::N175);
+(176) => ($crate::consts::U176);
+(+ 176) => ($crate::consts::P176);
+(- 176) => ($crate::consts::N176);
+(177) => ($crate::consts::U177);
+(+ 177) => ($crate::consts::P177);
+(- 177) => ($crate::consts::N177);
+(178) => ($crate::consts::U178);
+(+ 178) => ($crate::consts::P178);
+(- 178) => ($crate::consts::N178);
+(179) => ($crate::consts::U179);
+(+ 179) => ($crate::consts::P179);
+(- 179) => ($crate::consts::N179);
+(180) => ($crate::consts::U180);
+(+ 180) => ($crate::consts::P180);
+(- 180) => ($crate::consts::N180);
+(181) => ($crate::consts::U181);
+(+ 181) => ($crate::consts::P181);
+(- 181) => ($crate::consts::N181);
+(182) => ($crate::consts::U182);
+(+ 182) => ($crate::consts::P182);
+(- 182) => ($crate::consts::N182);
+(183) => ($crate::consts::U183);
+(+ 183) => ($crate::consts::P183);
+(- 183) => ($crate::consts::N183);
+(184) => ($crate::consts::U184);
+(+ 184) => ($crate::consts::P184);
+(- 184) => ($crate::consts::N184);
+(185) => ($crate::consts::U185);
+(+ 185) => ($crate::consts::P185);
+(- 185) => ($crate::consts::N185);
+(186) => ($crate::consts::U186);
+(+ 186) => ($crate::consts::P186);
+(- 186) => ($crate::consts::N186);
+(187) => ($crate::consts::U187);
+(+ 187) => ($crate::consts::P187);
+(- 187) => ($crate::consts::N187);
+(188) => ($crate::consts::U188);
+(+ 188) => ($crate::consts::P188);
+(- 188) => ($crate::consts::N188);
+(189) => ($crate::consts::U189);
+(+ 189) => ($crate::consts::P189);
+(- 189) => ($crate::consts::N189);
+(190) => ($crate::consts::U190);
+(+ 190) => ($crate::consts::P190);
+(- 190) => ($crate::consts::N190);
+(191) => ($crate::consts::U191);
+(+ 191) => ($crate::consts::P191);
+(- 191) => ($crate::consts::N191);
+(192) => ($crate::consts::U192);
+(+ 192) => ($crate::consts::P192);
+(- 192) => ($crate::consts::N192);
+(193) => ($crate::consts::U193);
+(+ 193) => ($crate::consts::P193);
+(- 193) => ($crate::consts::N193);
+(194) => ($crate::consts::U194);
+(+ 194) => ($crate::consts::P194);
+(- 194) => ($crate::consts::N194);
+(195) => ($crate::consts::U195);
+(+ 195) => ($crate::consts::P195);
+(- 195) => ($crate::consts::N195);
+(196) => ($crate::consts::U196);
+(+ 196) => ($crate::consts::P196);
+(- 196) => ($crate::consts::N196);
+(197) => ($crate::consts::U197);
+(+ 197) => ($crate::consts::P197);
+(- 197) => ($crate::consts::N197);
+(198) => ($crate::consts::U198);
+(+ 198) => ($crate::consts::P198);
+(- 198) => ($crate::consts::N198);
+(199) => ($crate::consts::U199);
+(+ 199) => ($crate::consts::P199);
+(- 199) => ($crate::consts::N199);
+(200) => ($crate::consts::U200);
+(+ 200) => ($crate::consts::P200);
+(- 200) => ($crate::consts::N200);
+(201) => ($crate::consts::U201);
+(+ 201) => ($crate::consts::P201);
+(- 201) => ($crate::consts::N201);
+(202) => ($crate::consts::U202);
+(+ 202) => ($crate::consts::P202);
+(- 202) => ($crate::consts::N202);
+(203) => ($crate::consts::U203);
+(+ 203) => ($crate::consts::P203);
+(- 203) => ($crate::consts::N203);
+(204) => ($crate::consts::U204);
+(+ 204) => ($crate::consts::P204);
+(- 204) => ($crate::consts::N204);
+(205) => ($crate::consts::U205);
+(+ 205) => ($crate::consts::P205);
+(- 205) => ($crate::consts::N205);
+(206) => ($crate::consts::U206);
+(+ 206) => ($crate::consts::P206);
+(- 206) => ($crate::consts::N206);
+(207) => ($crate::consts::U207);
+(+ 207) => ($crate::consts::P207);
+(- 207) => ($crate::consts::N207);
+(208) => ($crate::consts::U208);
+(+ 208) => ($crate::consts::P208);
+(- 208) => ($crate::consts::N208);
+(209) => ($crate::consts::U209);
+(+ 209) => ($crate::consts::P209);
+(- 209) => ($crate::consts::N209);
+(210) => ($crate::consts::U210);
+(+ 210) => ($crate::consts::P210);
+(- 210) => ($crate::consts::N210);
+(211) => ($crate::consts::U211);
+(+ 211) => ($crate::consts::P211);
+(- 211) => ($crate::consts::N211);
+(212) => ($crate::consts::U212);
+(+ 212) => ($crate::consts::P212);
+(- 212) => ($crate::consts::N212);
+(213) => ($crate::consts::U213);
+(+ 213) => ($crate::consts::P213);
+(- 213) => ($crate::consts::N213);
+(214) => ($crate::consts::U214);
+(+ 214) => ($crate::consts::P214);
+(- 214) => ($crate::consts::N214);
+(215) => ($crate::consts::U215);
+(+ 215) => ($crate::consts::P215);
+(- 215) => ($crate::consts::N215);
+(216) => ($crate::consts::U216);
+(+ 216) => ($crate::consts::P216);
+(- 216) => ($crate::consts::N216);
+(217) => ($crate::consts::U217);
+(+ 217) => ($crate::consts::P217);
+(- 217) => ($crate::consts::N217);
+(218) => ($crate::consts::U218);
+(+ 218) => ($crate::consts::P218);
+(- 218) => ($crate::consts::N218);
+(219) => ($crate::consts::U219);
+(+ 219) => ($crate::consts::P219);
+(- 219) => ($crate::consts::N219);
+(220) => ($crate::consts::U220);
+(+ 220) => ($crate::consts::P220);
+(- 220) => ($crate::consts::N220);
+(221) => ($crate::consts::U221);
+(+ 221) => ($crate::consts::P221);
+(- 221) => ($crate::consts::N221);
+(222) => ($crate::consts::U222);
+(+ 222) => ($crate::consts::P222);
+(- 222) => ($crate::consts::N222);
+(223) => ($crate::consts::U223);
+(+ 223) => ($crate::consts::P223);
+(- 223) => ($crate::consts::N223);
+(224) => ($crate::consts::U224);
+(+ 224) => ($crate::consts::P224);
+(- 224) => ($crate::consts::N224);
+(225) => ($crate::consts::U225);
+(+ 225) => ($crate::consts::P225);
+(- 225) => ($crate::consts::N225);
+(226) => ($crate::consts::U226);
+(+ 226) => ($crate::consts::P226);
+(- 226) => ($crate::consts::N226);
+(227) => ($crate::consts::U227);
+(+ 227) => ($crate::consts::P227);
+(- 227) => ($crate::consts::N227);
+(228) => ($crate::consts::U228);
+(+ 228) => ($crate::consts::P228);
+(- 228) => ($crate::consts::N228);
+(229) => ($crate::consts::U229);
+(+ 229) => ($crate::consts::P229);
+(- 229) => ($crate::consts::N229);
+(230) => ($crate::consts::U230);
+(+ 230) => ($crate::consts::P230);
+(- 230) => ($crate::consts::N230);
+(231) => ($crate::consts::U231);
+(+ 231) => ($crate::consts::P231);
+(- 231) => ($crate::consts::N231);
+(232) => ($crate::consts::U232);
+(+ 232) => ($crate::consts::P232);
+(- 232) => ($crate::consts::N232);
+(233) => ($crate::consts::U233);
+(+ 233) => ($crate::consts::P233);
+(- 233) => ($crate::consts::N233);
+(234) => ($crate::consts::U234);
+(+ 234) => ($crate::consts::P234);
+(- 234) => ($crate::consts::N234);
+(235) => ($crate::consts::U235);
+(+ 235) => ($crate::consts::P235);
+(- 235) => ($crate::consts::N235);
+(236) => ($crate::consts::U236);
+(+ 236) => ($crate::consts::P236);
+(- 236) => ($crate::consts::N236);
+(237) => ($crate::consts::U237);
+(+ 237) => ($crate::consts::P237);
+(- 237) => ($crate::consts::N237);
+(238) => ($crate::consts::U238);
+(+ 238) => ($crate::consts::P238);
+(- 238) => ($crate::consts::N238);
+(239) => ($crate::consts::U239);
+(+ 239) => ($crate::consts::P239);
+(- 239) => ($crate::consts::N239);
+(240) => ($crate::consts::U240);
+(+ 240) => ($crate::consts::P240);
+(- 240) => ($crate::consts::N240);
+(241) => ($crate::consts::U241);
+(+ 241) => ($crate::consts::P241);
+(- 241) => ($crate::consts::N241);
+(242) => ($crate::consts::U242);
+(+ 242) => ($crate::consts::P242);
+(- 242) => ($crate::consts::N242);
+(243) => ($crate::consts::U243);
+(+ 243) => ($crate::consts::P243);
+(- 243) => ($crate::consts::N243);
+(244) => ($crate::consts::U244);
+(+ 244) => ($crate::consts::P244);
+(- 244) => ($crate::consts::N244);
+(245) => ($crate::consts::U245);
+(+ 245) => ($crate::consts::P245);
+(- 245) => ($crate::consts::N245);
+(246) => ($crate::consts::U246);
+(+ 246) => ($crate::consts::P246);
+(- 246) => ($crate::consts::N246);
+(247) => ($crate::consts::U247);
+(+ 247) => ($crate::consts::P247);
+(- 247) => ($crate::consts::N247);
+(248) => ($crate::consts::U248);
+(+ 248) => ($crate::consts::P248);
+(- 248) => ($crate::consts::N248);
+(249) => ($crate::consts::U249);
+(+ 249) => ($crate::consts::P249);
+(- 249) => ($crate::consts::N249);
+(250) => ($crate::consts::U250);
+(+ 250) => ($crate::consts::P250);
+(- 250) => ($crate::consts::N250);
+(251) => ($crate::consts::U251);
+(+ 251) => ($crate::consts::P251);
+(- 251) => ($crate::consts::N251);
+(252) => ($crate::consts::U252);
+(+ 252) => ($crate::consts::P252);
+(- 252) => ($crate::consts::N252);
+(253) => ($crate::consts::U253);
+(+ 253) => ($crate::consts::P253);
+(- 253) => ($crate::consts::N253);
+(254) => ($crate::consts::U254);
+(+ 254) => ($crate::consts::P254);
+(- 254) => ($crate::consts::N254);
+(255) => ($crate::consts::U255);
+(+ 255) => ($crate::consts::P255);
+(- 255) => ($crate::consts::N255);
+(256) => ($crate::consts::U256);
+(+ 256) => ($crate::consts::P256);
+(- 256) => ($crate::consts::N256);
+(257) => ($crate::consts::U257);
+(+ 257) => ($crate::consts::P257);
+(- 257) => ($crate::consts::N257);
+(258) => ($crate::consts::U258);
+(+ 258) => ($crate::consts::P258);
+(- 258) => ($crate::consts::N258);
+(259) => ($crate::consts::U259);
+(+ 259) => ($crate::consts::P259);
+(- 259) => ($crate::consts::N259);
+(260) => ($crate::consts::U260);
+(+ 260) => ($crate::consts::P260);
+(- 260) => ($crate::consts::N260);
+(261) => ($crate::consts::U261);
+(+ 261) => ($crate::consts::P261);
+(- 261) => ($crate::consts::N261);
+(262) => ($crate::consts::U262);
+(+ 262) => ($crate::consts::P262);
+(- 262) => ($crate::consts::N262);
+(263) => ($crate::consts::U263);
+(+ 263) => ($crate::consts::P263);
+(- 263) => ($crate::consts::N263);
+(264) => ($crate::consts::U264);
+(+ 264) => ($crate::consts::P264);
+(- 264) => ($crate::consts::N264);
+(265) => ($crate::consts::U265);
+(+ 265) => ($crate::consts::P265);
+(- 265) => ($crate::consts::N265);
+(266) => ($crate::consts::U266);
+(+ 266) => ($crate::consts::P266);
+(- 266) => ($crate::consts::N266);
+(267) => ($crate::consts::U267);
+(+ 267) => ($crate::consts::P267);
+(- 267) => ($crate::consts::N267);
+(268) => ($crate::consts::U268);
+(+ 268) => ($crate::consts::P268);
+(- 268) => ($crate::consts::N268);
+(269) => ($crate::consts::U269);
+(+ 269) => ($crate::consts::P269);
+(- 269) => ($crate::consts::N269);
+(270) => ($crate::consts::U270);
+(+ 270) => ($crate::consts::P270);
+(- 270) => ($crate::consts::N270);
+(271) => ($crate::consts::U271);
+(+ 271) => ($crate::consts::P271);
+(- 271) => ($crate::consts::N271);
+(272) => ($crate::consts::U272);
+(+ 272) => ($crate::consts::P272);
+(- 272) => ($crate::consts::N272);
+(273) => ($crate::consts::U273);
+(+ 273) => ($crate::consts::P273);
+(- 273) => ($crate::consts::N273);
+(274) => ($crate::consts::U274);
+(+ 274) => ($crate::consts::P274);
+(- 274) => ($crate::consts::N274);
+(275) => ($crate::consts::U275);
+(+ 275) => ($crate::consts::P275);
+(- 275) => ($crate::consts::N275);
+(276) => ($crate::consts::U276);
+(+ 276) => ($crate::consts::P276);
+(- 276) => ($crate::consts::N276);
+(277) => ($crate::consts::U277);
+(+ 277) => ($crate::consts::P277);
+(- 277) => ($crate::consts::N277);
+(278) => ($crate::consts::U278);
+(+ 278) => ($crate::consts::P278);
+(- 278) => ($crate::consts::N278);
+(279) => ($crate::consts::U279);
+(+ 279) => ($crate::consts::P279);
+(- 279) => ($crate::consts::N279);
+(280) => ($crate::consts::U280);
+(+ 280) => ($crate::consts::P280);
+(- 280) => ($crate::consts::N280);
+(281) => ($crate::consts::U281);
+(+ 281) => ($crate::consts::P281);
+(- 281) => ($crate::consts::N281);
+(282) => ($crate::consts::U282);
+(+ 282) => ($crate::consts::P282);
+(- 282) => ($crate::consts::N282);
+(283) => ($crate::consts::U283);
+(+ 283) => ($crate::consts::P283);
+(- 283) => ($crate::consts::N283);
+(284) => ($crate::consts::U284);
+(+ 284) => ($crate::consts::P284);
+(- 284) => ($crate::consts::N284);
+(285) => ($crate::consts::U285);
+(+ 285) => ($crate::consts::P285);
+(- 285) => ($crate::consts::N285);
+(286) => ($crate::consts::U286);
+(+ 286) => ($crate::consts::P286);
+(- 286) => ($crate::consts::N286);
+(287) => ($crate::consts::U287);
+(+ 287) => ($crate::consts::P287);
+(- 287) => ($crate::consts::N287);
+(288) => ($crate::consts::U288);
+(+ 288) => ($crate::consts::P288);
+(- 288) => ($crate::consts::N288);
+(289) => ($crate::consts::U289);
+(+ 289) => ($crate::consts::P289);
+(- 289) => ($crate::consts::N289);
+(290) => ($crate::consts::U290);
+(+ 290) => ($crate::consts::P290);
+(- 290) => ($crate::consts::N290);
+(291) => ($crate::consts::U291);
+(+ 291) => ($crate::consts::P291);
+(- 291) => ($crate::consts::N291);
+(292) => ($crate::consts::U292);
+(+ 292) => ($crate::consts::P292);
+(- 292) => ($crate::consts::N292);
+(293) => ($crate::consts::U293);
+(+ 293) => ($crate::consts::P293);
+(- 293) => ($crate::consts::N293);
+(294) => ($crate::consts::U294);
+(+ 294) => ($crate::consts::P294);
+(- 294) => ($crate::consts::N294);
+(295) => ($crate::consts::U295);
+(+ 295) => ($crate::consts::P295);
+(- 295) => ($crate::consts::N295);
+(296) => ($crate::consts::U296);
+(+ 296) => ($crate::consts::P296);
+(- 296) => ($crate::consts::N296);
+(297) => ($crate::consts::U297);
+(+ 297) => ($crate::consts::P297);
+(- 297) => ($crate::consts::N297);
+(298) => ($crate::consts::U298);
+(+ 298) => ($crate::consts::P298);
+(- 298) => ($crate::consts::N298);
+(299) => ($crate::consts::U299);
+(+ 299) => ($crate::consts::P299);
+(- 299) => ($crate::consts::N299);
+(300) => ($crate::consts::U300);
+(+ 300) => ($crate::consts::P300);
+(- 300) => ($crate::consts::N300);
+(301) => ($crate::consts::U301);
+(+ 301) => ($crate::consts::P301);
+(- 301) => ($crate::consts::N301);
+(302) => ($crate::consts::U302);
+(+ 302) => ($crate::consts::P302);
+(- 302) => ($crate::consts::N302);
+(303) => ($crate::consts::U303);
+(+ 303) => ($crate::consts::P303);
+(- 303) => ($crate::consts::N303);
+(304) => ($crate::consts::U304);
+(+ 304) => ($crate::consts::P304);
+(- 304) => ($crate::consts::N304);
+(305) => ($crate::consts::U305);
+(+ 305) => ($crate::consts::P305);
+(- 305) => ($crate::consts::N305);
+(306) => ($crate::consts::U306);
+(+ 306) => ($crate::consts::P306);
+(- 306) => ($crate::consts::N306);
+(307) => ($crate::consts::U307);
+(+ 307) => ($crate::consts::P307);
+(- 307) => ($crate::consts::N307);
+(308) => ($crate::consts::U308);
+(+ 308) => ($crate::consts::P308);
+(- 308) => ($crate::consts::N308);
+(309) => ($crate::consts::U309);
+(+ 309) => ($crate::consts::P309);
+(- 309) => ($crate::consts::N309);
+(310) => ($crate::consts::U310);
+(+ 310) => ($crate::consts::P310);
+(- 310) => ($crate::consts::N310);
+(311) => ($crate::consts::U311);
+(+ 311) => ($crate::consts::P311);
+(- 311) => ($crate::consts::N311);
+(312) => ($crate::consts::U312);
+(+ 312) => ($crate::consts::P312);
+(- 312) => ($crate::consts::N312);
+(313) => ($crate::consts::U313);
+(+ 313) => ($crate::consts::P313);
+(- 313) => ($crate::consts::N313);
+(314) => ($crate::consts::U314);
+(+ 314) => ($crate::consts::P314);
+(- 314) => ($crate::consts::N314);
+(315) => ($crate::consts::U315);
+(+ 315) => ($crate::consts::P315);
+(- 315) => ($crate::consts::N315);
+(316) => ($crate::consts::U316);
+(+ 316) => ($crate::consts::P316);
+(- 316) => ($crate::consts::N316);
+(317) => ($crate::consts::U317);
+(+ 317) => ($crate::consts::P317);
+(- 317) => ($crate::consts::N317);
+(318) => ($crate::consts::U318);
+(+ 318) => ($crate::consts::P318);
+(- 318) => ($crate::consts::N318);
+(319) => ($crate::consts::U319);
+(+ 319) => ($crate::consts::P319);
+(- 319) => ($crate::consts::N319);
+(320) => ($crate::consts::U320);
+(+ 320) => ($crate::consts::P320);
+(- 320) => ($crate::consts::N320);
+(321) => ($crate::consts::U321);
+(+ 321) => ($crate::consts::P321);
+(- 321) => ($crate::consts::N321);
+(322) => ($crate::consts::U322);
+(+ 322) => ($crate::consts::P322);
+(- 322) => ($crate::consts::N322);
+(323) => ($crate::consts::U323);
+(+ 323) => ($crate::consts::P323);
+(- 323) => ($crate::consts::N323);
+(324) => ($crate::consts::U324);
+(+ 324) => ($crate::consts::P324);
+(- 324) => ($crate::consts::N324);
+(325) => ($crate::consts::U325);
+(+ 325) => ($crate::consts::P325);
+(- 325) => ($crate::consts::N325);
+(326) => ($crate::consts::U326);
+(+ 326) => ($crate::consts::P326);
+(- 326) => ($crate::consts::N326);
+(327) => ($crate::consts::U327);
+(+ 327) => ($crate::consts::P327);
+(- 327) => ($crate::consts::N327);
+(328) => ($crate::consts::U328);
+(+ 328) => ($crate::consts::P328);
+(- 328) => ($crate::consts::N328);
+(329) => ($crate::consts::U329);
+(+ 329) => ($crate::consts::P329);
+(- 329) => ($crate::consts::N329);
+(330) => ($crate::consts::U330);
+(+ 330) => ($crate::consts::P330);
+(- 330) => ($crate::consts::N330);
+(331) => ($crate::consts::U331);
+(+ 331) => ($crate::consts::P331);
+(- 331) => ($crate::consts::N331);
+(332) => ($crate::consts::U332);
+(+ 332) => ($crate::consts::P332);
+(- 332) => ($crate::consts::N332);
+(333) => ($crate::consts::U333);
+(+ 333) => ($crate::consts::P333);
+(- 333) => ($crate::consts::N333);
+(334) => ($crate::consts::U334);
+(+ 334) => ($crate::consts::P334);
+(- 334) => ($crate::consts::N334);
+(335) => ($crate::consts::U335);
+(+ 335) => ($crate::consts::P335);
+(- 335) => ($crate::consts::N335);
+(336) => ($crate::consts::U336);
+(+ 336) => ($crate::consts::P336);
+(- 336) => ($crate::consts::N336);
+(337) => ($crate::consts::U337);
+(+ 337) => ($crate::consts::P337);
+(- 337) => ($crate::consts::N337);
+(338) => ($crate::consts::U338);
+(+ 338) => ($crate::consts::P338);
+(- 338) => ($crate::consts::N338);
+(339) => ($crate::consts::U339);
+(+ 339) => ($crate::consts::P339);
+(- 339) => ($crate::consts::N339);
+(340) => ($crate::consts::U340);
+(+ 340) => ($crate::consts::P340);
+(- 340) => ($crate::consts::N340);
+(341) => ($crate::consts::U341);
+(+ 341) => ($crate::consts::P341);
+(- 341) => ($crate::consts::N341);
+(342) => ($crate::consts::U342);
+(+ 342) => ($crate::consts::P342);
+(- 342) => ($crate::consts::N342);
+(343) => ($crate::consts::U343);
+(+ 343) => ($crate::consts::P343);
+(- 343) => ($crate::consts::N343);
+(344) => ($crate::consts::U344);
+(+ 344) => ($crate::consts::P344);
+(- 344) => ($crate::consts::N344);
+(345) => ($crate::consts::U345);
+(+ 345) => ($crate::consts::P345);
+(- 345) => ($crate::consts::N345);
+(346) => ($crate::consts::U346);
+(+ 346) => ($crate::consts::P346);
+(- 346) => ($crate::consts::N346);
+(347) => ($crate::consts::U347);
+(+ 347) => ($crate::consts::P347);
+(- 347) => ($crate::consts::N347);
+(348) => ($crate::consts::U348);
+(+ 348) => ($crate::consts::P348);
+(- 348) => ($crate::consts::N348);
+(349) => ($crate::consts::U349);
+(+ 349) => ($crate::consts::P349);
+(- 349) => ($crate::consts::N349);
+(350) => ($crate::consts::U350);
+(+ 350) => ($crate::consts::P350);
+(- 350) => ($crate::consts::N350);
+(351) => ($crate::consts::U351);
+(+ 351) => ($crate::consts::P351);
+(- 351) => ($crate::consts::N351);
+(352) => ($crate::consts::U352);
+(+ 352) => ($crate::consts::P352);
+(- 352) => ($crate::consts::N352);
+(353) => ($crate::consts::U353);
+(+ 353) => ($crate::consts::P353);
+(- 353) => ($crate::consts::N353);
+(354) => ($crate::consts::U354);
+(+ 354) => ($crate::consts::P354);
+(- 354) => ($crate::consts::N354);
+(355) => ($crate::consts::U355);
+(+ 355) => ($crate::consts::P355);
+(- 355) => ($crate::consts::N355);
+(356) => ($crate::consts::U356);
+(+ 356) => ($crate::consts::P356);
+(- 356) => ($crate::consts::N356);
+(357) => ($crate::consts::U357);
+(+ 357) => ($crate::consts::P357);
+(- 357) => ($crate::consts::N357);
+(358) => ($crate::consts::U358);
+(+ 358) => ($crate::consts::P358);
+(- 358) => ($crate::consts::N358);
+(359) => ($crate::consts::U359);
+(+ 359) => ($crate::consts::P359);
+(- 359) => ($crate::consts::N359);
+(360) => ($crate::consts::U360);
+(+ 360) => ($crate::consts::P360);
+(- 360) => ($crate::consts::N360);
+(361) => ($crate::consts::U361);
+(+ 361) => ($crate::consts::P361);
+(- 361) => ($crate::consts::N361);
+(362) => ($crate::consts::U362);
+(+ 362) => ($crate::consts::P362);
+(- 362) => ($crate::consts::N362);
+(363) => ($crate::consts::U363);
+(+ 363) => ($crate::consts::P363);
+(- 363) => ($crate::consts::N363);
+(364) => ($crate::consts::U364);
+(+ 364) => ($crate::consts::P364);
+(- 364) => ($crate::consts::N364);
+(365) => ($crate::consts::U365);
+(+ 365) => ($crate::consts::P365);
+(- 365) => ($crate::consts::N365);
+(366) => ($crate::consts::U366);
+(+ 366) => ($crate::consts::P366);
+(- 366) => ($crate::consts::N366);
+(367) => ($crate::consts::U367);
+(+ 367) => ($crate::consts::P367);
+(- 367) => ($crate::consts::N367);
+(368) => ($crate::consts::U368);
+(+ 368) => ($crate::consts::P368);
+(- 368) => ($crate::consts::N368);
+(369) => ($crate::consts::U369);
+(+ 369) => ($crate::consts::P369);
+(- 369) => ($crate::consts::N369);
+(370) => ($crate::consts::U370);
+(+ 370) => ($crate::consts::P370);
+(- 370) => ($crate::consts::N370);
+(371) => ($crate::consts::U371);
+(+ 371) => ($crate::consts::P371);
+(- 371) => ($crate::consts::N371);
+(372) => ($crate::consts::U372);
+(+ 372) => ($crate::consts::P372);
+(- 372) => ($crate::consts::N372);
+(373) => ($crate::consts::U373);
+(+ 373) => ($crate::consts::P373);
+(- 373) => ($crate::consts::N373);
+(374) => ($crate::consts::U374);
+(+ 374) => ($crate::consts::P374);
+(- 374) => ($crate::consts::N374);
+(375) => ($crate::consts::U375);
+(+ 375) => ($crate::consts::P375);
+(- 375) => ($crate::consts::N375);
+(376) => ($crate::consts::U376);
+(+ 376) => ($crate::consts::P376);
+(- 376) => ($crate::consts::N376);
+(377) => ($crate::consts::U377);
+(+ 377) => ($crate::consts::P377);
+(- 377) => ($crate::consts::N377);
+(378) => ($crate::consts::U378);
+(+ 378) => ($crate::consts::P378);
+(- 378) => ($crate::consts::N378);
+(379) => ($crate::consts::U379);
+(+ 379) => ($crate::consts::P379);
+(- 379) => ($crate::consts::N379);
+(380) => ($crate::consts::U380);
+(+ 380) => ($crate::consts::P380);
+(- 380) => ($crate::consts::N380);
+(381) => ($crate::consts::U381);
+(+ 381) => ($crate::consts::P381);
+(- 381) => ($crate::consts::N381);
+(382) => ($crate::consts::U382);
+(+ 382) => ($crate::consts::P382);
+(- 382) => ($crate::consts::N382);
+(383) => ($crate::consts::U383);
+(+ 383) => ($crate::consts::P383);
+(- 383) => ($crate::consts::N383);
+(384) => ($crate::consts::U384);
+(+ 384) => ($crate::consts::P384);
+(- 384) => ($crate::consts::N384);
+(385) => ($crate::consts::U385);
+(+ 385) => ($crate::consts::P385);
+(- 385) => ($crate::consts::N385);
+(386) => ($crate::consts::U386);
+(+ 386) => ($crate::consts::P386);
+(- 386) => ($crate::consts::N386);
+(387) => ($crate::consts::U387);
+(+ 387) => ($crate::consts::P387);
+(- 387) => ($crate::consts::N387);
+(388) => ($crate::consts::U388);
+(+ 388) => ($crate::consts::P388);
+(- 388) => ($crate::consts::N388);
+(389) => ($crate::consts::U389);
+(+ 389) => ($crate::consts::P389);
+(- 389) => ($crate::consts::N389);
+(390) => ($crate::consts::U390);
+(+ 390) => ($crate::consts::P390);
+(- 390) => ($crate::consts::N390);
+(391) => ($crate::consts::U391);
+(+ 391) => ($crate::consts::P391);
+(- 391) => ($crate::consts::N391);
+(392) => ($crate::consts::U392);
+(+ 392) => ($crate::consts::P392);
+(- 392) => ($crate::consts::N392);
+(393) => ($crate::consts::U393);
+(+ 393) => ($crate::consts::P393);
+(- 393) => ($crate::consts::N393);
+(394) => ($crate::consts::U394);
+(+ 394) => ($crate::consts::P394);
+(- 394) => ($crate::consts::N394);
+(395) => ($crate::consts::U395);
+(+ 395) => ($crate::consts::P395);
+(- 395) => ($crate::consts::N395);
+(396) => ($crate::consts::U396);
+(+ 396) => ($crate::consts::P396);
+(- 396) => ($crate::consts::N396);
+(397) => ($crate::consts::U397);
+(+ 397) => ($crate::consts::P397);
+(- 397) => ($crate::consts::N397);
+(398) => ($crate::consts::U398);
+(+ 398) => ($crate::consts::P398);
+(- 398) => ($crate::consts::N398);
+(399) => ($crate::consts::U399);
+(+ 399) => ($crate::consts::P399);
+(- 399) => ($crate::consts::N399);
+(400) => ($crate::consts::U400);
+(+ 400) => ($crate::consts::P400);
+(- 400) => ($crate::consts::N400);
+(401) => ($crate::consts::U401);
+(+ 401) => ($crate::consts::P401);
+(- 401) => ($crate::consts::N401);
+(402) => ($crate::consts::U402);
+(+ 402) => ($crate::consts::P402);
+(- 402) => ($crate::consts::N402);
+(403) => ($crate::consts::U403);
+(+ 403) => ($crate::consts::P403);
+(- 403) => ($crate::consts::N403);
+(404) => ($crate::consts::U404);
+(+ 404) => ($crate::consts::P404);
+(- 404) => ($crate::consts::N404);
+(405) => ($crate::consts::U405);
+(+ 405) => ($crate::consts::P405);
+(- 405) => ($crate::consts::N405);
+(406) => ($crate::consts::U406);
+(+ 406) => ($crate::consts::P406);
+(- 406) => ($crate::consts::N406);
+(407) => ($crate::consts::U407);
+(+ 407) => ($crate::consts::P407);
+(- 407) => ($crate::consts::N407);
+(408) => ($crate::consts::U408);
+(+ 408) => ($crate::consts::P408);
+(- 408) => ($crate::consts::N408);
+(409) => ($crate::consts::U409);
+(+ 409) => ($crate::consts::P409);
+(- 409) => ($crate::consts::N409);
+(410) => ($crate::consts::U410);
+(+ 410) => ($crate::consts::P410);
+(- 410) => ($crate::consts::N410);
+(411) => ($crate::consts::U411);
+(+ 411) => ($crate::consts::P411);
+(- 411) => ($crate::consts::N411);
+(412) => ($crate::consts::U412);
+(+ 412) => ($crate::consts::P412);
+(- 412) => ($crate::consts::N412);
+(413) => ($crate::consts::U413);
+(+ 413) => ($crate::consts::P413);
+(- 413) => ($crate::consts::N413);
+(414) => ($crate::consts::U414);
+(+ 414) => ($crate::consts::P414);
+(- 414) => ($crate::consts::N414);
+(415) => ($crate::consts::U415);
+(+ 415) => ($crate::consts::P415);
+(- 415) => ($crate::consts::N415);
+(416) => ($crate::consts::U416);
+(+ 416) => ($crate::consts::P416);
+(- 416) => ($crate::consts::N416);
+(417) => ($crate::consts::U417);
+(+ 417) => ($crate::consts::P417);
+(- 417) => ($crate::consts::N417);
+(418) => ($crate::consts::U418);
+(+ 418) => ($crate::consts::P418);
+(- 418) => ($crate::consts::N418);
+(419) => ($crate::consts::U419);
+(+ 419) => ($crate::consts::P419);
+(- 419) => ($crate::consts::N419);
+(420) => ($crate::consts::U420);
+(+ 420) => ($crate::consts::P420);
+(- 420) => ($crate::consts::N420);
+(421) => ($crate::consts::U421);
+(+ 421) => ($crate::consts::P421);
+(- 421) => ($crate::consts::N421);
+(422) => ($crate::consts::U422);
+(+ 422) => ($crate::consts::P422);
+(- 422) => ($crate::consts::N422);
+(423) => ($crate::consts::U423);
+(+ 423) => ($crate::consts::P423);
+(- 423) => ($crate::consts::N423);
+(424) => ($crate::consts::U424);
+(+ 424) => ($crate::consts::P424);
+(- 424) => ($crate::consts::N424);
+(425) => ($crate::consts::U425);
+(+ 425) => ($crate::consts::P425);
+(- 425) => ($crate::consts::N425);
+(426) => ($crate::consts::U426);
+(+ 426) => ($crate::consts::P426);
+(- 426) => ($crate::consts::N426);
+(427) => ($crate::consts::U427);
+(+ 427) => ($crate::consts::P427);
+(- 427) => ($crate::consts::N427);
+(428) => ($crate::consts::U428);
+(+ 428) => ($crate::consts::P428);
+(- 428) => ($crate::consts::N428);
+(429) => ($crate::consts::U429);
+(+ 429) => ($crate::consts::P429);
+(- 429) => ($crate::consts::N429);
+(430) => ($crate::consts::U430);
+(+ 430) => ($crate::consts::P430);
+(- 430) => ($crate::consts::N430);
+(431) => ($crate::consts::U431);
+(+ 431) => ($crate::consts::P431);
+(- 431) => ($crate::consts::N431);
+(432) => ($crate::consts::U432);
+(+ 432) => ($crate::consts::P432);
+(- 432) => ($crate::consts::N432);
+(433) => ($crate::consts::U433);
+(+ 433) => ($crate::consts::P433);
+(- 433) => ($crate::consts::N433);
+(434) => ($crate::consts::U434);
+(+ 434) => ($crate::consts::P434);
+(- 434) => ($crate::consts::N434);
+(435) => ($crate::consts::U435);
+(+ 435) => ($crate::consts::P435);
+(- 435) => ($crate::consts::N435);
+(436) => ($crate::consts::U436);
+(+ 436) => ($crate::consts::P436);
+(- 436) => ($crate::consts::N436);
+(437) => ($crate::consts::U437);
+(+ 437) => ($crate::consts::P437);
+(- 437) => ($crate::consts::N437);
+(438) => ($crate::consts::U438);
+(+ 438) => ($crate::consts::P438);
+(- 438) => ($crate::consts::N438);
+(439) => ($crate::consts::U439);
+(+ 439) => ($crate::consts::P439);
+(- 439) => ($crate::consts::N439);
+(440) => ($crate::consts::U440);
+(+ 440) => ($crate::consts::P440);
+(- 440) => ($crate::consts::N440);
+(441) => ($crate::consts::U441);
+(+ 441) => ($crate::consts::P441);
+(- 441) => ($crate::consts::N441);
+(442) => ($crate::consts::U442);
+(+ 442) => ($crate::consts::P442);
+(- 442) => ($crate::consts::N442);
+(443) => ($crate::consts::U443);
+(+ 443) => ($crate::consts::P443);
+(- 443) => ($crate::consts::N443);
+(444) => ($crate::consts::U444);
+(+ 444) => ($crate::consts::P444);
+(- 444) => ($crate::consts::N444);
+(445) => ($crate::consts::U445);
+(+ 445) => ($crate::consts::P445);
+(- 445) => ($crate::consts::N445);
+(446) => ($crate::consts::U446);
+(+ 446) => ($crate::consts::P446);
+(- 446) => ($crate::consts::N446);
+(447) => ($crate::consts::U447);
+(+ 447) => ($crate::consts::P447);
+(- 447) => ($crate::consts::N447);
+(448) => ($crate::consts::U448);
+(+ 448) => ($crate::consts::P448);
+(- 448) => ($crate::consts::N448);
+(449) => ($crate::consts::U449);
+(+ 449) => ($crate::consts::P449);
+(- 449) => ($crate::consts::N449);
+(450) => ($crate::consts::U450);
+(+ 450) => ($crate::consts::P450);
+(- 450) => ($crate::consts::N450);
+(451) => ($crate::consts::U451);
+(+ 451) => ($crate::consts::P451);
+(- 451) => ($crate::consts::N451);
+(452) => ($crate::consts::U452);
+(+ 452) => ($crate::consts::P452);
+(- 452) => ($crate::consts::N452);
+(453) => ($crate::consts::U453);
+(+ 453) => ($crate::consts::P453);
+(- 453) => ($crate::consts::N453);
+(454) => ($crate::consts::U454);
+(+ 454) => ($crate::consts::P454);
+(- 454) => ($crate::consts::N454);
+(455) => ($crate::consts::U455);
+(+ 455) => ($crate::consts::P455);
+(- 455) => ($crate::consts::N455);
+(456) => ($crate::consts::U456);
+(+ 456) => ($crate::consts::P456);
+(- 456) => ($crate::consts::N456);
+(457) => ($crate::consts::U457);
+(+ 457) => ($crate::consts::P457);
+(- 457) => ($crate::consts::N457);
+(458) => ($crate::consts::U458);
+(+ 458) => ($crate::consts::P458);
+(- 458) => ($crate::consts::N458);
+(459) => ($crate::consts::U459);
+(+ 459) => ($crate::consts::P459);
+(- 459) => ($crate::consts::N459);
+(460) => ($crate::consts::U460);
+(+ 460) => ($crate::consts::P460);
+(- 460) => ($crate::consts::N460);
+(461) => ($crate::consts::U461);
+(+ 461) => ($crate::consts::P461);
+(- 461) => ($crate::consts::N461);
+(462) => ($crate::consts::U462);
+(+ 462) => ($crate::consts::P462);
+(- 462) => ($crate::consts::N462);
+(463) => ($crate::consts::U463);
+(+ 463) => ($crate::consts::P463);
+(- 463) => ($crate::consts::N463);
+(464) => ($crate::consts::U464);
+(+ 464) => ($crate::consts::P464);
+(- 464) => ($crate::consts::N464);
+(465) => ($crate::consts::U465);
+(+ 465) => ($crate::consts::P465);
+(- 465) => ($crate::consts::N465);
+(466) => ($crate::consts::U466);
+(+ 466) => ($crate::consts::P466);
+(- 466) => ($crate::consts::N466);
+(467) => ($crate::consts::U467);
+(+ 467) => ($crate::consts::P467);
+(- 467) => ($crate::consts::N467);
+(468) => ($crate::consts::U468);
+(+ 468) => ($crate::consts::P468);
+(- 468) => ($crate::consts::N468);
+(469) => ($crate::consts::U469);
+(+ 469) => ($crate::consts::P469);
+(- 469) => ($crate::consts::N469);
+(470) => ($crate::consts::U470);
+(+ 470) => ($crate::consts::P470);
+(- 470) => ($crate::consts::N470);
+(471) => ($crate::consts::U471);
+(+ 471) => ($crate::consts::P471);
+(- 471) => ($crate::consts::N471);
+(472) => ($crate::consts::U472);
+(+ 472) => ($crate::consts::P472);
+(- 472) => ($crate::consts::N472);
+(473) => ($crate::consts::U473);
+(+ 473) => ($crate::consts::P473);
+(- 473) => ($crate::consts::N473);
+(474) => ($crate::consts::U474);
+(+ 474) => ($crate::consts::P474);
+(- 474) => ($crate::consts::N474);
+(475) => ($crate::consts::U475);
+(+ 475) => ($crate::consts::P475);
+(- 475) => ($crate::consts::N475);
+(476) => ($crate::consts::U476);
+(+ 476) => ($crate::consts::P476);
+(- 476) => ($crate::consts::N476);
+(477) => ($crate::consts::U477);
+(+ 477) => ($crate::consts::P477);
+(- 477) => ($crate::consts::N477);
+(478) => ($crate::consts::U478);
+(+ 478) => ($crate::consts::P478);
+(- 478) => ($crate::consts::N478);
+(479) => ($crate::consts::U479);
+(+ 479) => ($crate::consts::P479);
+(- 479) => ($crate::consts::N479);
+(480) => ($crate::consts::U480);
+(+ 480) => ($crate::consts::P480);
+(- 480) => ($crate::consts::N480);
+(481) => ($crate::consts::U481);
+(+ 481) => ($crate::consts::P481);
+(- 481) => ($crate::consts::N481);
+(482) => ($crate::consts::U482);
+(+ 482) => ($crate::consts::P482);
+(- 482) => ($crate::consts::N482);
+(483) => ($crate::consts::U483);
+(+ 483) => ($crate::consts::P483);
+(- 483) => ($crate::consts::N483);
+(484) => ($crate::consts::U484);
+(+ 484) => ($crate::consts::P484);
+(- 484) => ($crate::consts::N484);
+(485) => ($crate::consts::U485);
+(+ 485) => ($crate::consts::P485);
+(- 485) => ($crate::consts::N485);
+(486) => ($crate::consts::U486);
+(+ 486) => ($crate::consts::P486);
+(- 486) => ($crate::consts::N486);
+(487) => ($crate::consts::U487);
+(+ 487) => ($crate::consts::P487);
+(- 487) => ($crate::consts::N487);
+(488) => ($crate::consts::U488);
+(+ 488) => ($crate::consts::P488);
+(- 488) => ($crate::consts::N488);
+(489) => ($crate::consts::U489);
+(+ 489) => ($crate::consts::P489);
+(- 489) => ($crate::consts::N489);
+(490) => ($crate::consts::U490);
+(+ 490) => ($crate::consts::P490);
+(- 490) => ($crate::consts::N490);
+(491) => ($crate::consts::U491);
+(+ 491) => ($crate::consts::P491);
+(- 491) => ($crate::consts::N491);
+(492) => ($crate::consts::U492);
+(+ 492) => ($crate::consts::P492);
+(- 492) => ($crate::consts::N492);
+(493) => ($crate::consts::U493);
+(+ 493) => ($crate::consts::P493);
+(- 493) => ($crate::consts::N493);
+(494) => ($crate::consts::U494);
+(+ 494) => ($crate::consts::P494);
+(- 494) => ($crate::consts::N494);
+(495) => ($crate::consts::U495);
+(+ 495) => ($crate::consts::P495);
+(- 495) => ($crate::consts::N495);
+(496) => ($crate::consts::U496);
+(+ 496) => ($crate::consts::P496);
+(- 496) => ($crate::consts::N496);
+(497) => ($crate::consts::U497);
+(+ 497) => ($crate::consts::P497);
+(- 497) => ($crate::consts::N497);
+(498) => ($crate::consts::U498);
+(+ 498) => ($crate::consts::P498);
+(- 498) => ($crate::consts::N498);
+(499) => ($crate::consts::U499);
+(+ 499) => ($crate::consts::P499);
+(- 499) => ($crate::consts::N499);
+(500) => ($crate::consts::U500);
+(+ 500) => ($crate::consts::P500);
+(- 500) => ($crate::consts::N500);
+(501) => ($crate::consts::U501);
+(+ 501) => ($crate::consts::P501);
+(- 501) => ($crate::consts::N501);
+(502) => ($crate::consts::U502);
+(+ 502) => ($crate::consts::P502);
+(- 502) => ($crate::consts::N502);
+(503) => ($crate::consts::U503);
+(+ 503) => ($crate::consts::P503);
+(- 503) => ($crate::consts::N503);
+(504) => ($crate::consts::U504);
+(+ 504) => ($crate::consts::P504);
+(- 504) => ($crate::consts::N504);
+(505) => ($crate::consts::U505);
+(+ 505) => ($crate::consts::P505);
+(- 505) => ($crate::consts::N505);
+(506) => ($crate::consts::U506);
+(+ 506) => ($crate::consts::P506);
+(- 506) => ($crate::consts::N506);
+(507) => ($crate::consts::U507);
+(+ 507) => ($crate::consts::P507);
+(- 507) => ($crate::consts::N507);
+(508) => ($crate::consts::U508);
+(+ 508) => ($crate::consts::P508);
+(- 508) => ($crate::consts::N508);
+(509) => ($crate::consts::U509);
+(+ 509) => ($crate::consts::P509);
+(- 509) => ($crate::consts::N509);
+(510) => ($crate::consts::U510);
+(+ 510) => ($crate::consts::P510);
+(- 510) => ($crate::consts::N510);
+(511) => ($crate::consts::U511);
+(+ 511) => ($crate::consts::P511);
+(- 511) => ($crate::consts::N511);
+(512) => ($crate::consts::U512);
+(+ 512) => ($crate::consts::P512);
+(- 512) => ($crate::consts::N512);
+(513) => ($crate::consts::U513);
+(+ 513) => ($crate::consts::P513);
+(- 513) => ($crate::consts::N513);
+(514) => ($crate::consts::U514);
+(+ 514) => ($crate::consts::P514);
+(- 514) => ($crate::consts::N514);
+(515) => ($crate::consts::U515);
+(+ 515) => ($crate::consts::P515);
+(- 515) => ($crate::consts::N515);
+(516) => ($crate::consts::U516);
+(+ 516) => ($crate::consts::P516);
+(- 516) => ($crate::consts::N516);
+(517) => ($crate::consts::U517);
+(+ 517) => ($crate::consts::P517);
+(- 517) => ($crate::consts::N517);
+(518) => ($crate::consts::U518);
+(+ 518) => ($crate::consts::P518);
+(- 518) => ($crate::consts::N518);
+(519) => ($crate::consts::U519);
+(+ 519) => ($crate::consts::P519);
+(- 519) => ($crate::consts::N519);
+(520) => ($crate::consts::U520);
+(+ 520) => ($crate::consts::P520);
+(- 520) => ($crate::consts::N520);
+(521) => ($crate::consts::U521);
+(+ 521) => ($crate::consts::P521);
+(- 521) => ($crate::consts::N521);
+(522) => ($crate::consts::U522);
+(+ 522) => ($crate::consts::P522);
+(- 522) => ($crate::consts::N522);
+(523) => ($crate::consts::U523);
+(+ 523) => ($crate::consts::P523);
+(- 523) => ($crate::consts::N523);
+(524) => ($crate::consts::U524);
+(+ 524) => ($crate::consts::P524);
+(- 524) => ($crate::consts::N524);
+(525) => ($crate::consts::U525);
+(+ 525) => ($crate::consts::P525);
+(- 525) => ($crate::consts::N525);
+(526) => ($crate::consts::U526);
+(+ 526) => ($crate::consts::P526);
+(- 526) => ($crate::consts::N526);
+(527) => ($crate::consts::U527);
+(+ 527) => ($crate::consts::P527);
+(- 527) => ($crate::consts::N527);
+(528) => ($crate::consts::U528);
+(+ 528) => ($crate::consts::P528);
+(- 528) => ($crate::consts::N528);
+(529) => ($crate::consts::U529);
+(+ 529) => ($crate::consts::P529);
+(- 529) => ($crate::consts::N529);
+(530) => ($crate::consts::U530);
+(+ 530) => ($crate::consts::P530);
+(- 530) => ($crate::consts::N530);
+(531) => ($crate::consts::U531);
+(+ 531) => ($crate::consts::P531);
+(- 531) => ($crate::consts::N531);
+(532) => ($crate::consts::U532);
+(+ 532) => ($crate::consts::P532);
+(- 532) => ($crate::consts::N532);
+(533) => ($crate::consts::U533);
+(+ 533) => ($crate::consts::P533);
+(- 533) => ($crate::consts::N533);
+(534) => ($crate::consts::U534);
+(+ 534) => ($crate::consts::P534);
+(- 534) => ($crate::consts::N534);
+(535) => ($crate::consts::U535);
+(+ 535) => ($crate::consts::P535);
+(- 535) => ($crate::consts::N535);
+(536) => ($crate::consts::U536);
+(+ 536) => ($crate::consts::P536);
+(- 536) => ($crate::consts::N536);
+(537) => ($crate::consts::U537);
+(+ 537) => ($crate::consts::P537);
+(- 537) => ($crate::consts::N537);
+(538) => ($crate::consts::U538);
+(+ 538) => ($crate::consts::P538);
+(- 538) => ($crate::consts::N538);
+(539) => ($crate::consts::U539);
+(+ 539) => ($crate::consts::P539);
+(- 539) => ($crate::consts::N539);
+(540) => ($crate::consts::U540);
+(+ 540) => ($crate::consts::P540);
+(- 540) => ($crate::consts::N540);
+(541) => ($crate::consts::U541);
+(+ 541) => ($crate::consts::P541);
+(- 541) => ($crate::consts::N541);
+(542) => ($crate::consts::U542);
+(+ 542) => ($crate::consts::P542);
+(- 542) => ($crate::consts::N542);
+(543) => ($crate::consts::U543);
+(+ 543) => ($crate::consts::P543);
+(- 543) => ($crate::consts::N543);
+(544) => ($crate::consts::U544);
+(+ 544) => ($crate::consts::P544);
+(- 544) => ($crate::consts::N544);
+(545) => ($crate::consts::U545);
+(+ 545) => ($crate::consts::P545);
+(- 545) => ($crate::consts::N545);
+(546) => ($crate::consts::U546);
+(+ 546) => ($crate::consts::P546);
+(- 546) => ($crate::consts::N546);
+(547) => ($crate::consts::U547);
+(+ 547) => ($crate::consts::P547);
+(- 547) => ($crate::consts::N547);
+(548) => ($crate::consts::U548);
+(+ 548) => ($crate::consts::P548);
+(- 548) => ($crate::consts::N548);
+(549) => ($crate::consts::U549);
+(+ 549) => ($crate::consts::P549);
+(- 549) => ($crate::consts::N549);
+(550) => ($crate::consts::U550);
+(+ 550) => ($crate::consts::P550);
+(- 550) => ($crate::consts::N550);
+(551) => ($crate::consts::U551);
+(+ 551) => ($crate::consts::P551);
+(- 551) => ($crate::consts::N551);
+(552) => ($crate::consts::U552);
+(+ 552) => ($crate::consts::P552);
+(- 552) => ($crate::consts::N552);
+(553) => ($crate::consts::U553);
+(+ 553) => ($crate::consts::P553);
+(- 553) => ($crate::consts::N553);
+(554) => ($crate::consts::U554);
+(+ 554) => ($crate::consts::P554);
+(- 554) => ($crate::consts::N554);
+(555) => ($crate::consts::U555);
+(+ 555) => ($crate::consts::P555);
+(- 555) => ($crate::consts::N555);
+(556) => ($crate::consts::U556);
+(+ 556) => ($crate::consts::P556);
+(- 556) => ($crate::consts::N556);
+(557) => ($crate::consts::U557);
+(+ 557) => ($crate::consts::P557);
+(- 557) => ($crate::consts::N557);
+(558) => ($crate::consts::U558);
+(+ 558) => ($crate::consts::P558);
+(- 558) => ($crate::consts::N558);
+(559) => ($crate::consts::U559);
+(+ 559) => ($crate::consts::P559);
+(- 559) => ($crate::consts::N559);
+(560) => ($crate::consts::U560);
+(+ 560) => ($crate::consts::P560);
+(- 560) => ($crate::consts::N560);
+(561) => ($crate::consts::U561);
+(+ 561) => ($crate::consts::P561);
+(- 561) => ($crate::consts::N561);
+(562) => ($crate::consts::U562);
+(+ 562) => ($crate::consts::P562);
+(- 562) => ($crate::consts::N562);
+(563) => ($crate::consts::U563);
+(+ 563) => ($crate::consts::P563);
+(- 563) => ($crate::consts::N563);
+(564) => ($crate::consts::U564);
+(+ 564) => ($crate::consts::P564);
+(- 564) => ($crate::consts::N564);
+(565) => ($crate::consts::U565);
+(+ 565) => ($crate::consts::P565);
+(- 565) => ($crate::consts::N565);
+(566) => ($crate::consts::U566);
+(+ 566) => ($crate::consts::P566);
+(- 566) => ($crate::consts::N566);
+(567) => ($crate::consts::U567);
+(+ 567) => ($crate::consts::P567);
+(- 567) => ($crate::consts::N567);
+(568) => ($crate::consts::U568);
+(+ 568) => ($crate::consts::P568);
+(- 568) => ($crate::consts::N568);
+(569) => ($crate::consts::U569);
+(+ 569) => ($crate::consts::P569);
+(- 569) => ($crate::consts::N569);
+(570) => ($crate::consts::U570);
+(+ 570) => ($crate::consts::P570);
+(- 570) => ($crate::consts::N570);
+(571) => ($crate::consts::U571);
+(+ 571) => ($crate::consts::P571);
+(- 571) => ($crate::consts::N571);
+(572) => ($crate::consts::U572);
+(+ 572) => ($crate::consts::P572);
+(- 572) => ($crate::consts::N572);
+(573) => ($crate::consts::U573);
+(+ 573) => ($crate::consts::P573);
+(- 573) => ($crate::consts::N573);
+(574) => ($crate::consts::U574);
+(+ 574) => ($crate::consts::P574);
+(- 574) => ($crate::consts::N574);
+(575) => ($crate::consts::U575);
+(+ 575) => ($crate::consts::P575);
+(- 575) => ($crate::consts::N575);
+(576) => ($crate::consts::U576);
+(+ 576) => ($crate::consts::P576);
+(- 576) => ($crate::consts::N576);
+(577) => ($crate::consts::U577);
+(+ 577) => ($crate::consts::P577);
+(- 577) => ($crate::consts::N577);
+(578) => ($crate::consts::U578);
+(+ 578) => ($crate::consts::P578);
+(- 578) => ($crate::consts::N578);
+(579) => ($crate::consts::U579);
+(+ 579) => ($crate::consts::P579);
+(- 579) => ($crate::consts::N579);
+(580) => ($crate::consts::U580);
+(+ 580) => ($crate::consts::P580);
+(- 580) => ($crate::consts::N580);
+(581) => ($crate::consts::U581);
+(+ 581) => ($crate::consts::P581);
+(- 581) => ($crate::consts::N581);
+(582) => ($crate::consts::U582);
+(+ 582) => ($crate::consts::P582);
+(- 582) => ($crate::consts::N582);
+(583) => ($crate::consts::U583);
+(+ 583) => ($crate::consts::P583);
+(- 583) => ($crate::consts::N583);
+(584) => ($crate::consts::U584);
+(+ 584) => ($crate::consts::P584);
+(- 584) => ($crate::consts::N584);
+(585) => ($crate::consts::U585);
+(+ 585) => ($crate::consts::P585);
+(- 585) => ($crate::consts::N585);
+(586) => ($crate::consts::U586);
+(+ 586) => ($crate::consts::P586);
+(- 586) => ($crate::consts::N586);
+(587) => ($crate::consts::U587);
+(+ 587) => ($crate::consts::P587);
+(- 587) => ($crate::consts::N587);
+(588) => ($crate::consts::U588);
+(+ 588) => ($crate::consts::P588);
+(- 588) => ($crate::consts::N588);
+(589) => ($crate::consts::U589);
+(+ 589) => ($crate::consts::P589);
+(- 589) => ($crate::consts::N589);
+(590) => ($crate::consts::U590);
+(+ 590) => ($crate::consts::P590);
+(- 590) => ($crate::consts::N590);
+(591) => ($crate::consts::U591);
+(+ 591) => ($crate::consts::P591);
+(- 591) => ($crate::consts::N591);
+(592) => ($crate::consts::U592);
+(+ 592) => ($crate::consts::P592);
+(- 592) => ($crate::consts::N592);
+(593) => ($crate::consts::U593);
+(+ 593) => ($crate::consts::P593);
+(- 593) => ($crate::consts::N593);
+(594) => ($crate::consts::U594);
+(+ 594) => ($crate::consts::P594);
+(- 594) => ($crate::consts::N594);
+(595) => ($crate::consts::U595);
+(+ 595) => ($crate::consts::P595);
+(- 595) => ($crate::consts::N595);
+(596) => ($crate::consts::U596);
+(+ 596) => ($crate::consts::P596);
+(- 596) => ($crate::consts::N596);
+(597) => ($crate::consts::U597);
+(+ 597) => ($crate::consts::P597);
+(- 597) => ($crate::consts::N597);
+(598) => ($crate::consts::U598);
+(+ 598) => ($crate::consts::P598);
+(- 598) => ($crate::consts::N598);
+(599) => ($crate::consts::U599);
+(+ 599) => ($crate::consts::P599);
+(- 599) => ($crate::consts::N599);
+(600) => ($crate::consts::U600);
+(+ 600) => ($crate::consts::P600);
+(- 600) => ($crate::consts::N600);
+(601) => ($crate::consts::U601);
+(+ 601) => ($crate::consts::P601);
+(- 601) => ($crate::consts::N601);
+(602) => ($crate::consts::U602);
+(+ 602) => ($crate::consts::P602);
+(- 602) => ($crate::consts::N602);
+(603) => ($crate::consts::U603);
+(+ 603) => ($crate::consts::P603);
+(- 603) => ($crate::consts::N603);
+(604) => ($crate::consts::U604);
+(+ 604) => ($crate::consts::P604);
+(- 604) => ($crate::consts::N604);
+(605) => ($crate::consts::U605);
+(+ 605) => ($crate::consts::P605);
+(- 605) => ($crate::consts::N605);
+(606) => ($crate::consts::U606);
+(+ 606) => ($crate::consts::P606);
+(- 606) => ($crate::consts::N606);
+(607) => ($crate::consts::U607);
+(+ 607) => ($crate::consts::P607);
+(- 607) => ($crate::consts::N607);
+(608) => ($crate::consts::U608);
+(+ 608) => ($crate::consts::P608);
+(- 608) => ($crate::consts::N608);
+(609) => ($crate::consts::U609);
+(+ 609) => ($crate::consts::P609);
+(- 609) => ($crate::consts::N609);
+(610) => ($crate::consts::U610);
+(+ 610) => ($crate::consts::P610);
+(- 610) => ($crate::consts::N610);
+(611) => ($crate::consts::U611);
+(+ 611) => ($crate::consts::P611);
+(- 611) => ($crate::consts::N611);
+(612) => ($crate::consts::U612);
+(+ 612) => ($crate::consts::P612);
+(- 612) => ($crate::consts::N612);
+(613) => ($crate::consts::U613);
+(+ 613) => ($crate::consts::P613);
+(- 613) => ($crate::consts::N613);
+(614) => ($crate::consts::U614);
+(+ 614) => ($crate::consts::P614);
+(- 614) => ($crate::consts::N614);
+(615) => ($crate::consts::U615);
+(+ 615) => ($crate::consts::P615);
+(- 615) => ($crate::consts::N615);
+(616) => ($crate::consts::U616);
+(+ 616) => ($crate::consts::P616);
+(- 616) => ($crate::consts::N616);
+(617) => ($crate::consts::U617);
+(+ 617) => ($crate::consts::P617);
+(- 617) => ($crate::consts::N617);
+(618) => ($crate::consts::U618);
+(+ 618) => ($crate::consts::P618);
+(- 618) => ($crate::consts::N618);
+(619) => ($crate::consts::U619);
+(+ 619) => ($crate::consts::P619);
+(- 619) => ($crate::consts::N619);
+(620) => ($crate::consts::U620);
+(+ 620) => ($crate::consts::P620);
+(- 620) => ($crate::consts::N620);
+(621) => ($crate::consts::U621);
+(+ 621) => ($crate::consts::P621);
+(- 621) => ($crate::consts::N621);
+(622) => ($crate::consts::U622);
+(+ 622) => ($crate::consts::P622);
+(- 622) => ($crate::consts::N622);
+(623) => ($crate::consts::U623);
+(+ 623) => ($crate::consts::P623);
+(- 623) => ($crate::consts::N623);
+(624) => ($crate::consts::U624);
+(+ 624) => ($crate::consts::P624);
+(- 624) => ($crate::consts::N624);
+(625) => ($crate::consts::U625);
+(+ 625) => ($crate::consts::P625);
+(- 625) => ($crate::consts::N625);
+(626) => ($crate::consts::U626);
+(+ 626) => ($crate::consts::P626);
+(- 626) => ($crate::consts::N626);
+(627) => ($crate::consts::U627);
+(+ 627) => ($crate::consts::P627);
+(- 627) => ($crate::consts::N627);
+(628) => ($crate::consts::U628);
+(+ 628) => ($crate::consts::P628);
+(- 628) => ($crate::consts::N628);
+(629) => ($crate::consts::U629);
+(+ 629) => ($crate::consts::P629);
+(- 629) => ($crate::consts::N629);
+(630) => ($crate::consts::U630);
+(+ 630) => ($crate::consts::P630);
+(- 630) => ($crate::consts::N630);
+(631) => ($crate::consts::U631);
+(+ 631) => ($crate::consts::P631);
+(- 631) => ($crate::consts::N631);
+(632) => ($crate::consts::U632);
+(+ 632) => ($crate::consts::P632);
+(- 632) => ($crate::consts::N632);
+(633) => ($crate::consts::U633);
+(+ 633) => ($crate::consts::P633);
+(- 633) => ($crate::consts::N633);
+(634) => ($crate::consts::U634);
+(+ 634) => ($crate::consts::P634);
+(- 634) => ($crate::consts::N634);
+(635) => ($crate::consts::U635);
+(+ 635) => ($crate::consts::P635);
+(- 635) => ($crate::consts::N635);
+(636) => ($crate::consts::U636);
+(+ 636) => ($crate::consts::P636);
+(- 636) => ($crate::consts::N636);
+(637) => ($crate::consts::U637);
+(+ 637) => ($crate::consts::P637);
+(- 637) => ($crate::consts::N637);
+(638) => ($crate::consts::U638);
+(+ 638) => ($crate::consts::P638);
+(- 638) => ($crate::consts::N638);
+(639) => ($crate::consts::U639);
+(+ 639) => ($crate::consts::P639);
+(- 639) => ($crate::consts::N639);
+(640) => ($crate::consts::U640);
+(+ 640) => ($crate::consts::P640);
+(- 640) => ($crate::consts::N640);
+(641) => ($crate::consts::U641);
+(+ 641) => ($crate::consts::P641);
+(- 641) => ($crate::consts::N641);
+(642) => ($crate::consts::U642);
+(+ 642) => ($crate::consts::P642);
+(- 642) => ($crate::consts::N642);
+(643) => ($crate::consts::U643);
+(+ 643) => ($crate::consts::P643);
+(- 643) => ($crate::consts::N643);
+(644) => ($crate::consts::U644);
+(+ 644) => ($crate::consts::P644);
+(- 644) => ($crate::consts::N644);
+(645) => ($crate::consts::U645);
+(+ 645) => ($crate::consts::P645);
+(- 645) => ($crate::consts::N645);
+(646) => ($crate::consts::U646);
+(+ 646) => ($crate::consts::P646);
+(- 646) => ($crate::consts::N646);
+(647) => ($crate::consts::U647);
+(+ 647) => ($crate::consts::P647);
+(- 647) => ($crate::consts::N647);
+(648) => ($crate::consts::U648);
+(+ 648) => ($crate::consts::P648);
+(- 648) => ($crate::consts::N648);
+(649) => ($crate::consts::U649);
+(+ 649) => ($crate::consts::P649);
+(- 649) => ($crate::consts::N649);
+(650) => ($crate::consts::U650);
+(+ 650) => ($crate::consts::P650);
+(- 650) => ($crate::consts::N650);
+(651) => ($crate::consts::U651);
+(+ 651) => ($crate::consts::P651);
+(- 651) => ($crate::consts::N651);
+(652) => ($crate::consts::U652);
+(+ 652) => ($crate::consts::P652);
+(- 652) => ($crate::consts::N652);
+(653) => ($crate::consts::U653);
+(+ 653) => ($crate::consts::P653);
+(- 653) => ($crate::consts::N653);
+(654) => ($crate::consts::U654);
+(+ 654) => ($crate::consts::P654);
+(- 654) => ($crate::consts::N654);
+(655) => ($crate::consts::U655);
+(+ 655) => ($crate::consts::P655);
+(- 655) => ($crate::consts::N655);
+(656) => ($crate::consts::U656);
+(+ 656) => ($crate::consts::P656);
+(- 656) => ($crate::consts::N656);
+(657) => ($crate::consts::U657);
+(+ 657) => ($crate::consts::P657);
+(- 657) => ($crate::consts::N657);
+(658) => ($crate::consts::U658);
+(+ 658) => ($crate::consts::P658);
+(- 658) => ($crate::consts::N658);
+(659) => ($crate::consts::U659);
+(+ 659) => ($crate::consts::P659);
+(- 659) => ($crate::consts::N659);
+(660) => ($crate::consts::U660);
+(+ 660) => ($crate::consts::P660);
+(- 660) => ($crate::consts::N660);
+(661) => ($crate::consts::U661);
+(+ 661) => ($crate::consts::P661);
+(- 661) => ($crate::consts::N661);
+(662) => ($crate::consts::U662);
+(+ 662) => ($crate::consts::P662);
+(- 662) => ($crate::consts::N662);
+(663) => ($crate::consts::U663);
+(+ 663) => ($crate::consts::P663);
+(- 663) => ($crate::consts::N663);
+(664) => ($crate::consts::U664);
+(+ 664) => ($crate::consts::P664);
+(- 664) => ($crate::consts::N664);
+(665) => ($crate::consts::U665);
+(+ 665) => ($crate::consts::P665);
+(- 665) => ($crate::consts::N665);
+(666) => ($crate::consts::U666);
+(+ 666) => ($crate::consts::P666);
+(- 666) => ($crate::consts::N666);
+(667) => ($crate::consts::U667);
+(+ 667) => ($crate::consts::P667);
+(- 667) => ($crate::consts::N667);
+(668) => ($crate::consts::U668);
+(+ 668) => ($crate::consts::P668);
+(- 668) => ($crate::consts::N668);
+(669) => ($crate::consts::U669);
+(+ 669) => ($crate::consts::P669);
+(- 669) => ($crate::consts::N669);
+(670) => ($crate::consts::U670);
+(+ 670) => ($crate::consts::P670);
+(- 670) => ($crate::consts::N670);
+(671) => ($crate::consts::U671);
+(+ 671) => ($crate::consts::P671);
+(- 671) => ($crate::consts::N671);
+(672) => ($crate::consts::U672);
+(+ 672) => ($crate::consts::P672);
+(- 672) => ($crate::consts::N672);
+(673) => ($crate::consts::U673);
+(+ 673) => ($crate::consts::P673);
+(- 673) => ($crate::consts::N673);
+(674) => ($crate::consts::U674);
+(+ 674) => ($crate::consts::P674);
+(- 674) => ($crate::consts::N674);
+(675) => ($crate::consts::U675);
+(+ 675) => ($crate::consts::P675);
+(- 675) => ($crate::consts::N675);
+(676) => ($crate::consts::U676);
+(+ 676) => ($crate::consts::P676);
+(- 676) => ($crate::consts::N676);
+(677) => ($crate::consts::U677);
+(+ 677) => ($crate::consts::P677);
+(- 677) => ($crate::consts::N677);
+(678) => ($crate::consts::U678);
+(+ 678) => ($crate::consts::P678);
+(- 678) => ($crate::consts::N678);
+(679) => ($crate::consts::U679);
+(+ 679) => ($crate::consts::P679);
+(- 679) => ($crate::consts::N679);
+(680) => ($crate::consts::U680);
+(+ 680) => ($crate::consts::P680);
+(- 680) => ($crate::consts::N680);
+(681) => ($crate::consts::U681);
+(+ 681) => ($crate::consts::P681);
+(- 681) => ($crate::consts::N681);
+(682) => ($crate::consts::U682);
+(+ 682) => ($crate::consts::P682);
+(- 682) => ($crate::consts::N682);
+(683) => ($crate::consts::U683);
+(+ 683) => ($crate::consts::P683);
+(- 683) => ($crate::consts::N683);
+(684) => ($crate::consts::U684);
+(+ 684) => ($crate::consts::P684);
+(- 684) => ($crate::consts::N684);
+(685) => ($crate::consts::U685);
+(+ 685) => ($crate::consts::P685);
+(- 685) => ($crate::consts::N685);
+(686) => ($crate::consts::U686);
+(+ 686) => ($crate::consts::P686);
+(- 686) => ($crate::consts::N686);
+(687) => ($crate::consts::U687);
+(+ 687) => ($crate::consts::P687);
+(- 687) => ($crate::consts::N687);
+(688) => ($crate::consts::U688);
+(+ 688) => ($crate::consts::P688);
+(- 688) => ($crate::consts::N688);
+(689) => ($crate::consts::U689);
+(+ 689) => ($crate::consts::P689);
+(- 689) => ($crate::consts::N689);
+(690) => ($crate::consts::U690);
+(+ 690) => ($crate::consts::P690);
+(- 690) => ($crate::consts::N690);
+(691) => ($crate::consts::U691);
+(+ 691) => ($crate::consts::P691);
+(- 691) => ($crate::consts::N691);
+(692) => ($crate::consts::U692);
+(+ 692) => ($crate::consts::P692);
+(- 692) => ($crate::consts::N692);
+(693) => ($crate::consts::U693);
+(+ 693) => ($crate::consts::P693);
+(- 693) => ($crate::consts::N693);
+(694) => ($crate::consts::U694);
+(+ 694) => ($crate::consts::P694);
+(- 694) => ($crate::consts::N694);
+(695) => ($crate::consts::U695);
+(+ 695) => ($crate::consts::P695);
+(- 695) => ($crate::consts::N695);
+(696) => ($crate::consts::U696);
+(+ 696) => ($crate::consts::P696);
+(- 696) => ($crate::consts::N696);
+(697) => ($crate::consts::U697);
+(+ 697) => ($crate::consts::P697);
+(- 697) => ($crate::consts::N697);
+(698) => ($crate::consts::U698);
+(+ 698) => ($crate::consts::P698);
+(- 698) => ($crate::consts::N698);
+(699) => ($crate::consts::U699);
+(+ 699) => ($crate::consts::P699);
+(- 699) => ($crate::consts::N699);
+(700) => ($crate::consts::U700);
+(+ 700) => ($crate::consts::P700);
+(- 700) => ($crate::consts::N700);
+(701) => ($crate::consts::U701);
+(+ 701) => ($crate::consts::P701);
+(- 701) => ($crate::consts::N701);
+(702) => ($crate::consts::U702);
+(+ 702) => ($crate::consts::P702);
+(- 702) => ($crate::consts::N702);
+(703) => ($crate::consts::U703);
+(+ 703) => ($crate::consts::P703);
+(- 703) => ($crate::consts::N703);
+(704) => ($crate::consts::U704);
+(+ 704) => ($crate::consts::P704);
+(- 704) => ($crate::consts::N704);
+(705) => ($crate::consts::U705);
+(+ 705) => ($crate::consts::P705);
+(- 705) => ($crate::consts::N705);
+(706) => ($crate::consts::U706);
+(+ 706) => ($crate::consts::P706);
+(- 706) => ($crate::consts::N706);
+(707) => ($crate::consts::U707);
+(+ 707) => ($crate::consts::P707);
+(- 707) => ($crate::consts::N707);
+(708) => ($crate::consts::U708);
+(+ 708) => ($crate::consts::P708);
+(- 708) => ($crate::consts::N708);
+(709) => ($crate::consts::U709);
+(+ 709) => ($crate::consts::P709);
+(- 709) => ($crate::consts::N709);
+(710) => ($crate::consts::U710);
+(+ 710) => ($crate::consts::P710);
+(- 710) => ($crate::consts::N710);
+(711) => ($crate::consts::U711);
+(+ 711) => ($crate::consts::P711);
+(- 711) => ($crate::consts::N711);
+(712) => ($crate::consts::U712);
+(+ 712) => ($crate::consts::P712);
+(- 712) => ($crate::consts::N712);
+(713) => ($crate::consts::U713);
+(+ 713) => ($crate::consts::P713);
+(- 713) => ($crate::consts::N713);
+(714) => ($crate::consts::U714);
+(+ 714) => ($crate::consts::P714);
+(- 714) => ($crate::consts::N714);
+(715) => ($crate::consts::U715);
+(+ 715) => ($crate::consts::P715);
+(- 715) => ($crate::consts::N715);
+(716) => ($crate::consts::U716);
+(+ 716) => ($crate::consts::P716);
+(- 716) => ($crate::consts::N716);
+(717) => ($crate::consts::U717);
+(+ 717) => ($crate::consts::P717);
+(- 717) => ($crate::consts::N717);
+(718) => ($crate::consts::U718);
+(+ 718) => ($crate::consts::P718);
+(- 718) => ($crate::consts::N718);
+(719) => ($crate::consts::U719);
+(+ 719) => ($crate::consts::P719);
+(- 719) => ($crate::consts::N719);
+(720) => ($crate::consts::U720);
+(+ 720) => ($crate::consts::P720);
+(- 720) => ($crate::consts::N720);
+(721) => ($crate::consts::U721);
+(+ 721) => ($crate::consts::P721);
+(- 721) => ($crate::consts::N721);
+(722) => ($crate::consts::U722);
+(+ 722) => ($crate::consts::P722);
+(- 722) => ($crate::consts::N722);
+(723) => ($crate::consts::U723);
+(+ 723) => ($crate::consts::P723);
+(- 723) => ($crate::consts::N723);
+(724) => ($crate::consts::U724);
+(+ 724) => ($crate::consts::P724);
+(- 724) => ($crate::consts::N724);
+(725) => ($crate::consts::U725);
+(+ 725) => ($crate::consts::P725);
+(- 725) => ($crate::consts::N725);
+(726) => ($crate::consts::U726);
+(+ 726) => ($crate::consts::P726);
+(- 726) => ($crate::consts::N726);
+(727) => ($crate::consts::U727);
+(+ 727) => ($crate::consts::P727);
+(- 727) => ($crate::consts::N727);
+(728) => ($crate::consts::U728);
+(+ 728) => ($crate::consts::P728);
+(- 728) => ($crate::consts::N728);
+(729) => ($crate::consts::U729);
+(+ 729) => ($crate::consts::P729);
+(- 729) => ($crate::consts::N729);
+(730) => ($crate::consts::U730);
+(+ 730) => ($crate::consts::P730);
+(- 730) => ($crate::consts::N730);
+(731) => ($crate::consts::U731);
+(+ 731) => ($crate::consts::P731);
+(- 731) => ($crate::consts::N731);
+(732) => ($crate::consts::U732);
+(+ 732) => ($crate::consts::P732);
+(- 732) => ($crate::consts::N732);
+(733) => ($crate::consts::U733);
+(+ 733) => ($crate::consts::P733);
+(- 733) => ($crate::consts::N733);
+(734) => ($crate::consts::U734);
+(+ 734) => ($crate::consts::P734);
+(- 734) => ($crate::consts::N734);
+(735) => ($crate::consts::U735);
+(+ 735) => ($crate::consts::P735);
+(- 735) => ($crate::consts::N735);
+(736) => ($crate::consts::U736);
+(+ 736) => ($crate::consts::P736);
+(- 736) => ($crate::consts::N736);
+(737) => ($crate::consts::U737);
+(+ 737) => ($crate::consts::P737);
+(- 737) => ($crate::consts::N737);
+(738) => ($crate::consts::U738);
+(+ 738) => ($crate::consts::P738);
+(- 738) => ($crate::consts::N738);
+(739) => ($crate::consts::U739);
+(+ 739) => ($crate::consts::P739);
+(- 739) => ($crate::consts::N739);
+(740) => ($crate::consts::U740);
+(+ 740) => ($crate::consts::P740);
+(- 740) => ($crate::consts::N740);
+(741) => ($crate::consts::U741);
+(+ 741) => ($crate::consts::P741);
+(- 741) => ($crate::consts::N741);
+(742) => ($crate::consts::U742);
+(+ 742) => ($crate::consts::P742);
+(- 742) => ($crate::consts::N742);
+(743) => ($crate::consts::U743);
+(+ 743) => ($crate::consts::P743);
+(- 743) => ($crate::consts::N743);
+(744) => ($crate::consts::U744);
+(+ 744) => ($crate::consts::P744);
+(- 744) => ($crate::consts::N744);
+(745) => ($crate::consts::U745);
+(+ 745) => ($crate::consts::P745);
+(- 745) => ($crate::consts::N745);
+(746) => ($crate::consts::U746);
+(+ 746) => ($crate::consts::P746);
+(- 746) => ($crate::consts::N746);
+(747) => ($crate::consts::U747);
+(+ 747) => ($crate::consts::P747);
+(- 747) => ($crate::consts::N747);
+(748) => ($crate::consts::U748);
+(+ 748) => ($crate::consts::P748);
+(- 748) => ($crate::consts::N748);
+(749) => ($crate::consts::U749);
+(+ 749) => ($crate::consts::P749);
+(- 749) => ($crate::consts::N749);
+(750) => ($crate::consts::U750);
+(+ 750) => ($crate::consts::P750);
+(- 750) => ($crate::consts::N750);
+(751) => ($crate::consts::U751);
+(+ 751) => ($crate::consts::P751);
+(- 751) => ($crate::consts::N751);
+(752) => ($crate::consts::U752);
+(+ 752) => ($crate::consts::P752);
+(- 752) => ($crate::consts::N752);
+(753) => ($crate::consts::U753);
+(+ 753) => ($crate::consts::P753);
+(- 753) => ($crate::consts::N753);
+(754) => ($crate::consts::U754);
+(+ 754) => ($crate::consts::P754);
+(- 754) => ($crate::consts::N754);
+(755) => ($crate::consts::U755);
+(+ 755) => ($crate::consts::P755);
+(- 755) => ($crate::consts::N755);
+(756) => ($crate::consts::U756);
+(+ 756) => ($crate::consts::P756);
+(- 756) => ($crate::consts::N756);
+(757) => ($crate::consts::U757);
+(+ 757) => ($crate::consts::P757);
+(- 757) => ($crate::consts::N757);
+(758) => ($crate::consts::U758);
+(+ 758) => ($crate::consts::P758);
+(- 758) => ($crate::consts::N758);
+(759) => ($crate::consts::U759);
+(+ 759) => ($crate::consts::P759);
+(- 759) => ($crate::consts::N759);
+(760) => ($crate::consts::U760);
+(+ 760) => ($crate::consts::P760);
+(- 760) => ($crate::consts::N760);
+(761) => ($crate::consts::U761);
+(+ 761) => ($crate::consts::P761);
+(- 761) => ($crate::consts::N761);
+(762) => ($crate::consts::U762);
+(+ 762) => ($crate::consts::P762);
+(- 762) => ($crate::consts::N762);
+(763) => ($crate::consts::U763);
+(+ 763) => ($crate::consts::P763);
+(- 763) => ($crate::consts::N763);
+(764) => ($crate::consts::U764);
+(+ 764) => ($crate::consts::P764);
+(- 764) => ($crate::consts::N764);
+(765) => ($crate::consts::U765);
+(+ 765) => ($crate::consts::P765);
+(- 765) => ($crate::consts::N765);
+(766) => ($crate::consts::U766);
+(+ 766) => ($crate::consts::P766);
+(- 766) => ($crate::consts::N766);
+(767) => ($crate::consts::U767);
+(+ 767) => ($crate::consts::P767);
+(- 767) => ($crate::consts::N767);
+(768) => ($crate::consts::U768);
+(+ 768) => ($crate::consts::P768);
+(- 768) => ($crate::consts::N768);
+(769) => ($crate::consts::U769);
+(+ 769) => ($crate::consts::P769);
+(- 769) => ($crate::consts::N769);
+(770) => ($crate::consts::U770);
+(+ 770) => ($crate::consts::P770);
+(- 770) => ($crate::consts::N770);
+(771) => ($crate::consts::U771);
+(+ 771) => ($crate::consts::P771);
+(- 771) => ($crate::consts::N771);
+(772) => ($crate::consts::U772);
+(+ 772) => ($crate::consts::P772);
+(- 772) => ($crate::consts::N772);
+(773) => ($crate::consts::U773);
+(+ 773) => ($crate::consts::P773);
+(- 773) => ($crate::consts::N773);
+(774) => ($crate::consts::U774);
+(+ 774) => ($crate::consts::P774);
+(- 774) => ($crate::consts::N774);
+(775) => ($crate::consts::U775);
+(+ 775) => ($crate::consts::P775);
+(- 775) => ($crate::consts::N775);
+(776) => ($crate::consts::U776);
+(+ 776) => ($crate::consts::P776);
+(- 776) => ($crate::consts::N776);
+(777) => ($crate::consts::U777);
+(+ 777) => ($crate::consts::P777);
+(- 777) => ($crate::consts::N777);
+(778) => ($crate::consts::U778);
+(+ 778) => ($crate::consts::P778);
+(- 778) => ($crate::consts::N778);
+(779) => ($crate::consts::U779);
+(+ 779) => ($crate::consts::P779);
+(- 779) => ($crate::consts::N779);
+(780) => ($crate::consts::U780);
+(+ 780) => ($crate::consts::P780);
+(- 780) => ($crate::consts::N780);
+(781) => ($crate::consts::U781);
+(+ 781) => ($crate::consts::P781);
+(- 781) => ($crate::consts::N781);
+(782) => ($crate::consts::U782);
+(+ 782) => ($crate::consts::P782);
+(- 782) => ($crate::consts::N782);
+(783) => ($crate::consts::U783);
+(+ 783) => ($crate::consts::P783);
+(- 783) => ($crate::consts::N783);
+(784) => ($crate::consts::U784);
+(+ 784) => ($crate::consts::P784);
+(- 784) => ($crate::consts::N784);
+(785) => ($crate::consts::U785);
+(+ 785) => ($crate::consts::P785);
+(- 785) => ($crate::consts::N785);
+(786) => ($crate::consts::U786);
+(+ 786) => ($crate::consts::P786);
+(- 786) => ($crate::consts::N786);
+(787) => ($crate::consts::U787);
+(+ 787) => ($crate::consts::P787);
+(- 787) => ($crate::consts::N787);
+(788) => ($crate::consts::U788);
+(+ 788) => ($crate::consts::P788);
+(- 788) => ($crate::consts::N788);
+(789) => ($crate::consts::U789);
+(+ 789) => ($crate::consts::P789);
+(- 789) => ($crate::consts::N789);
+(790) => ($crate::consts::U790);
+(+ 790) => ($crate::consts::P790);
+(- 790) => ($crate::consts::N790);
+(791) => ($crate::consts::U791);
+(+ 791) => ($crate::consts::P791);
+(- 791) => ($crate::consts::N791);
+(792) => ($crate::consts::U792);
+(+ 792) => ($crate::consts::P792);
+(- 792) => ($crate::consts::N792);
+(793) => ($crate::consts::U793);
+(+ 793) => ($crate::consts::P793);
+(- 793) => ($crate::consts::N793);
+(794) => ($crate::consts::U794);
+(+ 794) => ($crate::consts::P794);
+(- 794) => ($crate::consts::N794);
+(795) => ($crate::consts::U795);
+(+ 795) => ($crate::consts::P795);
+(- 795) => ($crate::consts::N795);
+(796) => ($crate::consts::U796);
+(+ 796) => ($crate::consts::P796);
+(- 796) => ($crate::consts::N796);
+(797) => ($crate::consts::U797);
+(+ 797) => ($crate::consts::P797);
+(- 797) => ($crate::consts::N797);
+(798) => ($crate::consts::U798);
+(+ 798) => ($crate::consts::P798);
+(- 798) => ($crate::consts::N798);
+(799) => ($crate::consts::U799);
+(+ 799) => ($crate::consts::P799);
+(- 799) => ($crate::consts::N799);
+(800) => ($crate::consts::U800);
+(+ 800) => ($crate::consts::P800);
+(- 800) => ($crate::consts::N800);
+(801) => ($crate::consts::U801);
+(+ 801) => ($crate::consts::P801);
+(- 801) => ($crate::consts::N801);
+(802) => ($crate::consts::U802);
+(+ 802) => ($crate::consts::P802);
+(- 802) => ($crate::consts::N802);
+(803) => ($crate::consts::U803);
+(+ 803) => ($crate::consts::P803);
+(- 803) => ($crate::consts::N803);
+(804) => ($crate::consts::U804);
+(+ 804) => ($crate::consts::P804);
+(- 804) => ($crate::consts::N804);
+(805) => ($crate::consts::U805);
+(+ 805) => ($crate::consts::P805);
+(- 805) => ($crate::consts::N805);
+(806) => ($crate::consts::U806);
+(+ 806) => ($crate::consts::P806);
+(- 806) => ($crate::consts::N806);
+(807) => ($crate::consts::U807);
+(+ 807) => ($crate::consts::P807);
+(- 807) => ($crate::consts::N807);
+(808) => ($crate::consts::U808);
+(+ 808) => ($crate::consts::P808);
+(- 808) => ($crate::consts::N808);
+(809) => ($crate::consts::U809);
+(+ 809) => ($crate::consts::P809);
+(- 809) => ($crate::consts::N809);
+(810) => ($crate::consts::U810);
+(+ 810) => ($crate::consts::P810);
+(- 810) => ($crate::consts::N810);
+(811) => ($crate::consts::U811);
+(+ 811) => ($crate::consts::P811);
+(- 811) => ($crate::consts::N811);
+(812) => ($crate::consts::U812);
+(+ 812) => ($crate::consts::P812);
+(- 812) => ($crate::consts::N812);
+(813) => ($crate::consts::U813);
+(+ 813) => ($crate::consts::P813);
+(- 813) => ($crate::consts::N813);
+(814) => ($crate::consts::U814);
+(+ 814) => ($crate::consts::P814);
+(- 814) => ($crate::consts::N814);
+(815) => ($crate::consts::U815);
+(+ 815) => ($crate::consts::P815);
+(- 815) => ($crate::consts::N815);
+(816) => ($crate::consts::U816);
+(+ 816) => ($crate::consts::P816);
+(- 816) => ($crate::consts::N816);
+(817) => ($crate::consts::U817);
+(+ 817) => ($crate::consts::P817);
+(- 817) => ($crate::consts::N817);
+(818) => ($crate::consts::U818);
+(+ 818) => ($crate::consts::P818);
+(- 818) => ($crate::consts::N818);
+(819) => ($crate::consts::U819);
+(+ 819) => ($crate::consts::P819);
+(- 819) => ($crate::consts::N819);
+(820) => ($crate::consts::U820);
+(+ 820) => ($crate::consts::P820);
+(- 820) => ($crate::consts::N820);
+(821) => ($crate::consts::U821);
+(+ 821) => ($crate::consts::P821);
+(- 821) => ($crate::consts::N821);
+(822) => ($crate::consts::U822);
+(+ 822) => ($crate::consts::P822);
+(- 822) => ($crate::consts::N822);
+(823) => ($crate::consts::U823);
+(+ 823) => ($crate::consts::P823);
+(- 823) => ($crate::consts::N823);
+(824) => ($crate::consts::U824);
+(+ 824) => ($crate::consts::P824);
+(- 824) => ($crate::consts::N824);
+(825) => ($crate::consts::U825);
+(+ 825) => ($crate::consts::P825);
+(- 825) => ($crate::consts::N825);
+(826) => ($crate::consts::U826);
+(+ 826) => ($crate::consts::P826);
+(- 826) => ($crate::consts::N826);
+(827) => ($crate::consts::U827);
+(+ 827) => ($crate::consts::P827);
+(- 827) => ($crate::consts::N827);
+(828) => ($crate::consts::U828);
+(+ 828) => ($crate::consts::P828);
+(- 828) => ($crate::consts::N828);
+(829) => ($crate::consts::U829);
+(+ 829) => ($crate::consts::P829);
+(- 829) => ($crate::consts::N829);
+(830) => ($crate::consts::U830);
+(+ 830) => ($crate::consts::P830);
+(- 830) => ($crate::consts::N830);
+(831) => ($crate::consts::U831);
+(+ 831) => ($crate::consts::P831);
+(- 831) => ($crate::consts::N831);
+(832) => ($crate::consts::U832);
+(+ 832) => ($crate::consts::P832);
+(- 832) => ($crate::consts::N832);
+(833) => ($crate::consts::U833);
+(+ 833) => ($crate::consts::P833);
+(- 833) => ($crate::consts::N833);
+(834) => ($crate::consts::U834);
+(+ 834) => ($crate::consts::P834);
+(- 834) => ($crate::consts::N834);
+(835) => ($crate::consts::U835);
+(+ 835) => ($crate::consts::P835);
+(- 835) => ($crate::consts::N835);
+(836) => ($crate::consts::U836);
+(+ 836) => ($crate::consts::P836);
+(- 836) => ($crate::consts::N836);
+(837) => ($crate::consts::U837);
+(+ 837) => ($crate::consts::P837);
+(- 837) => ($crate::consts::N837);
+(838) => ($crate::consts::U838);
+(+ 838) => ($crate::consts::P838);
+(- 838) => ($crate::consts::N838);
+(839) => ($crate::consts::U839);
+(+ 839) => ($crate::consts::P839);
+(- 839) => ($crate::consts::N839);
+(840) => ($crate::consts::U840);
+(+ 840) => ($crate::consts::P840);
+(- 840) => ($crate::consts::N840);
+(841) => ($crate::consts::U841);
+(+ 841) => ($crate::consts::P841);
+(- 841) => ($crate::consts::N841);
+(842) => ($crate::consts::U842);
+(+ 842) => ($crate::consts::P842);
+(- 842) => ($crate::consts::N842);
+(843) => ($crate::consts::U843);
+(+ 843) => ($crate::consts::P843);
+(- 843) => ($crate::consts::N843);
+(844) => ($crate::consts::U844);
+(+ 844) => ($crate::consts::P844);
+(- 844) => ($crate::consts::N844);
+(845) => ($crate::consts::U845);
+(+ 845) => ($crate::consts::P845);
+(- 845) => ($crate::consts::N845);
+(846) => ($crate::consts::U846);
+(+ 846) => ($crate::consts::P846);
+(- 846) => ($crate::consts::N846);
+(847) => ($crate::consts::U847);
+(+ 847) => ($crate::consts::P847);
+(- 847) => ($crate::consts::N847);
+(848) => ($crate::consts::U848);
+(+ 848) => ($crate::consts::P848);
+(- 848) => ($crate::consts::N848);
+(849) => ($crate::consts::U849);
+(+ 849) => ($crate::consts::P849);
+(- 849) => ($crate::consts::N849);
+(850) => ($crate::consts::U850);
+(+ 850) => ($crate::consts::P850);
+(- 850) => ($crate::consts::N850);
+(851) => ($crate::consts::U851);
+(+ 851) => ($crate::consts::P851);
+(- 851) => ($crate::consts::N851);
+(852) => ($crate::consts::U852);
+(+ 852) => ($crate::consts::P852);
+(- 852) => ($crate::consts::N852);
+(853) => ($crate::consts::U853);
+(+ 853) => ($crate::consts::P853);
+(- 853) => ($crate::consts::N853);
+(854) => ($crate::consts::U854);
+(+ 854) => ($crate::consts::P854);
+(- 854) => ($crate::consts::N854);
+(855) => ($crate::consts::U855);
+(+ 855) => ($crate::consts::P855);
+(- 855) => ($crate::consts::N855);
+(856) => ($crate::consts::U856);
+(+ 856) => ($crate::consts::P856);
+(- 856) => ($crate::consts::N856);
+(857) => ($crate::consts::U857);
+(+ 857) => ($crate::consts::P857);
+(- 857) => ($crate::consts::N857);
+(858) => ($crate::consts::U858);
+(+ 858) => ($crate::consts::P858);
+(- 858) => ($crate::consts::N858);
+(859) => ($crate::consts::U859);
+(+ 859) => ($crate::consts::P859);
+(- 859) => ($crate::consts::N859);
+(860) => ($crate::consts::U860);
+(+ 860) => ($crate::consts::P860);
+(- 860) => ($crate::consts::N860);
+(861) => ($crate::consts::U861);
+(+ 861) => ($crate::consts::P861);
+(- 861) => ($crate::consts::N861);
+(862) => ($crate::consts::U862);
+(+ 862) => ($crate::consts::P862);
+(- 862) => ($crate::consts::N862);
+(863) => ($crate::consts::U863);
+(+ 863) => ($crate::consts::P863);
+(- 863) => ($crate::consts::N863);
+(864) => ($crate::consts::U864);
+(+ 864) => ($crate::consts::P864);
+(- 864) => ($crate::consts::N864);
+(865) => ($crate::consts::U865);
+(+ 865) => ($crate::consts::P865);
+(- 865) => ($crate::consts::N865);
+(866) => ($crate::consts::U866);
+(+ 866) => ($crate::consts::P866);
+(- 866) => ($crate::consts::N866);
+(867) => ($crate::consts::U867);
+(+ 867) => ($crate::consts::P867);
+(- 867) => ($crate::consts::N867);
+(868) => ($crate::consts::U868);
+(+ 868) => ($crate::consts::P868);
+(- 868) => ($crate::consts::N868);
+(869) => ($crate::consts::U869);
+(+ 869) => ($crate::consts::P869);
+(- 869) => ($crate::consts::N869);
+(870) => ($crate::consts::U870);
+(+ 870) => ($crate::consts::P870);
+(- 870) => ($crate::consts::N870);
+(871) => ($crate::consts::U871);
+(+ 871) => ($crate::consts::P871);
+(- 871) => ($crate::consts::N871);
+(872) => ($crate::consts::U872);
+(+ 872) => ($crate::consts::P872);
+(- 872) => ($crate::consts::N872);
+(873) => ($crate::consts::U873);
+(+ 873) => ($crate::consts::P873);
+(- 873) => ($crate::consts::N873);
+(874) => ($crate::consts::U874);
+(+ 874) => ($crate::consts::P874);
+(- 874) => ($crate::consts::N874);
+(875) => ($crate::consts::U875);
+(+ 875) => ($crate::consts::P875);
+(- 875) => ($crate::consts::N875);
+(876) => ($crate::consts::U876);
+(+ 876) => ($crate::consts::P876);
+(- 876) => ($crate::consts::N876);
+(877) => ($crate::consts::U877);
+(+ 877) => ($crate::consts::P877);
+(- 877) => ($crate::consts::N877);
+(878) => ($crate::consts::U878);
+(+ 878) => ($crate::consts::P878);
+(- 878) => ($crate::consts::N878);
+(879) => ($crate::consts::U879);
+(+ 879) => ($crate::consts::P879);
+(- 879) => ($crate::consts::N879);
+(880) => ($crate::consts::U880);
+(+ 880) => ($crate::consts::P880);
+(- 880) => ($crate::consts::N880);
+(881) => ($crate::consts::U881);
+(+ 881) => ($crate::consts::P881);
+(- 881) => ($crate::consts::N881);
+(882) => ($crate::consts::U882);
+(+ 882) => ($crate::consts::P882);
+(- 882) => ($crate::consts::N882);
+(883) => ($crate::consts::U883);
+(+ 883) => ($crate::consts::P883);
+(- 883) => ($crate::consts::N883);
+(884) => ($crate::consts::U884);
+(+ 884) => ($crate::consts::P884);
+(- 884) => ($crate::consts::N884);
+(885) => ($crate::consts::U885);
+(+ 885) => ($crate::consts::P885);
+(- 885) => ($crate::consts::N885);
+(886) => ($crate::consts::U886);
+(+ 886) => ($crate::consts::P886);
+(- 886) => ($crate::consts::N886);
+(887) => ($crate::consts::U887);
+(+ 887) => ($crate::consts::P887);
+(- 887) => ($crate::consts::N887);
+(888) => ($crate::consts::U888);
+(+ 888) => ($crate::consts::P888);
+(- 888) => ($crate::consts::N888);
+(889) => ($crate::consts::U889);
+(+ 889) => ($crate::consts::P889);
+(- 889) => ($crate::consts::N889);
+(890) => ($crate::consts::U890);
+(+ 890) => ($crate::consts::P890);
+(- 890) => ($crate::consts::N890);
+(891) => ($crate::consts::U891);
+(+ 891) => ($crate::consts::P891);
+(- 891) => ($crate::consts::N891);
+(892) => ($crate::consts::U892);
+(+ 892) => ($crate::consts::P892);
+(- 892) => ($crate::consts::N892);
+(893) => ($crate::consts::U893);
+(+ 893) => ($crate::consts::P893);
+(- 893) => ($crate::consts::N893);
+(894) => ($crate::consts::U894);
+(+ 894) => ($crate::consts::P894);
+(- 894) => ($crate::consts::N894);
+(895) => ($crate::consts::U895);
+(+ 895) => ($crate::consts::P895);
+(- 895) => ($crate::consts::N895);
+(896) => ($crate::consts::U896);
+(+ 896) => ($crate::consts::P896);
+(- 896) => ($crate::consts::N896);
+(897) => ($crate::consts::U897);
+(+ 897) => ($crate::consts::P897);
+(- 897) => ($crate::consts::N897);
+(898) => ($crate::consts::U898);
+(+ 898) => ($crate::consts::P898);
+(- 898) => ($crate::consts::N898);
+(899) => ($crate::consts::U899);
+(+ 899) => ($crate::consts::P899);
+(- 899) => ($crate::consts::N899);
+(900) => ($crate::consts::U900);
+(+ 900) => ($crate::consts::P900);
+(- 900) => ($crate::consts::N900);
+(901) => ($crate::consts::U901);
+(+ 901) => ($crate::consts::P901);
+(- 901) => ($crate::consts::N901);
+(902) => ($crate::consts::U902);
+(+ 902) => ($crate::consts::P902);
+(- 902) => ($crate::consts::N902);
+(903) => ($crate::consts::U903);
+(+ 903) => ($crate::consts::P903);
+(- 903) => ($crate::consts::N903);
+(904) => ($crate::consts::U904);
+(+ 904) => ($crate::consts::P904);
+(- 904) => ($crate::consts::N904);
+(905) => ($crate::consts::U905);
+(+ 905) => ($crate::consts::P905);
+(- 905) => ($crate::consts::N905);
+(906) => ($crate::consts::U906);
+(+ 906) => ($crate::consts::P906);
+(- 906) => ($crate::consts::N906);
+(907) => ($crate::consts::U907);
+(+ 907) => ($crate::consts::P907);
+(- 907) => ($crate::consts::N907);
+(908) => ($crate::consts::U908);
+(+ 908) => ($crate::consts::P908);
+(- 908) => ($crate::consts::N908);
+(909) => ($crate::consts::U909);
+(+ 909) => ($crate::consts::P909);
+(- 909) => ($crate::consts::N909);
+(910) => ($crate::consts::U910);
+(+ 910) => ($crate::consts::P910);
+(- 910) => ($crate::consts::N910);
+(911) => ($crate::consts::U911);
+(+ 911) => ($crate::consts::P911);
+(- 911) => ($crate::consts::N911);
+(912) => ($crate::consts::U912);
+(+ 912) => ($crate::consts::P912);
+(- 912) => ($crate::consts::N912);
+(913) => ($crate::consts::U913);
+(+ 913) => ($crate::consts::P913);
+(- 913) => ($crate::consts::N913);
+(914) => ($crate::consts::U914);
+(+ 914) => ($crate::consts::P914);
+(- 914) => ($crate::consts::N914);
+(915) => ($crate::consts::U915);
+(+ 915) => ($crate::consts::P915);
+(- 915) => ($crate::consts::N915);
+(916) => ($crate::consts::U916);
+(+ 916) => ($crate::consts::P916);
+(- 916) => ($crate::consts::N916);
+(917) => ($crate::consts::U917);
+(+ 917) => ($crate::consts::P917);
+(- 917) => ($crate::consts::N917);
+(918) => ($crate::consts::U918);
+(+ 918) => ($crate::consts::P918);
+(- 918) => ($crate::consts::N918);
+(919) => ($crate::consts::U919);
+(+ 919) => ($crate::consts::P919);
+(- 919) => ($crate::consts::N919);
+(920) => ($crate::consts::U920);
+(+ 920) => ($crate::consts::P920);
+(- 920) => ($crate::consts::N920);
+(921) => ($crate::consts::U921);
+(+ 921) => ($crate::consts::P921);
+(- 921) => ($crate::consts::N921);
+(922) => ($crate::consts::U922);
+(+ 922) => ($crate::consts::P922);
+(- 922) => ($crate::consts::N922);
+(923) => ($crate::consts::U923);
+(+ 923) => ($crate::consts::P923);
+(- 923) => ($crate::consts::N923);
+(924) => ($crate::consts::U924);
+(+ 924) => ($crate::consts::P924);
+(- 924) => ($crate::consts::N924);
+(925) => ($crate::consts::U925);
+(+ 925) => ($crate::consts::P925);
+(- 925) => ($crate::consts::N925);
+(926) => ($crate::consts::U926);
+(+ 926) => ($crate::consts::P926);
+(- 926) => ($crate::consts::N926);
+(927) => ($crate::consts::U927);
+(+ 927) => ($crate::consts::P927);
+(- 927) => ($crate::consts::N927);
+(928) => ($crate::consts::U928);
+(+ 928) => ($crate::consts::P928);
+(- 928) => ($crate::consts::N928);
+(929) => ($crate::consts::U929);
+(+ 929) => ($crate::consts::P929);
+(- 929) => ($crate::consts::N929);
+(930) => ($crate::consts::U930);
+(+ 930) => ($crate::consts::P930);
+(- 930) => ($crate::consts::N930);
+(931) => ($crate::consts::U931);
+(+ 931) => ($crate::consts::P931);
+(- 931) => ($crate::consts::N931);
+(932) => ($crate::consts::U932);
+(+ 932) => ($crate::consts::P932);
+(- 932) => ($crate::consts::N932);
+(933) => ($crate::consts::U933);
+(+ 933) => ($crate::consts::P933);
+(- 933) => ($crate::consts::N933);
+(934) => ($crate::consts::U934);
+(+ 934) => ($crate::consts::P934);
+(- 934) => ($crate::consts::N934);
+(935) => ($crate::consts::U935);
+(+ 935) => ($crate::consts::P935);
+(- 935) => ($crate::consts::N935);
+(936) => ($crate::consts::U936);
+(+ 936) => ($crate::consts::P936);
+(- 936) => ($crate::consts::N936);
+(937) => ($crate::consts::U937);
+(+ 937) => ($crate::consts::P937);
+(- 937) => ($crate::consts::N937);
+(938) => ($crate::consts::U938);
+(+ 938) => ($crate::consts::P938);
+(- 938) => ($crate::consts::N938);
+(939) => ($crate::consts::U939);
+(+ 939) => ($crate::consts::P939);
+(- 939) => ($crate::consts::N939);
+(940) => ($crate::consts::U940);
+(+ 940) => ($crate::consts::P940);
+(- 940) => ($crate::consts::N940);
+(941) => ($crate::consts::U941);
+(+ 941) => ($crate::consts::P941);
+(- 941) => ($crate::consts::N941);
+(942) => ($crate::consts::U942);
+(+ 942) => ($crate::consts::P942);
+(- 942) => ($crate::consts::N942);
+(943) => ($crate::consts::U943);
+(+ 943) => ($crate::consts::P943);
+(- 943) => ($crate::consts::N943);
+(944) => ($crate::consts::U944);
+(+ 944) => ($crate::consts::P944);
+(- 944) => ($crate::consts::N944);
+(945) => ($crate::consts::U945);
+(+ 945) => ($crate::consts::P945);
+(- 945) => ($crate::consts::N945);
+(946) => ($crate::consts::U946);
+(+ 946) => ($crate::consts::P946);
+(- 946) => ($crate::consts::N946);
+(947) => ($crate::consts::U947);
+(+ 947) => ($crate::consts::P947);
+(- 947) => ($crate::consts::N947);
+(948) => ($crate::consts::U948);
+(+ 948) => ($crate::consts::P948);
+(- 948) => ($crate::consts::N948);
+(949) => ($crate::consts::U949);
+(+ 949) => ($crate::consts::P949);
+(- 949) => ($crate::consts::N949);
+(950) => ($crate::consts::U950);
+(+ 950) => ($crate::consts::P950);
+(- 950) => ($crate::consts::N950);
+(951) => ($crate::consts::U951);
+(+ 951) => ($crate::consts::P951);
+(- 951) => ($crate::consts::N951);
+(952) => ($crate::consts::U952);
+(+ 952) => ($crate::consts::P952);
+(- 952) => ($crate::consts::N952);
+(953) => ($crate::consts::U953);
+(+ 953) => ($crate::consts::P953);
+(- 953) => ($crate::consts::N953);
+(954) => ($crate::consts::U954);
+(+ 954) => ($crate::consts::P954);
+(- 954) => ($crate::consts::N954);
+(955) => ($crate::consts::U955);
+(+ 955) => ($crate::consts::P955);
+(- 955) => ($crate::consts::N955);
+(956) => ($crate::consts::U956);
+(+ 956) => ($crate::consts::P956);
+(- 956) => ($crate::consts::N956);
+(957) => ($crate::consts::U957);
+(+ 957) => ($crate::consts::P957);
+(- 957) => ($crate::consts::N957);
+(958) => ($crate::consts::U958);
+(+ 958) => ($crate::consts::P958);
+(- 958) => ($crate::consts::N958);
+(959) => ($crate::consts::U959);
+(+ 959) => ($crate::consts::P959);
+(- 959) => ($crate::consts::N959);
+(960) => ($crate::consts::U960);
+(+ 960) => ($crate::consts::P960);
+(- 960) => ($crate::consts::N960);
+(961) => ($crate::consts::U961);
+(+ 961) => ($crate::consts::P961);
+(- 961) => ($crate::consts::N961);
+(962) => ($crate::consts::U962);
+(+ 962) => ($crate::consts::P962);
+(- 962) => ($crate::consts::N962);
+(963) => ($crate::consts::U963);
+(+ 963) => ($crate::consts::P963);
+(- 963) => ($crate::consts::N963);
+(964) => ($crate::consts::U964);
+(+ 964) => ($crate::consts::P964);
+(- 964) => ($crate::consts::N964);
+(965) => ($crate::consts::U965);
+(+ 965) => ($crate::consts::P965);
+(- 965) => ($crate::consts::N965);
+(966) => ($crate::consts::U966);
+(+ 966) => ($crate::consts::P966);
+(- 966) => ($crate::consts::N966);
+(967) => ($crate::consts::U967);
+(+ 967) => ($crate::consts::P967);
+(- 967) => ($crate::consts::N967);
+(968) => ($crate::consts::U968);
+(+ 968) => ($crate::consts::P968);
+(- 968) => ($crate::consts::N968);
+(969) => ($crate::consts::U969);
+(+ 969) => ($crate::consts::P969);
+(- 969) => ($crate::consts::N969);
+(970) => ($crate::consts::U970);
+(+ 970) => ($crate::consts::P970);
+(- 970) => ($crate::consts::N970);
+(971) => ($crate::consts::U971);
+(+ 971) => ($crate::consts::P971);
+(- 971) => ($crate::consts::N971);
+(972) => ($crate::consts::U972);
+(+ 972) => ($crate::consts::P972);
+(- 972) => ($crate::consts::N972);
+(973) => ($crate::consts::U973);
+(+ 973) => ($crate::consts::P973);
+(- 973) => ($crate::consts::N973);
+(974) => ($crate::consts::U974);
+(+ 974) => ($crate::consts::P974);
+(- 974) => ($crate::consts::N974);
+(975) => ($crate::consts::U975);
+(+ 975) => ($crate::consts::P975);
+(- 975) => ($crate::consts::N975);
+(976) => ($crate::consts::U976);
+(+ 976) => ($crate::consts::P976);
+(- 976) => ($crate::consts::N976);
+(977) => ($crate::consts::U977);
+(+ 977) => ($crate::consts::P977);
+(- 977) => ($crate::consts::N977);
+(978) => ($crate::consts::U978);
+(+ 978) => ($crate::consts::P978);
+(- 978) => ($crate::consts::N978);
+(979) => ($crate::consts::U979);
+(+ 979) => ($crate::consts::P979);
+(- 979) => ($crate::consts::N979);
+(980) => ($crate::consts::U980);
+(+ 980) => ($crate::consts::P980);
+(- 980) => ($crate::consts::N980);
+(981) => ($crate::consts::U981);
+(+ 981) => ($crate::consts::P981);
+(- 981) => ($crate::consts::N981);
+(982) => ($crate::consts::U982);
+(+ 982) => ($crate::consts::P982);
+(- 982) => ($crate::consts::N982);
+(983) => ($crate::consts::U983);
+(+ 983) => ($crate::consts::P983);
+(- 983) => ($crate::consts::N983);
+(984) => ($crate::consts::U984);
+(+ 984) => ($crate::consts::P984);
+(- 984) => ($crate::consts::N984);
+(985) => ($crate::consts::U985);
+(+ 985) => ($crate::consts::P985);
+(- 985) => ($crate::consts::N985);
+(986) => ($crate::consts::U986);
+(+ 986) => ($crate::consts::P986);
+(- 986) => ($crate::consts::N986);
+(987) => ($crate::consts::U987);
+(+ 987) => ($crate::consts::P987);
+(- 987) => ($crate::consts::N987);
+(988) => ($crate::consts::U988);
+(+ 988) => ($crate::consts::P988);
+(- 988) => ($crate::consts::N988);
+(989) => ($crate::consts::U989);
+(+ 989) => ($crate::consts::P989);
+(- 989) => ($crate::consts::N989);
+(990) => ($crate::consts::U990);
+(+ 990) => ($crate::consts::P990);
+(- 990) => ($crate::consts::N990);
+(991) => ($crate::consts::U991);
+(+ 991) => ($crate::consts::P991);
+(- 991) => ($crate::consts::N991);
+(992) => ($crate::consts::U992);
+(+ 992) => ($crate::consts::P992);
+(- 992) => ($crate::consts::N992);
+(993) => ($crate::consts::U993);
+(+ 993) => ($crate::consts::P993);
+(- 993) => ($crate::consts::N993);
+(994) => ($crate::consts::U994);
+(+ 994) => ($crate::consts::P994);
+(- 994) => ($crate::consts::N994);
+(995) => ($crate::consts::U995);
+(+ 995) => ($crate::consts::P995);
+(- 995) => ($crate::consts::N995);
+(996) => ($crate::consts::U996);
+(+ 996) => ($crate::consts::P996);
+(- 996) => ($crate::consts::N996);
+(997) => ($crate::consts::U997);
+(+ 997) => ($crate::consts::P997);
+(- 997) => ($crate::consts::N997);
+(998) => ($crate::consts::U998);
+(+ 998) => ($crate::consts::P998);
+(- 998) => ($crate::consts::N998);
+(999) => ($crate::consts::U999);
+(+ 999) => ($crate::consts::P999);
+(- 999) => ($crate::consts::N999);
+(1000) => ($crate::consts::U1000);
+(+ 1000) => ($crate::consts::P1000);
+(- 1000) => ($crate::consts::N1000);
+(1001) => ($crate::consts::U1001);
+(+ 1001) => ($crate::consts::P1001);
+(- 1001) => ($crate::consts::N1001);
+(1002) => ($crate::consts::U1002);
+(+ 1002) => ($crate::consts::P1002);
+(- 1002) => ($crate::consts::N1002);
+(1003) => ($crate::consts::U1003);
+(+ 1003) => ($crate::consts::P1003);
+(- 1003) => ($crate::consts::N1003);
+(1004) => ($crate::consts::U1004);
+(+ 1004) => ($crate::consts::P1004);
+(- 1004) => ($crate::consts::N1004);
+(1005) => ($crate::consts::U1005);
+(+ 1005) => ($crate::consts::P1005);
+(- 1005) => ($crate::consts::N1005);
+(1006) => ($crate::consts::U1006);
+(+ 1006) => ($crate::consts::P1006);
+(- 1006) => ($crate::consts::N1006);
+(1007) => ($crate::consts::U1007);
+(+ 1007) => ($crate::consts::P1007);
+(- 1007) => ($crate::consts::N1007);
+(1008) => ($crate::consts::U1008);
+(+ 1008) => ($crate::consts::P1008);
+(- 1008) => ($crate::consts::N1008);
+(1009) => ($crate::consts::U1009);
+(+ 1009) => ($crate::consts::P1009);
+(- 1009) => ($crate::consts::N1009);
+(1010) => ($crate::consts::U1010);
+(+ 1010) => ($crate::consts::P1010);
+(- 1010) => ($crate::consts::N1010);
+(1011) => ($crate::consts::U1011);
+(+ 1011) => ($crate::consts::P1011);
+(- 1011) => ($crate::consts::N1011);
+(1012) => ($crate::consts::U1012);
+(+ 1012) => ($crate::consts::P1012);
+(- 1012) => ($crate::consts::N1012);
+(1013) => ($crate::consts::U1013);
+(+ 1013) => ($crate::consts::P1013);
+(- 1013) => ($crate::consts::N1013);
+(1014) => ($crate::consts::U1014);
+(+ 1014) => ($crate::consts::P1014);
+(- 1014) => ($crate::consts::N1014);
+(1015) => ($crate::consts::U1015);
+(+ 1015) => ($crate::consts::P1015);
+(- 1015) => ($crate::consts::N1015);
+(1016) => ($crate::consts::U1016);
+(+ 1016) => ($crate::consts::P1016);
+(- 1016) => ($crate::consts::N1016);
+(1017) => ($crate::consts::U1017);
+(+ 1017) => ($crate::consts::P1017);
+(- 1017) => ($crate::consts::N1017);
+(1018) => ($crate::consts::U1018);
+(+ 1018) => ($crate::consts::P1018);
+(- 1018) => ($crate::consts::N1018);
+(1019) => ($crate::consts::U1019);
+(+ 1019) => ($crate::consts::P1019);
+(- 1019) => ($crate::consts::N1019);
+(1020) => ($crate::consts::U1020);
+(+ 1020) => ($crate::consts::P1020);
+(- 1020) => ($crate::consts::N1020);
+(1021) => ($crate::consts::U1021);
+(+ 1021) => ($crate::consts::P1021);
+(- 1021) => ($crate::consts::N1021);
+(1022) => ($crate::consts::U1022);
+(+ 1022) => ($crate::consts::P1022);
+(- 1022) => ($crate::consts::N1022);
+(1023) => ($crate::consts::U1023);
+(+ 1023) => ($crate::consts::P1023);
+(- 1023) => ($crate::consts::N1023);
+(1024) => ($crate::consts::U1024);
+(+ 1024) => ($crate::consts::P1024);
+(- 1024) => ($crate::consts::N1024);
+(3600) => ($crate::consts::U3600);
+(+ 3600) => ($crate::consts::P3600);
+(- 3600) => ($crate::consts::N3600);
+(2047) => ($crate::consts::U2047);
+(+ 2047) => ($crate::consts::P2047);
+(- 2047) => ($crate::consts::N2047);
+(2048) => ($crate::consts::U2048);
+(+ 2048) => ($crate::consts::P2048);
+(- 2048) => ($crate::consts::N2048);
+(4095) => ($crate::consts::U4095);
+(+ 4095) => ($crate::consts::P4095);
+(- 4095) => ($crate::consts::N4095);
+(4096) => ($crate::consts::U4096);
+(+ 4096) => ($crate::consts::P4096);
+(- 4096) => ($crate::consts::N4096);
+(8191) => ($crate::consts::U8191);
+(+ 8191) => ($crate::consts::P8191);
+(- 8191) => ($crate::consts::N8191);
+(8192) => ($crate::consts::U8192);
+(+ 8192) => ($crate::consts::P8192);
+(- 8192) => ($crate::consts::N8192);
+(16383) => ($crate::consts::U16383);
+(+ 16383) => ($crate::consts::P16383);
+(- 16383) => ($crate::consts::N16383);
+(16384) => ($crate::consts::U16384);
+(+ 16384) => ($crate::consts::P16384);
+(- 16384) => ($crate::consts::N16384);
+(32767) => ($crate::consts::U32767);
+(+ 32767) => ($crate::consts::P32767);
+(- 32767) => ($crate::consts::N32767);
+(32768) => ($crate::consts::U32768);
+(+ 32768) => ($crate::consts::P32768);
+(- 32768) => ($crate::consts::N32768);
+(65535) => ($crate::consts::U65535);
+(+ 65535) => ($crate::consts::P65535);
+(- 65535) => ($crate::consts::N65535);
+(65536) => ($crate::consts::U65536);
+(+ 65536) => ($crate::consts::P65536);
+(- 65536) => ($crate::consts::N65536);
+(131071) => ($crate::consts::U131071);
+(+ 131071) => ($crate::consts::P131071);
+(- 131071) => ($crate::consts::N131071);
+(131072) => ($crate::consts::U131072);
+(+ 131072) => ($crate::consts::P131072);
+(- 131072) => ($crate::consts::N131072);
+(262143) => ($crate::consts::U262143);
+(+ 262143) => ($crate::consts::P262143);
+(- 262143) => ($crate::consts::N262143);
+(262144) => ($crate::consts::U262144);
+(+ 262144) => ($crate::consts::P262144);
+(- 262144) => ($crate::consts::N262144);
+(524287) => ($crate::consts::U524287);
+(+ 524287) => ($crate::consts::P524287);
+(- 524287) => ($crate::consts::N524287);
+(524288) => ($crate::consts::U524288);
+(+ 524288) => ($crate::consts::P524288);
+(- 524288) => ($crate::consts::N524288);
+(1048575) => ($crate::consts::U1048575);
+(+ 1048575) => ($crate::consts::P1048575);
+(- 1048575) => ($crate::consts::N1048575);
+(1048576) => ($crate::consts::U1048576);
+(+ 1048576) => ($crate::consts::P1048576);
+(- 1048576) => ($crate::consts::N1048576);
+(2097151) => ($crate::consts::U2097151);
+(+ 2097151) => ($crate::consts::P2097151);
+(- 2097151) => ($crate::consts::N2097151);
+(2097152) => ($crate::consts::U2097152);
+(+ 2097152) => ($crate::consts::P2097152);
+(- 2097152) => ($crate::consts::N2097152);
+(4194303) => ($crate::consts::U4194303);
+(+ 4194303) => ($crate::consts::P4194303);
+(- 4194303) => ($crate::consts::N4194303);
+(4194304) => ($crate::consts::U4194304);
+(+ 4194304) => ($crate::consts::P4194304);
+(- 4194304) => ($crate::consts::N4194304);
+(8388607) => ($crate::consts::U8388607);
+(+ 8388607) => ($crate::consts::P8388607);
+(- 8388607) => ($crate::consts::N8388607);
+(8388608) => ($crate::consts::U8388608);
+(+ 8388608) => ($crate::consts::P8388608);
+(- 8388608) => ($crate::consts::N8388608);
+(16777215) => ($crate::consts::U16777215);
+(+ 16777215) => ($crate::consts::P16777215);
+(- 16777215) => ($crate::consts::N16777215);
+(16777216) => ($crate::consts::U16777216);
+(+ 16777216) => ($crate::consts::P16777216);
+(- 16777216) => ($crate::consts::N16777216);
+(33554431) => ($crate::consts::U33554431);
+(+ 33554431) => ($crate::consts::P33554431);
+(- 33554431) => ($crate::consts::N33554431);
+(33554432) => ($crate::consts::U33554432);
+(+ 33554432) => ($crate::consts::P33554432);
+(- 33554432) => ($crate::consts::N33554432);
+(67108863) => ($crate::consts::U67108863);
+(+ 67108863) => ($crate::consts::P67108863);
+(- 67108863) => ($crate::consts::N67108863);
+(67108864) => ($crate::consts::U67108864);
+(+ 67108864) => ($crate::consts::P67108864);
+(- 67108864) => ($crate::consts::N67108864);
+(134217727) => ($crate::consts::U134217727);
+(+ 134217727) => ($crate::consts::P134217727);
+(- 134217727) => ($crate::consts::N134217727);
+(134217728) => ($crate::consts::U134217728);
+(+ 134217728) => ($crate::consts::P134217728);
+(- 134217728) => ($crate::consts::N134217728);
+(268435455) => ($crate::consts::U268435455);
+(+ 268435455) => ($crate::consts::P268435455);
+(- 268435455) => ($crate::consts::N268435455);
+(268435456) => ($crate::consts::U268435456);
+(+ 268435456) => ($crate::consts::P268435456);
+(- 268435456) => ($crate::consts::N268435456);
+(536870911) => ($crate::consts::U536870911);
+(+ 536870911) => ($crate::consts::P536870911);
+(- 536870911) => ($crate::consts::N536870911);
+(536870912) => ($crate::consts::U536870912);
+(+ 536870912) => ($crate::consts::P536870912);
+(- 536870912) => ($crate::consts::N536870912);
+(1073741823) => ($crate::consts::U1073741823);
+(+ 1073741823) => ($crate::consts::P1073741823);
+(- 1073741823) => ($crate::consts::N1073741823);
+(1073741824) => ($crate::consts::U1073741824);
+(+ 1073741824) => ($crate::consts::P1073741824);
+(- 1073741824) => ($crate::consts::N1073741824);
+(2147483647) => ($crate::consts::U2147483647);
+(+ 2147483647) => ($crate::consts::P2147483647);
+(- 2147483647) => ($crate::consts::N2147483647);
+(2147483648) => ($crate::consts::U2147483648);
+(+ 2147483648) => ($crate::consts::P2147483648);
+(- 2147483648) => ($crate::consts::N2147483648);
+(4294967295) => ($crate::consts::U4294967295);
+(+ 4294967295) => ($crate::consts::P4294967295);
+(- 4294967295) => ($crate::consts::N4294967295);
+(4294967296) => ($crate::consts::U4294967296);
+(+ 4294967296) => ($crate::consts::P4294967296);
+(- 4294967296) => ($crate::consts::N4294967296);
+(8589934591) => ($crate::consts::U8589934591);
+(+ 8589934591) => ($crate::consts::P8589934591);
+(- 8589934591) => ($crate::consts::N8589934591);
+(8589934592) => ($crate::consts::U8589934592);
+(+ 8589934592) => ($crate::consts::P8589934592);
+(- 8589934592) => ($crate::consts::N8589934592);
+(17179869183) => ($crate::consts::U17179869183);
+(+ 17179869183) => ($crate::consts::P17179869183);
+(- 17179869183) => ($crate::consts::N17179869183);
+(17179869184) => ($crate::consts::U17179869184);
+(+ 17179869184) => ($crate::consts::P17179869184);
+(- 17179869184) => ($crate::consts::N17179869184);
+(34359738367) => ($crate::consts::U34359738367);
+(+ 34359738367) => ($crate::consts::P34359738367);
+(- 34359738367) => ($crate::consts::N34359738367);
+(34359738368) => ($crate::consts::U34359738368);
+(+ 34359738368) => ($crate::consts::P34359738368);
+(- 34359738368) => ($crate::consts::N34359738368);
+(68719476735) => ($crate::consts::U68719476735);
+(+ 68719476735) => ($crate::consts::P68719476735);
+(- 68719476735) => ($crate::consts::N68719476735);
+(68719476736) => ($crate::consts::U68719476736);
+(+ 68719476736) => ($crate::consts::P68719476736);
+(- 68719476736) => ($crate::consts::N68719476736);
+(137438953471) => ($crate::consts::U137438953471);
+(+ 137438953471) => ($crate::consts::P137438953471);
+(- 137438953471) => ($crate::consts::N137438953471);
+(137438953472) => ($crate::consts::U137438953472);
+(+ 137438953472) => ($crate::consts::P137438953472);
+(- 137438953472) => ($crate::consts::N137438953472);
+(274877906943) => ($crate::consts::U274877906943);
+(+ 274877906943) => ($crate::consts::P274877906943);
+(- 274877906943) => ($crate::consts::N274877906943);
+(274877906944) => ($crate::consts::U274877906944);
+(+ 274877906944) => ($crate::consts::P274877906944);
+(- 274877906944) => ($crate::consts::N274877906944);
+(549755813887) => ($crate::consts::U549755813887);
+(+ 549755813887) => ($crate::consts::P549755813887);
+(- 549755813887) => ($crate::consts::N549755813887);
+(549755813888) => ($crate::consts::U549755813888);
+(+ 549755813888) => ($crate::consts::P549755813888);
+(- 549755813888) => ($crate::consts::N549755813888);
+(1099511627775) => ($crate::consts::U1099511627775);
+(+ 1099511627775) => ($crate::consts::P1099511627775);
+(- 1099511627775) => ($crate::consts::N1099511627775);
+(1099511627776) => ($crate::consts::U1099511627776);
+(+ 1099511627776) => ($crate::consts::P1099511627776);
+(- 1099511627776) => ($crate::consts::N1099511627776);
+(2199023255551) => ($crate::consts::U2199023255551);
+(+ 2199023255551) => ($crate::consts::P2199023255551);
+(- 2199023255551) => ($crate::consts::N2199023255551);
+(2199023255552) => ($crate::consts::U2199023255552);
+(+ 2199023255552) => ($crate::consts::P2199023255552);
+(- 2199023255552) => ($crate::consts::N2199023255552);
+(4398046511103) => ($crate::consts::U4398046511103);
+(+ 4398046511103) => ($crate::consts::P4398046511103);
+(- 4398046511103) => ($crate::consts::N4398046511103);
+(4398046511104) => ($crate::consts::U4398046511104);
+(+ 4398046511104) => ($crate::consts::P4398046511104);
+(- 4398046511104) => ($crate::consts::N4398046511104);
+(8796093022207) => ($crate::consts::U8796093022207);
+(+ 8796093022207) => ($crate::consts::P8796093022207);
+(- 8796093022207) => ($crate::consts::N8796093022207);
+(8796093022208) => ($crate::consts::U8796093022208);
+(+ 8796093022208) => ($crate::consts::P8796093022208);
+(- 8796093022208) => ($crate::consts::N8796093022208);
+(17592186044415) => ($crate::consts::U17592186044415);
+(+ 17592186044415) => ($crate::consts::P17592186044415);
+(- 17592186044415) => ($crate::consts::N17592186044415);
+(17592186044416) => ($crate::consts::U17592186044416);
+(+ 17592186044416) => ($crate::consts::P17592186044416);
+(- 17592186044416) => ($crate::consts::N17592186044416);
+(35184372088831) => ($crate::consts::U35184372088831);
+(+ 35184372088831) => ($crate::consts::P35184372088831);
+(- 35184372088831) => ($crate::consts::N35184372088831);
+(35184372088832) => ($crate::consts::U35184372088832);
+(+ 35184372088832) => ($crate::consts::P35184372088832);
+(- 35184372088832) => ($crate::consts::N35184372088832);
+(70368744177663) => ($crate::consts::U70368744177663);
+(+ 70368744177663) => ($crate::consts::P70368744177663);
+(- 70368744177663) => ($crate::consts::N70368744177663);
+(70368744177664) => ($crate::consts::U70368744177664);
+(+ 70368744177664) => ($crate::consts::P70368744177664);
+(- 70368744177664) => ($crate::consts::N70368744177664);
+(140737488355327) => ($crate::consts::U140737488355327);
+(+ 140737488355327) => ($crate::consts::P140737488355327);
+(- 140737488355327) => ($crate::consts::N140737488355327);
+(140737488355328) => ($crate::consts::U140737488355328);
+(+ 140737488355328) => ($crate::consts::P140737488355328);
+(- 140737488355328) => ($crate::consts::N140737488355328);
+(281474976710655) => ($crate::consts::U281474976710655);
+(+ 281474976710655) => ($crate::consts::P281474976710655);
+(- 281474976710655) => ($crate::consts::N281474976710655);
+(281474976710656) => ($crate::consts::U281474976710656);
+(+ 281474976710656) => ($crate::consts::P281474976710656);
+(- 281474976710656) => ($crate::consts::N281474976710656);
+(562949953421311) => ($crate::consts::U562949953421311);
+(+ 562949953421311) => ($crate::consts::P562949953421311);
+(- 562949953421311) => ($crate::consts::N562949953421311);
+(562949953421312) => ($crate::consts::U562949953421312);
+(+ 562949953421312) => ($crate::consts::P562949953421312);
+(- 562949953421312) => ($crate::consts::N562949953421312);
+(1125899906842623) => ($crate::consts::U1125899906842623);
+(+ 1125899906842623) => ($crate::consts::P1125899906842623);
+(- 1125899906842623) => ($crate::consts::N1125899906842623);
+(1125899906842624) => ($crate::consts::U1125899906842624);
+(+ 1125899906842624) => ($crate::consts::P1125899906842624);
+(- 1125899906842624) => ($crate::consts::N1125899906842624);
+(2251799813685247) => ($crate::consts::U2251799813685247);
+(+ 2251799813685247) => ($crate::consts::P2251799813685247);
+(- 2251799813685247) => ($crate::consts::N2251799813685247);
+(2251799813685248) => ($crate::consts::U2251799813685248);
+(+ 2251799813685248) => ($crate::consts::P2251799813685248);
+(- 2251799813685248) => ($crate::consts::N2251799813685248);
+(4503599627370495) => ($crate::consts::U4503599627370495);
+(+ 4503599627370495) => ($crate::consts::P4503599627370495);
+(- 4503599627370495) => ($crate::consts::N4503599627370495);
+(4503599627370496) => ($crate::consts::U4503599627370496);
+(+ 4503599627370496) => ($crate::consts::P4503599627370496);
+(- 4503599627370496) => ($crate::consts::N4503599627370496);
+(9007199254740991) => ($crate::consts::U9007199254740991);
+(+ 9007199254740991) => ($crate::consts::P9007199254740991);
+(- 9007199254740991) => ($crate::consts::N9007199254740991);
+(9007199254740992) => ($crate::consts::U9007199254740992);
+(+ 9007199254740992) => ($crate::consts::P9007199254740992);
+(- 9007199254740992) => ($crate::consts::N9007199254740992);
+(18014398509481983) => ($crate::consts::U18014398509481983);
+(+ 18014398509481983) => ($crate::consts::P18014398509481983);
+(- 18014398509481983) => ($crate::consts::N18014398509481983);
+(18014398509481984) => ($crate::consts::U18014398509481984);
+(+ 18014398509481984) => ($crate::consts::P18014398509481984);
+(- 18014398509481984) => ($crate::consts::N18014398509481984);
+(36028797018963967) => ($crate::consts::U36028797018963967);
+(+ 36028797018963967) => ($crate::consts::P36028797018963967);
+(- 36028797018963967) => ($crate::consts::N36028797018963967);
+(36028797018963968) => ($crate::consts::U36028797018963968);
+(+ 36028797018963968) => ($crate::consts::P36028797018963968);
+(- 36028797018963968) => ($crate::consts::N36028797018963968);
+(72057594037927935) => ($crate::consts::U72057594037927935);
+(+ 72057594037927935) => ($crate::consts::P72057594037927935);
+(- 72057594037927935) => ($crate::consts::N72057594037927935);
+(72057594037927936) => ($crate::consts::U72057594037927936);
+(+ 72057594037927936) => ($crate::consts::P72057594037927936);
+(- 72057594037927936) => ($crate::consts::N72057594037927936);
+(144115188075855871) => ($crate::consts::U144115188075855871);
+(+ 144115188075855871) => ($crate::consts::P144115188075855871);
+(- 144115188075855871) => ($crate::consts::N144115188075855871);
+(144115188075855872) => ($crate::consts::U144115188075855872);
+(+ 144115188075855872) => ($crate::consts::P144115188075855872);
+(- 144115188075855872) => ($crate::consts::N144115188075855872);
+(288230376151711743) => ($crate::consts::U288230376151711743);
+(+ 288230376151711743) => ($crate::consts::P288230376151711743);
+(- 288230376151711743) => ($crate::consts::N288230376151711743);
+(288230376151711744) => ($crate::consts::U288230376151711744);
+(+ 288230376151711744) => ($crate::consts::P288230376151711744);
+(- 288230376151711744) => ($crate::consts::N288230376151711744);
+(576460752303423487) => ($crate::consts::U576460752303423487);
+(+ 576460752303423487) => ($crate::consts::P576460752303423487);
+(- 576460752303423487) => ($crate::consts::N576460752303423487);
+(576460752303423488) => ($crate::consts::U576460752303423488);
+(+ 576460752303423488) => ($crate::consts::P576460752303423488);
+(- 576460752303423488) => ($crate::consts::N576460752303423488);
+(1152921504606846975) => ($crate::consts::U1152921504606846975);
+(+ 1152921504606846975) => ($crate::consts::P1152921504606846975);
+(- 1152921504606846975) => ($crate::consts::N1152921504606846975);
+(1152921504606846976) => ($crate::consts::U1152921504606846976);
+(+ 1152921504606846976) => ($crate::consts::P1152921504606846976);
+(- 1152921504606846976) => ($crate::consts::N1152921504606846976);
+(2305843009213693951) => ($crate::consts::U2305843009213693951);
+(+ 2305843009213693951) => ($crate::consts::P2305843009213693951);
+(- 2305843009213693951) => ($crate::consts::N2305843009213693951);
+(2305843009213693952) => ($crate::consts::U2305843009213693952);
+(+ 2305843009213693952) => ($crate::consts::P2305843009213693952);
+(- 2305843009213693952) => ($crate::consts::N2305843009213693952);
+(4611686018427387903) => ($crate::consts::U4611686018427387903);
+(+ 4611686018427387903) => ($crate::consts::P4611686018427387903);
+(- 4611686018427387903) => ($crate::consts::N4611686018427387903);
+(4611686018427387904) => ($crate::consts::U4611686018427387904);
+(+ 4611686018427387904) => ($crate::consts::P4611686018427387904);
+(- 4611686018427387904) => ($crate::consts::N4611686018427387904);
+(9223372036854775807) => ($crate::consts::U9223372036854775807);
+(+ 9223372036854775807) => ($crate::consts::P9223372036854775807);
+(- 9223372036854775807) => ($crate::consts::N9223372036854775807);
+(9223372036854775808) => ($crate::consts::U9223372036854775808);
+(10000) => ($crate::consts::U10000);
+(+ 10000) => ($crate::consts::P10000);
+(- 10000) => ($crate::consts::N10000);
+(100000) => ($crate::consts::U100000);
+(+ 100000) => ($crate::consts::P100000);
+(- 100000) => ($crate::consts::N100000);
+(1000000) => ($crate::consts::U1000000);
+(+ 1000000) => ($crate::consts::P1000000);
+(- 1000000) => ($crate::consts::N1000000);
+(10000000) => ($crate::consts::U10000000);
+(+ 10000000) => ($crate::consts::P10000000);
+(- 10000000) => ($crate::consts::N10000000);
+(100000000) => ($crate::consts::U100000000);
+(+ 100000000) => ($crate::consts::P100000000);
+(- 100000000) => ($crate::consts::N100000000);
+(1000000000) => ($crate::consts::U1000000000);
+(+ 1000000000) => ($crate::consts::P1000000000);
+(- 1000000000) => ($crate::consts::N1000000000);
+(10000000000) => ($crate::consts::U10000000000);
+(+ 10000000000) => ($crate::consts::P10000000000);
+(- 10000000000) => ($crate::consts::N10000000000);
+(100000000000) => ($crate::consts::U100000000000);
+(+ 100000000000) => ($crate::consts::P100000000000);
+(- 100000000000) => ($crate::consts::N100000000000);
+(1000000000000) => ($crate::consts::U1000000000000);
+(+ 1000000000000) => ($crate::consts::P1000000000000);
+(- 1000000000000) => ($crate::consts::N1000000000000);
+(10000000000000) => ($crate::consts::U10000000000000);
+(+ 10000000000000) => ($crate::consts::P10000000000000);
+(- 10000000000000) => ($crate::consts::N10000000000000);
+(100000000000000) => ($crate::consts::U100000000000000);
+(+ 100000000000000) => ($crate::consts::P100000000000000);
+(- 100000000000000) => ($crate::consts::N100000000000000);
+(1000000000000000) => ($crate::consts::U1000000000000000);
+(+ 1000000000000000) => ($crate::consts::P1000000000000000);
+(- 1000000000000000) => ($crate::consts::N1000000000000000);
+(10000000000000000) => ($crate::consts::U10000000000000000);
+(+ 10000000000000000) => ($crate::consts::P10000000000000000);
+(- 10000000000000000) => ($crate::consts::N10000000000000000);
+(100000000000000000) => ($crate::consts::U100000000000000000);
+(+ 100000000000000000) => ($crate::consts::P100000000000000000);
+(- 100000000000000000) => ($crate::consts::N100000000000000000);
+(1000000000000000000) => ($crate::consts::U1000000000000000000);
+(+ 1000000000000000000) => ($crate::consts::P1000000000000000000);
+(- 1000000000000000000) => ($crate::consts::N1000000000000000000);
+(10000000000000000000) => ($crate::consts::U10000000000000000000);
+($operand:ty) => ($operand);
+($($literal:tt)*) => (
+    compile_error!(concat!("`op!` has no constant for `", stringify!($($literal)*), "`"))
 );
 }
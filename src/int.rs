@@ -3,16 +3,22 @@
 //!
 //! Type **operators** implemented:
 //!
-//! From `core::ops`: `Add`, `Sub`, `Mul`, `Div`, and `Rem`.
-//! From `typenum`: `Same`, `Cmp`, and `Pow`.
+//! From `core::ops`: `BitAnd`, `BitOr`, `BitXor`, `Shl`, `Shr`, `Not`, `Add`, `Sub`, `Mul`,
+//! `Div`, and `Rem`.
+//! From `typenum`: `Same`, `Cmp`, `Pow`, `SquareRoot`, `Logarithm2`, `DivFloor`, `DivCeil`,
+//! `DivEuclid`, and `RemEuclid`.
 //!
 //! Rather than directly using the structs defined in this module, it is recommended that
 //! you import and use the relevant aliases from the [consts](../consts/index.html) module.
 //!
-//! Note that operators that work on the underlying structure of the number are
-//! intentionally not implemented. This is because this implementation of signed integers
-//! does *not* use twos-complement, and implementing them would require making arbitrary
-//! choices, causing the results of such operators to be difficult to reason about.
+//! Although signed integers are not stored in twos-complement, the bitwise operators
+//! behave as if they were, with infinite sign extension, so they agree with the primitive
+//! integers. Shifts take an unsigned shift amount, and shifting right rounds towards
+//! negative infinity, as `i64 >> n` does.
+//!
+//! Signed integers implement `Display`, `LowerHex`, `UpperHex`, and `Octal` like the
+//! primitive integers, except that negative numbers are written with a minus sign rather than
+//! in twos-complement. `Debug` writes the name of the constant instead.
 //!
 //! # Example
 //! ```rust
@@ -25,26 +31,46 @@
 //! assert_eq!(<N3 as Div<P2>>::Output::to_i32(), -1);
 //! assert_eq!(<N3 as Rem<P2>>::Output::to_i32(), -1);
 //! ```
+//!
+//! ```rust
+//! use typenum::{op, Integer, N5, N8, P3, U1};
+//!
+//! assert_eq!(<op!(N8 >> U1)>::to_i32(), -4);
+//! assert_eq!(<op!(N5 >> U1)>::to_i32(), -3);
+//! assert_eq!(<op!(N5 & P3)>::to_i32(), 3);
+//! assert_eq!(<op!(N5 | P3)>::to_i32(), -5);
+//! assert_eq!(<op!(N5 ^ P3)>::to_i32(), -8);
+//! ```
+//!
+//! ```rust
+//! use typenum::{N42, P255, Z0};
+//!
+//! assert_eq!(format!("{} {} {}", N42::new(), Z0::new(), P255::new()), "-42 0 255");
+//! assert_eq!(format!("{:x} {:#o} {:+}", N42::new(), P255::new(), P255::new()), "-2a 0o377 +255");
+//! assert_eq!(format!("{:?} {:?} {:?}", N42::new(), Z0::new(), P255::new()), "N42 Z0 P255");
+//! ```
 
 pub use crate::marker_traits::Integer;
 use crate::{
     bit::{Bit, B0, B1},
     consts::{N1, P1, U0, U1},
-    private::{Internal, InternalMarker, PrivateDivInt, PrivateIntegerAdd, PrivateRem},
-    uint::{UInt, Unsigned},
+    private::{
+        Internal, InternalMarker, PrivateDivInt, PrivateInteger, PrivateIntegerAdd, PrivateRem,
+    },
+    uint::{fmt_debug, fmt_radix, UInt, Unsigned},
     Cmp, Equal, Greater, Less, NonZero, Pow, PowerOfTwo, ToInt, Zero,
 };
 use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
 /// Type-level signed integers with positive sign.
-#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Default)]
 #[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
 pub struct PInt<U: Unsigned + NonZero> {
     pub(crate) n: U,
 }
 
 /// Type-level signed integers with negative sign.
-#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Default)]
 #[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
 pub struct NInt<U: Unsigned + NonZero> {
     pub(crate) n: U,
@@ -157,6 +183,19 @@ impl<U: Unsigned + NonZero> Integer for PInt<U> {
     }
 }
 
+impl PrivateInteger for Z0 {
+    const PRIVATE_BITS: usize = 0;
+}
+
+impl<U: Unsigned + NonZero> PrivateInteger for PInt<U> {
+    const PRIVATE_BITS: usize = U::PRIVATE_BITS + 1;
+}
+
+impl<U: Unsigned + NonZero> PrivateInteger for NInt<U> {
+    // `-2^n` needs no more bits than `2^n - 1`.
+    const PRIVATE_BITS: usize = U::PRIVATE_BITS + 1 - U::PRIVATE_IS_POWER_OF_TWO as usize;
+}
+
 // Simply negating the result of e.g. `U::I8` will result in overflow for `std::i8::MIN`. Instead,
 // we use the fact that `U: NonZero` by subtracting one from the `U::U8` before negating.
 impl<U: Unsigned + NonZero> Integer for NInt<U> {
@@ -193,6 +232,58 @@ impl<U: Unsigned + NonZero> Integer for NInt<U> {
     fn to_isize() -> isize {
         Self::ISIZE
     }
+
+    // The constants above may fail to evaluate for values that do not fit, so these do not use
+    // them.
+    #[inline]
+    fn try_to_i8() -> Option<i8> {
+        if Self::PRIVATE_BITS <= 8 {
+            Some((U::U8 as i8).wrapping_neg())
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn try_to_i16() -> Option<i16> {
+        if Self::PRIVATE_BITS <= 16 {
+            Some((U::U16 as i16).wrapping_neg())
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn try_to_i32() -> Option<i32> {
+        if Self::PRIVATE_BITS <= 32 {
+            Some((U::U32 as i32).wrapping_neg())
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn try_to_i64() -> Option<i64> {
+        if Self::PRIVATE_BITS <= 64 {
+            Some((U::U64 as i64).wrapping_neg())
+        } else {
+            None
+        }
+    }
+    #[cfg(feature = "i128")]
+    #[inline]
+    fn try_to_i128() -> Option<i128> {
+        if Self::PRIVATE_BITS <= 128 {
+            Some((U::U128 as i128).wrapping_neg())
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn try_to_isize() -> Option<isize> {
+        if Self::PRIVATE_BITS <= ::core::mem::size_of::<isize>() * 8 {
+            Some((U::USIZE as isize).wrapping_neg())
+        } else {
+            None
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------------------
@@ -216,6 +307,57 @@ impl<U: Unsigned + NonZero + core::fmt::Binary> core::fmt::Binary for NInt<U> {
     }
 }
 
+// ---------------------------------------------------------------------------------------
+// Formatting in decimal, hexadecimal, and octal
+
+macro_rules! impl_int_fmt_radix {
+    ($($Trait:ident: $radix:expr, $prefix:expr, $upper:expr;)*) => {
+        $(
+            impl core::fmt::$Trait for Z0 {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    fmt_radix::<U0>(f, true, $radix, $prefix, $upper)
+                }
+            }
+
+            impl<U: Unsigned + NonZero> core::fmt::$Trait for PInt<U> {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    fmt_radix::<U>(f, true, $radix, $prefix, $upper)
+                }
+            }
+
+            impl<U: Unsigned + NonZero> core::fmt::$Trait for NInt<U> {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    fmt_radix::<U>(f, false, $radix, $prefix, $upper)
+                }
+            }
+        )*
+    };
+}
+
+impl_int_fmt_radix! {
+    Display: 10, "", false;
+    LowerHex: 16, "0x", false;
+    UpperHex: 16, "0x", true;
+    Octal: 8, "0o", false;
+}
+
+/// Formats as the name of the constant, such as `P42`.
+impl<U: Unsigned + NonZero> core::fmt::Debug for PInt<U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_debug::<U>(f, "P")
+    }
+}
+
+/// Formats as the name of the constant, such as `N42`.
+impl<U: Unsigned + NonZero> core::fmt::Debug for NInt<U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_debug::<U>(f, "N")
+    }
+}
+
 // ---------------------------------------------------------------------------------------
 // Neg
 
@@ -630,6 +772,29 @@ impl_int_div!(PInt, NInt, NInt);
 impl_int_div!(NInt, PInt, NInt);
 impl_int_div!(NInt, NInt, PInt);
 
+#[cfg(feature = "diagnostics")]
+use crate::private::DivideByZero;
+
+macro_rules! impl_int_div_by_zero {
+    ($Op:ident, $fn:ident, $A:ident) => {
+        // `$A<U> $Op Z0`, only so that it can fail with an error naming `DivideByZero`
+        #[cfg(feature = "diagnostics")]
+        impl<U: Unsigned + NonZero> $Op<Z0> for $A<U>
+        where
+            $A<U>: DivideByZero,
+        {
+            type Output = Z0;
+            #[inline]
+            fn $fn(self, _: Z0) -> Self::Output {
+                Z0
+            }
+        }
+    };
+}
+
+impl_int_div_by_zero!(Div, div, PInt);
+impl_int_div_by_zero!(Div, div, NInt);
+
 // ---------------------------------------------------------------------------------------
 // PartialDiv
 
@@ -646,6 +811,116 @@ where
     }
 }
 
+// ---------------------------------------------------------------------------------------
+// DivFloor, DivCeil, DivEuclid
+//
+// Each of these is `|Self| / |Rhs|` rounded either down or up, with the sign fixed afterwards.
+use crate::{DivCeil, DivEuclid, DivFloor, Negate};
+
+/// `Z0 / I = Z0` where `I != 0`
+impl<I: Integer + NonZero> DivFloor<I> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn div_floor(self, _: I) -> Self::Output {
+        Z0
+    }
+}
+
+/// `Z0 / I = Z0` where `I != 0`
+impl<I: Integer + NonZero> DivCeil<I> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn div_ceil(self, _: I) -> Self::Output {
+        Z0
+    }
+}
+
+/// `Z0 / I = Z0` where `I != 0`
+impl<I: Integer + NonZero> DivEuclid<I> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn div_euclid(self, _: I) -> Self::Output {
+        Z0
+    }
+}
+
+macro_rules! impl_int_div_rounded {
+    ($Op:ident, $op:ident, $A:ident, $B:ident, $UOp:ident, $uop:ident) => {
+        impl<Ul, Ur> $Op<$B<Ur>> for $A<Ul>
+        where
+            Ul: Unsigned + NonZero + $UOp<Ur>,
+            Ur: Unsigned + NonZero,
+            <Ul as $UOp<Ur>>::Output: PrivateToInteger,
+        {
+            type Output = PrivateToIntegerOut<<Ul as $UOp<Ur>>::Output>;
+            #[inline]
+            fn $op(self, rhs: $B<Ur>) -> Self::Output {
+                self.n.$uop(rhs.n).private_to_integer()
+            }
+        }
+    };
+    ($Op:ident, $op:ident, $A:ident, $B:ident, -$UOp:ident, $uop:ident) => {
+        impl<Ul, Ur> $Op<$B<Ur>> for $A<Ul>
+        where
+            Ul: Unsigned + NonZero + $UOp<Ur>,
+            Ur: Unsigned + NonZero,
+            <Ul as $UOp<Ur>>::Output: PrivateToInteger,
+            PrivateToIntegerOut<<Ul as $UOp<Ur>>::Output>: Neg,
+        {
+            type Output = Negate<PrivateToIntegerOut<<Ul as $UOp<Ur>>::Output>>;
+            #[inline]
+            fn $op(self, rhs: $B<Ur>) -> Self::Output {
+                -self.n.$uop(rhs.n).private_to_integer()
+            }
+        }
+    };
+}
+
+// floor(P(Ul) / P(Ur)) = Ul / Ur
+impl_int_div_rounded!(DivFloor, div_floor, PInt, PInt, Div, div);
+// floor(P(Ul) / N(Ur)) = -ceil(Ul / Ur)
+impl_int_div_rounded!(DivFloor, div_floor, PInt, NInt, -DivCeil, div_ceil);
+// floor(N(Ul) / P(Ur)) = -ceil(Ul / Ur)
+impl_int_div_rounded!(DivFloor, div_floor, NInt, PInt, -DivCeil, div_ceil);
+// floor(N(Ul) / N(Ur)) = Ul / Ur
+impl_int_div_rounded!(DivFloor, div_floor, NInt, NInt, Div, div);
+
+// ceil(P(Ul) / P(Ur)) = ceil(Ul / Ur)
+impl_int_div_rounded!(DivCeil, div_ceil, PInt, PInt, DivCeil, div_ceil);
+// ceil(P(Ul) / N(Ur)) = -(Ul / Ur)
+impl_int_div_rounded!(DivCeil, div_ceil, PInt, NInt, -Div, div);
+// ceil(N(Ul) / P(Ur)) = -(Ul / Ur)
+impl_int_div_rounded!(DivCeil, div_ceil, NInt, PInt, -Div, div);
+// ceil(N(Ul) / N(Ur)) = ceil(Ul / Ur)
+impl_int_div_rounded!(DivCeil, div_ceil, NInt, NInt, DivCeil, div_ceil);
+
+// The Euclidean quotient rounds down for a positive divisor and up for a negative one.
+// P(Ul) div_euclid P(Ur) = Ul / Ur
+impl_int_div_rounded!(DivEuclid, div_euclid, PInt, PInt, Div, div);
+// P(Ul) div_euclid N(Ur) = -(Ul / Ur)
+impl_int_div_rounded!(DivEuclid, div_euclid, PInt, NInt, -Div, div);
+// N(Ul) div_euclid P(Ur) = -ceil(Ul / Ur)
+impl_int_div_rounded!(DivEuclid, div_euclid, NInt, PInt, -DivCeil, div_ceil);
+// N(Ul) div_euclid N(Ur) = ceil(Ul / Ur)
+impl_int_div_rounded!(DivEuclid, div_euclid, NInt, NInt, DivCeil, div_ceil);
+
+// ---------------------------------------------------------------------------------------
+// RemEuclid
+use crate::{Diff, Prod, QuotEuclid, RemEuclid};
+
+/// `M rem_euclid N = M - N * (M div_euclid N)`
+impl<M, N> RemEuclid<N> for M
+where
+    M: Integer + DivEuclid<N> + Sub<Prod<N, QuotEuclid<M, N>>>,
+    N: Integer + Mul<QuotEuclid<M, N>>,
+{
+    type Output = Diff<M, Prod<N, QuotEuclid<M, N>>>;
+    #[inline]
+    fn rem_euclid(self, rhs: N) -> Self::Output {
+        self - rhs * self.div_euclid(rhs)
+    }
+}
+
 // ---------------------------------------------------------------------------------------
 // Cmp
 
@@ -795,6 +1070,9 @@ impl_int_rem!(PInt, NInt, PInt);
 impl_int_rem!(NInt, PInt, NInt);
 impl_int_rem!(NInt, NInt, NInt);
 
+impl_int_div_by_zero!(Rem, rem, PInt);
+impl_int_div_by_zero!(Rem, rem, NInt);
+
 // ---------------------------------------------------------------------------------------
 // Pow
 
@@ -981,6 +1259,189 @@ where
     type Output = PInt<Gcf<U1, U2>>;
 }
 
+// ---------------------------------------------------------------------------------------
+// Lcm
+use crate::{Lcm, Scm};
+
+impl Lcm<Z0> for Z0 {
+    type Output = Z0;
+}
+
+impl<U> Lcm<PInt<U>> for Z0
+where
+    U: Unsigned + NonZero,
+{
+    type Output = Z0;
+}
+
+impl<U> Lcm<Z0> for PInt<U>
+where
+    U: Unsigned + NonZero,
+{
+    type Output = Z0;
+}
+
+impl<U> Lcm<NInt<U>> for Z0
+where
+    U: Unsigned + NonZero,
+{
+    type Output = Z0;
+}
+
+impl<U> Lcm<Z0> for NInt<U>
+where
+    U: Unsigned + NonZero,
+{
+    type Output = Z0;
+}
+
+impl<U1, U2> Lcm<PInt<U2>> for PInt<U1>
+where
+    U1: Unsigned + NonZero + Lcm<U2>,
+    U2: Unsigned + NonZero,
+    Scm<U1, U2>: Unsigned + NonZero,
+{
+    type Output = PInt<Scm<U1, U2>>;
+}
+
+impl<U1, U2> Lcm<PInt<U2>> for NInt<U1>
+where
+    U1: Unsigned + NonZero + Lcm<U2>,
+    U2: Unsigned + NonZero,
+    Scm<U1, U2>: Unsigned + NonZero,
+{
+    type Output = PInt<Scm<U1, U2>>;
+}
+
+impl<U1, U2> Lcm<NInt<U2>> for PInt<U1>
+where
+    U1: Unsigned + NonZero + Lcm<U2>,
+    U2: Unsigned + NonZero,
+    Scm<U1, U2>: Unsigned + NonZero,
+{
+    type Output = PInt<Scm<U1, U2>>;
+}
+
+impl<U1, U2> Lcm<NInt<U2>> for NInt<U1>
+where
+    U1: Unsigned + NonZero + Lcm<U2>,
+    U2: Unsigned + NonZero,
+    Scm<U1, U2>: Unsigned + NonZero,
+{
+    type Output = PInt<Scm<U1, U2>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// ExtGcd
+use crate::{
+    private::{PrivateExtGcd, PrivateExtGcdG, PrivateExtGcdX, PrivateExtGcdY},
+    ExtGcd, Mod,
+};
+
+impl<Y> ExtGcd<Y> for Z0
+where
+    Y: PrivateExtGcd<Z0>,
+{
+    type G = PrivateExtGcdG<Y, Z0>;
+    type X = PrivateExtGcdX<Y, Z0>;
+    type Y = PrivateExtGcdY<Y, Z0>;
+}
+
+impl<U, Y> ExtGcd<Y> for PInt<U>
+where
+    U: Unsigned + NonZero,
+    Y: PrivateExtGcd<PInt<U>>,
+{
+    type G = PrivateExtGcdG<Y, PInt<U>>;
+    type X = PrivateExtGcdX<Y, PInt<U>>;
+    type Y = PrivateExtGcdY<Y, PInt<U>>;
+}
+
+impl<U, Y> ExtGcd<Y> for NInt<U>
+where
+    U: Unsigned + NonZero,
+    Y: PrivateExtGcd<NInt<U>>,
+{
+    type G = PrivateExtGcdG<Y, NInt<U>>;
+    type X = PrivateExtGcdX<Y, NInt<U>>;
+    type Y = PrivateExtGcdY<Y, NInt<U>>;
+}
+
+/// Extended Euclid for (x, 0) is (x, 1, 0), or (-x, -1, 0) when x is negative
+impl PrivateExtGcd<Z0> for Z0 {
+    type G = Z0;
+    type X = P1;
+    type Y = Z0;
+}
+
+impl<U: Unsigned + NonZero> PrivateExtGcd<PInt<U>> for Z0 {
+    type G = PInt<U>;
+    type X = P1;
+    type Y = Z0;
+}
+
+impl<U: Unsigned + NonZero> PrivateExtGcd<NInt<U>> for Z0 {
+    type G = PInt<U>;
+    type X = N1;
+    type Y = Z0;
+}
+
+/// If extended Euclid for (y, x rem y) is (g, s, t), then for (x, y) it is (g, t, s - (x / y) t),
+/// with truncating division
+macro_rules! impl_int_ext_gcd {
+    ($Rhs:ident) => {
+        impl<X, U> PrivateExtGcd<X> for $Rhs<U>
+        where
+            X: Integer + Div<$Rhs<U>> + Rem<$Rhs<U>>,
+            U: Unsigned + NonZero,
+            Mod<X, $Rhs<U>>: PrivateExtGcd<$Rhs<U>>,
+            Quot<X, $Rhs<U>>: Mul<PrivateExtGcdY<Mod<X, $Rhs<U>>, $Rhs<U>>>,
+            PrivateExtGcdX<Mod<X, $Rhs<U>>, $Rhs<U>>:
+                Sub<Prod<Quot<X, $Rhs<U>>, PrivateExtGcdY<Mod<X, $Rhs<U>>, $Rhs<U>>>>,
+        {
+            type G = PrivateExtGcdG<Mod<X, $Rhs<U>>, $Rhs<U>>;
+            type X = PrivateExtGcdY<Mod<X, $Rhs<U>>, $Rhs<U>>;
+            type Y = Diff<
+                PrivateExtGcdX<Mod<X, $Rhs<U>>, $Rhs<U>>,
+                Prod<Quot<X, $Rhs<U>>, PrivateExtGcdY<Mod<X, $Rhs<U>>, $Rhs<U>>>,
+            >;
+        }
+    };
+}
+
+impl_int_ext_gcd!(PInt);
+impl_int_ext_gcd!(NInt);
+
+// ---------------------------------------------------------------------------------------
+// IsCoprime
+use crate::{Coprime, Eq, IsCoprime, IsEqual};
+
+macro_rules! impl_int_coprime {
+    ($(impl<$($U:ident),*> for $T:ty;)*) => {
+        $(
+            impl<$($U: Unsigned + NonZero,)* Y> IsCoprime<Y> for $T
+            where
+                $T: Gcd<Y>,
+                Gcf<$T, Y>: IsEqual<P1>,
+            {
+                type Output = Eq<Gcf<$T, Y>, P1>;
+            }
+
+            impl<$($U: Unsigned + NonZero,)* Y> Coprime<Y> for $T
+            where
+                $T: IsCoprime<Y, Output = B1>,
+            {
+            }
+        )*
+    };
+}
+
+impl_int_coprime! {
+    impl<> for Z0;
+    impl<U> for PInt<U>;
+    impl<U> for NInt<U>;
+}
+
 // ---------------------------------------------------------------------------------------
 // Min
 use crate::{Max, Maximum, Min, Minimum};
@@ -1200,6 +1661,568 @@ where
     }
 }
 
+// ---------------------------------------------------------------------------------------
+// Converting from unsigned integers
+use crate::private::{PrivateToInteger, PrivateToIntegerOut};
+use crate::uint::UTerm;
+
+/// `U0 -> Z0`
+impl PrivateToInteger for UTerm {
+    type Output = Z0;
+
+    #[inline]
+    fn private_to_integer(self) -> Self::Output {
+        Z0
+    }
+}
+
+/// `U -> P(U)` where `U != 0`
+impl<U: Unsigned, B: Bit> PrivateToInteger for UInt<U, B> {
+    type Output = PInt<UInt<U, B>>;
+
+    #[inline]
+    fn private_to_integer(self) -> Self::Output {
+        PInt { n: self }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// SquareRoot
+use crate::{private::PrivateSquareRoot, Sqrt, SquareRoot};
+
+// There is deliberately no impl for `NInt`, so `Sqrt<N9>` is a compile error; see the
+// `compile_fail` example on `SquareRoot`.
+
+/// `sqrt(0) = 0`
+impl PrivateSquareRoot for Z0 {
+    type Output = Z0;
+}
+
+/// `sqrt(P(U)) = P(sqrt(U))`
+impl<U> PrivateSquareRoot for PInt<U>
+where
+    U: Unsigned + NonZero + SquareRoot,
+    Sqrt<U>: Unsigned + NonZero,
+{
+    type Output = PInt<Sqrt<U>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Logarithm2
+use crate::{private::PrivateLogarithm2, Log2, Logarithm2};
+
+// There are deliberately no impls for `Z0` or `NInt`, so `Log2<Z0>` and `Log2<N8>` are compile
+// errors; see the `compile_fail` examples on `Logarithm2`.
+
+/// `log2(P(U)) = log2(U)`, as a signed integer
+impl<U> PrivateLogarithm2 for PInt<U>
+where
+    U: Unsigned + NonZero + Logarithm2,
+    Log2<U>: PrivateToInteger,
+{
+    type Output = PrivateToIntegerOut<Log2<U>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// IsPowerOfTwo
+use crate::{IsPow2, IsPowerOfTwo};
+
+/// Zero is not a power of two
+impl IsPowerOfTwo for Z0 {
+    type Output = B0;
+}
+
+/// `P(U)` is a power of two when `U` is
+impl<U: Unsigned + NonZero + IsPowerOfTwo> IsPowerOfTwo for PInt<U> {
+    type Output = IsPow2<U>;
+}
+
+/// Negative numbers are not powers of two
+impl<U: Unsigned + NonZero> IsPowerOfTwo for NInt<U> {
+    type Output = B0;
+}
+
+// ---------------------------------------------------------------------------------------
+// Not
+//
+// The bitwise operators below treat signed integers as if they were stored in infinitely wide
+// twos-complement, so `-X` is `!(X - 1)`, just like the primitive integers.
+use crate::{Add1, And, Or, Shleft, Shright, Sub1, Xor};
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+/// `!Z0 = N1`
+impl Not for Z0 {
+    type Output = N1;
+    #[inline]
+    fn not(self) -> Self::Output {
+        N1::new()
+    }
+}
+
+/// `!P(U) = N(U + 1)`
+impl<U> Not for PInt<U>
+where
+    U: Unsigned + NonZero + Add<B1>,
+    Add1<U>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<U>>;
+    #[inline]
+    fn not(self) -> Self::Output {
+        NInt { n: self.n + B1 }
+    }
+}
+
+/// `!N(U) = U - 1`
+impl<U> Not for NInt<U>
+where
+    U: Unsigned + NonZero + Sub<B1>,
+    Sub1<U>: PrivateToInteger,
+{
+    type Output = PrivateToIntegerOut<Sub1<U>>;
+    #[inline]
+    fn not(self) -> Self::Output {
+        (self.n - B1).private_to_integer()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// BitAnd
+
+/// `Z0 & I = Z0`
+impl<I: Integer> BitAnd<I> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn bitand(self, _: I) -> Self::Output {
+        Z0
+    }
+}
+
+/// `P(U) & Z0 = Z0`
+impl<U: Unsigned + NonZero> BitAnd<Z0> for PInt<U> {
+    type Output = Z0;
+    #[inline]
+    fn bitand(self, _: Z0) -> Self::Output {
+        Z0
+    }
+}
+
+/// `N(U) & Z0 = Z0`
+impl<U: Unsigned + NonZero> BitAnd<Z0> for NInt<U> {
+    type Output = Z0;
+    #[inline]
+    fn bitand(self, _: Z0) -> Self::Output {
+        Z0
+    }
+}
+
+/// `P(Ul) & P(Ur) = Ul & Ur`
+impl<Ul, Ur> BitAnd<PInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitAnd<Ur>,
+    Ur: Unsigned + NonZero,
+    And<Ul, Ur>: PrivateToInteger,
+{
+    type Output = PrivateToIntegerOut<And<Ul, Ur>>;
+    #[inline]
+    fn bitand(self, rhs: PInt<Ur>) -> Self::Output {
+        (self.n & rhs.n).private_to_integer()
+    }
+}
+
+/// `P(Ul) & N(Ur) = Ul & !(Ur - 1) = Ul ^ (Ul & (Ur - 1))`
+impl<Ul, Ur> BitAnd<NInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitAnd<Sub1<Ur>> + BitXor<And<Ul, Sub1<Ur>>>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Xor<Ul, And<Ul, Sub1<Ur>>>: PrivateToInteger,
+{
+    type Output = PrivateToIntegerOut<Xor<Ul, And<Ul, Sub1<Ur>>>>;
+    #[inline]
+    fn bitand(self, rhs: NInt<Ur>) -> Self::Output {
+        (self.n ^ (self.n & (rhs.n - B1))).private_to_integer()
+    }
+}
+
+/// `N(Ul) & P(Ur) = P(Ur) & N(Ul)`
+impl<Ul, Ur> BitAnd<PInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero,
+    Ur: Unsigned + NonZero,
+    PInt<Ur>: BitAnd<NInt<Ul>>,
+{
+    type Output = And<PInt<Ur>, NInt<Ul>>;
+    #[inline]
+    fn bitand(self, rhs: PInt<Ur>) -> Self::Output {
+        rhs & self
+    }
+}
+
+/// `N(Ul) & N(Ur) = !(Ul - 1) & !(Ur - 1) = N(((Ul - 1) | (Ur - 1)) + 1)`
+impl<Ul, Ur> BitAnd<NInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Sub1<Ul>: BitOr<Sub1<Ur>>,
+    Or<Sub1<Ul>, Sub1<Ur>>: Add<B1>,
+    Add1<Or<Sub1<Ul>, Sub1<Ur>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Or<Sub1<Ul>, Sub1<Ur>>>>;
+    #[inline]
+    fn bitand(self, rhs: NInt<Ur>) -> Self::Output {
+        NInt {
+            n: ((self.n - B1) | (rhs.n - B1)) + B1,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// BitOr
+
+/// `Z0 | I = I`
+impl<I: Integer> BitOr<I> for Z0 {
+    type Output = I;
+    #[inline]
+    fn bitor(self, rhs: I) -> Self::Output {
+        rhs
+    }
+}
+
+/// `P(U) | Z0 = P(U)`
+impl<U: Unsigned + NonZero> BitOr<Z0> for PInt<U> {
+    type Output = PInt<U>;
+    #[inline]
+    fn bitor(self, _: Z0) -> Self::Output {
+        self
+    }
+}
+
+/// `N(U) | Z0 = N(U)`
+impl<U: Unsigned + NonZero> BitOr<Z0> for NInt<U> {
+    type Output = NInt<U>;
+    #[inline]
+    fn bitor(self, _: Z0) -> Self::Output {
+        self
+    }
+}
+
+/// `P(Ul) | P(Ur) = P(Ul | Ur)`
+impl<Ul, Ur> BitOr<PInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitOr<Ur>,
+    Ur: Unsigned + NonZero,
+    Or<Ul, Ur>: Unsigned + NonZero,
+{
+    type Output = PInt<Or<Ul, Ur>>;
+    #[inline]
+    fn bitor(self, rhs: PInt<Ur>) -> Self::Output {
+        PInt { n: self.n | rhs.n }
+    }
+}
+
+/// `P(Ul) | N(Ur) = Ul | !(Ur - 1) = N(((Ur - 1) ^ ((Ur - 1) & Ul)) + 1)`
+impl<Ul, Ur> BitOr<NInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Sub1<Ur>: BitAnd<Ul> + BitXor<And<Sub1<Ur>, Ul>>,
+    Xor<Sub1<Ur>, And<Sub1<Ur>, Ul>>: Add<B1>,
+    Add1<Xor<Sub1<Ur>, And<Sub1<Ur>, Ul>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Xor<Sub1<Ur>, And<Sub1<Ur>, Ul>>>>;
+    #[inline]
+    fn bitor(self, rhs: NInt<Ur>) -> Self::Output {
+        NInt {
+            n: ((rhs.n - B1) ^ ((rhs.n - B1) & self.n)) + B1,
+        }
+    }
+}
+
+/// `N(Ul) | P(Ur) = P(Ur) | N(Ul)`
+impl<Ul, Ur> BitOr<PInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero,
+    Ur: Unsigned + NonZero,
+    PInt<Ur>: BitOr<NInt<Ul>>,
+{
+    type Output = Or<PInt<Ur>, NInt<Ul>>;
+    #[inline]
+    fn bitor(self, rhs: PInt<Ur>) -> Self::Output {
+        rhs | self
+    }
+}
+
+/// `N(Ul) | N(Ur) = !(Ul - 1) | !(Ur - 1) = N(((Ul - 1) & (Ur - 1)) + 1)`
+impl<Ul, Ur> BitOr<NInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Sub1<Ul>: BitAnd<Sub1<Ur>>,
+    And<Sub1<Ul>, Sub1<Ur>>: Add<B1>,
+    Add1<And<Sub1<Ul>, Sub1<Ur>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<And<Sub1<Ul>, Sub1<Ur>>>>;
+    #[inline]
+    fn bitor(self, rhs: NInt<Ur>) -> Self::Output {
+        NInt {
+            n: ((self.n - B1) & (rhs.n - B1)) + B1,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// BitXor
+
+/// `Z0 ^ I = I`
+impl<I: Integer> BitXor<I> for Z0 {
+    type Output = I;
+    #[inline]
+    fn bitxor(self, rhs: I) -> Self::Output {
+        rhs
+    }
+}
+
+/// `P(U) ^ Z0 = P(U)`
+impl<U: Unsigned + NonZero> BitXor<Z0> for PInt<U> {
+    type Output = PInt<U>;
+    #[inline]
+    fn bitxor(self, _: Z0) -> Self::Output {
+        self
+    }
+}
+
+/// `N(U) ^ Z0 = N(U)`
+impl<U: Unsigned + NonZero> BitXor<Z0> for NInt<U> {
+    type Output = NInt<U>;
+    #[inline]
+    fn bitxor(self, _: Z0) -> Self::Output {
+        self
+    }
+}
+
+/// `P(Ul) ^ P(Ur) = Ul ^ Ur`
+impl<Ul, Ur> BitXor<PInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitXor<Ur>,
+    Ur: Unsigned + NonZero,
+    Xor<Ul, Ur>: PrivateToInteger,
+{
+    type Output = PrivateToIntegerOut<Xor<Ul, Ur>>;
+    #[inline]
+    fn bitxor(self, rhs: PInt<Ur>) -> Self::Output {
+        (self.n ^ rhs.n).private_to_integer()
+    }
+}
+
+/// `P(Ul) ^ N(Ur) = Ul ^ !(Ur - 1) = N((Ul ^ (Ur - 1)) + 1)`
+impl<Ul, Ur> BitXor<NInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitXor<Sub1<Ur>>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Xor<Ul, Sub1<Ur>>: Add<B1>,
+    Add1<Xor<Ul, Sub1<Ur>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Xor<Ul, Sub1<Ur>>>>;
+    #[inline]
+    fn bitxor(self, rhs: NInt<Ur>) -> Self::Output {
+        NInt {
+            n: (self.n ^ (rhs.n - B1)) + B1,
+        }
+    }
+}
+
+/// `N(Ul) ^ P(Ur) = P(Ur) ^ N(Ul)`
+impl<Ul, Ur> BitXor<PInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero,
+    Ur: Unsigned + NonZero,
+    PInt<Ur>: BitXor<NInt<Ul>>,
+{
+    type Output = Xor<PInt<Ur>, NInt<Ul>>;
+    #[inline]
+    fn bitxor(self, rhs: PInt<Ur>) -> Self::Output {
+        rhs ^ self
+    }
+}
+
+/// `N(Ul) ^ N(Ur) = !(Ul - 1) ^ !(Ur - 1) = (Ul - 1) ^ (Ur - 1)`
+impl<Ul, Ur> BitXor<NInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Sub1<Ul>: BitXor<Sub1<Ur>>,
+    Xor<Sub1<Ul>, Sub1<Ur>>: PrivateToInteger,
+{
+    type Output = PrivateToIntegerOut<Xor<Sub1<Ul>, Sub1<Ur>>>;
+    #[inline]
+    fn bitxor(self, rhs: NInt<Ur>) -> Self::Output {
+        ((self.n - B1) ^ (rhs.n - B1)).private_to_integer()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Shl
+
+/// `Z0 << U = Z0`
+impl<U: Unsigned> Shl<U> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn shl(self, _: U) -> Self::Output {
+        Z0
+    }
+}
+
+/// `P(Ul) << Ur = P(Ul << Ur)`
+impl<Ul, Ur> Shl<Ur> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + Shl<Ur>,
+    Ur: Unsigned,
+    Shleft<Ul, Ur>: Unsigned + NonZero,
+{
+    type Output = PInt<Shleft<Ul, Ur>>;
+    #[inline]
+    fn shl(self, rhs: Ur) -> Self::Output {
+        PInt { n: self.n << rhs }
+    }
+}
+
+/// `N(Ul) << Ur = N(Ul << Ur)`
+impl<Ul, Ur> Shl<Ur> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Shl<Ur>,
+    Ur: Unsigned,
+    Shleft<Ul, Ur>: Unsigned + NonZero,
+{
+    type Output = NInt<Shleft<Ul, Ur>>;
+    #[inline]
+    fn shl(self, rhs: Ur) -> Self::Output {
+        NInt { n: self.n << rhs }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Shr
+//
+// Shifting right is arithmetic, so negative numbers round towards negative infinity.
+
+/// `Z0 >> U = Z0`
+impl<U: Unsigned> Shr<U> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn shr(self, _: U) -> Self::Output {
+        Z0
+    }
+}
+
+/// `P(Ul) >> Ur = Ul >> Ur`
+impl<Ul, Ur> Shr<Ur> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + Shr<Ur>,
+    Ur: Unsigned,
+    Shright<Ul, Ur>: PrivateToInteger,
+{
+    type Output = PrivateToIntegerOut<Shright<Ul, Ur>>;
+    #[inline]
+    fn shr(self, rhs: Ur) -> Self::Output {
+        (self.n >> rhs).private_to_integer()
+    }
+}
+
+/// `N(Ul) >> Ur = N(((Ul - 1) >> Ur) + 1)`
+impl<Ul, Ur> Shr<Ur> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned,
+    Sub1<Ul>: Shr<Ur>,
+    Shright<Sub1<Ul>, Ur>: Add<B1>,
+    Add1<Shright<Sub1<Ul>, Ur>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Shright<Sub1<Ul>, Ur>>>;
+    #[inline]
+    fn shr(self, rhs: Ur) -> Self::Output {
+        NInt {
+            n: ((self.n - B1) >> rhs) + B1,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// FitsIn
+use crate::{consts, FitsIn, IsLessOrEqual, Len, Length, True};
+
+macro_rules! impl_fits_in_unsigned {
+    ($($(#[$attr:meta])* $T:ty: $Bits:ty;)*) => {
+        $(
+            $(#[$attr])*
+            impl FitsIn<$T> for Z0 {}
+
+            $(#[$attr])*
+            impl<U> FitsIn<$T> for PInt<U>
+            where
+                U: Unsigned + NonZero + Len,
+                Length<U>: IsLessOrEqual<$Bits, Output = True>,
+            {
+            }
+        )*
+    };
+}
+
+macro_rules! impl_fits_in_signed {
+    ($($(#[$attr:meta])* $T:ty: $Bits:ty;)*) => {
+        $(
+            $(#[$attr])*
+            impl FitsIn<$T> for Z0 {}
+
+            $(#[$attr])*
+            impl<U> FitsIn<$T> for PInt<U>
+            where
+                U: Unsigned + NonZero + Len,
+                Length<U>: IsLessOrEqual<$Bits, Output = True>,
+            {
+            }
+
+            /// The magnitude may be one larger than for positive numbers, as in `i8::MIN`.
+            $(#[$attr])*
+            impl<U> FitsIn<$T> for NInt<U>
+            where
+                U: Unsigned + NonZero + Sub<B1>,
+                Sub1<U>: Len,
+                Length<Sub1<U>>: IsLessOrEqual<$Bits, Output = True>,
+            {
+            }
+        )*
+    };
+}
+
+impl_fits_in_unsigned! {
+    u8: consts::U8;
+    u16: consts::U16;
+    u32: consts::U32;
+    u64: consts::U64;
+    #[cfg(feature = "i128")]
+    u128: consts::U128;
+    #[cfg(target_pointer_width = "16")]
+    usize: consts::U16;
+    #[cfg(target_pointer_width = "32")]
+    usize: consts::U32;
+    #[cfg(target_pointer_width = "64")]
+    usize: consts::U64;
+}
+
+impl_fits_in_signed! {
+    i8: consts::U7;
+    i16: consts::U15;
+    i32: consts::U31;
+    i64: consts::U63;
+    #[cfg(feature = "i128")]
+    i128: consts::U127;
+    #[cfg(target_pointer_width = "16")]
+    isize: consts::U15;
+    #[cfg(target_pointer_width = "32")]
+    isize: consts::U31;
+    #[cfg(target_pointer_width = "64")]
+    isize: consts::U63;
+}
+
 // -----------------------------------------
 // ToInt
 
@@ -1390,10 +2413,140 @@ where
     const INT: i128 = Self::I128;
 }
 
+// -----------------------------------------
+// ToBytes
+#[cfg(feature = "const-generics")]
+use crate::private::{negate_le, PrivateBytes};
+use crate::{consts::U8, QuotCeil, ToBytes};
+
+impl ToBytes for Z0 {
+    type ByteLen = U0;
+}
+
+impl<U> ToBytes for PInt<U>
+where
+    U: Unsigned + NonZero + Len,
+    Length<U>: Add<B1>,
+    Add1<Length<U>>: DivCeil<U8>,
+    QuotCeil<Add1<Length<U>>, U8>: Unsigned,
+{
+    type ByteLen = QuotCeil<Add1<Length<U>>, U8>;
+}
+
+/// The length is that of `U - 1`, as in twos-complement `-2^n` needs no more bits than `2^n - 1`.
+impl<U> ToBytes for NInt<U>
+where
+    U: Unsigned + NonZero + Sub<B1>,
+    Sub1<U>: Len,
+    Length<Sub1<U>>: Add<B1>,
+    Add1<Length<Sub1<U>>>: DivCeil<U8>,
+    QuotCeil<Add1<Length<Sub1<U>>>, U8>: Unsigned,
+{
+    type ByteLen = QuotCeil<Add1<Length<Sub1<U>>>, U8>;
+}
+
+#[cfg(feature = "const-generics")]
+impl<const N: usize> PrivateBytes<N> for Z0 {
+    const PRIVATE_LE_BYTES: [u8; N] = [0; N];
+}
+
+#[cfg(feature = "const-generics")]
+impl<U: Unsigned + NonZero + PrivateBytes<N>, const N: usize> PrivateBytes<N> for PInt<U> {
+    const PRIVATE_LE_BYTES: [u8; N] = U::PRIVATE_LE_BYTES;
+}
+
+#[cfg(feature = "const-generics")]
+impl<U: Unsigned + NonZero + PrivateBytes<N>, const N: usize> PrivateBytes<N> for NInt<U> {
+    const PRIVATE_LE_BYTES: [u8; N] = negate_le(U::PRIVATE_LE_BYTES);
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{consts::*, Integer, ToInt};
 
+    #[test]
+    fn lcm() {
+        use crate::Scm;
+
+        assert_eq!(<Scm<Z0, N4>>::to_i32(), 0);
+        assert_eq!(<Scm<P4, Z0>>::to_i32(), 0);
+        assert_eq!(<Scm<N4, P6>>::to_i32(), 12);
+        assert_eq!(<Scm<N4, N6>>::to_i32(), 12);
+        assert_eq!(<Scm<P4, N6>>::to_i32(), 12);
+    }
+
+    #[test]
+    fn ext_gcd() {
+        use crate::{BezoutX, BezoutY, ExtGcd};
+
+        macro_rules! ext_gcd_test {
+            ($($a:ident, $b:ident => $g:expr, $x:expr, $y:expr;)*) => {
+                $(
+                    assert_eq!(<<$a as ExtGcd<$b>>::G as Integer>::to_i64(), $g);
+                    assert_eq!(<BezoutX<$a, $b>>::to_i64(), $x);
+                    assert_eq!(<BezoutY<$a, $b>>::to_i64(), $y);
+                )*
+            };
+        }
+
+        ext_gcd_test! {
+            Z0, Z0 => 0, 1, 0;
+            N7, Z0 => 7, -1, 0;
+            Z0, N7 => 7, 0, -1;
+            P240, P46 => 2, -9, 47;
+            N240, P46 => 2, 9, 47;
+            P240, N46 => 2, -9, -47;
+            N12, N18 => 6, 1, -1;
+        }
+    }
+
+    #[test]
+    fn coprime() {
+        use crate::{Bit, Coprimality, Coprime};
+
+        fn coprime<A: Coprime<B>, B>() {}
+
+        coprime::<N4, P9>();
+        coprime::<P1, Z0>();
+        assert!(!<Coprimality<Z0, Z0>>::to_bool());
+        assert!(!<Coprimality<N12, P64>>::to_bool());
+    }
+
+    #[cfg(feature = "const-generics")]
+    #[test]
+    fn to_bytes() {
+        use crate::ToBytes;
+
+        assert_eq!(Z0::to_le_bytes(), []);
+        assert_eq!(P1::to_le_bytes(), [1]);
+        assert_eq!(N1::to_le_bytes(), [0xff]);
+        assert_eq!(P127::to_le_bytes(), [0x7f]);
+        assert_eq!(N128::to_le_bytes(), [0x80]);
+        assert_eq!(P128::to_le_bytes(), [0x80, 0]);
+        assert_eq!(N129::to_le_bytes(), [0x7f, 0xff]);
+        assert_eq!(N129::to_be_bytes(), [0xff, 0x7f]);
+        assert_eq!(N32768::to_le_bytes(), (-32768i16).to_le_bytes());
+        let bytes: [u8; 3] = P32768::to_be_bytes();
+        assert_eq!(bytes, 32768i32.to_be_bytes()[1..]);
+    }
+
+    #[test]
+    fn checked_constants() {
+        assert_eq!(Z0::CHECKED_I8, 0);
+        assert_eq!(P127::CHECKED_I8, 127);
+        assert_eq!(N128::CHECKED_I8, -128);
+        assert_eq!(N32768::CHECKED_I16, -32768);
+        assert_eq!(P128::CHECKED_I16, 128);
+
+        assert_eq!(P127::try_to_i8(), Some(127));
+        assert_eq!(P128::try_to_i8(), None);
+        assert_eq!(N128::try_to_i8(), Some(-128));
+        assert_eq!(N129::try_to_i8(), None);
+        assert_eq!(N1::try_to_i8(), Some(-1));
+        assert_eq!(N65536::try_to_i16(), None);
+        assert_eq!(N65536::try_to_i32(), Some(-65536));
+    }
+
     #[test]
     fn to_ix_min() {
         assert_eq!(N128::to_i8(), ::core::i8::MIN);
@@ -59,6 +59,7 @@ pub mod int;
 pub mod marker_traits;
 pub mod operator_aliases;
 pub mod private;
+pub mod rational;
 pub mod type_operators;
 pub mod uint;
 
@@ -71,8 +72,9 @@ pub use crate::{
     int::{NInt, PInt},
     marker_traits::*,
     operator_aliases::*,
+    rational::Ratio,
     type_operators::*,
-    uint::{UInt, UTerm},
+    uint::{GetBit, GetBitOut, SetBit, SetBitOut, UInt, UTerm},
 };
 
 #[doc(no_inline)]
@@ -84,7 +86,44 @@ pub use crate::gen::generic_const_mappings;
 
 #[cfg(feature = "const-generics")]
 #[doc(no_inline)]
-pub use generic_const_mappings::{Const, ToUInt, U};
+pub use generic_const_mappings::{Const, ConstI, ToConst, ToInteger, ToUInt, I, U};
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use typenum_macros::__tn;
+
+/// Expands to the type of an integer literal, or of an array of them.
+///
+/// An unsigned literal is an unsigned integer and a signed one is a signed integer, so
+/// `tn!(42)` is `U42`, `tn!(+42)` is `P42`, and `tn!(-42)` is `N42`. If any element of an array
+/// has a sign, they all become signed integers. See
+/// [`typenum_macros::tn`](https://docs.rs/typenum-macros) for the details.
+///
+/// The generated types are named through this crate, so the macro works when `typenum` is
+/// renamed or re-exported by another crate. With this feature, [`op!`] reads its integer
+/// literals with `tn!` as well.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, op, tarr, tn, Integer, N2, P1, P3, U1016, U42};
+///
+/// assert_type_eq!(tn!(42), U42);
+/// assert_type_eq!(tn!([1, -2, 3]), tarr![P1, N2, P3]);
+///
+/// use typenum as numbers;
+/// assert_type_eq!(numbers::tn!(42), U42);
+///
+/// assert_type_eq!(op!(0x10 + 1_000), U1016);
+/// assert_eq!(<op!(-0xff * +1_000_000)>::I64, -255_000_000);
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+#[macro_export]
+macro_rules! tn {
+    ($($value:tt)*) => {
+        $crate::__tn!($crate; $($value)*)
+    };
+}
 
 /// A potential output from `Cmp`, this is the type equivalent to the enum variant
 /// `core::cmp::Ordering::Greater`.
@@ -146,10 +185,115 @@ macro_rules! assert_type {
     };
 }
 
+/// Asserts that `A < B`, for two unsigned or two signed integers.
+///
+/// Like the other assertions here, it may be used wherever an item may be, and fails to compile
+/// with an error naming the relation that does not hold.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_lt, N2, P1, U3, U5};
+///
+/// assert_lt!(U3, U5);
+/// assert_lt!(N2, P1);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{assert_lt, U5};
+///
+/// assert_lt!(U5, U5);
+/// ```
+#[macro_export]
+macro_rules! assert_lt {
+    ($a:ty, $b:ty) => {
+        const _: () = {
+            fn assert<T: $crate::private::AssertLt<$a, $b>>() {}
+            let _ = assert::<$crate::Le<$a, $b>>;
+        };
+    };
+}
+
+/// Asserts that `Lo <= N <= Hi`, so both bounds are inclusive.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_in_range, U1, U10, U5};
+///
+/// assert_in_range!(U5, U1, U10);
+/// assert_in_range!(U10, U1, U10);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{assert_in_range, U1, U10, U11};
+///
+/// assert_in_range!(U11, U1, U10);
+/// ```
+#[macro_export]
+macro_rules! assert_in_range {
+    ($n:ty, $lo:ty, $hi:ty) => {
+        const _: () = {
+            fn assert<T: $crate::private::AssertInRange<$n, $lo, $hi>>() {}
+            let _ = assert::<$crate::And<$crate::GrEq<$n, $lo>, $crate::LeEq<$n, $hi>>>;
+        };
+    };
+}
+
+/// Asserts that `D` divides `N`, that is, `N % D` is zero.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_divides, N12, P3, U0, U4, U12};
+///
+/// assert_divides!(U4, U12);
+/// assert_divides!(P3, N12);
+/// assert_divides!(U4, U0);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{assert_divides, U5, U12};
+///
+/// assert_divides!(U5, U12);
+/// ```
+#[macro_export]
+macro_rules! assert_divides {
+    ($d:ty, $n:ty) => {
+        const _: () = {
+            fn assert<T: $crate::private::AssertDivides<$d, $n>>() {}
+            let _ = assert::<$crate::Mod<$n, $d>>;
+        };
+    };
+}
+
+/// Asserts that `N` is a power of two, by requiring the [`PowerOfTwo`] marker trait.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_power_of_two, P64, U1, U1024};
+///
+/// assert_power_of_two!(U1);
+/// assert_power_of_two!(U1024);
+/// assert_power_of_two!(P64);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{assert_power_of_two, U1000};
+///
+/// assert_power_of_two!(U1000);
+/// ```
+#[macro_export]
+macro_rules! assert_power_of_two {
+    ($n:ty) => {
+        const _: () = {
+            fn assert<T: $crate::PowerOfTwo>() {}
+            let _ = assert::<$n>;
+        };
+    };
+}
+
 mod sealed {
     use crate::{
-        ATerm, Bit, Equal, Greater, Less, NInt, NonZero, PInt, TArr, UInt, UTerm, Unsigned, B0, B1,
-        Z0,
+        ATerm, Bit, Equal, Greater, Integer, Less, NInt, NonZero, PInt, Ratio, TArr, UInt, UTerm,
+        Unsigned, B0, B1, Z0,
     };
 
     pub trait Sealed {}
@@ -164,6 +308,8 @@ mod sealed {
     impl<U: Unsigned + NonZero> Sealed for PInt<U> {}
     impl<U: Unsigned + NonZero> Sealed for NInt<U> {}
 
+    impl<N: Integer, D: Unsigned + NonZero> Sealed for Ratio<N, D> {}
+
     impl Sealed for Less {}
     impl Sealed for Equal {}
     impl Sealed for Greater {}
@@ -15,23 +15,56 @@
 //! assert_eq!(-42, N42::I32);
 //! ```
 
-use crate::sealed::Sealed;
+use crate::{
+    private::{PrivateInteger, PrivateUnsigned},
+    sealed::Sealed,
+};
 
 /// A **marker trait** to designate that a type is not zero. All number types in this
 /// crate implement `NonZero` except `B0`, `U0`, and `Z0`.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is zero, but must not be",
+        label = "must not be zero",
+        note = "only `B0`, `U0`, and `Z0` are zero"
+    )
+)]
 pub trait NonZero: Sealed {}
 
 /// A **marker trait** to designate that a type is zero. Only `B0`, `U0`, and `Z0`
 /// implement this trait.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not zero",
+        label = "must be zero",
+        note = "only `B0`, `U0`, and `Z0` are zero"
+    )
+)]
 pub trait Zero: Sealed {}
 
 /// A **Marker trait** for the types `Greater`, `Equal`, and `Less`.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not an ordering",
+        note = "the orderings are `Less`, `Equal`, and `Greater`"
+    )
+)]
 pub trait Ord: Sealed {
     #[allow(missing_docs)]
     fn to_ordering() -> ::core::cmp::Ordering;
 }
 
 /// The **marker trait** for compile time bits.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not a bit",
+        label = "expected `B0` or `B1`"
+    )
+)]
 pub trait Bit: Sealed + Copy + Default + 'static {
     #[allow(missing_docs)]
     const U8: u8;
@@ -56,7 +89,39 @@ pub trait Bit: Sealed + Copy + Default + 'static {
 /// assert_eq!(U3::to_u32(), 3);
 /// assert_eq!(U3::I32, 3);
 /// ```
-pub trait Unsigned: Sealed + Copy + Default + 'static {
+///
+/// The constants and functions for each primitive type truncate values that do not fit. The
+/// checked constants, such as `CHECKED_U8`, fail to compile instead, and the `try_to_*`
+/// functions return `None`:
+///
+/// ```rust
+/// use typenum::{Unsigned, U300};
+///
+/// assert_eq!(U300::U8, 44);
+/// assert_eq!(U300::CHECKED_U16, 300);
+/// assert_eq!(U300::try_to_u8(), None);
+/// assert_eq!(U300::try_to_i16(), Some(300));
+/// ```
+///
+/// Using `CHECKED_U8` here is an error (`E0080`) when the program is built, as 300 does not
+/// fit in a `u8`:
+///
+/// ```rust,no_run
+/// use typenum::{Unsigned, U300};
+///
+/// assert_eq!(U300::CHECKED_U8, 44);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not an unsigned integer",
+        label = "expected an unsigned integer, such as `U42`",
+        note = "signed integers, such as `P42`, are not unsigned integers"
+    )
+)]
+// `PrivateUnsigned` is a supertrait so that the default checked constants and `try_to_*`
+// methods can read `PRIVATE_BITS`; associated constants cannot carry bounds of their own.
+pub trait Unsigned: Sealed + PrivateUnsigned + Copy + Default + 'static {
     #[allow(missing_docs)]
     const U8: u8;
     #[allow(missing_docs)]
@@ -112,6 +177,161 @@ pub trait Unsigned: Sealed + Copy + Default + 'static {
     fn to_i128() -> i128;
     #[allow(missing_docs)]
     fn to_isize() -> isize;
+
+    // Indexing out of bounds is a const-evaluation error, so the checked constants fail to
+    // compile for values that do not fit.
+
+    /// Like [`U8`](Self::U8), but fails to compile instead of truncating a value that does
+    /// not fit in a `u8`.
+    const CHECKED_U8: u8 = [Self::U8][(Self::PRIVATE_BITS > 8) as usize];
+    /// Like [`U16`](Self::U16), but fails to compile instead of truncating a value that does
+    /// not fit in a `u16`.
+    const CHECKED_U16: u16 = [Self::U16][(Self::PRIVATE_BITS > 16) as usize];
+    /// Like [`U32`](Self::U32), but fails to compile instead of truncating a value that does
+    /// not fit in a `u32`.
+    const CHECKED_U32: u32 = [Self::U32][(Self::PRIVATE_BITS > 32) as usize];
+    /// Like [`U64`](Self::U64), but fails to compile instead of truncating a value that does
+    /// not fit in a `u64`.
+    const CHECKED_U64: u64 = [Self::U64][(Self::PRIVATE_BITS > 64) as usize];
+    /// Like [`U128`](Self::U128), but fails to compile instead of truncating a value that does
+    /// not fit in a `u128`.
+    #[cfg(feature = "i128")]
+    const CHECKED_U128: u128 = [Self::U128][(Self::PRIVATE_BITS > 128) as usize];
+    /// Like [`USIZE`](Self::USIZE), but fails to compile instead of truncating a value that does
+    /// not fit in a `usize`.
+    const CHECKED_USIZE: usize =
+        [Self::USIZE][(Self::PRIVATE_BITS > ::core::mem::size_of::<usize>() * 8) as usize];
+    /// Like [`I8`](Self::I8), but fails to compile instead of truncating a value that does
+    /// not fit in an `i8`.
+    const CHECKED_I8: i8 = [Self::I8][(Self::PRIVATE_BITS >= 8) as usize];
+    /// Like [`I16`](Self::I16), but fails to compile instead of truncating a value that does
+    /// not fit in an `i16`.
+    const CHECKED_I16: i16 = [Self::I16][(Self::PRIVATE_BITS >= 16) as usize];
+    /// Like [`I32`](Self::I32), but fails to compile instead of truncating a value that does
+    /// not fit in an `i32`.
+    const CHECKED_I32: i32 = [Self::I32][(Self::PRIVATE_BITS >= 32) as usize];
+    /// Like [`I64`](Self::I64), but fails to compile instead of truncating a value that does
+    /// not fit in an `i64`.
+    const CHECKED_I64: i64 = [Self::I64][(Self::PRIVATE_BITS >= 64) as usize];
+    /// Like [`I128`](Self::I128), but fails to compile instead of truncating a value that does
+    /// not fit in an `i128`.
+    #[cfg(feature = "i128")]
+    const CHECKED_I128: i128 = [Self::I128][(Self::PRIVATE_BITS >= 128) as usize];
+    /// Like [`ISIZE`](Self::ISIZE), but fails to compile instead of truncating a value that does
+    /// not fit in an `isize`.
+    const CHECKED_ISIZE: isize =
+        [Self::ISIZE][(Self::PRIVATE_BITS >= ::core::mem::size_of::<isize>() * 8) as usize];
+
+    /// Returns the value as a `u8`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_u8() -> Option<u8> {
+        if Self::PRIVATE_BITS <= 8 {
+            Some(Self::to_u8())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as a `u16`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_u16() -> Option<u16> {
+        if Self::PRIVATE_BITS <= 16 {
+            Some(Self::to_u16())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as a `u32`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_u32() -> Option<u32> {
+        if Self::PRIVATE_BITS <= 32 {
+            Some(Self::to_u32())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as a `u64`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_u64() -> Option<u64> {
+        if Self::PRIVATE_BITS <= 64 {
+            Some(Self::to_u64())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as a `u128`, or `None` if it does not fit.
+    #[cfg(feature = "i128")]
+    #[inline]
+    fn try_to_u128() -> Option<u128> {
+        if Self::PRIVATE_BITS <= 128 {
+            Some(Self::to_u128())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as a `usize`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_usize() -> Option<usize> {
+        if Self::PRIVATE_BITS <= ::core::mem::size_of::<usize>() * 8 {
+            Some(Self::to_usize())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i8`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_i8() -> Option<i8> {
+        if Self::PRIVATE_BITS < 8 {
+            Some(Self::to_i8())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i16`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_i16() -> Option<i16> {
+        if Self::PRIVATE_BITS < 16 {
+            Some(Self::to_i16())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i32`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_i32() -> Option<i32> {
+        if Self::PRIVATE_BITS < 32 {
+            Some(Self::to_i32())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i64`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_i64() -> Option<i64> {
+        if Self::PRIVATE_BITS < 64 {
+            Some(Self::to_i64())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i128`, or `None` if it does not fit.
+    #[cfg(feature = "i128")]
+    #[inline]
+    fn try_to_i128() -> Option<i128> {
+        if Self::PRIVATE_BITS < 128 {
+            Some(Self::to_i128())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `isize`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_isize() -> Option<isize> {
+        if Self::PRIVATE_BITS < ::core::mem::size_of::<isize>() * 8 {
+            Some(Self::to_isize())
+        } else {
+            None
+        }
+    }
 }
 
 /// The **marker trait** for compile time signed integers.
@@ -123,7 +343,35 @@ pub trait Unsigned: Sealed + Copy + Default + 'static {
 /// assert_eq!(P3::to_i32(), 3);
 /// assert_eq!(P3::I32, 3);
 /// ```
-pub trait Integer: Sealed + Copy + Default + 'static {
+///
+/// As with [`Unsigned`], there are checked constants and `try_to_*` functions that do not
+/// truncate:
+///
+/// ```rust
+/// use typenum::{Integer, N128, N129};
+///
+/// assert_eq!(N128::CHECKED_I8, -128);
+/// assert_eq!(N129::try_to_i8(), None);
+/// ```
+///
+/// Using `CHECKED_I8` here is an error (`E0080`) when the program is built, as 128 does not
+/// fit in an `i8`:
+///
+/// ```rust,no_run
+/// use typenum::{Integer, P128};
+///
+/// assert_eq!(P128::CHECKED_I8, 127);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not a signed integer",
+        label = "expected a signed integer, such as `P42`, `N42`, or `Z0`",
+        note = "unsigned integers, such as `U42`, are not signed integers"
+    )
+)]
+// `PrivateInteger` is a supertrait for the same reason as `PrivateUnsigned` on `Unsigned`.
+pub trait Integer: Sealed + PrivateInteger + Copy + Default + 'static {
     #[allow(missing_docs)]
     const I8: i8;
     #[allow(missing_docs)]
@@ -151,13 +399,127 @@ pub trait Integer: Sealed + Copy + Default + 'static {
     fn to_i128() -> i128;
     #[allow(missing_docs)]
     fn to_isize() -> isize;
+
+    // The checked constants use the same out-of-bounds indexing trick as in `Unsigned`.
+
+    /// Like [`I8`](Self::I8), but fails to compile instead of truncating a value that does
+    /// not fit in an `i8`.
+    const CHECKED_I8: i8 = [Self::I8][(Self::PRIVATE_BITS > 8) as usize];
+    /// Like [`I16`](Self::I16), but fails to compile instead of truncating a value that does
+    /// not fit in an `i16`.
+    const CHECKED_I16: i16 = [Self::I16][(Self::PRIVATE_BITS > 16) as usize];
+    /// Like [`I32`](Self::I32), but fails to compile instead of truncating a value that does
+    /// not fit in an `i32`.
+    const CHECKED_I32: i32 = [Self::I32][(Self::PRIVATE_BITS > 32) as usize];
+    /// Like [`I64`](Self::I64), but fails to compile instead of truncating a value that does
+    /// not fit in an `i64`.
+    const CHECKED_I64: i64 = [Self::I64][(Self::PRIVATE_BITS > 64) as usize];
+    /// Like [`I128`](Self::I128), but fails to compile instead of truncating a value that does
+    /// not fit in an `i128`.
+    #[cfg(feature = "i128")]
+    const CHECKED_I128: i128 = [Self::I128][(Self::PRIVATE_BITS > 128) as usize];
+    /// Like [`ISIZE`](Self::ISIZE), but fails to compile instead of truncating a value that does
+    /// not fit in an `isize`.
+    const CHECKED_ISIZE: isize =
+        [Self::ISIZE][(Self::PRIVATE_BITS > ::core::mem::size_of::<isize>() * 8) as usize];
+
+    /// Returns the value as an `i8`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_i8() -> Option<i8> {
+        if Self::PRIVATE_BITS <= 8 {
+            Some(Self::to_i8())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i16`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_i16() -> Option<i16> {
+        if Self::PRIVATE_BITS <= 16 {
+            Some(Self::to_i16())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i32`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_i32() -> Option<i32> {
+        if Self::PRIVATE_BITS <= 32 {
+            Some(Self::to_i32())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i64`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_i64() -> Option<i64> {
+        if Self::PRIVATE_BITS <= 64 {
+            Some(Self::to_i64())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `i128`, or `None` if it does not fit.
+    #[cfg(feature = "i128")]
+    #[inline]
+    fn try_to_i128() -> Option<i128> {
+        if Self::PRIVATE_BITS <= 128 {
+            Some(Self::to_i128())
+        } else {
+            None
+        }
+    }
+    /// Returns the value as an `isize`, or `None` if it does not fit.
+    #[inline]
+    fn try_to_isize() -> Option<isize> {
+        if Self::PRIVATE_BITS <= ::core::mem::size_of::<isize>() * 8 {
+            Some(Self::to_isize())
+        } else {
+            None
+        }
+    }
+}
+
+/// The **marker trait** for compile time rational numbers.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Ratio, Rational, N3, U4};
+///
+/// assert_eq!(<Ratio<N3, U4>>::NUM, -3);
+/// assert_eq!(<Ratio<N3, U4>>::DEN, 4);
+/// assert_eq!(<Ratio<N3, U4>>::to_f64(), -0.75);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not a rational number",
+        label = "expected a `Ratio`"
+    )
+)]
+pub trait Rational: Sealed + Copy + Default + 'static {
+    /// The numerator, which carries the sign.
+    const NUM: i64;
+    /// The denominator, which is always positive.
+    const DEN: u64;
+
+    #[allow(missing_docs)]
+    fn to_f32() -> f32;
+    #[allow(missing_docs)]
+    fn to_f64() -> f64;
 }
 
 /// The **marker trait** for type-level arrays of type-level numbers.
 ///
-/// Someday, it may contain an associated constant to produce a runtime array,
-/// like the other marker traits here. However, that is blocked by [this
-/// issue](https://github.com/rust-lang/rust/issues/44168).
+/// To get the values of the elements, use [`ToArray`](crate::ToArray), or
+/// [`ToConstArray`](crate::ToConstArray) for a constant array.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not a type-level array",
+        label = "expected an array, such as `tarr![P1, P2]`"
+    )
+)]
 pub trait TypeArray: Sealed {}
 
 /// The **marker trait** for type-level numbers which are a power of two.
@@ -186,4 +548,144 @@ pub trait TypeArray: Sealed {}
 /// only_p2::<P511>();
 /// only_p2::<P1023>();
 /// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not a power of two",
+        label = "must be a power of two"
+    )
+)]
 pub trait PowerOfTwo: Sealed {}
+
+/// The **marker trait** for type-level numbers whose value can be represented by the primitive
+/// integer type `T`.
+///
+/// The constants of `Unsigned` and `Integer`, such as `Unsigned::U8`, silently truncate values
+/// that do not fit. Requiring `FitsIn` rules that out at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use typenum::{FitsIn, Unsigned, N128, U255};
+///
+/// fn to_byte<N: Unsigned + FitsIn<u8>>() -> u8 {
+///     N::U8
+/// }
+///
+/// assert_eq!(to_byte::<U255>(), 255);
+///
+/// fn only_i8<N: FitsIn<i8>>() {}
+///
+/// only_i8::<N128>();
+/// ```
+///
+/// Values that are too large fail to compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{FitsIn, Unsigned, U256};
+///
+/// fn to_byte<N: Unsigned + FitsIn<u8>>() -> u8 {
+///     N::U8
+/// }
+///
+/// to_byte::<U256>();
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not fit in `{T}`",
+        label = "must be representable by `{T}`"
+    )
+)]
+pub trait FitsIn<T>: Sealed {}
+
+#[test]
+fn fits_in_test() {
+    use crate::consts::*;
+
+    fn fits_in<T, N: FitsIn<T>>() {}
+
+    fits_in::<u8, U0>();
+    fits_in::<u8, U255>();
+    fits_in::<i8, U127>();
+    fits_in::<u16, U65535>();
+    fits_in::<i16, U32767>();
+
+    fits_in::<i8, N128>();
+    fits_in::<i8, P127>();
+    fits_in::<i8, Z0>();
+    fits_in::<u8, P255>();
+    fits_in::<u8, Z0>();
+    fits_in::<i16, N32768>();
+    fits_in::<i16, P32767>();
+}
+
+/// The **marker trait** for unsigned integers which are prime.
+///
+/// # Examples
+///
+/// ```rust
+/// use typenum::{Prime, Unsigned, U1009, U2, U7};
+///
+/// fn only_prime<P: Unsigned + Prime>() -> u32 {
+///     P::U32
+/// }
+///
+/// assert_eq!(only_prime::<U2>(), 2);
+/// assert_eq!(only_prime::<U7>(), 7);
+/// assert_eq!(only_prime::<U1009>(), 1009);
+/// ```
+///
+/// Composite numbers, zero, and one fail to compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{Prime, U1001};
+///
+/// fn only_prime<P: Prime>() {}
+///
+/// only_prime::<U1001>();
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not prime",
+        label = "must be a prime number"
+    )
+)]
+pub trait Prime: Sealed {}
+
+/// The **marker trait** for integers which are coprime to `Rhs`, having no common factor other
+/// than one.
+///
+/// # Examples
+///
+/// ```rust
+/// use typenum::{Coprime, Unsigned, U3, U64};
+///
+/// // A stride coprime to the length visits every index once.
+/// fn visit<Len: Unsigned, Stride: Unsigned + Coprime<Len>>() -> Vec<usize> {
+///     (0..Len::USIZE)
+///         .map(|i| i * Stride::USIZE % Len::USIZE)
+///         .collect()
+/// }
+///
+/// let mut order = visit::<U64, U3>();
+/// order.sort();
+/// assert_eq!(order, (0..64).collect::<Vec<_>>());
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{Coprime, U4, U64};
+///
+/// fn only_coprime<A: Coprime<B>, B>() {}
+///
+/// only_coprime::<U4, U64>();
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` and `{Rhs}` are not coprime",
+        label = "must have no common factor with `{Rhs}` other than one"
+    )
+)]
+pub trait Coprime<Rhs>: Sealed {}
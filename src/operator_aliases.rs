@@ -18,9 +18,15 @@
 
 // Aliases!!!
 use crate::type_operators::{
-    Abs, Cmp, FoldAdd, FoldMul, Gcd, Len, Logarithm2, Max, Min, PartialDiv, Pow, SquareRoot,
+    Abs, AddMod, CeilLogarithm2, CheckedAdd, Cmp, Concat, Contains, CountOnes, CountZeros,
+    CubeRoot, Dedup, DivCeil, DivEuclid, DivFloor, DotProduct, ExtGcd, Factorize, Filter, Fold,
+    FoldAdd, FoldAnd, FoldMax, FoldMin, FoldMul, FoldOr, Gcd, Get, IfThenElse, Implies, IsCoprime,
+    IsPowerOfTwo, IsPrime, IsSorted, Lcm, LeadingZeros, Len, Logarithm, Logarithm2, Map, Max, Min,
+    ModInverse, MulMod, NextPowerOfTwo, NthRoot, PartialDiv, Pop, Pow, PowMod, PrevPowerOfTwo,
+    Push, RemEuclid, Reverse, ReverseBits, SaturatingSub, Skip, SmallestFactor, Sort, SortDesc,
+    SquareRoot, Take, TrailingZeros, TypeFn, WrappingAdd, ZipWith,
 };
-use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Shl, Shr, Sub};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 
 /// Alias for the associated type of `BitAnd`: `And<A, B> = <A as BitAnd<B>>::Output`
 pub type And<A, B> = <A as BitAnd<B>>::Output;
@@ -28,6 +34,8 @@ pub type And<A, B> = <A as BitAnd<B>>::Output;
 pub type Or<A, B> = <A as BitOr<B>>::Output;
 /// Alias for the associated type of `BitXor`: `Xor<A, B> = <A as BitXor<B>>::Output`
 pub type Xor<A, B> = <A as BitXor<B>>::Output;
+/// Alias for the associated type of `Not`: `Complement<A> = <A as Not>::Output`
+pub type Complement<A> = <A as Not>::Output;
 
 /// Alias for the associated type of `Shl`: `Shleft<A, B> = <A as Shl<B>>::Output`
 pub type Shleft<A, B> = <A as Shl<B>>::Output;
@@ -49,6 +57,15 @@ pub type Mod<A, B> = <A as Rem<B>>::Output;
 /// `PartialDiv`: `PartialQuot<A, B> = <A as PartialDiv<B>>::Output`
 pub type PartialQuot<A, B> = <A as PartialDiv<B>>::Output;
 
+/// Alias for the associated type of `DivFloor`: `QuotFloor<A, B> = <A as DivFloor<B>>::Output`
+pub type QuotFloor<A, B> = <A as DivFloor<B>>::Output;
+/// Alias for the associated type of `DivCeil`: `QuotCeil<A, B> = <A as DivCeil<B>>::Output`
+pub type QuotCeil<A, B> = <A as DivCeil<B>>::Output;
+/// Alias for the associated type of `DivEuclid`: `QuotEuclid<A, B> = <A as DivEuclid<B>>::Output`
+pub type QuotEuclid<A, B> = <A as DivEuclid<B>>::Output;
+/// Alias for the associated type of `RemEuclid`: `ModEuclid<A, B> = <A as RemEuclid<B>>::Output`
+pub type ModEuclid<A, B> = <A as RemEuclid<B>>::Output;
+
 /// Alias for the associated type of `Neg`: `Negate<A> = <A as Neg>::Output`
 pub type Negate<A> = <A as Neg>::Output;
 
@@ -89,6 +106,115 @@ pub type FoldSum<A> = <A as FoldAdd>::Output;
 /// Alias for the associated type of `FoldMul`: `FoldProd<A> = <A as FoldMul>::Output`
 pub type FoldProd<A> = <A as FoldMul>::Output;
 
+/// Alias for the associated type of `Get`: `At<A, I> = <A as Get<I>>::Output`
+pub type At<A, I> = <A as Get<I>>::Output;
+/// Alias for the associated type of `Push`: `Pushed<A, V> = <A as Push<V>>::Output`
+pub type Pushed<A, V> = <A as Push<V>>::Output;
+/// Alias for the associated type of `Pop`: `Popped<A> = <A as Pop>::Output`
+pub type Popped<A> = <A as Pop>::Output;
+/// Alias for the associated type `Last` of `Pop`: `Last<A> = <A as Pop>::Last`
+pub type Last<A> = <A as Pop>::Last;
+/// Alias for the associated type of `Concat`: `Concatenated<A, B> = <A as Concat<B>>::Output`
+pub type Concatenated<A, B> = <A as Concat<B>>::Output;
+/// Alias for the associated type of `Reverse`: `Reversed<A> = <A as Reverse>::Output`
+pub type Reversed<A> = <A as Reverse>::Output;
+/// Alias for the associated type of `Take`: `Taken<A, N> = <A as Take<N>>::Output`
+pub type Taken<A, N> = <A as Take<N>>::Output;
+/// Alias for the associated type of `Skip`: `Skipped<A, N> = <A as Skip<N>>::Output`
+pub type Skipped<A, N> = <A as Skip<N>>::Output;
+/// Alias for the associated type of `Contains`: `Contained<A, V> = <A as Contains<V>>::Output`
+pub type Contained<A, V> = <A as Contains<V>>::Output;
+/// Alias for the associated type of `FoldMax`: `FoldMaximum<A> = <A as FoldMax>::Output`
+pub type FoldMaximum<A> = <A as FoldMax>::Output;
+/// Alias for the associated type of `FoldMin`: `FoldMinimum<A> = <A as FoldMin>::Output`
+pub type FoldMinimum<A> = <A as FoldMin>::Output;
+/// Alias for the associated type of `DotProduct`: `Dot<A, B> = <A as DotProduct<B>>::Output`
+pub type Dot<A, B> = <A as DotProduct<B>>::Output;
+
+/// Alias for the associated type of `Sort`: `Sorted<A> = <A as Sort>::Output`
+pub type Sorted<A> = <A as Sort>::Output;
+/// Alias for the associated type of `SortDesc`: `SortedDesc<A> = <A as SortDesc>::Output`
+pub type SortedDesc<A> = <A as SortDesc>::Output;
+/// Alias for the associated type of `Dedup`: `Deduped<A> = <A as Dedup>::Output`
+pub type Deduped<A> = <A as Dedup>::Output;
+/// Alias for the associated type of `IsSorted`: `InOrder<A> = <A as IsSorted>::Output`
+pub type InOrder<A> = <A as IsSorted>::Output;
+
+/// Alias for the associated type of `TypeFn`: `Apply<F, Arg> = <F as TypeFn<Arg>>::Output`
+pub type Apply<F, Arg> = <F as TypeFn<Arg>>::Output;
+/// Alias for the associated type of `Map`: `Mapped<A, F> = <A as Map<F>>::Output`
+pub type Mapped<A, F> = <A as Map<F>>::Output;
+/// Alias for the associated type of `ZipWith`:
+/// `ZippedWith<A, F, B> = <A as ZipWith<F, B>>::Output`
+pub type ZippedWith<A, F, B> = <A as ZipWith<F, B>>::Output;
+/// Alias for the associated type of `Filter`: `Filtered<A, P> = <A as Filter<P>>::Output`
+pub type Filtered<A, P> = <A as Filter<P>>::Output;
+/// Alias for the associated type of `Fold`: `Folded<A, F, Init> = <A as Fold<F, Init>>::Output`
+pub type Folded<A, F, Init> = <A as Fold<F, Init>>::Output;
+
+/// Alias for the associated type of `IfThenElse`:
+/// `If<C, Then, Else> = <C as IfThenElse<Then, Else>>::Output`
+pub type If<C, Then, Else> = <C as IfThenElse<Then, Else>>::Output;
+/// Alias for the associated type of `Implies`: `Imply<A, B> = <A as Implies<B>>::Output`
+pub type Imply<A, B> = <A as Implies<B>>::Output;
+/// Alias for the associated type of `FoldAnd`: `FoldAll<A> = <A as FoldAnd>::Output`
+pub type FoldAll<A> = <A as FoldAnd>::Output;
+/// Alias for the associated type of `FoldOr`: `FoldAny<A> = <A as FoldOr>::Output`
+pub type FoldAny<A> = <A as FoldOr>::Output;
+
+/// Alias for the associated type of `AddMod`: `SumMod<A, B, M> = <A as AddMod<B, M>>::Output`
+pub type SumMod<A, B, M> = <A as AddMod<B, M>>::Output;
+/// Alias for the associated type of `MulMod`: `ProdMod<A, B, M> = <A as MulMod<B, M>>::Output`
+pub type ProdMod<A, B, M> = <A as MulMod<B, M>>::Output;
+/// Alias for the associated type of `PowMod`: `ExpMod<A, E, M> = <A as PowMod<E, M>>::Output`
+pub type ExpMod<A, E, M> = <A as PowMod<E, M>>::Output;
+/// Alias for the associated type of `ModInverse`: `InvMod<A, M> = <A as ModInverse<M>>::Output`
+pub type InvMod<A, M> = <A as ModInverse<M>>::Output;
+
+/// Alias for the associated type of `IsPrime`: `Primality<A> = <A as IsPrime>::Output`
+pub type Primality<A> = <A as IsPrime>::Output;
+/// Alias for the associated type of `SmallestFactor`: `LeastFactor<A> = <A as SmallestFactor>::Output`
+pub type LeastFactor<A> = <A as SmallestFactor>::Output;
+/// Alias for the associated type of `Factorize`: `Factors<A> = <A as Factorize>::Output`
+pub type Factors<A> = <A as Factorize>::Output;
+
+/// Alias for the associated type of `Lcm`: `Scm<A, B> = <A as Lcm<B>>::Output`, for smallest
+/// common multiple, as `Gcf` is to `Gcd`
+pub type Scm<A, B> = <A as Lcm<B>>::Output;
+/// Alias for the coefficient `X` of `ExtGcd`: `BezoutX<A, B> = <A as ExtGcd<B>>::X`
+pub type BezoutX<A, B> = <A as ExtGcd<B>>::X;
+/// Alias for the coefficient `Y` of `ExtGcd`: `BezoutY<A, B> = <A as ExtGcd<B>>::Y`
+pub type BezoutY<A, B> = <A as ExtGcd<B>>::Y;
+/// Alias for the associated type of `IsCoprime`: `Coprimality<A, B> = <A as IsCoprime<B>>::Output`
+pub type Coprimality<A, B> = <A as IsCoprime<B>>::Output;
+
+/// Alias for the associated type of `IsPowerOfTwo`: `IsPow2<A> = <A as IsPowerOfTwo>::Output`
+pub type IsPow2<A> = <A as IsPowerOfTwo>::Output;
+/// Alias for the associated type of `NextPowerOfTwo`: `NextPow2<A> = <A as NextPowerOfTwo>::Output`
+pub type NextPow2<A> = <A as NextPowerOfTwo>::Output;
+/// Alias for the associated type of `PrevPowerOfTwo`: `PrevPow2<A> = <A as PrevPowerOfTwo>::Output`
+pub type PrevPow2<A> = <A as PrevPowerOfTwo>::Output;
+/// Alias for the associated type of `CountOnes`: `Ones<A> = <A as CountOnes>::Output`
+pub type Ones<A> = <A as CountOnes>::Output;
+/// Alias for the associated type of `CountZeros`: `Zeros<A, W> = <A as CountZeros<W>>::Output`
+pub type Zeros<A, W> = <A as CountZeros<W>>::Output;
+/// Alias for the associated type of `TrailingZeros`: `TrailZeros<A> = <A as TrailingZeros>::Output`
+pub type TrailZeros<A> = <A as TrailingZeros>::Output;
+/// Alias for the associated type of `LeadingZeros`: `LeadZeros<A, W> = <A as LeadingZeros<W>>::Output`
+pub type LeadZeros<A, W> = <A as LeadingZeros<W>>::Output;
+/// Alias for the associated type of `ReverseBits`: `BitsReversed<A, W> = <A as ReverseBits<W>>::Output`
+pub type BitsReversed<A, W> = <A as ReverseBits<W>>::Output;
+
+/// Alias for the associated type of `CheckedAdd`:
+/// `CheckedSum<A, B, W> = <A as CheckedAdd<B, W>>::Output`
+pub type CheckedSum<A, B, W> = <A as CheckedAdd<B, W>>::Output;
+/// Alias for the associated type of `WrappingAdd`:
+/// `WrappingSum<A, B, W> = <A as WrappingAdd<B, W>>::Output`
+pub type WrappingSum<A, B, W> = <A as WrappingAdd<B, W>>::Output;
+/// Alias for the associated type of `SaturatingSub`:
+/// `SaturatingDiff<A, B> = <A as SaturatingSub<B>>::Output`
+pub type SaturatingDiff<A, B> = <A as SaturatingSub<B>>::Output;
+
 /// Alias for the associated type of `Min`: `Minimum<A, B> = <A as Min<B>>::Output`
 pub type Minimum<A, B> = <A as Min<B>>::Output;
 
@@ -113,3 +239,14 @@ pub type LeEq<A, B> = <A as IsLessOrEqual<B>>::Output;
 pub type NotEq<A, B> = <A as IsNotEqual<B>>::Output;
 /// Alias for the associated type of `Logarithm2`: `Log2<A> = <A as Logarithm2>::Output`
 pub type Log2<A> = <A as Logarithm2>::Output;
+
+/// Alias for the associated type of `Logarithm`: `Log<A, B> = <A as Logarithm<B>>::Output`
+pub type Log<A, B> = <A as Logarithm<B>>::Output;
+/// Alias for the decimal logarithm: `Log10<A> = <A as Logarithm<U10>>::Output`
+pub type Log10<A> = <A as Logarithm<crate::consts::U10>>::Output;
+/// Alias for the associated type of `CeilLogarithm2`: `CeilLog2<A> = <A as CeilLogarithm2>::Output`
+pub type CeilLog2<A> = <A as CeilLogarithm2>::Output;
+/// Alias for the associated type of `NthRoot`: `Root<A, K> = <A as NthRoot<K>>::Output`
+pub type Root<A, K> = <A as NthRoot<K>>::Output;
+/// Alias for the associated type of `CubeRoot`: `Cbrt<A> = <A as CubeRoot>::Output`
+pub type Cbrt<A> = <A as CubeRoot>::Output;
@@ -93,6 +93,14 @@ pub trait PrivateXor<Rhs = Self> {
 pub type PrivateXorOut<A, Rhs> = <A as PrivateXor<Rhs>>::Output;
 
 /// Does the real subtraction for `UInt`s; `Sub` just calls this and then `Trim`.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "attempt to subtract with overflow",
+        label = "the difference of unsigned integers is negative",
+        note = "use signed integers, or `SaturatingSub` to stop at zero"
+    )
+)]
 pub trait PrivateSub<Rhs = Self> {
     type Output;
 
@@ -100,6 +108,18 @@ pub trait PrivateSub<Rhs = Self> {
 }
 pub type PrivateSubOut<A, Rhs> = <A as PrivateSub<Rhs>>::Output;
 
+/// Implemented for nothing. With the `diagnostics` feature, `Div` and `Rem` by zero require it,
+/// so that dividing by zero fails with an error naming it, rather than one about a missing `Div`
+/// implementation.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "attempt to divide `{Self}` by zero",
+        label = "the divisor is zero"
+    )
+)]
+pub trait DivideByZero {}
+
 /// Used for addition of signed integers; `C = P.cmp(N)`
 /// Assumes `P = Self` is positive and `N` is negative
 /// where `P` and `N` are both passed as unsigned integers
@@ -110,6 +130,14 @@ pub trait PrivateIntegerAdd<C, N> {
 }
 pub type PrivateIntegerAddOut<P, C, N> = <P as PrivateIntegerAdd<C, N>>::Output;
 
+/// Used for reducing a `Ratio` to lowest terms; `Self` is the numerator and `D` the denominator.
+pub trait PrivateReduce<D> {
+    type Output;
+
+    fn private_reduce(self, _: D) -> Self::Output;
+}
+pub type PrivateReduceOut<N, D> = <N as PrivateReduce<D>>::Output;
+
 pub trait PrivatePow<Y, N> {
     type Output;
 
@@ -583,10 +611,318 @@ impl<A, B> IsGreaterOrEqualPrivate<B, Greater> for A {
     }
 }
 
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the square root of `{Self}`",
+        note = "the square root is only defined for nonnegative numbers"
+    )
+)]
 pub trait PrivateSquareRoot {
     type Output;
 }
 
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the binary logarithm of `{Self}`",
+        note = "the logarithm is only defined for positive numbers"
+    )
+)]
 pub trait PrivateLogarithm2 {
     type Output;
 }
+
+/// The integer logarithm of `Self` in base `B`, for `Self` of at least one.
+pub trait PrivateLogarithm<B> {
+    type Output;
+}
+pub type PrivateLogarithmOut<N, B> = <N as PrivateLogarithm<B>>::Output;
+
+/// A step of `PrivateLogarithm`, where `Less` is whether `Self < B`.
+pub trait PrivateLogarithmIf<B, Less> {
+    type Output;
+}
+pub type PrivateLogarithmIfOut<N, B, Less> = <N as PrivateLogarithmIf<B, Less>>::Output;
+
+/// The integer `K`th root of `Self`, for `K` of at least one.
+pub trait PrivateNthRoot<K> {
+    type Output;
+}
+pub type PrivateNthRootOut<N, K> = <N as PrivateNthRoot<K>>::Output;
+
+/// The power of two one bit longer than `Self`, `2^len(Self)`.
+pub trait PrivateLenPowerOfTwo {
+    type Output;
+}
+pub type PrivateLenPowerOfTwoOut<A> = <A as PrivateLenPowerOfTwo>::Output;
+
+/// Reverses the low `W` bits of `Self` onto the end of `Acc`.
+pub trait PrivateReverseBits<W, Acc> {
+    type Output;
+}
+pub type PrivateReverseBitsOut<N, W, Acc> = <N as PrivateReverseBits<W, Acc>>::Output;
+
+/// Converts an unsigned integer into the signed integer of the same value.
+pub trait PrivateToInteger {
+    type Output;
+
+    fn private_to_integer(self) -> Self::Output;
+}
+pub type PrivateToIntegerOut<A> = <A as PrivateToInteger>::Output;
+
+/// The maximum of `V` and every element of the type-level array `Self`.
+pub trait PrivateFoldMax<V> {
+    type Output;
+}
+pub type PrivateFoldMaxOut<A, V> = <A as PrivateFoldMax<V>>::Output;
+
+/// The minimum of `V` and every element of the type-level array `Self`.
+pub trait PrivateFoldMin<V> {
+    type Output;
+}
+pub type PrivateFoldMinOut<A, V> = <A as PrivateFoldMin<V>>::Output;
+
+/// The dot product of `TArr<Vl, Self>` and `TArr<Vr, Ar>`.
+pub trait PrivateDotProduct<Vl, Vr, Ar> {
+    type Output;
+}
+pub type PrivateDotProductOut<Al, Vl, Vr, Ar> = <Al as PrivateDotProduct<Vl, Vr, Ar>>::Output;
+
+/// Inserts `V` into an already sorted type-level array, keeping it sorted.
+pub trait PrivateInsertSorted<V> {
+    type Output;
+}
+pub type PrivateInsertSortedOut<A, V> = <A as PrivateInsertSorted<V>>::Output;
+
+pub trait PrivateInsertSortedIf<V, CmpResult> {
+    type Output;
+}
+pub type PrivateInsertSortedIfOut<A, V, CmpResult> =
+    <A as PrivateInsertSortedIf<V, CmpResult>>::Output;
+
+pub trait PrivateDedup<IsEq> {
+    type Output;
+}
+pub type PrivateDedupOut<A, IsEq> = <A as PrivateDedup<IsEq>>::Output;
+
+/// Whether `V` followed by the type-level array `Self` is sorted.
+pub trait PrivateIsSorted<V> {
+    type Output;
+}
+pub type PrivateIsSortedOut<A, V> = <A as PrivateIsSorted<V>>::Output;
+
+pub trait PrivateFilter<P, Keep> {
+    type Output;
+}
+pub type PrivateFilterOut<A, P, Keep> = <A as PrivateFilter<P, Keep>>::Output;
+
+pub trait PrivateSaturatingSub<Rhs, CmpResult> {
+    type Output;
+
+    fn private_saturating_sub(self, rhs: Rhs, _: CmpResult) -> Self::Output;
+}
+pub type PrivateSaturatingSubOut<A, Rhs, CmpResult> =
+    <A as PrivateSaturatingSub<Rhs, CmpResult>>::Output;
+
+/// A step of the extended Euclidean algorithm for `ModInverse<M>`, where `Self` is the current
+/// remainder and `R0` the previous one, and `T1` and `T0` their coefficients modulo `M`.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "the number has no inverse modulo `{M}`, as they are not coprime",
+        label = "their greatest common divisor is `{R0}`"
+    )
+)]
+pub trait PrivateModInverse<M, R0, T0, T1> {
+    type Output;
+}
+pub type PrivateModInverseOut<A, M, R0, T0, T1> = <A as PrivateModInverse<M, R0, T0, T1>>::Output;
+
+/// The extended Euclidean algorithm for `ExtGcd`, run on `X` and `Self`, so that recursing on
+/// the remainder puts it in `Self`.
+pub trait PrivateExtGcd<X> {
+    type G;
+    type X;
+    type Y;
+}
+pub type PrivateExtGcdG<Y, X> = <Y as PrivateExtGcd<X>>::G;
+pub type PrivateExtGcdX<Y, X> = <Y as PrivateExtGcd<X>>::X;
+pub type PrivateExtGcdY<Y, X> = <Y as PrivateExtGcd<X>>::Y;
+
+/// Trial division for `SmallestFactor`: the smallest factor of `Self` that is at least `D`,
+/// where `Self` has no smaller factors greater than one.
+pub trait PrivateSmallestFactor<D> {
+    type Output;
+}
+pub type PrivateSmallestFactorOut<N, D> = <N as PrivateSmallestFactor<D>>::Output;
+
+/// A step of `PrivateSmallestFactor`, trying `D` as a factor, where `L` is the square root of
+/// `Self` and `M = D mod 3`.
+pub trait PrivateTrialDivision<D, L, M> {
+    type Output;
+}
+pub type PrivateTrialDivisionOut<N, D, L, M> = <N as PrivateTrialDivision<D, L, M>>::Output;
+
+/// `PrivateTrialDivision` once we know whether `D > L` and the remainder `R = Self mod D`.
+pub trait PrivateSmallestFactorIf<D, L, M, Past, R> {
+    type Output;
+}
+pub type PrivateSmallestFactorIfOut<N, D, L, M, Past, R> =
+    <N as PrivateSmallestFactorIf<D, L, M, Past, R>>::Output;
+
+/// The prime factors of `Self`, none of which are less than `D`.
+pub trait PrivateFactorize<D> {
+    type Output;
+}
+pub type PrivateFactorizeOut<N, D> = <N as PrivateFactorize<D>>::Output;
+
+/// Implemented only by `B1`, so that `assert_lt!` can require `Le<A, B>: AssertLt<A, B>` and
+/// name both numbers when it fails.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "assertion failed: `{A}` < `{B}`",
+        label = "the first number is not less than the second"
+    )
+)]
+pub trait AssertLt<A, B> {}
+impl<A, B> AssertLt<A, B> for B1 {}
+
+/// Implemented only by `B1`, for `assert_in_range!`.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "assertion failed: `{Lo}` <= `{N}` <= `{Hi}`",
+        label = "the number is not in the range"
+    )
+)]
+pub trait AssertInRange<N, Lo, Hi> {}
+impl<N, Lo, Hi> AssertInRange<N, Lo, Hi> for B1 {}
+
+/// Implemented only by the zeros `U0` and `Z0`, for `assert_divides!` to require of the
+/// remainder.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "assertion failed: `{D}` divides `{N}`",
+        label = "the remainder is `{Self}`, not zero"
+    )
+)]
+pub trait AssertDivides<D, N> {}
+impl<D, N> AssertDivides<D, N> for UTerm {}
+impl<D, N> AssertDivides<D, N> for crate::int::Z0 {}
+
+/// The first element of a type-level array, for `ToArray::iter`: a function returning its value,
+/// and one returning the next element the same way.
+pub struct PrivateElement<T> {
+    pub value: fn() -> T,
+    pub rest: fn() -> Option<PrivateElement<T>>,
+}
+
+impl<T> Clone for PrivateElement<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        PrivateElement {
+            value: self.value,
+            rest: self.rest,
+        }
+    }
+}
+
+/// Walks the elements of a type-level array for `ToArray`.
+pub trait PrivateToArray<T> {
+    fn private_first() -> Option<PrivateElement<T>>;
+}
+
+/// The values of the last `Self::LEN` elements of an array of length `N`, for `ToConstArray`. The
+/// elements before them are filled with the first element of `Self`.
+#[cfg(feature = "const-generics")]
+pub trait PrivateArraySuffix<T, const N: usize> {
+    const PRIVATE_SUFFIX: [T; N];
+}
+
+/// Adds the elements of a type-level array to a `DebugList`.
+pub trait PrivateDebugEntries {
+    fn private_debug_entries(&self, list: &mut core::fmt::DebugList<'_, '_>);
+}
+
+/// Writes the elements of a type-level array separated by commas, with `Display`.
+pub trait PrivateDisplayEntries {
+    fn private_display_entries(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        first: bool,
+    ) -> core::fmt::Result;
+}
+
+/// The parts of `Unsigned` that are for typenum's own use.
+pub trait PrivateUnsigned {
+    /// The number of bits of `Self`, without leading zeros.
+    const PRIVATE_BITS: usize;
+    /// Whether `Self` is a power of two.
+    const PRIVATE_IS_POWER_OF_TWO: bool;
+
+    /// Calls `f` with each bit of `Self`, from the most significant one. Used for formatting
+    /// numbers too wide for any primitive.
+    fn private_for_each_bit<F: FnMut(bool)>(f: &mut F);
+}
+
+/// The parts of `Integer` that are for typenum's own use.
+pub trait PrivateInteger {
+    /// The number of bits of the shortest twos-complement representation of `Self`.
+    const PRIVATE_BITS: usize;
+}
+
+/// The little-endian twos-complement bytes of a number, truncated to `N` bytes.
+#[cfg(feature = "const-generics")]
+pub trait PrivateBytes<const N: usize> {
+    const PRIVATE_LE_BYTES: [u8; N];
+}
+
+/// Returns `bytes`, or fails const evaluation if `N` is not `len`.
+#[cfg(feature = "const-generics")]
+pub const fn check_len<const N: usize>(bytes: [u8; N], len: usize) -> [u8; N] {
+    [bytes][(N != len) as usize]
+}
+
+/// Shifts the little-endian `bytes` left by one and sets the lowest bit to `bit`.
+#[cfg(feature = "const-generics")]
+pub const fn push_bit_le<const N: usize>(bytes: [u8; N], bit: u8) -> [u8; N] {
+    let mut out = [0; N];
+    let mut carry = bit;
+    let mut i = 0;
+    while i < N {
+        out[i] = bytes[i] << 1 | carry;
+        carry = bytes[i] >> 7;
+        i += 1;
+    }
+    out
+}
+
+/// Negates the little-endian twos-complement `bytes`.
+#[cfg(feature = "const-generics")]
+pub const fn negate_le<const N: usize>(bytes: [u8; N]) -> [u8; N] {
+    let mut out = [0; N];
+    let mut carry = 1;
+    let mut i = 0;
+    while i < N {
+        let sum = !bytes[i] as u16 + carry;
+        out[i] = sum as u8;
+        carry = sum >> 8;
+        i += 1;
+    }
+    out
+}
+
+/// Reverses the order of `bytes`, converting between little- and big-endian.
+#[cfg(feature = "const-generics")]
+pub const fn reverse_bytes<const N: usize>(bytes: [u8; N]) -> [u8; N] {
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = bytes[N - 1 - i];
+        i += 1;
+    }
+    out
+}
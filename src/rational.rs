@@ -0,0 +1,433 @@
+//! Type-level rational numbers.
+//!
+//! A rational number is represented as `Ratio<N, D>`, where the numerator `N` is a type-level
+//! signed integer and the denominator `D` is a non-zero type-level unsigned integer. The sign of
+//! the ratio is carried entirely by the numerator.
+//!
+//! Ratios should be kept in lowest terms so that each value has exactly one representation. All
+//! type operators in this module produce reduced output, using `Gcd` to normalize. Zero is always
+//! represented as `Ratio<Z0, U1>`.
+//!
+//! **Type operators** implemented:
+//!
+//! From `core::ops`: `Add`, `Sub`, `Mul`, `Div`, and `Neg`.
+//! From `typenum`: `Same`, `Cmp`, `Min`, `Max`, and `Abs`.
+//!
+//! # Example
+//! ```rust
+//! use std::ops::{Add, Div, Mul, Neg, Sub};
+//! use typenum::{Ratio, Rational, N1, P1, P3, U2, U4};
+//!
+//! type Half = Ratio<P1, U2>;
+//! type ThreeQuarters = Ratio<P3, U4>;
+//!
+//! assert_eq!(<Half as Add<ThreeQuarters>>::Output::to_f64(), 1.25);
+//! assert_eq!(<Half as Sub<ThreeQuarters>>::Output::to_f64(), -0.25);
+//! assert_eq!(<Half as Mul<ThreeQuarters>>::Output::to_f64(), 0.375);
+//! assert_eq!(<Half as Div<ThreeQuarters>>::Output::NUM, 2);
+//! assert_eq!(<Half as Div<ThreeQuarters>>::Output::DEN, 3);
+//! assert_eq!(<Ratio<N1, U2> as Neg>::Output::to_f64(), 0.5);
+//! ```
+
+pub use crate::marker_traits::Rational;
+use crate::{
+    consts::U1,
+    int::{Integer, NInt, PInt, Z0},
+    private::{
+        Internal, InternalMarker, PrivateMax, PrivateMaxOut, PrivateMin, PrivateMinOut,
+        PrivateReduce, PrivateReduceOut,
+    },
+    uint::Unsigned,
+    Abs, AbsVal, Cmp, Compare, Equal, Gcd, Gcf, Greater, Less, Max, Min, NonZero, Prod, Quot, Zero,
+};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Type-level rational numbers, `N / D`.
+///
+/// `N` is the numerator and carries the sign; `D` is the denominator. See the [module
+/// documentation](index.html) for details on normalization.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Ratio, Rational, N3, U4};
+///
+/// type X = Ratio<N3, U4>;
+/// assert_eq!(X::NUM, -3);
+/// assert_eq!(X::DEN, 4);
+/// assert_eq!(X::to_f64(), -0.75);
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
+#[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
+pub struct Ratio<N: Integer, D: Unsigned + NonZero> {
+    /// The numerator of `Self`.
+    pub(crate) n: N,
+    /// The denominator of `Self`.
+    pub(crate) d: D,
+}
+
+impl<N: Integer, D: Unsigned + NonZero> Ratio<N, D> {
+    /// Instantiates a singleton representing this rational number.
+    #[inline]
+    pub fn new() -> Ratio<N, D> {
+        Ratio::default()
+    }
+}
+
+impl<N: Integer, D: Unsigned + NonZero> Rational for Ratio<N, D> {
+    const NUM: i64 = N::I64;
+    const DEN: u64 = D::U64;
+
+    #[inline]
+    fn to_f32() -> f32 {
+        Self::NUM as f32 / Self::DEN as f32
+    }
+    #[inline]
+    fn to_f64() -> f64 {
+        Self::NUM as f64 / Self::DEN as f64
+    }
+}
+
+impl<D: Unsigned + NonZero> Zero for Ratio<Z0, D> {}
+impl<U: Unsigned + NonZero, D: Unsigned + NonZero> NonZero for Ratio<PInt<U>, D> {}
+impl<U: Unsigned + NonZero, D: Unsigned + NonZero> NonZero for Ratio<NInt<U>, D> {}
+
+// ---------------------------------------------------------------------------------------
+// Reducing to lowest terms
+
+/// `0 / D = 0 / 1`
+impl<D: Unsigned + NonZero> PrivateReduce<D> for Z0 {
+    type Output = Ratio<Z0, U1>;
+
+    #[inline]
+    fn private_reduce(self, _: D) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// `P(U) / D = P(U / gcd(U, D)) / (D / gcd(U, D))`
+impl<U, D> PrivateReduce<D> for PInt<U>
+where
+    U: Unsigned + NonZero + Gcd<D> + Div<Gcf<U, D>>,
+    D: Unsigned + NonZero + Div<Gcf<U, D>>,
+    Quot<U, Gcf<U, D>>: Unsigned + NonZero,
+    Quot<D, Gcf<U, D>>: Unsigned + NonZero,
+{
+    type Output = Ratio<PInt<Quot<U, Gcf<U, D>>>, Quot<D, Gcf<U, D>>>;
+
+    #[inline]
+    fn private_reduce(self, _: D) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// `N(U) / D = N(U / gcd(U, D)) / (D / gcd(U, D))`
+impl<U, D> PrivateReduce<D> for NInt<U>
+where
+    U: Unsigned + NonZero + Gcd<D> + Div<Gcf<U, D>>,
+    D: Unsigned + NonZero + Div<Gcf<U, D>>,
+    Quot<U, Gcf<U, D>>: Unsigned + NonZero,
+    Quot<D, Gcf<U, D>>: Unsigned + NonZero,
+{
+    type Output = Ratio<NInt<Quot<U, Gcf<U, D>>>, Quot<D, Gcf<U, D>>>;
+
+    #[inline]
+    fn private_reduce(self, _: D) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Neg
+
+/// `-(N / D) = (-N) / D`
+impl<N, D> Neg for Ratio<N, D>
+where
+    N: Integer + Neg,
+    D: Unsigned + NonZero,
+    <N as Neg>::Output: Integer,
+{
+    type Output = Ratio<<N as Neg>::Output, D>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Abs
+
+/// `|N / D| = |N| / D`
+impl<N, D> Abs for Ratio<N, D>
+where
+    N: Integer + Abs,
+    D: Unsigned + NonZero,
+    AbsVal<N>: Integer,
+{
+    type Output = Ratio<AbsVal<N>, D>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Add
+
+/// `Nl / Dl + Nr / Dr = (Nl * Dr + Nr * Dl) / (Dl * Dr)`, reduced
+impl<Nl, Dl, Nr, Dr> Add<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<PInt<Dr>>,
+    Dl: Unsigned + NonZero + Mul<Dr>,
+    Nr: Integer + Mul<PInt<Dl>>,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, PInt<Dr>>: Add<Prod<Nr, PInt<Dl>>>,
+    <Prod<Nl, PInt<Dr>> as Add<Prod<Nr, PInt<Dl>>>>::Output: PrivateReduce<Prod<Dl, Dr>>,
+{
+    type Output =
+        PrivateReduceOut<<Prod<Nl, PInt<Dr>> as Add<Prod<Nr, PInt<Dl>>>>::Output, Prod<Dl, Dr>>;
+    #[inline]
+    fn add(self, rhs: Ratio<Nr, Dr>) -> Self::Output {
+        (self.n * PInt { n: rhs.d } + rhs.n * PInt { n: self.d }).private_reduce(self.d * rhs.d)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Sub
+
+/// `Nl / Dl - Nr / Dr = (Nl * Dr - Nr * Dl) / (Dl * Dr)`, reduced
+impl<Nl, Dl, Nr, Dr> Sub<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<PInt<Dr>>,
+    Dl: Unsigned + NonZero + Mul<Dr>,
+    Nr: Integer + Mul<PInt<Dl>>,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, PInt<Dr>>: Sub<Prod<Nr, PInt<Dl>>>,
+    <Prod<Nl, PInt<Dr>> as Sub<Prod<Nr, PInt<Dl>>>>::Output: PrivateReduce<Prod<Dl, Dr>>,
+{
+    type Output =
+        PrivateReduceOut<<Prod<Nl, PInt<Dr>> as Sub<Prod<Nr, PInt<Dl>>>>::Output, Prod<Dl, Dr>>;
+    #[inline]
+    fn sub(self, rhs: Ratio<Nr, Dr>) -> Self::Output {
+        (self.n * PInt { n: rhs.d } - rhs.n * PInt { n: self.d }).private_reduce(self.d * rhs.d)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Mul
+
+/// `(Nl / Dl) * (Nr / Dr) = (Nl * Nr) / (Dl * Dr)`, reduced
+impl<Nl, Dl, Nr, Dr> Mul<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<Nr>,
+    Dl: Unsigned + NonZero + Mul<Dr>,
+    Nr: Integer,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, Nr>: PrivateReduce<Prod<Dl, Dr>>,
+{
+    type Output = PrivateReduceOut<Prod<Nl, Nr>, Prod<Dl, Dr>>;
+    #[inline]
+    fn mul(self, rhs: Ratio<Nr, Dr>) -> Self::Output {
+        (self.n * rhs.n).private_reduce(self.d * rhs.d)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Div
+
+/// `(Nl / Dl) / (P(Ur) / Dr) = (Nl * Dr) / (Dl * Ur)`, reduced
+impl<Nl, Dl, Ur, Dr> Div<Ratio<PInt<Ur>, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<PInt<Dr>>,
+    Dl: Unsigned + NonZero + Mul<Ur>,
+    Ur: Unsigned + NonZero,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, PInt<Dr>>: PrivateReduce<Prod<Dl, Ur>>,
+{
+    type Output = PrivateReduceOut<Prod<Nl, PInt<Dr>>, Prod<Dl, Ur>>;
+    #[inline]
+    fn div(self, rhs: Ratio<PInt<Ur>, Dr>) -> Self::Output {
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        (self.n * PInt { n: rhs.d }).private_reduce(self.d * rhs.n.n)
+    }
+}
+
+/// `(Nl / Dl) / (N(Ur) / Dr) = (Nl * -Dr) / (Dl * Ur)`, reduced
+impl<Nl, Dl, Ur, Dr> Div<Ratio<NInt<Ur>, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<NInt<Dr>>,
+    Dl: Unsigned + NonZero + Mul<Ur>,
+    Ur: Unsigned + NonZero,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, NInt<Dr>>: PrivateReduce<Prod<Dl, Ur>>,
+{
+    type Output = PrivateReduceOut<Prod<Nl, NInt<Dr>>, Prod<Dl, Ur>>;
+    #[inline]
+    fn div(self, rhs: Ratio<NInt<Ur>, Dr>) -> Self::Output {
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        (self.n * NInt { n: rhs.d }).private_reduce(self.d * rhs.n.n)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Cmp
+
+/// `Nl / Dl <==> Nr / Dr` is the same as `Nl * Dr <==> Nr * Dl`, as both denominators are positive
+impl<Nl, Dl, Nr, Dr> Cmp<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<PInt<Dr>>,
+    Dl: Unsigned + NonZero,
+    Nr: Integer + Mul<PInt<Dl>>,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, PInt<Dr>>: Cmp<Prod<Nr, PInt<Dl>>>,
+{
+    type Output = Compare<Prod<Nl, PInt<Dr>>, Prod<Nr, PInt<Dl>>>;
+
+    #[inline]
+    fn compare<IM: InternalMarker>(&self, rhs: &Ratio<Nr, Dr>) -> Self::Output {
+        let lhs = self.n * PInt { n: rhs.d };
+        let rhs = rhs.n * PInt { n: self.d };
+        lhs.compare::<Internal>(&rhs)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Min
+
+impl<N: Integer, D: Unsigned + NonZero, Rhs> PrivateMin<Rhs, Less> for Ratio<N, D> {
+    type Output = Self;
+    #[inline]
+    fn private_min(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
+
+impl<N: Integer, D: Unsigned + NonZero, Rhs> PrivateMin<Rhs, Equal> for Ratio<N, D> {
+    type Output = Self;
+    #[inline]
+    fn private_min(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
+
+impl<N: Integer, D: Unsigned + NonZero, Rhs> PrivateMin<Rhs, Greater> for Ratio<N, D> {
+    type Output = Rhs;
+    #[inline]
+    fn private_min(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<N, D, Rhs> Min<Rhs> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+    Ratio<N, D>: Cmp<Rhs> + PrivateMin<Rhs, Compare<Ratio<N, D>, Rhs>>,
+{
+    type Output = PrivateMinOut<Ratio<N, D>, Rhs, Compare<Ratio<N, D>, Rhs>>;
+    #[inline]
+    fn min(self, rhs: Rhs) -> Self::Output {
+        self.private_min(rhs)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Max
+
+impl<N: Integer, D: Unsigned + NonZero, Rhs> PrivateMax<Rhs, Less> for Ratio<N, D> {
+    type Output = Rhs;
+    #[inline]
+    fn private_max(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<N: Integer, D: Unsigned + NonZero, Rhs> PrivateMax<Rhs, Equal> for Ratio<N, D> {
+    type Output = Self;
+    #[inline]
+    fn private_max(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
+
+impl<N: Integer, D: Unsigned + NonZero, Rhs> PrivateMax<Rhs, Greater> for Ratio<N, D> {
+    type Output = Self;
+    #[inline]
+    fn private_max(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
+
+impl<N, D, Rhs> Max<Rhs> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+    Ratio<N, D>: Cmp<Rhs> + PrivateMax<Rhs, Compare<Ratio<N, D>, Rhs>>,
+{
+    type Output = PrivateMaxOut<Ratio<N, D>, Rhs, Compare<Ratio<N, D>, Rhs>>;
+    #[inline]
+    fn max(self, rhs: Rhs) -> Self::Output {
+        self.private_max(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::consts::*;
+    use crate::{
+        assert_type_eq, AbsVal, Compare, Diff, Maximum, Minimum, Negate, Ord, Prod, Quot, Ratio,
+        Rational, Sum,
+    };
+    use core::cmp::Ordering;
+
+    type Half = Ratio<P1, U2>;
+    type Third = Ratio<P1, U3>;
+    type NegHalf = Ratio<N1, U2>;
+
+    #[test]
+    fn arithmetic() {
+        assert_type_eq!(Sum<Half, Third>, Ratio<P5, U6>);
+        assert_type_eq!(Sum<Half, Half>, Ratio<P1, U1>);
+        assert_type_eq!(Sum<Half, NegHalf>, Ratio<Z0, U1>);
+        assert_type_eq!(Diff<Third, Half>, Ratio<N1, U6>);
+        assert_type_eq!(Prod<Ratio<P2, U3>, Ratio<P3, U4>>, Half);
+        assert_type_eq!(Prod<NegHalf, NegHalf>, Ratio<P1, U4>);
+        assert_type_eq!(Quot<Half, Third>, Ratio<P3, U2>);
+        assert_type_eq!(Quot<Half, Ratio<N3, U4>>, Ratio<N2, U3>);
+        assert_type_eq!(Quot<Ratio<Z0, U1>, Half>, Ratio<Z0, U1>);
+        assert_type_eq!(Negate<Half>, NegHalf);
+        assert_type_eq!(AbsVal<NegHalf>, Half);
+    }
+
+    #[test]
+    fn same() {
+        use crate::Same;
+
+        type T = <Sum<Half, Half> as Same<Ratio<P1, U1>>>::Output;
+        assert_eq!(<T as Rational>::NUM, 1);
+    }
+
+    #[test]
+    fn comparison() {
+        assert_eq!(
+            <Compare<Half, Third> as Ord>::to_ordering(),
+            Ordering::Greater
+        );
+        assert_eq!(
+            <Compare<NegHalf, Third> as Ord>::to_ordering(),
+            Ordering::Less
+        );
+        assert_eq!(
+            <Compare<Half, Ratio<P2, U4>> as Ord>::to_ordering(),
+            Ordering::Equal
+        );
+
+        assert_type_eq!(Minimum<Half, Third>, Third);
+        assert_type_eq!(Maximum<Half, Third>, Half);
+        assert_type_eq!(Minimum<NegHalf, Third>, NegHalf);
+        assert_type_eq!(Maximum<NegHalf, Third>, Third);
+    }
+
+    #[test]
+    fn to_runtime() {
+        assert_eq!(<Ratio<N3, U4> as Rational>::NUM, -3);
+        assert_eq!(<Ratio<N3, U4> as Rational>::DEN, 4);
+        assert_eq!(<Ratio<N3, U4> as Rational>::to_f64(), -0.75);
+        assert_eq!(<Sum<Half, Third> as Rational>::to_f32(), 5.0 / 6.0);
+    }
+}
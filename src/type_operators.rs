@@ -26,6 +26,13 @@ use crate::{
 /// // Compiler error:
 /// // Undefined::to_u32();
 /// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not the same type as `{Rhs}`",
+        label = "expected `{Rhs}`"
+    )
+)]
 pub trait Same<Rhs = Self> {
     /// Should always be `Self`
     type Output;
@@ -43,6 +50,13 @@ impl<T> Same<T> for T {
 ///
 /// assert_eq!(<N5 as Abs>::Output::to_i32(), 5);
 /// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the absolute value of `{Self}`",
+        note = "`Abs` is defined for signed integers"
+    )
+)]
 pub trait Abs {
     /// The absolute value.
     type Output;
@@ -68,6 +82,13 @@ impl<U: Unsigned + NonZero> Abs for NInt<U> {
 ///
 /// assert_eq!(<N3 as Pow<P3>>::Output::to_i32(), -27);
 /// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot raise `{Self}` to the power `{Exp}`",
+        note = "the exponent must be an unsigned integer or a nonnegative signed integer"
+    )
+)]
 pub trait Pow<Exp> {
     /// The result of the exponentiation.
     type Output;
@@ -307,6 +328,15 @@ fn pow_test() {
 /// assert_eq!(<P2 as Cmp<N3>>::Output::to_ordering(), Ordering::Greater);
 /// assert_eq!(<P2 as Cmp<P2>>::Output::to_ordering(), Ordering::Equal);
 /// assert_eq!(<P2 as Cmp<P5>>::Output::to_ordering(), Ordering::Less);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot compare `{Self}` with `{Rhs}`",
+        note = "only numbers of the same kind can be compared, such as two unsigned or two signed \
+                integers"
+    )
+)]
 pub trait Cmp<Rhs = Self> {
     /// The result of the comparison. It should only ever be one of `Greater`, `Less`, or `Equal`.
     type Output;
@@ -317,6 +347,13 @@ pub trait Cmp<Rhs = Self> {
 
 /// A **type operator** that gives the length of an `Array` or the number of bits in a `UInt`.
 #[allow(clippy::len_without_is_empty)]
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` has no length",
+        note = "`Len` is defined for numbers, the number of their bits, and for arrays"
+    )
+)]
 pub trait Len {
     /// The length as a type-level unsigned integer.
     type Output: crate::Unsigned;
@@ -336,6 +373,328 @@ pub trait FoldMul {
     type Output;
 }
 
+/// A **type operator** that gives the element of an `Array` at the zero-based index `I`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{tarr, At, Integer, N4, P3, P8, U1};
+///
+/// assert_eq!(<At<tarr![P3, N4, P8], U1>>::to_i32(), -4);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` has no element at index `{I}`",
+        note = "the index must be an unsigned integer less than the length of the array"
+    )
+)]
+pub trait Get<I> {
+    /// The element at index `I`
+    type Output;
+    /// Method returning the element at index `I`
+    fn get(self, index: I) -> Self::Output;
+}
+
+/// A **type operator** that appends an element to the end of an `Array`.
+pub trait Push<V> {
+    /// The array with `V` appended
+    type Output;
+    /// Method appending the element
+    fn push(self, value: V) -> Self::Output;
+}
+
+/// A **type operator** that removes the last element of a non-empty `Array`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Last, Popped, N4, P3, P8};
+///
+/// assert_type_eq!(Popped<tarr![P3, N4, P8]>, tarr![P3, N4]);
+/// assert_type_eq!(Last<tarr![P3, N4, P8]>, P8);
+/// ```
+pub trait Pop {
+    /// The array without its last element
+    type Output;
+    /// The element that was removed
+    type Last;
+    /// Method splitting off the last element
+    fn pop(self) -> (Self::Output, Self::Last);
+}
+
+/// A **type operator** that joins two `Array`s, with the elements of `Self` first.
+pub trait Concat<Rhs> {
+    /// The joined array
+    type Output;
+    /// Method joining the arrays
+    fn concat(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A **type operator** that reverses the order of the elements of an `Array`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Reversed, N4, P3, P8};
+///
+/// assert_type_eq!(Reversed<tarr![P3, N4, P8]>, tarr![P8, N4, P3]);
+/// ```
+pub trait Reverse {
+    /// The reversed array
+    type Output;
+    /// Method reversing the array
+    fn reverse(self) -> Self::Output;
+}
+
+/// A **type operator** that keeps only the first `N` elements of an `Array`.
+///
+/// It is not implemented if the array has fewer than `N` elements.
+pub trait Take<N> {
+    /// The array of the first `N` elements
+    type Output;
+    /// Method keeping the first `N` elements
+    fn take(self, n: N) -> Self::Output;
+}
+
+/// A **type operator** that removes the first `N` elements of an `Array`.
+///
+/// It is not implemented if the array has fewer than `N` elements.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Skipped, Taken, N4, P3, P8, U1};
+///
+/// assert_type_eq!(Taken<tarr![P3, N4, P8], U1>, tarr![P3]);
+/// assert_type_eq!(Skipped<tarr![P3, N4, P8], U1>, tarr![N4, P8]);
+/// ```
+pub trait Skip<N> {
+    /// The array without its first `N` elements
+    type Output;
+    /// Method removing the first `N` elements
+    fn skip(self, n: N) -> Self::Output;
+}
+
+/// A **type operator** that checks whether an `Array` has an element equal to `V`, as decided by
+/// `IsEqual`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{tarr, Bit, Contained, N4, P3, P4, P8};
+///
+/// assert!(<Contained<tarr![P3, N4, P8], N4>>::to_bool());
+/// assert!(!<Contained<tarr![P3, N4, P8], P4>>::to_bool());
+/// ```
+pub trait Contains<V> {
+    /// `True` if `V` is in the array, `False` otherwise
+    type Output: crate::Bit;
+}
+
+/// A **type operator** that gives the largest element of a non-empty `Array`.
+pub trait FoldMax {
+    /// The type of the largest element
+    type Output;
+}
+
+/// A **type operator** that gives the smallest element of a non-empty `Array`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{tarr, FoldMaximum, FoldMinimum, Integer, N4, P3, P8};
+///
+/// assert_eq!(<FoldMaximum<tarr![P3, N4, P8]>>::to_i32(), 8);
+/// assert_eq!(<FoldMinimum<tarr![P3, N4, P8]>>::to_i32(), -4);
+/// ```
+pub trait FoldMin {
+    /// The type of the smallest element
+    type Output;
+}
+
+/// A **type operator** that gives the dot product of two non-empty `Array`s of the same length.
+///
+/// # Example
+/// ```rust
+/// use typenum::{tarr, Dot, Integer, N4, P1, P2, P3, P8};
+///
+/// assert_eq!(<Dot<tarr![P3, N4, P8], tarr![P1, P2, P3]>>::to_i32(), 19);
+/// ```
+pub trait DotProduct<Rhs> {
+    /// The type of the dot product
+    type Output;
+}
+
+/// A **type operator** that sorts the elements of an `Array` in ascending order, according to
+/// `Cmp`.
+///
+/// Two arrays are permutations of each other exactly when they sort to the same type.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Sorted, SortedDesc, N4, P3, P8, Z0};
+///
+/// assert_type_eq!(Sorted<tarr![P3, N4, P8, Z0]>, tarr![N4, Z0, P3, P8]);
+/// assert_type_eq!(SortedDesc<tarr![P3, N4, P8, Z0]>, tarr![P8, P3, Z0, N4]);
+/// assert_type_eq!(Sorted<tarr![P3, N4, P8]>, Sorted<tarr![P8, P3, N4]>);
+/// ```
+pub trait Sort {
+    /// The sorted array
+    type Output;
+}
+
+/// A **type operator** that sorts the elements of an `Array` in descending order, according to
+/// `Cmp`.
+pub trait SortDesc {
+    /// The sorted array
+    type Output;
+}
+
+/// A **type operator** that removes consecutive repeated elements of an `Array`, as decided by
+/// `IsEqual`.
+///
+/// As with `Vec::dedup`, sort the array first to remove all duplicates.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Deduped, Sorted, P3, P8};
+///
+/// assert_type_eq!(Deduped<tarr![P3, P3, P8, P3]>, tarr![P3, P8, P3]);
+/// assert_type_eq!(Deduped<Sorted<tarr![P3, P3, P8, P3]>>, tarr![P3, P8]);
+/// ```
+pub trait Dedup {
+    /// The array without consecutive repeated elements
+    type Output;
+}
+
+/// A **type operator** that checks whether the elements of an `Array` are in ascending order.
+///
+/// # Example
+/// ```rust
+/// use typenum::{tarr, Bit, InOrder, N4, P3, P8};
+///
+/// assert!(<InOrder<tarr![N4, P3, P3, P8]>>::to_bool());
+/// assert!(!<InOrder<tarr![P3, N4, P8]>>::to_bool());
+/// ```
+pub trait IsSorted {
+    /// `True` if the array is sorted, `False` otherwise
+    type Output: Bit;
+}
+
+/// A type-level function, which maps the type `Arg` to `Self::Output`.
+///
+/// Implement this for your own types to use them with `Map`, `ZipWith`, `Filter`, and `Fold`.
+/// Functions of several arguments take them as a tuple.
+///
+/// # Example
+/// ```rust
+/// use std::ops::Mul;
+/// use typenum::{assert_type_eq, tarr, Apply, Mapped, Prod, TypeFn, N2, N4, P1, P2, P3, P6};
+///
+/// struct Double;
+///
+/// impl<X: Mul<P2>> TypeFn<X> for Double {
+///     type Output = Prod<X, P2>;
+/// }
+///
+/// assert_type_eq!(Apply<Double, P3>, P6);
+/// assert_type_eq!(Mapped<tarr![P1, N2, P3], Double>, tarr![P2, N4, P6]);
+/// ```
+pub trait TypeFn<Arg> {
+    /// The result of applying the function to `Arg`
+    type Output;
+}
+
+/// A **type operator** that applies the type-level function `F` to each element of an `Array`.
+pub trait Map<F> {
+    /// The array of results
+    type Output;
+}
+
+/// A **type operator** that combines the elements of two `Array`s of the same length pairwise,
+/// by applying the type-level function `F` to `(Self element, Rhs element)`.
+///
+/// # Example
+/// ```rust
+/// use std::ops::Sub;
+/// use typenum::{assert_type_eq, tarr, Diff, TypeFn, ZippedWith, N1, P2, P3, P5};
+///
+/// struct Minus;
+///
+/// impl<L: Sub<R>, R> TypeFn<(L, R)> for Minus {
+///     type Output = Diff<L, R>;
+/// }
+///
+/// assert_type_eq!(ZippedWith<tarr![P5, P2], Minus, tarr![P2, P3]>, tarr![P3, N1]);
+/// ```
+pub trait ZipWith<F, Rhs> {
+    /// The array of results
+    type Output;
+}
+
+/// A **type operator** that keeps the elements of an `Array` for which the type-level predicate
+/// `P` gives `True`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Filtered, Gr, IsGreater, TypeFn, N2, P1, P3, Z0};
+///
+/// struct Positive;
+///
+/// impl<X: IsGreater<Z0>> TypeFn<X> for Positive {
+///     type Output = Gr<X, Z0>;
+/// }
+///
+/// assert_type_eq!(Filtered<tarr![P1, N2, Z0, P3], Positive>, tarr![P1, P3]);
+/// ```
+pub trait Filter<P> {
+    /// The array of the kept elements
+    type Output;
+}
+
+/// A **type operator** that combines the elements of an `Array` from left to right, starting
+/// with `Init` and applying the type-level function `F` to `(accumulator, element)`.
+///
+/// # Example
+/// ```rust
+/// use std::ops::Add;
+/// use typenum::{tarr, Folded, Integer, Sum, TypeFn, N2, P1, P3, Z0};
+///
+/// struct Plus;
+///
+/// impl<L: Add<R>, R> TypeFn<(L, R)> for Plus {
+///     type Output = Sum<L, R>;
+/// }
+///
+/// assert_eq!(<Folded<tarr![P1, N2, P3], Plus, Z0>>::to_i32(), 2);
+/// assert_eq!(<Folded<tarr![], Plus, Z0>>::to_i32(), 0);
+/// ```
+pub trait Fold<F, Init> {
+    /// The final value of the accumulator
+    type Output;
+}
+
+/// A **type operator** that gives the logical and of all elements of an `Array` of bits.
+///
+/// It is `True` for an empty array.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, FoldAll, FoldAny, False, True};
+///
+/// assert_type_eq!(FoldAll<tarr![True, False, True]>, False);
+/// assert_type_eq!(FoldAny<tarr![True, False, True]>, True);
+/// assert_type_eq!(FoldAll<tarr![]>, True);
+/// assert_type_eq!(FoldAny<tarr![]>, False);
+/// ```
+pub trait FoldAnd {
+    /// The result of the fold
+    type Output: Bit;
+}
+
+/// A **type operator** that gives the logical or of all elements of an `Array` of bits.
+///
+/// It is `False` for an empty array.
+pub trait FoldOr {
+    /// The result of the fold
+    type Output: Bit;
+}
+
 #[test]
 fn fold_test() {
     use crate::*;
@@ -345,6 +704,13 @@ fn fold_test() {
 
 /// Division as a partial function. This **type operator** performs division just as `Div`, but is
 /// only defined when the result is an integer (i.e. there is no remainder).
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not divisible by `{Rhs}`",
+        note = "`PartialDiv` is only defined when the remainder is zero"
+    )
+)]
 pub trait PartialDiv<Rhs = Self> {
     /// The type of the result of the division
     type Output;
@@ -352,6 +718,160 @@ pub trait PartialDiv<Rhs = Self> {
     fn partial_div(self, _: Rhs) -> Self::Output;
 }
 
+/// A **type operator** for division that rounds the quotient towards negative infinity.
+///
+/// For unsigned integers this is the same as `Div`; for signed integers it differs from `Div`,
+/// which truncates towards zero, when the operands have different signs.
+///
+/// # Example
+/// ```rust
+/// use typenum::{DivFloor, Integer, QuotFloor, N4, N7, P2};
+///
+/// assert_eq!(<QuotFloor<N7, P2>>::to_i32(), -4);
+/// assert_eq!(N7::new().div_floor(P2::new()), N4::new());
+/// ```
+pub trait DivFloor<Rhs = Self> {
+    /// The type of the quotient
+    type Output;
+    /// Method for performing the division
+    fn div_floor(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A **type operator** for division that rounds the quotient towards positive infinity.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Integer, QuotCeil, Unsigned, N7, P2, U16, U40};
+///
+/// assert_eq!(<QuotCeil<U40, U16>>::to_u32(), 3);
+/// assert_eq!(<QuotCeil<N7, P2>>::to_i32(), -3);
+/// ```
+pub trait DivCeil<Rhs = Self> {
+    /// The type of the quotient
+    type Output;
+    /// Method for performing the division
+    fn div_ceil(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A **type operator** for Euclidean division.
+///
+/// The quotient is chosen so that the remainder given by `RemEuclid` is never negative, just
+/// like `i64::div_euclid`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Integer, QuotEuclid, N2, N7, P2, P7};
+///
+/// assert_eq!(<QuotEuclid<N7, P2>>::to_i32(), -4);
+/// assert_eq!(<QuotEuclid<N7, N2>>::to_i32(), 4);
+/// assert_eq!(<QuotEuclid<P7, N2>>::to_i32(), -3);
+/// ```
+pub trait DivEuclid<Rhs = Self> {
+    /// The type of the quotient
+    type Output;
+    /// Method for performing the division
+    fn div_euclid(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A **type operator** for the remainder of Euclidean division, which is never negative.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Integer, ModEuclid, N7, P2};
+///
+/// assert_eq!(<ModEuclid<N7, P2>>::to_i32(), 1);
+/// ```
+pub trait RemEuclid<Rhs = Self> {
+    /// The type of the remainder
+    type Output;
+    /// Method for computing the remainder
+    fn rem_euclid(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A **type operator** for logical implication on bits: `Self -> Rhs`, which is `!Self | Rhs`.
+pub trait Implies<Rhs = Self> {
+    /// The type of the result of the implication
+    type Output;
+    /// Method for performing the implication
+    fn implies(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A **type operator** that selects `Then` if `Self` is `B1`, and `Else` if it is `B0`.
+///
+/// The alias `If` is usually more convenient, and is also available in `op!` as
+/// `if(condition, then, else)`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, If, Le, N2, P3};
+///
+/// type Smaller<A, B> = If<Le<A, B>, A, B>;
+///
+/// assert_type_eq!(Smaller<N2, P3>, N2);
+/// assert_type_eq!(Smaller<P3, N2>, N2);
+/// ```
+pub trait IfThenElse<Then, Else> {
+    /// The selected type
+    type Output;
+    /// Method returning the selected value
+    fn if_then_else(self, then: Then, else_: Else) -> Self::Output;
+}
+
+/// A **type operator** for addition of unsigned integers that is only implemented if the sum
+/// fits in `Width` bits.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CheckedSum, Unsigned, U200, U55, U8};
+///
+/// assert_eq!(<CheckedSum<U200, U55, U8>>::to_u32(), 255);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{CheckedSum, Unsigned, U200, U56, U8};
+///
+/// <CheckedSum<U200, U56, U8>>::to_u32();
+/// ```
+pub trait CheckedAdd<Rhs, Width> {
+    /// The type of the sum
+    type Output;
+    /// Method for performing the addition
+    fn checked_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A **type operator** for addition of unsigned integers modulo `2^Width`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Unsigned, WrappingSum, U200, U56, U57, U8};
+///
+/// assert_eq!(<WrappingSum<U200, U56, U8>>::to_u32(), 0);
+/// assert_eq!(<WrappingSum<U200, U57, U8>>::to_u32(), 1);
+/// ```
+pub trait WrappingAdd<Rhs, Width> {
+    /// The type of the sum
+    type Output;
+    /// Method for performing the addition
+    fn wrapping_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A **type operator** for subtraction of unsigned integers that gives zero instead of
+/// underflowing.
+///
+/// # Example
+/// ```rust
+/// use typenum::{SaturatingDiff, Unsigned, U3, U5};
+///
+/// assert_eq!(<SaturatingDiff<U5, U3>>::to_u32(), 2);
+/// assert_eq!(<SaturatingDiff<U3, U5>>::to_u32(), 0);
+/// ```
+pub trait SaturatingSub<Rhs = Self> {
+    /// The type of the difference
+    type Output;
+    /// Method for performing the subtraction
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
 /// A **type operator** that returns the minimum of `Self` and `Rhs`.
 pub trait Min<Rhs = Self> {
     /// The type of the minimum of `Self` and `Rhs`
@@ -582,24 +1102,192 @@ macro_rules! cmp {
 /// The integer square root of `n` is the largest integer `m` such
 /// that `n >= m*m`. This definition is equivalent to truncating the
 /// real-valued square root: `floor(real_sqrt(n))`.
+///
+/// It is implemented for unsigned integers and for non-negative signed integers; the output has
+/// the same signedness as the input.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Integer, Sqrt, Unsigned, P10, U10};
+///
+/// assert_eq!(<Sqrt<U10>>::to_u32(), 3);
+/// assert_eq!(<Sqrt<P10>>::to_i32(), 3);
+/// ```
+///
+/// Negative integers have no square root, so this fails to compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{Integer, Sqrt, N9};
+///
+/// <Sqrt<N9>>::to_i32();
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the square root of `{Self}`",
+        note = "the square root is only defined for nonnegative numbers"
+    )
+)]
 pub trait SquareRoot {
     /// The result of the integer square root.
     type Output;
 }
 
-/// A **type operator** for taking the integer binary logarithm of `Self`.
+/// A **type operator** for taking the integer `K`th root of `Self`, rounded down.
 ///
-/// The integer binary logarighm of `n` is the largest integer `m` such
-/// that `n >= 2^m`. This definition is equivalent to truncating the
-/// real-valued binary logarithm: `floor(log2(n))`.
-pub trait Logarithm2 {
-    /// The result of the integer binary logarithm.
+/// It is implemented for unsigned integers, for `K` of at least one.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Cbrt, Root, Unsigned, U1000, U26, U27, U4, U81};
+///
+/// assert_eq!(<Root<U81, U4>>::to_u32(), 3);
+/// assert_eq!(<Cbrt<U26>>::to_u32(), 2);
+/// assert_eq!(<Cbrt<U27>>::to_u32(), 3);
+/// assert_eq!(<Cbrt<U1000>>::to_u32(), 10);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take root `{K}` of `{Self}`",
+        note = "roots are defined for unsigned integers, where `K` is at least one"
+    )
+)]
+pub trait NthRoot<K> {
+    /// The result of the integer root.
     type Output;
 }
 
-/// A **type operator** that computes the [greatest common divisor][gcd] of `Self` and `Rhs`.
+/// A **type operator** for taking the integer cube root of an unsigned integer, rounded down.
 ///
-/// [gcd]: https://en.wikipedia.org/wiki/Greatest_common_divisor
+/// # Example
+/// ```rust
+/// use typenum::{Cbrt, Unsigned, U63, U64};
+///
+/// assert_eq!(<Cbrt<U63>>::to_u32(), 3);
+/// assert_eq!(<Cbrt<U64>>::to_u32(), 4);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the cube root of `{Self}`",
+        note = "the cube root is defined for unsigned integers"
+    )
+)]
+pub trait CubeRoot {
+    /// The result of the integer cube root.
+    type Output;
+}
+
+/// A **type operator** for taking the integer binary logarithm of `Self`.
+///
+/// The integer binary logarighm of `n` is the largest integer `m` such
+/// that `n >= 2^m`. This definition is equivalent to truncating the
+/// real-valued binary logarithm: `floor(log2(n))`.
+///
+/// It is implemented for non-zero unsigned integers and for positive signed integers; the output
+/// has the same signedness as the input.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Integer, Log2, Unsigned, P10, U10};
+///
+/// assert_eq!(<Log2<U10>>::to_u32(), 3);
+/// assert_eq!(<Log2<P10>>::to_i32(), 3);
+/// ```
+///
+/// The logarithm of zero or of a negative integer is undefined, so both of these fail to
+/// compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{Integer, Log2, N8};
+///
+/// <Log2<N8>>::to_i32();
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{Integer, Log2, Z0};
+///
+/// <Log2<Z0>>::to_i32();
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the binary logarithm of `{Self}`",
+        note = "the logarithm is only defined for positive numbers"
+    )
+)]
+pub trait Logarithm2 {
+    /// The result of the integer binary logarithm.
+    type Output;
+}
+
+/// A **type operator** for taking the integer logarithm of `Self` in base `Base`, rounded down.
+///
+/// It is implemented for non-zero unsigned integers, in bases of at least two.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Log, Log10, Unsigned, U1, U1000, U243, U3, U999};
+///
+/// assert_eq!(<Log<U243, U3>>::to_u32(), 5);
+/// assert_eq!(<Log10<U999>>::to_u32(), 2);
+/// assert_eq!(<Log10<U1000>>::to_u32(), 3);
+/// assert_eq!(<Log10<U1>>::to_u32(), 0);
+///
+/// // The number of decimal digits of a number.
+/// type Digits<N> = typenum::Add1<Log10<N>>;
+/// assert_eq!(<Digits<U999>>::to_u32(), 3);
+/// ```
+///
+/// The logarithm of zero, or in base one, is undefined, so this fails to compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{Log, Unsigned, U1, U8};
+///
+/// <Log<U8, U1>>::to_u32();
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the logarithm of `{Self}` in base `{Base}`",
+        note = "the logarithm is only defined for positive numbers, in bases of at least two"
+    )
+)]
+pub trait Logarithm<Base> {
+    /// The result of the integer logarithm.
+    type Output;
+}
+
+/// A **type operator** for taking the integer binary logarithm of `Self`, rounded up.
+///
+/// This is the number of bits needed to count `Self` distinct values, so it is implemented for
+/// non-zero unsigned integers.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CeilLog2, Unsigned, U1, U1000, U1024, U5};
+///
+/// assert_eq!(<CeilLog2<U1>>::to_u32(), 0);
+/// assert_eq!(<CeilLog2<U5>>::to_u32(), 3);
+/// assert_eq!(<CeilLog2<U1000>>::to_u32(), 10);
+/// assert_eq!(<CeilLog2<U1024>>::to_u32(), 10);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the binary logarithm of `{Self}`",
+        note = "the logarithm is only defined for positive numbers"
+    )
+)]
+pub trait CeilLogarithm2 {
+    /// The result of the integer binary logarithm, rounded up.
+    type Output;
+}
+
+/// A **type operator** that computes the [greatest common divisor][gcd] of `Self` and `Rhs`.
+///
+/// [gcd]: https://en.wikipedia.org/wiki/Greatest_common_divisor
 ///
 /// # Example
 ///
@@ -608,19 +1296,688 @@ pub trait Logarithm2 {
 ///
 /// assert_eq!(<U12 as Gcd<U8>>::Output::to_i32(), 4);
 /// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the greatest common divisor of `{Self}` and `{Rhs}`",
+        note = "both must be unsigned integers, or both signed integers"
+    )
+)]
 pub trait Gcd<Rhs> {
     /// The greatest common divisor.
     type Output;
 }
 
+/// A **type operator** that computes the [least common multiple][lcm] of `Self` and `Rhs`.
+///
+/// It is zero if either is zero, and never negative.
+///
+/// [lcm]: https://en.wikipedia.org/wiki/Least_common_multiple
+///
+/// # Example
+///
+/// ```rust
+/// use typenum::{Integer, Scm, Unsigned, N4, P6, U0, U4, U6};
+///
+/// assert_eq!(<Scm<U4, U6>>::to_u32(), 12);
+/// assert_eq!(<Scm<U4, U0>>::to_u32(), 0);
+/// assert_eq!(<Scm<N4, P6>>::to_i32(), 12);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot take the least common multiple of `{Self}` and `{Rhs}`",
+        note = "both must be unsigned integers, or both signed integers"
+    )
+)]
+pub trait Lcm<Rhs> {
+    /// The least common multiple.
+    type Output;
+}
+
+/// A **type operator** for the [extended Euclidean algorithm][ext], which finds the greatest
+/// common divisor `G` of `Self` and `Rhs` along with signed integers `X` and `Y` such that
+/// `Self * X + Rhs * Y = G`.
+///
+/// `G` is the same as [`Gcf<Self, Rhs>`](crate::Gcf). The coefficients are those the algorithm
+/// finds, of which there are infinitely many.
+///
+/// [ext]: https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm
+///
+/// # Example
+///
+/// ```rust
+/// use typenum::{BezoutX, BezoutY, ExtGcd, Integer, Unsigned, N240, P46, U240, U46};
+///
+/// assert_eq!(<<U240 as ExtGcd<U46>>::G>::to_u32(), 2);
+/// assert_eq!(<BezoutX<U240, U46>>::to_i32(), -9);
+/// assert_eq!(<BezoutY<U240, U46>>::to_i32(), 47);
+///
+/// // -240 * 9 + 46 * 47 = 2
+/// assert_eq!(<BezoutX<N240, P46>>::to_i32(), 9);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot run the extended Euclidean algorithm on `{Self}` and `{Rhs}`",
+        note = "both must be unsigned integers, or both signed integers"
+    )
+)]
+pub trait ExtGcd<Rhs> {
+    /// The greatest common divisor.
+    type G;
+    /// The coefficient of `Self`.
+    type X;
+    /// The coefficient of `Rhs`.
+    type Y;
+}
+
+/// A **type operator** that tests whether `Self` and `Rhs` are coprime, that is, whether their
+/// greatest common divisor is one, giving `B1` or `B0`.
+///
+/// To require coprime numbers in a where clause, use the [`Coprime`](crate::Coprime) marker
+/// trait.
+///
+/// # Example
+///
+/// ```rust
+/// use typenum::{Bit, Coprimality, N4, P9, U12, U35, U64};
+///
+/// assert!(<Coprimality<U35, U64>>::to_bool());
+/// assert!(!<Coprimality<U12, U64>>::to_bool());
+/// assert!(<Coprimality<N4, P9>>::to_bool());
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot test whether `{Self}` and `{Rhs}` are coprime",
+        note = "both must be unsigned integers, or both signed integers"
+    )
+)]
+pub trait IsCoprime<Rhs> {
+    /// `B1` if `Self` and `Rhs` are coprime, `B0` otherwise.
+    type Output;
+}
+
+/// A **type operator** for modular addition of unsigned integers: `(Self + Rhs) mod M`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{SumMod, Unsigned, U4, U5, U7};
+///
+/// assert_eq!(<SumMod<U5, U4, U7>>::to_u32(), 2);
+/// ```
+pub trait AddMod<Rhs, M> {
+    /// The sum modulo `M`.
+    type Output;
+}
+
+/// A **type operator** for modular multiplication of unsigned integers: `(Self * Rhs) mod M`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ProdMod, Unsigned, U4, U5, U7};
+///
+/// assert_eq!(<ProdMod<U5, U4, U7>>::to_u32(), 6);
+/// ```
+pub trait MulMod<Rhs, M> {
+    /// The product modulo `M`.
+    type Output;
+}
+
+/// A **type operator** for modular exponentiation of unsigned integers: `Self^E mod M`.
+///
+/// It reduces modulo `M` after each step, so the intermediate numbers stay smaller than `M^2`
+/// times `Self`, however large `Self^E` is.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ExpMod, Unsigned, U1000, U1024, U2};
+///
+/// assert_eq!(<ExpMod<U2, U1024, U1000>>::to_u32(), 216);
+/// ```
+pub trait PowMod<E, M> {
+    /// The power modulo `M`.
+    type Output;
+}
+
+/// A **type operator** for the [modular multiplicative inverse][inverse] of an unsigned integer:
+/// the `X` in `0..M` with `Self * X mod M = 1`.
+///
+/// It is found with the extended Euclidean algorithm, and exists only when `Self` and `M` are
+/// coprime.
+///
+/// [inverse]: https://en.wikipedia.org/wiki/Modular_multiplicative_inverse
+///
+/// # Example
+///
+/// The constants for Montgomery multiplication modulo `N = 251` with `R = 2^8`:
+///
+/// ```rust
+/// use typenum::{Diff, InvMod, ProdMod, Unsigned, U251, U256};
+///
+/// type N = U251;
+/// type R = U256;
+///
+/// // R^2 mod N
+/// assert_eq!(<ProdMod<R, R, N>>::to_u32(), 25);
+/// // N' = -N^-1 mod R
+/// assert_eq!(<InvMod<N, R>>::to_u32(), 51);
+/// assert_eq!(<Diff<R, InvMod<N, R>>>::to_u32(), 205);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{InvMod, Unsigned, U4, U8};
+///
+/// <InvMod<U4, U8>>::to_u32();
+/// ```
+pub trait ModInverse<M> {
+    /// The inverse modulo `M`.
+    type Output;
+}
+
+/// A **type operator** that tests whether an unsigned integer is prime, giving `B1` or `B0`.
+///
+/// To require a prime number in a where clause, use the [`Prime`](crate::Prime) marker trait.
+/// It is decided by [`SmallestFactor`], which only scales to primes of about 2^16.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, Primality, U0, U1, U1009, U1011, U2};
+///
+/// assert!(!<Primality<U0>>::to_bool());
+/// assert!(!<Primality<U1>>::to_bool());
+/// assert!(<Primality<U2>>::to_bool());
+/// assert!(<Primality<U1009>>::to_bool());
+/// assert!(!<Primality<U1011>>::to_bool());
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot test `{Self}` for primality",
+        note = "only unsigned integers can be tested"
+    )
+)]
+pub trait IsPrime {
+    /// `B1` if `Self` is prime, `B0` otherwise.
+    type Output;
+}
+
+/// A **type operator** for the smallest factor greater than one of an unsigned integer, which
+/// is its smallest prime factor.
+///
+/// It is found by trial division up to the square root, so a prime is its own smallest factor.
+/// Only 2, 3, and the numbers of the form `6k ± 1` are tried, but each of those is a level of
+/// trait recursion, so the cost grows with the square root of the smallest factor:
+///
+/// - Numbers whose smallest factor is below about 2^12, such as 4099, work with the default
+///   `recursion_limit`.
+/// - A prime around 2^16, such as 65537, needs `#![recursion_limit = "256"]`.
+/// - A prime around 2^20 does not compile even with `#![recursion_limit = "1024"]`, and takes
+///   minutes to fail, so primes near 2^30, such as large moduli, cannot be checked at all.
+///
+/// # Example
+/// ```rust
+/// use typenum::{LeastFactor, Unsigned, U1009, U1011, U49};
+///
+/// assert_eq!(<LeastFactor<U49>>::to_u32(), 7);
+/// assert_eq!(<LeastFactor<U1011>>::to_u32(), 3);
+/// assert_eq!(<LeastFactor<U1009>>::to_u32(), 1009);
+/// ```
+///
+/// Zero and one have no such factor, so this fails to compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{LeastFactor, Unsigned, U1};
+///
+/// <LeastFactor<U1>>::to_u32();
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` has no smallest prime factor",
+        note = "only unsigned integers greater than one have prime factors"
+    )
+)]
+pub trait SmallestFactor {
+    /// The smallest prime factor of `Self`.
+    type Output;
+}
+
+/// A **type operator** for the prime factorization of a positive unsigned integer, as a type
+/// array of its prime factors in ascending order, repeated by their multiplicity.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Factors, U1, U1009, U2, U3, U360, U5, ATerm};
+///
+/// assert_type_eq!(Factors<U360>, tarr![U2, U2, U2, U3, U3, U5]);
+/// assert_type_eq!(Factors<U1009>, tarr![U1009]);
+/// assert_type_eq!(Factors<U1>, ATerm);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` has no prime factorization",
+        note = "only positive unsigned integers can be factorized"
+    )
+)]
+pub trait Factorize {
+    /// The prime factors of `Self`.
+    type Output;
+}
+
+/// A **type operator** that tests whether `Self` is a power of two, giving `B1` or `B0`.
+///
+/// Unlike the [`PowerOfTwo`](crate::PowerOfTwo) marker trait, it is implemented for every
+/// integer, so it can be branched on.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsPow2, N4, P4, U0, U6, U64};
+///
+/// assert!(<IsPow2<U64>>::to_bool());
+/// assert!(!<IsPow2<U6>>::to_bool());
+/// assert!(!<IsPow2<U0>>::to_bool());
+/// assert!(<IsPow2<P4>>::to_bool());
+/// assert!(!<IsPow2<N4>>::to_bool());
+/// ```
+pub trait IsPowerOfTwo {
+    /// `B1` if `Self` is a power of two, `B0` otherwise.
+    type Output;
+}
+
+/// A **type operator** for the smallest power of two greater than or equal to an unsigned
+/// integer. As with `u32::next_power_of_two`, that of zero is one.
+///
+/// # Example
+/// ```rust
+/// use typenum::{NextPow2, Unsigned, U0, U1000, U64};
+///
+/// assert_eq!(<NextPow2<U0>>::to_u32(), 1);
+/// assert_eq!(<NextPow2<U64>>::to_u32(), 64);
+/// assert_eq!(<NextPow2<U1000>>::to_u32(), 1024);
+/// ```
+pub trait NextPowerOfTwo {
+    /// The next power of two.
+    type Output;
+}
+
+/// A **type operator** for the largest power of two less than or equal to a non-zero unsigned
+/// integer.
+///
+/// # Example
+/// ```rust
+/// use typenum::{PrevPow2, Unsigned, U1, U1000, U64};
+///
+/// assert_eq!(<PrevPow2<U1>>::to_u32(), 1);
+/// assert_eq!(<PrevPow2<U64>>::to_u32(), 64);
+/// assert_eq!(<PrevPow2<U1000>>::to_u32(), 512);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` has no power of two below it",
+        note = "only non-zero unsigned integers do"
+    )
+)]
+pub trait PrevPowerOfTwo {
+    /// The previous power of two.
+    type Output;
+}
+
+/// A **type operator** for the number of ones in the binary representation of an unsigned
+/// integer.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Ones, Unsigned, U0, U255, U256};
+///
+/// assert_eq!(<Ones<U0>>::to_u32(), 0);
+/// assert_eq!(<Ones<U255>>::to_u32(), 8);
+/// assert_eq!(<Ones<U256>>::to_u32(), 1);
+/// ```
+pub trait CountOnes {
+    /// The number of ones.
+    type Output;
+}
+
+/// A **type operator** for the number of zeros in the binary representation of an unsigned
+/// integer that is `Width` bits wide, leading zeros included.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Unsigned, Zeros, U0, U1, U255, U256, U32, U8};
+///
+/// assert_eq!(<Zeros<U1, U8>>::to_u32(), 7);
+/// assert_eq!(<Zeros<U0, U8>>::to_u32(), 8);
+/// assert_eq!(<Zeros<U255, U8>>::to_u32(), 0);
+/// assert_eq!(<Zeros<U256, U32>>::to_u32(), 31);
+/// ```
+///
+/// The number must fit in `Width` bits, so this fails to compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{Unsigned, Zeros, U256, U8};
+///
+/// <Zeros<U256, U8>>::to_u32();
+/// ```
+pub trait CountZeros<Width> {
+    /// The number of zeros.
+    type Output;
+}
+
+/// A **type operator** for the number of zeros below the lowest one in the binary representation
+/// of a non-zero unsigned integer.
+///
+/// # Example
+/// ```rust
+/// use typenum::{TrailZeros, Unsigned, U1, U24, U256};
+///
+/// assert_eq!(<TrailZeros<U1>>::to_u32(), 0);
+/// assert_eq!(<TrailZeros<U24>>::to_u32(), 3);
+/// assert_eq!(<TrailZeros<U256>>::to_u32(), 8);
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot count the trailing zeros of `{Self}`",
+        note = "only non-zero unsigned integers have a lowest one"
+    )
+)]
+pub trait TrailingZeros {
+    /// The number of trailing zeros.
+    type Output;
+}
+
+/// A **type operator** for the number of zeros above the highest one of an unsigned integer
+/// that is `Width` bits wide.
+///
+/// # Example
+/// ```rust
+/// use typenum::{LeadZeros, Unsigned, U0, U1, U255, U32, U8};
+///
+/// assert_eq!(<LeadZeros<U1, U32>>::to_u32(), 31);
+/// assert_eq!(<LeadZeros<U255, U8>>::to_u32(), 0);
+/// assert_eq!(<LeadZeros<U0, U8>>::to_u32(), 8);
+/// ```
+///
+/// The number must fit in `Width` bits, so this fails to compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{LeadZeros, Unsigned, U256, U8};
+///
+/// <LeadZeros<U256, U8>>::to_u32();
+/// ```
+pub trait LeadingZeros<Width> {
+    /// The number of leading zeros.
+    type Output;
+}
+
+/// A **type operator** for reversing the order of the low `Width` bits of an unsigned integer,
+/// such as for bit-reversed indices in a fast Fourier transform.
+///
+/// # Example
+/// ```rust
+/// use typenum::{BitsReversed, Unsigned, U1, U128, U6, U8, U3};
+///
+/// assert_eq!(<BitsReversed<U1, U8>>::to_u32(), 128);
+/// assert_eq!(<BitsReversed<U128, U8>>::to_u32(), 1);
+/// // 0b110 becomes 0b011
+/// assert_eq!(<BitsReversed<U6, U3>>::to_u32(), 3);
+/// ```
+///
+/// The number must fit in `Width` bits, so this fails to compile:
+///
+/// ```rust,compile_fail
+/// use typenum::{BitsReversed, Unsigned, U3, U8};
+///
+/// <BitsReversed<U8, U3>>::to_u32();
+/// ```
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(
+        message = "cannot reverse the low `{Width}` bits of `{Self}`",
+        note = "the number must fit in `{Width}` bits"
+    )
+)]
+pub trait ReverseBits<Width> {
+    /// The number with its bits reversed.
+    type Output;
+}
+
 /// A **type operator** for taking a concrete integer value from a type.
 ///
 /// It returns arbitrary integer value without explicitly specifying the
 /// type. It is useful when you pass the values to methods that accept
 /// distinct types without runtime casting.
+#[cfg_attr(
+    feature = "diagnostics",
+    diagnostic::on_unimplemented(message = "`{Self}` cannot be converted to `{T}`")
+)]
 pub trait ToInt<T> {
     /// Method returning the concrete value for the type.
     fn to_int() -> T;
     /// The concrete value for the type. Can be used in `const` contexts.
     const INT: T;
 }
+
+/// A **type operator** for taking the concrete values of the elements of a type-level array.
+///
+/// It is implemented for arrays whose elements all implement [`ToInt<T>`](ToInt), so for arrays
+/// of signed or unsigned integers.
+///
+/// # Example
+/// ```rust
+/// use typenum::{tarr, ToArray, N4, P3, P8};
+///
+/// type Shape = tarr![P3, N4, P8];
+///
+/// assert_eq!(<Shape as ToArray<i64>>::LEN, 3);
+/// assert_eq!(<Shape as ToArray<i64>>::value_at(1), Some(-4));
+/// assert_eq!(<Shape as ToArray<i64>>::value_at(3), None);
+///
+/// let mut values = <Shape as ToArray<i32>>::iter();
+/// assert_eq!(values.next(), Some(3));
+/// assert_eq!(values.len(), 2);
+/// ```
+pub trait ToArray<T>: crate::TypeArray + crate::private::PrivateToArray<T> {
+    /// The number of elements in the array.
+    const LEN: usize;
+
+    /// Method returning the value of the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    fn value_at(index: usize) -> Option<T> {
+        Self::iter().nth(index)
+    }
+
+    /// Method returning an iterator over the values of the elements.
+    #[inline]
+    fn iter() -> crate::array::Iter<T> {
+        crate::array::Iter::new(Self::private_first(), Self::LEN)
+    }
+
+    /// Method returning the values of the elements as an array.
+    ///
+    /// The length `N` must match the length of the type-level array, which is checked at compile
+    /// time.
+    ///
+    /// # Example
+    /// ```rust
+    /// use typenum::{tarr, ToArray, N4, P3, P8};
+    ///
+    /// let shape: [i64; 3] = <tarr![P3, N4, P8]>::to_array();
+    /// assert_eq!(shape, [3, -4, 8]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use typenum::{tarr, ToArray, N4, P3, P8};
+    ///
+    /// let shape: [i64; 2] = <tarr![P3, N4, P8]>::to_array();
+    /// ```
+    #[cfg(feature = "const-generics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "const-generics")))]
+    #[inline]
+    fn to_array<const N: usize>() -> [T; N]
+    where
+        Self: ToConstArray<T, N>,
+    {
+        Self::ARRAY
+    }
+}
+
+/// A **type operator** for the values of the elements of a type-level array as a constant array.
+///
+/// The length `N` must match the length of the type-level array, which is checked at compile
+/// time.
+///
+///   - It requires the `const-generics` crate feature to be enabled.
+///
+/// # Example
+/// ```rust
+/// use typenum::{tarr, ToConstArray, N4, P3, P8};
+///
+/// const SHAPE: [i64; 3] = <tarr![P3, N4, P8] as ToConstArray<i64, 3>>::ARRAY;
+/// assert_eq!(SHAPE, [3, -4, 8]);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{tarr, ToConstArray, N4, P3, P8};
+///
+/// const SHAPE: [i64; 2] = <tarr![P3, N4, P8] as ToConstArray<i64, 2>>::ARRAY;
+/// ```
+#[cfg(feature = "const-generics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "const-generics")))]
+pub trait ToConstArray<T, const N: usize>: ToArray<T> {
+    /// The values of the elements.
+    const ARRAY: [T; N];
+}
+
+/// A **type operator** for the little- and big-endian bytes of a number of any width.
+///
+/// Unlike the constants of [`Unsigned`] and [`Integer`](crate::Integer), which truncate numbers
+/// that do not fit in the primitive type, the bytes hold the whole value. Signed integers are
+/// written in twos-complement.
+///
+///   - The methods require the `const-generics` crate feature to be enabled.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ToBytes, Unsigned, N128, P128, U256};
+///
+/// assert_eq!(<U256 as ToBytes>::ByteLen::USIZE, 2);
+/// assert_eq!(<N128 as ToBytes>::ByteLen::USIZE, 1);
+/// assert_eq!(<P128 as ToBytes>::ByteLen::USIZE, 2);
+/// ```
+pub trait ToBytes {
+    /// The number of bytes: the length of the bit representation rounded up to whole bytes,
+    /// with an extra sign bit for signed integers.
+    type ByteLen: Unsigned;
+
+    /// Method returning the little-endian bytes of the value.
+    ///
+    /// The length `N` must match [`ByteLen`](Self::ByteLen), which is checked at compile time.
+    ///
+    /// # Example
+    /// Numbers this wide usually need a higher `recursion_limit`:
+    ///
+    /// ```rust
+    /// #![recursion_limit = "256"]
+    /// use typenum::{Prod, ToBytes, Unsigned, U1000000000000000000};
+    ///
+    /// // 10^36 needs 120 bits.
+    /// type Big = Prod<U1000000000000000000, U1000000000000000000>;
+    ///
+    /// let bytes: [u8; 15] = Big::to_le_bytes();
+    /// assert_eq!(bytes, 10u128.pow(36).to_le_bytes()[..15]);
+    ///
+    /// // 10^54 needs 180 bits, more than any primitive holds.
+    /// type Bigger = Prod<Big, U1000000000000000000>;
+    ///
+    /// let bytes: [u8; <Bigger as ToBytes>::ByteLen::USIZE] = Bigger::to_le_bytes();
+    /// assert_eq!(
+    ///     bytes,
+    ///     [
+    ///         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x92, 0x7d, 0xf6, 0x65, 0x0b,
+    ///         0x09, 0x99, 0x19, 0xc5, 0xe6, 0x64, 0x0a, 0xc4, 0xc3, 0x70, 0x0a,
+    ///     ],
+    /// );
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use typenum::{ToBytes, U256};
+    ///
+    /// let bytes: [u8; 1] = U256::to_le_bytes();
+    /// ```
+    #[cfg(feature = "const-generics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "const-generics")))]
+    #[inline]
+    fn to_le_bytes<const N: usize>() -> [u8; N]
+    where
+        Self: ToConstBytes<N>,
+    {
+        Self::LE_BYTES
+    }
+
+    /// Method returning the big-endian bytes of the value.
+    ///
+    /// The length `N` must match [`ByteLen`](Self::ByteLen), which is checked at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// use typenum::{ToBytes, N129, P128, U256};
+    ///
+    /// assert_eq!(U256::to_be_bytes(), [1, 0]);
+    /// assert_eq!(P128::to_be_bytes(), [0, 0x80]);
+    /// assert_eq!(N129::to_be_bytes(), (-129i16).to_be_bytes());
+    /// ```
+    #[cfg(feature = "const-generics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "const-generics")))]
+    #[inline]
+    fn to_be_bytes<const N: usize>() -> [u8; N]
+    where
+        Self: ToConstBytes<N>,
+    {
+        Self::BE_BYTES
+    }
+}
+
+/// A **type operator** for the little- and big-endian bytes of a number as constant arrays.
+///
+/// The length `N` must match [`ToBytes::ByteLen`], which is checked at compile time.
+///
+///   - It requires the `const-generics` crate feature to be enabled.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ToConstBytes, N129};
+///
+/// const BYTES: [u8; 2] = <N129 as ToConstBytes<2>>::BE_BYTES;
+/// assert_eq!(BYTES, (-129i16).to_be_bytes());
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum::{ToConstBytes, N129};
+///
+/// const BYTES: [u8; 4] = <N129 as ToConstBytes<4>>::BE_BYTES;
+/// ```
+#[cfg(feature = "const-generics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "const-generics")))]
+pub trait ToConstBytes<const N: usize>: ToBytes {
+    /// The little-endian bytes of the value.
+    const LE_BYTES: [u8; N];
+    /// The big-endian bytes of the value.
+    const BE_BYTES: [u8; N];
+}
+
+#[cfg(feature = "const-generics")]
+use crate::private::{check_len, reverse_bytes, PrivateBytes};
+
+#[cfg(feature = "const-generics")]
+impl<A, const N: usize> ToConstBytes<N> for A
+where
+    A: ToBytes + PrivateBytes<N>,
+{
+    const LE_BYTES: [u8; N] = check_len(A::PRIVATE_LE_BYTES, A::ByteLen::USIZE);
+    const BE_BYTES: [u8; N] = reverse_bytes(Self::LE_BYTES);
+}
@@ -26,6 +26,20 @@
 //! assert_eq!(<U3 as Div<U2>>::Output::to_u32(), 1);
 //! assert_eq!(<U3 as Rem<U2>>::Output::to_u32(), 1);
 //! ```
+//!
+//! Unsigned integers of any width can be formatted with `Display`, `LowerHex`, `UpperHex`,
+//! `Octal`, and `Binary`, which respect the usual formatting flags. `Debug` writes the name of
+//! the constant instead.
+//!
+//! ```rust
+//! use typenum::{U1000000000000000000, U255};
+//!
+//! type Big = typenum::Prod<U1000000000000000000, U1000000000000000000>;
+//!
+//! let n = U255::new();
+//! assert_eq!(format!("{} {:x} {:#o} {:?}", n, n, n, n), "255 ff 0o377 U255");
+//! assert_eq!(format!("{}", Big::new()), "1000000000000000000000000000000000000");
+//! ```
 
 use crate::{
     bit::{Bit, B0, B1},
@@ -33,7 +47,7 @@ use crate::{
     private::{
         BitDiff, BitDiffOut, Internal, InternalMarker, PrivateAnd, PrivateAndOut, PrivateCmp,
         PrivateCmpOut, PrivateLogarithm2, PrivatePow, PrivatePowOut, PrivateSquareRoot, PrivateSub,
-        PrivateSubOut, PrivateXor, PrivateXorOut, Trim, TrimOut,
+        PrivateSubOut, PrivateUnsigned, PrivateXor, PrivateXorOut, Trim, TrimOut,
     },
     Add1, Cmp, Double, Equal, Gcd, Gcf, GrEq, Greater, IsGreaterOrEqual, Len, Length, Less, Log2,
     Logarithm2, Maximum, Minimum, NonZero, Or, Ord, Pow, Prod, Shleft, Shright, Sqrt, Square,
@@ -45,7 +59,7 @@ pub use crate::marker_traits::{PowerOfTwo, Unsigned};
 
 /// The terminating type for `UInt`; it always comes after the most significant
 /// bit. `UTerm` by itself represents zero, which is aliased to `U0`.
-#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Default)]
 #[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
 pub struct UTerm;
 
@@ -127,6 +141,14 @@ impl Unsigned for UTerm {
     }
 }
 
+impl PrivateUnsigned for UTerm {
+    const PRIVATE_BITS: usize = 0;
+    const PRIVATE_IS_POWER_OF_TWO: bool = false;
+
+    #[inline]
+    fn private_for_each_bit<F: FnMut(bool)>(_: &mut F) {}
+}
+
 /// `UInt` is defined recursively, where `B` is the least significant bit and `U` is the rest
 /// of the number. Conceptually, `U` should be bound by the trait `Unsigned` and `B` should
 /// be bound by the trait `Bit`, but enforcing these bounds causes linear instead of
@@ -143,7 +165,7 @@ impl Unsigned for UTerm {
 /// # #[allow(dead_code)]
 /// type U6 = UInt<UInt<UInt<UTerm, B1>, B1>, B0>;
 /// ```
-#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Default)]
 #[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
 pub struct UInt<U, B> {
     /// The more significant bits of `Self`.
@@ -230,6 +252,18 @@ impl<U: Unsigned, B: Bit> Unsigned for UInt<U, B> {
     }
 }
 
+impl<U: Unsigned, B: Bit> PrivateUnsigned for UInt<U, B> {
+    const PRIVATE_BITS: usize = U::PRIVATE_BITS + 1;
+    const PRIVATE_IS_POWER_OF_TWO: bool =
+        B::BOOL & (U::PRIVATE_BITS == 0) | !B::BOOL & U::PRIVATE_IS_POWER_OF_TWO;
+
+    #[inline]
+    fn private_for_each_bit<F: FnMut(bool)>(f: &mut F) {
+        U::private_for_each_bit(f);
+        f(B::BOOL);
+    }
+}
+
 impl<U: Unsigned, B: Bit> NonZero for UInt<U, B> {}
 impl Zero for UTerm {}
 
@@ -301,6 +335,208 @@ where
     }
 }
 
+// ---------------------------------------------------------------------------------------
+// Formatting in decimal, hexadecimal, and octal
+
+/// Replays the bits of a number, most significant first, into the given sink.
+type BitSource<'a> = dyn FnMut(&mut dyn FnMut(u64)) + 'a;
+
+/// Visits a chunk of digits, and whether it is the most significant one.
+type ChunkVisitor<'a> = dyn FnMut(u64, bool) -> core::fmt::Result + 'a;
+
+/// Visits the chunks of the number whose bits `bits` replays, most significant first, where a
+/// chunk is a digit in base `base`. The second argument of `visit` is whether the chunk is the
+/// most significant one.
+///
+/// Each level of recursion divides the bits by `base` once more as they stream by, keeping only
+/// a remainder, so numbers of any width are handled without a buffer for their digits.
+fn for_each_chunk(
+    bits: &mut BitSource<'_>,
+    base: u64,
+    visit: &mut ChunkVisitor<'_>,
+) -> core::fmt::Result {
+    // The chunk is the remainder of dividing by `base`, and there are more chunks if the
+    // quotient is not zero.
+    let mut chunk = 0;
+    let mut more = false;
+    bits(&mut |bit| {
+        chunk = chunk * 2 + bit;
+        if chunk >= base {
+            chunk -= base;
+            more = true;
+        }
+    });
+
+    if more {
+        let mut quotient = |sink: &mut dyn FnMut(u64)| {
+            let mut remainder = 0;
+            bits(&mut |bit| {
+                remainder = remainder * 2 + bit;
+                let q = u64::from(remainder >= base);
+                remainder -= q * base;
+                sink(q);
+            });
+        };
+        for_each_chunk(&mut quotient, base, visit)?;
+    }
+    visit(chunk, !more)
+}
+
+/// Writes the digits of the value of `U` in `radix` (8, 10, or 16), ignoring the flags of `f`.
+///
+/// Returns the number of digits instead if `count` is set.
+fn write_digits<U: Unsigned>(
+    f: &mut core::fmt::Formatter<'_>,
+    radix: u32,
+    upper: bool,
+    count: bool,
+) -> Result<usize, core::fmt::Error> {
+    // Each chunk holds `per_chunk` digits, so that chunks can be printed one at a time.
+    let per_chunk = match radix {
+        8 => 10,
+        10 => 9,
+        16 => 7,
+        _ => unreachable!("unsupported radix"),
+    };
+    let digits = if upper {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+
+    let mut len = 0;
+    let mut bits =
+        |sink: &mut dyn FnMut(u64)| U::private_for_each_bit(&mut |bit| sink(u64::from(bit)));
+    for_each_chunk(
+        &mut bits,
+        u64::from(radix).pow(per_chunk),
+        &mut |mut chunk, is_top| {
+            let mut buffer = [b'0'; 10];
+            let mut start = buffer.len();
+            for _ in 0..per_chunk {
+                start -= 1;
+                buffer[start] = digits[(chunk % u64::from(radix)) as usize];
+                chunk /= u64::from(radix);
+                // Only the most significant chunk drops its leading zeros.
+                if chunk == 0 && is_top {
+                    break;
+                }
+            }
+            len += buffer.len() - start;
+            if count {
+                Ok(())
+            } else {
+                f.write_str(core::str::from_utf8(&buffer[start..]).map_err(|_| core::fmt::Error)?)
+            }
+        },
+    )?;
+    Ok(len)
+}
+
+/// Formats the value of `U` in `radix` (8, 10, or 16), with the sign given by `is_nonnegative`.
+///
+/// The usual width, fill, alignment, sign, and `#` flags apply, as with
+/// `Formatter::pad_integral`, which can't be used as the digits are never all in memory at once.
+pub(crate) fn fmt_radix<U: Unsigned>(
+    f: &mut core::fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    radix: u32,
+    prefix: &str,
+    upper: bool,
+) -> core::fmt::Result {
+    use core::fmt::Write;
+
+    let sign = if !is_nonnegative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let prefix = if f.alternate() { prefix } else { "" };
+    let len = sign.len() + prefix.len() + write_digits::<U>(f, radix, upper, true)?;
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        write_digits::<U>(f, radix, upper, false)?;
+        return Ok(());
+    }
+
+    let (before, after) = match f.align() {
+        Some(core::fmt::Alignment::Left) => (0, padding),
+        Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(core::fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    f.write_str(prefix)?;
+    write_digits::<U>(f, radix, upper, false)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Formats the name of the constant for the value of `U`, such as `U42`, ignoring the flags of
+/// `f`.
+pub(crate) fn fmt_debug<U: Unsigned>(
+    f: &mut core::fmt::Formatter<'_>,
+    letter: &str,
+) -> core::fmt::Result {
+    f.write_str(letter)?;
+    write_digits::<U>(f, 10, false, false)?;
+    Ok(())
+}
+
+macro_rules! impl_fmt_radix {
+    ($($Trait:ident: $radix:expr, $prefix:expr, $upper:expr;)*) => {
+        $(
+            impl core::fmt::$Trait for UTerm {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    fmt_radix::<Self>(f, true, $radix, $prefix, $upper)
+                }
+            }
+
+            impl<U: Unsigned, B: Bit> core::fmt::$Trait for UInt<U, B> {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    fmt_radix::<Self>(f, true, $radix, $prefix, $upper)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_radix! {
+    Display: 10, "", false;
+    LowerHex: 16, "0x", false;
+    UpperHex: 16, "0x", true;
+    Octal: 8, "0o", false;
+}
+
+/// Formats as the name of the constant, such as `U42`.
+impl core::fmt::Debug for UTerm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_debug::<Self>(f, "U")
+    }
+}
+
+/// Formats as the name of the constant, such as `U42`.
+impl<U: Unsigned, B: Bit> core::fmt::Debug for UInt<U, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_debug::<Self>(f, "U")
+    }
+}
+
 #[cfg(test)]
 mod fmt_tests {
     use super::*;
@@ -309,14 +545,14 @@ mod fmt_tests {
 
     struct LimitedString {
         len: usize,
-        buffer: [u8; 64],
+        buffer: [u8; 1024],
     }
 
     impl LimitedString {
         fn new() -> Self {
             Self {
                 len: 0,
-                buffer: [0u8; 64],
+                buffer: [0u8; 1024],
             }
         }
 
@@ -355,6 +591,143 @@ mod fmt_tests {
 
         assert_binary_fmt::<U2147483648>("10000000000000000000000000000000");
     }
+
+    fn assert_fmt<U>(expected: [&str; 5])
+    where
+        U: Unsigned
+            + core::fmt::Display
+            + core::fmt::LowerHex
+            + core::fmt::UpperHex
+            + core::fmt::Octal
+            + core::fmt::Debug,
+    {
+        let mut s = LimitedString::new();
+        write!(&mut s, "{}", U::default()).unwrap();
+        assert_eq!(s.as_str(), expected[0]);
+
+        let mut s = LimitedString::new();
+        write!(&mut s, "{:x}", U::default()).unwrap();
+        assert_eq!(s.as_str(), expected[1]);
+
+        let mut s = LimitedString::new();
+        write!(&mut s, "{:X}", U::default()).unwrap();
+        assert_eq!(s.as_str(), expected[2]);
+
+        let mut s = LimitedString::new();
+        write!(&mut s, "{:o}", U::default()).unwrap();
+        assert_eq!(s.as_str(), expected[3]);
+
+        let mut s = LimitedString::new();
+        write!(&mut s, "{:?}", U::default()).unwrap();
+        assert_eq!(s.as_str(), expected[4]);
+    }
+
+    #[test]
+    fn radix() {
+        assert_fmt::<U0>(["0", "0", "0", "0", "U0"]);
+        assert_fmt::<U1>(["1", "1", "1", "1", "U1"]);
+        assert_fmt::<U10>(["10", "a", "A", "12", "U10"]);
+        assert_fmt::<U255>(["255", "ff", "FF", "377", "U255"]);
+        assert_fmt::<U1000000000>([
+            "1000000000",
+            "3b9aca00",
+            "3B9ACA00",
+            "7346545000",
+            "U1000000000",
+        ]);
+
+        // Wider than `u64`.
+        type Big = Prod<U1000000000000000000, U1000000000000000000>;
+        assert_fmt::<Big>([
+            "1000000000000000000000000000000000000",
+            "c097ce7bc90715b34b9f1000000000",
+            "C097CE7BC90715B34B9F1000000000",
+            "6011371636744407053315134761000000000000",
+            "U1000000000000000000000000000000000000",
+        ]);
+    }
+
+    #[test]
+    fn flags() {
+        let mut s = LimitedString::new();
+        write!(
+            &mut s,
+            "{:>5}|{:<4}|{:05}|{:+}",
+            U42::new(),
+            U7::new(),
+            U42::new(),
+            U3::new()
+        )
+        .unwrap();
+        assert_eq!(s.as_str(), "   42|7   |00042|+3");
+
+        let mut s = LimitedString::new();
+        write!(
+            &mut s,
+            "{:#x} {:#o} {:#06X}",
+            U255::new(),
+            U8::new(),
+            U255::new()
+        )
+        .unwrap();
+        assert_eq!(s.as_str(), "0xff 0o10 0x00FF");
+
+        let mut s = LimitedString::new();
+        write!(
+            &mut s,
+            "{:^7}|{:*<5x}|{:+#o}",
+            U42::new(),
+            U10::new(),
+            U8::new()
+        )
+        .unwrap();
+        assert_eq!(s.as_str(), "  42   |a****|+0o10");
+    }
+
+    #[test]
+    fn debug_ignores_flags() {
+        let mut s = LimitedString::new();
+        write!(
+            &mut s,
+            "{:+?}|{:>6?}|{:06?}",
+            U42::new(),
+            U7::new(),
+            U0::new()
+        )
+        .unwrap();
+        assert_eq!(s.as_str(), "U42|U7|U0");
+
+        let mut s = LimitedString::new();
+        write!(&mut s, "{:+?}|{:>6?}", P42::new(), N3::new()).unwrap();
+        assert_eq!(s.as_str(), "P42|N3");
+    }
+
+    #[test]
+    fn wide() {
+        // The bits of 2^2304, which is 2305 bits wide, most significant first.
+        let mut bits = |sink: &mut dyn FnMut(u64)| {
+            sink(1);
+            for _ in 0..2304 {
+                sink(0);
+            }
+        };
+
+        let mut s = LimitedString::new();
+        let mut chunks = 0;
+        for_each_chunk(&mut bits, 1_000_000_000, &mut |chunk, is_top| {
+            chunks += 1;
+            if is_top {
+                write!(&mut s, "{}", chunk)
+            } else {
+                write!(&mut s, "{:09}", chunk)
+            }
+        })
+        .unwrap();
+        assert_eq!(chunks, 78);
+        assert_eq!(s.len, 694);
+        assert!(s.as_str().starts_with("37420536508921334323"));
+        assert!(s.as_str().ends_with("43212128456085078016"));
+    }
 }
 
 // ---------------------------------------------------------------------------------------
@@ -1557,6 +1930,171 @@ mod gcd_tests {
     }
 }
 
+//------------------------------------------
+// Least Common Multiple, Extended Euclidean Algorithm, and Coprimality
+use crate::{
+    consts::P1,
+    int::Z0,
+    private::{
+        PrivateExtGcd, PrivateExtGcdG, PrivateExtGcdX, PrivateExtGcdY, PrivateToInteger,
+        PrivateToIntegerOut,
+    },
+    Coprime, ExtGcd, IsCoprime, Lcm,
+};
+
+/// lcm(0, 0) = 0
+impl Lcm<U0> for U0 {
+    type Output = U0;
+}
+
+/// lcm(x, 0) = 0
+impl<X> Lcm<U0> for X
+where
+    X: Unsigned + NonZero,
+{
+    type Output = U0;
+}
+
+/// lcm(0, y) = 0
+impl<Y> Lcm<Y> for U0
+where
+    Y: Unsigned + NonZero,
+{
+    type Output = U0;
+}
+
+/// lcm(x, y) = x / gcd(x, y) * y
+impl<X, Y> Lcm<Y> for X
+where
+    X: Unsigned + NonZero + Gcd<Y> + Div<Gcf<X, Y>>,
+    Y: Unsigned + NonZero,
+    Quot<X, Gcf<X, Y>>: Mul<Y>,
+{
+    type Output = Prod<Quot<X, Gcf<X, Y>>, Y>;
+}
+
+impl<Y> ExtGcd<Y> for U0
+where
+    Y: PrivateExtGcd<U0>,
+{
+    type G = PrivateExtGcdG<Y, U0>;
+    type X = PrivateExtGcdX<Y, U0>;
+    type Y = PrivateExtGcdY<Y, U0>;
+}
+
+impl<U, B, Y> ExtGcd<Y> for UInt<U, B>
+where
+    Y: PrivateExtGcd<UInt<U, B>>,
+{
+    type G = PrivateExtGcdG<Y, UInt<U, B>>;
+    type X = PrivateExtGcdX<Y, UInt<U, B>>;
+    type Y = PrivateExtGcdY<Y, UInt<U, B>>;
+}
+
+/// Extended Euclid for (x, 0) is (x, 1, 0)
+impl<X> PrivateExtGcd<X> for U0
+where
+    X: Unsigned,
+{
+    type G = X;
+    type X = P1;
+    type Y = Z0;
+}
+
+/// If extended Euclid for (y, x mod y) is (g, s, t), then for (x, y) it is (g, t, s - (x / y) t)
+impl<X, Yu, Yb> PrivateExtGcd<X> for UInt<Yu, Yb>
+where
+    X: Unsigned + Div<UInt<Yu, Yb>> + Rem<UInt<Yu, Yb>>,
+    Yu: Unsigned,
+    Yb: Bit,
+    Mod<X, UInt<Yu, Yb>>: PrivateExtGcd<UInt<Yu, Yb>>,
+    Quot<X, UInt<Yu, Yb>>: PrivateToInteger,
+    PrivateToIntegerOut<Quot<X, UInt<Yu, Yb>>>:
+        Mul<PrivateExtGcdY<Mod<X, UInt<Yu, Yb>>, UInt<Yu, Yb>>>,
+    PrivateExtGcdX<Mod<X, UInt<Yu, Yb>>, UInt<Yu, Yb>>: Sub<
+        Prod<
+            PrivateToIntegerOut<Quot<X, UInt<Yu, Yb>>>,
+            PrivateExtGcdY<Mod<X, UInt<Yu, Yb>>, UInt<Yu, Yb>>,
+        >,
+    >,
+{
+    type G = PrivateExtGcdG<Mod<X, UInt<Yu, Yb>>, UInt<Yu, Yb>>;
+    type X = PrivateExtGcdY<Mod<X, UInt<Yu, Yb>>, UInt<Yu, Yb>>;
+    type Y = Diff<
+        PrivateExtGcdX<Mod<X, UInt<Yu, Yb>>, UInt<Yu, Yb>>,
+        Prod<
+            PrivateToIntegerOut<Quot<X, UInt<Yu, Yb>>>,
+            PrivateExtGcdY<Mod<X, UInt<Yu, Yb>>, UInt<Yu, Yb>>,
+        >,
+    >;
+}
+
+impl<X, Y> IsCoprime<Y> for X
+where
+    X: Unsigned + Gcd<Y>,
+    Gcf<X, Y>: IsEqual<U1>,
+{
+    type Output = Eq<Gcf<X, Y>, U1>;
+}
+
+impl<Y> Coprime<Y> for UTerm where UTerm: IsCoprime<Y, Output = B1> {}
+
+impl<U, B, Y> Coprime<Y> for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: IsCoprime<Y, Output = B1>,
+{
+}
+
+#[cfg(test)]
+mod lcm_tests {
+    use crate::consts::*;
+    use crate::{BezoutX, BezoutY, Bit, Coprimality, ExtGcd, Integer, Scm, Unsigned};
+
+    #[test]
+    fn lcm() {
+        assert_eq!(<Scm<U0, U0>>::to_u32(), 0);
+        assert_eq!(<Scm<U0, U42>>::to_u32(), 0);
+        assert_eq!(<Scm<U42, U0>>::to_u32(), 0);
+        assert_eq!(<Scm<U4, U6>>::to_u32(), 12);
+        assert_eq!(<Scm<U13, U1013>>::to_u32(), 13169);
+        assert_eq!(<Scm<U143, U273>>::to_u32(), 3003);
+    }
+
+    macro_rules! ext_gcd_test {
+        ($($a:ident, $b:ident => $g:expr, $x:expr, $y:expr;)*) => {
+            $(
+                assert_eq!(<<$a as ExtGcd<$b>>::G as Unsigned>::to_i64(), $g);
+                assert_eq!(<BezoutX<$a, $b>>::to_i64(), $x);
+                assert_eq!(<BezoutY<$a, $b>>::to_i64(), $y);
+            )*
+        };
+    }
+
+    #[test]
+    fn ext_gcd() {
+        ext_gcd_test! {
+            U0, U0 => 0, 1, 0;
+            U5, U0 => 5, 1, 0;
+            U0, U5 => 5, 0, 1;
+            U240, U46 => 2, -9, 47;
+            U46, U240 => 2, 47, -9;
+            U17, U5 => 1, -2, 7;
+            U1024, U768 => 256, 1, -1;
+            U35, U64 => 1, 11, -6;
+        }
+    }
+
+    #[test]
+    fn coprime() {
+        assert!(<Coprimality<U35, U64>>::to_bool());
+        assert!(<Coprimality<U1, U0>>::to_bool());
+        assert!(!<Coprimality<U0, U0>>::to_bool());
+        assert!(!<Coprimality<U12, U64>>::to_bool());
+    }
+}
+
 // -----------------------------------------
 // GetBit
 
@@ -1787,6 +2325,8 @@ mod div_tests {
 }
 // -----------------------------------------
 // Div
+#[cfg(feature = "diagnostics")]
+use crate::private::DivideByZero;
 use core::ops::Div;
 
 // 0 // N
@@ -1798,6 +2338,19 @@ impl<Ur: Unsigned, Br: Bit> Div<UInt<Ur, Br>> for UTerm {
     }
 }
 
+// N // 0, only so that it can fail with a clearer error
+#[cfg(feature = "diagnostics")]
+impl<Ul: Unsigned, Bl: Bit> Div<UTerm> for UInt<Ul, Bl>
+where
+    UInt<Ul, Bl>: DivideByZero,
+{
+    type Output = UTerm;
+    #[inline]
+    fn div(self, _: UTerm) -> Self::Output {
+        UTerm
+    }
+}
+
 // M // N
 impl<Ul: Unsigned, Bl: Bit, Ur: Unsigned, Br: Bit> Div<UInt<Ur, Br>> for UInt<Ul, Bl>
 where
@@ -1826,6 +2379,19 @@ impl<Ur: Unsigned, Br: Bit> Rem<UInt<Ur, Br>> for UTerm {
     }
 }
 
+// N % 0, only so that it can fail with a clearer error
+#[cfg(feature = "diagnostics")]
+impl<Ul: Unsigned, Bl: Bit> Rem<UTerm> for UInt<Ul, Bl>
+where
+    UInt<Ul, Bl>: DivideByZero,
+{
+    type Output = UTerm;
+    #[inline]
+    fn rem(self, _: UTerm) -> Self::Output {
+        UTerm
+    }
+}
+
 // M % N
 impl<Ul: Unsigned, Bl: Bit, Ur: Unsigned, Br: Bit> Rem<UInt<Ur, Br>> for UInt<Ul, Bl>
 where
@@ -2167,126 +2733,471 @@ where
 }
 
 // -----------------------------------------
-// PrivateMin
-use crate::private::{PrivateMin, PrivateMinOut};
+// DivFloor, DivCeil, DivEuclid, RemEuclid
+//
+// Unsigned division already rounds down, so only `DivCeil` differs from `Div`.
+use crate::{DivCeil, DivEuclid, DivFloor, Mod, RemEuclid};
 
-impl<U, B, Ur> PrivateMin<Ur, Equal> for UInt<U, B>
+impl<Ur: Unsigned> DivFloor<Ur> for UTerm
 where
-    Ur: Unsigned,
-    U: Unsigned,
-    B: Bit,
+    UTerm: Div<Ur>,
 {
-    type Output = UInt<U, B>;
+    type Output = Quot<UTerm, Ur>;
     #[inline]
-    fn private_min(self, _: Ur) -> Self::Output {
-        self
+    fn div_floor(self, rhs: Ur) -> Self::Output {
+        self / rhs
     }
 }
 
-impl<U, B, Ur> PrivateMin<Ur, Less> for UInt<U, B>
+impl<Ul: Unsigned, Bl: Bit, Ur: Unsigned> DivFloor<Ur> for UInt<Ul, Bl>
 where
-    Ur: Unsigned,
-    U: Unsigned,
-    B: Bit,
+    UInt<Ul, Bl>: Div<Ur>,
 {
-    type Output = UInt<U, B>;
+    type Output = Quot<UInt<Ul, Bl>, Ur>;
     #[inline]
-    fn private_min(self, _: Ur) -> Self::Output {
-        self
+    fn div_floor(self, rhs: Ur) -> Self::Output {
+        self / rhs
     }
 }
 
-impl<U, B, Ur> PrivateMin<Ur, Greater> for UInt<U, B>
+impl<Ur: Unsigned> DivEuclid<Ur> for UTerm
 where
-    Ur: Unsigned,
-    U: Unsigned,
-    B: Bit,
+    UTerm: Div<Ur>,
 {
-    type Output = Ur;
+    type Output = Quot<UTerm, Ur>;
     #[inline]
-    fn private_min(self, rhs: Ur) -> Self::Output {
-        rhs
+    fn div_euclid(self, rhs: Ur) -> Self::Output {
+        self / rhs
     }
 }
 
-// -----------------------------------------
-// Min
-use crate::Min;
-
-impl<U> Min<U> for UTerm
+impl<Ul: Unsigned, Bl: Bit, Ur: Unsigned> DivEuclid<Ur> for UInt<Ul, Bl>
 where
-    U: Unsigned,
+    UInt<Ul, Bl>: Div<Ur>,
 {
-    type Output = UTerm;
+    type Output = Quot<UInt<Ul, Bl>, Ur>;
     #[inline]
-    fn min(self, _: U) -> Self::Output {
-        self
+    fn div_euclid(self, rhs: Ur) -> Self::Output {
+        self / rhs
     }
 }
 
-impl<U, B, Ur> Min<Ur> for UInt<U, B>
+impl<Ur: Unsigned> RemEuclid<Ur> for UTerm
 where
-    U: Unsigned,
-    B: Bit,
-    Ur: Unsigned,
-    UInt<U, B>: Cmp<Ur> + PrivateMin<Ur, Compare<UInt<U, B>, Ur>>,
+    UTerm: Rem<Ur>,
 {
-    type Output = PrivateMinOut<UInt<U, B>, Ur, Compare<UInt<U, B>, Ur>>;
+    type Output = Mod<UTerm, Ur>;
     #[inline]
-    fn min(self, rhs: Ur) -> Self::Output {
-        self.private_min(rhs)
+    fn rem_euclid(self, rhs: Ur) -> Self::Output {
+        self % rhs
     }
 }
 
-// -----------------------------------------
-// PrivateMax
-use crate::private::{PrivateMax, PrivateMaxOut};
-
-impl<U, B, Ur> PrivateMax<Ur, Equal> for UInt<U, B>
+impl<Ul: Unsigned, Bl: Bit, Ur: Unsigned> RemEuclid<Ur> for UInt<Ul, Bl>
 where
-    Ur: Unsigned,
-    U: Unsigned,
-    B: Bit,
+    UInt<Ul, Bl>: Rem<Ur>,
 {
-    type Output = UInt<U, B>;
+    type Output = Mod<UInt<Ul, Bl>, Ur>;
     #[inline]
-    fn private_max(self, _: Ur) -> Self::Output {
-        self
+    fn rem_euclid(self, rhs: Ur) -> Self::Output {
+        self % rhs
     }
 }
 
-impl<U, B, Ur> PrivateMax<Ur, Less> for UInt<U, B>
-where
-    Ur: Unsigned,
-    U: Unsigned,
-    B: Bit,
-{
-    type Output = Ur;
+/// `ceil(0 / Ur) = 0` where `Ur != 0`
+impl<Ur: Unsigned, Br: Bit> DivCeil<UInt<Ur, Br>> for UTerm {
+    type Output = UTerm;
     #[inline]
-    fn private_max(self, rhs: Ur) -> Self::Output {
-        rhs
+    fn div_ceil(self, _: UInt<Ur, Br>) -> Self::Output {
+        UTerm
     }
 }
 
-impl<U, B, Ur> PrivateMax<Ur, Greater> for UInt<U, B>
+/// `ceil(Ul / Ur) = (Ul + Ur - 1) / Ur`
+impl<Ul: Unsigned, Bl: Bit, Ur: Unsigned, Br: Bit> DivCeil<UInt<Ur, Br>> for UInt<Ul, Bl>
 where
-    Ur: Unsigned,
-    U: Unsigned,
-    B: Bit,
+    UInt<Ur, Br>: Sub<B1>,
+    UInt<Ul, Bl>: Add<Sub1<UInt<Ur, Br>>>,
+    Sum<UInt<Ul, Bl>, Sub1<UInt<Ur, Br>>>: Div<UInt<Ur, Br>>,
 {
-    type Output = UInt<U, B>;
+    type Output = Quot<Sum<UInt<Ul, Bl>, Sub1<UInt<Ur, Br>>>, UInt<Ur, Br>>;
     #[inline]
-    fn private_max(self, _: Ur) -> Self::Output {
-        self
+    fn div_ceil(self, rhs: UInt<Ur, Br>) -> Self::Output {
+        (self + (rhs - B1)) / rhs
     }
 }
 
 // -----------------------------------------
-// Max
-use crate::Max;
+// FitsIn
+use crate::{consts, FitsIn, IsLessOrEqual, True};
+
+macro_rules! impl_fits_in {
+    ($($(#[$attr:meta])* $T:ty: $Bits:ty;)*) => {
+        $(
+            $(#[$attr])*
+            impl FitsIn<$T> for UTerm {}
+
+            $(#[$attr])*
+            impl<U: Unsigned, B: Bit> FitsIn<$T> for UInt<U, B>
+            where
+                UInt<U, B>: Len,
+                Length<UInt<U, B>>: IsLessOrEqual<$Bits, Output = True>,
+            {
+            }
+        )*
+    };
+}
 
-impl<U> Max<U> for UTerm
-where
+impl_fits_in! {
+    u8: consts::U8;
+    u16: consts::U16;
+    u32: consts::U32;
+    u64: consts::U64;
+    #[cfg(feature = "i128")]
+    u128: consts::U128;
+    #[cfg(target_pointer_width = "16")]
+    usize: consts::U16;
+    #[cfg(target_pointer_width = "32")]
+    usize: consts::U32;
+    #[cfg(target_pointer_width = "64")]
+    usize: consts::U64;
+    i8: consts::U7;
+    i16: consts::U15;
+    i32: consts::U31;
+    i64: consts::U63;
+    #[cfg(feature = "i128")]
+    i128: consts::U127;
+    #[cfg(target_pointer_width = "16")]
+    isize: consts::U15;
+    #[cfg(target_pointer_width = "32")]
+    isize: consts::U31;
+    #[cfg(target_pointer_width = "64")]
+    isize: consts::U63;
+}
+
+// -----------------------------------------
+// CheckedAdd
+use crate::{And, CheckedAdd, WrappingAdd};
+
+impl<Ul, Ur, W> CheckedAdd<Ur, W> for Ul
+where
+    Ul: Unsigned + Add<Ur>,
+    Ur: Unsigned,
+    Sum<Ul, Ur>: Len,
+    Length<Sum<Ul, Ur>>: IsLessOrEqual<W, Output = True>,
+{
+    type Output = Sum<Ul, Ur>;
+    #[inline]
+    fn checked_add(self, rhs: Ur) -> Self::Output {
+        self + rhs
+    }
+}
+
+// -----------------------------------------
+// WrappingAdd
+
+/// `(Ul + Ur) mod 2^W`, computed by masking off all bits from bit `W` up
+impl<Ul, Ur, W> WrappingAdd<Ur, W> for Ul
+where
+    Ul: Unsigned + Add<Ur>,
+    Ur: Unsigned,
+    W: Unsigned,
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<B1>,
+    Sum<Ul, Ur>: BitAnd<Sub1<Shleft<U1, W>>>,
+{
+    type Output = And<Sum<Ul, Ur>, Sub1<Shleft<U1, W>>>;
+    #[inline]
+    fn wrapping_add(self, rhs: Ur) -> Self::Output {
+        (self + rhs) & (<U1 as Shl<W>>::shl(U1::new(), W::default()) - B1)
+    }
+}
+
+// -----------------------------------------
+// SaturatingSub
+use crate::{
+    private::{PrivateSaturatingSub, PrivateSaturatingSubOut},
+    SaturatingSub,
+};
+
+impl<Ul, Ur> SaturatingSub<Ur> for Ul
+where
+    Ul: Unsigned + Cmp<Ur> + PrivateSaturatingSub<Ur, Compare<Ul, Ur>>,
+    Ur: Unsigned,
+{
+    type Output = PrivateSaturatingSubOut<Ul, Ur, Compare<Ul, Ur>>;
+    #[inline]
+    fn saturating_sub(self, rhs: Ur) -> Self::Output {
+        let lhs_cmp_rhs = self.compare::<Internal>(&rhs);
+        self.private_saturating_sub(rhs, lhs_cmp_rhs)
+    }
+}
+
+impl<Ul, Ur> PrivateSaturatingSub<Ur, Less> for Ul {
+    type Output = U0;
+    #[inline]
+    fn private_saturating_sub(self, _: Ur, _: Less) -> Self::Output {
+        UTerm
+    }
+}
+
+impl<Ul, Ur> PrivateSaturatingSub<Ur, Equal> for Ul {
+    type Output = U0;
+    #[inline]
+    fn private_saturating_sub(self, _: Ur, _: Equal) -> Self::Output {
+        UTerm
+    }
+}
+
+impl<Ul, Ur> PrivateSaturatingSub<Ur, Greater> for Ul
+where
+    Ul: Sub<Ur>,
+{
+    type Output = Diff<Ul, Ur>;
+    #[inline]
+    fn private_saturating_sub(self, rhs: Ur, _: Greater) -> Self::Output {
+        self - rhs
+    }
+}
+
+// -----------------------------------------
+// Modular arithmetic
+use crate::{
+    private::{PrivateModInverse, PrivateModInverseOut},
+    AddMod, ExpMod, If, IfThenElse, ModInverse, MulMod, PowMod, ProdMod,
+};
+
+/// `(a + b) mod m`
+impl<A, B, M> AddMod<B, M> for A
+where
+    A: Unsigned + Add<B>,
+    Sum<A, B>: Rem<M>,
+{
+    type Output = Mod<Sum<A, B>, M>;
+}
+
+/// `(a * b) mod m`
+impl<A, B, M> MulMod<B, M> for A
+where
+    A: Unsigned + Mul<B>,
+    Prod<A, B>: Rem<M>,
+{
+    type Output = Mod<Prod<A, B>, M>;
+}
+
+/// `a^0 mod m = 1 mod m`
+impl<A, M> PowMod<U0, M> for A
+where
+    A: Unsigned,
+    U1: Rem<M>,
+{
+    type Output = Mod<U1, M>;
+}
+
+/// `a^(2e + b) mod m = (a^e mod m)^2 * a^b mod m`
+impl<A, E, Eb, M> PowMod<UInt<E, Eb>, M> for A
+where
+    A: Unsigned + PowMod<E, M>,
+    Eb: Bit + IfThenElse<A, U1>,
+    ExpMod<A, E, M>: MulMod<ExpMod<A, E, M>, M>,
+    ProdMod<ExpMod<A, E, M>, ExpMod<A, E, M>, M>: MulMod<If<Eb, A, U1>, M>,
+{
+    type Output = ProdMod<ProdMod<ExpMod<A, E, M>, ExpMod<A, E, M>, M>, If<Eb, A, U1>, M>;
+}
+
+/// `a^-1 mod m`, starting the extended Euclidean algorithm with the remainders `m` and
+/// `a mod m`, whose coefficients are `0` and `1`
+impl<A, M> ModInverse<M> for A
+where
+    A: Unsigned + Rem<M>,
+    Mod<A, M>: PrivateModInverse<M, M, U0, U1>,
+{
+    type Output = PrivateModInverseOut<Mod<A, M>, M, M, U0, U1>;
+}
+
+/// The remainder is zero, so the previous one is the greatest common divisor, which must be one.
+/// Its coefficient is the inverse.
+impl<M, T0, T1> PrivateModInverse<M, U1, T0, T1> for U0 {
+    type Output = T0;
+}
+
+/// With `q = r0 / r1`, the next remainder is `r0 - q * r1 = r0 mod r1` and its coefficient is
+/// `t0 - q * t1`, which we keep in `0..m` by adding `m` before subtracting.
+impl<M, R0, T0, T1, U, B> PrivateModInverse<M, R0, T0, T1> for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    R0: Div<UInt<U, B>> + Rem<UInt<U, B>>,
+    Quot<R0, UInt<U, B>>: Mul<T1>,
+    Prod<Quot<R0, UInt<U, B>>, T1>: Rem<M>,
+    T0: Add<M>,
+    Sum<T0, M>: Sub<Mod<Prod<Quot<R0, UInt<U, B>>, T1>, M>>,
+    Diff<Sum<T0, M>, Mod<Prod<Quot<R0, UInt<U, B>>, T1>, M>>: Rem<M>,
+    Mod<R0, UInt<U, B>>: PrivateModInverse<
+        M,
+        UInt<U, B>,
+        T1,
+        Mod<Diff<Sum<T0, M>, Mod<Prod<Quot<R0, UInt<U, B>>, T1>, M>>, M>,
+    >,
+{
+    type Output = PrivateModInverseOut<
+        Mod<R0, UInt<U, B>>,
+        M,
+        UInt<U, B>,
+        T1,
+        Mod<Diff<Sum<T0, M>, Mod<Prod<Quot<R0, UInt<U, B>>, T1>, M>>, M>,
+    >;
+}
+
+#[cfg(test)]
+mod mod_tests {
+    use crate::consts::*;
+    use crate::{ExpMod, InvMod, ProdMod, SumMod, Unsigned};
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(<SumMod<U5, U4, U7>>::to_u32(), 2);
+        assert_eq!(<SumMod<U0, U0, U1>>::to_u32(), 0);
+        assert_eq!(<ProdMod<U1000, U1000, U999>>::to_u32(), 1);
+        assert_eq!(<ProdMod<U123, U0, U10>>::to_u32(), 0);
+
+        assert_eq!(<ExpMod<U3, U200, U1000>>::to_u32(), 1);
+        assert_eq!(<ExpMod<U10, U18, U1009>>::to_u32(), 707);
+        assert_eq!(<ExpMod<U7, U1000, U1023>>::to_u32(), 397);
+        assert_eq!(<ExpMod<U5, U0, U7>>::to_u32(), 1);
+        assert_eq!(<ExpMod<U5, U0, U1>>::to_u32(), 0);
+        assert_eq!(<ExpMod<U0, U5, U7>>::to_u32(), 0);
+
+        assert_eq!(<InvMod<U3, U7>>::to_u32(), 5);
+        assert_eq!(<InvMod<U7, U1024>>::to_u32(), 439);
+        assert_eq!(<InvMod<U123, U1000>>::to_u32(), 187);
+        assert_eq!(<InvMod<U1, U2>>::to_u32(), 1);
+        assert_eq!(<InvMod<U5, U1>>::to_u32(), 0);
+        assert_eq!(<InvMod<U1001, U1000>>::to_u32(), 1);
+    }
+}
+
+// -----------------------------------------
+// PrivateMin
+use crate::private::{PrivateMin, PrivateMinOut};
+
+impl<U, B, Ur> PrivateMin<Ur, Equal> for UInt<U, B>
+where
+    Ur: Unsigned,
+    U: Unsigned,
+    B: Bit,
+{
+    type Output = UInt<U, B>;
+    #[inline]
+    fn private_min(self, _: Ur) -> Self::Output {
+        self
+    }
+}
+
+impl<U, B, Ur> PrivateMin<Ur, Less> for UInt<U, B>
+where
+    Ur: Unsigned,
+    U: Unsigned,
+    B: Bit,
+{
+    type Output = UInt<U, B>;
+    #[inline]
+    fn private_min(self, _: Ur) -> Self::Output {
+        self
+    }
+}
+
+impl<U, B, Ur> PrivateMin<Ur, Greater> for UInt<U, B>
+where
+    Ur: Unsigned,
+    U: Unsigned,
+    B: Bit,
+{
+    type Output = Ur;
+    #[inline]
+    fn private_min(self, rhs: Ur) -> Self::Output {
+        rhs
+    }
+}
+
+// -----------------------------------------
+// Min
+use crate::Min;
+
+impl<U> Min<U> for UTerm
+where
+    U: Unsigned,
+{
+    type Output = UTerm;
+    #[inline]
+    fn min(self, _: U) -> Self::Output {
+        self
+    }
+}
+
+impl<U, B, Ur> Min<Ur> for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    Ur: Unsigned,
+    UInt<U, B>: Cmp<Ur> + PrivateMin<Ur, Compare<UInt<U, B>, Ur>>,
+{
+    type Output = PrivateMinOut<UInt<U, B>, Ur, Compare<UInt<U, B>, Ur>>;
+    #[inline]
+    fn min(self, rhs: Ur) -> Self::Output {
+        self.private_min(rhs)
+    }
+}
+
+// -----------------------------------------
+// PrivateMax
+use crate::private::{PrivateMax, PrivateMaxOut};
+
+impl<U, B, Ur> PrivateMax<Ur, Equal> for UInt<U, B>
+where
+    Ur: Unsigned,
+    U: Unsigned,
+    B: Bit,
+{
+    type Output = UInt<U, B>;
+    #[inline]
+    fn private_max(self, _: Ur) -> Self::Output {
+        self
+    }
+}
+
+impl<U, B, Ur> PrivateMax<Ur, Less> for UInt<U, B>
+where
+    Ur: Unsigned,
+    U: Unsigned,
+    B: Bit,
+{
+    type Output = Ur;
+    #[inline]
+    fn private_max(self, rhs: Ur) -> Self::Output {
+        rhs
+    }
+}
+
+impl<U, B, Ur> PrivateMax<Ur, Greater> for UInt<U, B>
+where
+    Ur: Unsigned,
+    U: Unsigned,
+    B: Bit,
+{
+    type Output = UInt<U, B>;
+    #[inline]
+    fn private_max(self, _: Ur) -> Self::Output {
+        self
+    }
+}
+
+// -----------------------------------------
+// Max
+use crate::Max;
+
+impl<U> Max<U> for UTerm
+where
     U: Unsigned,
 {
     type Output = U;
@@ -2356,6 +3267,75 @@ where
     type Output = Sum<Double<Sqrt<U>>, GrEq<Self, Square<Add1<Double<Sqrt<U>>>>>>;
 }
 
+// -----------------------------------------
+// NthRoot
+use crate::{
+    private::{PrivateNthRoot, PrivateNthRootOut},
+    CubeRoot, Exp, NthRoot, Root,
+};
+
+impl<N, K> NthRoot<K> for N
+where
+    N: Unsigned + PrivateNthRoot<K>,
+    K: Unsigned + NonZero,
+{
+    type Output = PrivateNthRootOut<N, K>;
+}
+
+impl<K> PrivateNthRoot<K> for UTerm {
+    type Output = UTerm;
+}
+
+// As for the square root, if Self = 2^k * u + v with v < 2^k, then the root of Self is either
+// 2*root(u) or 2*root(u)+1. We guess and check by comparing (2*root(u)+1)^k against Self.
+impl<U, B, K> PrivateNthRoot<K> for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: Shr<K>,
+    Shright<UInt<U, B>, K>: PrivateNthRoot<K>,
+    PrivateNthRootOut<Shright<UInt<U, B>, K>, K>: Shl<B1>,
+    Double<PrivateNthRootOut<Shright<UInt<U, B>, K>, K>>: Add<B1>,
+    Add1<Double<PrivateNthRootOut<Shright<UInt<U, B>, K>, K>>>: Pow<K>,
+    Self: IsGreaterOrEqual<Exp<Add1<Double<PrivateNthRootOut<Shright<UInt<U, B>, K>, K>>>, K>>,
+    Double<PrivateNthRootOut<Shright<UInt<U, B>, K>, K>>:
+        Add<GrEq<Self, Exp<Add1<Double<PrivateNthRootOut<Shright<UInt<U, B>, K>, K>>>, K>>>,
+{
+    type Output = Sum<
+        Double<PrivateNthRootOut<Shright<UInt<U, B>, K>, K>>,
+        GrEq<Self, Exp<Add1<Double<PrivateNthRootOut<Shright<UInt<U, B>, K>, K>>>, K>>,
+    >;
+}
+
+impl<N> CubeRoot for N
+where
+    N: NthRoot<U3>,
+{
+    type Output = Root<N, U3>;
+}
+
+#[test]
+fn root_test() {
+    use crate::consts::*;
+    use crate::Cbrt;
+
+    assert_eq!(0, <Cbrt<U0>>::to_u32());
+    assert_eq!(1, <Cbrt<U1>>::to_u32());
+    assert_eq!(1, <Cbrt<U7>>::to_u32());
+    assert_eq!(2, <Cbrt<U8>>::to_u32());
+    assert_eq!(4, <Cbrt<U124>>::to_u32());
+    assert_eq!(5, <Cbrt<U125>>::to_u32());
+    assert_eq!(99, <Cbrt<Sub1<U1000000>>>::to_u32());
+    assert_eq!(100, <Cbrt<U1000000>>::to_u32());
+
+    assert_eq!(1000, <Root<U1000, U1>>::to_u32());
+    assert_eq!(31, <Root<U1000, U2>>::to_u32());
+    assert_eq!(<Sqrt<U1000>>::to_u32(), <Root<U1000, U2>>::to_u32());
+    assert_eq!(1, <Root<U1023, U10>>::to_u32());
+    assert_eq!(2, <Root<U1024, U10>>::to_u32());
+    assert_eq!(0, <Root<U0, U5>>::to_u32());
+}
+
 #[test]
 fn sqrt_test() {
     use crate::consts::*;
@@ -2420,6 +3400,474 @@ where
     type Output = Add1<Log2<U>>;
 }
 
+// -----------------------------------------
+// Logarithm
+use crate::{
+    private::{PrivateLogarithm, PrivateLogarithmIf, PrivateLogarithmIfOut, PrivateLogarithmOut},
+    IsLess, Le, Logarithm,
+};
+
+// Bases of at least two.
+impl<N, Bu, Ba, Bb> Logarithm<UInt<UInt<Bu, Ba>, Bb>> for N
+where
+    N: Unsigned + NonZero + PrivateLogarithm<UInt<UInt<Bu, Ba>, Bb>>,
+    Bu: Unsigned,
+    Ba: Bit,
+    Bb: Bit,
+{
+    type Output = PrivateLogarithmOut<N, UInt<UInt<Bu, Ba>, Bb>>;
+}
+
+impl<N, B> PrivateLogarithm<B> for N
+where
+    N: IsLess<B> + PrivateLogarithmIf<B, Le<N, B>>,
+{
+    type Output = PrivateLogarithmIfOut<N, B, Le<N, B>>;
+}
+
+// log(n) = 0 if n < b.
+impl<N, B> PrivateLogarithmIf<B, B1> for N {
+    type Output = U0;
+}
+
+// log(n) = log(n / b) + 1 if n >= b.
+impl<N, B> PrivateLogarithmIf<B, B0> for N
+where
+    N: Div<B>,
+    Quot<N, B>: PrivateLogarithm<B>,
+    PrivateLogarithmOut<Quot<N, B>, B>: Add<B1>,
+{
+    type Output = Add1<PrivateLogarithmOut<Quot<N, B>, B>>;
+}
+
+// -----------------------------------------
+// CeilLogarithm2
+use crate::CeilLogarithm2;
+
+// ceil(log2(n)) is the number of bits of n - 1.
+impl<U, B> CeilLogarithm2 for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Len,
+{
+    type Output = Length<Sub1<UInt<U, B>>>;
+}
+
+#[test]
+fn log_test() {
+    use crate::consts::*;
+    use crate::{CeilLog2, Log, Log10};
+
+    assert_eq!(0, <Log10<U1>>::to_u32());
+    assert_eq!(0, <Log10<U9>>::to_u32());
+    assert_eq!(1, <Log10<U10>>::to_u32());
+    assert_eq!(1, <Log10<U99>>::to_u32());
+    assert_eq!(2, <Log10<U100>>::to_u32());
+    assert_eq!(9, <Log10<U1000000000>>::to_u32());
+
+    assert_eq!(0, <Log<U1, U2>>::to_u32());
+    assert_eq!(3, <Log<U15, U2>>::to_u32());
+    assert_eq!(4, <Log<U16, U2>>::to_u32());
+    assert_eq!(1, <Log<U1024, U1024>>::to_u32());
+    assert_eq!(0, <Log<U1023, U1024>>::to_u32());
+    assert_eq!(2, <Log<U342, U7>>::to_u32());
+    assert_eq!(3, <Log<U343, U7>>::to_u32());
+
+    assert_eq!(0, <CeilLog2<U1>>::to_u32());
+    assert_eq!(1, <CeilLog2<U2>>::to_u32());
+    assert_eq!(2, <CeilLog2<U3>>::to_u32());
+    assert_eq!(2, <CeilLog2<U4>>::to_u32());
+    assert_eq!(3, <CeilLog2<U5>>::to_u32());
+    assert_eq!(10, <CeilLog2<U1000>>::to_u32());
+}
+
+// -----------------------------------------
+// Primes
+use crate::{
+    consts::{U2, U3, U4},
+    private::{
+        PrivateFactorize, PrivateFactorizeOut, PrivateSmallestFactor, PrivateSmallestFactorIf,
+        PrivateSmallestFactorIfOut, PrivateSmallestFactorOut, PrivateTrialDivision,
+        PrivateTrialDivisionOut,
+    },
+    ATerm, Eq, Factorize, Gr, IsEqual, IsGreater, IsPrime, LeastFactor, Prime, SmallestFactor,
+    TArr,
+};
+
+impl IsPrime for UTerm {
+    type Output = B0;
+}
+
+impl IsPrime for UInt<UTerm, B1> {
+    type Output = B0;
+}
+
+/// A number greater than one is prime when it is its own smallest factor.
+impl<U: Unsigned, B: Bit, Bb: Bit> IsPrime for UInt<UInt<U, B>, Bb>
+where
+    UInt<UInt<U, B>, Bb>: SmallestFactor,
+    LeastFactor<UInt<UInt<U, B>, Bb>>: IsEqual<UInt<UInt<U, B>, Bb>>,
+{
+    type Output = Eq<LeastFactor<UInt<UInt<U, B>, Bb>>, UInt<UInt<U, B>, Bb>>;
+}
+
+impl<U: Unsigned, B: Bit> Prime for UInt<U, B> where UInt<U, B>: IsPrime<Output = B1> {}
+
+impl<U: Unsigned, B: Bit, Bb: Bit> SmallestFactor for UInt<UInt<U, B>, Bb>
+where
+    UInt<UInt<U, B>, Bb>: PrivateSmallestFactor<U2>,
+{
+    type Output = PrivateSmallestFactorOut<UInt<UInt<U, B>, Bb>, U2>;
+}
+
+// Factors greater than the square root need not be tried, as they come with one below it.
+// `D mod 3` is worked out once here and then kept up to date, to skip the multiples of three.
+impl<N, D> PrivateSmallestFactor<D> for N
+where
+    D: Rem<U3>,
+    N: SquareRoot + PrivateTrialDivision<D, Sqrt<N>, Mod<D, U3>>,
+{
+    type Output = PrivateTrialDivisionOut<N, D, Sqrt<N>, Mod<D, U3>>;
+}
+
+impl<N, D, L, M> PrivateTrialDivision<D, L, M> for N
+where
+    N: Rem<D>,
+    D: IsGreater<L>,
+    N: PrivateSmallestFactorIf<D, L, M, Gr<D, L>, Mod<N, D>>,
+{
+    type Output = PrivateSmallestFactorIfOut<N, D, L, M, Gr<D, L>, Mod<N, D>>;
+}
+
+// Every divisor up to the square root has been tried, so `N` is prime.
+impl<N, D, L, M, R> PrivateSmallestFactorIf<D, L, M, B1, R> for N {
+    type Output = N;
+}
+
+// `D` divides `N`.
+impl<N, D, L, M> PrivateSmallestFactorIf<D, L, M, B0, U0> for N {
+    type Output = D;
+}
+
+// After 2 and 3, only the numbers `6k - 1` and `6k + 1` need to be tried, as the rest are
+// multiples of 2 or 3. They are 2 and 4 apart in turn, which `D mod 3` tells apart.
+impl<N, L, M, U: Unsigned, B: Bit> PrivateSmallestFactorIf<U2, L, M, B0, UInt<U, B>> for N
+where
+    N: PrivateTrialDivision<U3, L, U0>,
+{
+    type Output = PrivateTrialDivisionOut<N, U3, L, U0>;
+}
+
+// 3 is followed by 5.
+impl<N, L, Du, U: Unsigned, B: Bit> PrivateSmallestFactorIf<UInt<Du, B1>, L, U0, B0, UInt<U, B>>
+    for N
+where
+    UInt<Du, B1>: Add<U2>,
+    N: PrivateTrialDivision<Sum<UInt<Du, B1>, U2>, L, U2>,
+{
+    type Output = PrivateTrialDivisionOut<N, Sum<UInt<Du, B1>, U2>, L, U2>;
+}
+
+// `6k - 1` is followed by `6k + 1`.
+impl<N, L, Du, U: Unsigned, B: Bit> PrivateSmallestFactorIf<UInt<Du, B1>, L, U2, B0, UInt<U, B>>
+    for N
+where
+    UInt<Du, B1>: Add<U2>,
+    N: PrivateTrialDivision<Sum<UInt<Du, B1>, U2>, L, U1>,
+{
+    type Output = PrivateTrialDivisionOut<N, Sum<UInt<Du, B1>, U2>, L, U1>;
+}
+
+// `6k + 1` is followed by `6k + 5`.
+impl<N, L, Du, U: Unsigned, B: Bit> PrivateSmallestFactorIf<UInt<Du, B1>, L, U1, B0, UInt<U, B>>
+    for N
+where
+    UInt<Du, B1>: Add<U4>,
+    N: PrivateTrialDivision<Sum<UInt<Du, B1>, U4>, L, U2>,
+{
+    type Output = PrivateTrialDivisionOut<N, Sum<UInt<Du, B1>, U4>, L, U2>;
+}
+
+impl<N> Factorize for N
+where
+    N: PrivateFactorize<U2>,
+{
+    type Output = PrivateFactorizeOut<N, U2>;
+}
+
+impl<D> PrivateFactorize<D> for UInt<UTerm, B1> {
+    type Output = ATerm;
+}
+
+// The smallest factor is at least `D`, as the smaller ones have been divided out already.
+impl<D, U: Unsigned, B: Bit, Bb: Bit> PrivateFactorize<D> for UInt<UInt<U, B>, Bb>
+where
+    UInt<UInt<U, B>, Bb>: PrivateSmallestFactor<D>,
+    UInt<UInt<U, B>, Bb>: Div<PrivateSmallestFactorOut<UInt<UInt<U, B>, Bb>, D>>,
+    Quot<UInt<UInt<U, B>, Bb>, PrivateSmallestFactorOut<UInt<UInt<U, B>, Bb>, D>>:
+        PrivateFactorize<PrivateSmallestFactorOut<UInt<UInt<U, B>, Bb>, D>>,
+{
+    type Output = TArr<
+        PrivateSmallestFactorOut<UInt<UInt<U, B>, Bb>, D>,
+        PrivateFactorizeOut<
+            Quot<UInt<UInt<U, B>, Bb>, PrivateSmallestFactorOut<UInt<UInt<U, B>, Bb>, D>>,
+            PrivateSmallestFactorOut<UInt<UInt<U, B>, Bb>, D>,
+        >,
+    >;
+}
+
+#[cfg(test)]
+mod prime_tests {
+    use crate::consts::*;
+    use crate::{assert_type_eq, tarr, ATerm, Bit, Factors, LeastFactor, Primality, Unsigned};
+
+    fn prime<P: crate::Prime>() {}
+
+    #[test]
+    fn primality() {
+        let primes = [
+            2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83,
+            89, 97,
+        ];
+        macro_rules! check {
+            ($($n:ident = $v:expr),*) => {
+                $(assert_eq!(<Primality<$n>>::to_bool(), primes.contains(&$v), "{}", $v);)*
+            };
+        }
+        check!(
+            U0 = 0,
+            U1 = 1,
+            U2 = 2,
+            U3 = 3,
+            U4 = 4,
+            U5 = 5,
+            U6 = 6,
+            U7 = 7,
+            U8 = 8,
+            U9 = 9,
+            U15 = 15,
+            U25 = 25,
+            U49 = 49,
+            U89 = 89,
+            U91 = 91,
+            U97 = 97,
+            U99 = 99
+        );
+
+        prime::<U2>();
+        prime::<U1009>();
+        assert!(!<Primality<U1001>>::to_bool());
+    }
+
+    #[test]
+    fn smallest_factor() {
+        assert_eq!(<LeastFactor<U2>>::to_u32(), 2);
+        assert_eq!(<LeastFactor<U1024>>::to_u32(), 2);
+        assert_eq!(<LeastFactor<U999>>::to_u32(), 3);
+        assert_eq!(<LeastFactor<U1001>>::to_u32(), 7);
+        assert_eq!(<LeastFactor<U961>>::to_u32(), 31);
+    }
+
+    #[test]
+    fn factorize() {
+        assert_type_eq!(Factors<U1>, ATerm);
+        assert_type_eq!(Factors<U2>, tarr![U2]);
+        assert_type_eq!(
+            Factors<U1024>,
+            tarr![U2, U2, U2, U2, U2, U2, U2, U2, U2, U2]
+        );
+        assert_type_eq!(Factors<U1001>, tarr![U7, U11, U13]);
+        assert_type_eq!(Factors<U961>, tarr![U31, U31]);
+        assert_type_eq!(Factors<U1000>, tarr![U2, U2, U2, U5, U5, U5]);
+    }
+}
+
+// -----------------------------------------
+// Powers of two and bit counting
+use crate::{
+    private::{
+        PrivateLenPowerOfTwo, PrivateLenPowerOfTwoOut, PrivateReverseBits, PrivateReverseBitsOut,
+    },
+    CountOnes, CountZeros, IsPow2, IsPowerOfTwo, LeadingZeros, NextPowerOfTwo, Ones,
+    PrevPowerOfTwo, ReverseBits, TrailZeros, TrailingZeros,
+};
+
+impl IsPowerOfTwo for UTerm {
+    type Output = B0;
+}
+
+impl IsPowerOfTwo for UInt<UTerm, B1> {
+    type Output = B1;
+}
+
+// Odd numbers greater than one.
+impl<U: Unsigned, B: Bit> IsPowerOfTwo for UInt<UInt<U, B>, B1> {
+    type Output = B0;
+}
+
+impl<U: Unsigned + IsPowerOfTwo> IsPowerOfTwo for UInt<U, B0> {
+    type Output = IsPow2<U>;
+}
+
+impl PrivateLenPowerOfTwo for UTerm {
+    type Output = U1;
+}
+
+impl<U: Unsigned + PrivateLenPowerOfTwo, B: Bit> PrivateLenPowerOfTwo for UInt<U, B> {
+    type Output = UInt<PrivateLenPowerOfTwoOut<U>, B0>;
+}
+
+// A power of two is its own next power of two, and otherwise it is one bit longer.
+impl<N> NextPowerOfTwo for N
+where
+    N: Unsigned + IsPowerOfTwo + PrivateLenPowerOfTwo,
+    IsPow2<N>: IfThenElse<N, PrivateLenPowerOfTwoOut<N>>,
+{
+    type Output = If<IsPow2<N>, N, PrivateLenPowerOfTwoOut<N>>;
+}
+
+// The previous power of two has the same length, which is one bit longer than `U`.
+impl<U: Unsigned + PrivateLenPowerOfTwo, B: Bit> PrevPowerOfTwo for UInt<U, B> {
+    type Output = PrivateLenPowerOfTwoOut<U>;
+}
+
+impl CountOnes for UTerm {
+    type Output = U0;
+}
+
+impl<U, B> CountOnes for UInt<U, B>
+where
+    U: Unsigned + CountOnes,
+    B: Bit,
+    Ones<U>: Add<B>,
+{
+    type Output = Sum<Ones<U>, B>;
+}
+
+impl<N, W> CountZeros<W> for N
+where
+    N: Unsigned + Len + CountOnes,
+    W: Unsigned + Sub<Length<N>> + Sub<Ones<N>>,
+{
+    type Output = Diff<W, Ones<N>>;
+}
+
+impl<U: Unsigned> TrailingZeros for UInt<U, B1> {
+    type Output = U0;
+}
+
+impl<U> TrailingZeros for UInt<U, B0>
+where
+    U: Unsigned + TrailingZeros,
+    TrailZeros<U>: Add<B1>,
+{
+    type Output = Add1<TrailZeros<U>>;
+}
+
+impl<N, W> LeadingZeros<W> for N
+where
+    N: Unsigned + Len,
+    W: Unsigned + Sub<Length<N>>,
+{
+    type Output = Diff<W, Length<N>>;
+}
+
+impl<N, W> ReverseBits<W> for N
+where
+    N: Unsigned + PrivateReverseBits<W, U0>,
+{
+    type Output = PrivateReverseBitsOut<N, W, U0>;
+}
+
+// Every bit of `Self` has been moved.
+impl<Acc> PrivateReverseBits<U0, Acc> for UTerm {
+    type Output = Acc;
+}
+
+// The remaining high bits are zeros.
+impl<Wu, Wb, Acc> PrivateReverseBits<UInt<Wu, Wb>, Acc> for UTerm
+where
+    UInt<Wu, Wb>: Sub<B1>,
+    Acc: Shl<B1>,
+    UTerm: PrivateReverseBits<Sub1<UInt<Wu, Wb>>, Double<Acc>>,
+{
+    type Output = PrivateReverseBitsOut<UTerm, Sub1<UInt<Wu, Wb>>, Double<Acc>>;
+}
+
+impl<U, B, Wu, Wb, Acc> PrivateReverseBits<UInt<Wu, Wb>, Acc> for UInt<U, B>
+where
+    UInt<Wu, Wb>: Sub<B1>,
+    Acc: Shl<B1>,
+    Double<Acc>: Add<B>,
+    U: PrivateReverseBits<Sub1<UInt<Wu, Wb>>, Sum<Double<Acc>, B>>,
+{
+    type Output = PrivateReverseBitsOut<U, Sub1<UInt<Wu, Wb>>, Sum<Double<Acc>, B>>;
+}
+
+#[cfg(test)]
+mod bit_count_tests {
+    use crate::consts::*;
+    use crate::{
+        BitsReversed, IsPow2, LeadZeros, NextPow2, Ones, PrevPow2, TrailZeros, Unsigned, Zeros,
+    };
+
+    #[test]
+    fn powers_of_two() {
+        use crate::Bit;
+
+        assert!(!<IsPow2<U0>>::to_bool());
+        assert!(<IsPow2<U1>>::to_bool());
+        assert!(<IsPow2<U1024>>::to_bool());
+        assert!(!<IsPow2<U1023>>::to_bool());
+
+        assert_eq!(<NextPow2<U0>>::to_u32(), 1);
+        assert_eq!(<NextPow2<U1>>::to_u32(), 1);
+        assert_eq!(<NextPow2<U3>>::to_u32(), 4);
+        assert_eq!(<NextPow2<U1024>>::to_u32(), 1024);
+        assert_eq!(<NextPow2<U1000>>::to_u32(), 1024);
+
+        assert_eq!(<PrevPow2<U1>>::to_u32(), 1);
+        assert_eq!(<PrevPow2<U3>>::to_u32(), 2);
+        assert_eq!(<PrevPow2<U1023>>::to_u32(), 512);
+        assert_eq!(<PrevPow2<U1024>>::to_u32(), 1024);
+    }
+
+    #[test]
+    fn bit_counts() {
+        assert_eq!(<Ones<U0>>::to_u32(), 0);
+        assert_eq!(<Ones<U1023>>::to_u32(), 10);
+        assert_eq!(<Ones<U1024>>::to_u32(), 1);
+        assert_eq!(<Zeros<U0, U64>>::to_u32(), 0u64.count_zeros());
+        assert_eq!(<Zeros<U1, U8>>::to_u32(), 1u8.count_zeros());
+        assert_eq!(<Zeros<U1023, U16>>::to_u32(), 1023u16.count_zeros());
+        assert_eq!(<Zeros<U1024, U32>>::to_u32(), 1024u32.count_zeros());
+        assert_eq!(<Zeros<U10, U4>>::to_u32(), 2);
+
+        assert_eq!(<TrailZeros<U1>>::to_u32(), 0);
+        assert_eq!(<TrailZeros<U10>>::to_u32(), 1);
+        assert_eq!(<TrailZeros<U1024>>::to_u32(), 10);
+
+        assert_eq!(<LeadZeros<U0, U64>>::to_u32(), 0u64.leading_zeros());
+        assert_eq!(<LeadZeros<U1, U64>>::to_u32(), 1u64.leading_zeros());
+        assert_eq!(<LeadZeros<U1000, U32>>::to_u32(), 1000u32.leading_zeros());
+        assert_eq!(<LeadZeros<U255, U8>>::to_u32(), 0);
+    }
+
+    #[test]
+    fn reverse_bits() {
+        assert_eq!(<BitsReversed<U0, U0>>::to_u32(), 0);
+        assert_eq!(<BitsReversed<U0, U8>>::to_u32(), 0);
+        assert_eq!(<BitsReversed<U1, U1>>::to_u32(), 1);
+        assert_eq!(<BitsReversed<U1, U8>>::to_u8(), 1u8.reverse_bits());
+        assert_eq!(<BitsReversed<U200, U8>>::to_u8(), 200u8.reverse_bits());
+        assert_eq!(<BitsReversed<U1000, U16>>::to_u16(), 1000u16.reverse_bits());
+        assert_eq!(<BitsReversed<U1000, U32>>::to_u32(), 1000u32.reverse_bits());
+        assert_eq!(<BitsReversed<U1, U64>>::to_u64(), 1u64.reverse_bits());
+    }
+}
+
 // -----------------------------------------
 // ToInt
 
@@ -2667,6 +4115,31 @@ where
     const INT: u128 = Self::U128;
 }
 
+// -----------------------------------------
+// ToBytes
+#[cfg(feature = "const-generics")]
+use crate::private::{push_bit_le, PrivateBytes};
+use crate::{consts::U8, QuotCeil, ToBytes};
+
+impl<Ul> ToBytes for Ul
+where
+    Ul: Unsigned + Len,
+    Length<Ul>: DivCeil<U8>,
+    QuotCeil<Length<Ul>, U8>: Unsigned,
+{
+    type ByteLen = QuotCeil<Length<Ul>, U8>;
+}
+
+#[cfg(feature = "const-generics")]
+impl<const N: usize> PrivateBytes<N> for UTerm {
+    const PRIVATE_LE_BYTES: [u8; N] = [0; N];
+}
+
+#[cfg(feature = "const-generics")]
+impl<U: Unsigned + PrivateBytes<N>, B: Bit, const N: usize> PrivateBytes<N> for UInt<U, B> {
+    const PRIVATE_LE_BYTES: [u8; N] = push_bit_le(U::PRIVATE_LE_BYTES, B::U8);
+}
+
 #[cfg(test)]
 mod tests {
     use crate::consts::*;
@@ -2716,6 +4189,36 @@ mod tests {
         // ...
     }
 
+    #[test]
+    fn checked_constants() {
+        assert_eq!(U0::CHECKED_U8, 0);
+        assert_eq!(U255::CHECKED_U8, 255);
+        assert_eq!(U256::CHECKED_U16, 256);
+        assert_eq!(U127::CHECKED_I8, 127);
+        assert_eq!(U65535::CHECKED_USIZE, 65535);
+
+        assert_eq!(U255::try_to_u8(), Some(255));
+        assert_eq!(U256::try_to_u8(), None);
+        assert_eq!(U127::try_to_i8(), Some(127));
+        assert_eq!(U128::try_to_i8(), None);
+        assert_eq!(U4294967295::try_to_u32(), Some(4294967295));
+        assert_eq!(U4294967296::try_to_u32(), None);
+        assert_eq!(U4294967296::try_to_u64(), Some(4294967296));
+    }
+
+    #[test]
+    fn fixed_width_arithmetic() {
+        use crate::{assert_type_eq, CheckedSum, WrappingSum};
+
+        assert_type_eq!(CheckedSum<U0, U0, U0>, U0);
+        assert_type_eq!(CheckedSum<U127, U128, U8>, U255);
+        assert_type_eq!(CheckedSum<U1, U1, U2>, U2);
+        assert_type_eq!(WrappingSum<U255, U1, U8>, U0);
+        assert_type_eq!(WrappingSum<U255, U255, U8>, U254);
+        assert_type_eq!(WrappingSum<U3, U4, U0>, U0);
+        assert_type_eq!(WrappingSum<U3, U4, U16>, U7);
+    }
+
     #[test]
     fn uint_toint_test() {
         // i8
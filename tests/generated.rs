@@ -146,6 +146,21 @@ fn test_0_Gcd_0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_SaturatingSub_0() {
+    type A = UTerm;
+    type B = UTerm;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0SaturatingSubU0 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0SaturatingSubU0 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_Sub_0() {
     type A = UTerm;
     type B = UTerm;
@@ -309,6 +324,21 @@ fn test_0_Gcd_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_SaturatingSub_1() {
+    type A = UTerm;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0SaturatingSubU1 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0SaturatingSubU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_Div_1() {
     type A = UTerm;
     type B = UInt<UTerm, B1>;
@@ -333,6 +363,66 @@ fn test_0_Rem_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_DivFloor_1() {
+    type A = UTerm;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivFloorU1 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivFloorU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivCeil_1() {
+    type A = UTerm;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivCeilU1 = <<A as DivCeil<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivCeilU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivEuclid_1() {
+    type A = UTerm;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivEuclidU1 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivEuclidU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_RemEuclid_1() {
+    type A = UTerm;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0RemEuclidU1 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0RemEuclidU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_PartialDiv_1() {
     type A = UTerm;
     type B = UInt<UTerm, B1>;
@@ -499,6 +589,21 @@ fn test_0_Gcd_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_SaturatingSub_2() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0SaturatingSubU2 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0SaturatingSubU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_Div_2() {
     type A = UTerm;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -523,6 +628,66 @@ fn test_0_Rem_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_DivFloor_2() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivFloorU2 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivFloorU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivCeil_2() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivCeilU2 = <<A as DivCeil<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivCeilU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivEuclid_2() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivEuclidU2 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivEuclidU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_RemEuclid_2() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0RemEuclidU2 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0RemEuclidU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_PartialDiv_2() {
     type A = UTerm;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -689,6 +854,21 @@ fn test_0_Gcd_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_SaturatingSub_3() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0SaturatingSubU3 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0SaturatingSubU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_Div_3() {
     type A = UTerm;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -713,6 +893,66 @@ fn test_0_Rem_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_DivFloor_3() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivFloorU3 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivFloorU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivCeil_3() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivCeilU3 = <<A as DivCeil<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivCeilU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivEuclid_3() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivEuclidU3 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivEuclidU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_RemEuclid_3() {
+    type A = UTerm;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0RemEuclidU3 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0RemEuclidU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_PartialDiv_3() {
     type A = UTerm;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -879,6 +1119,21 @@ fn test_0_Gcd_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_SaturatingSub_4() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0SaturatingSubU4 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0SaturatingSubU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_Div_4() {
     type A = UTerm;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -903,6 +1158,66 @@ fn test_0_Rem_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_DivFloor_4() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivFloorU4 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivFloorU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivCeil_4() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivCeilU4 = <<A as DivCeil<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivCeilU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivEuclid_4() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivEuclidU4 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivEuclidU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_RemEuclid_4() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0RemEuclidU4 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0RemEuclidU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_PartialDiv_4() {
     type A = UTerm;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -1069,16 +1384,31 @@ fn test_0_Gcd_5() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_0_Div_5() {
+fn test_0_SaturatingSub_5() {
     type A = UTerm;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type U0 = UTerm;
 
     #[allow(non_camel_case_types)]
-    type U0DivU5 = <<A as Div<B>>::Output as Same<U0>>::Output;
+    type U0SaturatingSubU5 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
 
-    assert_eq!(<U0DivU5 as Unsigned>::to_u64(), <U0 as Unsigned>::to_u64());
-}
+    assert_eq!(
+        <U0SaturatingSubU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_Div_5() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivU5 = <<A as Div<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(<U0DivU5 as Unsigned>::to_u64(), <U0 as Unsigned>::to_u64());
+}
 #[test]
 #[allow(non_snake_case)]
 fn test_0_Rem_5() {
@@ -1093,6 +1423,66 @@ fn test_0_Rem_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_0_DivFloor_5() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivFloorU5 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivFloorU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivCeil_5() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivCeilU5 = <<A as DivCeil<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivCeilU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_DivEuclid_5() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0DivEuclidU5 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0DivEuclidU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_0_RemEuclid_5() {
+    type A = UTerm;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U0RemEuclidU5 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U0RemEuclidU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_0_PartialDiv_5() {
     type A = UTerm;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -1259,6 +1649,21 @@ fn test_1_Gcd_0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_SaturatingSub_0() {
+    type A = UInt<UTerm, B1>;
+    type B = UTerm;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1SaturatingSubU0 = <<A as SaturatingSub<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1SaturatingSubU0 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Sub_0() {
     type A = UInt<UTerm, B1>;
     type B = UTerm;
@@ -1422,6 +1827,21 @@ fn test_1_Gcd_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_SaturatingSub_1() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1SaturatingSubU1 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1SaturatingSubU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Sub_1() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UTerm, B1>;
@@ -1458,6 +1878,66 @@ fn test_1_Rem_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_DivFloor_1() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UTerm, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1DivFloorU1 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1DivFloorU1 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivCeil_1() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UTerm, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1DivCeilU1 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1DivCeilU1 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivEuclid_1() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UTerm, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1DivEuclidU1 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1DivEuclidU1 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_RemEuclid_1() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1RemEuclidU1 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1RemEuclidU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_PartialDiv_1() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UTerm, B1>;
@@ -1624,6 +2104,21 @@ fn test_1_Gcd_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_SaturatingSub_2() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1SaturatingSubU2 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1SaturatingSubU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Div_2() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -1648,6 +2143,66 @@ fn test_1_Rem_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_DivFloor_2() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1DivFloorU2 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1DivFloorU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivCeil_2() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1DivCeilU2 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1DivCeilU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivEuclid_2() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1DivEuclidU2 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1DivEuclidU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_RemEuclid_2() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1RemEuclidU2 = <<A as RemEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1RemEuclidU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Cmp_2() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -1799,6 +2354,21 @@ fn test_1_Gcd_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_SaturatingSub_3() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1SaturatingSubU3 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1SaturatingSubU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Div_3() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -1823,6 +2393,66 @@ fn test_1_Rem_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_DivFloor_3() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1DivFloorU3 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1DivFloorU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivCeil_3() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1DivCeilU3 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1DivCeilU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivEuclid_3() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1DivEuclidU3 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1DivEuclidU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_RemEuclid_3() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1RemEuclidU3 = <<A as RemEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1RemEuclidU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Cmp_3() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -1974,6 +2604,21 @@ fn test_1_Gcd_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_SaturatingSub_4() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1SaturatingSubU4 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1SaturatingSubU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Div_4() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -1998,6 +2643,66 @@ fn test_1_Rem_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_DivFloor_4() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1DivFloorU4 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1DivFloorU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivCeil_4() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1DivCeilU4 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1DivCeilU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivEuclid_4() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1DivEuclidU4 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1DivEuclidU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_RemEuclid_4() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1RemEuclidU4 = <<A as RemEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1RemEuclidU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Cmp_4() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -2149,6 +2854,21 @@ fn test_1_Gcd_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_SaturatingSub_5() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1SaturatingSubU5 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1SaturatingSubU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Div_5() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -2173,6 +2893,66 @@ fn test_1_Rem_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_1_DivFloor_5() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1DivFloorU5 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1DivFloorU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivCeil_5() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1DivCeilU5 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1DivCeilU5 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_DivEuclid_5() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U1DivEuclidU5 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U1DivEuclidU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_1_RemEuclid_5() {
+    type A = UInt<UTerm, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U1RemEuclidU5 = <<A as RemEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U1RemEuclidU5 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_1_Cmp_5() {
     type A = UInt<UTerm, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -2324,6 +3104,21 @@ fn test_2_Gcd_0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_SaturatingSub_0() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UTerm;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U2SaturatingSubU0 = <<A as SaturatingSub<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U2SaturatingSubU0 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_Sub_0() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UTerm;
@@ -2487,6 +3282,21 @@ fn test_2_Gcd_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_SaturatingSub_1() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U2SaturatingSubU1 = <<A as SaturatingSub<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U2SaturatingSubU1 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_Sub_1() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UTerm, B1>;
@@ -2523,6 +3333,66 @@ fn test_2_Rem_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_DivFloor_1() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivFloorU1 = <<A as DivFloor<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U2DivFloorU1 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivCeil_1() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivCeilU1 = <<A as DivCeil<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U2DivCeilU1 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivEuclid_1() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivEuclidU1 = <<A as DivEuclid<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U2DivEuclidU1 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_RemEuclid_1() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2RemEuclidU1 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2RemEuclidU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_PartialDiv_1() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UTerm, B1>;
@@ -2689,6 +3559,21 @@ fn test_2_Gcd_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_SaturatingSub_2() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2SaturatingSubU2 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2SaturatingSubU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_Sub_2() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -2725,6 +3610,66 @@ fn test_2_Rem_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_DivFloor_2() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivFloorU2 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U2DivFloorU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivCeil_2() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivCeilU2 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U2DivCeilU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivEuclid_2() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivEuclidU2 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U2DivEuclidU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_RemEuclid_2() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2RemEuclidU2 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2RemEuclidU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_PartialDiv_2() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -2891,16 +3836,31 @@ fn test_2_Gcd_3() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_2_Div_3() {
+fn test_2_SaturatingSub_3() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UInt<UTerm, B1>, B1>;
     type U0 = UTerm;
 
     #[allow(non_camel_case_types)]
-    type U2DivU3 = <<A as Div<B>>::Output as Same<U0>>::Output;
+    type U2SaturatingSubU3 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
 
-    assert_eq!(<U2DivU3 as Unsigned>::to_u64(), <U0 as Unsigned>::to_u64());
-}
+    assert_eq!(
+        <U2SaturatingSubU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_Div_3() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2DivU3 = <<A as Div<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(<U2DivU3 as Unsigned>::to_u64(), <U0 as Unsigned>::to_u64());
+}
 #[test]
 #[allow(non_snake_case)]
 fn test_2_Rem_3() {
@@ -2915,6 +3875,66 @@ fn test_2_Rem_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_DivFloor_3() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2DivFloorU3 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2DivFloorU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivCeil_3() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivCeilU3 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U2DivCeilU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivEuclid_3() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2DivEuclidU3 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2DivEuclidU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_RemEuclid_3() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U2RemEuclidU3 = <<A as RemEuclid<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U2RemEuclidU3 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_Cmp_3() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -3066,6 +4086,21 @@ fn test_2_Gcd_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_SaturatingSub_4() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2SaturatingSubU4 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2SaturatingSubU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_Div_4() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -3090,6 +4125,66 @@ fn test_2_Rem_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_DivFloor_4() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2DivFloorU4 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2DivFloorU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivCeil_4() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivCeilU4 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U2DivCeilU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivEuclid_4() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2DivEuclidU4 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2DivEuclidU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_RemEuclid_4() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U2RemEuclidU4 = <<A as RemEuclid<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U2RemEuclidU4 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_Cmp_4() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -3241,6 +4336,21 @@ fn test_2_Gcd_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_SaturatingSub_5() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2SaturatingSubU5 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2SaturatingSubU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_Div_5() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -3265,6 +4375,66 @@ fn test_2_Rem_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_2_DivFloor_5() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2DivFloorU5 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2DivFloorU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivCeil_5() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U2DivCeilU5 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U2DivCeilU5 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_DivEuclid_5() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U2DivEuclidU5 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U2DivEuclidU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_2_RemEuclid_5() {
+    type A = UInt<UInt<UTerm, B1>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U2RemEuclidU5 = <<A as RemEuclid<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U2RemEuclidU5 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_2_Cmp_5() {
     type A = UInt<UInt<UTerm, B1>, B0>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -3416,6 +4586,21 @@ fn test_3_Gcd_0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_SaturatingSub_0() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UTerm;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3SaturatingSubU0 = <<A as SaturatingSub<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(
+        <U3SaturatingSubU0 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Sub_0() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UTerm;
@@ -3579,6 +4764,21 @@ fn test_3_Gcd_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_SaturatingSub_1() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U3SaturatingSubU1 = <<A as SaturatingSub<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U3SaturatingSubU1 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Sub_1() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UTerm, B1>;
@@ -3615,6 +4815,66 @@ fn test_3_Rem_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_DivFloor_1() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivFloorU1 = <<A as DivFloor<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(
+        <U3DivFloorU1 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivCeil_1() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivCeilU1 = <<A as DivCeil<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(
+        <U3DivCeilU1 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivEuclid_1() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivEuclidU1 = <<A as DivEuclid<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(
+        <U3DivEuclidU1 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_RemEuclid_1() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3RemEuclidU1 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3RemEuclidU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_PartialDiv_1() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UTerm, B1>;
@@ -3781,6 +5041,21 @@ fn test_3_Gcd_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_SaturatingSub_2() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3SaturatingSubU2 = <<A as SaturatingSub<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3SaturatingSubU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Sub_2() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -3817,6 +5092,66 @@ fn test_3_Rem_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_DivFloor_2() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivFloorU2 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3DivFloorU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivCeil_2() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivCeilU2 = <<A as DivCeil<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U3DivCeilU2 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivEuclid_2() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivEuclidU2 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3DivEuclidU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_RemEuclid_2() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3RemEuclidU2 = <<A as RemEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3RemEuclidU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Cmp_2() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -3968,6 +5303,21 @@ fn test_3_Gcd_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_SaturatingSub_3() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3SaturatingSubU3 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3SaturatingSubU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Sub_3() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -4004,6 +5354,66 @@ fn test_3_Rem_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_DivFloor_3() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivFloorU3 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3DivFloorU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivCeil_3() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivCeilU3 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3DivCeilU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivEuclid_3() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivEuclidU3 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3DivEuclidU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_RemEuclid_3() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3RemEuclidU3 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3RemEuclidU3 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_PartialDiv_3() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -4170,6 +5580,21 @@ fn test_3_Gcd_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_SaturatingSub_4() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3SaturatingSubU4 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3SaturatingSubU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Div_4() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -4194,6 +5619,66 @@ fn test_3_Rem_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_DivFloor_4() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3DivFloorU4 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3DivFloorU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivCeil_4() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivCeilU4 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3DivCeilU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivEuclid_4() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3DivEuclidU4 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3DivEuclidU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_RemEuclid_4() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3RemEuclidU4 = <<A as RemEuclid<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(
+        <U3RemEuclidU4 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Cmp_4() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -4349,6 +5834,21 @@ fn test_3_Gcd_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_SaturatingSub_5() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3SaturatingSubU5 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3SaturatingSubU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Div_5() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -4373,6 +5873,66 @@ fn test_3_Rem_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_3_DivFloor_5() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3DivFloorU5 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3DivFloorU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivCeil_5() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3DivCeilU5 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U3DivCeilU5 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_DivEuclid_5() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U3DivEuclidU5 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U3DivEuclidU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_3_RemEuclid_5() {
+    type A = UInt<UInt<UTerm, B1>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U3RemEuclidU5 = <<A as RemEuclid<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(
+        <U3RemEuclidU5 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_3_Cmp_5() {
     type A = UInt<UInt<UTerm, B1>, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -4524,6 +6084,21 @@ fn test_4_Gcd_0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_SaturatingSub_0() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UTerm;
+    type U4 = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4SaturatingSubU0 = <<A as SaturatingSub<B>>::Output as Same<U4>>::Output;
+
+    assert_eq!(
+        <U4SaturatingSubU0 as Unsigned>::to_u64(),
+        <U4 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_Sub_0() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UTerm;
@@ -4687,16 +6262,31 @@ fn test_4_Gcd_1() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_4_Sub_1() {
+fn test_4_SaturatingSub_1() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UTerm, B1>;
     type U3 = UInt<UInt<UTerm, B1>, B1>;
 
     #[allow(non_camel_case_types)]
-    type U4SubU1 = <<A as Sub<B>>::Output as Same<U3>>::Output;
+    type U4SaturatingSubU1 = <<A as SaturatingSub<B>>::Output as Same<U3>>::Output;
 
-    assert_eq!(<U4SubU1 as Unsigned>::to_u64(), <U3 as Unsigned>::to_u64());
-}
+    assert_eq!(
+        <U4SaturatingSubU1 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_Sub_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4SubU1 = <<A as Sub<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(<U4SubU1 as Unsigned>::to_u64(), <U3 as Unsigned>::to_u64());
+}
 #[test]
 #[allow(non_snake_case)]
 fn test_4_Div_1() {
@@ -4723,6 +6313,66 @@ fn test_4_Rem_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_DivFloor_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U4 = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivFloorU1 = <<A as DivFloor<B>>::Output as Same<U4>>::Output;
+
+    assert_eq!(
+        <U4DivFloorU1 as Unsigned>::to_u64(),
+        <U4 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivCeil_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U4 = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivCeilU1 = <<A as DivCeil<B>>::Output as Same<U4>>::Output;
+
+    assert_eq!(
+        <U4DivCeilU1 as Unsigned>::to_u64(),
+        <U4 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivEuclid_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U4 = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivEuclidU1 = <<A as DivEuclid<B>>::Output as Same<U4>>::Output;
+
+    assert_eq!(
+        <U4DivEuclidU1 as Unsigned>::to_u64(),
+        <U4 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_RemEuclid_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U4RemEuclidU1 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U4RemEuclidU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_PartialDiv_1() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UTerm, B1>;
@@ -4889,6 +6539,21 @@ fn test_4_Gcd_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_SaturatingSub_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4SaturatingSubU2 = <<A as SaturatingSub<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U4SaturatingSubU2 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_Sub_2() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -4925,6 +6590,66 @@ fn test_4_Rem_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_DivFloor_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivFloorU2 = <<A as DivFloor<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U4DivFloorU2 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivCeil_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivCeilU2 = <<A as DivCeil<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U4DivCeilU2 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivEuclid_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivEuclidU2 = <<A as DivEuclid<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U4DivEuclidU2 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_RemEuclid_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U4RemEuclidU2 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U4RemEuclidU2 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_PartialDiv_2() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -5091,6 +6816,21 @@ fn test_4_Gcd_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_SaturatingSub_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4SaturatingSubU3 = <<A as SaturatingSub<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U4SaturatingSubU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_Sub_3() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -5127,6 +6867,66 @@ fn test_4_Rem_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_DivFloor_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivFloorU3 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U4DivFloorU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivCeil_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivCeilU3 = <<A as DivCeil<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U4DivCeilU3 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivEuclid_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivEuclidU3 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U4DivEuclidU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_RemEuclid_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4RemEuclidU3 = <<A as RemEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U4RemEuclidU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_Cmp_3() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -5284,6 +7084,21 @@ fn test_4_Gcd_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_SaturatingSub_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U4SaturatingSubU4 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U4SaturatingSubU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_Sub_4() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -5320,6 +7135,66 @@ fn test_4_Rem_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_DivFloor_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivFloorU4 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U4DivFloorU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivCeil_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivCeilU4 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U4DivCeilU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivEuclid_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivEuclidU4 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U4DivEuclidU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_RemEuclid_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U4RemEuclidU4 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U4RemEuclidU4 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_PartialDiv_4() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -5505,6 +7380,21 @@ fn test_4_Gcd_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_SaturatingSub_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U4SaturatingSubU5 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U4SaturatingSubU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_Div_5() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -5529,6 +7419,66 @@ fn test_4_Rem_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_4_DivFloor_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U4DivFloorU5 = <<A as DivFloor<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U4DivFloorU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivCeil_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U4DivCeilU5 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U4DivCeilU5 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_DivEuclid_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U4DivEuclidU5 = <<A as DivEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U4DivEuclidU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_4_RemEuclid_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U4 = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U4RemEuclidU5 = <<A as RemEuclid<B>>::Output as Same<U4>>::Output;
+
+    assert_eq!(
+        <U4RemEuclidU5 as Unsigned>::to_u64(),
+        <U4 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_4_Cmp_5() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -5680,6 +7630,21 @@ fn test_5_Gcd_0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_SaturatingSub_0() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UTerm;
+    type U5 = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5SaturatingSubU0 = <<A as SaturatingSub<B>>::Output as Same<U5>>::Output;
+
+    assert_eq!(
+        <U5SaturatingSubU0 as Unsigned>::to_u64(),
+        <U5 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_Sub_0() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UTerm;
@@ -5843,6 +7808,21 @@ fn test_5_Gcd_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_SaturatingSub_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U4 = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U5SaturatingSubU1 = <<A as SaturatingSub<B>>::Output as Same<U4>>::Output;
+
+    assert_eq!(
+        <U5SaturatingSubU1 as Unsigned>::to_u64(),
+        <U4 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_Sub_1() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UTerm, B1>;
@@ -5879,6 +7859,66 @@ fn test_5_Rem_1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_DivFloor_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U5 = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivFloorU1 = <<A as DivFloor<B>>::Output as Same<U5>>::Output;
+
+    assert_eq!(
+        <U5DivFloorU1 as Unsigned>::to_u64(),
+        <U5 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivCeil_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U5 = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivCeilU1 = <<A as DivCeil<B>>::Output as Same<U5>>::Output;
+
+    assert_eq!(
+        <U5DivCeilU1 as Unsigned>::to_u64(),
+        <U5 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivEuclid_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U5 = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivEuclidU1 = <<A as DivEuclid<B>>::Output as Same<U5>>::Output;
+
+    assert_eq!(
+        <U5DivEuclidU1 as Unsigned>::to_u64(),
+        <U5 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_RemEuclid_1() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UTerm, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U5RemEuclidU1 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U5RemEuclidU1 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_PartialDiv_1() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UTerm, B1>;
@@ -6045,6 +8085,21 @@ fn test_5_Gcd_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_SaturatingSub_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5SaturatingSubU2 = <<A as SaturatingSub<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(
+        <U5SaturatingSubU2 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_Sub_2() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -6081,6 +8136,66 @@ fn test_5_Rem_2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_DivFloor_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivFloorU2 = <<A as DivFloor<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U5DivFloorU2 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivCeil_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U3 = UInt<UInt<UTerm, B1>, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivCeilU2 = <<A as DivCeil<B>>::Output as Same<U3>>::Output;
+
+    assert_eq!(
+        <U5DivCeilU2 as Unsigned>::to_u64(),
+        <U3 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivEuclid_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivEuclidU2 = <<A as DivEuclid<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U5DivEuclidU2 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_RemEuclid_2() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5RemEuclidU2 = <<A as RemEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5RemEuclidU2 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_Cmp_2() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UInt<UTerm, B1>, B0>;
@@ -6235,6 +8350,21 @@ fn test_5_Gcd_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_SaturatingSub_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U5SaturatingSubU3 = <<A as SaturatingSub<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U5SaturatingSubU3 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_Sub_3() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -6271,6 +8401,66 @@ fn test_5_Rem_3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_DivFloor_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivFloorU3 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5DivFloorU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivCeil_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivCeilU3 = <<A as DivCeil<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U5DivCeilU3 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivEuclid_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivEuclidU3 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5DivEuclidU3 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_RemEuclid_3() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U5RemEuclidU3 = <<A as RemEuclid<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U5RemEuclidU3 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_Cmp_3() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UInt<UTerm, B1>, B1>;
@@ -6431,6 +8621,21 @@ fn test_5_Gcd_4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_SaturatingSub_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5SaturatingSubU4 = <<A as SaturatingSub<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5SaturatingSubU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_Sub_4() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
@@ -6467,13 +8672,73 @@ fn test_5_Rem_4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_5_Cmp_4() {
+fn test_5_DivFloor_4() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
 
     #[allow(non_camel_case_types)]
-    type U5CmpU4 = <A as Cmp<B>>::Output;
-    assert_eq!(<U5CmpU4 as Ord>::to_ordering(), Ordering::Greater);
+    type U5DivFloorU4 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5DivFloorU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivCeil_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U2 = UInt<UInt<UTerm, B1>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivCeilU4 = <<A as DivCeil<B>>::Output as Same<U2>>::Output;
+
+    assert_eq!(
+        <U5DivCeilU4 as Unsigned>::to_u64(),
+        <U2 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivEuclid_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivEuclidU4 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5DivEuclidU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_RemEuclid_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5RemEuclidU4 = <<A as RemEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5RemEuclidU4 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_Cmp_4() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+
+    #[allow(non_camel_case_types)]
+    type U5CmpU4 = <A as Cmp<B>>::Output;
+    assert_eq!(<U5CmpU4 as Ord>::to_ordering(), Ordering::Greater);
 }
 #[test]
 #[allow(non_snake_case)]
@@ -6640,6 +8905,21 @@ fn test_5_Gcd_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_SaturatingSub_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U5SaturatingSubU5 = <<A as SaturatingSub<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U5SaturatingSubU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_Sub_5() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -6676,6 +8956,66 @@ fn test_5_Rem_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_5_DivFloor_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivFloorU5 = <<A as DivFloor<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5DivFloorU5 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivCeil_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivCeilU5 = <<A as DivCeil<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5DivCeilU5 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_DivEuclid_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U1 = UInt<UTerm, B1>;
+
+    #[allow(non_camel_case_types)]
+    type U5DivEuclidU5 = <<A as DivEuclid<B>>::Output as Same<U1>>::Output;
+
+    assert_eq!(
+        <U5DivEuclidU5 as Unsigned>::to_u64(),
+        <U1 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_5_RemEuclid_5() {
+    type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type U0 = UTerm;
+
+    #[allow(non_camel_case_types)]
+    type U5RemEuclidU5 = <<A as RemEuclid<B>>::Output as Same<U0>>::Output;
+
+    assert_eq!(
+        <U5RemEuclidU5 as Unsigned>::to_u64(),
+        <U0 as Unsigned>::to_u64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_5_PartialDiv_5() {
     type A = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
     type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
@@ -6701,6 +9041,42 @@ fn test_5_Cmp_5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_BitAnd_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndN5 = <<A as BitAnd<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5BitAndN5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrN5 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5BitOrN5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorN5 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N5BitXorN5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Add_N5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -6797,6 +9173,66 @@ fn test_N5_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_DivFloor_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivFloorN5 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N5DivFloorN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivCeil_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivCeilN5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N5DivCeilN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivEuclid_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N5DivEuclidN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_RemEuclid_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N5RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N5RemEuclidN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_PartialDiv_N5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -6822,6 +9258,42 @@ fn test_N5_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_BitAnd_N4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndN4 = <<A as BitAnd<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N5BitAndN4 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_N4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrN4 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N5BitOrN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_N4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorN4 = <<A as BitXor<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<N5BitXorN4 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Add_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -6918,59 +9390,155 @@ fn test_N5_Rem_N4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Cmp_N4() {
+fn test_N5_DivFloor_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<N5CmpN4 as Ord>::to_ordering(), Ordering::Less);
+    type N5DivFloorN4 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N5DivFloorN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Add_N3() {
+fn test_N5_DivCeil_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N5AddN3 = <<A as Add<B>>::Output as Same<N8>>::Output;
+    type N5DivCeilN4 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<N5AddN3 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+    assert_eq!(
+        <N5DivCeilN4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Sub_N3() {
+fn test_N5_DivEuclid_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N5SubN3 = <<A as Sub<B>>::Output as Same<N2>>::Output;
+    type N5DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<N5SubN3 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(
+        <N5DivEuclidN4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Mul_N3() {
+fn test_N5_RemEuclid_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type P15 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5MulN3 = <<A as Mul<B>>::Output as Same<P15>>::Output;
+    type N5RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<N5MulN3 as Integer>::to_i64(), <P15 as Integer>::to_i64());
+    assert_eq!(
+        <N5RemEuclidN4 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Min_N3() {
+fn test_N5_Cmp_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N5MinN3 = <<A as Min<B>>::Output as Same<N5>>::Output;
+    type N5CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<N5CmpN4 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitAnd_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndN3 = <<A as BitAnd<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<N5BitAndN3 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrN3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N5BitOrN3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorN3 = <<A as BitXor<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<N5BitXorN3 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Add_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5AddN3 = <<A as Add<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N5AddN3 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Sub_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5SubN3 = <<A as Sub<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N5SubN3 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Mul_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P15 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5MulN3 = <<A as Mul<B>>::Output as Same<P15>>::Output;
+
+    assert_eq!(<N5MulN3 as Integer>::to_i64(), <P15 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Min_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5MinN3 = <<A as Min<B>>::Output as Same<N5>>::Output;
 
     assert_eq!(<N5MinN3 as Integer>::to_i64(), <N5 as Integer>::to_i64());
 }
@@ -7024,6 +9592,66 @@ fn test_N5_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_DivFloor_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivFloorN3 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N5DivFloorN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivCeil_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivCeilN3 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N5DivCeilN3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivEuclid_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N5DivEuclidN3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_RemEuclid_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N5RemEuclidN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Cmp_N3() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -7034,6 +9662,42 @@ fn test_N5_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_BitAnd_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndN2 = <<A as BitAnd<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<N5BitAndN2 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrN2 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N5BitOrN2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorN2 = <<A as BitXor<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<N5BitXorN2 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Add_N2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -7130,6 +9794,66 @@ fn test_N5_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_DivFloor_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivFloorN2 = <<A as DivFloor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N5DivFloorN2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivCeil_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivCeilN2 = <<A as DivCeil<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N5DivCeilN2 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivEuclid_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N5DivEuclidN2 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_RemEuclid_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N5RemEuclidN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Cmp_N2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -7140,6 +9864,42 @@ fn test_N5_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_BitAnd_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndN1 = <<A as BitAnd<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5BitAndN1 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N5BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorN1 = <<A as BitXor<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<N5BitXorN1 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Add_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -7236,52 +9996,148 @@ fn test_N5_Rem_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_PartialDiv_N1() {
+fn test_N5_DivFloor_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
     type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5PartialDivN1 = <<A as PartialDiv<B>>::Output as Same<P5>>::Output;
+    type N5DivFloorN1 = <<A as DivFloor<B>>::Output as Same<P5>>::Output;
 
     assert_eq!(
-        <N5PartialDivN1 as Integer>::to_i64(),
+        <N5DivFloorN1 as Integer>::to_i64(),
         <P5 as Integer>::to_i64()
     );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Cmp_N1() {
+fn test_N5_DivCeil_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5CmpN1 = <A as Cmp<B>>::Output;
-    assert_eq!(<N5CmpN1 as Ord>::to_ordering(), Ordering::Less);
+    type N5DivCeilN1 = <<A as DivCeil<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(
+        <N5DivCeilN1 as Integer>::to_i64(),
+        <P5 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Add__0() {
+fn test_N5_DivEuclid_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = Z0;
-    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5Add_0 = <<A as Add<B>>::Output as Same<N5>>::Output;
+    type N5DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<P5>>::Output;
 
-    assert_eq!(<N5Add_0 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+    assert_eq!(
+        <N5DivEuclidN1 as Integer>::to_i64(),
+        <P5 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Sub__0() {
+fn test_N5_RemEuclid_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = Z0;
-    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N5Sub_0 = <<A as Sub<B>>::Output as Same<N5>>::Output;
+    type N5RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<N5Sub_0 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+    assert_eq!(
+        <N5RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_PartialDiv_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5PartialDivN1 = <<A as PartialDiv<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(
+        <N5PartialDivN1 as Integer>::to_i64(),
+        <P5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Cmp_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5CmpN1 = <A as Cmp<B>>::Output;
+    assert_eq!(<N5CmpN1 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitAnd__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N5BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = Z0;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOr_0 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5BitOr_0 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = Z0;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXor_0 = <<A as BitXor<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5BitXor_0 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Add__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = Z0;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5Add_0 = <<A as Add<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5Add_0 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Sub__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = Z0;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5Sub_0 = <<A as Sub<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5Sub_0 as Integer>::to_i64(), <N5 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -7355,6 +10211,66 @@ fn test_N5_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_Shl_0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UTerm;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShlU0 = <<A as Shl<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5ShlU0 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Shr_0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UTerm;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShrU0 = <<A as Shr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5ShrU0 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitAnd_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndP1 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N5BitAndP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrP1 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5BitOrP1 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorP1 = <<A as BitXor<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<N5BitXorP1 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Add_P1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -7451,6 +10367,66 @@ fn test_N5_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_DivFloor_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivFloorP1 = <<A as DivFloor<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(
+        <N5DivFloorP1 as Integer>::to_i64(),
+        <N5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivCeil_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivCeilP1 = <<A as DivCeil<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(
+        <N5DivCeilP1 as Integer>::to_i64(),
+        <N5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivEuclid_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(
+        <N5DivEuclidP1 as Integer>::to_i64(),
+        <N5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_RemEuclid_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N5RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N5RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_PartialDiv_P1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -7488,6 +10464,66 @@ fn test_N5_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_Shl_1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UTerm, B1>;
+    type N10 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShlU1 = <<A as Shl<B>>::Output as Same<N10>>::Output;
+
+    assert_eq!(<N5ShlU1 as Integer>::to_i64(), <N10 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Shr_1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UTerm, B1>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShrU1 = <<A as Shr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N5ShrU1 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitAnd_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndP2 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N5BitAndP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrP2 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5BitOrP2 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorP2 = <<A as BitXor<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<N5BitXorP2 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Add_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -7584,56 +10620,176 @@ fn test_N5_Rem_P2() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Pow_P2() {
+fn test_N5_DivFloor_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P25 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5PowP2 = <<A as Pow<B>>::Output as Same<P25>>::Output;
+    type N5DivFloorP2 = <<A as DivFloor<B>>::Output as Same<N3>>::Output;
 
-    assert_eq!(<N5PowP2 as Integer>::to_i64(), <P25 as Integer>::to_i64());
+    assert_eq!(
+        <N5DivFloorP2 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Cmp_P2() {
+fn test_N5_DivCeil_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N5CmpP2 = <A as Cmp<B>>::Output;
-    assert_eq!(<N5CmpP2 as Ord>::to_ordering(), Ordering::Less);
+    type N5DivCeilP2 = <<A as DivCeil<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N5DivCeilP2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Add_P3() {
+fn test_N5_DivEuclid_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5AddP3 = <<A as Add<B>>::Output as Same<N2>>::Output;
+    type N5DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<N3>>::Output;
 
-    assert_eq!(<N5AddP3 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(
+        <N5DivEuclidP2 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Sub_P3() {
+fn test_N5_RemEuclid_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5SubP3 = <<A as Sub<B>>::Output as Same<N8>>::Output;
+    type N5RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N5SubP3 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+    assert_eq!(
+        <N5RemEuclidP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Mul_P3() {
+fn test_N5_Pow_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N15 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P25 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5PowP2 = <<A as Pow<B>>::Output as Same<P25>>::Output;
+
+    assert_eq!(<N5PowP2 as Integer>::to_i64(), <P25 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Cmp_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5CmpP2 = <A as Cmp<B>>::Output;
+    assert_eq!(<N5CmpP2 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Shl_2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N20 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShlU2 = <<A as Shl<B>>::Output as Same<N20>>::Output;
+
+    assert_eq!(<N5ShlU2 as Integer>::to_i64(), <N20 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Shr_2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShrU2 = <<A as Shr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N5ShrU2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitAnd_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndP3 = <<A as BitAnd<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<N5BitAndP3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrP3 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5BitOrP3 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorP3 = <<A as BitXor<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N5BitXorP3 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Add_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5AddP3 = <<A as Add<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N5AddP3 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Sub_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5SubP3 = <<A as Sub<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N5SubP3 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Mul_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N15 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
     type N5MulP3 = <<A as Mul<B>>::Output as Same<N15>>::Output;
@@ -7702,6 +10858,66 @@ fn test_N5_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_DivFloor_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivFloorP3 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N5DivFloorP3 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivCeil_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivCeilP3 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N5DivCeilP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivEuclid_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N5DivEuclidP3 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_RemEuclid_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N5RemEuclidP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Pow_P3() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -7724,6 +10940,66 @@ fn test_N5_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_Shl_3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N40 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShlU3 = <<A as Shl<B>>::Output as Same<N40>>::Output;
+
+    assert_eq!(<N5ShlU3 as Integer>::to_i64(), <N40 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Shr_3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShrU3 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N5ShrU3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitAnd_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N5BitAndP4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N5BitAndP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitOr_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitOrP4 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N5BitOrP4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_BitXor_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorP4 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N5BitXorP4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Add_P4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -7820,6 +11096,66 @@ fn test_N5_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_DivFloor_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivFloorP4 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N5DivFloorP4 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivCeil_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivCeilP4 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N5DivCeilP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivEuclid_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N5DivEuclidP4 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_RemEuclid_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N5RemEuclidP4 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Pow_P4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -7853,58 +11189,118 @@ fn test_N5_Cmp_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Add_P5() {
+fn test_N5_Shl_4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type _0 = Z0;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N80 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N5AddP5 = <<A as Add<B>>::Output as Same<_0>>::Output;
+    type N5ShlU4 = <<A as Shl<B>>::Output as Same<N80>>::Output;
 
-    assert_eq!(<N5AddP5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<N5ShlU4 as Integer>::to_i64(), <N80 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Sub_P5() {
+fn test_N5_Shr_4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type N10 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5SubP5 = <<A as Sub<B>>::Output as Same<N10>>::Output;
+    type N5ShrU4 = <<A as Shr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N5SubP5 as Integer>::to_i64(), <N10 as Integer>::to_i64());
+    assert_eq!(<N5ShrU4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Mul_P5() {
+fn test_N5_BitAnd_P5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type N25 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5MulP5 = <<A as Mul<B>>::Output as Same<N25>>::Output;
+    type N5BitAndP5 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N5MulP5 as Integer>::to_i64(), <N25 as Integer>::to_i64());
+    assert_eq!(<N5BitAndP5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Min_P5() {
+fn test_N5_BitOr_P5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N5MinP5 = <<A as Min<B>>::Output as Same<N5>>::Output;
+    type N5BitOrP5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N5MinP5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+    assert_eq!(<N5BitOrP5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N5_Max_P5() {
+fn test_N5_BitXor_P5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5BitXorP5 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N5BitXorP5 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Add_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N5AddP5 = <<A as Add<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N5AddP5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Sub_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N10 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5SubP5 = <<A as Sub<B>>::Output as Same<N10>>::Output;
+
+    assert_eq!(<N5SubP5 as Integer>::to_i64(), <N10 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Mul_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N25 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5MulP5 = <<A as Mul<B>>::Output as Same<N25>>::Output;
+
+    assert_eq!(<N5MulP5 as Integer>::to_i64(), <N25 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Min_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5MinP5 = <<A as Min<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N5MinP5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Max_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
     type N5MaxP5 = <<A as Max<B>>::Output as Same<P5>>::Output;
@@ -7949,6 +11345,66 @@ fn test_N5_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_DivFloor_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivFloorP5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N5DivFloorP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivCeil_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivCeilP5 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N5DivCeilP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_DivEuclid_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N5DivEuclidP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_RemEuclid_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N5RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N5RemEuclidP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_PartialDiv_P5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -8006,6 +11462,67 @@ fn test_N5_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_Shl_5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N160 =
+        NInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShlU5 = <<A as Shl<B>>::Output as Same<N160>>::Output;
+
+    assert_eq!(<N5ShlU5 as Integer>::to_i64(), <N160 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N5_Shr_5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N5ShrU5 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N5ShrU5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitAnd_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndN5 = <<A as BitAnd<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N4BitAndN5 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrN5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4BitOrN5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorN5 = <<A as BitXor<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<N4BitXorN5 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Add_N5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -8102,6 +11619,66 @@ fn test_N4_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_DivFloor_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4DivFloorN5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N4DivFloorN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivCeil_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivCeilN5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N4DivCeilN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivEuclid_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N4DivEuclidN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_RemEuclid_N5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N4RemEuclidN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Cmp_N5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -8112,6 +11689,42 @@ fn test_N4_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_BitAnd_N4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndN4 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4BitAndN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_N4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrN4 = <<A as BitOr<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4BitOrN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_N4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorN4 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N4BitXorN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Add_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -8208,54 +11821,150 @@ fn test_N4_Rem_N4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_PartialDiv_N4() {
+fn test_N4_DivFloor_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N4PartialDivN4 = <<A as PartialDiv<B>>::Output as Same<P1>>::Output;
+    type N4DivFloorN4 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
 
     assert_eq!(
-        <N4PartialDivN4 as Integer>::to_i64(),
+        <N4DivFloorN4 as Integer>::to_i64(),
         <P1 as Integer>::to_i64()
     );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Cmp_N4() {
+fn test_N4_DivCeil_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N4CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<N4CmpN4 as Ord>::to_ordering(), Ordering::Equal);
+    type N4DivCeilN4 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N4DivCeilN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Add_N3() {
+fn test_N4_DivEuclid_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N4AddN3 = <<A as Add<B>>::Output as Same<N7>>::Output;
+    type N4DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N4AddN3 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+    assert_eq!(
+        <N4DivEuclidN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Sub_N3() {
+fn test_N4_RemEuclid_N4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N4SubN3 = <<A as Sub<B>>::Output as Same<N1>>::Output;
+    type N4RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<N4SubN3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
-}
-#[test]
+    assert_eq!(
+        <N4RemEuclidN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_PartialDiv_N4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4PartialDivN4 = <<A as PartialDiv<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N4PartialDivN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Cmp_N4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<N4CmpN4 as Ord>::to_ordering(), Ordering::Equal);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitAnd_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndN3 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4BitAndN3 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrN3 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N4BitOrN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorN3 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N4BitXorN3 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Add_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4AddN3 = <<A as Add<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<N4AddN3 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Sub_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4SubN3 = <<A as Sub<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4SubN3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
 #[allow(non_snake_case)]
 fn test_N4_Mul_N3() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -8329,6 +12038,66 @@ fn test_N4_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_DivFloor_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivFloorN3 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N4DivFloorN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivCeil_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivCeilN3 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N4DivCeilN3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivEuclid_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N4DivEuclidN3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_RemEuclid_N3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N4RemEuclidN3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Cmp_N3() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -8339,6 +12108,42 @@ fn test_N4_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_BitAnd_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndN2 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4BitAndN2 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrN2 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N4BitOrN2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorN2 = <<A as BitXor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N4BitXorN2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Add_N2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -8435,6 +12240,66 @@ fn test_N4_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_DivFloor_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivFloorN2 = <<A as DivFloor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N4DivFloorN2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivCeil_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivCeilN2 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N4DivCeilN2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivEuclid_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N4DivEuclidN2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_RemEuclid_N2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N4RemEuclidN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_PartialDiv_N2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -8460,6 +12325,42 @@ fn test_N4_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_BitAnd_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndN1 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4BitAndN1 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorN1 = <<A as BitXor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<N4BitXorN1 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Add_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -8556,48 +12457,144 @@ fn test_N4_Rem_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_PartialDiv_N1() {
+fn test_N4_DivFloor_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4PartialDivN1 = <<A as PartialDiv<B>>::Output as Same<P4>>::Output;
+    type N4DivFloorN1 = <<A as DivFloor<B>>::Output as Same<P4>>::Output;
 
     assert_eq!(
-        <N4PartialDivN1 as Integer>::to_i64(),
+        <N4DivFloorN1 as Integer>::to_i64(),
         <P4 as Integer>::to_i64()
     );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Cmp_N1() {
+fn test_N4_DivCeil_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4CmpN1 = <A as Cmp<B>>::Output;
-    assert_eq!(<N4CmpN1 as Ord>::to_ordering(), Ordering::Less);
+    type N4DivCeilN1 = <<A as DivCeil<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <N4DivCeilN1 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Add__0() {
+fn test_N4_DivEuclid_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = Z0;
-    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4Add_0 = <<A as Add<B>>::Output as Same<N4>>::Output;
+    type N4DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<P4>>::Output;
 
-    assert_eq!(<N4Add_0 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+    assert_eq!(
+        <N4DivEuclidN1 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Sub__0() {
+fn test_N4_RemEuclid_N1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = Z0;
-    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N4RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_PartialDiv_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4PartialDivN1 = <<A as PartialDiv<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <N4PartialDivN1 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Cmp_N1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4CmpN1 = <A as Cmp<B>>::Output;
+    assert_eq!(<N4CmpN1 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitAnd__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N4BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = Z0;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOr_0 = <<A as BitOr<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4BitOr_0 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = Z0;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXor_0 = <<A as BitXor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4BitXor_0 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Add__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = Z0;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4Add_0 = <<A as Add<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4Add_0 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Sub__0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = Z0;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
     #[allow(non_camel_case_types)]
     type N4Sub_0 = <<A as Sub<B>>::Output as Same<N4>>::Output;
 
@@ -8675,6 +12672,66 @@ fn test_N4_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_Shl_0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UTerm;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShlU0 = <<A as Shl<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4ShlU0 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Shr_0() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UTerm;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShrU0 = <<A as Shr<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4ShrU0 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitAnd_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndP1 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N4BitAndP1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrP1 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N4BitOrP1 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorP1 = <<A as BitXor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N4BitXorP1 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Add_P1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -8771,6 +12828,66 @@ fn test_N4_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_DivFloor_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivFloorP1 = <<A as DivFloor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(
+        <N4DivFloorP1 as Integer>::to_i64(),
+        <N4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivCeil_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivCeilP1 = <<A as DivCeil<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(
+        <N4DivCeilP1 as Integer>::to_i64(),
+        <N4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivEuclid_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(
+        <N4DivEuclidP1 as Integer>::to_i64(),
+        <N4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_RemEuclid_P1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N4RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_PartialDiv_P1() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -8808,6 +12925,66 @@ fn test_N4_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_Shl_1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UTerm, B1>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShlU1 = <<A as Shl<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N4ShlU1 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Shr_1() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UTerm, B1>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShrU1 = <<A as Shr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N4ShrU1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitAnd_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndP2 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N4BitAndP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrP2 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N4BitOrP2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorP2 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N4BitXorP2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Add_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -8904,64 +13081,184 @@ fn test_N4_Rem_P2() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_PartialDiv_P2() {
+fn test_N4_DivFloor_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4PartialDivP2 = <<A as PartialDiv<B>>::Output as Same<N2>>::Output;
+    type N4DivFloorP2 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
 
     assert_eq!(
-        <N4PartialDivP2 as Integer>::to_i64(),
+        <N4DivFloorP2 as Integer>::to_i64(),
         <N2 as Integer>::to_i64()
     );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Pow_P2() {
+fn test_N4_DivCeil_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P16 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4PowP2 = <<A as Pow<B>>::Output as Same<P16>>::Output;
+    type N4DivCeilP2 = <<A as DivCeil<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<N4PowP2 as Integer>::to_i64(), <P16 as Integer>::to_i64());
+    assert_eq!(
+        <N4DivCeilP2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Cmp_P2() {
+fn test_N4_DivEuclid_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4CmpP2 = <A as Cmp<B>>::Output;
-    assert_eq!(<N4CmpP2 as Ord>::to_ordering(), Ordering::Less);
+    type N4DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N4DivEuclidP2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Add_P3() {
+fn test_N4_RemEuclid_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N4AddP3 = <<A as Add<B>>::Output as Same<N1>>::Output;
+    type N4RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<N4AddP3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(
+        <N4RemEuclidP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Sub_P3() {
+fn test_N4_PartialDiv_P2() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4SubP3 = <<A as Sub<B>>::Output as Same<N7>>::Output;
+    type N4PartialDivP2 = <<A as PartialDiv<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<N4SubP3 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+    assert_eq!(
+        <N4PartialDivP2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Pow_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P16 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4PowP2 = <<A as Pow<B>>::Output as Same<P16>>::Output;
+
+    assert_eq!(<N4PowP2 as Integer>::to_i64(), <P16 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Cmp_P2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4CmpP2 = <A as Cmp<B>>::Output;
+    assert_eq!(<N4CmpP2 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Shl_2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N16 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShlU2 = <<A as Shl<B>>::Output as Same<N16>>::Output;
+
+    assert_eq!(<N4ShlU2 as Integer>::to_i64(), <N16 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Shr_2() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShrU2 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4ShrU2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitAnd_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndP3 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N4BitAndP3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrP3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4BitOrP3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorP3 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4BitXorP3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Add_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4AddP3 = <<A as Add<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4AddP3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Sub_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4SubP3 = <<A as Sub<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<N4SubP3 as Integer>::to_i64(), <N7 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -9037,6 +13334,66 @@ fn test_N4_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_DivFloor_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivFloorP3 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N4DivFloorP3 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivCeil_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivCeilP3 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N4DivCeilP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivEuclid_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N4DivEuclidP3 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_RemEuclid_P3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N4RemEuclidP3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Pow_P3() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -9059,6 +13416,66 @@ fn test_N4_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_Shl_3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N32 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShlU3 = <<A as Shl<B>>::Output as Same<N32>>::Output;
+
+    assert_eq!(<N4ShlU3 as Integer>::to_i64(), <N32 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Shr_3() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShrU3 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4ShrU3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitAnd_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitAndP4 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<N4BitAndP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitOr_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrP4 = <<A as BitOr<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N4BitOrP4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorP4 = <<A as BitXor<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N4BitXorP4 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Add_P4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -9155,6 +13572,66 @@ fn test_N4_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_DivFloor_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivFloorP4 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N4DivFloorP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivCeil_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivCeilP4 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N4DivCeilP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivEuclid_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N4DivEuclidP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_RemEuclid_P4() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N4RemEuclidP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_PartialDiv_P4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -9197,43 +13674,103 @@ fn test_N4_Cmp_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Add_P5() {
+fn test_N4_Shl_4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N64 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4AddP5 = <<A as Add<B>>::Output as Same<P1>>::Output;
+    type N4ShlU4 = <<A as Shl<B>>::Output as Same<N64>>::Output;
 
-    assert_eq!(<N4AddP5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<N4ShlU4 as Integer>::to_i64(), <N64 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Sub_P5() {
+fn test_N4_Shr_4() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type N9 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N4SubP5 = <<A as Sub<B>>::Output as Same<N9>>::Output;
+    type N4ShrU4 = <<A as Shr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N4SubP5 as Integer>::to_i64(), <N9 as Integer>::to_i64());
+    assert_eq!(<N4ShrU4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Mul_P5() {
+fn test_N4_BitAnd_P5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type N20 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N4MulP5 = <<A as Mul<B>>::Output as Same<N20>>::Output;
+    type N4BitAndP5 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
 
-    assert_eq!(<N4MulP5 as Integer>::to_i64(), <N20 as Integer>::to_i64());
+    assert_eq!(<N4BitAndP5 as Integer>::to_i64(), <P4 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N4_Min_P5() {
+fn test_N4_BitOr_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitOrP5 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N4BitOrP5 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_BitXor_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4BitXorP5 = <<A as BitXor<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<N4BitXorP5 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Add_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4AddP5 = <<A as Add<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N4AddP5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Sub_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N9 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4SubP5 = <<A as Sub<B>>::Output as Same<N9>>::Output;
+
+    assert_eq!(<N4SubP5 as Integer>::to_i64(), <N9 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Mul_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N20 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4MulP5 = <<A as Mul<B>>::Output as Same<N20>>::Output;
+
+    assert_eq!(<N4MulP5 as Integer>::to_i64(), <N20 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Min_P5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -9293,6 +13830,66 @@ fn test_N4_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_DivFloor_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivFloorP5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N4DivFloorP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivCeil_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N4DivCeilP5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N4DivCeilP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_DivEuclid_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N4DivEuclidP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_RemEuclid_P5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N4RemEuclidP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Pow_P5() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -9329,6 +13926,67 @@ fn test_N4_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_Shl_5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N128 =
+        NInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShlU5 = <<A as Shl<B>>::Output as Same<N128>>::Output;
+
+    assert_eq!(<N4ShlU5 as Integer>::to_i64(), <N128 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N4_Shr_5() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N4ShrU5 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N4ShrU5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitAnd_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndN5 = <<A as BitAnd<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<N3BitAndN5 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrN5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3BitOrN5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorN5 = <<A as BitXor<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<N3BitXorN5 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Add_N5() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -9425,6 +14083,66 @@ fn test_N3_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_DivFloor_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3DivFloorN5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N3DivFloorN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivCeil_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivCeilN5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N3DivCeilN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivEuclid_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N3DivEuclidN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_RemEuclid_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N3RemEuclidN5 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Cmp_N5() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -9435,6 +14153,42 @@ fn test_N3_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_BitAnd_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndN4 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N3BitAndN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrN4 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitOrN4 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorN4 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N3BitXorN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Add_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -9531,47 +14285,143 @@ fn test_N3_Rem_N4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Cmp_N4() {
+fn test_N3_DivFloor_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N3CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<N3CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+    type N3DivFloorN4 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N3DivFloorN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Add_N3() {
+fn test_N3_DivCeil_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3AddN3 = <<A as Add<B>>::Output as Same<N6>>::Output;
+    type N3DivCeilN4 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N3AddN3 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+    assert_eq!(
+        <N3DivCeilN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Sub_N3() {
+fn test_N3_DivEuclid_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type _0 = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3SubN3 = <<A as Sub<B>>::Output as Same<_0>>::Output;
+    type N3DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N3SubN3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <N3DivEuclidN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Mul_N3() {
+fn test_N3_RemEuclid_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type P9 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3MulN3 = <<A as Mul<B>>::Output as Same<P9>>::Output;
+    type N3RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N3RemEuclidN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Cmp_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<N3CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitAnd_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndN3 = <<A as BitAnd<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitAndN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrN3 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitOrN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorN3 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N3BitXorN3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Add_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3AddN3 = <<A as Add<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<N3AddN3 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Sub_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3SubN3 = <<A as Sub<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N3SubN3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Mul_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P9 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3MulN3 = <<A as Mul<B>>::Output as Same<P9>>::Output;
 
     assert_eq!(<N3MulN3 as Integer>::to_i64(), <P9 as Integer>::to_i64());
 }
@@ -9637,6 +14487,66 @@ fn test_N3_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_DivFloor_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivFloorN3 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N3DivFloorN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivCeil_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivCeilN3 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N3DivCeilN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivEuclid_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N3DivEuclidN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_RemEuclid_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N3RemEuclidN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_PartialDiv_N3() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -9662,6 +14572,42 @@ fn test_N3_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_BitAnd_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndN2 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N3BitAndN2 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrN2 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3BitOrN2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorN2 = <<A as BitXor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<N3BitXorN2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Add_N2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -9758,6 +14704,66 @@ fn test_N3_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_DivFloor_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivFloorN2 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N3DivFloorN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivCeil_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivCeilN2 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N3DivCeilN2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivEuclid_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N3DivEuclidN2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_RemEuclid_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N3RemEuclidN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Cmp_N2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -9768,6 +14774,42 @@ fn test_N3_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_BitAnd_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndN1 = <<A as BitAnd<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitAndN1 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorN1 = <<A as BitXor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N3BitXorN1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Add_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -9864,52 +14906,148 @@ fn test_N3_Rem_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_PartialDiv_N1() {
+fn test_N3_DivFloor_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
     type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3PartialDivN1 = <<A as PartialDiv<B>>::Output as Same<P3>>::Output;
+    type N3DivFloorN1 = <<A as DivFloor<B>>::Output as Same<P3>>::Output;
 
     assert_eq!(
-        <N3PartialDivN1 as Integer>::to_i64(),
+        <N3DivFloorN1 as Integer>::to_i64(),
         <P3 as Integer>::to_i64()
     );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Cmp_N1() {
+fn test_N3_DivCeil_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3CmpN1 = <A as Cmp<B>>::Output;
-    assert_eq!(<N3CmpN1 as Ord>::to_ordering(), Ordering::Less);
+    type N3DivCeilN1 = <<A as DivCeil<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N3DivCeilN1 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Add__0() {
+fn test_N3_DivEuclid_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = Z0;
-    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3Add_0 = <<A as Add<B>>::Output as Same<N3>>::Output;
+    type N3DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<N3Add_0 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+    assert_eq!(
+        <N3DivEuclidN1 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Sub__0() {
+fn test_N3_RemEuclid_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = Z0;
-    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N3Sub_0 = <<A as Sub<B>>::Output as Same<N3>>::Output;
+    type N3RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<N3Sub_0 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+    assert_eq!(
+        <N3RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_PartialDiv_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3PartialDivN1 = <<A as PartialDiv<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N3PartialDivN1 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Cmp_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3CmpN1 = <A as Cmp<B>>::Output;
+    assert_eq!(<N3CmpN1 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitAnd__0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N3BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr__0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = Z0;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOr_0 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitOr_0 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor__0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = Z0;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXor_0 = <<A as BitXor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitXor_0 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Add__0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = Z0;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3Add_0 = <<A as Add<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3Add_0 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Sub__0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = Z0;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3Sub_0 = <<A as Sub<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3Sub_0 as Integer>::to_i64(), <N3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -9983,6 +15121,66 @@ fn test_N3_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_Shl_0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UTerm;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShlU0 = <<A as Shl<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3ShlU0 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Shr_0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UTerm;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShrU0 = <<A as Shr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3ShrU0 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitAnd_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndP1 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N3BitAndP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrP1 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitOrP1 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorP1 = <<A as BitXor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N3BitXorP1 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Add_P1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -10079,6 +15277,66 @@ fn test_N3_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_DivFloor_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivFloorP1 = <<A as DivFloor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(
+        <N3DivFloorP1 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivCeil_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivCeilP1 = <<A as DivCeil<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(
+        <N3DivCeilP1 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivEuclid_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(
+        <N3DivEuclidP1 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_RemEuclid_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N3RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_PartialDiv_P1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -10116,6 +15374,66 @@ fn test_N3_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_Shl_1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UTerm, B1>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShlU1 = <<A as Shl<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<N3ShlU1 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Shr_1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UTerm, B1>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShrU1 = <<A as Shr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N3ShrU1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitAnd_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndP2 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N3BitAndP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrP2 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3BitOrP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorP2 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3BitXorP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Add_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -10212,56 +15530,176 @@ fn test_N3_Rem_P2() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Pow_P2() {
+fn test_N3_DivFloor_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P9 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N3PowP2 = <<A as Pow<B>>::Output as Same<P9>>::Output;
+    type N3DivFloorP2 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<N3PowP2 as Integer>::to_i64(), <P9 as Integer>::to_i64());
+    assert_eq!(
+        <N3DivFloorP2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Cmp_P2() {
+fn test_N3_DivCeil_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3CmpP2 = <A as Cmp<B>>::Output;
-    assert_eq!(<N3CmpP2 as Ord>::to_ordering(), Ordering::Less);
+    type N3DivCeilP2 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N3DivCeilP2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Add_P3() {
+fn test_N3_DivEuclid_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type _0 = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N3AddP3 = <<A as Add<B>>::Output as Same<_0>>::Output;
+    type N3DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<N3AddP3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <N3DivEuclidP2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Sub_P3() {
+fn test_N3_RemEuclid_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3SubP3 = <<A as Sub<B>>::Output as Same<N6>>::Output;
+    type N3RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N3SubP3 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+    assert_eq!(
+        <N3RemEuclidP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Mul_P3() {
+fn test_N3_Pow_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type N9 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P9 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3PowP2 = <<A as Pow<B>>::Output as Same<P9>>::Output;
+
+    assert_eq!(<N3PowP2 as Integer>::to_i64(), <P9 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Cmp_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3CmpP2 = <A as Cmp<B>>::Output;
+    assert_eq!(<N3CmpP2 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Shl_2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N12 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShlU2 = <<A as Shl<B>>::Output as Same<N12>>::Output;
+
+    assert_eq!(<N3ShlU2 as Integer>::to_i64(), <N12 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Shr_2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShrU2 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3ShrU2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitAnd_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndP3 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N3BitAndP3 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrP3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3BitOrP3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorP3 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N3BitXorP3 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Add_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3AddP3 = <<A as Add<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N3AddP3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Sub_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3SubP3 = <<A as Sub<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<N3SubP3 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Mul_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N9 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
     type N3MulP3 = <<A as Mul<B>>::Output as Same<N9>>::Output;
@@ -10330,6 +15768,66 @@ fn test_N3_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_DivFloor_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivFloorP3 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N3DivFloorP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivCeil_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivCeilP3 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N3DivCeilP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivEuclid_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N3DivEuclidP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_RemEuclid_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N3RemEuclidP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_PartialDiv_P3() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -10367,6 +15865,66 @@ fn test_N3_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_Shl_3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N24 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShlU3 = <<A as Shl<B>>::Output as Same<N24>>::Output;
+
+    assert_eq!(<N3ShlU3 as Integer>::to_i64(), <N24 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Shr_3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShrU3 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3ShrU3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitAnd_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitAndP4 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<N3BitAndP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrP4 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitOrP4 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorP4 = <<A as BitXor<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<N3BitXorP4 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Add_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -10463,107 +16021,227 @@ fn test_N3_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Pow_P4() {
+fn test_N3_DivFloor_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P81 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3PowP4 = <<A as Pow<B>>::Output as Same<P81>>::Output;
+    type N3DivFloorP4 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N3PowP4 as Integer>::to_i64(), <P81 as Integer>::to_i64());
+    assert_eq!(
+        <N3DivFloorP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Cmp_P4() {
+fn test_N3_DivCeil_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N3CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<N3CmpP4 as Ord>::to_ordering(), Ordering::Less);
+    type N3DivCeilP4 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N3DivCeilP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Add_P5() {
+fn test_N3_DivEuclid_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3AddP5 = <<A as Add<B>>::Output as Same<P2>>::Output;
+    type N3DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N3AddP5 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <N3DivEuclidP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Sub_P5() {
+fn test_N3_RemEuclid_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3SubP5 = <<A as Sub<B>>::Output as Same<N8>>::Output;
+    type N3RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N3SubP5 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+    assert_eq!(
+        <N3RemEuclidP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Mul_P5() {
+fn test_N3_Pow_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type N15 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P81 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3MulP5 = <<A as Mul<B>>::Output as Same<N15>>::Output;
+    type N3PowP4 = <<A as Pow<B>>::Output as Same<P81>>::Output;
 
-    assert_eq!(<N3MulP5 as Integer>::to_i64(), <N15 as Integer>::to_i64());
+    assert_eq!(<N3PowP4 as Integer>::to_i64(), <P81 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Min_P5() {
+fn test_N3_Cmp_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N3MinP5 = <<A as Min<B>>::Output as Same<N3>>::Output;
-
-    assert_eq!(<N3MinP5 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+    type N3CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<N3CmpP4 as Ord>::to_ordering(), Ordering::Less);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Max_P5() {
+fn test_N3_Shl_4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N48 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N3MaxP5 = <<A as Max<B>>::Output as Same<P5>>::Output;
+    type N3ShlU4 = <<A as Shl<B>>::Output as Same<N48>>::Output;
 
-    assert_eq!(<N3MaxP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+    assert_eq!(<N3ShlU4 as Integer>::to_i64(), <N48 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Gcd_P5() {
+fn test_N3_Shr_4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3GcdP5 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type N3ShrU4 = <<A as Shr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N3GcdP5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<N3ShrU4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N3_Div_P5() {
+fn test_N3_BitAnd_P5() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type _0 = Z0;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N3DivP5 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type N3BitAndP5 = <<A as BitAnd<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<N3BitAndP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitOr_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitOrP5 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3BitOrP5 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_BitXor_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3BitXorP5 = <<A as BitXor<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N3BitXorP5 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Add_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3AddP5 = <<A as Add<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N3AddP5 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Sub_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3SubP5 = <<A as Sub<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N3SubP5 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Mul_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N15 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3MulP5 = <<A as Mul<B>>::Output as Same<N15>>::Output;
+
+    assert_eq!(<N3MulP5 as Integer>::to_i64(), <N15 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Min_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3MinP5 = <<A as Min<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N3MinP5 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Max_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3MaxP5 = <<A as Max<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<N3MaxP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Gcd_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3GcdP5 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N3GcdP5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Div_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3DivP5 = <<A as Div<B>>::Output as Same<_0>>::Output;
 
     assert_eq!(<N3DivP5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
@@ -10581,6 +16259,66 @@ fn test_N3_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_DivFloor_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivFloorP5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N3DivFloorP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivCeil_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N3DivCeilP5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N3DivCeilP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_DivEuclid_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N3DivEuclidP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_RemEuclid_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N3RemEuclidP5 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Pow_P5() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -10604,6 +16342,66 @@ fn test_N3_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_Shl_5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N96 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShlU5 = <<A as Shl<B>>::Output as Same<N96>>::Output;
+
+    assert_eq!(<N3ShlU5 as Integer>::to_i64(), <N96 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N3_Shr_5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N3ShrU5 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N3ShrU5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitAnd_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndN5 = <<A as BitAnd<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<N2BitAndN5 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrN5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2BitOrN5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorN5 = <<A as BitXor<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<N2BitXorN5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add_N5() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -10700,6 +16498,66 @@ fn test_N2_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_DivFloor_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2DivFloorN5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N2DivFloorN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivCeil_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivCeilN5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2DivCeilN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivEuclid_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2DivEuclidN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_RemEuclid_N5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N2RemEuclidN5 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Cmp_N5() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -10710,6 +16568,42 @@ fn test_N2_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_BitAnd_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndN4 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N2BitAndN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrN4 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2BitOrN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorN4 = <<A as BitXor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N2BitXorN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -10734,85 +16628,181 @@ fn test_N2_Sub_N4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Mul_N4() {
+fn test_N2_Mul_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P8 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2MulN4 = <<A as Mul<B>>::Output as Same<P8>>::Output;
+
+    assert_eq!(<N2MulN4 as Integer>::to_i64(), <P8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Min_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2MinN4 = <<A as Min<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N2MinN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Max_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2MaxN4 = <<A as Max<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2MaxN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Gcd_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2GcdN4 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N2GcdN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Div_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N2DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Rem_N4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2RemN4 = <<A as Rem<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2RemN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivFloor_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P8 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N2MulN4 = <<A as Mul<B>>::Output as Same<P8>>::Output;
+    type N2DivFloorN4 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<N2MulN4 as Integer>::to_i64(), <P8 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivFloorN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Min_N4() {
+fn test_N2_DivCeil_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N2MinN4 = <<A as Min<B>>::Output as Same<N4>>::Output;
+    type N2DivCeilN4 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N2MinN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivCeilN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Max_N4() {
+fn test_N2_DivEuclid_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N2MaxN4 = <<A as Max<B>>::Output as Same<N2>>::Output;
+    type N2DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N2MaxN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivEuclidN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Gcd_N4() {
+fn test_N2_RemEuclid_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N2GcdN4 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+    type N2RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<N2GcdN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <N2RemEuclidN4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Div_N4() {
+fn test_N2_Cmp_N4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N2DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type N2CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<N2CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitAnd_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
-    assert_eq!(<N2DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    #[allow(non_camel_case_types)]
+    type N2BitAndN3 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N2BitAndN3 as Integer>::to_i64(), <N4 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Rem_N4() {
+fn test_N2_BitOr_N3() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N2RemN4 = <<A as Rem<B>>::Output as Same<N2>>::Output;
+    type N2BitOrN3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N2RemN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(<N2BitOrN3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Cmp_N4() {
+fn test_N2_BitXor_N3() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N2CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<N2CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+    type N2BitXorN3 = <<A as BitXor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<N2BitXorN3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -10912,6 +16902,66 @@ fn test_N2_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_DivFloor_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2DivFloorN3 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N2DivFloorN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivCeil_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivCeilN3 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2DivCeilN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivEuclid_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2DivEuclidN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_RemEuclid_N3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2RemEuclidN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Cmp_N3() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -10922,6 +16972,42 @@ fn test_N2_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_BitAnd_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndN2 = <<A as BitAnd<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2BitAndN2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrN2 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2BitOrN2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorN2 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N2BitXorN2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add_N2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -11018,6 +17104,66 @@ fn test_N2_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_DivFloor_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivFloorN2 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2DivFloorN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivCeil_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivCeilN2 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2DivCeilN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivEuclid_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2DivEuclidN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_RemEuclid_N2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N2RemEuclidN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_PartialDiv_N2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -11043,6 +17189,42 @@ fn test_N2_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_BitAnd_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndN1 = <<A as BitAnd<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2BitAndN1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorN1 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N2BitXorN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -11063,79 +17245,139 @@ fn test_N2_Sub_N1() {
     #[allow(non_camel_case_types)]
     type N2SubN1 = <<A as Sub<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N2SubN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<N2SubN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Mul_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2MulN1 = <<A as Mul<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N2MulN1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Min_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2MinN1 = <<A as Min<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2MinN1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Max_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2MaxN1 = <<A as Max<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2MaxN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Gcd_N1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2GcdN1 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N2GcdN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Mul_N1() {
+fn test_N2_Div_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N2MulN1 = <<A as Mul<B>>::Output as Same<P2>>::Output;
+    type N2DivN1 = <<A as Div<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<N2MulN1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(<N2DivN1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Min_N1() {
+fn test_N2_Rem_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N2MinN1 = <<A as Min<B>>::Output as Same<N2>>::Output;
+    type N2RemN1 = <<A as Rem<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<N2MinN1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(<N2RemN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Max_N1() {
+fn test_N2_DivFloor_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N2MaxN1 = <<A as Max<B>>::Output as Same<N1>>::Output;
+    type N2DivFloorN1 = <<A as DivFloor<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<N2MaxN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivFloorN1 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Gcd_N1() {
+fn test_N2_DivCeil_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N2GcdN1 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type N2DivCeilN1 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<N2GcdN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivCeilN1 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Div_N1() {
+fn test_N2_DivEuclid_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N2DivN1 = <<A as Div<B>>::Output as Same<P2>>::Output;
+    type N2DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<N2DivN1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivEuclidN1 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Rem_N1() {
+fn test_N2_RemEuclid_N1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N2RemN1 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type N2RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<N2RemN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <N2RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
@@ -11164,6 +17406,42 @@ fn test_N2_Cmp_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_BitAnd__0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N2BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr__0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = Z0;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOr_0 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2BitOr_0 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor__0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = Z0;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXor_0 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2BitXor_0 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add__0() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = Z0;
@@ -11258,6 +17536,66 @@ fn test_N2_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_Shl_0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UTerm;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShlU0 = <<A as Shl<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2ShlU0 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Shr_0() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UTerm;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShrU0 = <<A as Shr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2ShrU0 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitAnd_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndP1 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N2BitAndP1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrP1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2BitOrP1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorP1 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2BitXorP1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add_P1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -11354,6 +17692,66 @@ fn test_N2_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_DivFloor_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivFloorP1 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N2DivFloorP1 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivCeil_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivCeilP1 = <<A as DivCeil<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N2DivCeilP1 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivEuclid_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <N2DivEuclidP1 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_RemEuclid_P1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N2RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_PartialDiv_P1() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -11391,6 +17789,66 @@ fn test_N2_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_Shl_1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UTerm, B1>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShlU1 = <<A as Shl<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N2ShlU1 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Shr_1() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UTerm, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShrU1 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2ShrU1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitAnd_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndP2 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N2BitAndP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrP2 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2BitOrP2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorP2 = <<A as BitXor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N2BitXorP2 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -11445,45 +17903,105 @@ fn test_N2_Max_P2() {
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N2MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
+    type N2MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N2MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Gcd_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2GcdP2 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N2GcdP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Div_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivP2 = <<A as Div<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2DivP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Rem_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2RemP2 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N2RemP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivFloor_P2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivFloorP2 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N2MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivFloorP2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Gcd_P2() {
+fn test_N2_DivCeil_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N2GcdP2 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+    type N2DivCeilP2 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N2GcdP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivCeilP2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Div_P2() {
+fn test_N2_DivEuclid_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N2DivP2 = <<A as Div<B>>::Output as Same<N1>>::Output;
+    type N2DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N2DivP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(
+        <N2DivEuclidP2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N2_Rem_P2() {
+fn test_N2_RemEuclid_P2() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type N2RemP2 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type N2RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<N2RemP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <N2RemEuclidP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
@@ -11524,6 +18042,66 @@ fn test_N2_Cmp_P2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_Shl_2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShlU2 = <<A as Shl<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N2ShlU2 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Shr_2() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShrU2 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2ShrU2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitAnd_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndP3 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N2BitAndP3 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrP3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2BitOrP3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorP3 = <<A as BitXor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N2BitXorP3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add_P3() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -11620,6 +18198,66 @@ fn test_N2_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_DivFloor_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivFloorP3 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N2DivFloorP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivCeil_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2DivCeilP3 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N2DivCeilP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivEuclid_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N2DivEuclidP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_RemEuclid_P3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N2RemEuclidP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Pow_P3() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -11642,6 +18280,66 @@ fn test_N2_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_Shl_3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N16 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShlU3 = <<A as Shl<B>>::Output as Same<N16>>::Output;
+
+    assert_eq!(<N2ShlU3 as Integer>::to_i64(), <N16 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Shr_3() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShrU3 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2ShrU3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitAnd_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndP4 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<N2BitAndP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrP4 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N2BitOrP4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorP4 = <<A as BitXor<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<N2BitXorP4 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Add_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -11738,6 +18436,66 @@ fn test_N2_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_DivFloor_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivFloorP4 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N2DivFloorP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivCeil_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2DivCeilP4 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N2DivCeilP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivEuclid_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N2DivEuclidP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_RemEuclid_P4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N2RemEuclidP4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Pow_P4() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -11755,8 +18513,68 @@ fn test_N2_Cmp_P4() {
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N2CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<N2CmpP4 as Ord>::to_ordering(), Ordering::Less);
+    type N2CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<N2CmpP4 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Shl_4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N32 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShlU4 = <<A as Shl<B>>::Output as Same<N32>>::Output;
+
+    assert_eq!(<N2ShlU4 as Integer>::to_i64(), <N32 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Shr_4() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShrU4 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2ShrU4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitAnd_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitAndP5 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<N2BitAndP5 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitOr_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitOrP5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2BitOrP5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_BitXor_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2BitXorP5 = <<A as BitXor<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N2BitXorP5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -11856,6 +18674,66 @@ fn test_N2_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_DivFloor_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivFloorP5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N2DivFloorP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivCeil_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N2DivCeilP5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N2DivCeilP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_DivEuclid_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N2DivEuclidP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_RemEuclid_P5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N2RemEuclidP5 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Pow_P5() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -11878,6 +18756,66 @@ fn test_N2_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_Shl_5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N64 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShlU5 = <<A as Shl<B>>::Output as Same<N64>>::Output;
+
+    assert_eq!(<N2ShlU5 as Integer>::to_i64(), <N64 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N2_Shr_5() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N2ShrU5 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N2ShrU5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitAnd_N5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitAndN5 = <<A as BitAnd<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N1BitAndN5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitOr_N5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitOrN5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitOrN5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitXor_N5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitXorN5 = <<A as BitXor<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<N1BitXorN5 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Add_N5() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -11974,6 +18912,66 @@ fn test_N1_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_DivFloor_N5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorN5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1DivFloorN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_N5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilN5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivCeilN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_N5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_N5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidN5 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Pow_N5() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -11996,6 +18994,42 @@ fn test_N1_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_BitAnd_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitAndN4 = <<A as BitAnd<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N1BitAndN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitOr_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitOrN4 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitOrN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitXor_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitXorN4 = <<A as BitXor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<N1BitXorN4 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Add_N4() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -12071,46 +19105,142 @@ fn test_N1_Gcd_N4() {
 fn test_N1_Div_N4() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type _0 = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N1DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Rem_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemN4 = <<A as Rem<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1RemN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivFloor_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorN4 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1DivFloorN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilN4 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivCeilN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidN4 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Pow_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1PowN4 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N1PowN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Cmp_N4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
-
-    assert_eq!(<N1DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    type N1CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<N1CmpN4 as Ord>::to_ordering(), Ordering::Greater);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Rem_N4() {
+fn test_N1_BitAnd_N3() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1RemN4 = <<A as Rem<B>>::Output as Same<N1>>::Output;
+    type N1BitAndN3 = <<A as BitAnd<B>>::Output as Same<N3>>::Output;
 
-    assert_eq!(<N1RemN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<N1BitAndN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Pow_N4() {
+fn test_N1_BitOr_N3() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1PowN4 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+    type N1BitOrN3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N1PowN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<N1BitOrN3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Cmp_N4() {
+fn test_N1_BitXor_N3() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<N1CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+    type N1BitXorN3 = <<A as BitXor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N1BitXorN3 as Integer>::to_i64(), <P2 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -12210,6 +19340,66 @@ fn test_N1_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_DivFloor_N3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorN3 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1DivFloorN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_N3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilN3 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivCeilN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_N3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_N3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidN3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Pow_N3() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -12232,6 +19422,42 @@ fn test_N1_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_BitAnd_N2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitAndN2 = <<A as BitAnd<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N1BitAndN2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitOr_N2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitOrN2 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitOrN2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitXor_N2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitXorN2 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N1BitXorN2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Add_N2() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -12328,6 +19554,66 @@ fn test_N1_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_DivFloor_N2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorN2 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1DivFloorN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_N2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilN2 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivCeilN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_N2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_N2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Pow_N2() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -12350,6 +19636,42 @@ fn test_N1_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_BitAnd_N1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitAndN1 = <<A as BitAnd<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitAndN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitOr_N1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitXor_N1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1BitXorN1 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N1BitXorN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Add_N1() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -12446,6 +19768,66 @@ fn test_N1_Rem_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_DivFloor_N1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorN1 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivFloorN1 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_N1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilN1 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivCeilN1 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_N1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidN1 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_N1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_PartialDiv_N1() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -12463,23 +19845,59 @@ fn test_N1_PartialDiv_N1() {
 #[allow(non_snake_case)]
 fn test_N1_Pow_N1() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1PowN1 = <<A as Pow<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1PowN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Cmp_N1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1CmpN1 = <A as Cmp<B>>::Output;
+    assert_eq!(<N1CmpN1 as Ord>::to_ordering(), Ordering::Equal);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitAnd__0() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N1BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitOr__0() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = Z0;
     type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1PowN1 = <<A as Pow<B>>::Output as Same<N1>>::Output;
+    type N1BitOr_0 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N1PowN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<N1BitOr_0 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Cmp_N1() {
+fn test_N1_BitXor__0() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UTerm, B1>>;
+    type B = Z0;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1CmpN1 = <A as Cmp<B>>::Output;
-    assert_eq!(<N1CmpN1 as Ord>::to_ordering(), Ordering::Equal);
+    type N1BitXor_0 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitXor_0 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -12577,6 +19995,66 @@ fn test_N1_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_Shl_0() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = UTerm;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1ShlU0 = <<A as Shl<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1ShlU0 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Shr_0() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = UTerm;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1ShrU0 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1ShrU0 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitAnd_P1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitAndP1 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N1BitAndP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitOr_P1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitOrP1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitOrP1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitXor_P1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitXorP1 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N1BitXorP1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Add_P1() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -12673,6 +20151,66 @@ fn test_N1_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_DivFloor_P1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorP1 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivFloorP1 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_P1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilP1 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivCeilP1 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_P1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidP1 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_P1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_PartialDiv_P1() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -12710,6 +20248,66 @@ fn test_N1_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_Shl_1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = UInt<UTerm, B1>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1ShlU1 = <<A as Shl<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N1ShlU1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Shr_1() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = UInt<UTerm, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1ShrU1 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1ShrU1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitAnd_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitAndP2 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N1BitAndP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitOr_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitOrP2 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitOrP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitXor_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitXorP2 = <<A as BitXor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<N1BitXorP2 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Add_P2() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -12740,91 +20338,211 @@ fn test_N1_Mul_P2() {
     type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1MulP2 = <<A as Mul<B>>::Output as Same<N2>>::Output;
+    type N1MulP2 = <<A as Mul<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<N1MulP2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Min_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1MinP2 = <<A as Min<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1MinP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Max_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<N1MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Gcd_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1GcdP2 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N1GcdP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Div_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivP2 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N1DivP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Rem_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemP2 = <<A as Rem<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1RemP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivFloor_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorP2 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivFloorP2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilP2 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1DivCeilP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidP2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_P2() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N1MulP2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(
+        <N1RemEuclidP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Min_P2() {
+fn test_N1_Pow_P2() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1MinP2 = <<A as Min<B>>::Output as Same<N1>>::Output;
+    type N1PowP2 = <<A as Pow<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<N1MinP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<N1PowP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Max_P2() {
+fn test_N1_Cmp_P2() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
-
-    assert_eq!(<N1MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    type N1CmpP2 = <A as Cmp<B>>::Output;
+    assert_eq!(<N1CmpP2 as Ord>::to_ordering(), Ordering::Less);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Gcd_P2() {
+fn test_N1_Shl_2() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1GcdP2 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type N1ShlU2 = <<A as Shl<B>>::Output as Same<N4>>::Output;
 
-    assert_eq!(<N1GcdP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<N1ShlU2 as Integer>::to_i64(), <N4 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Div_P2() {
+fn test_N1_Shr_2() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type _0 = Z0;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1DivP2 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type N1ShrU2 = <<A as Shr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N1DivP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<N1ShrU2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Rem_P2() {
+fn test_N1_BitAnd_P3() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1RemP2 = <<A as Rem<B>>::Output as Same<N1>>::Output;
+    type N1BitAndP3 = <<A as BitAnd<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<N1RemP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<N1BitAndP3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Pow_P2() {
+fn test_N1_BitOr_P3() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1PowP2 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+    type N1BitOrP3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N1PowP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<N1BitOrP3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Cmp_P2() {
+fn test_N1_BitXor_P3() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1CmpP2 = <A as Cmp<B>>::Output;
-    assert_eq!(<N1CmpP2 as Ord>::to_ordering(), Ordering::Less);
+    type N1BitXorP3 = <<A as BitXor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<N1BitXorP3 as Integer>::to_i64(), <N4 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -12924,6 +20642,66 @@ fn test_N1_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_DivFloor_P3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorP3 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivFloorP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_P3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilP3 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1DivCeilP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_P3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidP3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_P3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidP3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Pow_P3() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -12946,6 +20724,66 @@ fn test_N1_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_Shl_3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1ShlU3 = <<A as Shl<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<N1ShlU3 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Shr_3() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1ShrU3 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1ShrU3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitAnd_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitAndP4 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<N1BitAndP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitOr_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitOrP4 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1BitOrP4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_BitXor_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1BitXorP4 = <<A as BitXor<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<N1BitXorP4 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Add_P4() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -13000,67 +20838,187 @@ fn test_N1_Max_P4() {
     type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1MaxP4 = <<A as Max<B>>::Output as Same<P4>>::Output;
-
-    assert_eq!(<N1MaxP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+    type N1MaxP4 = <<A as Max<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<N1MaxP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Gcd_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1GcdP4 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N1GcdP4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Div_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivP4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<N1DivP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Rem_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemP4 = <<A as Rem<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1RemP4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivFloor_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorP4 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivFloorP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilP4 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1DivCeilP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidP4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidP4 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Pow_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1PowP4 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<N1PowP4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Cmp_P4() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<N1CmpP4 as Ord>::to_ordering(), Ordering::Less);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Gcd_P4() {
+fn test_N1_Shl_4() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N16 = NInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1GcdP4 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type N1ShlU4 = <<A as Shl<B>>::Output as Same<N16>>::Output;
 
-    assert_eq!(<N1GcdP4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<N1ShlU4 as Integer>::to_i64(), <N16 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Div_P4() {
+fn test_N1_Shr_4() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type _0 = Z0;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1DivP4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type N1ShrU4 = <<A as Shr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N1DivP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<N1ShrU4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Rem_P4() {
+fn test_N1_BitAnd_P5() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1RemP4 = <<A as Rem<B>>::Output as Same<N1>>::Output;
+    type N1BitAndP5 = <<A as BitAnd<B>>::Output as Same<P5>>::Output;
 
-    assert_eq!(<N1RemP4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<N1BitAndP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Pow_P4() {
+fn test_N1_BitOr_P5() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type N1PowP4 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+    type N1BitOrP5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<N1PowP4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<N1BitOrP5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_N1_Cmp_P4() {
+fn test_N1_BitXor_P5() {
     type A = NInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type N1CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<N1CmpP4 as Ord>::to_ordering(), Ordering::Less);
+    type N1BitXorP5 = <<A as BitXor<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<N1BitXorP5 as Integer>::to_i64(), <N6 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -13160,6 +21118,66 @@ fn test_N1_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_DivFloor_P5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivFloorP5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivFloorP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivCeil_P5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type N1DivCeilP5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <N1DivCeilP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_DivEuclid_P5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <N1DivEuclidP5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_RemEuclid_P5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <N1RemEuclidP5 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Pow_P5() {
     type A = NInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -13182,6 +21200,66 @@ fn test_N1_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_Shl_5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N32 = NInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type N1ShlU5 = <<A as Shl<B>>::Output as Same<N32>>::Output;
+
+    assert_eq!(<N1ShlU5 as Integer>::to_i64(), <N32 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_N1_Shr_5() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type N1ShrU5 = <<A as Shr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<N1ShrU5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitAnd_N5() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndN5 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndN5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_N5() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrN5 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<_0BitOrN5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_N5() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorN5 = <<A as BitXor<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<_0BitXorN5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Add_N5() {
     type A = Z0;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -13278,6 +21356,66 @@ fn test__0_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_DivFloor_N5() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorN5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_N5() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilN5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_N5() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_N5() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0RemEuclidN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_PartialDiv_N5() {
     type A = Z0;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -13303,6 +21441,42 @@ fn test__0_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_BitAnd_N4() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndN4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_N4() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrN4 = <<A as BitOr<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<_0BitOrN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_N4() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorN4 = <<A as BitXor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<_0BitXorN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Add_N4() {
     type A = Z0;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -13387,15 +21561,75 @@ fn test__0_Div_N4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Rem_N4() {
+fn test__0_Rem_N4() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemN4 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0RemN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivFloor_N4() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorN4 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_N4() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilN4 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_N4() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_N4() {
     type A = Z0;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0RemN4 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type _0RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<_0RemN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <_0RemEuclidN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
@@ -13424,6 +21658,42 @@ fn test__0_Cmp_N4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_BitAnd_N3() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndN3 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndN3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_N3() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrN3 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<_0BitOrN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_N3() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorN3 = <<A as BitXor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<_0BitXorN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Add_N3() {
     type A = Z0;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -13520,6 +21790,66 @@ fn test__0_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_DivFloor_N3() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorN3 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_N3() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilN3 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_N3() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_N3() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0RemEuclidN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_PartialDiv_N3() {
     type A = Z0;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -13545,6 +21875,42 @@ fn test__0_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_BitAnd_N2() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndN2 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndN2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_N2() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrN2 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<_0BitOrN2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_N2() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorN2 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<_0BitXorN2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Add_N2() {
     type A = Z0;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -13641,6 +22007,66 @@ fn test__0_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_DivFloor_N2() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorN2 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_N2() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilN2 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_N2() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_N2() {
+    type A = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0RemEuclidN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_PartialDiv_N2() {
     type A = Z0;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -13666,6 +22092,42 @@ fn test__0_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_BitAnd_N1() {
+    type A = Z0;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndN1 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_N1() {
+    type A = Z0;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<_0BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_N1() {
+    type A = Z0;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorN1 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<_0BitXorN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Add_N1() {
     type A = Z0;
     type B = NInt<UInt<UTerm, B1>>;
@@ -13762,6 +22224,66 @@ fn test__0_Rem_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_DivFloor_N1() {
+    type A = Z0;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorN1 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_N1() {
+    type A = Z0;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilN1 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_N1() {
+    type A = Z0;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_N1() {
+    type A = Z0;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_PartialDiv_N1() {
     type A = Z0;
     type B = NInt<UInt<UTerm, B1>>;
@@ -13777,13 +22299,49 @@ fn test__0_PartialDiv_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Cmp_N1() {
+fn test__0_Cmp_N1() {
+    type A = Z0;
+    type B = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0CmpN1 = <A as Cmp<B>>::Output;
+    assert_eq!(<_0CmpN1 as Ord>::to_ordering(), Ordering::Greater);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitAnd__0() {
+    type A = Z0;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr__0() {
+    type A = Z0;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOr_0 = <<A as BitOr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitOr_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor__0() {
     type A = Z0;
-    type B = NInt<UInt<UTerm, B1>>;
+    type B = Z0;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0CmpN1 = <A as Cmp<B>>::Output;
-    assert_eq!(<_0CmpN1 as Ord>::to_ordering(), Ordering::Greater);
+    type _0BitXor_0 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitXor_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -13881,6 +22439,66 @@ fn test__0_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_Shl_0() {
+    type A = Z0;
+    type B = UTerm;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShlU0 = <<A as Shl<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShlU0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Shr_0() {
+    type A = Z0;
+    type B = UTerm;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShrU0 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShrU0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitAnd_P1() {
+    type A = Z0;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndP1 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndP1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_P1() {
+    type A = Z0;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrP1 = <<A as BitOr<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<_0BitOrP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_P1() {
+    type A = Z0;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorP1 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<_0BitXorP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Add_P1() {
     type A = Z0;
     type B = PInt<UInt<UTerm, B1>>;
@@ -13977,6 +22595,66 @@ fn test__0_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_DivFloor_P1() {
+    type A = Z0;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorP1 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_P1() {
+    type A = Z0;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilP1 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_P1() {
+    type A = Z0;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_P1() {
+    type A = Z0;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_PartialDiv_P1() {
     type A = Z0;
     type B = PInt<UInt<UTerm, B1>>;
@@ -14014,6 +22692,66 @@ fn test__0_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_Shl_1() {
+    type A = Z0;
+    type B = UInt<UTerm, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShlU1 = <<A as Shl<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShlU1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Shr_1() {
+    type A = Z0;
+    type B = UInt<UTerm, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShrU1 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShrU1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitAnd_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndP2 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrP2 = <<A as BitOr<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<_0BitOrP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorP2 = <<A as BitXor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<_0BitXorP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Add_P2() {
     type A = Z0;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -14026,124 +22764,244 @@ fn test__0_Add_P2() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Sub_P2() {
+fn test__0_Sub_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0SubP2 = <<A as Sub<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<_0SubP2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Mul_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0MulP2 = <<A as Mul<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0MulP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Min_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0MinP2 = <<A as Min<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0MinP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Max_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<_0MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Gcd_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0GcdP2 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<_0GcdP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Div_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivP2 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0DivP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Rem_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemP2 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0RemP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivFloor_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorP2 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_P2() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilP2 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_P2() {
     type A = Z0;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0SubP2 = <<A as Sub<B>>::Output as Same<N2>>::Output;
+    type _0DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<_0SubP2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(
+        <_0DivEuclidP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Mul_P2() {
+fn test__0_RemEuclid_P2() {
     type A = Z0;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0MulP2 = <<A as Mul<B>>::Output as Same<_0>>::Output;
+    type _0RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<_0MulP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <_0RemEuclidP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Min_P2() {
+fn test__0_PartialDiv_P2() {
     type A = Z0;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0MinP2 = <<A as Min<B>>::Output as Same<_0>>::Output;
+    type _0PartialDivP2 = <<A as PartialDiv<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<_0MinP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <_0PartialDivP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Max_P2() {
+fn test__0_Pow_P2() {
     type A = Z0;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
+    type _0PowP2 = <<A as Pow<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<_0MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(<_0PowP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Gcd_P2() {
+fn test__0_Cmp_P2() {
     type A = Z0;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type _0GcdP2 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
-
-    assert_eq!(<_0GcdP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    type _0CmpP2 = <A as Cmp<B>>::Output;
+    assert_eq!(<_0CmpP2 as Ord>::to_ordering(), Ordering::Less);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Div_P2() {
+fn test__0_Shl_2() {
     type A = Z0;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0DivP2 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type _0ShlU2 = <<A as Shl<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<_0DivP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<_0ShlU2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Rem_P2() {
+fn test__0_Shr_2() {
     type A = Z0;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0RemP2 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type _0ShrU2 = <<A as Shr<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<_0RemP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<_0ShrU2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_PartialDiv_P2() {
+fn test__0_BitAnd_P3() {
     type A = Z0;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type _0PartialDivP2 = <<A as PartialDiv<B>>::Output as Same<_0>>::Output;
+    type _0BitAndP3 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(
-        <_0PartialDivP2 as Integer>::to_i64(),
-        <_0 as Integer>::to_i64()
-    );
+    assert_eq!(<_0BitAndP3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Pow_P2() {
+fn test__0_BitOr_P3() {
     type A = Z0;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type _0 = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type _0PowP2 = <<A as Pow<B>>::Output as Same<_0>>::Output;
+    type _0BitOrP3 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<_0PowP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<_0BitOrP3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test__0_Cmp_P2() {
+fn test__0_BitXor_P3() {
     type A = Z0;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type _0CmpP2 = <A as Cmp<B>>::Output;
-    assert_eq!(<_0CmpP2 as Ord>::to_ordering(), Ordering::Less);
+    type _0BitXorP3 = <<A as BitXor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<_0BitXorP3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -14243,6 +23101,66 @@ fn test__0_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_DivFloor_P3() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorP3 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_P3() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilP3 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_P3() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_P3() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0RemEuclidP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_PartialDiv_P3() {
     type A = Z0;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -14280,6 +23198,66 @@ fn test__0_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_Shl_3() {
+    type A = Z0;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShlU3 = <<A as Shl<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShlU3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Shr_3() {
+    type A = Z0;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShrU3 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShrU3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitAnd_P4() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndP4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_P4() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrP4 = <<A as BitOr<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<_0BitOrP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_P4() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorP4 = <<A as BitXor<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<_0BitXorP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Add_P4() {
     type A = Z0;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -14376,6 +23354,66 @@ fn test__0_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_DivFloor_P4() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorP4 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_P4() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilP4 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_P4() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_P4() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0RemEuclidP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_PartialDiv_P4() {
     type A = Z0;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -14408,8 +23446,68 @@ fn test__0_Cmp_P4() {
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type _0CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<_0CmpP4 as Ord>::to_ordering(), Ordering::Less);
+    type _0CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<_0CmpP4 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Shl_4() {
+    type A = Z0;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShlU4 = <<A as Shl<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShlU4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Shr_4() {
+    type A = Z0;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShrU4 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShrU4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitAnd_P5() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0BitAndP5 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0BitAndP5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitOr_P5() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitOrP5 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<_0BitOrP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_BitXor_P5() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type _0BitXorP5 = <<A as BitXor<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<_0BitXorP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -14509,6 +23607,66 @@ fn test__0_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_DivFloor_P5() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivFloorP5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivFloorP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivCeil_P5() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivCeilP5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivCeilP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_DivEuclid_P5() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0DivEuclidP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_RemEuclid_P5() {
+    type A = Z0;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <_0RemEuclidP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_PartialDiv_P5() {
     type A = Z0;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -14546,6 +23704,66 @@ fn test__0_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_Shl_5() {
+    type A = Z0;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShlU5 = <<A as Shl<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShlU5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_Shr_5() {
+    type A = Z0;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type _0ShrU5 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<_0ShrU5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitAnd_N5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAndN5 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1BitAndN5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr_N5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitOrN5 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<P1BitOrN5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitXor_N5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitXorN5 = <<A as BitXor<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<P1BitXorN5 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Add_N5() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -14642,6 +23860,66 @@ fn test_P1_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_DivFloor_N5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorN5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P1DivFloorN5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_N5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilN5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivCeilN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_N5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_N5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidN5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Pow_N5() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -14664,6 +23942,42 @@ fn test_P1_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_BitAnd_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAndN4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1BitAndN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitOrN4 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<P1BitOrN4 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitXor_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitXorN4 = <<A as BitXor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<P1BitXorN4 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Add_N4() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -14736,49 +24050,145 @@ fn test_P1_Gcd_N4() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Div_N4() {
+fn test_P1_Div_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Rem_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemN4 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1RemN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivFloor_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorN4 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P1DivFloorN4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilN4 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivCeilN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Pow_N4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1PowN4 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1PowN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Cmp_N4() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P1DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
-
-    assert_eq!(<P1DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    type P1CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P1CmpN4 as Ord>::to_ordering(), Ordering::Greater);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Rem_N4() {
+fn test_P1_BitAnd_N3() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1RemN4 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+    type P1BitAndN3 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P1RemN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P1BitAndN3 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Pow_N4() {
+fn test_P1_BitOr_N3() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1PowN4 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+    type P1BitOrN3 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
 
-    assert_eq!(<P1PowN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P1BitOrN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Cmp_N4() {
+fn test_P1_BitXor_N3() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P1CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P1CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+    type P1BitXorN3 = <<A as BitXor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<P1BitXorN3 as Integer>::to_i64(), <N4 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -14878,6 +24288,66 @@ fn test_P1_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_DivFloor_N3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorN3 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P1DivFloorN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_N3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilN3 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivCeilN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_N3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_N3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Pow_N3() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -14900,6 +24370,42 @@ fn test_P1_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_BitAnd_N2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAndN2 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1BitAndN2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr_N2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitOrN2 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P1BitOrN2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitXor_N2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitXorN2 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P1BitXorN2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Add_N2() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -14996,6 +24502,66 @@ fn test_P1_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_DivFloor_N2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorN2 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P1DivFloorN2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_N2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilN2 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivCeilN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_N2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_N2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Pow_N2() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -15018,6 +24584,42 @@ fn test_P1_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_BitAnd_N1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAndN1 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1BitAndN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr_N1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P1BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitXor_N1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitXorN1 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<P1BitXorN1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Add_N1() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -15114,6 +24716,66 @@ fn test_P1_Rem_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_DivFloor_N1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorN1 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P1DivFloorN1 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_N1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilN1 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P1DivCeilN1 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_N1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidN1 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_N1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_PartialDiv_N1() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -15131,23 +24793,59 @@ fn test_P1_PartialDiv_N1() {
 #[allow(non_snake_case)]
 fn test_P1_Pow_N1() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1PowN1 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1PowN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Cmp_N1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1CmpN1 = <A as Cmp<B>>::Output;
+    assert_eq!(<P1CmpN1 as Ord>::to_ordering(), Ordering::Greater);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitAnd__0() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr__0() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = Z0;
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1PowN1 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+    type P1BitOr_0 = <<A as BitOr<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P1PowN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P1BitOr_0 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Cmp_N1() {
+fn test_P1_BitXor__0() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = NInt<UInt<UTerm, B1>>;
+    type B = Z0;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1CmpN1 = <A as Cmp<B>>::Output;
-    assert_eq!(<P1CmpN1 as Ord>::to_ordering(), Ordering::Greater);
+    type P1BitXor_0 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1BitXor_0 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -15245,6 +24943,66 @@ fn test_P1_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_Shl_0() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UTerm;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1ShlU0 = <<A as Shl<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1ShlU0 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Shr_0() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UTerm;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1ShrU0 = <<A as Shr<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1ShrU0 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitAnd_P1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAndP1 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1BitAndP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr_P1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitOrP1 = <<A as BitOr<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1BitOrP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitXor_P1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1BitXorP1 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1BitXorP1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Add_P1() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -15341,6 +25099,66 @@ fn test_P1_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_DivFloor_P1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorP1 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1DivFloorP1 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_P1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilP1 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1DivCeilP1 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_P1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidP1 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_P1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_PartialDiv_P1() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -15378,121 +25196,301 @@ fn test_P1_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Add_P2() {
+fn test_P1_Shl_1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UTerm, B1>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1ShlU1 = <<A as Shl<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P1ShlU1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Shr_1() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UTerm, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1ShrU1 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1ShrU1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitAnd_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAndP2 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1BitAndP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitOrP2 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P1BitOrP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitXor_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitXorP2 = <<A as BitXor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P1BitXorP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Add_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1AddP2 = <<A as Add<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P1AddP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Sub_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1SubP2 = <<A as Sub<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P1SubP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Mul_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1MulP2 = <<A as Mul<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P1MulP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Min_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1MinP2 = <<A as Min<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1MinP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Max_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P1MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Gcd_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1GcdP2 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1GcdP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Div_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivP2 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1DivP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Rem_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemP2 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1RemP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivFloor_P2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorP2 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivFloorP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_P2() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1AddP2 = <<A as Add<B>>::Output as Same<P3>>::Output;
+    type P1DivCeilP2 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P1AddP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+    assert_eq!(
+        <P1DivCeilP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Sub_P2() {
+fn test_P1_DivEuclid_P2() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P1SubP2 = <<A as Sub<B>>::Output as Same<N1>>::Output;
+    type P1DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P1SubP2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(
+        <P1DivEuclidP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Mul_P2() {
+fn test_P1_RemEuclid_P2() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1MulP2 = <<A as Mul<B>>::Output as Same<P2>>::Output;
+    type P1RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P1MulP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <P1RemEuclidP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Min_P2() {
+fn test_P1_Pow_P2() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1MinP2 = <<A as Min<B>>::Output as Same<P1>>::Output;
+    type P1PowP2 = <<A as Pow<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P1MinP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P1PowP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Max_P2() {
+fn test_P1_Cmp_P2() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P1MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
-
-    assert_eq!(<P1MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    type P1CmpP2 = <A as Cmp<B>>::Output;
+    assert_eq!(<P1CmpP2 as Ord>::to_ordering(), Ordering::Less);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Gcd_P2() {
+fn test_P1_Shl_2() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P1GcdP2 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type P1ShlU2 = <<A as Shl<B>>::Output as Same<P4>>::Output;
 
-    assert_eq!(<P1GcdP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P1ShlU2 as Integer>::to_i64(), <P4 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Div_P2() {
+fn test_P1_Shr_2() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P1DivP2 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type P1ShrU2 = <<A as Shr<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P1DivP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<P1ShrU2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Rem_P2() {
+fn test_P1_BitAnd_P3() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1RemP2 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+    type P1BitAndP3 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P1RemP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P1BitAndP3 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Pow_P2() {
+fn test_P1_BitOr_P3() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P1PowP2 = <<A as Pow<B>>::Output as Same<P1>>::Output;
+    type P1BitOrP3 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<P1PowP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P1BitOrP3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P1_Cmp_P2() {
+fn test_P1_BitXor_P3() {
     type A = PInt<UInt<UTerm, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P1CmpP2 = <A as Cmp<B>>::Output;
-    assert_eq!(<P1CmpP2 as Ord>::to_ordering(), Ordering::Less);
+    type P1BitXorP3 = <<A as BitXor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P1BitXorP3 as Integer>::to_i64(), <P2 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -15592,6 +25590,66 @@ fn test_P1_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_DivFloor_P3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorP3 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivFloorP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_P3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilP3 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1DivCeilP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_P3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_P3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Pow_P3() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -15614,6 +25672,66 @@ fn test_P1_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_Shl_3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type P8 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1ShlU3 = <<A as Shl<B>>::Output as Same<P8>>::Output;
+
+    assert_eq!(<P1ShlU3 as Integer>::to_i64(), <P8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Shr_3() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1ShrU3 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1ShrU3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitAnd_P4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAndP4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1BitAndP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr_P4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitOrP4 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P1BitOrP4 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitXor_P4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitXorP4 = <<A as BitXor<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P1BitXorP4 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Add_P4() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -15710,6 +25828,66 @@ fn test_P1_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_DivFloor_P4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorP4 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivFloorP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_P4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilP4 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1DivCeilP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_P4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_P4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Pow_P4() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -15727,8 +25905,68 @@ fn test_P1_Cmp_P4() {
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P1CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P1CmpP4 as Ord>::to_ordering(), Ordering::Less);
+    type P1CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P1CmpP4 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Shl_4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type P16 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1ShlU4 = <<A as Shl<B>>::Output as Same<P16>>::Output;
+
+    assert_eq!(<P1ShlU4 as Integer>::to_i64(), <P16 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Shr_4() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1ShrU4 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1ShrU4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitAnd_P5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitAndP5 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P1BitAndP5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitOr_P5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitOrP5 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P1BitOrP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_BitXor_P5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1BitXorP5 = <<A as BitXor<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P1BitXorP5 as Integer>::to_i64(), <P4 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -15828,6 +26066,66 @@ fn test_P1_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_DivFloor_P5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivFloorP5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivFloorP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivCeil_P5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1DivCeilP5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1DivCeilP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_DivEuclid_P5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P1DivEuclidP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_RemEuclid_P5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P1RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P1RemEuclidP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Pow_P5() {
     type A = PInt<UInt<UTerm, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -15850,6 +26148,66 @@ fn test_P1_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_Shl_5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type P32 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P1ShlU5 = <<A as Shl<B>>::Output as Same<P32>>::Output;
+
+    assert_eq!(<P1ShlU5 as Integer>::to_i64(), <P32 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Shr_5() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P1ShrU5 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P1ShrU5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitAnd_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndN5 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2BitAndN5 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrN5 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<P2BitOrN5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorN5 = <<A as BitXor<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<P2BitXorN5 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add_N5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -15946,6 +26304,66 @@ fn test_P2_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_DivFloor_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivFloorN5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P2DivFloorN5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivCeil_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivCeilN5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivCeilN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivEuclid_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivEuclidN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_RemEuclid_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P2RemEuclidN5 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Cmp_N5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -15956,6 +26374,42 @@ fn test_P2_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_BitAnd_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndN4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2BitAndN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrN4 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<P2BitOrN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorN4 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<P2BitXorN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add_N4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -15964,101 +26418,197 @@ fn test_P2_Add_N4() {
     #[allow(non_camel_case_types)]
     type P2AddN4 = <<A as Add<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<P2AddN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(<P2AddN4 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Sub_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2SubN4 = <<A as Sub<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P2SubN4 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Mul_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2MulN4 = <<A as Mul<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<P2MulN4 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Min_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2MinN4 = <<A as Min<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<P2MinN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Max_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2MaxN4 = <<A as Max<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2MaxN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Gcd_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2GcdN4 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2GcdN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Div_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Rem_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2RemN4 = <<A as Rem<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2RemN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Sub_N4() {
+fn test_P2_DivFloor_N4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P2SubN4 = <<A as Sub<B>>::Output as Same<P6>>::Output;
+    type P2DivFloorN4 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<P2SubN4 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivFloorN4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Mul_N4() {
+fn test_P2_DivCeil_N4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P2MulN4 = <<A as Mul<B>>::Output as Same<N8>>::Output;
+    type P2DivCeilN4 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P2MulN4 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivCeilN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Min_N4() {
+fn test_P2_DivEuclid_N4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P2MinN4 = <<A as Min<B>>::Output as Same<N4>>::Output;
+    type P2DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P2MinN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivEuclidN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Max_N4() {
+fn test_P2_RemEuclid_N4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P2MaxN4 = <<A as Max<B>>::Output as Same<P2>>::Output;
+    type P2RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<P2MaxN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <P2RemEuclidN4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Gcd_N4() {
+fn test_P2_Cmp_N4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P2GcdN4 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
-
-    assert_eq!(<P2GcdN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    type P2CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P2CmpN4 as Ord>::to_ordering(), Ordering::Greater);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Div_N4() {
+fn test_P2_BitAnd_N3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P2DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type P2BitAndN3 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P2DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<P2BitAndN3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Rem_N4() {
+fn test_P2_BitOr_N3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P2RemN4 = <<A as Rem<B>>::Output as Same<P2>>::Output;
+    type P2BitOrN3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<P2RemN4 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(<P2BitOrN3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Cmp_N4() {
+fn test_P2_BitXor_N3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P2CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P2CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+    type P2BitXorN3 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P2BitXorN3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -16158,6 +26708,66 @@ fn test_P2_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_DivFloor_N3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivFloorN3 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P2DivFloorN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivCeil_N3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivCeilN3 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivCeilN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivEuclid_N3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivEuclidN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_RemEuclid_N3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P2RemEuclidN3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Cmp_N3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -16168,6 +26778,42 @@ fn test_P2_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_BitAnd_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndN2 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2BitAndN2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrN2 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<P2BitOrN2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorN2 = <<A as BitXor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<P2BitXorN2 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add_N2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -16264,6 +26910,66 @@ fn test_P2_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_DivFloor_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivFloorN2 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P2DivFloorN2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivCeil_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivCeilN2 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P2DivCeilN2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivEuclid_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P2DivEuclidN2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_RemEuclid_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2RemEuclidN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_PartialDiv_N2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -16289,6 +26995,42 @@ fn test_P2_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_BitAnd_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndN1 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2BitAndN1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P2BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorN1 = <<A as BitXor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<P2BitXorN1 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -16309,79 +27051,139 @@ fn test_P2_Sub_N1() {
     #[allow(non_camel_case_types)]
     type P2SubN1 = <<A as Sub<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<P2SubN1 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+    assert_eq!(<P2SubN1 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Mul_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2MulN1 = <<A as Mul<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<P2MulN1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Min_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2MinN1 = <<A as Min<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P2MinN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Max_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2MaxN1 = <<A as Max<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2MaxN1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Gcd_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2GcdN1 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P2GcdN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Mul_N1() {
+fn test_P2_Div_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P2MulN1 = <<A as Mul<B>>::Output as Same<N2>>::Output;
+    type P2DivN1 = <<A as Div<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<P2MulN1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(<P2DivN1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Min_N1() {
+fn test_P2_Rem_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P2MinN1 = <<A as Min<B>>::Output as Same<N1>>::Output;
+    type P2RemN1 = <<A as Rem<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P2MinN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<P2RemN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Max_N1() {
+fn test_P2_DivFloor_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P2MaxN1 = <<A as Max<B>>::Output as Same<P2>>::Output;
+    type P2DivFloorN1 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<P2MaxN1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivFloorN1 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Gcd_N1() {
+fn test_P2_DivCeil_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P2GcdN1 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type P2DivCeilN1 = <<A as DivCeil<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<P2GcdN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivCeilN1 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Div_N1() {
+fn test_P2_DivEuclid_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P2DivN1 = <<A as Div<B>>::Output as Same<N2>>::Output;
+    type P2DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
 
-    assert_eq!(<P2DivN1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivEuclidN1 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Rem_N1() {
+fn test_P2_RemEuclid_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P2RemN1 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type P2RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P2RemN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <P2RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
@@ -16410,6 +27212,42 @@ fn test_P2_Cmp_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_BitAnd__0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr__0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = Z0;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOr_0 = <<A as BitOr<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2BitOr_0 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor__0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = Z0;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXor_0 = <<A as BitXor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2BitXor_0 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add__0() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = Z0;
@@ -16504,6 +27342,66 @@ fn test_P2_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_Shl_0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UTerm;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2ShlU0 = <<A as Shl<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2ShlU0 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Shr_0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UTerm;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2ShrU0 = <<A as Shr<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2ShrU0 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitAnd_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndP1 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2BitAndP1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrP1 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P2BitOrP1 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorP1 = <<A as BitXor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P2BitXorP1 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add_P1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -16600,6 +27498,66 @@ fn test_P2_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_DivFloor_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivFloorP1 = <<A as DivFloor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P2DivFloorP1 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivCeil_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivCeilP1 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P2DivCeilP1 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivEuclid_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P2DivEuclidP1 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_RemEuclid_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_PartialDiv_P1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -16637,6 +27595,66 @@ fn test_P2_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_Shl_1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UTerm, B1>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2ShlU1 = <<A as Shl<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P2ShlU1 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Shr_1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UTerm, B1>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2ShrU1 = <<A as Shr<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P2ShrU1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitAnd_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndP2 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2BitAndP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrP2 = <<A as BitOr<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2BitOrP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorP2 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2BitXorP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add_P2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -16691,45 +27709,105 @@ fn test_P2_Max_P2() {
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P2MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
+    type P2MaxP2 = <<A as Max<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Gcd_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2GcdP2 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2GcdP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Div_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivP2 = <<A as Div<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P2DivP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Rem_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2RemP2 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2RemP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivFloor_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivFloorP2 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P2MaxP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivFloorP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Gcd_P2() {
+fn test_P2_DivCeil_P2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P2GcdP2 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+    type P2DivCeilP2 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P2GcdP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivCeilP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Div_P2() {
+fn test_P2_DivEuclid_P2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P2DivP2 = <<A as Div<B>>::Output as Same<P1>>::Output;
+    type P2DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P2DivP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(
+        <P2DivEuclidP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P2_Rem_P2() {
+fn test_P2_RemEuclid_P2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P2RemP2 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type P2RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P2RemP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <P2RemEuclidP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
@@ -16770,6 +27848,66 @@ fn test_P2_Cmp_P2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_Shl_2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type P8 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2ShlU2 = <<A as Shl<B>>::Output as Same<P8>>::Output;
+
+    assert_eq!(<P2ShlU2 as Integer>::to_i64(), <P8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Shr_2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2ShrU2 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2ShrU2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitAnd_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndP3 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P2BitAndP3 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrP3 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P2BitOrP3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorP3 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P2BitXorP3 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add_P3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -16866,6 +28004,66 @@ fn test_P2_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_DivFloor_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivFloorP3 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivFloorP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivCeil_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivCeilP3 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P2DivCeilP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivEuclid_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivEuclidP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_RemEuclid_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P2RemEuclidP3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Pow_P3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -16888,6 +28086,66 @@ fn test_P2_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_Shl_3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type P16 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2ShlU3 = <<A as Shl<B>>::Output as Same<P16>>::Output;
+
+    assert_eq!(<P2ShlU3 as Integer>::to_i64(), <P16 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Shr_3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2ShrU3 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2ShrU3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitAnd_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndP4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2BitAndP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrP4 = <<A as BitOr<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P2BitOrP4 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorP4 = <<A as BitXor<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P2BitXorP4 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Add_P4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -16984,6 +28242,66 @@ fn test_P2_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_DivFloor_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivFloorP4 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivFloorP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivCeil_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivCeilP4 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P2DivCeilP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivEuclid_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivEuclidP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_RemEuclid_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P2RemEuclidP4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Pow_P4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -17001,8 +28319,68 @@ fn test_P2_Cmp_P4() {
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P2CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P2CmpP4 as Ord>::to_ordering(), Ordering::Less);
+    type P2CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P2CmpP4 as Ord>::to_ordering(), Ordering::Less);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Shl_4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type P32 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2ShlU4 = <<A as Shl<B>>::Output as Same<P32>>::Output;
+
+    assert_eq!(<P2ShlU4 as Integer>::to_i64(), <P32 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Shr_4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2ShrU4 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2ShrU4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitAnd_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2BitAndP5 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2BitAndP5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitOr_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitOrP5 = <<A as BitOr<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P2BitOrP5 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_BitXor_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2BitXorP5 = <<A as BitXor<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P2BitXorP5 as Integer>::to_i64(), <P7 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -17102,6 +28480,66 @@ fn test_P2_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_DivFloor_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivFloorP5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivFloorP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivCeil_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P2DivCeilP5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P2DivCeilP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_DivEuclid_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P2DivEuclidP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_RemEuclid_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P2RemEuclidP5 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Pow_P5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -17124,6 +28562,66 @@ fn test_P2_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_Shl_5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type P64 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P2ShlU5 = <<A as Shl<B>>::Output as Same<P64>>::Output;
+
+    assert_eq!(<P2ShlU5 as Integer>::to_i64(), <P64 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Shr_5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P2ShrU5 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P2ShrU5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitAnd_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitAndN5 = <<A as BitAnd<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3BitAndN5 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitOr_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitOrN5 = <<A as BitOr<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<P3BitOrN5 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitXor_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitXorN5 = <<A as BitXor<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<P3BitXorN5 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Add_N5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -17220,6 +28718,66 @@ fn test_P3_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_DivFloor_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorN5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P3DivFloorN5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilN5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3DivCeilN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_N5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidN5 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Cmp_N5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -17230,6 +28788,42 @@ fn test_P3_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_BitAnd_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3BitAndN4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P3BitAndN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitOr_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitOrN4 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P3BitOrN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitXor_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitXorN4 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P3BitXorN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Add_N4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -17296,43 +28890,139 @@ fn test_P3_Gcd_N4() {
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3GcdN4 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
-
-    assert_eq!(<P3GcdN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    type P3GcdN4 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P3GcdN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Div_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P3DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Rem_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemN4 = <<A as Rem<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3RemN4 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivFloor_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorN4 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P3DivFloorN4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilN4 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3DivCeilN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidN4 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Cmp_N4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P3CmpN4 as Ord>::to_ordering(), Ordering::Greater);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Div_N4() {
+fn test_P3_BitAnd_N3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type _0 = Z0;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3DivN4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type P3BitAndN3 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P3DivN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<P3BitAndN3 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Rem_N4() {
+fn test_P3_BitOr_N3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3RemN4 = <<A as Rem<B>>::Output as Same<P3>>::Output;
+    type P3BitOrN3 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<P3RemN4 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+    assert_eq!(<P3BitOrN3 as Integer>::to_i64(), <N1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Cmp_N4() {
+fn test_P3_BitXor_N3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P3CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P3CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+    type P3BitXorN3 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<P3BitXorN3 as Integer>::to_i64(), <N2 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -17432,6 +29122,66 @@ fn test_P3_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_DivFloor_N3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorN3 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P3DivFloorN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_N3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilN3 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P3DivCeilN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_N3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_N3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidN3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_PartialDiv_N3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -17457,6 +29207,42 @@ fn test_P3_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_BitAnd_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitAndN2 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P3BitAndN2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitOr_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitOrN2 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P3BitOrN2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitXor_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitXorN2 = <<A as BitXor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<P3BitXorN2 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Add_N2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -17553,6 +29339,66 @@ fn test_P3_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_DivFloor_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorN2 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P3DivFloorN2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilN2 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P3DivCeilN2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidN2 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_N2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Cmp_N2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -17563,6 +29409,42 @@ fn test_P3_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_BitAnd_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitAndN1 = <<A as BitAnd<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3BitAndN1 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitOr_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P3BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitXor_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitXorN1 = <<A as BitXor<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<P3BitXorN1 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Add_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -17659,6 +29541,66 @@ fn test_P3_Rem_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_DivFloor_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorN1 = <<A as DivFloor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(
+        <P3DivFloorN1 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilN1 = <<A as DivCeil<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(
+        <P3DivCeilN1 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidN1 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_PartialDiv_N1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -17667,20 +29609,56 @@ fn test_P3_PartialDiv_N1() {
     #[allow(non_camel_case_types)]
     type P3PartialDivN1 = <<A as PartialDiv<B>>::Output as Same<N3>>::Output;
 
-    assert_eq!(
-        <P3PartialDivN1 as Integer>::to_i64(),
-        <N3 as Integer>::to_i64()
-    );
+    assert_eq!(
+        <P3PartialDivN1 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Cmp_N1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3CmpN1 = <A as Cmp<B>>::Output;
+    assert_eq!(<P3CmpN1 as Ord>::to_ordering(), Ordering::Greater);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitAnd__0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P3BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitOr__0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = Z0;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitOr_0 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3BitOr_0 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Cmp_N1() {
+fn test_P3_BitXor__0() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = NInt<UInt<UTerm, B1>>;
+    type B = Z0;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3CmpN1 = <A as Cmp<B>>::Output;
-    assert_eq!(<P3CmpN1 as Ord>::to_ordering(), Ordering::Greater);
+    type P3BitXor_0 = <<A as BitXor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3BitXor_0 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -17778,6 +29756,66 @@ fn test_P3_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_Shl_0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UTerm;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3ShlU0 = <<A as Shl<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3ShlU0 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Shr_0() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UTerm;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3ShrU0 = <<A as Shr<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3ShrU0 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitAnd_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitAndP1 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P3BitAndP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitOr_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitOrP1 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3BitOrP1 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitXor_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitXorP1 = <<A as BitXor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P3BitXorP1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Add_P1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -17874,6 +29912,66 @@ fn test_P3_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_DivFloor_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorP1 = <<A as DivFloor<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <P3DivFloorP1 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilP1 = <<A as DivCeil<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <P3DivCeilP1 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidP1 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_P1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_PartialDiv_P1() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -17911,6 +30009,66 @@ fn test_P3_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_Shl_1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UTerm, B1>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3ShlU1 = <<A as Shl<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P3ShlU1 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Shr_1() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UTerm, B1>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3ShrU1 = <<A as Shr<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P3ShrU1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitAnd_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitAndP2 = <<A as BitAnd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P3BitAndP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitOr_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitOrP2 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3BitOrP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitXor_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitXorP2 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P3BitXorP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Add_P2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -17941,91 +30099,211 @@ fn test_P3_Mul_P2() {
     type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P3MulP2 = <<A as Mul<B>>::Output as Same<P6>>::Output;
+    type P3MulP2 = <<A as Mul<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P3MulP2 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Min_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3MinP2 = <<A as Min<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P3MinP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Max_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3MaxP2 = <<A as Max<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3MaxP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Gcd_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3GcdP2 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P3GcdP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Div_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivP2 = <<A as Div<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P3DivP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Rem_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemP2 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P3RemP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivFloor_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorP2 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P3DivFloorP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilP2 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P3DivCeilP2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_P2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P3MulP2 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+    assert_eq!(
+        <P3RemEuclidP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Min_P2() {
+fn test_P3_Pow_P2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P9 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3MinP2 = <<A as Min<B>>::Output as Same<P2>>::Output;
+    type P3PowP2 = <<A as Pow<B>>::Output as Same<P9>>::Output;
 
-    assert_eq!(<P3MinP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(<P3PowP2 as Integer>::to_i64(), <P9 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Max_P2() {
+fn test_P3_Cmp_P2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3MaxP2 = <<A as Max<B>>::Output as Same<P3>>::Output;
-
-    assert_eq!(<P3MaxP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+    type P3CmpP2 = <A as Cmp<B>>::Output;
+    assert_eq!(<P3CmpP2 as Ord>::to_ordering(), Ordering::Greater);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Gcd_P2() {
+fn test_P3_Shl_2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type P12 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P3GcdP2 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type P3ShlU2 = <<A as Shl<B>>::Output as Same<P12>>::Output;
 
-    assert_eq!(<P3GcdP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P3ShlU2 as Integer>::to_i64(), <P12 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Div_P2() {
+fn test_P3_Shr_2() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P3DivP2 = <<A as Div<B>>::Output as Same<P1>>::Output;
+    type P3ShrU2 = <<A as Shr<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P3DivP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P3ShrU2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Rem_P2() {
+fn test_P3_BitAnd_P3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3RemP2 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+    type P3BitAndP3 = <<A as BitAnd<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<P3RemP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P3BitAndP3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Pow_P2() {
+fn test_P3_BitOr_P3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P9 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3PowP2 = <<A as Pow<B>>::Output as Same<P9>>::Output;
+    type P3BitOrP3 = <<A as BitOr<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<P3PowP2 as Integer>::to_i64(), <P9 as Integer>::to_i64());
+    assert_eq!(<P3BitOrP3 as Integer>::to_i64(), <P3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Cmp_P2() {
+fn test_P3_BitXor_P3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P3CmpP2 = <A as Cmp<B>>::Output;
-    assert_eq!(<P3CmpP2 as Ord>::to_ordering(), Ordering::Greater);
+    type P3BitXorP3 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P3BitXorP3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -18125,6 +30403,66 @@ fn test_P3_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_DivFloor_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorP3 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P3DivFloorP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilP3 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P3DivCeilP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_P3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidP3 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_PartialDiv_P3() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -18162,6 +30500,66 @@ fn test_P3_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_Shl_3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type P24 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3ShlU3 = <<A as Shl<B>>::Output as Same<P24>>::Output;
+
+    assert_eq!(<P3ShlU3 as Integer>::to_i64(), <P24 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Shr_3() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3ShrU3 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P3ShrU3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitAnd_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3BitAndP4 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P3BitAndP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitOr_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitOrP4 = <<A as BitOr<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P3BitOrP4 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_BitXor_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3BitXorP4 = <<A as BitXor<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P3BitXorP4 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Add_P4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -18206,77 +30604,197 @@ fn test_P3_Min_P4() {
     #[allow(non_camel_case_types)]
     type P3MinP4 = <<A as Min<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<P3MinP4 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+    assert_eq!(<P3MinP4 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Max_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3MaxP4 = <<A as Max<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P3MaxP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Gcd_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3GcdP4 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P3GcdP4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Div_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivP4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P3DivP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Rem_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemP4 = <<A as Rem<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P3RemP4 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivFloor_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorP4 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3DivFloorP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilP4 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P3DivCeilP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidP4 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Pow_P4() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P81 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3PowP4 = <<A as Pow<B>>::Output as Same<P81>>::Output;
+
+    assert_eq!(<P3PowP4 as Integer>::to_i64(), <P81 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Max_P4() {
+fn test_P3_Cmp_P4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P3MaxP4 = <<A as Max<B>>::Output as Same<P4>>::Output;
-
-    assert_eq!(<P3MaxP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+    type P3CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P3CmpP4 as Ord>::to_ordering(), Ordering::Less);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Gcd_P4() {
+fn test_P3_Shl_4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type P48 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P3GcdP4 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type P3ShlU4 = <<A as Shl<B>>::Output as Same<P48>>::Output;
 
-    assert_eq!(<P3GcdP4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P3ShlU4 as Integer>::to_i64(), <P48 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Div_P4() {
+fn test_P3_Shr_4() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P3DivP4 = <<A as Div<B>>::Output as Same<_0>>::Output;
+    type P3ShrU4 = <<A as Shr<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P3DivP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(<P3ShrU4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Rem_P4() {
+fn test_P3_BitAnd_P5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3RemP4 = <<A as Rem<B>>::Output as Same<P3>>::Output;
+    type P3BitAndP5 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P3RemP4 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+    assert_eq!(<P3BitAndP5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Pow_P4() {
+fn test_P3_BitOr_P5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P81 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P3PowP4 = <<A as Pow<B>>::Output as Same<P81>>::Output;
+    type P3BitOrP5 = <<A as BitOr<B>>::Output as Same<P7>>::Output;
 
-    assert_eq!(<P3PowP4 as Integer>::to_i64(), <P81 as Integer>::to_i64());
+    assert_eq!(<P3BitOrP5 as Integer>::to_i64(), <P7 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P3_Cmp_P4() {
+fn test_P3_BitXor_P5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P3CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P3CmpP4 as Ord>::to_ordering(), Ordering::Less);
+    type P3BitXorP5 = <<A as BitXor<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P3BitXorP5 as Integer>::to_i64(), <P6 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -18376,6 +30894,66 @@ fn test_P3_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_DivFloor_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivFloorP5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3DivFloorP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivCeil_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3DivCeilP5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P3DivCeilP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_DivEuclid_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P3DivEuclidP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_RemEuclid_P5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P3RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(
+        <P3RemEuclidP5 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Pow_P5() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -18399,6 +30977,66 @@ fn test_P3_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_Shl_5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type P96 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P3ShlU5 = <<A as Shl<B>>::Output as Same<P96>>::Output;
+
+    assert_eq!(<P3ShlU5 as Integer>::to_i64(), <P96 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Shr_5() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P3ShrU5 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P3ShrU5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitAnd_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndN5 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4BitAndN5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrN5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P4BitOrN5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorN5 = <<A as BitXor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P4BitXorN5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_N5() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -18495,6 +31133,66 @@ fn test_P4_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_DivFloor_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivFloorN5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P4DivFloorN5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivCeil_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4DivCeilN5 = <<A as DivCeil<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P4DivCeilN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivEuclid_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P4DivEuclidN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_RemEuclid_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <P4RemEuclidN5 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Cmp_N5() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -18505,6 +31203,42 @@ fn test_P4_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_BitAnd_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndN4 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitAndN4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrN4 = <<A as BitOr<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<P4BitOrN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorN4 = <<A as BitXor<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<P4BitXorN4 as Integer>::to_i64(), <N8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_N4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -18549,55 +31283,115 @@ fn test_P4_Min_N4() {
     #[allow(non_camel_case_types)]
     type P4MinN4 = <<A as Min<B>>::Output as Same<N4>>::Output;
 
-    assert_eq!(<P4MinN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+    assert_eq!(<P4MinN4 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Max_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4MaxN4 = <<A as Max<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4MaxN4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Gcd_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4GcdN4 = <<A as Gcd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4GcdN4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Div_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivN4 = <<A as Div<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P4DivN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Rem_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4RemN4 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4RemN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Max_N4() {
+fn test_P4_DivFloor_N4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P4MaxN4 = <<A as Max<B>>::Output as Same<P4>>::Output;
+    type P4DivFloorN4 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<P4MaxN4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivFloorN4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Gcd_N4() {
+fn test_P4_DivCeil_N4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P4GcdN4 = <<A as Gcd<B>>::Output as Same<P4>>::Output;
+    type P4DivCeilN4 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<P4GcdN4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivCeilN4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Div_N4() {
+fn test_P4_DivEuclid_N4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type N1 = NInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P4DivN4 = <<A as Div<B>>::Output as Same<N1>>::Output;
+    type P4DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
 
-    assert_eq!(<P4DivN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivEuclidN4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Rem_N4() {
+fn test_P4_RemEuclid_N4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P4RemN4 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type P4RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P4RemN4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <P4RemEuclidN4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
@@ -18626,6 +31420,42 @@ fn test_P4_Cmp_N4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_BitAnd_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndN3 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitAndN3 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrN3 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<P4BitOrN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorN3 = <<A as BitXor<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<P4BitXorN3 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_N3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -18722,6 +31552,66 @@ fn test_P4_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_DivFloor_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivFloorN3 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P4DivFloorN3 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivCeil_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivCeilN3 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P4DivCeilN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivEuclid_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P4DivEuclidN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_RemEuclid_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P4RemEuclidN3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Cmp_N3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -18732,6 +31622,42 @@ fn test_P4_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_BitAnd_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndN2 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitAndN2 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrN2 = <<A as BitOr<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<P4BitOrN2 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorN2 = <<A as BitXor<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<P4BitXorN2 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_N2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -18828,6 +31754,66 @@ fn test_P4_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_DivFloor_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivFloorN2 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P4DivFloorN2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivCeil_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivCeilN2 = <<A as DivCeil<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P4DivCeilN2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivEuclid_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P4DivEuclidN2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_RemEuclid_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P4RemEuclidN2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_PartialDiv_N2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -18853,6 +31839,42 @@ fn test_P4_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_BitAnd_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndN1 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitAndN1 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P4BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorN1 = <<A as BitXor<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<P4BitXorN1 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -18873,79 +31895,139 @@ fn test_P4_Sub_N1() {
     #[allow(non_camel_case_types)]
     type P4SubN1 = <<A as Sub<B>>::Output as Same<P5>>::Output;
 
-    assert_eq!(<P4SubN1 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+    assert_eq!(<P4SubN1 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Mul_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4MulN1 = <<A as Mul<B>>::Output as Same<N4>>::Output;
+
+    assert_eq!(<P4MulN1 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Min_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4MinN1 = <<A as Min<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P4MinN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Max_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4MaxN1 = <<A as Max<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4MaxN1 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Gcd_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4GcdN1 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P4GcdN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Mul_N1() {
+fn test_P4_Div_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P4MulN1 = <<A as Mul<B>>::Output as Same<N4>>::Output;
+    type P4DivN1 = <<A as Div<B>>::Output as Same<N4>>::Output;
 
-    assert_eq!(<P4MulN1 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+    assert_eq!(<P4DivN1 as Integer>::to_i64(), <N4 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Min_N1() {
+fn test_P4_Rem_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P4MinN1 = <<A as Min<B>>::Output as Same<N1>>::Output;
+    type P4RemN1 = <<A as Rem<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P4MinN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<P4RemN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Max_N1() {
+fn test_P4_DivFloor_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P4MaxN1 = <<A as Max<B>>::Output as Same<P4>>::Output;
+    type P4DivFloorN1 = <<A as DivFloor<B>>::Output as Same<N4>>::Output;
 
-    assert_eq!(<P4MaxN1 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivFloorN1 as Integer>::to_i64(),
+        <N4 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Gcd_N1() {
+fn test_P4_DivCeil_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P4GcdN1 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type P4DivCeilN1 = <<A as DivCeil<B>>::Output as Same<N4>>::Output;
 
-    assert_eq!(<P4GcdN1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivCeilN1 as Integer>::to_i64(),
+        <N4 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Div_N1() {
+fn test_P4_DivEuclid_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P4DivN1 = <<A as Div<B>>::Output as Same<N4>>::Output;
+    type P4DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<N4>>::Output;
 
-    assert_eq!(<P4DivN1 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivEuclidN1 as Integer>::to_i64(),
+        <N4 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Rem_N1() {
+fn test_P4_RemEuclid_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = NInt<UInt<UTerm, B1>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P4RemN1 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type P4RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P4RemN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <P4RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
@@ -18974,6 +32056,42 @@ fn test_P4_Cmp_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_BitAnd__0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr__0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = Z0;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOr_0 = <<A as BitOr<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitOr_0 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor__0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = Z0;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXor_0 = <<A as BitXor<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitXor_0 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add__0() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = Z0;
@@ -19068,6 +32186,66 @@ fn test_P4_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_Shl_0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UTerm;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShlU0 = <<A as Shl<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4ShlU0 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Shr_0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UTerm;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShrU0 = <<A as Shr<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4ShrU0 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitAnd_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndP1 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4BitAndP1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrP1 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P4BitOrP1 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorP1 = <<A as BitXor<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P4BitXorP1 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_P1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -19164,6 +32342,66 @@ fn test_P4_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_DivFloor_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivFloorP1 = <<A as DivFloor<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <P4DivFloorP1 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivCeil_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivCeilP1 = <<A as DivCeil<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <P4DivCeilP1 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivEuclid_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <P4DivEuclidP1 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_RemEuclid_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P4RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_PartialDiv_P1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -19201,6 +32439,66 @@ fn test_P4_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_Shl_1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UTerm, B1>;
+    type P8 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShlU1 = <<A as Shl<B>>::Output as Same<P8>>::Output;
+
+    assert_eq!(<P4ShlU1 as Integer>::to_i64(), <P8 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Shr_1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UTerm, B1>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShrU1 = <<A as Shr<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P4ShrU1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitAnd_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndP2 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4BitAndP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrP2 = <<A as BitOr<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P4BitOrP2 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorP2 = <<A as BitXor<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P4BitXorP2 as Integer>::to_i64(), <P6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -19252,48 +32550,108 @@ fn test_P4_Min_P2() {
 fn test_P4_Max_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4MaxP2 = <<A as Max<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4MaxP2 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Gcd_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4GcdP2 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P4GcdP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Div_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivP2 = <<A as Div<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P4DivP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Rem_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4RemP2 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4RemP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivFloor_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P4MaxP2 = <<A as Max<B>>::Output as Same<P4>>::Output;
+    type P4DivFloorP2 = <<A as DivFloor<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<P4MaxP2 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivFloorP2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Gcd_P2() {
+fn test_P4_DivCeil_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P4GcdP2 = <<A as Gcd<B>>::Output as Same<P2>>::Output;
+    type P4DivCeilP2 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<P4GcdP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivCeilP2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Div_P2() {
+fn test_P4_DivEuclid_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P4DivP2 = <<A as Div<B>>::Output as Same<P2>>::Output;
+    type P4DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<P4DivP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(
+        <P4DivEuclidP2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P4_Rem_P2() {
+fn test_P4_RemEuclid_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type _0 = Z0;
 
     #[allow(non_camel_case_types)]
-    type P4RemP2 = <<A as Rem<B>>::Output as Same<_0>>::Output;
+    type P4RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
 
-    assert_eq!(<P4RemP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+    assert_eq!(
+        <P4RemEuclidP2 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
@@ -19334,6 +32692,66 @@ fn test_P4_Cmp_P2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_Shl_2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type P16 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShlU2 = <<A as Shl<B>>::Output as Same<P16>>::Output;
+
+    assert_eq!(<P4ShlU2 as Integer>::to_i64(), <P16 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Shr_2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShrU2 = <<A as Shr<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P4ShrU2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitAnd_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndP3 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4BitAndP3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrP3 = <<A as BitOr<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P4BitOrP3 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorP3 = <<A as BitXor<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P4BitXorP3 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_P3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -19430,6 +32848,66 @@ fn test_P4_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_DivFloor_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivFloorP3 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P4DivFloorP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivCeil_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivCeilP3 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P4DivCeilP3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivEuclid_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P4DivEuclidP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_RemEuclid_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P4RemEuclidP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Pow_P3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -19452,6 +32930,66 @@ fn test_P4_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_Shl_3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type P32 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShlU3 = <<A as Shl<B>>::Output as Same<P32>>::Output;
+
+    assert_eq!(<P4ShlU3 as Integer>::to_i64(), <P32 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Shr_3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4ShrU3 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4ShrU3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitAnd_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndP4 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitAndP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrP4 = <<A as BitOr<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitOrP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4BitXorP4 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4BitXorP4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Add_P4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -19548,6 +33086,66 @@ fn test_P4_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_DivFloor_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivFloorP4 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P4DivFloorP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivCeil_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivCeilP4 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P4DivCeilP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivEuclid_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P4DivEuclidP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_RemEuclid_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P4RemEuclidP4 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_PartialDiv_P4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -19582,11 +33180,71 @@ fn test_P4_Pow_P4() {
 #[allow(non_snake_case)]
 fn test_P4_Cmp_P4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P4CmpP4 as Ord>::to_ordering(), Ordering::Equal);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Shl_4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type P64 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShlU4 = <<A as Shl<B>>::Output as Same<P64>>::Output;
+
+    assert_eq!(<P4ShlU4 as Integer>::to_i64(), <P64 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Shr_4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4ShrU4 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4ShrU4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitAnd_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitAndP5 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P4BitAndP5 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitOr_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4BitOrP5 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P4BitOrP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_BitXor_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P4CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P4CmpP4 as Ord>::to_ordering(), Ordering::Equal);
+    type P4BitXorP5 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P4BitXorP5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -19686,6 +33344,66 @@ fn test_P4_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_DivFloor_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4DivFloorP5 = <<A as DivFloor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P4DivFloorP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivCeil_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P4DivCeilP5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P4DivCeilP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_DivEuclid_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P4DivEuclidP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_RemEuclid_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(
+        <P4RemEuclidP5 as Integer>::to_i64(),
+        <P4 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Pow_P5() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -19722,6 +33440,67 @@ fn test_P4_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_Shl_5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type P128 =
+        PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P4ShlU5 = <<A as Shl<B>>::Output as Same<P128>>::Output;
+
+    assert_eq!(<P4ShlU5 as Integer>::to_i64(), <P128 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Shr_5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P4ShrU5 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P4ShrU5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitAnd_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAndN5 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P5BitAndN5 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOrN5 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P5BitOrN5 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitXor_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitXorN5 = <<A as BitXor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(<P5BitXorN5 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Add_N5() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -19818,6 +33597,66 @@ fn test_P5_Rem_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_DivFloor_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorN5 = <<A as DivFloor<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P5DivFloorN5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilN5 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P5DivCeilN5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidN5 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidN5 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_N5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidN5 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidN5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_PartialDiv_N5() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -19843,6 +33682,42 @@ fn test_P5_Cmp_N5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_BitAnd_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAndN4 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P5BitAndN4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOrN4 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(<P5BitOrN4 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitXor_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N7 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitXorN4 = <<A as BitXor<B>>::Output as Same<N7>>::Output;
+
+    assert_eq!(<P5BitXorN4 as Integer>::to_i64(), <N7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Add_N4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -19909,43 +33784,139 @@ fn test_P5_Gcd_N4() {
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5GcdN4 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
-
-    assert_eq!(<P5GcdN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    type P5GcdN4 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P5GcdN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Div_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivN4 = <<A as Div<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P5DivN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Rem_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5RemN4 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P5RemN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivFloor_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorN4 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P5DivFloorN4 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilN4 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P5DivCeilN4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidN4 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidN4 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidN4 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidN4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Cmp_N4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5CmpN4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P5CmpN4 as Ord>::to_ordering(), Ordering::Greater);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Div_N4() {
+fn test_P5_BitAnd_N3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type N1 = NInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5DivN4 = <<A as Div<B>>::Output as Same<N1>>::Output;
+    type P5BitAndN3 = <<A as BitAnd<B>>::Output as Same<P5>>::Output;
 
-    assert_eq!(<P5DivN4 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+    assert_eq!(<P5BitAndN3 as Integer>::to_i64(), <P5 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Rem_N4() {
+fn test_P5_BitOr_N3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5RemN4 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+    type P5BitOrN3 = <<A as BitOr<B>>::Output as Same<N3>>::Output;
 
-    assert_eq!(<P5RemN4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P5BitOrN3 as Integer>::to_i64(), <N3 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Cmp_N4() {
+fn test_P5_BitXor_N3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N8 = NInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P5CmpN4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P5CmpN4 as Ord>::to_ordering(), Ordering::Greater);
+    type P5BitXorN3 = <<A as BitXor<B>>::Output as Same<N8>>::Output;
+
+    assert_eq!(<P5BitXorN3 as Integer>::to_i64(), <N8 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -20045,6 +34016,66 @@ fn test_P5_Rem_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_DivFloor_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorN3 = <<A as DivFloor<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P5DivFloorN3 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilN3 = <<A as DivCeil<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P5DivCeilN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidN3 = <<A as DivEuclid<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidN3 as Integer>::to_i64(),
+        <N1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_N3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidN3 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidN3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Cmp_N3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -20055,6 +34086,42 @@ fn test_P5_Cmp_N3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_BitAnd_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAndN2 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P5BitAndN2 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOrN2 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P5BitOrN2 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitXor_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitXorN2 = <<A as BitXor<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(<P5BitXorN2 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Add_N2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -20151,6 +34218,66 @@ fn test_P5_Rem_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_DivFloor_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorN2 = <<A as DivFloor<B>>::Output as Same<N3>>::Output;
+
+    assert_eq!(
+        <P5DivFloorN2 as Integer>::to_i64(),
+        <N3 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilN2 = <<A as DivCeil<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P5DivCeilN2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidN2 = <<A as DivEuclid<B>>::Output as Same<N2>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidN2 as Integer>::to_i64(),
+        <N2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_N2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidN2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidN2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Cmp_N2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -20161,6 +34288,42 @@ fn test_P5_Cmp_N2() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_BitAnd_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAndN1 = <<A as BitAnd<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5BitAndN1 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOrN1 = <<A as BitOr<B>>::Output as Same<N1>>::Output;
+
+    assert_eq!(<P5BitOrN1 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitXor_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitXorN1 = <<A as BitXor<B>>::Output as Same<N6>>::Output;
+
+    assert_eq!(<P5BitXorN1 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Add_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -20257,6 +34420,66 @@ fn test_P5_Rem_N1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_DivFloor_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorN1 = <<A as DivFloor<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(
+        <P5DivFloorN1 as Integer>::to_i64(),
+        <N5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilN1 = <<A as DivCeil<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(
+        <P5DivCeilN1 as Integer>::to_i64(),
+        <N5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidN1 = <<A as DivEuclid<B>>::Output as Same<N5>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidN1 as Integer>::to_i64(),
+        <N5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidN1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidN1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_PartialDiv_N1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = NInt<UInt<UTerm, B1>>;
@@ -20265,20 +34488,56 @@ fn test_P5_PartialDiv_N1() {
     #[allow(non_camel_case_types)]
     type P5PartialDivN1 = <<A as PartialDiv<B>>::Output as Same<N5>>::Output;
 
-    assert_eq!(
-        <P5PartialDivN1 as Integer>::to_i64(),
-        <N5 as Integer>::to_i64()
-    );
+    assert_eq!(
+        <P5PartialDivN1 as Integer>::to_i64(),
+        <N5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Cmp_N1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5CmpN1 = <A as Cmp<B>>::Output;
+    assert_eq!(<P5CmpN1 as Ord>::to_ordering(), Ordering::Greater);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitAnd__0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAnd_0 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P5BitAnd_0 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr__0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = Z0;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOr_0 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5BitOr_0 as Integer>::to_i64(), <P5 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Cmp_N1() {
+fn test_P5_BitXor__0() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = NInt<UInt<UTerm, B1>>;
+    type B = Z0;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5CmpN1 = <A as Cmp<B>>::Output;
-    assert_eq!(<P5CmpN1 as Ord>::to_ordering(), Ordering::Greater);
+    type P5BitXor_0 = <<A as BitXor<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5BitXor_0 as Integer>::to_i64(), <P5 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -20376,6 +34635,66 @@ fn test_P5_Cmp__0() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_Shl_0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UTerm;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5ShlU0 = <<A as Shl<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5ShlU0 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Shr_0() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UTerm;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5ShrU0 = <<A as Shr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5ShrU0 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitAnd_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAndP1 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P5BitAndP1 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOrP1 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5BitOrP1 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitXor_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitXorP1 = <<A as BitXor<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P5BitXorP1 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Add_P1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -20472,6 +34791,66 @@ fn test_P5_Rem_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_DivFloor_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorP1 = <<A as DivFloor<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(
+        <P5DivFloorP1 as Integer>::to_i64(),
+        <P5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilP1 = <<A as DivCeil<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(
+        <P5DivCeilP1 as Integer>::to_i64(),
+        <P5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidP1 = <<A as DivEuclid<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidP1 as Integer>::to_i64(),
+        <P5 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_P1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidP1 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidP1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_PartialDiv_P1() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UTerm, B1>>;
@@ -20509,121 +34888,301 @@ fn test_P5_Cmp_P1() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_Shl_1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UTerm, B1>;
+    type P10 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5ShlU1 = <<A as Shl<B>>::Output as Same<P10>>::Output;
+
+    assert_eq!(<P5ShlU1 as Integer>::to_i64(), <P10 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Shr_1() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UTerm, B1>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5ShrU1 = <<A as Shr<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P5ShrU1 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitAnd_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAndP2 = <<A as BitAnd<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P5BitAndP2 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOrP2 = <<A as BitOr<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P5BitOrP2 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitXor_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitXorP2 = <<A as BitXor<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P5BitXorP2 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Add_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5AddP2 = <<A as Add<B>>::Output as Same<P7>>::Output;
+
+    assert_eq!(<P5AddP2 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Sub_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5SubP2 = <<A as Sub<B>>::Output as Same<P3>>::Output;
+
+    assert_eq!(<P5SubP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Mul_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P10 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5MulP2 = <<A as Mul<B>>::Output as Same<P10>>::Output;
+
+    assert_eq!(<P5MulP2 as Integer>::to_i64(), <P10 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Min_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5MinP2 = <<A as Min<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P5MinP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Max_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5MaxP2 = <<A as Max<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5MaxP2 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Gcd_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5GcdP2 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P5GcdP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Div_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivP2 = <<A as Div<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(<P5DivP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Rem_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5RemP2 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P5RemP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivFloor_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorP2 = <<A as DivFloor<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P5DivFloorP2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_P2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5AddP2 = <<A as Add<B>>::Output as Same<P7>>::Output;
+    type P5DivCeilP2 = <<A as DivCeil<B>>::Output as Same<P3>>::Output;
 
-    assert_eq!(<P5AddP2 as Integer>::to_i64(), <P7 as Integer>::to_i64());
+    assert_eq!(
+        <P5DivCeilP2 as Integer>::to_i64(),
+        <P3 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Sub_P2() {
+fn test_P5_DivEuclid_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P5SubP2 = <<A as Sub<B>>::Output as Same<P3>>::Output;
+    type P5DivEuclidP2 = <<A as DivEuclid<B>>::Output as Same<P2>>::Output;
 
-    assert_eq!(<P5SubP2 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+    assert_eq!(
+        <P5DivEuclidP2 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Mul_P2() {
+fn test_P5_RemEuclid_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P10 = PInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5MulP2 = <<A as Mul<B>>::Output as Same<P10>>::Output;
+    type P5RemEuclidP2 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P5MulP2 as Integer>::to_i64(), <P10 as Integer>::to_i64());
+    assert_eq!(
+        <P5RemEuclidP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Min_P2() {
+fn test_P5_Pow_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P25 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5MinP2 = <<A as Min<B>>::Output as Same<P2>>::Output;
+    type P5PowP2 = <<A as Pow<B>>::Output as Same<P25>>::Output;
 
-    assert_eq!(<P5MinP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(<P5PowP2 as Integer>::to_i64(), <P25 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Max_P2() {
+fn test_P5_Cmp_P2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5MaxP2 = <<A as Max<B>>::Output as Same<P5>>::Output;
-
-    assert_eq!(<P5MaxP2 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+    type P5CmpP2 = <A as Cmp<B>>::Output;
+    assert_eq!(<P5CmpP2 as Ord>::to_ordering(), Ordering::Greater);
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Gcd_P2() {
+fn test_P5_Shl_2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P1 = PInt<UInt<UTerm, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type P20 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P5GcdP2 = <<A as Gcd<B>>::Output as Same<P1>>::Output;
+    type P5ShlU2 = <<A as Shl<B>>::Output as Same<P20>>::Output;
 
-    assert_eq!(<P5GcdP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P5ShlU2 as Integer>::to_i64(), <P20 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Div_P2() {
+fn test_P5_Shr_2() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = UInt<UInt<UTerm, B1>, B0>;
+    type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5DivP2 = <<A as Div<B>>::Output as Same<P2>>::Output;
+    type P5ShrU2 = <<A as Shr<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P5DivP2 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+    assert_eq!(<P5ShrU2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Rem_P2() {
+fn test_P5_BitAnd_P3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type P1 = PInt<UInt<UTerm, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5RemP2 = <<A as Rem<B>>::Output as Same<P1>>::Output;
+    type P5BitAndP3 = <<A as BitAnd<B>>::Output as Same<P1>>::Output;
 
-    assert_eq!(<P5RemP2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+    assert_eq!(<P5BitAndP3 as Integer>::to_i64(), <P1 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Pow_P2() {
+fn test_P5_BitOr_P3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
-    type P25 = PInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P7 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>>;
 
     #[allow(non_camel_case_types)]
-    type P5PowP2 = <<A as Pow<B>>::Output as Same<P25>>::Output;
+    type P5BitOrP3 = <<A as BitOr<B>>::Output as Same<P7>>::Output;
 
-    assert_eq!(<P5PowP2 as Integer>::to_i64(), <P25 as Integer>::to_i64());
+    assert_eq!(<P5BitOrP3 as Integer>::to_i64(), <P7 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
-fn test_P5_Cmp_P2() {
+fn test_P5_BitXor_P3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
-    type B = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P6 = PInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P5CmpP2 = <A as Cmp<B>>::Output;
-    assert_eq!(<P5CmpP2 as Ord>::to_ordering(), Ordering::Greater);
+    type P5BitXorP3 = <<A as BitXor<B>>::Output as Same<P6>>::Output;
+
+    assert_eq!(<P5BitXorP3 as Integer>::to_i64(), <P6 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -20723,6 +35282,66 @@ fn test_P5_Rem_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_DivFloor_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorP3 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5DivFloorP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilP3 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P5DivCeilP3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidP3 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_P3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidP3 = <<A as RemEuclid<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidP3 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Pow_P3() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -20745,6 +35364,66 @@ fn test_P5_Cmp_P3() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_Shl_3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type P40 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5ShlU3 = <<A as Shl<B>>::Output as Same<P40>>::Output;
+
+    assert_eq!(<P5ShlU3 as Integer>::to_i64(), <P40 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Shr_3() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UTerm, B1>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5ShrU3 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P5ShrU3 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitAnd_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAndP4 = <<A as BitAnd<B>>::Output as Same<P4>>::Output;
+
+    assert_eq!(<P5BitAndP4 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOrP4 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5BitOrP4 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitXor_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitXorP4 = <<A as BitXor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(<P5BitXorP4 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Add_P4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -20841,6 +35520,66 @@ fn test_P5_Rem_P4() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_DivFloor_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorP4 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5DivFloorP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilP4 = <<A as DivCeil<B>>::Output as Same<P2>>::Output;
+
+    assert_eq!(
+        <P5DivCeilP4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidP4 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_P4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidP4 = <<A as RemEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidP4 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Pow_P4() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -20869,8 +35608,68 @@ fn test_P5_Cmp_P4() {
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
 
     #[allow(non_camel_case_types)]
-    type P5CmpP4 = <A as Cmp<B>>::Output;
-    assert_eq!(<P5CmpP4 as Ord>::to_ordering(), Ordering::Greater);
+    type P5CmpP4 = <A as Cmp<B>>::Output;
+    assert_eq!(<P5CmpP4 as Ord>::to_ordering(), Ordering::Greater);
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Shl_4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type P80 = PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5ShlU4 = <<A as Shl<B>>::Output as Same<P80>>::Output;
+
+    assert_eq!(<P5ShlU4 as Integer>::to_i64(), <P80 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Shr_4() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B0>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5ShrU4 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P5ShrU4 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitAnd_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitAndP5 = <<A as BitAnd<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5BitAndP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitOr_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5BitOrP5 = <<A as BitOr<B>>::Output as Same<P5>>::Output;
+
+    assert_eq!(<P5BitOrP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_BitXor_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5BitXorP5 = <<A as BitXor<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P5BitXorP5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
 }
 #[test]
 #[allow(non_snake_case)]
@@ -20970,6 +35769,66 @@ fn test_P5_Rem_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_DivFloor_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivFloorP5 = <<A as DivFloor<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5DivFloorP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivCeil_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivCeilP5 = <<A as DivCeil<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5DivCeilP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_DivEuclid_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type P5DivEuclidP5 = <<A as DivEuclid<B>>::Output as Same<P1>>::Output;
+
+    assert_eq!(
+        <P5DivEuclidP5 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_RemEuclid_P5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5RemEuclidP5 = <<A as RemEuclid<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(
+        <P5RemEuclidP5 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_PartialDiv_P5() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type B = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -21027,6 +35886,31 @@ fn test_P5_Cmp_P5() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P5_Shl_5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type P160 =
+        PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>, B0>, B0>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type P5ShlU5 = <<A as Shl<B>>::Output as Same<P160>>::Output;
+
+    assert_eq!(<P5ShlU5 as Integer>::to_i64(), <P160 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Shr_5() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type B = UInt<UInt<UInt<UTerm, B1>, B0>, B1>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type P5ShrU5 = <<A as Shr<B>>::Output as Same<_0>>::Output;
+
+    assert_eq!(<P5ShrU5 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N5_Neg() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type P5 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -21047,6 +35931,16 @@ fn test_N5_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N5_Not() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type NotN5 = <<A as Not>::Output as Same<P4>>::Output;
+    assert_eq!(<NotN5 as Integer>::to_i64(), <P4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N4_Neg() {
     type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type P4 = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -21067,6 +35961,16 @@ fn test_N4_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N4_Not() {
+    type A = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type NotN4 = <<A as Not>::Output as Same<P3>>::Output;
+    assert_eq!(<NotN4 as Integer>::to_i64(), <P3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N3_Neg() {
     type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
     type P3 = PInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -21087,6 +35991,16 @@ fn test_N3_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N3_Not() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type NotN3 = <<A as Not>::Output as Same<P2>>::Output;
+    assert_eq!(<NotN3 as Integer>::to_i64(), <P2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N2_Neg() {
     type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
     type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -21107,6 +36021,16 @@ fn test_N2_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N2_Not() {
+    type A = NInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type NotN2 = <<A as Not>::Output as Same<P1>>::Output;
+    assert_eq!(<NotN2 as Integer>::to_i64(), <P1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_N1_Neg() {
     type A = NInt<UInt<UTerm, B1>>;
     type P1 = PInt<UInt<UTerm, B1>>;
@@ -21127,6 +36051,16 @@ fn test_N1_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_N1_Not() {
+    type A = NInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type NotN1 = <<A as Not>::Output as Same<_0>>::Output;
+    assert_eq!(<NotN1 as Integer>::to_i64(), <_0 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
 fn test__0_Neg() {
     type A = Z0;
     type _0 = Z0;
@@ -21147,6 +36081,29 @@ fn test__0_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test__0_Not() {
+    type A = Z0;
+    type N1 = NInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type Not_0 = <<A as Not>::Output as Same<N1>>::Output;
+    assert_eq!(<Not_0 as Integer>::to_i64(), <N1 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test__0_SquareRoot() {
+    type A = Z0;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type SquareRoot_0 = <<A as SquareRoot>::Output as Same<_0>>::Output;
+    assert_eq!(
+        <SquareRoot_0 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P1_Neg() {
     type A = PInt<UInt<UTerm, B1>>;
     type N1 = NInt<UInt<UTerm, B1>>;
@@ -21167,6 +36124,42 @@ fn test_P1_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P1_Not() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type NotP1 = <<A as Not>::Output as Same<N2>>::Output;
+    assert_eq!(<NotP1 as Integer>::to_i64(), <N2 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_SquareRoot() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type SquareRootP1 = <<A as SquareRoot>::Output as Same<P1>>::Output;
+    assert_eq!(
+        <SquareRootP1 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P1_Logarithm2() {
+    type A = PInt<UInt<UTerm, B1>>;
+    type _0 = Z0;
+
+    #[allow(non_camel_case_types)]
+    type Logarithm2P1 = <<A as Logarithm2>::Output as Same<_0>>::Output;
+    assert_eq!(
+        <Logarithm2P1 as Integer>::to_i64(),
+        <_0 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P2_Neg() {
     type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
     type N2 = NInt<UInt<UInt<UTerm, B1>, B0>>;
@@ -21187,6 +36180,42 @@ fn test_P2_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P2_Not() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type NotP2 = <<A as Not>::Output as Same<N3>>::Output;
+    assert_eq!(<NotP2 as Integer>::to_i64(), <N3 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_SquareRoot() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type SquareRootP2 = <<A as SquareRoot>::Output as Same<P1>>::Output;
+    assert_eq!(
+        <SquareRootP2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P2_Logarithm2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B0>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type Logarithm2P2 = <<A as Logarithm2>::Output as Same<P1>>::Output;
+    assert_eq!(
+        <Logarithm2P2 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P3_Neg() {
     type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
     type N3 = NInt<UInt<UInt<UTerm, B1>, B1>>;
@@ -21207,6 +36236,42 @@ fn test_P3_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P3_Not() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type NotP3 = <<A as Not>::Output as Same<N4>>::Output;
+    assert_eq!(<NotP3 as Integer>::to_i64(), <N4 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_SquareRoot() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type SquareRootP3 = <<A as SquareRoot>::Output as Same<P1>>::Output;
+    assert_eq!(
+        <SquareRootP3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P3_Logarithm2() {
+    type A = PInt<UInt<UInt<UTerm, B1>, B1>>;
+    type P1 = PInt<UInt<UTerm, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type Logarithm2P3 = <<A as Logarithm2>::Output as Same<P1>>::Output;
+    assert_eq!(
+        <Logarithm2P3 as Integer>::to_i64(),
+        <P1 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P4_Neg() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
     type N4 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
@@ -21227,6 +36292,42 @@ fn test_P4_Abs() {
 }
 #[test]
 #[allow(non_snake_case)]
+fn test_P4_Not() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+
+    #[allow(non_camel_case_types)]
+    type NotP4 = <<A as Not>::Output as Same<N5>>::Output;
+    assert_eq!(<NotP4 as Integer>::to_i64(), <N5 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_SquareRoot() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type SquareRootP4 = <<A as SquareRoot>::Output as Same<P2>>::Output;
+    assert_eq!(
+        <SquareRootP4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P4_Logarithm2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B0>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type Logarithm2P4 = <<A as Logarithm2>::Output as Same<P2>>::Output;
+    assert_eq!(
+        <Logarithm2P4 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
 fn test_P5_Neg() {
     type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
     type N5 = NInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
@@ -21245,3 +36346,39 @@ fn test_P5_Abs() {
     type AbsP5 = <<A as Abs>::Output as Same<P5>>::Output;
     assert_eq!(<AbsP5 as Integer>::to_i64(), <P5 as Integer>::to_i64());
 }
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Not() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type N6 = NInt<UInt<UInt<UInt<UTerm, B1>, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type NotP5 = <<A as Not>::Output as Same<N6>>::Output;
+    assert_eq!(<NotP5 as Integer>::to_i64(), <N6 as Integer>::to_i64());
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_SquareRoot() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type SquareRootP5 = <<A as SquareRoot>::Output as Same<P2>>::Output;
+    assert_eq!(
+        <SquareRootP5 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_P5_Logarithm2() {
+    type A = PInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>>;
+    type P2 = PInt<UInt<UInt<UTerm, B1>, B0>>;
+
+    #[allow(non_camel_case_types)]
+    type Logarithm2P5 = <<A as Logarithm2>::Output as Same<P2>>::Output;
+    assert_eq!(
+        <Logarithm2P5 as Integer>::to_i64(),
+        <P2 as Integer>::to_i64()
+    );
+}